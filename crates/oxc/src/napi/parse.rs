@@ -17,6 +17,17 @@ pub struct ParserOptions {
     ///
     /// Default: true
     pub preserve_parens: Option<bool>,
+    /// Report `require("...")` calls in the `requires` array of module lexer output.
+    ///
+    /// Calls of a `require` which is declared in the file are not reported.
+    ///
+    /// Default: false
+    pub detect_requires: Option<bool>,
+    /// Report 1-based line and 0-based UTF-16 column positions alongside byte offsets
+    /// of module lexer import and export specifiers.
+    ///
+    /// Default: false
+    pub include_positions: Option<bool>,
 }
 
 #[napi(object)]
@@ -80,6 +80,13 @@ pub struct TransformOptions {
     /// Configure how TSX and JSX are transformed.
     pub jsx: Option<JsxOptions>,
 
+    /// Keep decorators in the output as written.
+    ///
+    /// Decorators are not transformed, so when this is not enabled they are reported as errors.
+    ///
+    /// @default false
+    pub decorators: Option<bool>,
+
     /// Define Plugin
     #[napi(ts_type = "Record<string, string>")]
     pub define: Option<FxHashMap<String, String>>,
@@ -98,6 +105,10 @@ impl From<TransformOptions> for oxc_transformer::TransformOptions {
                 .map(oxc_transformer::TypeScriptOptions::from)
                 .unwrap_or_default(),
             jsx: options.jsx.map(Into::into).unwrap_or_default(),
+            proposals: oxc_transformer::ProposalOptions {
+                decorators: options.decorators.unwrap_or_default(),
+                ..oxc_transformer::ProposalOptions::default()
+            },
             ..Self::default()
         }
     }
@@ -109,8 +120,31 @@ pub struct TypeScriptOptions {
     pub jsx_pragma: Option<String>,
     pub jsx_pragma_frag: Option<String>,
     pub only_remove_type_imports: Option<bool>,
+    /// Only remove type-only imports and exports and inline `type` specifiers,
+    /// and keep other imports as written, the same as TypeScript's `verbatimModuleSyntax`.
+    ///
+    /// @default false
+    pub verbatim_module_syntax: Option<bool>,
+    /// Warn about re-exports of imported names that may be types,
+    /// the same as TypeScript's `isolatedModules`.
+    ///
+    /// @default false
+    pub isolated_modules: Option<bool>,
     pub allow_namespaces: Option<bool>,
+    /// Emit the members of flat exported namespaces as ES module exports, instead of an IIFE.
+    ///
+    /// @default false
+    pub flatten_namespaces: Option<bool>,
     pub allow_declare_fields: Option<bool>,
+    /// Inline members of `const enum`s at use sites and remove their declarations.
+    /// Constant members of regular enums which are only used by reading members are inlined too.
+    ///
+    /// @default false
+    pub optimize_const_enums: Option<bool>,
+    /// Emit design-time type metadata for decorated declarations, as `Reflect.metadata` decorators.
+    ///
+    /// @default false
+    pub emit_decorator_metadata: Option<bool>,
     /// Also generate a `.d.ts` declaration file for TypeScript files.
     ///
     /// The source file must be compliant with all
@@ -140,9 +174,17 @@ impl From<TypeScriptOptions> for oxc_transformer::TypeScriptOptions {
             only_remove_type_imports: options
                 .only_remove_type_imports
                 .unwrap_or(ops.only_remove_type_imports),
+            verbatim_module_syntax: options
+                .verbatim_module_syntax
+                .unwrap_or(ops.verbatim_module_syntax),
+            isolated_modules: options.isolated_modules.unwrap_or(ops.isolated_modules),
             allow_namespaces: options.allow_namespaces.unwrap_or(ops.allow_namespaces),
+            flatten_namespaces: options.flatten_namespaces.unwrap_or(ops.flatten_namespaces),
             allow_declare_fields: options.allow_declare_fields.unwrap_or(ops.allow_declare_fields),
-            optimize_const_enums: false,
+            optimize_const_enums: options.optimize_const_enums.unwrap_or(ops.optimize_const_enums),
+            emit_decorator_metadata: options
+                .emit_decorator_metadata
+                .unwrap_or(ops.emit_decorator_metadata),
             rewrite_import_extensions: options.rewrite_import_extensions.and_then(|value| {
                 match value {
                     Either::A(v) => {
@@ -172,9 +214,10 @@ pub struct JsxOptions {
     ///
     /// - 'automatic' - auto-import the correct JSX factories
     /// - 'classic' - no auto-import
+    /// - 'preserve' - keep JSX as is, only removing TypeScript syntax
     ///
     /// @default 'automatic'
-    #[napi(ts_type = "'classic' | 'automatic'")]
+    #[napi(ts_type = "'classic' | 'automatic' | 'preserve'")]
     pub runtime: Option<String>,
 
     /// Emit development-specific information, such as `__source` and `__self`.
@@ -193,6 +236,12 @@ pub struct JsxOptions {
     /// @default true
     pub throw_if_namespace: Option<bool>,
 
+    /// Report a warning if a component element name (e.g. `<Foo />`) does not
+    /// resolve to any binding in scope, which usually means a missing import.
+    ///
+    /// @default false
+    pub check_unresolved_components: Option<bool>,
+
     /// Enables `@babel/plugin-transform-react-pure-annotations`.
     ///
     /// It will mark top-level React method calls as pure for tree shaking.
@@ -253,10 +302,14 @@ impl From<JsxOptions> for oxc_transformer::JsxOptions {
         oxc_transformer::JsxOptions {
             runtime: match options.runtime.as_deref() {
                 Some("classic") => JsxRuntime::Classic,
+                Some("preserve") => JsxRuntime::Preserve,
                 /* "automatic" */ _ => JsxRuntime::Automatic,
             },
             development: options.development.unwrap_or(ops.development),
             throw_if_namespace: options.throw_if_namespace.unwrap_or(ops.throw_if_namespace),
+            check_unresolved_components: options
+                .check_unresolved_components
+                .unwrap_or(ops.check_unresolved_components),
             pure: options.pure.unwrap_or(ops.pure),
             import_source: options.import_source,
             pragma: options.pragma,
@@ -284,6 +337,9 @@ pub struct ReactRefreshOptions {
     /// @default `$RefreshSig$`.
     pub refresh_sig: Option<String>,
 
+    /// Emit readable signatures of hook calls, instead of a hash of them.
+    ///
+    /// @default false
     pub emit_full_signatures: Option<bool>,
 }
 
@@ -319,10 +375,37 @@ impl From<ArrowFunctionsOptions> for oxc_transformer::ArrowFunctionsOptions {
 pub struct Es2015Options {
     /// Transform arrow functions into function expressions.
     pub arrow_function: Option<ArrowFunctionsOptions>,
+    /// Transform `let` and `const` declarations into `var` declarations.
+    ///
+    /// @default false
+    pub block_scoping: Option<bool>,
+    /// Transform classes into constructor functions.
+    ///
+    /// @default false
+    pub classes: Option<bool>,
+    /// Transform array and object destructuring into property accesses.
+    ///
+    /// @default false
+    pub destructuring: Option<bool>,
+    /// Transform `__proto__` in object literals into `Object.setPrototypeOf` calls.
+    ///
+    /// @default false
+    pub object_literal_proto: Option<bool>,
+    /// Transform template literals into string concatenation, and tagged templates into calls.
+    ///
+    /// @default false
+    pub template_literals: Option<bool>,
 }
 
 impl From<Es2015Options> for oxc_transformer::ES2015Options {
     fn from(options: Es2015Options) -> Self {
-        oxc_transformer::ES2015Options { arrow_function: options.arrow_function.map(Into::into) }
+        oxc_transformer::ES2015Options {
+            arrow_function: options.arrow_function.map(Into::into),
+            block_scoping: options.block_scoping.unwrap_or_default(),
+            classes: options.classes.unwrap_or_default(),
+            destructuring: options.destructuring.unwrap_or_default(),
+            object_literal_proto: options.object_literal_proto.unwrap_or_default(),
+            template_literals: options.template_literals.unwrap_or_default(),
+        }
     }
 }
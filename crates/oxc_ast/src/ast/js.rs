@@ -548,6 +548,12 @@ pub struct CallExpression<'a> {
     pub type_parameters: Option<Box<'a, TSTypeParameterInstantiation<'a>>>,
     pub arguments: Vec<'a, Argument<'a>>,
     pub optional: bool, // for optional chaining
+    /// Marks the call as side-effect free if its result is unused,
+    /// which is printed as a `/* @__PURE__ */` comment.
+    ///
+    /// Set by transforms. `/* @__PURE__ */` comments in the source text are kept as comments.
+    #[estree(skip)]
+    pub pure: bool,
 }
 
 /// `new C()` in `class C {}; new C();`
@@ -571,6 +577,12 @@ pub struct NewExpression<'a> {
     pub arguments: Vec<'a, Argument<'a>>,
     #[ts]
     pub type_parameters: Option<Box<'a, TSTypeParameterInstantiation<'a>>>,
+    /// Marks the `new` expression as side-effect free if its result is unused,
+    /// which is printed as a `/* @__PURE__ */` comment.
+    ///
+    /// Set by transforms. `/* @__PURE__ */` comments in the source text are kept as comments.
+    #[estree(skip)]
+    pub pure: bool,
 }
 
 /// `import.meta` in `console.log(import.meta);`
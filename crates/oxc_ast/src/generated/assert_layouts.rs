@@ -183,13 +183,15 @@ const _: () = {
     assert!(offset_of!(CallExpression, type_parameters) == 24usize);
     assert!(offset_of!(CallExpression, arguments) == 32usize);
     assert!(offset_of!(CallExpression, optional) == 64usize);
+    assert!(offset_of!(CallExpression, pure) == 65usize);
 
-    assert!(size_of::<NewExpression>() == 64usize);
+    assert!(size_of::<NewExpression>() == 72usize);
     assert!(align_of::<NewExpression>() == 8usize);
     assert!(offset_of!(NewExpression, span) == 0usize);
     assert!(offset_of!(NewExpression, callee) == 8usize);
     assert!(offset_of!(NewExpression, arguments) == 24usize);
     assert!(offset_of!(NewExpression, type_parameters) == 56usize);
+    assert!(offset_of!(NewExpression, pure) == 64usize);
 
     assert!(size_of::<MetaProperty>() == 56usize);
     assert!(align_of::<MetaProperty>() == 8usize);
@@ -1739,13 +1741,15 @@ const _: () = {
     assert!(offset_of!(CallExpression, type_parameters) == 16usize);
     assert!(offset_of!(CallExpression, arguments) == 20usize);
     assert!(offset_of!(CallExpression, optional) == 36usize);
+    assert!(offset_of!(CallExpression, pure) == 37usize);
 
-    assert!(size_of::<NewExpression>() == 36usize);
+    assert!(size_of::<NewExpression>() == 40usize);
     assert!(align_of::<NewExpression>() == 4usize);
     assert!(offset_of!(NewExpression, span) == 0usize);
     assert!(offset_of!(NewExpression, callee) == 8usize);
     assert!(offset_of!(NewExpression, arguments) == 16usize);
     assert!(offset_of!(NewExpression, type_parameters) == 32usize);
+    assert!(offset_of!(NewExpression, pure) == 36usize);
 
     assert!(size_of::<MetaProperty>() == 40usize);
     assert!(align_of::<MetaProperty>() == 4usize);
@@ -666,6 +666,7 @@ impl<'a> AstBuilder<'a> {
     /// - type_parameters
     /// - arguments
     /// - optional
+    /// - pure: Marks the call as side-effect free if its result is unused,
     #[inline]
     pub fn expression_call<T1>(
         self,
@@ -674,6 +675,7 @@ impl<'a> AstBuilder<'a> {
         type_parameters: T1,
         arguments: Vec<'a, Argument<'a>>,
         optional: bool,
+        pure: bool,
     ) -> Expression<'a>
     where
         T1: IntoIn<'a, Option<Box<'a, TSTypeParameterInstantiation<'a>>>>,
@@ -684,6 +686,7 @@ impl<'a> AstBuilder<'a> {
             type_parameters,
             arguments,
             optional,
+            pure,
         )))
     }
 
@@ -874,6 +877,7 @@ impl<'a> AstBuilder<'a> {
     /// - callee
     /// - arguments
     /// - type_parameters
+    /// - pure: Marks the `new` expression as side-effect free if its result is unused,
     #[inline]
     pub fn expression_new<T1>(
         self,
@@ -881,6 +885,7 @@ impl<'a> AstBuilder<'a> {
         callee: Expression<'a>,
         arguments: Vec<'a, Argument<'a>>,
         type_parameters: T1,
+        pure: bool,
     ) -> Expression<'a>
     where
         T1: IntoIn<'a, Option<Box<'a, TSTypeParameterInstantiation<'a>>>>,
@@ -890,6 +895,7 @@ impl<'a> AstBuilder<'a> {
             callee,
             arguments,
             type_parameters,
+            pure,
         )))
     }
 
@@ -2047,6 +2053,7 @@ impl<'a> AstBuilder<'a> {
     /// - type_parameters
     /// - arguments
     /// - optional
+    /// - pure: Marks the call as side-effect free if its result is unused,
     #[inline]
     pub fn call_expression<T1>(
         self,
@@ -2055,6 +2062,7 @@ impl<'a> AstBuilder<'a> {
         type_parameters: T1,
         arguments: Vec<'a, Argument<'a>>,
         optional: bool,
+        pure: bool,
     ) -> CallExpression<'a>
     where
         T1: IntoIn<'a, Option<Box<'a, TSTypeParameterInstantiation<'a>>>>,
@@ -2065,6 +2073,7 @@ impl<'a> AstBuilder<'a> {
             type_parameters: type_parameters.into_in(self.allocator),
             arguments,
             optional,
+            pure,
         }
     }
 
@@ -2078,6 +2087,7 @@ impl<'a> AstBuilder<'a> {
     /// - type_parameters
     /// - arguments
     /// - optional
+    /// - pure: Marks the call as side-effect free if its result is unused,
     #[inline]
     pub fn alloc_call_expression<T1>(
         self,
@@ -2086,12 +2096,13 @@ impl<'a> AstBuilder<'a> {
         type_parameters: T1,
         arguments: Vec<'a, Argument<'a>>,
         optional: bool,
+        pure: bool,
     ) -> Box<'a, CallExpression<'a>>
     where
         T1: IntoIn<'a, Option<Box<'a, TSTypeParameterInstantiation<'a>>>>,
     {
         Box::new_in(
-            self.call_expression(span, callee, type_parameters, arguments, optional),
+            self.call_expression(span, callee, type_parameters, arguments, optional, pure),
             self.allocator,
         )
     }
@@ -2105,6 +2116,7 @@ impl<'a> AstBuilder<'a> {
     /// - callee
     /// - arguments
     /// - type_parameters
+    /// - pure: Marks the `new` expression as side-effect free if its result is unused,
     #[inline]
     pub fn new_expression<T1>(
         self,
@@ -2112,6 +2124,7 @@ impl<'a> AstBuilder<'a> {
         callee: Expression<'a>,
         arguments: Vec<'a, Argument<'a>>,
         type_parameters: T1,
+        pure: bool,
     ) -> NewExpression<'a>
     where
         T1: IntoIn<'a, Option<Box<'a, TSTypeParameterInstantiation<'a>>>>,
@@ -2121,6 +2134,7 @@ impl<'a> AstBuilder<'a> {
             callee,
             arguments,
             type_parameters: type_parameters.into_in(self.allocator),
+            pure,
         }
     }
 
@@ -2133,6 +2147,7 @@ impl<'a> AstBuilder<'a> {
     /// - callee
     /// - arguments
     /// - type_parameters
+    /// - pure: Marks the `new` expression as side-effect free if its result is unused,
     #[inline]
     pub fn alloc_new_expression<T1>(
         self,
@@ -2140,11 +2155,15 @@ impl<'a> AstBuilder<'a> {
         callee: Expression<'a>,
         arguments: Vec<'a, Argument<'a>>,
         type_parameters: T1,
+        pure: bool,
     ) -> Box<'a, NewExpression<'a>>
     where
         T1: IntoIn<'a, Option<Box<'a, TSTypeParameterInstantiation<'a>>>>,
     {
-        Box::new_in(self.new_expression(span, callee, arguments, type_parameters), self.allocator)
+        Box::new_in(
+            self.new_expression(span, callee, arguments, type_parameters, pure),
+            self.allocator,
+        )
     }
 
     /// Build a [`MetaProperty`].
@@ -3071,6 +3090,7 @@ impl<'a> AstBuilder<'a> {
     /// - type_parameters
     /// - arguments
     /// - optional
+    /// - pure: Marks the call as side-effect free if its result is unused,
     #[inline]
     pub fn chain_element_call_expression<T1>(
         self,
@@ -3079,6 +3099,7 @@ impl<'a> AstBuilder<'a> {
         type_parameters: T1,
         arguments: Vec<'a, Argument<'a>>,
         optional: bool,
+        pure: bool,
     ) -> ChainElement<'a>
     where
         T1: IntoIn<'a, Option<Box<'a, TSTypeParameterInstantiation<'a>>>>,
@@ -3089,6 +3110,7 @@ impl<'a> AstBuilder<'a> {
             type_parameters,
             arguments,
             optional,
+            pure,
         )))
     }
 
@@ -367,6 +367,7 @@ impl<'a> ContentHash for CallExpression<'a> {
         ContentHash::content_hash(&self.type_parameters, state);
         ContentHash::content_hash(&self.arguments, state);
         ContentHash::content_hash(&self.optional, state);
+        ContentHash::content_hash(&self.pure, state);
     }
 }
 
@@ -375,6 +376,7 @@ impl<'a> ContentHash for NewExpression<'a> {
         ContentHash::content_hash(&self.callee, state);
         ContentHash::content_hash(&self.arguments, state);
         ContentHash::content_hash(&self.type_parameters, state);
+        ContentHash::content_hash(&self.pure, state);
     }
 }
 
@@ -143,6 +143,18 @@ impl<'a> Codegen<'a> {
         }
     }
 
+    /// Print `/* @__PURE__ */` for a call or `new` expression marked as `pure`,
+    /// unless it has an annotation comment in the source text.
+    pub(crate) fn print_pure_annotation(&mut self, pure: bool, node_start: u32) {
+        if pure
+            && self.options.print_annotation_comments()
+            && !self.has_annotation_comment(node_start)
+        {
+            self.print_str("/* @__PURE__ */");
+            self.print_hard_space();
+        }
+    }
+
     pub(crate) fn print_expr_comments(&mut self, start: u32) -> bool {
         if self.options.minify {
             return false;
@@ -44,6 +44,13 @@ impl<'a> Gen for Program<'a> {
         for directive in &self.directives {
             directive.print(p, ctx);
         }
+        if p.options.asi_safe
+            && self.hashbang.is_none()
+            && self.directives.is_empty()
+            && matches!(self.body.first(), Some(Statement::ExpressionStatement(_)))
+        {
+            p.print_semicolon();
+        }
         for stmt in &self.body {
             stmt.print(p, ctx);
             p.print_semicolon_if_needed();
@@ -1346,11 +1353,15 @@ impl<'a> GenExpr for CallExpression<'a> {
     fn gen_expr(&self, p: &mut Codegen, precedence: Precedence, ctx: Context) {
         let is_export_default = p.start_of_default_export == p.code_len();
         let mut wrap = precedence >= Precedence::New || ctx.intersects(Context::FORBID_CALL);
-        if precedence >= Precedence::Postfix && p.has_annotation_comment(self.span.start) {
+        if precedence >= Precedence::Postfix
+            && ((self.pure && p.options.print_annotation_comments())
+                || p.has_annotation_comment(self.span.start))
+        {
             wrap = true;
         }
 
         p.wrap(wrap, |p| {
+            p.print_pure_annotation(self.pure, self.span.start);
             p.print_annotation_comments(self.span.start);
             if is_export_default {
                 p.start_of_default_export = p.code_len();
@@ -2057,23 +2068,29 @@ impl<'a> GenExpr for ChainExpression<'a> {
 impl<'a> GenExpr for NewExpression<'a> {
     fn gen_expr(&self, p: &mut Codegen, precedence: Precedence, ctx: Context) {
         let mut wrap = precedence >= self.precedence();
-        if precedence >= Precedence::Postfix && p.has_annotation_comment(self.span.start) {
+        if precedence >= Precedence::Postfix
+            && ((self.pure && p.options.print_annotation_comments())
+                || p.has_annotation_comment(self.span.start))
+        {
             wrap = true;
         }
         p.wrap(wrap, |p| {
+            p.print_pure_annotation(self.pure, self.span.start);
             p.print_annotation_comments(self.span.start);
             p.print_space_before_identifier();
             p.add_source_mapping(self.span.start);
             p.print_str("new ");
             self.callee.print_expr(p, Precedence::New, Context::FORBID_CALL);
             p.print_ascii_byte(b'(');
-            let has_comment = p.has_comment(self.span.end - 1)
+            let has_comment_before_right_paren =
+                self.span.end > 0 && p.has_comment(self.span.end - 1);
+            let has_comment = has_comment_before_right_paren
                 || self.arguments.iter().any(|item| p.has_comment(item.span().start));
             if has_comment {
                 p.indent();
                 p.print_list_with_comments(&self.arguments, ctx);
                 // Handle `/* comment */);`
-                if !p.print_expr_comments(self.span.end - 1) {
+                if !has_comment_before_right_paren || !p.print_expr_comments(self.span.end - 1) {
                     p.print_soft_newline();
                 }
                 p.dedent();
@@ -572,10 +572,17 @@ impl<'a> Codegen<'a> {
         }
     }
 
-    // `get_minified_number` from terser
-    // https://github.com/terser/terser/blob/c5315c3fd6321d6b2e076af35a70ef532f498505/lib/output.js#L2418
-    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss, clippy::cast_possible_wrap)]
-    fn get_minified_number(num: f64) -> String {
+    /// Shortest representation of a non-negative finite number, e.g. `1e6` for `1000000`.
+    ///
+    /// `get_minified_number` from terser
+    /// <https://github.com/terser/terser/blob/c5315c3fd6321d6b2e076af35a70ef532f498505/lib/output.js#L2418>
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss,
+        clippy::cast_possible_wrap,
+        clippy::missing_panics_doc
+    )]
+    pub fn get_minified_number(num: f64) -> String {
         use cow_utils::CowUtils;
         use oxc_syntax::number::ToJsString;
         if num < 1000.0 && num.fract() == 0.0 {
@@ -75,6 +75,15 @@ pub struct CodegenOptions {
     /// By default, the source map path is the same as the input source code
     /// (with a `.map` extension).
     pub source_map_path: Option<PathBuf>,
+
+    /// Make the code safe to concatenate after other code which may not end with `;`.
+    ///
+    /// A leading `;` is printed if the program starts with an expression statement,
+    /// which may start with `(`, `[` or `` ` `` and continue the previous statement.
+    /// The last statement always ends with `;`, so it can't continue into the following code.
+    ///
+    /// Default is `false`.
+    pub asi_safe: bool,
 }
 
 impl Default for CodegenOptions {
@@ -86,6 +95,7 @@ impl Default for CodegenOptions {
             annotation_comments: false,
             legal_comments: LegalComment::default(),
             source_map_path: None,
+            asi_safe: false,
         }
     }
 }
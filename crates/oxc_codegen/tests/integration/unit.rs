@@ -1,4 +1,9 @@
-use crate::tester::{test, test_minify, test_without_source};
+use oxc_codegen::CodegenOptions;
+
+use crate::{
+    codegen, codegen_options,
+    tester::{test, test_minify, test_without_source},
+};
 
 #[test]
 fn module_decl() {
@@ -294,3 +299,26 @@ fn in_expr_in_sequence_in_for_loop_init() {
         "for ((\"hidden\" in a) && (m = a.hidden), r = 0; s > r; r++) {}\n",
     );
 }
+
+#[test]
+fn asi_safe() {
+    let options = CodegenOptions { asi_safe: true, ..CodegenOptions::default() };
+    let minify = CodegenOptions { asi_safe: true, minify: true, ..CodegenOptions::default() };
+
+    // Program ending in a bare expression statement is terminated
+    assert_eq!(codegen_options("const a = 1\nfoo()", &options).code, "const a = 1;\nfoo();\n");
+    assert_eq!(codegen_options("const a = 1\nfoo()", &minify).code, "const a=1;foo();");
+
+    // Program starting with an expression statement is guarded
+    assert_eq!(codegen_options("(function() {})()", &minify).code, ";(function(){})();");
+    assert_eq!(codegen_options("[a, b] = c", &minify).code, ";[a,b]=c;");
+    assert_eq!(codegen_options("`a`.length", &options).code, ";`a`.length;\n");
+    assert_eq!(codegen_options("foo()", &minify).code, ";foo();");
+
+    // Other statements and directives can't continue the previous statement
+    assert_eq!(codegen_options("let a = [b]", &minify).code, "let a=[b];");
+    assert_eq!(codegen_options("'use strict'; (a)", &minify).code, "'use strict';a;");
+
+    // Disabled by default
+    assert_eq!(codegen("(function() {})()"), "(function() {})();\n");
+}
@@ -227,6 +227,9 @@ pub trait ConstantEvaluation<'a> {
             | BinaryOperator::Remainder
             | BinaryOperator::Multiplication
             | BinaryOperator::Exponential => {
+                if left.may_have_side_effects() || right.may_have_side_effects() {
+                    return None;
+                }
                 let lval = self.eval_to_number(left)?;
                 let rval = self.eval_to_number(right)?;
                 let val = match e.operator {
@@ -240,6 +243,13 @@ pub trait ConstantEvaluation<'a> {
                         }
                     }
                     BinaryOperator::Multiplication => lval * rval,
+                    // `1 ** NaN` and `1 ** Infinity` are `NaN`, unlike `powf`
+                    #[expect(clippy::float_cmp)]
+                    BinaryOperator::Exponential
+                        if rval.is_nan() || (lval.abs() == 1.0 && rval.is_infinite()) =>
+                    {
+                        f64::NAN
+                    }
                     BinaryOperator::Exponential => lval.powf(rval),
                     _ => unreachable!(),
                 };
@@ -335,12 +345,19 @@ pub trait ConstantEvaluation<'a> {
                     {
                         "object"
                     }
-                    Expression::FunctionExpression(_) => "function",
+                    Expression::FunctionExpression(_) | Expression::ArrowFunctionExpression(_) => {
+                        "function"
+                    }
                     Expression::StringLiteral(_) => "string",
+                    Expression::TemplateLiteral(lit) if lit.is_no_substitution_template() => {
+                        "string"
+                    }
                     Expression::NumericLiteral(_) => "number",
                     Expression::BooleanLiteral(_) => "boolean",
-                    Expression::NullLiteral(_) => "object",
-                    Expression::UnaryExpression(e) if e.operator == UnaryOperator::Void => {
+                    Expression::NullLiteral(_) | Expression::RegExpLiteral(_) => "object",
+                    Expression::UnaryExpression(e)
+                        if e.operator == UnaryOperator::Void && !e.may_have_side_effects() =>
+                    {
                         "undefined"
                     }
                     Expression::BigIntLiteral(_) => "bigint",
@@ -349,6 +366,16 @@ pub trait ConstantEvaluation<'a> {
                         "NaN" | "Infinity" if self.is_global_reference(ident) => "number",
                         _ => return None,
                     },
+                    // Fall back to type inference for side effect free operands, e.g. `typeof !x`.
+                    argument if !argument.may_have_side_effects() => {
+                        match ValueType::from(argument) {
+                            ValueType::String => "string",
+                            ValueType::Boolean => "boolean",
+                            // `Number` is assumed for `-x`, which may be a BigInt at runtime,
+                            // and an `Object` may also be a function.
+                            _ => return None,
+                        }
+                    }
                     _ => return None,
                 };
                 Some(ConstantValue::String(Cow::Borrowed(s)))
@@ -1,14 +1,19 @@
 use itertools::Itertools;
-use oxc_ast::ast::Program;
+use rustc_hash::FxHashSet;
+
+use oxc_ast::{ast::Program, AstKind};
 use oxc_index::{index_vec, Idx, IndexVec};
-use oxc_semantic::{ReferenceId, ScopeTree, SemanticBuilder, SymbolId, SymbolTable};
+use oxc_semantic::{ReferenceId, Semantic, SemanticBuilder, SymbolId, SymbolTable};
 use oxc_span::CompactStr;
 
 type Slot = usize;
 
-#[derive(Default)]
+#[derive(Debug, Default, Clone)]
 pub struct MangleOptions {
     pub debug: bool,
+
+    /// Names which are not mangled, and not used as mangled names.
+    pub reserved: Vec<String>,
 }
 
 /// # Name Mangler / Symbol Minification
@@ -52,6 +57,14 @@ pub struct MangleOptions {
 ///     }
 /// }
 /// ```
+///
+/// Symbols whose names are observable are not renamed:
+/// - Top-level symbols in scripts, which are global variables.
+/// - Top-level symbols in modules declared with `export`, e.g. `export const a = 1`.
+/// - Symbols visible from a direct `eval` call or a `with` statement, which are resolved at runtime.
+/// - `arguments`, `exports`, and names listed in [`MangleOptions::reserved`].
+///
+/// Labels and property names are not symbols, so they are not renamed either.
 #[derive(Default)]
 pub struct Mangler {
     symbol_table: SymbolTable,
@@ -83,6 +96,7 @@ impl Mangler {
     #[must_use]
     pub fn build<'a>(mut self, program: &'a Program<'a>) -> Mangler {
         let semantic = SemanticBuilder::new().build(program).semantic;
+        let kept_symbols = self.collect_kept_symbols(&semantic, program.source_type.is_module());
 
         // Mangle the symbol table by computing slots from the scope tree.
         // A slot is the occurrence index of a binding identifier inside a scope.
@@ -123,26 +137,36 @@ impl Mangler {
             }
         }
 
-        let frequencies =
-            Self::tally_slot_frequencies(&symbol_table, &scope_tree, total_number_of_slots, &slots);
+        let frequencies = Self::tally_slot_frequencies(
+            &symbol_table,
+            total_number_of_slots,
+            &slots,
+            &kept_symbols,
+        );
 
         let root_unresolved_references = scope_tree.root_unresolved_references();
-        let root_bindings = scope_tree.get_bindings(scope_tree.root_scope_id());
+        // Names of symbols which are not renamed
+        let kept_names = kept_symbols
+            .iter()
+            .map(|&symbol_id| CompactStr::from(symbol_table.get_name(symbol_id)))
+            .chain(self.options.reserved.iter().map(|name| CompactStr::from(name.as_str())))
+            .collect::<FxHashSet<_>>();
 
-        let mut reserved_names = Vec::with_capacity(total_number_of_slots);
+        // Only slots with symbols to rename need names
+        let mut reserved_names = Vec::with_capacity(frequencies.len());
 
         let generate_name = if self.options.debug { debug_name } else { base54 };
         let mut count = 0;
-        for _ in 0..total_number_of_slots {
+        for _ in 0..frequencies.len() {
             let name = loop {
                 let name = generate_name(count);
                 count += 1;
-                // Do not mangle keywords and unresolved references
+                // Do not mangle to keywords, unresolved references and names which are kept
                 let n = name.as_str();
                 if !is_keyword(n)
                     && !is_special_name(n)
                     && !root_unresolved_references.contains_key(n)
-                    && !root_bindings.contains_key(n)
+                    && !kept_names.contains(&name)
                 {
                     break name;
                 }
@@ -200,19 +224,62 @@ impl Mangler {
         self
     }
 
+    /// Collect symbols which must keep their names.
+    fn collect_kept_symbols(
+        &self,
+        semantic: &Semantic<'_>,
+        is_module: bool,
+    ) -> FxHashSet<SymbolId> {
+        let symbol_table = semantic.symbols();
+        let scope_tree = semantic.scopes();
+        let nodes = semantic.nodes();
+
+        // Scopes visible from a direct `eval` call or a `with` statement
+        let mut dynamic_scopes = FxHashSet::default();
+        for node in nodes.iter() {
+            let is_dynamic = match node.kind() {
+                AstKind::CallExpression(call) => call.callee.is_specific_id("eval"),
+                AstKind::WithStatement(_) => true,
+                _ => false,
+            };
+            if is_dynamic {
+                dynamic_scopes.extend(scope_tree.ancestors(node.scope_id()));
+            }
+        }
+
+        let root_scope_id = scope_tree.root_scope_id();
+        symbol_table
+            .symbol_ids()
+            .filter(|&symbol_id| {
+                let name = symbol_table.get_name(symbol_id);
+                let scope_id = symbol_table.get_scope_id(symbol_id);
+                is_special_name(name)
+                    || self.options.reserved.iter().any(|reserved| reserved == name)
+                    || dynamic_scopes.contains(&scope_id)
+                    || (scope_id == root_scope_id
+                        && (!is_module || Self::is_exported(symbol_id, semantic)))
+            })
+            .collect()
+    }
+
+    /// `export const a = 1`, `export function a() {}`, `export class a {}`
+    fn is_exported(symbol_id: SymbolId, semantic: &Semantic<'_>) -> bool {
+        let declaration = semantic.symbols().get_declaration(symbol_id);
+        semantic
+            .nodes()
+            .ancestor_kinds(declaration)
+            .any(|kind| matches!(kind, AstKind::ExportNamedDeclaration(_)))
+    }
+
     fn tally_slot_frequencies(
         symbol_table: &SymbolTable,
-        scope_tree: &ScopeTree,
         total_number_of_slots: usize,
         slots: &IndexVec<SymbolId, Slot>,
+        kept_symbols: &FxHashSet<SymbolId>,
     ) -> Vec<SlotFrequency> {
-        let root_scope_id = scope_tree.root_scope_id();
         let mut frequencies = vec![SlotFrequency::default(); total_number_of_slots];
         for (symbol_id, slot) in slots.iter_enumerated() {
-            if symbol_table.get_scope_id(symbol_id) == root_scope_id {
-                continue;
-            }
-            if is_special_name(symbol_table.get_name(symbol_id)) {
+            if kept_symbols.contains(&symbol_id) {
                 continue;
             }
             let index = *slot;
@@ -221,6 +288,7 @@ impl Mangler {
                 symbol_table.get_resolved_reference_ids(symbol_id).len();
             frequencies[index].symbol_ids.push(symbol_id);
         }
+        frequencies.retain(|x| !x.symbol_ids.is_empty());
         frequencies.sort_unstable_by_key(|x| std::cmp::Reverse(x.frequency));
         frequencies
     }
@@ -0,0 +1,205 @@
+use oxc_allocator::Vec;
+use oxc_ast::ast::*;
+use oxc_ecmascript::constant_evaluation::ValueType;
+use oxc_semantic::SymbolId;
+use oxc_span::GetSpan;
+use oxc_syntax::operator::{AssignmentOperator, BinaryOperator};
+use oxc_traverse::{Ancestor, Traverse, TraverseCtx};
+
+use crate::CompressorPass;
+
+/// Collapse string assignments.
+///
+/// `s += "a"; s += b; s += "c"` => `s += "a" + b + "c"`
+///
+/// Only applies to a local variable `s`, so reading and writing it can't be observed.
+/// The first value must be a string, so `+` in the joined value is string concatenation,
+/// which is associative. Values after the first must not have side effects or read `s`,
+/// as they are now evaluated before `s` is updated.
+pub struct CollapseStringAssignments {
+    changed: bool,
+}
+
+impl<'a> CompressorPass<'a> for CollapseStringAssignments {
+    fn changed(&self) -> bool {
+        self.changed
+    }
+
+    fn build(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.changed = false;
+        oxc_traverse::walk_program(self, program, ctx);
+    }
+}
+
+impl<'a> Traverse<'a> for CollapseStringAssignments {
+    fn enter_statements(&mut self, stmts: &mut Vec<'a, Statement<'a>>, ctx: &mut TraverseCtx<'a>) {
+        if stmts.len() < 2 || ctx.ancestors().any(Ancestor::is_with_statement) {
+            return;
+        }
+        self.collapse_string_assignments(stmts, ctx);
+    }
+}
+
+impl<'a> CollapseStringAssignments {
+    pub fn new() -> Self {
+        Self { changed: false }
+    }
+
+    fn collapse_string_assignments(
+        &mut self,
+        stmts: &mut Vec<'a, Statement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let mut new_stmts = ctx.ast.vec_with_capacity(stmts.len());
+        // Symbol of `s` in the last statement of `new_stmts`, if it is `s += "..."`
+        let mut target = None;
+        for mut stmt in stmts.drain(..) {
+            if let Some(symbol_id) = target {
+                if let Some(value) = Self::take_joinable_value(&mut stmt, symbol_id, ctx) {
+                    let Some(Statement::ExpressionStatement(last)) = new_stmts.last_mut() else {
+                        unreachable!()
+                    };
+                    let Expression::AssignmentExpression(assign) = &mut last.expression else {
+                        unreachable!()
+                    };
+                    let left = ctx.ast.move_expression(&mut assign.right);
+                    assign.right = ctx.ast.expression_binary(
+                        left.span(),
+                        left,
+                        BinaryOperator::Addition,
+                        value,
+                    );
+                    self.changed = true;
+                    continue;
+                }
+            }
+            target = Self::get_string_assignment_target(&stmt, ctx);
+            new_stmts.push(stmt);
+        }
+        *stmts = new_stmts;
+    }
+
+    /// Get symbol of `s` in `s += value`, if `s` is a local variable and `value` is a string.
+    fn get_string_assignment_target(
+        stmt: &Statement<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> Option<SymbolId> {
+        let (symbol_id, value) = Self::get_add_assignment(stmt, ctx)?;
+        let is_string =
+            matches!(value, Expression::TemplateLiteral(_)) || ValueType::from(value).is_string();
+        is_string.then_some(symbol_id)
+    }
+
+    /// Take `value` out of `s += value`, if it can be joined with the previous assignment to `s`.
+    fn take_joinable_value(
+        stmt: &mut Statement<'a>,
+        symbol_id: SymbolId,
+        ctx: &TraverseCtx<'a>,
+    ) -> Option<Expression<'a>> {
+        let (target_symbol_id, value) = Self::get_add_assignment(stmt, ctx)?;
+        if target_symbol_id != symbol_id || !Self::is_joinable_value(value, symbol_id, ctx) {
+            return None;
+        }
+        let Statement::ExpressionStatement(stmt) = stmt else { unreachable!() };
+        let Expression::AssignmentExpression(assign) = &mut stmt.expression else { unreachable!() };
+        Some(ctx.ast.move_expression(&mut assign.right))
+    }
+
+    /// `s += value`, where `s` is a local variable which is not a constant.
+    fn get_add_assignment<'s>(
+        stmt: &'s Statement<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> Option<(SymbolId, &'s Expression<'a>)> {
+        let Statement::ExpressionStatement(stmt) = stmt else { return None };
+        let Expression::AssignmentExpression(assign) = &stmt.expression else { return None };
+        if assign.operator != AssignmentOperator::Addition {
+            return None;
+        }
+        let AssignmentTarget::AssignmentTargetIdentifier(ident) = &assign.left else {
+            return None;
+        };
+        let symbol_id = ctx.symbols().get_reference(ident.reference_id()).symbol_id()?;
+        if ctx.symbols().get_flags(symbol_id).is_const_variable() {
+            return None;
+        }
+        Some((symbol_id, &assign.right))
+    }
+
+    /// Check if `value` has no side effects, and does not read `s`.
+    fn is_joinable_value(
+        value: &Expression<'a>,
+        symbol_id: SymbolId,
+        ctx: &TraverseCtx<'a>,
+    ) -> bool {
+        match value {
+            Expression::StringLiteral(_)
+            | Expression::NumericLiteral(_)
+            | Expression::BooleanLiteral(_)
+            | Expression::NullLiteral(_) => true,
+            Expression::TemplateLiteral(template) => template
+                .expressions
+                .iter()
+                .all(|expr| Self::is_joinable_value(expr, symbol_id, ctx)),
+            // A reference to another local variable.
+            // Global variables may be getters, and `undefined` etc. are not worth joining.
+            Expression::Identifier(ident) => ctx
+                .symbols()
+                .get_reference(ident.reference_id())
+                .symbol_id()
+                .is_some_and(|id| id != symbol_id),
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+
+    use crate::tester;
+
+    fn test(source_text: &str, expected: &str) {
+        let allocator = Allocator::default();
+        let mut pass = super::CollapseStringAssignments::new();
+        tester::test(&allocator, source_text, expected, &mut pass);
+    }
+
+    fn test_same(source_text: &str) {
+        test(source_text, source_text);
+    }
+
+    #[test]
+    fn local_variable() {
+        test("let s = ''; s += 'a'; s += 'b';", "let s = ''; s += 'a' + 'b';");
+        test("let s, b; s += 'a'; s += b; s += 'c';", "let s, b; s += 'a' + b + 'c';");
+        test(
+            "let s, b; s += `a${b}`; s += 1; s += `c${b}`;",
+            "let s, b; s += `a${b}` + 1 + `c${b}`;",
+        );
+        test(
+            "function f(s, b) { s += 'a'; s += b; foo(); s += 'c'; s += 'd'; return s; }",
+            "function f(s, b) { s += 'a' + b; foo(); s += 'c' + 'd'; return s; }",
+        );
+        test("let s, t; s += 'a'; t += 'b'; t += 'c';", "let s, t; s += 'a'; t += 'b' + 'c';");
+    }
+
+    #[test]
+    fn bail_out() {
+        // Global variable may be a getter or setter
+        test_same("s += 'a'; s += 'b';");
+        // Member target may be a getter or setter
+        test_same("let s = {}; s.x += 'a'; s.x += 'b';");
+        test_same("let s = {}; s[0] += 'a'; s[0] += 'b';");
+        // First value is not known to be a string, so `+` may be addition
+        test_same("let s, b; s += b; s += 'a';");
+        test_same("let s; s += 1; s += 'a';");
+        // Value has side effects, or reads `s`
+        test_same("let s; s += 'a'; s += foo();");
+        test_same("let s; s += 'a'; s += s;");
+        test_same("let s; s += 'a'; s += `${s}`;");
+        test_same("let s; s += 'a'; s += b;");
+        // Not `+=`
+        test_same("let s; s += 'a'; s = 'b';");
+        test_same("let s; s += 'a'; s -= 'b';");
+    }
+}
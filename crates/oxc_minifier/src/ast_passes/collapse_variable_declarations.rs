@@ -1,12 +1,17 @@
 use oxc_allocator::Vec;
 use oxc_ast::ast::*;
+use oxc_ecmascript::side_effects::MayHaveSideEffects;
+use oxc_syntax::operator::AssignmentOperator;
 use oxc_traverse::{Traverse, TraverseCtx};
 
 use crate::CompressorPass;
 
 /// Collapse variable declarations.
 ///
-/// `var a; var b = 1; var c = 2` => `var a, b = 1; c = 2`
+/// `var a; var b = 1; var c = 2` => `var a, b = 1, c = 2`
+///
+/// An assignment to the last declared variable is moved into its declaration:
+/// `var a; a = 1` => `var a = 1`
 /// <https://github.com/google/closure-compiler/blob/master/src/com/google/javascript/jscomp/CollapseVariableDeclarations.java>
 pub struct CollapseVariableDeclarations {
     changed: bool,
@@ -42,69 +47,73 @@ impl<'a> CollapseVariableDeclarations {
             .is_some_and(Expression::is_require_call)
     }
 
-    fn is_valid_var_decl(
-        stmt: &Statement,
-        kind: Option<VariableDeclarationKind>,
-    ) -> Option<VariableDeclarationKind> {
-        if let Statement::VariableDeclaration(cur_decl) = stmt {
-            let is_not_require_call = !Self::is_require_call(cur_decl);
-            if kind.map_or(true, |k| cur_decl.kind == k) && is_not_require_call {
-                return Some(cur_decl.kind);
-            }
-        }
-        None
-    }
-
     fn join_vars(&mut self, stmts: &mut Vec<'a, Statement<'a>>, ctx: &mut TraverseCtx<'a>) {
         if stmts.len() < 2 {
             return;
         }
 
-        let mut prev: usize = stmts.len() - 1;
-        let mut items = std::vec::Vec::<usize>::new();
-
-        while prev > 0 {
-            prev -= 1;
-
-            let cur: usize = prev + 1;
-
-            if !Self::is_valid_var_decl(&stmts[cur], None)
-                .is_some_and(|kind| Self::is_valid_var_decl(&stmts[prev], Some(kind)).is_some())
-            {
-                continue;
-            }
-            let Some(Statement::VariableDeclaration(cur_decl)) = stmts.get_mut(cur) else {
-                continue;
-            };
-
-            let mut decls = ctx.ast.move_vec(&mut cur_decl.declarations);
-            if let Some(Statement::VariableDeclaration(prev_decl)) = stmts.get_mut(prev) {
-                items.push(cur);
-                prev_decl.declarations.append(&mut decls);
-            }
-        }
-
-        if items.is_empty() {
-            return;
-        }
-
-        let mut item_iter = items.iter().rev();
-        let mut next_item = item_iter.next();
-
-        let mut new_stmts = ctx.ast.vec_with_capacity(stmts.len() - items.len());
-
-        for (index, stmt) in stmts.drain(..).enumerate() {
-            if let Some(item) = next_item {
-                if *item == index {
-                    next_item = item_iter.next();
+        let mut new_stmts: Vec<'a, Statement<'a>> = ctx.ast.vec_with_capacity(stmts.len());
+        for mut stmt in stmts.drain(..) {
+            if let Some(Statement::VariableDeclaration(prev_decl)) = new_stmts.last_mut() {
+                // `var a; var b;` -> `var a, b;`
+                if let Statement::VariableDeclaration(cur_decl) = &mut stmt {
+                    if cur_decl.kind == prev_decl.kind
+                        && !Self::is_require_call(prev_decl)
+                        && !Self::is_require_call(cur_decl)
+                    {
+                        prev_decl.declarations.append(&mut cur_decl.declarations);
+                        self.changed = true;
+                        continue;
+                    }
+                }
+                // `var a; a = 1;` -> `var a = 1;`
+                if Self::fold_assignment(prev_decl, &mut stmt, ctx) {
+                    self.changed = true;
                     continue;
                 }
             }
             new_stmts.push(stmt);
         }
-
         *stmts = new_stmts;
-        self.changed = true;
+    }
+
+    /// Move the value of `a = value` into the last declarator of `var a`, if it has no initializer.
+    ///
+    /// For `let`, `value` must not have side effects, as it may read `a`, which would throw
+    /// before `a` is initialized.
+    ///
+    /// Returns `true` if the assignment was moved.
+    fn fold_assignment(
+        decl: &mut VariableDeclaration<'a>,
+        stmt: &mut Statement<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> bool {
+        let Statement::ExpressionStatement(expr_stmt) = stmt else { return false };
+        let Expression::AssignmentExpression(assign) = &mut expr_stmt.expression else {
+            return false;
+        };
+        let AssignmentTarget::AssignmentTargetIdentifier(ident) = &assign.left else {
+            return false;
+        };
+        let Some(declarator) = decl.declarations.last_mut() else { return false };
+        if assign.operator != AssignmentOperator::Assign || declarator.init.is_some() {
+            return false;
+        }
+        let BindingPatternKind::BindingIdentifier(binding) = &declarator.id.kind else {
+            return false;
+        };
+        let symbol_id = ctx.symbols().get_reference(ident.reference_id()).symbol_id();
+        if symbol_id.is_none() || symbol_id != binding.symbol_id.get() {
+            return false;
+        }
+        match decl.kind {
+            VariableDeclarationKind::Var => {}
+            VariableDeclarationKind::Let if !assign.right.may_have_side_effects() => {}
+            _ => return false,
+        }
+        ctx.delete_reference_for_identifier(ident);
+        declarator.init = Some(ctx.ast.move_expression(&mut assign.right));
+        true
     }
 }
 
@@ -185,12 +194,46 @@ mod test {
 
     #[test]
     fn test_issue397() {
-        test_same("var x; x = 5; var z = 7;");
+        test("var x; x = 5; var z = 7;", "var x = 5, z = 7;");
         test("var x; var y = 3; x = 5;", "var x, y = 3; x = 5;");
         test("var a = 1; var x; var y = 3; x = 5;", "var a = 1, x, y = 3; x = 5;");
         test("var x; var y = 3; x = 5; var z = 7;", "var x, y = 3; x = 5; var z = 7;");
     }
 
+    #[test]
+    fn test_fold_assignment() {
+        test("var x; x = 5;", "var x = 5;");
+        test("var x; x = foo();", "var x = foo();");
+        test("var x; x = x + 1;", "var x = x + 1;");
+        test("var a = 1, x; x = 2; var b;", "var a = 1, x = 2, b;");
+        test("function f() { var x; x = 1; return x; }", "function f() { var x = 1; return x; }");
+        test("let x; x = 5;", "let x = 5;");
+        test("let x; x = `a`;", "let x = `a`;");
+
+        // Only the first assignment
+        test("var x; x = 1; x = 2;", "var x = 1; x = 2;");
+        // Not the last declared variable, or already initialized
+        test_same("var x, y; x = 1;");
+        test_same("var x = 1; x = 2;");
+        // Other variables, or other operators
+        test_same("var x; y = 1;");
+        test_same("var x; x += 1;");
+        test_same("var x; x.y = 1;");
+        test_same("var { x } = {}; x = 1;");
+        // `let` value may read the variable before it is initialized
+        test_same("let x; x = x + 1;");
+        test_same("let x; x = foo();");
+        // `const` must be initialized
+        test_same("const x = 1; x = 2;");
+    }
+
+    #[test]
+    fn test_separated_by_side_effects() {
+        test_same("var a = 1; foo(); var b = 2;");
+        test_same("var a; foo(); a = 1;");
+        test("var a; a = 1; foo(); var b; b = 2;", "var a = 1; foo(); var b = 2;");
+    }
+
     #[test]
     fn test_arguments_assignment() {
         test_same("function f() {arguments = 1;}");
@@ -1,3 +1,4 @@
+mod collapse_string_assignments;
 mod collapse_variable_declarations;
 mod exploit_assigns;
 mod peephole_fold_constants;
@@ -8,6 +9,7 @@ mod peephole_substitute_alternate_syntax;
 mod remove_syntax;
 mod statement_fusion;
 
+pub use collapse_string_assignments::CollapseStringAssignments;
 pub use collapse_variable_declarations::CollapseVariableDeclarations;
 pub use exploit_assigns::ExploitAssigns;
 pub use peephole_fold_constants::PeepholeFoldConstants;
@@ -1,6 +1,6 @@
 use oxc_ast::ast::*;
 use oxc_ecmascript::{
-    constant_evaluation::{ConstantEvaluation, ValueType},
+    constant_evaluation::{ConstantEvaluation, ConstantValue, ValueType},
     side_effects::MayHaveSideEffects,
 };
 use oxc_span::{GetSpan, SPAN};
@@ -10,12 +10,13 @@ use oxc_syntax::{
 };
 use oxc_traverse::{Ancestor, Traverse, TraverseCtx};
 
-use crate::{node_util::Ctx, CompressorPass};
+use crate::{node_util::Ctx, CompressOptions, CompressorPass};
 
 /// Constant Folding
 ///
 /// <https://github.com/google/closure-compiler/blob/master/src/com/google/javascript/jscomp/PeepholeFoldConstants.java>
 pub struct PeepholeFoldConstants {
+    options: CompressOptions,
     changed: bool,
 }
 
@@ -42,7 +43,15 @@ impl<'a> Traverse<'a> for PeepholeFoldConstants {
             // return tryFoldSpread(subtree);
             Expression::ArrayExpression(e) => Self::try_flatten_array_expression(e, ctx),
             Expression::ObjectExpression(e) => Self::try_flatten_object_expression(e, ctx),
-            Expression::BinaryExpression(e) => Self::try_fold_binary_expression(e, ctx),
+            Expression::BinaryExpression(e) => {
+                Self::try_fold_binary_expression(e, ctx).or_else(|| {
+                    if self.options.unsafe_math {
+                        Self::try_reassociate_arithmetic(e, ctx)
+                    } else {
+                        None
+                    }
+                })
+            }
             #[allow(clippy::float_cmp)]
             Expression::UnaryExpression(e) => {
                 match e.operator {
@@ -68,8 +77,8 @@ impl<'a> Traverse<'a> for PeepholeFoldConstants {
 }
 
 impl<'a, 'b> PeepholeFoldConstants {
-    pub fn new() -> Self {
-        Self { changed: false }
+    pub fn new(options: CompressOptions) -> Self {
+        Self { options, changed: false }
     }
 
     fn try_fold_useless_object_dot_define_properties_call(
@@ -231,15 +240,15 @@ impl<'a, 'b> PeepholeFoldConstants {
         match e.operator {
             BinaryOperator::ShiftLeft
             | BinaryOperator::ShiftRight
-            | BinaryOperator::ShiftRightZeroFill
-            | BinaryOperator::Addition
+            | BinaryOperator::ShiftRightZeroFill => {
+                ctx.eval_binary_expression(e).map(|v| ctx.value_to_expr(e.span, v))
+            }
+            BinaryOperator::Addition
             | BinaryOperator::Subtraction
             | BinaryOperator::Division
             | BinaryOperator::Remainder
             | BinaryOperator::Multiplication
-            | BinaryOperator::Exponential => {
-                ctx.eval_binary_expression(e).map(|v| ctx.value_to_expr(e.span, v))
-            }
+            | BinaryOperator::Exponential => Self::try_fold_arithmetic_op(e, ctx),
             BinaryOperator::BitwiseAnd | BinaryOperator::BitwiseOR | BinaryOperator::BitwiseXOR => {
                 // TODO:
                 // self.try_fold_arithmetic_op(e.span, &e.left, &e.right, ctx)
@@ -254,6 +263,103 @@ impl<'a, 'b> PeepholeFoldConstants {
         }
     }
 
+    /// Fold arithmetic, unless the result is longer than the operands or too large to be exact.
+    ///
+    /// `2 ** 3` -> `8`, but not `3 ** -1` (`0.3333333333333333`) or `0.1 + 0.2` (`0.30000000000000004`).
+    ///
+    /// port from [closure-compiler](https://github.com/google/closure-compiler/blob/09094b551915a6487a980a783831cba58b5739d1/src/com/google/javascript/jscomp/PeepholeFoldConstants.java#L794)
+    fn try_fold_arithmetic_op(
+        e: &BinaryExpression<'a>,
+        ctx: Ctx<'a, 'b>,
+    ) -> Option<Expression<'a>> {
+        /// Numbers after this are not exact, so folding them would lose precision
+        const MAX_FOLD_NUMBER: f64 = 9_007_199_254_740_992.0; // 2^53
+
+        /// Length of `n` as formatted by `String.valueOf(double)` in Java, which closure compiler compares.
+        /// Integers are formatted with `.0`.
+        fn java_number_length(n: f64) -> usize {
+            let len = n.to_js_string().len();
+            if n.fract() == 0.0 && n.abs() < 1e21 {
+                len + 2
+            } else {
+                len
+            }
+        }
+
+        let value = ctx.eval_binary_expression(e)?;
+        if let ConstantValue::Number(result) = value {
+            if result.is_finite() {
+                let (Some(lval), Some(rval)) = (
+                    ctx.get_side_free_number_value(&e.left),
+                    ctx.get_side_free_number_value(&e.right),
+                ) else {
+                    return None;
+                };
+                // Length of the left and right value plus 1 byte for the operator.
+                if java_number_length(result)
+                    > java_number_length(lval) + java_number_length(rval) + 1
+                    || result.abs() > MAX_FOLD_NUMBER
+                {
+                    return None;
+                }
+            }
+        }
+        Some(ctx.value_to_expr(e.span, value))
+    }
+
+    /// Fold adjacent numeric constants of a `+` or `*` chain, when `unsafe_math` is enabled.
+    ///
+    /// `2 * x * 3` -> `x * 6`, `+x + 1 + 2` -> `+x + 3`
+    ///
+    /// Floating point arithmetic is not associative, so this may change the result:
+    /// `+x + 1e16 + 1` is not the same as `+x + 1e16` for `x = 2`.
+    fn try_reassociate_arithmetic(
+        e: &mut BinaryExpression<'a>,
+        ctx: Ctx<'a, 'b>,
+    ) -> Option<Expression<'a>> {
+        let op = e.operator;
+        if !matches!(op, BinaryOperator::Addition | BinaryOperator::Multiplication) {
+            return None;
+        }
+        // `(x op c1) op c2`, `(c1 op x) op c2`, `c2 op (x op c1)` or `c2 op (c1 op x)`
+        let (inner, outer_value, inner_is_left) = match (&mut e.left, &mut e.right) {
+            (Expression::BinaryExpression(inner), Expression::NumericLiteral(lit)) => {
+                (inner, lit.value, true)
+            }
+            (Expression::NumericLiteral(lit), Expression::BinaryExpression(inner)) => {
+                (inner, lit.value, false)
+            }
+            _ => return None,
+        };
+        if inner.operator != op {
+            return None;
+        }
+        let (inner_value, operand_is_left) = match (&inner.left, &inner.right) {
+            (_, Expression::NumericLiteral(lit)) => (lit.value, true),
+            (Expression::NumericLiteral(lit), _) => (lit.value, false),
+            _ => return None,
+        };
+        let operand = if operand_is_left { &mut inner.left } else { &mut inner.right };
+        // `"a" + 1 + 2` is `"a12"`, so the operand must be a number for `+`.
+        // `*` converts any operand to a number, or throws for a BigInt either way.
+        if op == BinaryOperator::Addition && !ValueType::from(&*operand).is_number() {
+            return None;
+        }
+        let value = if op == BinaryOperator::Addition {
+            inner_value + outer_value
+        } else {
+            inner_value * outer_value
+        };
+        let operand = ctx.ast.move_expression(operand);
+        let constant = ctx.value_to_expr(SPAN, ConstantValue::Number(value));
+        let (left, right) = if inner_is_left && operand_is_left {
+            (operand, constant)
+        } else {
+            (constant, operand)
+        };
+        Some(ctx.ast.expression_binary(e.span, left, op, right))
+    }
+
     fn try_fold_comparison(e: &BinaryExpression<'a>, ctx: Ctx<'a, 'b>) -> Option<Expression<'a>> {
         let left = &e.left;
         let right = &e.right;
@@ -460,17 +566,17 @@ mod test {
     static MAX_SAFE_INT: i64 = 9_007_199_254_740_991_i64;
     static NEG_MAX_SAFE_INT: i64 = -9_007_199_254_740_991_i64;
 
-    use crate::tester;
+    use crate::{tester, CompressOptions};
 
     fn test(source_text: &str, expected: &str) {
         let allocator = Allocator::default();
-        let mut pass = super::PeepholeFoldConstants::new();
+        let mut pass = super::PeepholeFoldConstants::new(CompressOptions::default());
         tester::test(&allocator, source_text, expected, &mut pass);
     }
 
     fn test_nospace(source_text: &str, expected: &str) {
         let allocator = Allocator::default();
-        let mut pass = super::PeepholeFoldConstants::new();
+        let mut pass = super::PeepholeFoldConstants::new(CompressOptions::default());
         tester::test_impl(&allocator, source_text, expected, &mut pass, true);
     }
 
@@ -924,9 +1030,22 @@ mod test {
         test("x = typeof [1,[]]", "x = \"object\"");
         test("x = typeof {}", "x = \"object\"");
         test("x = typeof function() {}", "x = 'function'");
+        test("x = typeof (() => {})", "x = 'function'");
+        test("x = typeof 1n", "x = 'bigint'");
+        test("x = typeof /a/", "x = 'object'");
+        test("x = typeof `foo`", "x = 'string'");
+        test("x = typeof !this", "x = 'boolean'");
+        test("x = typeof ('a' + this)", "x = 'string'");
 
         test_same("x = typeof[1,[foo()]]");
         test_same("x = typeof{bathwater:baby()}");
+        test_same("x = typeof Symbol()");
+        test_same("x = typeof void foo()");
+        test_same("x = typeof `${foo()}`");
+        test_same("x = typeof !foo()");
+        test_same("x = typeof ('a' + foo())");
+        test_same("x = typeof -a");
+        test_same("x = typeof class {}");
     }
 
     #[test]
@@ -1181,14 +1300,28 @@ mod test {
 
         test("x = 2 ** 3", "x = 8");
         test("x = 2 ** -3", "x = 0.125");
-        // FIXME
-        // test_same("x = 2 ** 55"); // backs off folding because 2 ** 55 is too large
-        // test_same("x = 3 ** -1"); // backs off because 3**-1 is shorter than 0.3333333333333333
+        test_same("x = 2 ** 55"); // backs off folding because 2 ** 55 is too large
+        test_same("x = 3 ** -1"); // backs off because 3**-1 is shorter than 0.3333333333333333
+        test("x = 2 ** 10", "x = 1024");
+        test("x = 1 ** NaN", "x = NaN");
+        test("x = 1 ** Infinity", "x = NaN");
+        test("x = (-1) ** -Infinity", "x = NaN");
+        test("x = NaN ** 0", "x = 1");
 
         test("x = 0 / 0", "x = NaN");
         test("x = 0 % 0", "x = NaN");
         test("x = (-1) ** 0.5", "x = NaN");
 
+        // Floating point results are only folded if they are not longer
+        test("x = 0.5 + 0.25", "x = 0.75");
+        test_same("x = 0.1 + 0.2");
+        test_same("x = 1 / 3");
+        test_same("x = 4294967296 * 4294967296");
+
+        // Operands with side effects are not removed
+        test_same("x = void foo() * 0");
+        test_same("x = 1 - void foo()");
+
         test_nospace("1n+ +1n", "1n + +1n");
         test_nospace("1n- -1n", "1n - -1n");
         test_nospace("a- -b", "a - -b");
@@ -1205,6 +1338,39 @@ mod test {
         // test("x = y + (z * 24 * 60 * 60 * 1000)", "x = y + z * 864E5");
     }
 
+    #[test]
+    fn test_fold_arithmetic_unsafe_math() {
+        let test = |source_text: &str, expected: &str| {
+            let allocator = Allocator::default();
+            let options = CompressOptions { unsafe_math: true, ..CompressOptions::default() };
+            let mut pass = super::PeepholeFoldConstants::new(options);
+            tester::test(&allocator, source_text, expected, &mut pass);
+        };
+        let test_same = |source_text: &str| test(source_text, source_text);
+
+        test("x = y * 2.25 * 3", "x = y * 6.75");
+        test("x = 2 * y * 3", "x = 6 * y");
+        test("x = 2 * (y * 3)", "x = 6 * y");
+        test("x = y * 2 * 3 * 4", "x = y * 24");
+        test("x = +y + 1 + 2", "x = +y + 3");
+        test("x = 1 + (-y + 2)", "x = 3 + -y");
+        // Reassociation changes the result, e.g. for `y = 2`
+        test("x = +y + 1e16 + 1", "x = +y + 1e16");
+
+        // Not associative
+        test_same("x = y / 2 / 4");
+        test_same("x = y - 2 - 4");
+        test_same("x = y * 2 + 3");
+        // `y` may be a string
+        test_same("x = y + 1 + 2");
+        test_same("x = y * 2.25 * z * 3");
+
+        // Disabled by default
+        let allocator = Allocator::default();
+        let mut pass = super::PeepholeFoldConstants::new(CompressOptions::default());
+        tester::test(&allocator, "x = y * 2 * 3", "x = y * 2 * 3", &mut pass);
+    }
+
     #[test]
     fn test_fold_arithmetic3() {
         test("x = null * undefined", "x = NaN");
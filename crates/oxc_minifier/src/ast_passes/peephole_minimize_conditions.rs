@@ -1,7 +1,9 @@
+use oxc_allocator::Vec;
 use oxc_ast::ast::*;
+use oxc_span::GetSpan;
 use oxc_traverse::{Traverse, TraverseCtx};
 
-use crate::CompressorPass;
+use crate::{node_util::is_known_boolean, CompressorPass};
 
 /// Minimize Conditions
 ///
@@ -26,9 +28,14 @@ impl<'a> CompressorPass<'a> for PeepholeMinimizeConditions {
 }
 
 impl<'a> Traverse<'a> for PeepholeMinimizeConditions {
+    fn exit_statements(&mut self, stmts: &mut Vec<'a, Statement<'a>>, ctx: &mut TraverseCtx<'a>) {
+        self.try_fold_boolean_returns(stmts, ctx);
+    }
+
     fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
         if let Some(folded_expr) = match expr {
             Expression::UnaryExpression(e) if e.operator.is_not() => Self::try_minimize_not(e, ctx),
+            Expression::BinaryExpression(e) => Self::try_minimize_boolean_comparison(e, ctx),
             _ => None,
         } {
             *expr = folded_expr;
@@ -56,6 +63,106 @@ impl<'a> PeepholeMinimizeConditions {
         }
         None
     }
+
+    /// Try to minimize comparisons of a known boolean with a boolean literal,
+    /// e.g. `x === true` => `x` and `x === false` => `!x`.
+    ///
+    /// Only applies when `x` is known to be a boolean, since e.g. `1 == true` is `true`
+    /// but `1 === true` is `false`.
+    fn try_minimize_boolean_comparison(
+        expr: &mut BinaryExpression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<Expression<'a>> {
+        let negate = match expr.operator {
+            BinaryOperator::Equality | BinaryOperator::StrictEquality => false,
+            BinaryOperator::Inequality | BinaryOperator::StrictInequality => true,
+            _ => return None,
+        };
+        let (value, other) = match (&mut expr.left, &mut expr.right) {
+            (Expression::BooleanLiteral(lit), other) | (other, Expression::BooleanLiteral(lit)) => {
+                (lit.value, other)
+            }
+            _ => return None,
+        };
+        if !is_known_boolean(other) {
+            return None;
+        }
+        let other = ctx.ast.move_expression(other);
+        // `x === true` => `x`, `x !== true` => `!x`, `x === false` => `!x`, `x !== false` => `x`
+        Some(if value == negate {
+            ctx.ast.expression_unary(expr.span, UnaryOperator::LogicalNot, other)
+        } else {
+            other
+        })
+    }
+
+    /// Try to fold an `if` statement returning opposite boolean literals into a single return,
+    /// e.g. `if (x) return true; return false;` => `return !!x;`
+    /// and `if (x) return false; else return true;` => `return !x;`.
+    ///
+    /// If `x` is known to be a boolean, `!!x` is simplified to `x`.
+    fn try_fold_boolean_returns(
+        &mut self,
+        stmts: &mut Vec<'a, Statement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let mut i = 0;
+        while i < stmts.len() {
+            let Statement::IfStatement(if_stmt) = &stmts[i] else {
+                i += 1;
+                continue;
+            };
+            let Some(consequent) = Self::get_boolean_return(&if_stmt.consequent) else {
+                i += 1;
+                continue;
+            };
+            // `if (x) return true; else return false;` or `if (x) return true; return false;`
+            let (alternate, has_else) = match &if_stmt.alternate {
+                Some(alternate) => (Self::get_boolean_return(alternate), true),
+                None => (stmts.get(i + 1).and_then(Self::get_boolean_return), false),
+            };
+            if alternate != Some(!consequent) {
+                i += 1;
+                continue;
+            }
+
+            let stmt = stmts.get_mut(i).unwrap();
+            let Statement::IfStatement(if_stmt) = stmt else { unreachable!() };
+            let span = if_stmt.span;
+            let test = ctx.ast.move_expression(&mut if_stmt.test);
+            let argument = if consequent {
+                if is_known_boolean(&test) {
+                    test
+                } else {
+                    let not =
+                        ctx.ast.expression_unary(test.span(), UnaryOperator::LogicalNot, test);
+                    ctx.ast.expression_unary(not.span(), UnaryOperator::LogicalNot, not)
+                }
+            } else {
+                ctx.ast.expression_unary(test.span(), UnaryOperator::LogicalNot, test)
+            };
+            *stmt = ctx.ast.statement_return(span, Some(argument));
+            if !has_else {
+                stmts.remove(i + 1);
+            }
+            self.changed = true;
+            i += 1;
+        }
+    }
+
+    /// `return true` or `{ return true }` => `Some(true)`
+    fn get_boolean_return(stmt: &Statement<'a>) -> Option<bool> {
+        match stmt {
+            Statement::ReturnStatement(ret) => match &ret.argument {
+                Some(Expression::BooleanLiteral(lit)) => Some(lit.value),
+                _ => None,
+            },
+            Statement::BlockStatement(block) if block.body.len() == 1 => {
+                Self::get_boolean_return(&block.body[0])
+            }
+            _ => None,
+        }
+    }
 }
 
 /// <https://github.com/google/closure-compiler/blob/master/test/com/google/javascript/jscomp/PeepholeMinimizeConditionsTest.java>
@@ -1156,4 +1263,55 @@ mod test {
             concat!("function x() {", "  return new.target ? 1 : 2;", "}"),
         );
     }
+
+    #[test]
+    fn test_minimize_known_boolean_comparison() {
+        test("if ((a < b) === true) foo()", "if (a < b) foo()");
+        test("if ((a < b) === false) foo()", "if (!(a < b)) foo()");
+        test("if ((a in b) !== true) foo()", "if (!(a in b)) foo()");
+        test("if ((a instanceof b) !== false) foo()", "if (a instanceof b) foo()");
+        test("x = true == !y", "x = !y");
+        test("x = false != (a == b)", "x = a == b");
+        test("x = (a ? !b : c > d) === true", "x = a ? !b : c > d");
+        test("x = (!a && !b) === false", "x = !(!a && !b)");
+        test("x = (f(), !y) === true", "x = (f(), !y)");
+    }
+
+    #[test]
+    fn test_fold_boolean_returns() {
+        test("function f(){if(x)return true;return false}", "function f(){return !!x}");
+        test("function f(){if(x)return false;return true}", "function f(){return !x}");
+        test("function f(){if(x){return true}else{return false}}", "function f(){return !!x}");
+        test("function f(){if(x)return false;else return true}", "function f(){return !x}");
+        test("function f(){if(x())return true;return false}", "function f(){return !!x()}");
+        test("function f(){foo();if(x)return true;return false}", "function f(){foo();return !!x}");
+        test("function f(){if(a)return true;return false;bar()}", "function f(){return !!a;bar()}");
+        test(
+            "function f(){if(x)return true;else return false;bar()}",
+            "function f(){return !!x;bar()}",
+        );
+
+        test_same("function f(){if(x)return true;return true}");
+        test_same("function f(){if(x)return true;return 0}");
+        test_same("function f(){if(x)return 1;return false}");
+        test_same("function f(){if(x)return true}");
+        test_same("function f(){if(x){foo();return true}return false}");
+        test_same("function f(){if(x)return true;foo();return false}");
+    }
+
+    #[test]
+    fn test_fold_known_boolean_returns() {
+        test("function f(){if(a<b)return true;return false}", "function f(){return a<b}");
+        test("function f(){if(!x)return true;return false}", "function f(){return !x}");
+        test("function f(){if(a in b)return false;return true}", "function f(){return !(a in b)}");
+    }
+
+    #[test]
+    fn test_minimize_unknown_boolean_comparison() {
+        test_same("if (x === true) foo()");
+        test_same("if (x == false) foo()");
+        test_same("x = f() !== true");
+        test_same("x = (a || !b) === true");
+        test_same("x = (a < b) === 1");
+    }
 }
@@ -1,6 +1,7 @@
 use oxc_allocator::Vec;
 use oxc_ast::{ast::*, Visit};
 use oxc_ecmascript::constant_evaluation::{ConstantEvaluation, IsLiteralValue};
+use oxc_semantic::ScopeFlags;
 use oxc_span::SPAN;
 use oxc_traverse::{Ancestor, Traverse, TraverseCtx};
 
@@ -30,10 +31,17 @@ impl<'a> CompressorPass<'a> for PeepholeRemoveDeadCode {
 
 impl<'a> Traverse<'a> for PeepholeRemoveDeadCode {
     fn enter_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Statement::IfStatement(if_stmt) = stmt {
+            if let Some(new_stmt) = self.try_fold_if(if_stmt, ctx) {
+                *stmt = new_stmt;
+                self.changed = true;
+            }
+            return;
+        }
         let ctx = Ctx(ctx);
         if let Some(new_stmt) = match stmt {
-            Statement::IfStatement(if_stmt) => self.try_fold_if(if_stmt, ctx),
             Statement::ForStatement(for_stmt) => self.try_fold_for(for_stmt, ctx),
+            Statement::WhileStatement(while_stmt) => Self::try_fold_while(while_stmt, ctx),
             Statement::ExpressionStatement(expr_stmt) => {
                 Self::try_fold_expression_stmt(expr_stmt, ctx)
             }
@@ -161,7 +169,7 @@ impl<'a, 'b> PeepholeRemoveDeadCode {
     fn try_fold_if(
         &mut self,
         if_stmt: &mut IfStatement<'a>,
-        ctx: Ctx<'a, 'b>,
+        ctx: &mut TraverseCtx<'a>,
     ) -> Option<Statement<'a>> {
         // Descend and remove `else` blocks first.
         if let Some(Statement::IfStatement(alternate)) = &mut if_stmt.alternate {
@@ -175,26 +183,74 @@ impl<'a, 'b> PeepholeRemoveDeadCode {
             }
         }
 
-        match ctx.get_boolean_value(&if_stmt.test) {
-            Some(true) => {
-                // self.changed = true;
-                Some(ctx.ast.move_statement(&mut if_stmt.consequent))
+        let test_boolean = Ctx(ctx).get_boolean_value(&if_stmt.test)?;
+        let dead_branch =
+            if test_boolean { if_stmt.alternate.as_ref() } else { Some(&if_stmt.consequent) };
+        if dead_branch.is_some_and(|stmt| Self::has_annex_b_function(stmt, Ctx(ctx))) {
+            return None;
+        }
+        // Keep hoisted `vars` from the removed branch.
+        let mut keep_var = KeepVar::new(ctx.ast);
+        if let Some(dead_branch) = dead_branch {
+            keep_var.visit_statement(dead_branch);
+        }
+        let var_decl = keep_var.get_variable_declaration_statement();
+
+        let live_branch = if test_boolean {
+            Some(ctx.ast.move_statement(&mut if_stmt.consequent))
+        } else {
+            if_stmt.alternate.as_mut().map(|alternate| ctx.ast.move_statement(alternate))
+        };
+        Some(match (live_branch, var_decl) {
+            (Some(stmt), None) => stmt,
+            (None, Some(var_decl)) => var_decl,
+            (None, None) => ctx.ast.statement_empty(SPAN),
+            // `if (true) { a } else { var x }` -> `{ a; var x }`
+            (Some(Statement::BlockStatement(mut block)), Some(var_decl)) => {
+                block.body.push(var_decl);
+                Statement::BlockStatement(block)
             }
-            Some(false) => {
-                Some(if let Some(alternate) = &mut if_stmt.alternate {
-                    ctx.ast.move_statement(alternate)
-                } else {
-                    // Keep hoisted `vars` from the consequent block.
-                    let mut keep_var = KeepVar::new(ctx.ast);
-                    keep_var.visit_statement(&if_stmt.consequent);
-                    keep_var
-                        .get_variable_declaration_statement()
-                        .unwrap_or_else(|| ctx.ast.statement_empty(SPAN))
-                })
-                // self.changed = true;
+            (Some(stmt), Some(var_decl)) => {
+                let scope_id = ctx.create_child_scope_of_current(ScopeFlags::empty());
+                let body = ctx.ast.vec_from_iter([stmt, var_decl]);
+                Statement::BlockStatement(
+                    ctx.ast.alloc_block_statement_with_scope_id(SPAN, body, scope_id),
+                )
             }
-            None => None,
+        })
+    }
+
+    /// `while (false) { var x }` -> `var x`
+    fn try_fold_while(
+        while_stmt: &mut WhileStatement<'a>,
+        ctx: Ctx<'a, 'b>,
+    ) -> Option<Statement<'a>> {
+        if ctx.get_boolean_value(&while_stmt.test)?
+            || Self::has_annex_b_function(&while_stmt.body, ctx)
+        {
+            return None;
         }
+        let mut keep_var = KeepVar::new(ctx.ast);
+        keep_var.visit_statement(&while_stmt.body);
+        Some(
+            keep_var
+                .get_variable_declaration_statement()
+                .unwrap_or_else(|| ctx.ast.statement_empty(SPAN)),
+        )
+    }
+
+    /// Check if `stmt` declares a function in a block in sloppy mode,
+    /// which also declares a `var` in the enclosing function (Annex B.3.3).
+    ///
+    /// `if (false) { function f() {} } f;` is `undefined` rather than a `ReferenceError`,
+    /// so these are kept.
+    fn has_annex_b_function(stmt: &Statement<'a>, ctx: Ctx<'a, 'b>) -> bool {
+        if ctx.current_scope_flags().is_strict_mode() {
+            return false;
+        }
+        let mut finder = FunctionDeclarationFinder { found: false };
+        finder.visit_statement(stmt);
+        finder.found
     }
 
     fn try_fold_for(
@@ -417,6 +473,19 @@ impl<'a, 'b> PeepholeRemoveDeadCode {
     }
 }
 
+/// Finds function declarations, which are not nested in another function.
+struct FunctionDeclarationFinder {
+    found: bool,
+}
+
+impl<'a> Visit<'a> for FunctionDeclarationFinder {
+    fn visit_function(&mut self, func: &Function<'a>, _flags: ScopeFlags) {
+        self.found |= func.is_declaration();
+    }
+
+    fn visit_arrow_function_expression(&mut self, _arrow: &ArrowFunctionExpression<'a>) {}
+}
+
 /// <https://github.com/google/closure-compiler/blob/master/test/com/google/javascript/jscomp/PeepholeRemoveDeadCodeTest.java>
 #[cfg(test)]
 mod test {
@@ -512,6 +581,38 @@ mod test {
         // fold("l1:for(;false;) {  }", "");
     }
 
+    #[test]
+    fn test_fold_constant_if() {
+        fold("if (true) a(); else b();", "a()");
+        fold("if (false) a(); else b();", "b()");
+        fold("if (false) a();", "");
+        fold("if (!0) a(); else b();", "a()");
+        fold("x = true ? a : b", "x = a");
+        fold("x = 0 ? a : b", "x = b");
+        fold_same("if (x) a(); else b();");
+
+        // `var`s in the removed branch are hoisted, so they are kept.
+        fold("if (false) { var a = 1; } a = 2;", "var a; a = 2;");
+        fold("if (false) { var a = 1, b; }", "var a, b");
+        fold("if (true) { a() } else { var b = 1; }", "{ a(); var b; }");
+        fold("if (true) a(); else var b = 1;", "{ a(); var b; }");
+        fold("if (false) { var a; } else b();", "{ b(); var a; }");
+        fold("if (false) { if (x) { var a = 1; } }", "var a");
+        fold("if (false) { let a = 1; const b = 2; }", "");
+        fold("if (false) { function f() {} }", "");
+        fold("if (false) { (function() { var a; }); }", "");
+    }
+
+    #[test]
+    fn test_fold_while_false() {
+        fold("while (false) foo()", "");
+        fold("while (0) { foo() }", "");
+        fold("while (false) { var a = foo(); }", "var a");
+        fold("while (false) { for (var a of b) { var c; } }", "var a, c");
+        fold_same("while (x) foo()");
+        fold_same("while (true) foo()");
+    }
+
     #[test]
     fn test_minimize_loop_with_constant_condition_vanilla_for() {
         fold("for(;true;) foo()", "for(;;) foo()");
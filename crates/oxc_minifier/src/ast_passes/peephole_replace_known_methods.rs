@@ -4,6 +4,7 @@ use oxc_ecmascript::{
     constant_evaluation::ConstantEvaluation, StringCharAt, StringCharCodeAt, StringIndexOf,
     StringLastIndexOf, StringSubstring,
 };
+use oxc_semantic::IsGlobalReference;
 use oxc_traverse::{Traverse, TraverseCtx};
 
 use crate::{node_util::Ctx, CompressorPass};
@@ -29,6 +30,10 @@ impl<'a> Traverse<'a> for PeepholeReplaceKnownMethods {
     fn enter_expression(&mut self, node: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
         self.try_fold_known_string_methods(node, ctx);
     }
+
+    fn exit_expression(&mut self, node: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.try_fold_known_array_methods(node, ctx);
+    }
 }
 
 impl PeepholeReplaceKnownMethods {
@@ -96,6 +101,41 @@ impl PeepholeReplaceKnownMethods {
         }
     }
 
+    fn try_fold_known_array_methods<'a>(
+        &mut self,
+        node: &mut Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let Expression::CallExpression(call_expr) = node else { return };
+        let has_arguments = !call_expr.arguments.is_empty();
+        let Expression::StaticMemberExpression(member) = &mut call_expr.callee else { return };
+
+        let replacement = match member.property.name.as_str() {
+            // `Array.of(a, ...b)` -> `[a, ...b]`
+            "of" if member.object.is_global_reference_name("Array", ctx.symbols()) => {
+                let elements = ctx.ast.vec_from_iter(
+                    ctx.ast.move_vec(&mut call_expr.arguments).into_iter().map(|arg| match arg {
+                        Argument::SpreadElement(spread) => {
+                            ArrayExpressionElement::SpreadElement(spread)
+                        }
+                        _ => ArrayExpressionElement::from(arg.into_expression()),
+                    }),
+                );
+                ctx.ast.expression_array(call_expr.span, elements, None)
+            }
+            // `[a, b].concat()` -> `[a, b]`
+            "concat"
+                if !has_arguments && matches!(member.object, Expression::ArrayExpression(_)) =>
+            {
+                ctx.ast.move_expression(&mut member.object)
+            }
+            _ => return,
+        };
+
+        self.changed = true;
+        *node = replacement;
+    }
+
     fn try_fold_string_index_of<'a>(
         span: Span,
         call_expr: &CallExpression<'a>,
@@ -1062,7 +1102,6 @@ mod test {
     }
 
     #[test]
-    #[ignore]
     fn test_array_of_spread() {
         fold("x = Array.of(...['a', 'b', 'c'])", "x = [...['a', 'b', 'c']]");
         fold("x = Array.of(...['a', 'b', 'c',])", "x = [...['a', 'b', 'c']]");
@@ -1072,7 +1111,6 @@ mod test {
     }
 
     #[test]
-    #[ignore]
     fn test_array_of_no_spread() {
         fold("x = Array.of('a', 'b', 'c')", "x = ['a', 'b', 'c']");
         fold("x = Array.of('a', ['b', 'c'])", "x = ['a', ['b', 'c']]");
@@ -1080,21 +1118,30 @@ mod test {
     }
 
     #[test]
-    #[ignore]
     fn test_array_of_no_args() {
         fold("x = Array.of()", "x = []");
+        fold("x = Array.of(5)", "x = [5]");
     }
 
     #[test]
-    #[ignore]
     fn test_array_of_no_change() {
         fold_same("x = Array.of.apply(window, ['a', 'b', 'c'])");
         fold_same("x = ['a', 'b', 'c']");
         fold_same("x = [Array.of, 'a', 'b', 'c']");
+        fold_same("x = Array.prototype.slice.call(arguments)");
+        fold_same("var Array; x = Array.of(1, 2, 3)");
+        fold_same("function f(Array) { return Array.of(1, 2, 3) }");
+    }
+
+    #[test]
+    fn test_fold_empty_concat() {
+        fold("x = [].concat()", "x = []");
+        fold("x = [1, 2].concat()", "x = [1, 2]");
+        fold_same("x = obj.concat()");
+        fold_same("x = [].concat(1)");
     }
 
     #[test]
-    #[ignore]
     fn test_fold_array_bug() {
         fold_same("Array[123]()");
     }
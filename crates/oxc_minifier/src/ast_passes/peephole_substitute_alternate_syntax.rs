@@ -1,21 +1,29 @@
 use oxc_allocator::Vec;
 use oxc_ast::{ast::*, NONE};
-use oxc_ecmascript::{ToInt32, ToJsString};
-use oxc_semantic::IsGlobalReference;
-use oxc_span::{GetSpan, SPAN};
+use oxc_codegen::Codegen;
+use oxc_ecmascript::{
+    constant_evaluation::ConstantEvaluation, side_effects::MayHaveSideEffects, ToInt32, ToJsString,
+};
+use oxc_semantic::{IsGlobalReference, ScopeFlags};
+use oxc_span::{GetSpan, Span, SPAN};
 use oxc_syntax::{
-    number::NumberBase,
+    number::{NumberBase, ToJsString as _},
     operator::{BinaryOperator, UnaryOperator},
 };
 use oxc_traverse::{Ancestor, Traverse, TraverseCtx};
 
-use crate::{node_util::Ctx, CompressorPass};
+use crate::{
+    node_util::{is_known_boolean, Ctx},
+    CompressOptions, CompressorPass,
+};
 
 /// A peephole optimization that minimizes code by simplifying conditional
 /// expressions, replacing IFs with HOOKs, replacing object constructors
 /// with literals, and simplifying returns.
 /// <https://github.com/google/closure-compiler/blob/master/src/com/google/javascript/jscomp/PeepholeSubstituteAlternateSyntax.java>
 pub struct PeepholeSubstituteAlternateSyntax {
+    options: CompressOptions,
+
     /// Do not compress syntaxes that are hard to analyze inside the fixed loop.
     /// e.g. Do not compress `undefined -> void 0`, `true` -> `!0`.
     /// Opposite of `late` in Closure Compier.
@@ -39,6 +47,12 @@ impl<'a> CompressorPass<'a> for PeepholeSubstituteAlternateSyntax {
 }
 
 impl<'a> Traverse<'a> for PeepholeSubstituteAlternateSyntax {
+    fn exit_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.loops {
+            self.compress_while(stmt, ctx);
+        }
+    }
+
     fn exit_return_statement(
         &mut self,
         stmt: &mut ReturnStatement<'a>,
@@ -93,19 +107,30 @@ impl<'a> Traverse<'a> for PeepholeSubstituteAlternateSyntax {
 
     fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
         let ctx = Ctx(ctx);
-        self.try_compress_boolean(expr, ctx);
+        if self.options.expand_booleans {
+            self.try_expand_boolean(expr, ctx);
+        } else {
+            self.try_compress_boolean(expr, ctx);
+        }
         self.try_compress_undefined(expr, ctx);
+        if self.options.numbers {
+            self.try_compress_number(expr, ctx);
+        }
         match expr {
-            Expression::NewExpression(new_expr) => {
+            Expression::NewExpression(new_expr) if self.options.literal_constructors => {
                 if let Some(new_expr) = Self::try_fold_new_expression(new_expr, ctx) {
                     *expr = new_expr;
                     self.changed = true;
                 }
             }
             Expression::CallExpression(call_expr) => {
-                if let Some(new_expr) =
+                let literal = if self.options.literal_constructors {
                     Self::try_fold_literal_constructor_call_expression(call_expr, ctx)
-                        .or_else(|| Self::try_fold_simple_function_call(call_expr, ctx))
+                } else {
+                    None
+                };
+                if let Some(new_expr) =
+                    literal.or_else(|| Self::try_fold_simple_function_call(call_expr, ctx))
                 {
                     *expr = new_expr;
                     self.changed = true;
@@ -116,6 +141,18 @@ impl<'a> Traverse<'a> for PeepholeSubstituteAlternateSyntax {
                     self.try_fold_chain_call_expression(call_expr, ctx);
                 }
             }
+            Expression::ConditionalExpression(cond_expr) => {
+                if let Some(new_expr) = Self::try_fold_boolean_conditional(cond_expr, ctx) {
+                    *expr = new_expr;
+                    self.changed = true;
+                }
+            }
+            Expression::UnaryExpression(unary_expr) => {
+                if let Some(new_expr) = Self::try_remove_double_negation(unary_expr, ctx) {
+                    *expr = new_expr;
+                    self.changed = true;
+                }
+            }
             Expression::TemplateLiteral(_) => {
                 if let Some(val) = expr.to_js_string() {
                     *expr = ctx.ast.expression_string_literal(expr.span(), val);
@@ -153,11 +190,24 @@ impl<'a> Traverse<'a> for PeepholeSubstituteAlternateSyntax {
     ) {
         self.compress_typeof_undefined(expr, Ctx(ctx));
     }
+
+    fn exit_property_key(&mut self, key: &mut PropertyKey<'a>, ctx: &mut TraverseCtx<'a>) {
+        let computed = match ctx.parent() {
+            Ancestor::ObjectPropertyKey(prop) => *prop.computed(),
+            Ancestor::MethodDefinitionKey(method) => *method.computed(),
+            Ancestor::PropertyDefinitionKey(prop) => *prop.computed(),
+            Ancestor::AccessorPropertyKey(prop) => *prop.computed(),
+            _ => return,
+        };
+        if !computed {
+            self.compress_property_key(key, Ctx(ctx));
+        }
+    }
 }
 
 impl<'a, 'b> PeepholeSubstituteAlternateSyntax {
-    pub fn new(in_fixed_loop: bool) -> Self {
-        Self { in_fixed_loop, in_define_export: false, changed: false }
+    pub fn new(in_fixed_loop: bool, options: CompressOptions) -> Self {
+        Self { options, in_fixed_loop, in_define_export: false, changed: false }
     }
 
     /* Utilities */
@@ -173,6 +223,64 @@ impl<'a, 'b> PeepholeSubstituteAlternateSyntax {
         }
     }
 
+    /// Transforms numeric literals into their shortest form, e.g. `1000000` => `1e6`,
+    /// and `Infinity` => `1/0`.
+    ///
+    /// Numeric property keys are left in their canonical form by `compress_property_key`.
+    fn try_compress_number(&mut self, expr: &mut Expression<'a>, ctx: Ctx<'a, 'b>) {
+        if self.in_fixed_loop {
+            return;
+        }
+        match expr {
+            Expression::NumericLiteral(lit) if lit.value.is_finite() && lit.value >= 0.0 => {
+                let raw = Codegen::get_minified_number(lit.value);
+                // Only use the new form if it is shorter, and has exactly the same value
+                if (!lit.raw.is_empty() && raw.len() >= lit.raw.len())
+                    || Self::parse_minified_number(&raw) != Some(lit.value)
+                {
+                    return;
+                }
+                if raw.starts_with("0x") {
+                    lit.base = NumberBase::Hex;
+                }
+                lit.raw = ctx.ast.str(&raw);
+                self.changed = true;
+            }
+            Expression::NumericLiteral(lit) if lit.value == f64::INFINITY => {
+                *expr = Self::create_one_div_zero(lit.span, ctx);
+                self.changed = true;
+            }
+            Expression::Identifier(ident)
+                if ident.name == "Infinity" && ctx.is_global_reference(ident) =>
+            {
+                // `{ Infinity }` can't be changed without changing the property key
+                if matches!(ctx.parent(), Ancestor::ObjectPropertyValue(prop) if *prop.shorthand())
+                {
+                    return;
+                }
+                *expr = Self::create_one_div_zero(ident.span, ctx);
+                self.changed = true;
+            }
+            _ => {}
+        }
+    }
+
+    /// Parse the result of `Codegen::get_minified_number`.
+    fn parse_minified_number(raw: &str) -> Option<f64> {
+        match raw.strip_prefix("0x") {
+            #[allow(clippy::cast_precision_loss)]
+            Some(hex) => u128::from_str_radix(hex, 16).ok().map(|n| n as f64),
+            None => raw.parse::<f64>().ok(),
+        }
+    }
+
+    /// `1/0`
+    fn create_one_div_zero(span: Span, ctx: Ctx<'a, 'b>) -> Expression<'a> {
+        let one = ctx.ast.expression_numeric_literal(SPAN, 1.0, "1", NumberBase::Decimal);
+        let zero = ctx.ast.expression_numeric_literal(SPAN, 0.0, "0", NumberBase::Decimal);
+        ctx.ast.expression_binary(span, one, BinaryOperator::Division, zero)
+    }
+
     /// Test `Object.defineProperty(exports, ...)`
     fn is_object_define_property_exports(call_expr: &CallExpression<'a>) -> bool {
         let Some(Argument::Identifier(ident)) = call_expr.arguments.first() else { return false };
@@ -194,16 +302,34 @@ impl<'a, 'b> PeepholeSubstituteAlternateSyntax {
 
     /* Statements */
 
-    // /// Transforms `while(expr)` to `for(;expr;)`
-    // fn compress_while(&mut self, stmt: &mut Statement<'a>) {
-    // let Statement::WhileStatement(while_stmt) = stmt else { return };
-    // if self.options.loops {
-    // let dummy_test = ctx.ast.expression_this(SPAN);
-    // let test = std::mem::replace(&mut while_stmt.test, dummy_test);
-    // let body = ctx.ast.move_statement(&mut while_stmt.body);
-    // *stmt = ctx.ast.statement_for(SPAN, None, Some(test), None, body);
-    // }
-    // }
+    /// Transforms `while(expr)` to `for(;expr;)`, and `while(true)` to `for(;;)`.
+    ///
+    /// A label of the `while` statement is the parent `LabeledStatement`, so it is kept.
+    fn compress_while(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if !matches!(stmt, Statement::WhileStatement(_)) {
+            return;
+        }
+        // `for` statement has a scope, which becomes the parent of scopes in the test and body
+        let scope_id = ctx.insert_scope_below_statement(stmt, ScopeFlags::empty());
+        let Statement::WhileStatement(while_stmt) = stmt else { unreachable!() };
+        let test = ctx.ast.move_expression(&mut while_stmt.test);
+        let test =
+            if !test.may_have_side_effects() && Ctx(ctx).get_boolean_value(&test) == Some(true) {
+                None
+            } else {
+                Some(test)
+            };
+        let body = ctx.ast.move_statement(&mut while_stmt.body);
+        *stmt = Statement::ForStatement(ctx.ast.alloc_for_statement_with_scope_id(
+            while_stmt.span,
+            None,
+            test,
+            None,
+            body,
+            scope_id,
+        ));
+        self.changed = true;
+    }
 
     /* Expressions */
 
@@ -246,6 +372,105 @@ impl<'a, 'b> PeepholeSubstituteAlternateSyntax {
         }
     }
 
+    /// Transforms `!0` => `true` and `!1` => `false`. Inverse of `try_compress_boolean`.
+    /// Other numbers (`!2`) are not boolean idioms, so are left as is.
+    fn try_expand_boolean(&mut self, expr: &mut Expression<'a>, ctx: Ctx<'a, 'b>) {
+        if self.in_fixed_loop {
+            return;
+        }
+        let Expression::UnaryExpression(unary_expr) = expr else { return };
+        if unary_expr.operator != UnaryOperator::LogicalNot {
+            return;
+        }
+        let Expression::NumericLiteral(lit) = &unary_expr.argument else { return };
+        let value = match lit.value {
+            0.0 => true,
+            1.0 => false,
+            _ => return,
+        };
+        *expr = ctx.ast.expression_boolean_literal(unary_expr.span, value);
+        self.changed = true;
+    }
+
+    /// Transforms `x ? true : false` => `!!x` and `x ? false : true` => `!x`.
+    ///
+    /// `!!x` is `x` if `x` is known to be a boolean, or the value is used as a boolean.
+    fn try_fold_boolean_conditional(
+        expr: &mut ConditionalExpression<'a>,
+        ctx: Ctx<'a, 'b>,
+    ) -> Option<Expression<'a>> {
+        let consequent = Self::get_boolean_literal(&expr.consequent)?;
+        let alternate = Self::get_boolean_literal(&expr.alternate)?;
+        if consequent == alternate {
+            return None;
+        }
+        let test = ctx.ast.move_expression(&mut expr.test);
+        if !consequent {
+            return Some(ctx.ast.expression_unary(expr.span, UnaryOperator::LogicalNot, test));
+        }
+        if is_known_boolean(&test) || Self::is_in_boolean_context(ctx) {
+            return Some(test);
+        }
+        let not = ctx.ast.expression_unary(expr.span, UnaryOperator::LogicalNot, test);
+        Some(ctx.ast.expression_unary(expr.span, UnaryOperator::LogicalNot, not))
+    }
+
+    /// Transforms `!!x` => `x` if the value is used as a boolean, e.g. `if (!!x)` => `if (x)`.
+    ///
+    /// Otherwise `!!x` converts `x` to a boolean, so it is kept.
+    fn try_remove_double_negation(
+        expr: &mut UnaryExpression<'a>,
+        ctx: Ctx<'a, 'b>,
+    ) -> Option<Expression<'a>> {
+        if !expr.operator.is_not() {
+            return None;
+        }
+        let Expression::UnaryExpression(argument) = &mut expr.argument else { return None };
+        if !argument.operator.is_not() || !Self::is_in_boolean_context(ctx) {
+            return None;
+        }
+        Some(ctx.ast.move_expression(&mut argument.argument))
+    }
+
+    /// `true`, `false`, `!0` or `!1`
+    fn get_boolean_literal(expr: &Expression<'a>) -> Option<bool> {
+        match expr {
+            Expression::BooleanLiteral(lit) => Some(lit.value),
+            Expression::UnaryExpression(e) if e.operator.is_not() => match &e.argument {
+                Expression::NumericLiteral(lit) => match lit.value {
+                    0.0 => Some(true),
+                    1.0 => Some(false),
+                    _ => None,
+                },
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// Check if the value of the current expression is only used as a boolean,
+    /// e.g. test of an `if` statement, or operand of `!`.
+    ///
+    /// An operand of `&&` or `||` is only used as a boolean if the result of the logical expression is.
+    fn is_in_boolean_context(ctx: Ctx<'a, 'b>) -> bool {
+        for ancestor in ctx.ancestors() {
+            match ancestor {
+                Ancestor::IfStatementTest(_)
+                | Ancestor::WhileStatementTest(_)
+                | Ancestor::DoWhileStatementTest(_)
+                | Ancestor::ForStatementTest(_)
+                | Ancestor::ConditionalExpressionTest(_) => return true,
+                Ancestor::UnaryExpressionArgument(e) => return e.operator().is_not(),
+                Ancestor::LogicalExpressionLeft(e)
+                    if *e.operator() != LogicalOperator::Coalesce => {}
+                Ancestor::LogicalExpressionRight(e)
+                    if *e.operator() != LogicalOperator::Coalesce => {}
+                _ => return false,
+            }
+        }
+        false
+    }
+
     /// Compress `typeof foo == "undefined"` into `typeof foo > "u"`
     /// Enabled by `compress.typeofs`
     fn compress_typeof_undefined(&mut self, expr: &mut BinaryExpression<'a>, ctx: Ctx<'a, 'b>) {
@@ -325,6 +550,43 @@ impl<'a, 'b> PeepholeSubstituteAlternateSyntax {
         }
     }
 
+    /// Normalizes numeric property keys to their canonical form,
+    /// and converts string keys to numeric keys where they represent the same key.
+    ///
+    /// `{ 0xff: x }` -> `{ 255: x }`
+    /// `{ "1000": x }` -> `{ 1000: x }`
+    fn compress_property_key(&mut self, key: &mut PropertyKey<'a>, ctx: Ctx<'a, 'b>) {
+        let (span, value) = match key {
+            PropertyKey::NumericLiteral(lit) => {
+                if lit.raw == lit.value.to_js_string() {
+                    return;
+                }
+                (lit.span, lit.value)
+            }
+            PropertyKey::StringLiteral(lit) => {
+                // Only if converting the number back to a string gives the same key.
+                // e.g. not `"01"`, `"1e3"` or `"-1"`.
+                let Ok(value) = lit.value.parse::<f64>() else { return };
+                if !value.is_finite()
+                    || value.is_sign_negative()
+                    || value.to_js_string() != lit.value.as_str()
+                {
+                    return;
+                }
+                (lit.span, value)
+            }
+            _ => return,
+        };
+        let raw = value.to_js_string();
+        *key = PropertyKey::NumericLiteral(ctx.ast.alloc_numeric_literal(
+            span,
+            value,
+            raw,
+            NumberBase::Decimal,
+        ));
+        self.changed = true;
+    }
+
     fn try_compress_assignment_expression(
         expr: &mut AssignmentExpression<'a>,
         ctx: Ctx<'a, 'b>,
@@ -378,6 +640,9 @@ impl<'a, 'b> PeepholeSubstituteAlternateSyntax {
                 || Self::is_window_object(&new_expr.callee))
         {
             Some(ctx.ast.expression_object(new_expr.span, ctx.ast.vec(), None))
+        } else if new_expr.callee.is_global_reference_name("RegExp", ctx.symbols()) {
+            // `new RegExp("a", "g")` -> `/a/g`
+            Self::try_fold_regexp_constructor(new_expr.span, &new_expr.arguments, ctx)
         } else if new_expr.callee.is_global_reference_name("Array", ctx.symbols()) {
             // `new Array` -> `[]`
             if new_expr.arguments.is_empty() {
@@ -435,6 +700,9 @@ impl<'a, 'b> PeepholeSubstituteAlternateSyntax {
                 || Self::is_window_object(&call_expr.callee))
         {
             Some(ctx.ast.expression_object(call_expr.span, ctx.ast.vec(), None))
+        } else if call_expr.callee.is_global_reference_name("RegExp", ctx.symbols()) {
+            // `RegExp("a", "g")` -> `/a/g`
+            Self::try_fold_regexp_constructor(call_expr.span, &call_expr.arguments, ctx)
         } else if call_expr.callee.is_global_reference_name("Array", ctx.symbols()) {
             // `Array()` -> `[]`
             if call_expr.arguments.is_empty() {
@@ -477,6 +745,45 @@ impl<'a, 'b> PeepholeSubstituteAlternateSyntax {
         }
     }
 
+    /// `RegExp` constructor with a string literal pattern and optional string literal flags
+    /// is replaced with a regular expression literal, if the pattern is valid.
+    ///
+    /// Patterns which would need escaping to be a literal (containing `/` or line terminators)
+    /// and empty patterns are left as is.
+    fn try_fold_regexp_constructor(
+        span: Span,
+        arguments: &Vec<'a, Argument<'a>>,
+        ctx: Ctx<'a, 'b>,
+    ) -> Option<Expression<'a>> {
+        let (pattern, flags_text) = match arguments.as_slice() {
+            [Argument::StringLiteral(pattern)] => (pattern.value.as_str(), None),
+            [Argument::StringLiteral(pattern), Argument::StringLiteral(flags)] => {
+                (pattern.value.as_str(), Some(flags.value.as_str()))
+            }
+            _ => return None,
+        };
+        if pattern.is_empty() || pattern.contains(['/', '\n', '\r', '\u{2028}', '\u{2029}']) {
+            return None;
+        }
+
+        let mut flags = RegExpFlags::empty();
+        for ch in flags_text.unwrap_or_default().chars() {
+            let flag = RegExpFlags::try_from(ch).ok()?;
+            if flags.contains(flag) {
+                return None;
+            }
+            flags |= flag;
+        }
+        let options = oxc_regular_expression::Options::default();
+        oxc_regular_expression::LiteralParser::new(ctx.ast.allocator, pattern, flags_text, options)
+            .parse()
+            .ok()?;
+
+        let raw = ctx.ast.atom(&format!("/{pattern}/{flags}"));
+        let regex = RegExp { pattern: RegExpPattern::Raw(pattern), flags };
+        Some(ctx.ast.expression_reg_exp_literal(span, regex, raw.as_str()))
+    }
+
     fn try_fold_simple_function_call(
         call_expr: &mut CallExpression<'a>,
         ctx: Ctx<'a, 'b>,
@@ -549,7 +856,7 @@ impl<'a, 'b> PeepholeSubstituteAlternateSyntax {
         ctx: Ctx<'a, 'b>,
     ) -> Expression<'a> {
         let callee = ctx.ast.expression_identifier_reference(SPAN, "Array");
-        ctx.ast.expression_call(SPAN, callee, NONE, arguments, false)
+        ctx.ast.expression_call(SPAN, callee, NONE, arguments, false, false)
     }
 
     /// returns an array literal `[]` of zero, one, or more elements, copying from the input
@@ -570,12 +877,18 @@ impl<'a, 'b> PeepholeSubstituteAlternateSyntax {
 #[cfg(test)]
 mod test {
     use oxc_allocator::Allocator;
+    use oxc_ast::ast::{Expression, Statement};
+    use oxc_parser::Parser;
+    use oxc_semantic::SemanticBuilder;
+    use oxc_span::SourceType;
+    use oxc_traverse::TraverseCtx;
 
-    use crate::tester;
+    use crate::{tester, CompressOptions, CompressorPass};
 
     fn test(source_text: &str, expected: &str) {
         let allocator = Allocator::default();
-        let mut pass = super::PeepholeSubstituteAlternateSyntax::new(false);
+        let mut pass =
+            super::PeepholeSubstituteAlternateSyntax::new(false, CompressOptions::default());
         tester::test(&allocator, source_text, expected, &mut pass);
     }
 
@@ -594,6 +907,98 @@ mod test {
         test("function f(){if(a()){return undefined;}}", "function f(){if(a()){return}}");
     }
 
+    #[test]
+    fn test_while() {
+        test("while(x) foo()", "for(;x;) foo()");
+        test("while(true) foo()", "for(;;) foo()");
+        test("while(!0) foo()", "for(;;) foo()");
+        test("while(1) foo()", "for(;;) foo()");
+        test("while(false) foo()", "for(;!1;) foo()");
+        test("while(foo(), true) bar()", "for(;foo(), !0;) bar()");
+        test(
+            "while(x) { if (y) continue; if (z) break; foo() }",
+            "for(;x;) { if (y) continue; if (z) break; foo() }",
+        );
+        test("do { foo() } while(x)", "do { foo() } while(x)");
+    }
+
+    #[test]
+    fn test_while_labeled() {
+        test("a: while(x) { foo() }", "a: for(;x;) { foo() }");
+        test(
+            "a: while(true) { b: while(y) { if (z) continue a; break b; } }",
+            "a: for(;;) { b: for(;y;) { if (z) continue a; break b; } }",
+        );
+    }
+
+    #[test]
+    fn test_while_loops_disabled() {
+        let allocator = Allocator::default();
+        let options = CompressOptions { loops: false, ..CompressOptions::default() };
+        let mut pass = super::PeepholeSubstituteAlternateSyntax::new(false, options);
+        tester::test(&allocator, "while(x) foo()", "while(x) foo()", &mut pass);
+    }
+
+    #[test]
+    fn test_expand_boolean() {
+        let test = |source_text: &str, expected: &str| {
+            let allocator = Allocator::default();
+            let options = CompressOptions { expand_booleans: true, ..CompressOptions::default() };
+            let mut pass = super::PeepholeSubstituteAlternateSyntax::new(false, options);
+            tester::test(&allocator, source_text, expected, &mut pass);
+        };
+        test("x = !0", "x = true");
+        test("x = !1", "x = false");
+        test("x = [!0, !1, true, false]", "x = [true, false, true, false]");
+        test("if (!0) foo(!1)", "if (true) foo(false)");
+        test("x === !0", "x === true");
+        // Not a boolean idiom
+        test("x = !2", "x = !2");
+        test("x = !-0", "x = !-0");
+        test("x = !a", "x = !a");
+        test("x = !!0", "x = !true");
+    }
+
+    #[test]
+    fn test_fold_boolean_conditional() {
+        test("x = y ? true : false", "x = !!y");
+        test("x = y ? false : true", "x = !y");
+        test("x = y ? !0 : !1", "x = !!y");
+        test("x = y ? !1 : !0", "x = !y");
+        // Known boolean
+        test("x = a === b ? true : false", "x = a === b");
+        test("x = !y ? true : false", "x = !y");
+        test("x = (a < b && c in d) ? true : false", "x = a < b && c in d");
+        // Boolean context
+        test("if (y ? true : false) foo()", "if (y) foo()");
+        test("if ((y ? true : false) || z) foo()", "if (y || z) foo()");
+        // Not foldable
+        test("x = y ? true : true", "x = y ? !0 : !0");
+        test("x = y ? true : z", "x = y ? !0 : z");
+        test("x = y ? 1 : 0", "x = y ? 1 : 0");
+    }
+
+    #[test]
+    fn test_remove_double_negation() {
+        // Boolean context
+        test("if (!!x) foo()", "if (x) foo()");
+        test("while (!!x) foo()", "for (;x;) foo()");
+        test("do foo(); while (!!x)", "do foo(); while (x)");
+        test("for (;!!x;) foo()", "for (;x;) foo()");
+        test("a = !!x ? b : c", "a = x ? b : c");
+        test("a = !!!x", "a = !x");
+        test("if (!!a && !!b) foo()", "if (a && b) foo()");
+        test("if (!!a || (!!b && !!c)) foo()", "if (a || b && c) foo()");
+        // Coercion is preserved
+        test_same("a = !!x");
+        test_same("foo(!!x)");
+        test_same("a = !!x && !!y");
+        test_same("a = !!x || y");
+        test_same("if (!!x ?? y) foo()");
+        test_same("if ((a = !!x)) foo()");
+        test_same("function f() { return !!x }");
+    }
+
     #[test]
     fn test_undefined() {
         test("var x = undefined", "var x");
@@ -705,6 +1110,117 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_fold_literal_array_constructors_single_number() {
+        // `new Array(5)` creates an array of length 5, not `[5]`
+        test("x = new Array(5)", "x = Array(5)");
+        test_same("x = Array(5)");
+        test("x = new Array(1, 2)", "x = [1, 2]");
+    }
+
+    #[test]
+    fn test_fold_literal_constructors_shadowed() {
+        test_same("function f(Array) { return new Array(1, 2) }");
+        test_same("function f(Object) { return new Object() }");
+        test_same("function f(RegExp) { return new RegExp('a', 'g') }");
+        test_same("var Array; x = Array()");
+    }
+
+    #[test]
+    fn test_fold_literal_regexp_constructors() {
+        test("x = new RegExp('a', 'g')", "x = /a/g");
+        test("x = new RegExp('a')", "x = /a/");
+        test("x = RegExp('a', 'gi')", "x = /a/gi");
+        test(r"x = new RegExp('\\d+', 'u')", r"x = /\d+/u");
+
+        // Not constant
+        test_same("x = new RegExp(a, 'g')");
+        test_same("x = new RegExp('a', g)");
+        test_same("x = new RegExp()");
+        test_same("x = new RegExp('a', 'g', 1)");
+        // Needs escaping in a literal
+        test_same("x = new RegExp('')");
+        test_same("x = new RegExp('a/b')");
+        test_same(r"x = new RegExp('a\nb')");
+        // Invalid
+        test_same("x = new RegExp('(', 'g')");
+        test_same("x = new RegExp('a', 'gg')");
+        test_same("x = new RegExp('a', 'z')");
+        test_same(r"x = new RegExp('\\p{Foo}', 'u')");
+    }
+
+    #[test]
+    fn test_fold_literal_constructors_disabled() {
+        let allocator = Allocator::default();
+        let options = CompressOptions { literal_constructors: false, ..CompressOptions::default() };
+        let mut pass = super::PeepholeSubstituteAlternateSyntax::new(false, options);
+        for source_text in ["x = new Array(1, 2)", "x = Object()", "x = new RegExp('a', 'g')"] {
+            tester::test(&allocator, source_text, source_text, &mut pass);
+        }
+    }
+
+    /// Check that numeric literal `source_text` is minified to `expected`, which is not longer.
+    fn test_number(source_text: &str, expected: &str) {
+        let allocator = Allocator::default();
+        let source_text = format!("x = {source_text}");
+        let source_text = allocator.alloc_str(&source_text);
+        let mut program = Parser::new(&allocator, source_text, SourceType::mjs()).parse().program;
+        let (symbols, scopes) =
+            SemanticBuilder::new().build(&program).semantic.into_symbol_table_and_scope_tree();
+        let mut ctx = TraverseCtx::new(scopes, symbols, &allocator);
+        let mut pass =
+            super::PeepholeSubstituteAlternateSyntax::new(false, CompressOptions::default());
+        pass.build(&mut program, &mut ctx);
+
+        let Statement::ExpressionStatement(stmt) = &program.body[0] else { unreachable!() };
+        let Expression::AssignmentExpression(assign) = &stmt.expression else { unreachable!() };
+        let Expression::NumericLiteral(lit) = &assign.right else { unreachable!() };
+        assert_eq!(lit.raw, expected, "for {source_text}");
+        assert!(lit.raw.len() <= source_text.len() - "x = ".len());
+    }
+
+    #[test]
+    fn test_compress_numbers() {
+        // Integers
+        test_number("0", "0");
+        test_number("100", "100");
+        test_number("1000", "1e3");
+        test_number("1_000", "1e3");
+        test_number("123456", "123456");
+        test_number("1000000", "1e6");
+        test_number("1230000", "123e4");
+        test_number("1000000000000000000000", "1e21");
+        test_number("0xff", "255");
+        test_number("0b1111", "15");
+        test_number("1152921504606846976", "0x1000000000000000");
+        // Fractions
+        test_number("0.5", ".5");
+        test_number("1.5", "1.5");
+        test_number("0.000005", "5e-6");
+        test_number("0.0012", ".0012");
+        test_number("0.00012", "12e-5");
+        test_number("1.2e101", "12e100");
+        test_number("5e-324", "5e-324");
+        test_number("1.7976931348623157e308", "17976931348623157e292");
+
+        test("x = Infinity", "x = 1/0");
+        test("x = -Infinity", "x = -(1/0)");
+        test("x = Infinity.toString()", "x = (1/0).toString()");
+        test("x = 1/0", "x = 1/0");
+        test_same("x = { Infinity }");
+        test_same("x = { Infinity: 1 }");
+        test_same("x = y.Infinity");
+        test_same("let Infinity = 1; x = Infinity");
+    }
+
+    #[test]
+    fn test_compress_numbers_disabled() {
+        let allocator = Allocator::default();
+        let options = CompressOptions { numbers: false, ..CompressOptions::default() };
+        let mut pass = super::PeepholeSubstituteAlternateSyntax::new(false, options);
+        tester::test(&allocator, "x = Infinity", "x = Infinity", &mut pass);
+    }
+
     #[test]
     #[ignore]
     fn test_split_comma_expressions() {
@@ -977,6 +1493,21 @@ mod test {
         test_same("1/x * (y/1) * (1/z)");
     }
 
+    #[test]
+    fn test_numeric_property_key() {
+        test("x = { 1e3: a }", "x = { 1000: a }");
+        test("x = { 0xff: a, 0o17: b, 0b11: c }", "x = { 255: a, 15: b, 3: c }");
+        test("x = { .5: a, 1_000: b }", "x = { 0.5: a, 1000: b }");
+        test("x = { '1000': a, '0.5': b }", "x = { 1000: a, 0.5: b }");
+        test(
+            "class C { 0xff() {} static '10' = 1; get 1e3() {} }",
+            "class C { 255() {} static 10 = 1; get 1000() {} }",
+        );
+        test_same("x = { '01': a, '1e3': b, '-1': c, '.5': d, 'Infinity': e, 'NaN': f }");
+        test_same("x = { ['1000']: a }");
+        test_same("({ '1000': a } = b)");
+    }
+
     #[test]
     fn test_fold_arrow_function_return() {
         test("const foo = () => { return 'baz' }", "const foo = () => 'baz'");
@@ -1,5 +1,7 @@
 use oxc_allocator::Vec;
 use oxc_ast::ast::*;
+use oxc_ecmascript::side_effects::MayHaveSideEffects;
+use oxc_semantic::IsGlobalReference;
 use oxc_span::GetSpan;
 use oxc_traverse::{Traverse, TraverseCtx};
 
@@ -10,6 +12,7 @@ use crate::{CompressOptions, CompressorPass};
 /// * Parenthesized Expression
 /// * `debugger`
 /// * `console.log`
+/// * Calls to `pure_funcs`
 pub struct RemoveSyntax {
     options: CompressOptions,
 }
@@ -25,11 +28,16 @@ impl<'a> CompressorPass<'a> for RemoveSyntax {
 }
 
 impl<'a> Traverse<'a> for RemoveSyntax {
-    fn enter_statements(&mut self, stmts: &mut Vec<'a, Statement<'a>>, _ctx: &mut TraverseCtx<'a>) {
+    fn enter_statements(&mut self, stmts: &mut Vec<'a, Statement<'a>>, ctx: &mut TraverseCtx<'a>) {
+        if !self.options.pure_funcs.is_empty() {
+            for stmt in stmts.iter_mut() {
+                self.drop_pure_funcs(stmt, ctx);
+            }
+        }
         stmts.retain(|stmt| {
             !(matches!(stmt, Statement::EmptyStatement(_))
                 || self.drop_debugger(stmt)
-                || self.drop_console(stmt))
+                || self.drop_console(stmt, ctx))
         });
     }
 
@@ -71,13 +79,13 @@ impl<'a> RemoveSyntax {
     /// Drop `console.*` expressions.
     ///
     /// Enabled by `compress.drop_console
-    fn drop_console(&mut self, stmt: &Statement<'a>) -> bool {
+    fn drop_console(&mut self, stmt: &Statement<'a>, ctx: &TraverseCtx<'a>) -> bool {
         self.options.drop_console
-            && matches!(stmt, Statement::ExpressionStatement(expr) if Self::is_console(&expr.expression))
+            && matches!(stmt, Statement::ExpressionStatement(expr) if Self::is_console(&expr.expression, ctx))
     }
 
     fn compress_console(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
-        if self.options.drop_console && Self::is_console(expr) {
+        if self.options.drop_console && Self::is_console(expr, ctx) {
             *expr = ctx.ast.void_0(expr.span());
         }
     }
@@ -88,13 +96,79 @@ impl<'a> RemoveSyntax {
         }
     }
 
-    fn is_console(expr: &Expression<'_>) -> bool {
-        // let Statement::ExpressionStatement(expr) = stmt else { return false };
+    /// Drop calls to `pure_funcs` whose result is unused, keeping arguments which have side effects.
+    ///
+    /// `assert(foo(), "message")` -> `foo()`
+    ///
+    /// Enabled by `compress.pure_funcs`
+    fn drop_pure_funcs(&self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Statement::ExpressionStatement(expr_stmt) = stmt else { return };
+        let Expression::CallExpression(call_expr) = &mut expr_stmt.expression else { return };
+        if !self.is_pure_func(&call_expr.callee)
+            || call_expr.arguments.iter().any(Argument::is_spread)
+        {
+            return;
+        }
+        let mut expressions = ctx.ast.vec_from_iter(
+            call_expr
+                .arguments
+                .drain(..)
+                .map(Argument::into_expression)
+                .filter(|expr| Self::may_have_side_effects(expr, ctx)),
+        );
+        expr_stmt.expression = match expressions.len() {
+            0 => {
+                *stmt = ctx.ast.statement_empty(stmt.span());
+                return;
+            }
+            1 => expressions.pop().unwrap(),
+            _ => ctx.ast.expression_sequence(call_expr.span, expressions),
+        };
+    }
+
+    /// Unlike `MayHaveSideEffects`, reading a declared binding is side-effect free.
+    fn may_have_side_effects(expr: &Expression<'a>, ctx: &TraverseCtx<'a>) -> bool {
+        match expr {
+            Expression::Identifier(ident) => {
+                ctx.symbols().get_reference(ident.reference_id()).symbol_id().is_none()
+                    && expr.may_have_side_effects()
+            }
+            _ => expr.may_have_side_effects(),
+        }
+    }
+
+    /// Check if `callee` is one of `pure_funcs`, e.g. `assert` or `Math.floor`.
+    fn is_pure_func(&self, callee: &Expression<'a>) -> bool {
+        self.options.pure_funcs.iter().any(|name| Self::is_name(callee, name))
+    }
+
+    /// Check if `expr` is the identifier or static member expression `name`, e.g. `Math.floor`.
+    fn is_name(expr: &Expression<'a>, name: &str) -> bool {
+        match expr {
+            Expression::Identifier(ident) => ident.name == name,
+            Expression::StaticMemberExpression(member_expr) => {
+                name.rsplit_once('.').is_some_and(|(object, property)| {
+                    member_expr.property.name == property
+                        && Self::is_name(&member_expr.object, object)
+                })
+            }
+            _ => false,
+        }
+    }
+
+    /// Check if `expr` is a call to a method of the global `console`,
+    /// e.g. `console.log()`, `window.console.log()` or `globalThis.console.log()`.
+    fn is_console(expr: &Expression<'a>, ctx: &TraverseCtx<'a>) -> bool {
         let Expression::CallExpression(call_expr) = &expr else { return false };
         let Some(member_expr) = call_expr.callee.as_member_expression() else { return false };
         let obj = member_expr.object();
-        let Some(ident) = obj.get_identifier_reference() else { return false };
-        ident.name == "console"
+        if obj.is_global_reference_name("console", ctx.symbols()) {
+            return true;
+        }
+        let Expression::StaticMemberExpression(obj) = obj else { return false };
+        obj.property.name == "console"
+            && (obj.object.is_global_reference_name("window", ctx.symbols())
+                || obj.object.is_global_reference_name("globalThis", ctx.symbols()))
     }
 }
 
@@ -105,8 +179,12 @@ mod test {
     use crate::{tester, CompressOptions};
 
     fn test(source_text: &str, expected: &str) {
+        test_with_options(source_text, expected, CompressOptions::all_true());
+    }
+
+    fn test_with_options(source_text: &str, expected: &str, options: CompressOptions) {
         let allocator = Allocator::default();
-        let mut pass = super::RemoveSyntax::new(CompressOptions::all_true());
+        let mut pass = super::RemoveSyntax::new(options);
         tester::test(&allocator, source_text, expected, &mut pass);
     }
 
@@ -119,6 +197,63 @@ mod test {
     #[test]
     fn drop_console() {
         test("console.log()", "");
+        test("console.log('x'); foo()", "foo()");
+        test("console['log']('x')", "");
+        test("window.console.log('x')", "");
+        test("globalThis.console.warn('x')", "");
+        test("const r = console.log()", "const r = void 0");
+        test("foo(console.error(x))", "foo(void 0)");
+        test("const f = () => console.log()", "const f = () => {}");
+
+        // Shadowed `console` is not the global one
+        test("const console = {}; console.log()", "const console = {}; console.log()");
+        test(
+            "function f(console) { console.log() } console.log()",
+            "function f(console) { console.log() }",
+        );
+        test("const window = {}; window.console.log()", "const window = {}; window.console.log()");
+        test("foo.console.log()", "foo.console.log()");
+        test("console.log", "console.log");
+    }
+
+    #[test]
+    fn drop_console_disabled() {
+        let options = CompressOptions { drop_console: false, ..CompressOptions::all_true() };
+        test_with_options(
+            "console.log(); foo(console.log())",
+            "console.log(); foo(console.log())",
+            options,
+        );
+    }
+
+    #[test]
+    fn pure_funcs() {
+        let options = CompressOptions {
+            pure_funcs: vec!["assert".into(), "Math.floor".into()],
+            ..CompressOptions::all_true()
+        };
+        let test = |source_text: &str, expected: &str| {
+            test_with_options(source_text, expected, options.clone());
+        };
+        test("let x; assert(x, 'message'); foo()", "let x; foo()");
+        test("Math.floor(1.5)", "");
+        // Arguments with side effects are kept
+        test("assert(foo(), 'message')", "foo()");
+        test("assert(foo(), bar())", "foo(), bar()");
+        test("function f(x) { assert(x) }", "function f(x) {}");
+        // Only calls whose result is unused are removed
+        test("const x = Math.floor(1.5)", "const x = Math.floor(1.5)");
+        test("foo(assert(x))", "foo(assert(x))");
+        // Reading a global variable may throw
+        test("assert(x)", "x");
+        // Spread arguments may have side effects
+        test("assert(...args)", "assert(...args)");
+        // Other functions
+        test(
+            "floor(1.5); Math.ceil(1.5); Math.floor.call(null, 1.5)",
+            "floor(1.5); Math.ceil(1.5); Math.floor.call(null, 1.5)",
+        );
+        test("foo.assert(x)", "foo.assert(x)");
     }
 
     #[test]
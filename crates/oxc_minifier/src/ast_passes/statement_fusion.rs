@@ -8,7 +8,10 @@ use crate::CompressorPass;
 
 /// Statement Fusion
 ///
-/// Tries to fuse all the statements in a block into a one statement by using COMMAs or statements.
+/// Tries to fuse consecutive expression statements in a block into one statement by using COMMAs,
+/// and fuses them into the control flow statement which follows them.
+///
+/// Directives (`"use strict"`) are not statements, so they are never joined.
 ///
 /// <https://github.com/google/closure-compiler/blob/master/src/com/google/javascript/jscomp/StatementFusion.java>
 pub struct StatementFusion {
@@ -45,21 +48,82 @@ impl<'a> StatementFusion {
         Self { changed: false }
     }
 
+    /// Join each run of consecutive expression statements into one sequence expression,
+    /// and fuse it into the statement which follows the run, if possible.
+    ///
+    /// `a(); b(); if (c) {} d(); e(); var f;` => `if (a(), b(), c) {} d(), e(); var f;`
     fn fuse_statements(&mut self, stmts: &mut Vec<'a, Statement<'a>>, ctx: &mut TraverseCtx<'a>) {
-        if Self::can_fuse_into_one_statement(stmts) {
-            self.fuse_into_one_statement(stmts, ctx);
+        if !Self::can_fuse_statements(stmts) {
+            return;
+        }
+
+        let mut new_stmts = ctx.ast.vec_with_capacity(stmts.len());
+        // Consecutive expression statements which are not fused yet
+        let mut run = ctx.ast.vec();
+        for mut stmt in stmts.drain(..) {
+            if matches!(stmt, Statement::ExpressionStatement(_)) {
+                run.push(stmt);
+                continue;
+            }
+            if !run.is_empty() {
+                if Self::is_fusable_control_statement(&stmt) {
+                    let exprs = Self::take_expressions(&mut run, ctx);
+                    Self::fuse_expression_into_control_flow_statement(&mut stmt, exprs, ctx);
+                    self.changed = true;
+                } else {
+                    self.flush_run(&mut run, &mut new_stmts, ctx);
+                }
+            }
+            new_stmts.push(stmt);
         }
+        self.flush_run(&mut run, &mut new_stmts, ctx);
+        *stmts = new_stmts;
     }
 
-    fn can_fuse_into_one_statement(stmts: &[Statement<'a>]) -> bool {
-        let len = stmts.len();
-        if len <= 1 {
-            return false;
+    /// Check if there is an expression statement which can be joined with the statement after it.
+    fn can_fuse_statements(stmts: &[Statement<'a>]) -> bool {
+        stmts.windows(2).any(|pair| {
+            matches!(pair[0], Statement::ExpressionStatement(_))
+                && (matches!(pair[1], Statement::ExpressionStatement(_))
+                    || Self::is_fusable_control_statement(&pair[1]))
+        })
+    }
+
+    /// Push the run of expression statements to `new_stmts`, joined into one statement.
+    fn flush_run(
+        &mut self,
+        run: &mut Vec<'a, Statement<'a>>,
+        new_stmts: &mut Vec<'a, Statement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        match run.len() {
+            0 => {}
+            1 => new_stmts.push(run.pop().unwrap()),
+            _ => {
+                let exprs = Self::take_expressions(run, ctx);
+                let expr = ctx.ast.expression_sequence(SPAN, exprs);
+                new_stmts.push(ctx.ast.statement_expression(SPAN, expr));
+                self.changed = true;
+            }
         }
-        if stmts[0..len - 1].iter().any(|s| !matches!(s, Statement::ExpressionStatement(_))) {
-            return false;
+    }
+
+    /// Take the expressions out of expression statements, flattening sequence expressions.
+    fn take_expressions(
+        run: &mut Vec<'a, Statement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Vec<'a, Expression<'a>> {
+        let mut expressions = ctx.ast.vec();
+        for stmt in run.drain(..) {
+            let Statement::ExpressionStatement(expr_stmt) = stmt else { unreachable!() };
+            match expr_stmt.unbox().expression {
+                Expression::SequenceExpression(sequence_expr) => {
+                    expressions.extend(sequence_expr.unbox().expressions);
+                }
+                expr => expressions.push(expr),
+            }
         }
-        Self::is_fusable_control_statement(&stmts[len - 1])
+        expressions
     }
 
     fn is_fusable_control_statement(stmt: &Statement<'a>) -> bool {
@@ -86,41 +150,6 @@ impl<'a> StatementFusion {
         }
     }
 
-    fn fuse_into_one_statement(
-        &mut self,
-        stmts: &mut Vec<'a, Statement<'a>>,
-        ctx: &mut TraverseCtx<'a>,
-    ) {
-        let len = stmts.len();
-        let mut expressions = ctx.ast.vec();
-
-        for stmt in stmts.iter_mut().take(len - 1) {
-            match stmt {
-                Statement::ExpressionStatement(expr_stmt) => {
-                    if let Expression::SequenceExpression(sequence_expr) = &mut expr_stmt.expression
-                    {
-                        expressions.extend(
-                            sequence_expr
-                                .expressions
-                                .iter_mut()
-                                .map(|e| ctx.ast.move_expression(e)),
-                        );
-                    } else {
-                        expressions.push(ctx.ast.move_expression(&mut expr_stmt.expression));
-                    }
-                    *stmt = ctx.ast.statement_empty(SPAN);
-                }
-                _ => unreachable!(),
-            }
-        }
-
-        let last = stmts.last_mut().unwrap();
-        Self::fuse_expression_into_control_flow_statement(last, expressions, ctx);
-
-        *stmts = ctx.ast.vec1(ctx.ast.move_statement(last));
-        self.changed = true;
-    }
-
     fn fuse_expression_into_control_flow_statement(
         stmt: &mut Statement<'a>,
         exprs: Vec<'a, Expression<'a>>,
@@ -234,8 +263,8 @@ mod test {
         fuse("a;b;c;if(x,y){}", "if(a,b,c,x,y){}");
         fuse("a;b;c;if(x,y,z){}", "if(a,b,c,x,y,z){}");
 
-        // Can't fuse if there are statements after the IF.
-        fuse_same("a();if(a()){}a()");
+        // Statements after the IF are not fused into it.
+        fuse("a();if(a()){}a()", "if(a(),a()){}a()");
     }
 
     #[test]
@@ -244,14 +273,15 @@ mod test {
         fuse("a;b;c;return x+y", "return a,b,c,x+y");
 
         // DeadAssignmentElimination would have cleaned it up anyways.
-        fuse_same("a;b;c;return x;a;b;c");
+        fuse("a;b;c;return x;a;b;c", "return a,b,c,x;a,b,c");
+        fuse_same("a;return");
     }
 
     #[test]
     fn fold_block_throw() {
         fuse("a;b;c;throw x", "throw a,b,c,x");
         fuse("a;b;c;throw x+y", "throw a,b,c,x+y");
-        fuse_same("a;b;c;throw x;a;b;c");
+        fuse("a;b;c;throw x;a;b;c", "throw a,b,c,x;a,b,c");
     }
 
     #[test]
@@ -282,9 +312,9 @@ mod test {
 
     #[test]
     fn fuse_into_vanilla_for2() {
-        fuse_same("a;b;c;for(var d;g;){}");
-        fuse_same("a;b;c;for(let d;g;){}");
-        fuse_same("a;b;c;for(const d = 5;g;){}");
+        fuse("a;b;c;for(var d;g;){}", "a,b,c;for(var d;g;){}");
+        fuse("a;b;c;for(let d;g;){}", "a,b,c;for(let d;g;){}");
+        fuse("a;b;c;for(const d = 5;g;){}", "a,b,c;for(const d = 5;g;){}");
     }
 
     #[test]
@@ -292,7 +322,7 @@ mod test {
         // fuse("a;b;c;label:for(x in y){}", "label:for(x in a,b,c,y){}");
         // fuse("a;b;c;label:for(;g;){}", "label:for(a,b,c;g;){}");
         // fuse("a;b;c;l1:l2:l3:for(;g;){}", "l1:l2:l3:for(a,b,c;g;){}");
-        fuse_same("a;b;c;label:while(true){}");
+        fuse("a;b;c;label:while(true){}", "a,b,c;label:while(true){}");
     }
 
     #[test]
@@ -302,18 +332,18 @@ mod test {
             "a;b; label: { if(q) break label; bar(); }",
             "label: { if(a,b,q) break label; bar(); }",
         );
-        fuse_same("a;b;c;{var x;d;e;}");
-        fuse_same("a;b;c;label:{break label;d;e;}");
+        fuse("a;b;c;{var x;d;e;}", "a,b,c;{var x;d,e;}");
+        fuse("a;b;c;label:{break label;d;e;}", "a,b,c;label:{break label;d,e;}");
     }
 
     #[test]
     fn no_fuse_into_while() {
-        fuse_same("a;b;c;while(x){}");
+        fuse("a;b;c;while(x){}", "a,b,c;while(x){}");
     }
 
     #[test]
     fn no_fuse_into_do() {
-        fuse_same("a;b;c;do{}while(x)");
+        fuse("a;b;c;do{}while(x)", "a,b,c;do{}while(x)");
     }
 
     #[test]
@@ -339,6 +369,25 @@ mod test {
         // );
     }
 
+    #[test]
+    fn fuse_separated_by_declarations() {
+        fuse("a;b;var x;c;d", "a,b;var x;c,d");
+        fuse("a;b;let x = 1;c;if(d){}", "a,b;let x = 1;if(c,d){}");
+        fuse("a;function f(){}b;return c", "a;function f(){}return b,c");
+        fuse("a;b;while(x){c;d}e;f", "a,b;while(x){c,d}e,f");
+        fuse_same("a;var x;b");
+    }
+
+    #[test]
+    fn no_fuse_directives() {
+        test("'use strict'; a(); b();", "'use strict'; a(), b();");
+        test(
+            "function f() { 'use strict'; 'use asm'; a(); b(); }",
+            "function f() { 'use strict'; 'use asm'; a(), b(); }",
+        );
+        test_same("function f() { 'use strict'; a() }");
+    }
+
     #[test]
     fn no_global_scope_changes() {
         test_same("a,b,c");
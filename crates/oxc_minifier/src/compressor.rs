@@ -5,9 +5,10 @@ use oxc_traverse::TraverseCtx;
 
 use crate::{
     ast_passes::{
-        CollapseVariableDeclarations, ExploitAssigns, PeepholeFoldConstants,
-        PeepholeMinimizeConditions, PeepholeRemoveDeadCode, PeepholeReplaceKnownMethods,
-        PeepholeSubstituteAlternateSyntax, RemoveSyntax, StatementFusion,
+        CollapseStringAssignments, CollapseVariableDeclarations, ExploitAssigns,
+        PeepholeFoldConstants, PeepholeMinimizeConditions, PeepholeRemoveDeadCode,
+        PeepholeReplaceKnownMethods, PeepholeSubstituteAlternateSyntax, RemoveSyntax,
+        StatementFusion,
     },
     CompressOptions, CompressorPass,
 };
@@ -35,22 +36,28 @@ impl<'a> Compressor<'a> {
         program: &mut Program<'a>,
     ) {
         let mut ctx = TraverseCtx::new(scopes, symbols, self.allocator);
-        RemoveSyntax::new(self.options).build(program, &mut ctx);
+        RemoveSyntax::new(self.options.clone()).build(program, &mut ctx);
 
         if self.options.dead_code_elimination {
-            Self::dead_code_elimination(program, &mut ctx);
+            Self::dead_code_elimination(self.options, program, &mut ctx);
             return;
         }
 
+        // Before `StatementFusion` joins the statements into a sequence expression
+        CollapseStringAssignments::new().build(program, &mut ctx);
+
         // See `latePeepholeOptimizations`
         let mut passes: [&mut dyn CompressorPass; 6] = [
             &mut StatementFusion::new(),
             &mut PeepholeRemoveDeadCode::new(),
             // TODO: MinimizeExitPoints
             &mut PeepholeMinimizeConditions::new(),
-            &mut PeepholeSubstituteAlternateSyntax::new(/* in_fixed_loop */ true),
+            &mut PeepholeSubstituteAlternateSyntax::new(
+                /* in_fixed_loop */ true,
+                self.options.clone(),
+            ),
             &mut PeepholeReplaceKnownMethods::new(),
-            &mut PeepholeFoldConstants::new(),
+            &mut PeepholeFoldConstants::new(self.options.clone()),
         ];
 
         let mut i = 0;
@@ -74,14 +81,21 @@ impl<'a> Compressor<'a> {
 
         // Passes listed in `getFinalization` in `DefaultPassConfig`
         ExploitAssigns::new().build(program, &mut ctx);
-        CollapseVariableDeclarations::new().build(program, &mut ctx);
+        if self.options.join_vars {
+            CollapseVariableDeclarations::new().build(program, &mut ctx);
+        }
 
         // Late latePeepholeOptimizations
-        PeepholeSubstituteAlternateSyntax::new(/* in_fixed_loop */ false).build(program, &mut ctx);
+        PeepholeSubstituteAlternateSyntax::new(/* in_fixed_loop */ false, self.options)
+            .build(program, &mut ctx);
     }
 
-    fn dead_code_elimination(program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
-        PeepholeFoldConstants::new().build(program, ctx);
+    fn dead_code_elimination(
+        options: CompressOptions,
+        program: &mut Program<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        PeepholeFoldConstants::new(options).build(program, ctx);
         PeepholeMinimizeConditions::new().build(program, ctx);
         PeepholeRemoveDeadCode::new().build(program, ctx);
     }
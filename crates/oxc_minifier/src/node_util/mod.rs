@@ -25,6 +25,26 @@ pub fn is_exact_int64(num: f64) -> bool {
     num.fract() == 0.0
 }
 
+/// Check if `expr` always evaluates to a boolean.
+pub fn is_known_boolean(expr: &Expression) -> bool {
+    match expr {
+        Expression::BooleanLiteral(_) => true,
+        Expression::UnaryExpression(e) => {
+            matches!(e.operator, UnaryOperator::LogicalNot | UnaryOperator::Delete)
+        }
+        Expression::BinaryExpression(e) => {
+            e.operator.is_equality() || e.operator.is_compare() || e.operator.is_relational()
+        }
+        Expression::LogicalExpression(e) => is_known_boolean(&e.left) && is_known_boolean(&e.right),
+        Expression::ConditionalExpression(e) => {
+            is_known_boolean(&e.consequent) && is_known_boolean(&e.alternate)
+        }
+        Expression::SequenceExpression(e) => e.expressions.last().is_some_and(is_known_boolean),
+        Expression::ParenthesizedExpression(e) => is_known_boolean(&e.expression),
+        _ => false,
+    }
+}
+
 impl<'a, 'b> Ctx<'a, 'b> {
     fn symbols(&self) -> &SymbolTable {
         self.0.symbols()
@@ -1,4 +1,4 @@
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
 pub struct CompressOptions {
     pub dead_code_elimination: bool,
 
@@ -9,8 +9,61 @@ pub struct CompressOptions {
 
     /// Remove `console.*` statements.
     ///
+    /// Calls used as expressions are replaced with `void 0`.
+    /// `window.console.*` and `globalThis.console.*` are also removed, but not a shadowed `console`.
+    ///
     /// Default `false`
     pub drop_console: bool,
+
+    /// Functions which can be assumed to have no side effects, e.g. `["assert", "Math.floor"]`.
+    ///
+    /// Calls to them whose result is unused are removed, keeping arguments which have side effects:
+    /// `assert(foo(), "message")` is transformed into `foo()`.
+    /// Shadowing is not checked, so a local `assert` is removed too.
+    ///
+    /// Default `[]`
+    pub pure_funcs: Vec<String>,
+
+    /// Join consecutive `var`, `let` and `const` declarations of the same kind,
+    /// and move an assignment following a declaration into its initializer.
+    ///
+    /// `var a; var b = 1; a = 2` => `var a, b = 1; a = 2`, `var a; a = 1` => `var a = 1`
+    ///
+    /// Default `true`
+    pub join_vars: bool,
+
+    /// Transform `while(expr)` into `for(;expr;)`, and `while(true)` into `for(;;)`.
+    ///
+    /// Default `true`
+    pub loops: bool,
+
+    /// Transform `!0` into `true` and `!1` into `false`, instead of the other way around.
+    ///
+    /// This increases size, and is for making previously minified code readable.
+    ///
+    /// Default `false`
+    pub expand_booleans: bool,
+
+    /// Transform `new Array()`, `new Object()` and `new RegExp()` with constant arguments,
+    /// and calls to them without `new`, into literals.
+    ///
+    /// Default `true`
+    pub literal_constructors: bool,
+
+    /// Print numeric literals in their shortest form, e.g. `1000000` as `1e6` and `255` as `0xff`,
+    /// and transform `Infinity` into `1/0`.
+    ///
+    /// Default `true`
+    pub numbers: bool,
+
+    /// Reassociate `+` and `*` chains to fold adjacent numeric constants,
+    /// e.g. `2 * x * 3` => `x * 6`, and `+x + 1 + 2` => `+x + 3`.
+    ///
+    /// This may change results due to floating point rounding.
+    /// `+` chains are only folded when the other operand is known to be a number.
+    ///
+    /// Default `false`
+    pub unsafe_math: bool,
 }
 
 #[allow(clippy::derivable_impls)]
@@ -22,11 +75,35 @@ impl Default for CompressOptions {
 
 impl CompressOptions {
     pub fn all_true() -> Self {
-        Self { dead_code_elimination: false, drop_debugger: true, drop_console: true }
+        Self {
+            dead_code_elimination: false,
+            drop_debugger: true,
+            drop_console: true,
+            pure_funcs: vec![],
+            join_vars: true,
+            loops: true,
+            // Opposite of compressing booleans
+            expand_booleans: false,
+            literal_constructors: true,
+            numbers: true,
+            // Changes floating point results
+            unsafe_math: false,
+        }
     }
 
     pub fn all_false() -> Self {
-        Self { dead_code_elimination: false, drop_debugger: false, drop_console: false }
+        Self {
+            dead_code_elimination: false,
+            drop_debugger: false,
+            drop_console: false,
+            pure_funcs: vec![],
+            join_vars: false,
+            loops: false,
+            expand_booleans: false,
+            literal_constructors: false,
+            numbers: false,
+            unsafe_math: false,
+        }
     }
 
     pub fn dead_code_elimination() -> Self {
@@ -43,7 +43,7 @@ fn dce_if_statement() {
     );
     test(
         "if (xxx) { foo } else if (false) { var a; var b; } else if (false) { var c; var d; }",
-        "if (xxx) foo; else var c, d;",
+        "if (xxx) foo; else { var c, d; var a, b; }",
     );
 
     test("if (!false) { foo }", "foo");
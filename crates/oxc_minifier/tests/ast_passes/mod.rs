@@ -57,3 +57,20 @@ fn tagged_template() {
     test("foo(true && o.f)", "foo(o.f)");
     test("foo(true ? o.f : false)", "foo(o.f)");
 }
+
+#[test]
+fn boolean_cast() {
+    test("if (Boolean(x)) foo()", "if (x) foo()");
+    test("x = Boolean(y) ? true : false", "x = !!y");
+    test("x = Boolean(y)", "x = !!y");
+}
+
+#[test]
+fn join_vars() {
+    test("var a; var b = 1; a = 2;", "var a, b = 1; a = 2;");
+    test("var a; a = 1; var b;", "var a = 1, b;");
+    test_same("let a = 1; const b = 2; var c = 3;");
+
+    let options = CompressOptions { join_vars: false, ..CompressOptions::default() };
+    crate::test("var a; a = 1; var b;", "var a; a = 1; var b;", options);
+}
@@ -2,19 +2,29 @@ use std::fmt::Write;
 
 use oxc_allocator::Allocator;
 use oxc_codegen::CodeGenerator;
-use oxc_mangler::Mangler;
+use oxc_mangler::{MangleOptions, Mangler};
 use oxc_parser::Parser;
 use oxc_span::SourceType;
 
-fn mangle(source_text: &str) -> String {
+fn mangle(source_text: &str, source_type: SourceType, options: MangleOptions) -> String {
     let allocator = Allocator::default();
-    let source_type = SourceType::mjs();
     let ret = Parser::new(&allocator, source_text, source_type).parse();
     let program = ret.program;
-    let mangler = Mangler::new().build(&program);
+    let mangler = Mangler::new().with_options(options).build(&program);
     CodeGenerator::new().with_mangler(Some(mangler)).build(&program).code
 }
 
+fn snapshot(name: &str, cases: &[&str], source_type: SourceType, options: &MangleOptions) {
+    let snapshot = cases.iter().fold(String::new(), |mut w, case| {
+        write!(w, "{case}\n{}\n", mangle(case, source_type, options.clone())).unwrap();
+        w
+    });
+
+    insta::with_settings!({ prepend_module_to_snapshot => false, omit_expression => true }, {
+        insta::assert_snapshot!(name, snapshot);
+    });
+}
+
 #[test]
 fn mangler() {
     let cases = [
@@ -24,14 +34,40 @@ fn mangler() {
         "var x; function foo(a) { ({ x } = y) }",
         "import { x } from 's'; export { x }",
         "function _ (exports) { Object.defineProperty(exports, '__esModule', { value: true }) }",
+        // Nested closures
+        "function foo(first) { return function(second) { return () => first + second } }",
+        "function foo(x) { { let x = 1; x } return function() { let y; return x + y } }",
+        // Top-level declarations in modules are renamed, unless exported
+        "const foo = 1; let bar = foo; export { bar }",
+        "export const foo = 1, bar = 2; export function baz(x) { return x } export class Qux {}",
+        "export default function foo(x) { return foo(x) }",
+        // Direct `eval` can read any name in scope
+        "function foo(x) { function bar(y) { return y } eval('x') }",
+        // Labels, properties and `arguments` are kept
+        "function foo(x) { outer: for (;;) { x.prop = arguments; break outer } }",
     ];
+    snapshot("mangler", &cases, SourceType::mjs(), &MangleOptions::default());
+}
 
-    let snapshot = cases.into_iter().fold(String::new(), |mut w, case| {
-        write!(w, "{case}\n{}\n", mangle(case)).unwrap();
-        w
-    });
+#[test]
+fn script() {
+    let cases = [
+        // Top-level declarations in scripts are global variables
+        "var foo = 1; function bar(x) { return x + foo }",
+        "let foo = 1; class Bar { method(x) { return x } }",
+        // Names of global variables are not used as mangled names
+        "var a; function foo(x) { return a + x }",
+        // `with` can read any name in scope
+        "function foo(x) { with (obj) { x } } function bar(y) { return y }",
+    ];
+    snapshot("mangler_script", &cases, SourceType::cjs(), &MangleOptions::default());
+}
 
-    insta::with_settings!({ prepend_module_to_snapshot => false, omit_expression => true }, {
-        insta::assert_snapshot!("mangler", snapshot);
-    });
+#[test]
+fn reserved() {
+    let cases =
+        ["function foo(keep, other) { return keep + other }", "function foo(x) { return x }"];
+    let options =
+        MangleOptions { reserved: vec!["keep".into(), "a".into()], ..MangleOptions::default() };
+    snapshot("mangler_reserved", &cases, SourceType::mjs(), &options);
 }
@@ -33,10 +33,20 @@ pub struct ImportSpecifier<'a> {
     /// If this import has an import assertion, this is the start value
     pub a: Option<u32>,
 
+    /// Import attributes (`with { type: "json" }` or `assert { type: "json" }`),
+    /// or undefined if there are none, or they are not statically known.
+    pub attributes: Option<Vec<ImportAttribute<'a>>>,
+
     /// If this import is for types only
     pub t: bool,
 }
 
+#[derive(Debug, Clone)]
+pub struct ImportAttribute<'a> {
+    pub key: Atom<'a>,
+    pub value: Atom<'a>,
+}
+
 #[derive(Debug, Clone)]
 pub struct ExportSpecifier<'a> {
     /// Exported name
@@ -57,6 +67,10 @@ pub struct ExportSpecifier<'a> {
     /// End of local name
     pub le: Option<u32>,
 
+    /// Module specifier of a re-export (`export { a } from "foo"`, `export * as ns from "foo"`),
+    /// or undefined for a local export.
+    pub from: Option<Atom<'a>>,
+
     /// If this export is for types only
     pub t: bool,
 }
@@ -116,6 +130,63 @@ impl<'a> ModuleLexer<'a> {
     }
 }
 
+impl<'a> ModuleLexer<'a> {
+    /// `import "foo" with { type: "json" }`
+    fn with_clause_attributes(
+        with_clause: Option<&WithClause<'a>>,
+    ) -> Option<Vec<ImportAttribute<'a>>> {
+        let with_clause = with_clause?;
+        Some(
+            with_clause
+                .with_entries
+                .iter()
+                .map(|entry| ImportAttribute {
+                    key: entry.key.as_atom(),
+                    value: entry.value.value.clone(),
+                })
+                .collect(),
+        )
+    }
+
+    /// `import("foo", { with: { type: "json" } })`
+    ///
+    /// Returns `None` if the attributes are not static.
+    fn import_expression_attributes(
+        expr: &ImportExpression<'a>,
+    ) -> Option<Vec<ImportAttribute<'a>>> {
+        let Some(Expression::ObjectExpression(options)) = expr.arguments.first() else {
+            return None;
+        };
+        let attributes = options.properties.iter().find_map(|property| match property {
+            ObjectPropertyKind::ObjectProperty(property)
+                if !property.computed
+                    && (property.key.is_specific_static_name("with")
+                        || property.key.is_specific_static_name("assert")) =>
+            {
+                Some(&property.value)
+            }
+            _ => None,
+        })?;
+        let Expression::ObjectExpression(attributes) = attributes else { return None };
+        attributes
+            .properties
+            .iter()
+            .map(|property| {
+                let ObjectPropertyKind::ObjectProperty(property) = property else { return None };
+                let key = match &property.key {
+                    PropertyKey::StaticIdentifier(ident) if !property.computed => {
+                        ident.name.clone()
+                    }
+                    PropertyKey::StringLiteral(s) => s.value.clone(),
+                    _ => return None,
+                };
+                let Expression::StringLiteral(value) = &property.value else { return None };
+                Some(ImportAttribute { key, value: value.value.clone() })
+            })
+            .collect()
+    }
+}
+
 impl<'a> Visit<'a> for ModuleLexer<'a> {
     fn visit_statement(&mut self, stmt: &Statement<'a>) {
         if self.facade && !stmt.is_module_declaration() && !stmt.is_declaration() {
@@ -145,6 +216,7 @@ impl<'a> Visit<'a> for ModuleLexer<'a> {
                 se: prop.span.end,
                 d: ImportType::ImportMeta,
                 a: None,
+                attributes: None,
                 t: false,
             });
         }
@@ -168,6 +240,7 @@ impl<'a> Visit<'a> for ModuleLexer<'a> {
             se: expr.span.end,
             d: ImportType::DynamicImport(expr.span.start + 6),
             a: expr.arguments.first().map(|e| e.span().start),
+            attributes: Self::import_expression_attributes(expr),
             t: false,
         });
         walk::walk_import_expression(self, expr);
@@ -190,6 +263,7 @@ impl<'a> Visit<'a> for ModuleLexer<'a> {
             se: impt.span.end,
             d: ImportType::DynamicImport(impt.span.start + 6),
             a: None,
+            attributes: None,
             t: true,
         });
     }
@@ -208,6 +282,7 @@ impl<'a> Visit<'a> for ModuleLexer<'a> {
             se: decl.span.end,
             d: ImportType::StaticImport,
             a: assertions,
+            attributes: Self::with_clause_attributes(decl.with_clause.as_deref()),
             t: decl.import_kind.is_type(),
         });
         walk::walk_import_declaration(self, decl);
@@ -224,6 +299,7 @@ impl<'a> Visit<'a> for ModuleLexer<'a> {
                 se: decl.span.end,
                 d: ImportType::StaticImport,
                 a: None,
+                attributes: Self::with_clause_attributes(decl.with_clause.as_deref()),
                 t: decl.export_kind.is_type(),
             });
         }
@@ -241,6 +317,7 @@ impl<'a> Visit<'a> for ModuleLexer<'a> {
                     e: ident.span.end,
                     ls: None,
                     le: None,
+                    from: None,
                     t: false,
                 });
             });
@@ -261,6 +338,7 @@ impl<'a> Visit<'a> for ModuleLexer<'a> {
                 e: exported_end,
                 ls: Some(s.local.span().start),
                 le: Some(s.local.span().end),
+                from: decl.source.as_ref().map(|source| source.value.clone()),
                 t: decl.export_kind.is_type(),
             }
         }));
@@ -284,6 +362,7 @@ impl<'a> Visit<'a> for ModuleLexer<'a> {
             e: decl.exported.span().end,
             ls: None,
             le: None,
+            from: None,
             t: false,
         });
     }
@@ -301,6 +380,7 @@ impl<'a> Visit<'a> for ModuleLexer<'a> {
                 e,
                 ls: None,
                 le: None,
+                from: Some(decl.source.value.clone()),
                 t: decl.export_kind.is_type(),
             });
             self.imports.push(ImportSpecifier {
@@ -311,6 +391,7 @@ impl<'a> Visit<'a> for ModuleLexer<'a> {
                 se: decl.span.end,
                 d: ImportType::StaticImport,
                 a: None,
+                attributes: Self::with_clause_attributes(decl.with_clause.as_deref()),
                 t: decl.export_kind.is_type(),
             });
         } else {
@@ -323,6 +404,7 @@ impl<'a> Visit<'a> for ModuleLexer<'a> {
                 se: decl.span.end,
                 d: ImportType::ExportStar,
                 a: None,
+                attributes: Self::with_clause_attributes(decl.with_clause.as_deref()),
                 t: decl.export_kind.is_type(),
             });
         }
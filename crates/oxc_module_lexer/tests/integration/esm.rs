@@ -69,6 +69,82 @@ fn named_imports() {
     assert_eq!(source.slice(impt.s, impt.e), "foo");
 }
 
+fn attributes(source: &str) -> Vec<Option<Vec<(String, String)>>> {
+    parse(source).imports.into_iter().map(|i| i.attributes).collect()
+}
+
+fn attribute_list(attributes: &[(&str, &str)]) -> Vec<(String, String)> {
+    attributes.iter().map(|(k, v)| ((*k).to_string(), (*v).to_string())).collect()
+}
+
+#[test]
+fn static_import_attributes() {
+    let source = r#"
+      import json from './foo.json' with { type: 'json' };
+      import sheet from './foo.css' assert { type: "css" };
+      import './bar.js' with { type: 'json', 'other-key': 'x' };
+      export { a } from './a.json' with { type: 'json' };
+      export * from './b.json' with { type: 'json' };
+      import empty from './empty.js' with {};
+      import none from './none.js';
+    "#;
+    assert_eq!(
+        attributes(source),
+        [
+            Some(attribute_list(&[("type", "json")])),
+            Some(attribute_list(&[("type", "css")])),
+            Some(attribute_list(&[("type", "json"), ("other-key", "x")])),
+            Some(attribute_list(&[("type", "json")])),
+            Some(attribute_list(&[("type", "json")])),
+            Some(attribute_list(&[])),
+            None,
+        ]
+    );
+}
+
+#[test]
+fn dynamic_import_attributes() {
+    let source = r#"
+      import('./foo.json', { with: { type: 'json' } });
+      import('./foo.css', { assert: { "type": 'css' } });
+      import('./foo.json', { with: { type: dynamic } });
+      import('./foo.json', options);
+      import('./foo.js');
+    "#;
+    assert_eq!(
+        attributes(source),
+        [
+            Some(attribute_list(&[("type", "json")])),
+            Some(attribute_list(&[("type", "css")])),
+            None,
+            None,
+            None
+        ]
+    );
+}
+
+#[test]
+fn re_export_from() {
+    let source = r"
+      export { a, b as c, default as d } from './a';
+      export * as ns from './b';
+      export * from './c';
+      export { e };
+    ";
+    let ModuleLexer { exports, .. } = parse(source);
+    let exports = exports.iter().map(|e| (e.n.as_str(), e.from.as_deref())).collect::<Vec<_>>();
+    assert_eq!(
+        exports,
+        [
+            ("a", Some("./a")),
+            ("c", Some("./a")),
+            ("d", Some("./a")),
+            ("ns", Some("./b")),
+            ("e", None),
+        ]
+    );
+}
+
 #[test]
 fn export_star_from() {
     let source = "export * from 'foo'";
@@ -23,6 +23,7 @@ pub struct ImportSpecifier {
     pub se: u32,
     pub d: ImportType,
     pub a: Option<u32>,
+    pub attributes: Option<Vec<(String, String)>>,
     pub t: bool,
 }
 
@@ -36,6 +37,9 @@ impl From<oxc_module_lexer::ImportSpecifier<'_>> for ImportSpecifier {
             se: value.se,
             d: value.d,
             a: value.a,
+            attributes: value.attributes.map(|attributes| {
+                attributes.into_iter().map(|a| (a.key.to_string(), a.value.to_string())).collect()
+            }),
             t: value.t,
         }
     }
@@ -49,6 +53,7 @@ pub struct ExportSpecifier {
     pub e: u32,
     pub ls: Option<u32>,
     pub le: Option<u32>,
+    pub from: Option<String>,
     pub t: bool,
 }
 
@@ -61,6 +66,7 @@ impl From<oxc_module_lexer::ExportSpecifier<'_>> for ExportSpecifier {
             e: value.e,
             ls: value.ls,
             le: value.le,
+            from: value.from.map(|from| from.to_string()),
             t: value.t,
         }
     }
@@ -781,7 +781,7 @@ impl<'a> ParserImpl<'a> {
             self.error(diagnostics::new_optional_chain(span));
         }
 
-        Ok(self.ast.expression_new(span, callee, arguments, type_parameter))
+        Ok(self.ast.expression_new(span, callee, arguments, type_parameter, false))
     }
 
     /// Section 13.3 Call Expression
@@ -851,6 +851,7 @@ impl<'a> ParserImpl<'a> {
             type_parameters,
             call_arguments,
             optional,
+            false,
         ))
     }
 
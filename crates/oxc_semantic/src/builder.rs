@@ -1771,6 +1771,13 @@ impl<'a> SemanticBuilder<'a> {
                 } {
                     self.current_symbol_flags |= SymbolFlags::Export;
                 }
+                // export default a;
+                //                ^ can reference value or type in TypeScript
+                if self.source_type.is_typescript()
+                    && matches!(decl.declaration, ExportDefaultDeclarationKind::Identifier(_))
+                {
+                    self.current_reference_flags = ReferenceFlags::Read | ReferenceFlags::Type;
+                }
             }
             AstKind::ExportNamedDeclaration(decl) => {
                 self.current_symbol_flags |= SymbolFlags::Export;
@@ -2005,6 +2012,7 @@ impl<'a> SemanticBuilder<'a> {
                 self.current_reference_flags -= ReferenceFlags::Write;
             }
             AstKind::AssignmentExpression(_) | AstKind::ExportNamedDeclaration(_)
+            | AstKind::ExportDefaultDeclaration(_)
             | AstKind::TSTypeQuery(_)
             // Clear the reference flags that are set in AstKind::PropertySignature
             | AstKind::PropertyKey(_) => {
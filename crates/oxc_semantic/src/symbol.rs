@@ -187,6 +187,17 @@ impl SymbolTable {
         };
     }
 
+    /// Remove a redeclaration of a symbol, e.g. when the redeclaring declaration is removed.
+    pub fn remove_redeclaration(&mut self, symbol_id: SymbolId, span: Span) {
+        if let Some(redeclaration_id) = self.redeclarations[symbol_id] {
+            let spans = &mut self.redeclaration_spans[redeclaration_id];
+            spans.retain(|&redeclaration| redeclaration != span);
+            if spans.is_empty() {
+                self.redeclarations[symbol_id] = None;
+            }
+        }
+    }
+
     pub fn create_reference(&mut self, reference: Reference) -> ReferenceId {
         self.references.push(reference)
     }
@@ -90,15 +90,19 @@
 use oxc_allocator::{Box as ArenaBox, String as ArenaString, Vec as ArenaVec};
 use oxc_ast::{ast::*, AstBuilder, NONE};
 use oxc_data_structures::stack::SparseStack;
-use oxc_span::SPAN;
+use oxc_span::{GetSpan, SPAN};
 use oxc_syntax::{
+    reference::ReferenceFlags,
     scope::{ScopeFlags, ScopeId},
     symbol::SymbolFlags,
 };
 use oxc_traverse::{Ancestor, BoundIdentifier, Traverse, TraverseCtx};
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet};
 
-use crate::EnvOptions;
+use crate::{
+    common::{function_name, helper_loader::Helper},
+    EnvOptions, TransformCtx,
+};
 
 /// Mode for arrow function conversion
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -122,14 +126,27 @@ struct SuperMethodInfo<'a> {
     is_assignment: bool,
 }
 
-pub struct ArrowFunctionConverter<'a> {
+pub struct ArrowFunctionConverter<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
     mode: ArrowFunctionConverterMode,
+    /// `spec` option of the arrow functions plugin.
+    /// Converted functions are bound to `this` and checked with the `newArrowCheck` helper.
+    spec: bool,
+    /// Bodies of async methods are moved into a generator function by the `AsyncToGenerator` plugin.
+    async_to_generator: bool,
+    /// Bodies of async generator methods are moved into a generator function by the
+    /// `AsyncGeneratorFunctions` plugin.
+    async_generator_functions: bool,
     this_var_stack: SparseStack<BoundIdentifier<'a>>,
+    arguments_var_stack: SparseStack<BoundIdentifier<'a>>,
     super_methods: Option<FxHashMap<Atom<'a>, SuperMethodInfo<'a>>>,
+    /// Arrow functions in class property values which use `this`.
+    /// There is no function to insert `var _this = this;` into, so they are bound to `this` instead.
+    arrows_to_bind: FxHashSet<ScopeId>,
 }
 
-impl<'a> ArrowFunctionConverter<'a> {
-    pub fn new(env: &EnvOptions) -> Self {
+impl<'a, 'ctx> ArrowFunctionConverter<'a, 'ctx> {
+    pub fn new(env: &EnvOptions, ctx: &'ctx TransformCtx<'a>) -> Self {
         let mode = if env.es2015.arrow_function.is_some() {
             ArrowFunctionConverterMode::Enabled
         } else if env.es2017.async_to_generator || env.es2018.async_generator_functions {
@@ -137,12 +154,23 @@ impl<'a> ArrowFunctionConverter<'a> {
         } else {
             ArrowFunctionConverterMode::Disabled
         };
+        let spec = env.es2015.arrow_function.is_some_and(|options| options.spec);
         // `SparseStack` is created with 1 empty entry, for `Program`
-        Self { mode, this_var_stack: SparseStack::new(), super_methods: None }
+        Self {
+            ctx,
+            mode,
+            spec,
+            async_to_generator: env.es2017.async_to_generator,
+            async_generator_functions: env.es2018.async_generator_functions,
+            this_var_stack: SparseStack::new(),
+            arguments_var_stack: SparseStack::new(),
+            super_methods: None,
+            arrows_to_bind: FxHashSet::default(),
+        }
     }
 }
 
-impl<'a> Traverse<'a> for ArrowFunctionConverter<'a> {
+impl<'a, 'ctx> Traverse<'a> for ArrowFunctionConverter<'a, 'ctx> {
     // Note: No visitors for `TSModuleBlock` because `this` is not legal in TS module blocks.
     // <https://www.typescriptlang.org/play/?#code/HYQwtgpgzgDiDGEAEAxA9mpBvAsAKCSXjWCgBckANJAXiQAoBKWgPiTIAsBLKAbnwC++fGDQATAK4AbZACEQAJ2z5CxUhWp0mrdtz6D8QA>
 
@@ -153,14 +181,18 @@ impl<'a> Traverse<'a> for ArrowFunctionConverter<'a> {
         }
 
         let this_var = self.this_var_stack.take_last();
+        let arguments_var = self.arguments_var_stack.take_last();
         self.insert_variable_statement_at_the_top_of_statements(
             program.scope_id(),
             &mut program.body,
             this_var,
+            arguments_var,
             ctx,
         );
         debug_assert!(self.this_var_stack.len() == 1);
         debug_assert!(self.this_var_stack.last().is_none());
+        debug_assert!(self.arguments_var_stack.len() == 1);
+        debug_assert!(self.arguments_var_stack.last().is_none());
     }
 
     fn enter_function(&mut self, func: &mut Function<'a>, ctx: &mut TraverseCtx<'a>) {
@@ -169,7 +201,9 @@ impl<'a> Traverse<'a> for ArrowFunctionConverter<'a> {
         }
 
         self.this_var_stack.push(None);
-        if self.is_async_only() && func.r#async && Self::is_class_method_like_ancestor(ctx.parent())
+        self.arguments_var_stack.push(None);
+        if self.is_transformed_async_method(func.r#async, func.generator)
+            && Self::is_class_method_like_ancestor(ctx.parent())
         {
             self.super_methods = Some(FxHashMap::default());
         }
@@ -196,10 +230,12 @@ impl<'a> Traverse<'a> for ArrowFunctionConverter<'a> {
             return;
         };
         let this_var = self.this_var_stack.pop();
+        let arguments_var = self.arguments_var_stack.pop();
         self.insert_variable_statement_at_the_top_of_statements(
             scope_id,
             &mut body.statements,
             this_var,
+            arguments_var,
             ctx,
         );
     }
@@ -210,6 +246,7 @@ impl<'a> Traverse<'a> for ArrowFunctionConverter<'a> {
         }
 
         self.this_var_stack.push(None);
+        self.arguments_var_stack.push(None);
     }
 
     fn exit_static_block(&mut self, block: &mut StaticBlock<'a>, ctx: &mut TraverseCtx<'a>) {
@@ -218,10 +255,12 @@ impl<'a> Traverse<'a> for ArrowFunctionConverter<'a> {
         }
 
         let this_var = self.this_var_stack.pop();
+        let arguments_var = self.arguments_var_stack.pop();
         self.insert_variable_statement_at_the_top_of_statements(
             block.scope_id(),
             &mut block.body,
             this_var,
+            arguments_var,
             ctx,
         );
     }
@@ -267,6 +306,9 @@ impl<'a> Traverse<'a> for ArrowFunctionConverter<'a> {
             Expression::ThisExpression(this) => {
                 self.get_this_identifier(this.span, ctx).map(Expression::Identifier)
             }
+            Expression::Identifier(ident) => {
+                self.get_arguments_identifier(ident, ctx).map(Expression::Identifier)
+            }
             Expression::CallExpression(call) => self.transform_call_expression_for_super(call, ctx),
             Expression::AssignmentExpression(assignment) => {
                 self.transform_assignment_expression_for_super(assignment, ctx)
@@ -298,12 +340,30 @@ impl<'a> Traverse<'a> for ArrowFunctionConverter<'a> {
                 unreachable!()
             };
 
+            let scope_id = arrow_function_expr.scope_id();
             *expr = Self::transform_arrow_function_expression(arrow_function_expr, ctx);
+            let is_bound = if self.is_spec() {
+                *expr = self.bind_and_check_function_expression(ctx.ast.move_expression(expr), ctx);
+                true
+            } else if self.arrows_to_bind.remove(&scope_id) {
+                *expr = Self::bind_this(ctx.ast.move_expression(expr), ctx);
+                true
+            } else {
+                false
+            };
+
+            // Bound function is named `"bound "`, instead of the name inferred for the arrow function
+            if is_bound && self.ctx.keep_names {
+                if let Some(name) = function_name::infer_function_name(ctx) {
+                    let bound = ctx.ast.move_expression(expr);
+                    *expr = function_name::create_set_function_name(bound, name, ctx);
+                }
+            }
         }
     }
 }
 
-impl<'a> ArrowFunctionConverter<'a> {
+impl<'a, 'ctx> ArrowFunctionConverter<'a, 'ctx> {
     /// Check if arrow function conversion is disabled
     fn is_disabled(&self) -> bool {
         self.mode == ArrowFunctionConverterMode::Disabled
@@ -314,36 +374,131 @@ impl<'a> ArrowFunctionConverter<'a> {
         self.mode == ArrowFunctionConverterMode::AsyncOnly
     }
 
+    /// Check if the body of an async function is moved into a new generator function
+    /// by the `AsyncToGenerator` or `AsyncGeneratorFunctions` plugin.
+    fn is_transformed_async_method(&self, r#async: bool, generator: bool) -> bool {
+        r#async && if generator { self.async_generator_functions } else { self.async_to_generator }
+    }
+
+    /// Check if converted arrow functions should be bound to `this` rather than renaming `this`
+    fn is_spec(&self) -> bool {
+        self.spec && self.mode == ArrowFunctionConverterMode::Enabled
+    }
+
     fn get_this_identifier(
         &mut self,
         span: Span,
         ctx: &mut TraverseCtx<'a>,
     ) -> Option<ArenaBox<'a, IdentifierReference<'a>>> {
+        // In spec mode, converted functions are bound to `this`, so `this` can stay as it is.
+        if self.is_spec() {
+            return None;
+        }
+
         // Find arrow function we are currently in (if we are)
         let arrow_scope_id = self.get_scope_id_from_this_affected_block(ctx)?;
 
+        // `class C { x = () => this }` -> `class C { x = function() { return this; }.bind(this) }`
+        if self.mode == ArrowFunctionConverterMode::Enabled && self.mark_arrows_to_bind(ctx) {
+            return None;
+        }
+
         // TODO(improve-on-babel): We create a new UID for every scope. This is pointless, as only one
         // `this` can be in scope at a time. We could create a single `_this` UID and reuse it in each
         // scope. But this does not match output for some of Babel's test cases.
         // <https://github.com/oxc-project/oxc/pull/5840>
         let this_var = self.this_var_stack.last_or_init(|| {
-            let target_scope_id = ctx
-                .scopes()
-                .ancestors(arrow_scope_id)
-                // Skip arrow function scope
-                .skip(1)
-                .find(|&scope_id| {
-                    let scope_flags = ctx.scopes().get_flags(scope_id);
-                    scope_flags.intersects(
-                        ScopeFlags::Function | ScopeFlags::Top | ScopeFlags::ClassStaticBlock,
-                    ) && !scope_flags.contains(ScopeFlags::Arrow)
-                })
-                .unwrap();
+            let target_scope_id = Self::get_this_target_scope_id(arrow_scope_id, ctx);
             ctx.generate_uid("this", target_scope_id, SymbolFlags::FunctionScopedVariable)
         });
         Some(ctx.ast.alloc(this_var.create_spanned_read_reference(span, ctx)))
     }
 
+    /// Replace `arguments` inside arrow functions with `_arguments`,
+    /// and insert `var _arguments = arguments;` into the nearest non-arrow function.
+    ///
+    /// ```js
+    /// function a() {
+    ///   return () => arguments;
+    /// }
+    /// // to
+    /// function a() {
+    ///   var _arguments = arguments;
+    ///   return function() { return _arguments; };
+    /// }
+    /// ```
+    fn get_arguments_identifier(
+        &mut self,
+        ident: &IdentifierReference<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<ArenaBox<'a, IdentifierReference<'a>>> {
+        if ident.name != "arguments" {
+            return None;
+        }
+
+        // `arguments` is declared by the user, e.g. `function foo(arguments) {}`
+        let reference = ctx.symbols().get_reference(ident.reference_id());
+        if reference.symbol_id().is_some() {
+            return None;
+        }
+
+        let arrow_scope_id = self.get_scope_id_from_this_affected_block(ctx)?;
+        let arguments_var = self.arguments_var_stack.last_or_init(|| {
+            let target_scope_id = Self::get_this_target_scope_id(arrow_scope_id, ctx);
+            ctx.generate_uid("arguments", target_scope_id, SymbolFlags::FunctionScopedVariable)
+        });
+        ctx.delete_reference_for_identifier(ident);
+        Some(ctx.ast.alloc(arguments_var.create_spanned_read_reference(ident.span, ctx)))
+    }
+
+    /// If `this` is inherited from a class property value, mark all arrow functions
+    /// it is nested in to be bound to `this`, and return `true`.
+    ///
+    /// ```js
+    /// class C {
+    ///   x = () => () => this;
+    /// }
+    /// ```
+    fn mark_arrows_to_bind(&mut self, ctx: &TraverseCtx<'a>) -> bool {
+        let mut arrow_scope_ids = vec![];
+        for ancestor in ctx.ancestors() {
+            match ancestor {
+                Ancestor::ArrowFunctionExpressionParams(func) => {
+                    arrow_scope_ids.push(func.scope_id().get().unwrap());
+                }
+                Ancestor::ArrowFunctionExpressionBody(func) => {
+                    arrow_scope_ids.push(func.scope_id().get().unwrap());
+                }
+                Ancestor::PropertyDefinitionValue(_) | Ancestor::AccessorPropertyValue(_) => {
+                    self.arrows_to_bind.extend(arrow_scope_ids);
+                    return true;
+                }
+                Ancestor::ProgramBody(_)
+                | Ancestor::FunctionParams(_)
+                | Ancestor::FunctionBody(_)
+                | Ancestor::StaticBlockBody(_) => return false,
+                _ => {}
+            }
+        }
+        unreachable!();
+    }
+
+    /// Find the nearest non-arrow function scope (or program / class static block scope)
+    /// at or above `scope_id`, which `this` and `arguments` are inherited from.
+    ///
+    /// Arrow function scopes are skipped, so this can be passed an arrow function's own scope.
+    fn get_this_target_scope_id(scope_id: ScopeId, ctx: &TraverseCtx<'a>) -> ScopeId {
+        ctx.scopes()
+            .ancestors(scope_id)
+            .find(|&scope_id| {
+                let scope_flags = ctx.scopes().get_flags(scope_id);
+                scope_flags.intersects(
+                    ScopeFlags::Function | ScopeFlags::Top | ScopeFlags::ClassStaticBlock,
+                ) && !scope_flags.contains(ScopeFlags::Arrow)
+            })
+            .unwrap()
+    }
+
     /// Traverses upward through ancestor nodes to find the `ScopeId` of the block
     /// that potential affects the `this` expression.
     fn get_scope_id_from_this_affected_block(&self, ctx: &mut TraverseCtx<'a>) -> Option<ScopeId> {
@@ -420,14 +575,13 @@ impl<'a> ArrowFunctionConverter<'a> {
                 }
                 // Function body (includes class method or object method)
                 Ancestor::FunctionBody(func) => {
-                    // If we're inside a class async method or an object async method, and `is_async_only` is true,
+                    // If we're inside a class async method or an object async method,
                     // the `AsyncToGenerator` or `AsyncGeneratorFunctions` plugin will move the body
                     // of the method into a new generator function. This transformation can cause `this`
                     // to point to the wrong context.
                     // To prevent this issue, we replace `this` with `_this`, treating it similarly
                     // to how we handle arrow functions. Therefore, we return the `ScopeId` of the function.
-                    return if self.is_async_only()
-                    && *func.r#async()
+                    return if self.is_transformed_async_method(*func.r#async(), *func.generator())
                     && Self::is_class_method_like_ancestor(
                         ancestors.next().unwrap()
                     ) {
@@ -478,6 +632,64 @@ impl<'a> ArrowFunctionConverter<'a> {
         ))
     }
 
+    /// Wrap the converted function in `.bind(this)` and add a `newArrowCheck` call to its body.
+    ///
+    /// ```js
+    /// () => this
+    /// // to
+    /// var _this = this;
+    /// (function() {
+    ///   babelHelpers.newArrowCheck(this, _this);
+    ///   return this;
+    /// }).bind(this);
+    /// ```
+    ///
+    /// The check is omitted where there is no enclosing function body for `var _this = this;`
+    /// to be inserted into, i.e. function params and class property values.
+    fn bind_and_check_function_expression(
+        &mut self,
+        mut expr: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let has_this_binding = ctx.ancestors().find_map(|ancestor| match ancestor {
+            Ancestor::ProgramBody(_) | Ancestor::FunctionBody(_) | Ancestor::StaticBlockBody(_) => {
+                Some(true)
+            }
+            Ancestor::FunctionParams(_)
+            | Ancestor::PropertyDefinitionValue(_)
+            | Ancestor::AccessorPropertyValue(_) => Some(false),
+            _ => None,
+        });
+
+        if has_this_binding == Some(true) {
+            let this_var = self.this_var_stack.last_or_init(|| {
+                let target_scope_id = Self::get_this_target_scope_id(ctx.current_scope_id(), ctx);
+                ctx.generate_uid("this", target_scope_id, SymbolFlags::FunctionScopedVariable)
+            });
+            let mut arguments = ctx.ast.vec_with_capacity(2);
+            arguments.push(Argument::from(ctx.ast.expression_this(SPAN)));
+            arguments.push(Argument::from(this_var.create_read_expression(ctx)));
+            let check = self.ctx.helper_call_expr(Helper::NewArrowCheck, arguments, ctx);
+            let Expression::FunctionExpression(func) = &mut expr else { unreachable!() };
+            // Map the check to the arrow function it was added for
+            let span = func.span;
+            let body = func.body.as_mut().unwrap();
+            body.statements.insert(0, ctx.ast.statement_expression(span, check));
+        }
+
+        Self::bind_this(expr, ctx)
+    }
+
+    /// `function() {}` -> `function() {}.bind(this)`
+    fn bind_this(expr: Expression<'a>, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        let span = expr.span();
+        let property = ctx.ast.identifier_name(SPAN, "bind");
+        let callee =
+            Expression::from(ctx.ast.member_expression_static(span, expr, property, false));
+        let arguments = ctx.ast.vec1(Argument::from(ctx.ast.expression_this(SPAN)));
+        ctx.ast.expression_call(span, callee, NONE, arguments, false, false)
+    }
+
     /// Check whether the given [`Ancestor`] is a class method-like node.
     fn is_class_method_like_ancestor(ancestor: Ancestor) -> bool {
         match ancestor {
@@ -583,7 +795,7 @@ impl<'a> ArrowFunctionConverter<'a> {
         if let Some(assign_value) = assign_value {
             arguments.push(Argument::from(ctx.ast.move_expression(assign_value)));
         }
-        let call = ctx.ast.expression_call(SPAN, callee, NONE, arguments, false);
+        let call = ctx.ast.expression_call(SPAN, callee, NONE, arguments, false, false);
         Some(call)
     }
 
@@ -623,7 +835,7 @@ impl<'a> ArrowFunctionConverter<'a> {
         let property = ctx.ast.identifier_name(SPAN, "call");
         let callee = ctx.ast.member_expression_static(SPAN, object, property, false);
         let callee = Expression::from(callee);
-        Some(ctx.ast.expression_call(SPAN, callee, NONE, arguments, false))
+        Some(ctx.ast.expression_call(SPAN, callee, NONE, arguments, false, false))
     }
 
     /// Transform an `AssignmentExpression` whose assignment target is a `super` member expression.
@@ -787,12 +999,47 @@ impl<'a> ArrowFunctionConverter<'a> {
         ast.atom(name.into_bump_str())
     }
 
+    /// Create the initializer for `var _arguments = ...;`.
+    ///
+    /// `arguments` is not defined at the top level of a program, so guard against that:
+    /// `typeof arguments === "undefined" ? void 0 : arguments`
+    fn create_arguments_var_init(
+        target_scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let create_arguments = |ctx: &mut TraverseCtx<'a>| {
+            let ident = ctx.create_unbound_reference_id(
+                SPAN,
+                Atom::from("arguments"),
+                ReferenceFlags::Read,
+            );
+            Expression::Identifier(ctx.alloc(ident))
+        };
+
+        let arguments = create_arguments(ctx);
+        if !ctx.scopes().get_flags(target_scope_id).is_top() {
+            return arguments;
+        }
+
+        let typeof_arguments = ctx.ast.expression_unary(SPAN, UnaryOperator::Typeof, arguments);
+        let test = ctx.ast.expression_binary(
+            SPAN,
+            typeof_arguments,
+            BinaryOperator::StrictEquality,
+            ctx.ast.expression_string_literal(SPAN, "undefined"),
+        );
+        let consequent = ctx.ast.void_0(SPAN);
+        let alternate = create_arguments(ctx);
+        ctx.ast.expression_conditional(SPAN, test, consequent, alternate)
+    }
+
     /// Insert variable statement at the top of the statements.
     fn insert_variable_statement_at_the_top_of_statements(
         &mut self,
         target_scope_id: ScopeId,
         statements: &mut ArenaVec<'a, Statement<'a>>,
         this_var: Option<BoundIdentifier<'a>>,
+        arguments_var: Option<BoundIdentifier<'a>>,
         ctx: &mut TraverseCtx<'a>,
     ) {
         // `_superprop_getSomething = () => super.getSomething;`
@@ -823,6 +1070,20 @@ impl<'a> ArrowFunctionConverter<'a> {
             declarations.push(variable_declarator);
         }
 
+        // `_arguments = arguments;`
+        if let Some(arguments_var) = arguments_var {
+            Self::adjust_binding_scope(target_scope_id, &arguments_var, ctx);
+            let init = Self::create_arguments_var_init(target_scope_id, ctx);
+            let variable_declarator = ctx.ast.variable_declarator(
+                SPAN,
+                VariableDeclarationKind::Var,
+                arguments_var.create_binding_pattern(ctx),
+                Some(init),
+                false,
+            );
+            declarations.push(variable_declarator);
+        }
+
         // If there are no declarations, we don't need to insert a variable declaration.
         if declarations.is_empty() {
             return;
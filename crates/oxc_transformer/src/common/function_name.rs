@@ -0,0 +1,112 @@
+//! Utilities for `keep_names` option.
+//!
+//! An anonymous function or class gets its `name` from where it is defined:
+//! `const f = () => {}` has the name `"f"`, and `export default class {}` has the name `"default"`.
+//! Transforms which wrap a function (`function() {}.bind(this)`), or replace a class with a
+//! function with a different name, lose this name.
+//!
+//! With `keep_names` enabled, those transforms set the original name explicitly:
+//!
+//! ```js
+//! Object.defineProperty(function() {}.bind(this), "name", { value: "f" })
+//! ```
+//!
+//! ## References:
+//! * NamedEvaluation specification: <https://tc39.es/ecma262/#sec-runtime-semantics-namedevaluation>
+
+use oxc_ast::{ast::*, NONE};
+use oxc_semantic::ReferenceFlags;
+use oxc_span::SPAN;
+use oxc_syntax::operator::AssignmentOperator;
+use oxc_traverse::{Ancestor, TraverseCtx};
+
+/// Get the name an anonymous function or class gets from its position,
+/// when it is the expression currently being visited.
+///
+/// Returns `None` if the name is not known statically, or it gets no name.
+pub fn infer_function_name<'a>(ctx: &TraverseCtx<'a>) -> Option<Atom<'a>> {
+    match ctx.parent() {
+        // `const f = () => {}`
+        Ancestor::VariableDeclaratorInit(declarator) => {
+            declarator.id().get_binding_identifier().map(|ident| ident.name.clone())
+        }
+        // `f = () => {}`, `f ||= () => {}`
+        Ancestor::AssignmentExpressionRight(assign) => match assign.left() {
+            AssignmentTarget::AssignmentTargetIdentifier(ident)
+                if matches!(
+                    assign.operator(),
+                    AssignmentOperator::Assign
+                        | AssignmentOperator::LogicalAnd
+                        | AssignmentOperator::LogicalOr
+                        | AssignmentOperator::LogicalNullish
+                ) =>
+            {
+                Some(ident.name.clone())
+            }
+            _ => None,
+        },
+        // `function g(f = () => {}) {}`, `const { f = () => {} } = obj`
+        Ancestor::AssignmentPatternRight(pattern) => {
+            pattern.left().get_binding_identifier().map(|ident| ident.name.clone())
+        }
+        // `[f = () => {}] = arr`
+        Ancestor::AssignmentTargetWithDefaultInit(target) => match target.binding() {
+            AssignmentTarget::AssignmentTargetIdentifier(ident) => Some(ident.name.clone()),
+            _ => None,
+        },
+        // `({ f = () => {} } = obj)`
+        Ancestor::AssignmentTargetPropertyIdentifierInit(property) => {
+            Some(property.binding().name.clone())
+        }
+        // `({ f: () => {} })`
+        Ancestor::ObjectPropertyValue(property)
+            if *property.kind() == PropertyKind::Init && !*property.method() =>
+        {
+            property_key_name(property.key(), ctx)
+        }
+        // `class C { f = () => {} }`
+        Ancestor::PropertyDefinitionValue(property) => property_key_name(property.key(), ctx),
+        // `export default () => {}`
+        Ancestor::ExportDefaultDeclarationDeclaration(_) => Some(Atom::from("default")),
+        _ => None,
+    }
+}
+
+/// Name of a function defined with property key `key`, if key is known statically.
+fn property_key_name<'a>(key: &PropertyKey<'a>, ctx: &TraverseCtx<'a>) -> Option<Atom<'a>> {
+    match key {
+        PropertyKey::PrivateIdentifier(ident) => Some(ctx.ast.atom(&format!("#{}", ident.name))),
+        key => key.static_name().map(|name| ctx.ast.atom(&name)),
+    }
+}
+
+/// `Object.defineProperty(expr, "name", { value: "f" })`
+pub fn create_set_function_name<'a>(
+    expr: Expression<'a>,
+    name: Atom<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) -> Expression<'a> {
+    let symbol_id = ctx.scopes().find_binding(ctx.current_scope_id(), "Object");
+    let ident =
+        ctx.create_reference_id(SPAN, Atom::from("Object"), symbol_id, ReferenceFlags::Read);
+    let object = Expression::Identifier(ctx.alloc(ident));
+    let property = ctx.ast.identifier_name(SPAN, "defineProperty");
+    let callee = Expression::from(ctx.ast.member_expression_static(SPAN, object, property, false));
+
+    let value = ctx.ast.object_property_kind_object_property(
+        SPAN,
+        PropertyKind::Init,
+        ctx.ast.property_key_identifier_name(SPAN, "value"),
+        ctx.ast.expression_string_literal(SPAN, name),
+        false,
+        false,
+        false,
+    );
+    let descriptor = ctx.ast.expression_object(SPAN, ctx.ast.vec1(value), None);
+    let arguments = ctx.ast.vec_from_iter([
+        Argument::from(expr),
+        Argument::from(ctx.ast.expression_string_literal(SPAN, "name")),
+        Argument::from(descriptor),
+    ]);
+    ctx.ast.expression_call(SPAN, callee, NONE, arguments, false, false)
+}
@@ -0,0 +1,201 @@
+//! Utility to reference the global object.
+//!
+//! `globalThis` is not available in older environments, so the global object is detected with:
+//!
+//! ```js
+//! var _global = typeof globalThis !== "undefined" ? globalThis
+//!   : typeof self !== "undefined" ? self
+//!   : typeof window !== "undefined" ? window
+//!   : typeof global !== "undefined" ? global
+//!   : Function("return this")();
+//! ```
+//!
+//! The `var` is created on first use and inserted at top of program via `TopLevelStatementsStore`.
+//! All later uses reference the same binding.
+//!
+//! Transforms get a reference to the global object with `TransformCtx::global_object`:
+//!
+//! ```rs
+//! let global = self.ctx.global_object(ctx);
+//! ```
+
+use std::cell::RefCell;
+
+use oxc_ast::{ast::*, NONE};
+use oxc_semantic::{ReferenceFlags, SymbolFlags};
+use oxc_span::SPAN;
+use oxc_syntax::operator::{BinaryOperator, UnaryOperator};
+use oxc_traverse::{BoundIdentifier, TraverseCtx};
+
+use crate::TransformCtx;
+
+/// Global object candidates, in the order they are checked.
+const GLOBAL_OBJECT_NAMES: [&str; 4] = ["globalThis", "self", "window", "global"];
+
+/// Store for the binding of the global object
+pub struct GlobalObjectStore<'a> {
+    binding: RefCell<Option<BoundIdentifier<'a>>>,
+}
+
+impl<'a> GlobalObjectStore<'a> {
+    pub fn new() -> Self {
+        Self { binding: RefCell::new(None) }
+    }
+}
+
+// Public methods implemented directly on `TransformCtx`, as they need access to `TransformCtx::top_level_statements`.
+impl<'a> TransformCtx<'a> {
+    /// Get an expression referencing the global object.
+    ///
+    /// Inserts `var _global = typeof globalThis !== "undefined" ? globalThis : ...` at top of
+    /// program the first time it's called.
+    #[cfg_attr(not(test), expect(dead_code))]
+    pub fn global_object(&self, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        let mut binding = self.global_object.binding.borrow_mut();
+        let binding = binding.get_or_insert_with(|| {
+            let binding =
+                ctx.generate_uid_in_root_scope("global", SymbolFlags::FunctionScopedVariable);
+            let init = GlobalObjectStore::create_detection_expression(ctx);
+            let declarator = ctx.ast.variable_declarator(
+                SPAN,
+                VariableDeclarationKind::Var,
+                binding.create_binding_pattern(ctx),
+                Some(init),
+                false,
+            );
+            let stmt = Statement::VariableDeclaration(ctx.ast.alloc_variable_declaration(
+                SPAN,
+                VariableDeclarationKind::Var,
+                ctx.ast.vec1(declarator),
+                false,
+            ));
+            self.top_level_statements.insert_statement(stmt);
+            binding
+        });
+        binding.create_read_expression(ctx)
+    }
+}
+
+impl<'a> GlobalObjectStore<'a> {
+    /// `typeof globalThis !== "undefined" ? globalThis : ... : Function("return this")()`
+    fn create_detection_expression(ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        // `Function("return this")()`
+        let callee = Self::create_global_reference("Function", ctx);
+        let argument = Argument::from(ctx.ast.expression_string_literal(SPAN, "return this"));
+        let function =
+            ctx.ast.expression_call(SPAN, callee, NONE, ctx.ast.vec1(argument), false, false);
+        let fallback = ctx.ast.expression_call(SPAN, function, NONE, ctx.ast.vec(), false, false);
+
+        GLOBAL_OBJECT_NAMES.iter().rev().fold(fallback, |alternate, &name| {
+            // `typeof name !== "undefined"`
+            let argument = Self::create_global_reference(name, ctx);
+            let test = ctx.ast.expression_binary(
+                SPAN,
+                ctx.ast.expression_unary(SPAN, UnaryOperator::Typeof, argument),
+                BinaryOperator::StrictInequality,
+                ctx.ast.expression_string_literal(SPAN, "undefined"),
+            );
+            let consequent = Self::create_global_reference(name, ctx);
+            ctx.ast.expression_conditional(SPAN, test, consequent, alternate)
+        })
+    }
+
+    /// Create a read reference to `name` in root scope, which is usually unbound.
+    fn create_global_reference(name: &'static str, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        let symbol_id = ctx.scopes().get_root_binding(name);
+        let reference_id = ctx.create_reference(name.into(), symbol_id, ReferenceFlags::Read);
+        let ident = ctx.ast.identifier_reference_with_reference_id(SPAN, name, reference_id);
+        Expression::Identifier(ctx.alloc(ident))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use oxc_allocator::Allocator;
+    use oxc_ast::ast::*;
+    use oxc_codegen::CodeGenerator;
+    use oxc_parser::Parser;
+    use oxc_semantic::SemanticBuilder;
+    use oxc_span::SourceType;
+    use oxc_traverse::{traverse_mut, Traverse, TraverseCtx};
+
+    use crate::{common::top_level_statements::TopLevelStatements, TransformCtx, TransformOptions};
+
+    /// Replace each `GLOBAL` identifier with a reference to the global object.
+    struct ReplaceGlobal<'a, 'ctx> {
+        ctx: &'ctx TransformCtx<'a>,
+    }
+
+    impl<'a, 'ctx> Traverse<'a> for ReplaceGlobal<'a, 'ctx> {
+        fn exit_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+            TopLevelStatements::new(self.ctx).exit_program(program, ctx);
+        }
+
+        fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+            if matches!(expr, Expression::Identifier(ident) if ident.name == "GLOBAL") {
+                *expr = self.ctx.global_object(ctx);
+            }
+        }
+    }
+
+    fn transform(source_text: &str) -> String {
+        let allocator = Allocator::default();
+        let source_type = SourceType::mjs();
+        let mut program = Parser::new(&allocator, source_text, source_type).parse().program;
+        let (symbols, scopes) =
+            SemanticBuilder::new().build(&program).semantic.into_symbol_table_and_scope_tree();
+        let transform_ctx = TransformCtx::new(Path::new(""), &TransformOptions::default());
+        let mut traverser = ReplaceGlobal { ctx: &transform_ctx };
+        traverse_mut(&mut traverser, &allocator, &mut program, symbols, scopes);
+        CodeGenerator::new().build(&program).code
+    }
+
+    fn codegen(source_text: &str) -> String {
+        let allocator = Allocator::default();
+        let program = Parser::new(&allocator, source_text, SourceType::mjs()).parse().program;
+        CodeGenerator::new().build(&program).code
+    }
+
+    #[test]
+    fn global_object() {
+        let source_text = "
+            import x from 'x';
+            GLOBAL.foo = x;
+            function f() { return GLOBAL.bar; }
+        ";
+        let expected = "
+            import x from 'x';
+            var _global = typeof globalThis !== 'undefined' ? globalThis
+                : typeof self !== 'undefined' ? self
+                : typeof window !== 'undefined' ? window
+                : typeof global !== 'undefined' ? global
+                : Function('return this')();
+            _global.foo = x;
+            function f() { return _global.bar; }
+        ";
+        assert_eq!(transform(source_text), codegen(expected));
+    }
+
+    #[test]
+    fn no_global_object() {
+        let source_text = "let foo = 1;";
+        assert_eq!(transform(source_text), codegen(source_text));
+    }
+
+    #[test]
+    fn global_name_conflict() {
+        let source_text = "var _global = 1; GLOBAL;";
+        let expected = "
+            var _global2 = typeof globalThis !== 'undefined' ? globalThis
+                : typeof self !== 'undefined' ? self
+                : typeof window !== 'undefined' ? window
+                : typeof global !== 'undefined' ? global
+                : Function('return this')();
+            var _global = 1;
+            _global2;
+        ";
+        assert_eq!(transform(source_text), codegen(expected));
+    }
+}
@@ -1,7 +1,7 @@
 //! Utility to load helper functions.
 //!
 //! This module provides functionality to load helper functions in different modes.
-//! It supports runtime, external, and inline (not yet implemented) modes for loading helper functions.
+//! It supports runtime, external, and inline modes for loading helper functions.
 //!
 //! ## Usage
 //!
@@ -9,7 +9,7 @@
 //!
 //! ```rs
 //! let callee = self.ctx.helper_load("helperName");
-//! let call = self.ctx.ast.call_expression(callee, ...arguments);
+//! let call = self.ctx.ast.call_expression(callee, ...arguments, false);
 //! ```
 //!
 //! And also you can call [`TransformCtx::helper_call`] directly to load and call a helper function.
@@ -33,6 +33,14 @@
 //!
 //! Based on [@babel/plugin-transform-runtime](https://github.com/babel/babel/tree/main/packages/babel-plugin-transform-runtime).
 //!
+//! The import source of individual helpers can be overridden with
+//! [`HelperLoaderOptions::resolve_source`], e.g. to import helpers from different chunks of a bundle:
+//!
+//! ```js
+//! import asyncToGenerator from "./chunks/async.js";
+//! import objectSpread2 from "./chunks/object.js";
+//! ```
+//!
 //! ### External ([`HelperLoaderMode::External`])
 //!
 //! Uses helper functions from a global `babelHelpers` variable. This is the default mode for testing.
@@ -47,48 +55,54 @@
 //!
 //! ### Inline ([`HelperLoaderMode::Inline`])
 //!
-//! > Note: This mode is not currently implemented.
-//!
 //! Inline helper functions are inserted directly into the top of program.
+//! Each helper is defined only once, however many times it's used, along with any helpers it depends on.
+//! Helpers are given UIDs, so they can't collide with other bindings in the program.
 //!
 //! Generated code example:
 //!
 //! ```js
-//! function helperName(...arguments) { ... } // Inlined helper function
-//! helperName(...arguments);
+//! function _helperName(...arguments) { ... } // Inlined helper function
+//! _helperName(...arguments);
 //! ```
 //!
+//! Definitions of helpers are in [`inline_helpers`].
+//!
 //! Based on [@babel/helper](https://github.com/babel/babel/tree/main/packages/babel-helpers).
 //!
 //! ## Implementation
 //!
 //! Unlike other "common" utilities, this one has no transformer. It adds imports to the program
-//! via `ModuleImports` transform.
+//! via `ModuleImports` transform, and inline helper definitions via `TopLevelStatements` transform.
 
-use std::{borrow::Cow, cell::RefCell};
+use std::{borrow::Cow, cell::RefCell, fmt, sync::Arc};
 
 use rustc_hash::FxHashMap;
 use serde::Deserialize;
 
 use oxc_allocator::{String as ArenaString, Vec as ArenaVec};
-use oxc_ast::ast::{Argument, CallExpression, Expression, TSTypeParameterInstantiation};
-use oxc_semantic::{ReferenceFlags, SymbolFlags};
-use oxc_span::{Atom, SPAN};
+use oxc_ast::{
+    ast::{Argument, CallExpression, Expression, TSTypeParameterInstantiation},
+    visit::VisitMut,
+};
+use oxc_parser::Parser;
+use oxc_semantic::{IsGlobalReference, ReferenceFlags, SymbolFlags};
+use oxc_span::{Atom, SourceType, SPAN};
 use oxc_traverse::{BoundIdentifier, TraverseCtx};
 
 use crate::TransformCtx;
 
+use super::inline_helpers::{self, HelperDefinitionBinder};
+
 /// Defines the mode for loading helper functions.
 #[derive(Default, Clone, Copy, Debug, Deserialize)]
 pub enum HelperLoaderMode {
     /// Inline mode: Helper functions are directly inserted into the program.
     ///
-    /// Note: This mode is not currently implemented.
-    ///
     /// Example output:
     /// ```js
-    /// function helperName(...arguments) { ... } // Inlined helper function
-    /// helperName(...arguments);
+    /// function _helperName(...arguments) { ... } // Inlined helper function
+    /// _helperName(...arguments);
     /// ```
     Inline,
     /// External mode: Helper functions are accessed from a global `babelHelpers` object.
@@ -122,11 +136,53 @@ pub struct HelperLoaderOptions {
     /// Default: `@babel/runtime`
     pub module_name: Cow<'static, str>,
     pub mode: HelperLoaderMode,
+    /// Override the import source of individual helpers in [`HelperLoaderMode::Runtime`] mode.
+    ///
+    /// Helpers for which it returns `None` are imported from `{module_name}/helpers/{helper_name}`.
+    #[serde(skip)]
+    pub resolve_source: Option<HelperSourceResolver>,
 }
 
 impl Default for HelperLoaderOptions {
     fn default() -> Self {
-        Self { module_name: default_as_module_name(), mode: HelperLoaderMode::default() }
+        Self {
+            module_name: default_as_module_name(),
+            mode: HelperLoaderMode::default(),
+            resolve_source: None,
+        }
+    }
+}
+
+/// Callback which returns the import source for a helper, given the helper's name
+/// (e.g. `asyncToGenerator`), or `None` to use the default source.
+///
+/// ```rs
+/// HelperSourceResolver::new(|helper_name| match helper_name {
+///     "asyncToGenerator" => Some("./chunks/async.js".to_string()),
+///     _ => None,
+/// })
+/// ```
+#[derive(Clone)]
+pub struct HelperSourceResolver(Arc<ResolveSourceFn>);
+
+type ResolveSourceFn = dyn Fn(&str) -> Option<String> + Send + Sync;
+
+impl HelperSourceResolver {
+    pub fn new<F>(resolve: F) -> Self
+    where
+        F: Fn(&str) -> Option<String> + Send + Sync + 'static,
+    {
+        Self(Arc::new(resolve))
+    }
+
+    fn resolve(&self, helper_name: &str) -> Option<String> {
+        (self.0)(helper_name)
+    }
+}
+
+impl fmt::Debug for HelperSourceResolver {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("HelperSourceResolver")
     }
 }
 
@@ -137,33 +193,131 @@ fn default_as_module_name() -> Cow<'static, str> {
 /// Available helpers.
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
 pub enum Helper {
+    ArrayLikeToArray,
+    ArrayWithHoles,
+    AssertThisInitialized,
     AwaitAsyncGenerator,
     AsyncGeneratorDelegate,
     AsyncIterator,
     AsyncToGenerator,
+    CallSuper,
+    CheckInRHS,
+    CheckPrivateRedeclaration,
+    ClassApplyDescriptorDestructureSet,
+    ClassApplyDescriptorGet,
+    ClassApplyDescriptorSet,
+    ClassCallCheck,
+    ClassExtractFieldDescriptor,
+    ClassPrivateFieldDestructureSet,
+    ClassPrivateFieldGet,
+    ClassPrivateFieldInitSpec,
+    ClassPrivateFieldSet,
+    CreateClass,
+    DefineProperty,
+    Get,
+    GetPrototypeOf,
+    Inherits,
+    IsNativeReflectConstruct,
+    IterableToArray,
+    IterableToArrayLimit,
+    NewArrowCheck,
+    NonIterableRest,
+    ObjectDestructuringEmpty,
     ObjectSpread2,
+    ObjectWithoutProperties,
+    ObjectWithoutPropertiesLoose,
+    OptionalChain,
+    OptionalChainDelete,
+    OverloadYield,
+    PossibleConstructorReturn,
+    SetPrototypeOf,
+    SlicedToArray,
+    SuperPropBase,
+    TaggedTemplateLiteral,
+    TaggedTemplateLiteralLoose,
+    ToArray,
+    ToPrimitive,
+    ToPropertyKey,
+    UnsupportedIterableToArray,
+    UsingCtx,
     WrapAsyncGenerator,
+    WrapRegExp,
+    WriteOnlyError,
 }
 
 impl Helper {
     const fn name(self) -> &'static str {
         match self {
+            Self::ArrayLikeToArray => "arrayLikeToArray",
+            Self::ArrayWithHoles => "arrayWithHoles",
+            Self::AssertThisInitialized => "assertThisInitialized",
             Self::AwaitAsyncGenerator => "awaitAsyncGenerator",
             Self::AsyncGeneratorDelegate => "asyncGeneratorDelegate",
             Self::AsyncIterator => "asyncIterator",
             Self::AsyncToGenerator => "asyncToGenerator",
+            Self::CallSuper => "callSuper",
+            Self::CheckInRHS => "checkInRHS",
+            Self::CheckPrivateRedeclaration => "checkPrivateRedeclaration",
+            Self::ClassApplyDescriptorDestructureSet => "classApplyDescriptorDestructureSet",
+            Self::ClassApplyDescriptorGet => "classApplyDescriptorGet",
+            Self::ClassApplyDescriptorSet => "classApplyDescriptorSet",
+            Self::ClassCallCheck => "classCallCheck",
+            Self::ClassExtractFieldDescriptor => "classExtractFieldDescriptor",
+            Self::ClassPrivateFieldDestructureSet => "classPrivateFieldDestructureSet",
+            Self::ClassPrivateFieldGet => "classPrivateFieldGet",
+            Self::ClassPrivateFieldInitSpec => "classPrivateFieldInitSpec",
+            Self::ClassPrivateFieldSet => "classPrivateFieldSet",
+            Self::CreateClass => "createClass",
+            Self::DefineProperty => "defineProperty",
+            Self::Get => "get",
+            Self::GetPrototypeOf => "getPrototypeOf",
+            Self::Inherits => "inherits",
+            Self::IsNativeReflectConstruct => "isNativeReflectConstruct",
+            Self::IterableToArray => "iterableToArray",
+            Self::IterableToArrayLimit => "iterableToArrayLimit",
+            Self::NewArrowCheck => "newArrowCheck",
+            Self::NonIterableRest => "nonIterableRest",
+            Self::ObjectDestructuringEmpty => "objectDestructuringEmpty",
             Self::ObjectSpread2 => "objectSpread2",
+            Self::ObjectWithoutProperties => "objectWithoutProperties",
+            Self::ObjectWithoutPropertiesLoose => "objectWithoutPropertiesLoose",
+            Self::OptionalChain => "optionalChain",
+            Self::OptionalChainDelete => "optionalChainDelete",
+            Self::OverloadYield => "OverloadYield",
+            Self::PossibleConstructorReturn => "possibleConstructorReturn",
+            Self::SetPrototypeOf => "setPrototypeOf",
+            Self::SlicedToArray => "slicedToArray",
+            Self::SuperPropBase => "superPropBase",
+            Self::TaggedTemplateLiteral => "taggedTemplateLiteral",
+            Self::TaggedTemplateLiteralLoose => "taggedTemplateLiteralLoose",
+            Self::ToArray => "toArray",
+            Self::ToPrimitive => "toPrimitive",
+            Self::ToPropertyKey => "toPropertyKey",
+            Self::UnsupportedIterableToArray => "unsupportedIterableToArray",
+            Self::UsingCtx => "usingCtx",
             Self::WrapAsyncGenerator => "wrapAsyncGenerator",
+            Self::WrapRegExp => "wrapRegExp",
+            Self::WriteOnlyError => "writeOnlyError",
         }
     }
+
+    /// Helpers which have no side effects other than returning a value.
+    const PURE: [Self; 2] = [Self::CreateClass, Self::WrapRegExp];
+
+    fn is_pure(self) -> bool {
+        Self::PURE.contains(&self)
+    }
 }
 
 /// Stores the state of the helper loader in [`TransformCtx`].
 pub struct HelperLoaderStore<'a> {
     module_name: Cow<'static, str>,
     mode: HelperLoaderMode,
+    resolve_source: Option<HelperSourceResolver>,
     /// Loaded helpers, determined what helpers are loaded and what imports should be added.
     loaded_helpers: RefCell<FxHashMap<Helper, BoundIdentifier<'a>>>,
+    /// Helpers used in any mode, in order of first use.
+    used_helpers: RefCell<Vec<Helper>>,
 }
 
 impl<'a> HelperLoaderStore<'a> {
@@ -171,9 +325,16 @@ impl<'a> HelperLoaderStore<'a> {
         Self {
             module_name: options.module_name.clone(),
             mode: options.mode,
+            resolve_source: options.resolve_source.clone(),
             loaded_helpers: RefCell::new(FxHashMap::default()),
+            used_helpers: RefCell::new(vec![]),
         }
     }
+
+    /// Names of helpers which have been used, in order of first use (e.g. `asyncToGenerator`).
+    pub fn used_helper_names(&self) -> Vec<&'static str> {
+        self.used_helpers.borrow().iter().map(|helper| helper.name()).collect()
+    }
 }
 
 // Public methods implemented directly on `TransformCtx`, as they need access to `TransformCtx::module_imports`.
@@ -187,12 +348,14 @@ impl<'a> TransformCtx<'a> {
         ctx: &mut TraverseCtx<'a>,
     ) -> CallExpression<'a> {
         let callee = self.helper_load(helper, ctx);
+        let pure = self.is_pure_helper_call(helper);
         ctx.ast.call_expression(
             SPAN,
             callee,
             None::<TSTypeParameterInstantiation<'a>>,
             arguments,
             false,
+            pure,
         )
     }
 
@@ -204,18 +367,60 @@ impl<'a> TransformCtx<'a> {
         ctx: &mut TraverseCtx<'a>,
     ) -> Expression<'a> {
         let callee = self.helper_load(helper, ctx);
+        let pure = self.is_pure_helper_call(helper);
         ctx.ast.expression_call(
             SPAN,
             callee,
             None::<TSTypeParameterInstantiation<'a>>,
             arguments,
             false,
+            pure,
         )
     }
 
+    /// Check if a call to `helper` should be annotated with `/* @__PURE__ */`.
+    fn is_pure_helper_call(&self, helper: Helper) -> bool {
+        self.pure_annotations && helper.is_pure()
+    }
+
+    /// Check if `callee` is a loaded helper which has no side effects other than returning a value.
+    pub fn is_pure_helper(&self, callee: &Expression<'a>, ctx: &TraverseCtx<'a>) -> bool {
+        let helper_loader = &self.helper_loader;
+        match (helper_loader.mode, callee) {
+            // `_createClass`
+            (
+                HelperLoaderMode::Runtime | HelperLoaderMode::Inline,
+                Expression::Identifier(ident),
+            ) => {
+                let Some(symbol_id) = ctx.symbols().get_reference(ident.reference_id()).symbol_id()
+                else {
+                    return false;
+                };
+                helper_loader
+                    .loaded_helpers
+                    .borrow()
+                    .iter()
+                    .any(|(helper, binding)| binding.symbol_id == symbol_id && helper.is_pure())
+            }
+            // `babelHelpers.createClass`
+            (HelperLoaderMode::External, Expression::StaticMemberExpression(member)) => {
+                matches!(&member.object, Expression::Identifier(ident)
+                    if ident.name == "babelHelpers" && ident.is_global_reference(ctx.symbols()))
+                    && Helper::PURE.iter().any(|helper| member.property.name == helper.name())
+            }
+            _ => false,
+        }
+    }
+
     /// Load a helper function and return a callee expression.
     pub fn helper_load(&self, helper: Helper, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
         let helper_loader = &self.helper_loader;
+        {
+            let mut used_helpers = helper_loader.used_helpers.borrow_mut();
+            if !used_helpers.contains(&helper) {
+                used_helpers.push(helper);
+            }
+        }
         match helper_loader.mode {
             HelperLoaderMode::Runtime => {
                 helper_loader.transform_for_runtime_helper(helper, self, ctx)
@@ -224,7 +429,7 @@ impl<'a> TransformCtx<'a> {
                 HelperLoaderStore::transform_for_external_helper(helper, ctx)
             }
             HelperLoaderMode::Inline => {
-                unreachable!("Inline helpers are not supported yet");
+                helper_loader.transform_for_inline_helper(helper, self, ctx)
             }
         }
     }
@@ -253,13 +458,19 @@ impl<'a> HelperLoaderStore<'a> {
     ) -> BoundIdentifier<'a> {
         let helper_name = helper.name();
 
-        // Construct string directly in arena without an intermediate temp allocation
-        let len = self.module_name.len() + "/helpers/".len() + helper_name.len();
-        let mut source = ArenaString::with_capacity_in(len, ctx.ast.allocator);
-        source.push_str(&self.module_name);
-        source.push_str("/helpers/");
-        source.push_str(helper_name);
-        let source = Atom::from(source.into_bump_str());
+        let source = if let Some(source) =
+            self.resolve_source.as_ref().and_then(|resolver| resolver.resolve(helper_name))
+        {
+            ctx.ast.atom(&source)
+        } else {
+            // Construct string directly in arena without an intermediate temp allocation
+            let len = self.module_name.len() + "/helpers/".len() + helper_name.len();
+            let mut source = ArenaString::with_capacity_in(len, ctx.ast.allocator);
+            source.push_str(&self.module_name);
+            source.push_str("/helpers/");
+            source.push_str(helper_name);
+            Atom::from(source.into_bump_str())
+        };
 
         let flag = if transform_ctx.source_type.is_module() {
             SymbolFlags::Import
@@ -273,6 +484,60 @@ impl<'a> HelperLoaderStore<'a> {
         binding
     }
 
+    fn transform_for_inline_helper(
+        &self,
+        helper: Helper,
+        transform_ctx: &TransformCtx<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let binding = self.load_inline_helper(helper, transform_ctx, ctx);
+        binding.create_read_expression(ctx)
+    }
+
+    /// Insert definition of `helper` and its dependencies at top of program, if not already inserted.
+    fn load_inline_helper(
+        &self,
+        helper: Helper,
+        transform_ctx: &TransformCtx<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> BoundIdentifier<'a> {
+        if let Some(binding) = self.loaded_helpers.borrow().get(&helper) {
+            return binding.clone();
+        }
+
+        let (source_text, dependencies) = inline_helpers::definition(helper);
+
+        // Top-level function declarations are block-scoped in modules, and var-like in scripts
+        let (source_type, flags) = if transform_ctx.source_type.is_module() {
+            (SourceType::mjs(), SymbolFlags::Function | SymbolFlags::BlockScopedVariable)
+        } else {
+            (SourceType::cjs(), SymbolFlags::FunctionScopedVariable)
+        };
+
+        // Record binding before loading dependencies, so each helper is only defined once
+        let binding = ctx.generate_uid_in_root_scope(helper.name(), flags);
+        self.loaded_helpers.borrow_mut().insert(helper, binding.clone());
+
+        let mut names = FxHashMap::default();
+        names.insert(format!("_{}", helper.name()), (binding.name.clone(), binding.symbol_id));
+        for &dependency in dependencies {
+            let dependency_binding = self.load_inline_helper(dependency, transform_ctx, ctx);
+            names.insert(
+                format!("_{}", dependency.name()),
+                (dependency_binding.name, dependency_binding.symbol_id),
+            );
+        }
+
+        let ret = Parser::new(ctx.ast.allocator, source_text, source_type).parse();
+        debug_assert!(ret.errors.is_empty(), "Invalid definition of `{}` helper", helper.name());
+        let mut program = ret.program;
+
+        HelperDefinitionBinder::new(names, &program, ctx).visit_program(&mut program);
+        transform_ctx.top_level_statements.insert_statements(program.body);
+
+        binding
+    }
+
     fn transform_for_external_helper(helper: Helper, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
         static HELPER_VAR: &str = "babelHelpers";
 
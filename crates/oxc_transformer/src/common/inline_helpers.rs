@@ -0,0 +1,780 @@
+//! Definitions of helpers for [`HelperLoaderMode::Inline`].
+//!
+//! Each helper is defined as a function declaration named after the helper with a `_` prefix
+//! (e.g. `function _classCallCheck() {}`), and refers to other helpers it depends on in the same way.
+//! A definition may also contain private functions which are only used by that helper.
+//!
+//! Definitions are parsed when a helper is first used, and all top-level names are replaced with
+//! UIDs in the root scope, so they can't collide with bindings in the program. Scopes, symbols and
+//! references in the definition are added to the program's semantic data, as for any other node
+//! the transformer creates.
+//!
+//! Based on [@babel/helpers](https://github.com/babel/babel/tree/main/packages/babel-helpers/src/helpers).
+//!
+//! [`HelperLoaderMode::Inline`]: super::helper_loader::HelperLoaderMode::Inline
+
+use std::cell::Cell;
+
+use rustc_hash::FxHashMap;
+
+use oxc_ast::{
+    ast::*,
+    visit::{walk_mut, VisitMut},
+};
+use oxc_semantic::{ScopeTree, SemanticBuilder, SymbolTable};
+use oxc_span::SPAN;
+use oxc_syntax::{
+    scope::{ScopeFlags, ScopeId},
+    symbol::SymbolId,
+};
+use oxc_traverse::TraverseCtx;
+
+use super::helper_loader::Helper;
+
+/// Get source text of definition of `helper`, and the helpers it depends on.
+pub fn definition(helper: Helper) -> (&'static str, &'static [Helper]) {
+    match helper {
+        Helper::ArrayLikeToArray => (
+            "function _arrayLikeToArray(r, a) {
+                (null == a || a > r.length) && (a = r.length);
+                for (var e = 0, n = Array(a); e < a; e++) n[e] = r[e];
+                return n;
+            }",
+            &[],
+        ),
+        Helper::ArrayWithHoles => {
+            ("function _arrayWithHoles(r) { if (Array.isArray(r)) return r; }", &[])
+        }
+        Helper::AssertThisInitialized => (
+            "function _assertThisInitialized(e) {
+                if (void 0 === e) throw new ReferenceError(\"this hasn't been initialised - super() hasn't been called\");
+                return e;
+            }",
+            &[],
+        ),
+        Helper::AwaitAsyncGenerator => (
+            "function _awaitAsyncGenerator(e) { return new _OverloadYield(e, 0); }",
+            &[Helper::OverloadYield],
+        ),
+        Helper::AsyncGeneratorDelegate => (
+            "function _asyncGeneratorDelegate(t) {
+                var e = {}, n = !1;
+                function pump(e, r) {
+                    return n = !0, r = new Promise(function (n) { n(t[e](r)); }), { done: !1, value: new _OverloadYield(r, 1) };
+                }
+                return e[\"undefined\" != typeof Symbol && Symbol.iterator || \"@@iterator\"] = function () {
+                    return this;
+                }, e.next = function (t) {
+                    return n ? (n = !1, t) : pump(\"next\", t);
+                }, \"function\" == typeof t.throw && (e.throw = function (t) {
+                    if (n) throw n = !1, t;
+                    return pump(\"throw\", t);
+                }), \"function\" == typeof t.return && (e.return = function (t) {
+                    return n ? (n = !1, t) : pump(\"return\", t);
+                }), e;
+            }",
+            &[Helper::OverloadYield],
+        ),
+        Helper::AsyncIterator => (
+            "function _asyncIterator(r) {
+                var n, t, o, e = 2;
+                for (\"undefined\" != typeof Symbol && (t = Symbol.asyncIterator, o = Symbol.iterator); e--;) {
+                    if (t && null != (n = r[t])) return n.call(r);
+                    if (o && null != (n = r[o])) return new AsyncFromSyncIterator(n.call(r));
+                    t = \"@@asyncIterator\", o = \"@@iterator\";
+                }
+                throw new TypeError(\"Object is not async iterable\");
+            }
+            function AsyncFromSyncIterator(r) {
+                function AsyncFromSyncIteratorContinuation(r) {
+                    if (Object(r) !== r) return Promise.reject(new TypeError(r + \" is not an object.\"));
+                    var n = r.done;
+                    return Promise.resolve(r.value).then(function (r) { return { value: r, done: n }; });
+                }
+                return AsyncFromSyncIterator = function (r) { this.s = r, this.n = r.next; }, AsyncFromSyncIterator.prototype = {
+                    s: null,
+                    n: null,
+                    next: function () {
+                        return AsyncFromSyncIteratorContinuation(this.n.apply(this.s, arguments));
+                    },
+                    return: function (r) {
+                        var n = this.s.return;
+                        return void 0 === n ? Promise.resolve({ value: r, done: !0 }) : AsyncFromSyncIteratorContinuation(n.apply(this.s, arguments));
+                    },
+                    throw: function (r) {
+                        var n = this.s.return;
+                        return void 0 === n ? Promise.reject(r) : AsyncFromSyncIteratorContinuation(n.apply(this.s, arguments));
+                    }
+                }, new AsyncFromSyncIterator(r);
+            }",
+            &[],
+        ),
+        Helper::AsyncToGenerator => (
+            "function asyncGeneratorStep(n, t, e, r, o, a, c) {
+                try { var i = n[a](c), u = i.value; } catch (n) { return void e(n); }
+                i.done ? t(u) : Promise.resolve(u).then(r, o);
+            }
+            function _asyncToGenerator(n) {
+                return function () {
+                    var t = this, e = arguments;
+                    return new Promise(function (r, o) {
+                        var a = n.apply(t, e);
+                        function _next(n) { asyncGeneratorStep(a, r, o, _next, _throw, \"next\", n); }
+                        function _throw(n) { asyncGeneratorStep(a, r, o, _next, _throw, \"throw\", n); }
+                        _next(void 0);
+                    });
+                };
+            }",
+            &[],
+        ),
+        Helper::CallSuper => (
+            "function _callSuper(t, o, e) {
+                return o = _getPrototypeOf(o), _possibleConstructorReturn(t, _isNativeReflectConstruct() ? Reflect.construct(o, e || [], _getPrototypeOf(t).constructor) : o.apply(t, e));
+            }",
+            &[
+                Helper::GetPrototypeOf,
+                Helper::IsNativeReflectConstruct,
+                Helper::PossibleConstructorReturn,
+            ],
+        ),
+        Helper::CheckInRHS => (
+            "function _checkInRHS(e) {
+                if (Object(e) !== e) throw TypeError(\"right-hand side of 'in' should be an object, got \" + (null !== e ? typeof e : \"null\"));
+                return e;
+            }",
+            &[],
+        ),
+        Helper::CheckPrivateRedeclaration => (
+            "function _checkPrivateRedeclaration(e, t) {
+                if (t.has(e)) throw new TypeError(\"Cannot initialize the same private elements twice on an object\");
+            }",
+            &[],
+        ),
+        Helper::ClassApplyDescriptorDestructureSet => (
+            "function _classApplyDescriptorDestructureSet(e, t) {
+                if (t.set) {
+                    if (!(\"__destrObj\" in t)) t.__destrObj = { set value(r) { t.set.call(e, r); } };
+                    return t.__destrObj;
+                }
+                if (!t.writable) throw new TypeError(\"attempted to set read only private field\");
+                return t;
+            }",
+            &[],
+        ),
+        Helper::ClassApplyDescriptorGet => (
+            "function _classApplyDescriptorGet(e, t) { return t.get ? t.get.call(e) : t.value; }",
+            &[],
+        ),
+        Helper::ClassApplyDescriptorSet => (
+            "function _classApplyDescriptorSet(e, t, l) {
+                if (t.set) t.set.call(e, l);
+                else {
+                    if (!t.writable) throw new TypeError(\"attempted to set read only private field\");
+                    t.value = l;
+                }
+            }",
+            &[],
+        ),
+        Helper::ClassCallCheck => (
+            "function _classCallCheck(a, n) {
+                if (!(a instanceof n)) throw new TypeError(\"Cannot call a class as a function\");
+            }",
+            &[],
+        ),
+        Helper::ClassExtractFieldDescriptor => (
+            "function _classExtractFieldDescriptor(e, t, a) {
+                if (!t.has(e)) throw new TypeError(\"attempted to \" + a + \" private field on non-instance\");
+                return t.get(e);
+            }",
+            &[],
+        ),
+        Helper::ClassPrivateFieldDestructureSet => (
+            "function _classPrivateFieldDestructureSet(e, t) {
+                return _classApplyDescriptorDestructureSet(e, _classExtractFieldDescriptor(e, t, \"set\"));
+            }",
+            &[Helper::ClassApplyDescriptorDestructureSet, Helper::ClassExtractFieldDescriptor],
+        ),
+        Helper::ClassPrivateFieldGet => (
+            "function _classPrivateFieldGet(e, t) {
+                return _classApplyDescriptorGet(e, _classExtractFieldDescriptor(e, t, \"get\"));
+            }",
+            &[Helper::ClassApplyDescriptorGet, Helper::ClassExtractFieldDescriptor],
+        ),
+        Helper::ClassPrivateFieldInitSpec => (
+            "function _classPrivateFieldInitSpec(e, t, a) { _checkPrivateRedeclaration(e, t), t.set(e, a); }",
+            &[Helper::CheckPrivateRedeclaration],
+        ),
+        Helper::ClassPrivateFieldSet => (
+            "function _classPrivateFieldSet(e, t, l) {
+                return _classApplyDescriptorSet(e, _classExtractFieldDescriptor(e, t, \"set\"), l), l;
+            }",
+            &[Helper::ClassApplyDescriptorSet, Helper::ClassExtractFieldDescriptor],
+        ),
+        Helper::CreateClass => (
+            "function _defineProperties(e, r) {
+                for (var t = 0; t < r.length; t++) {
+                    var o = r[t];
+                    o.enumerable = o.enumerable || !1, o.configurable = !0, \"value\" in o && (o.writable = !0), Object.defineProperty(e, _toPropertyKey(o.key), o);
+                }
+            }
+            function _createClass(e, r, t) {
+                return r && _defineProperties(e.prototype, r), t && _defineProperties(e, t), Object.defineProperty(e, \"prototype\", { writable: !1 }), e;
+            }",
+            &[Helper::ToPropertyKey],
+        ),
+        Helper::DefineProperty => (
+            "function _defineProperty(e, r, t) {
+                return (r = _toPropertyKey(r)) in e ? Object.defineProperty(e, r, { value: t, enumerable: !0, configurable: !0, writable: !0 }) : e[r] = t, e;
+            }",
+            &[Helper::ToPropertyKey],
+        ),
+        Helper::Get => (
+            "function _get() {
+                return _get = \"undefined\" != typeof Reflect && Reflect.get ? Reflect.get.bind() : function (e, t, r) {
+                    var p = _superPropBase(e, t);
+                    if (p) {
+                        var n = Object.getOwnPropertyDescriptor(p, t);
+                        return n.get ? n.get.call(arguments.length < 3 ? e : r) : n.value;
+                    }
+                }, _get.apply(null, arguments);
+            }",
+            &[Helper::SuperPropBase],
+        ),
+        Helper::GetPrototypeOf => (
+            "function _getPrototypeOf(t) {
+                return _getPrototypeOf = Object.setPrototypeOf ? Object.getPrototypeOf.bind() : function (t) {
+                    return t.__proto__ || Object.getPrototypeOf(t);
+                }, _getPrototypeOf(t);
+            }",
+            &[],
+        ),
+        Helper::Inherits => (
+            "function _inherits(t, e) {
+                if (\"function\" != typeof e && null !== e) throw new TypeError(\"Super expression must either be null or a function\");
+                t.prototype = Object.create(e && e.prototype, { constructor: { value: t, writable: !0, configurable: !0 } }), Object.defineProperty(t, \"prototype\", { writable: !1 }), e && _setPrototypeOf(t, e);
+            }",
+            &[Helper::SetPrototypeOf],
+        ),
+        Helper::IsNativeReflectConstruct => (
+            "function _isNativeReflectConstruct() {
+                try { var t = !Boolean.prototype.valueOf.call(Reflect.construct(Boolean, [], function () {})); } catch (t) {}
+                return (_isNativeReflectConstruct = function () { return !!t; })();
+            }",
+            &[],
+        ),
+        Helper::IterableToArray => (
+            "function _iterableToArray(r) {
+                if (\"undefined\" != typeof Symbol && null != r[Symbol.iterator] || null != r[\"@@iterator\"]) return Array.from(r);
+            }",
+            &[],
+        ),
+        Helper::IterableToArrayLimit => (
+            "function _iterableToArrayLimit(r, l) {
+                var t = null == r ? null : \"undefined\" != typeof Symbol && r[Symbol.iterator] || r[\"@@iterator\"];
+                if (null != t) {
+                    var e, n, i, u, a = [], f = !0, o = !1;
+                    try {
+                        if (i = (t = t.call(r)).next, 0 === l) {
+                            if (Object(t) !== t) return;
+                            f = !1;
+                        } else for (; !(f = (e = i.call(t)).done) && (a.push(e.value), a.length !== l); f = !0);
+                    } catch (r) {
+                        o = !0, n = r;
+                    } finally {
+                        try {
+                            if (!f && null != t.return && (u = t.return(), Object(u) !== u)) return;
+                        } finally {
+                            if (o) throw n;
+                        }
+                    }
+                    return a;
+                }
+            }",
+            &[],
+        ),
+        Helper::NewArrowCheck => (
+            "function _newArrowCheck(n, r) {
+                if (n !== r) throw new TypeError(\"Cannot instantiate an arrow function\");
+            }",
+            &[],
+        ),
+        Helper::NonIterableRest => (
+            "function _nonIterableRest() {
+                throw new TypeError(\"Invalid attempt to destructure non-iterable instance.\\nIn order to be iterable, non-array objects must have a [Symbol.iterator]() method.\");
+            }",
+            &[],
+        ),
+        Helper::ObjectDestructuringEmpty => (
+            "function _objectDestructuringEmpty(t) { if (null == t) throw new TypeError(\"Cannot destructure \" + t); }",
+            &[],
+        ),
+        Helper::ObjectSpread2 => (
+            "function ownKeys(e, r) {
+                var t = Object.keys(e);
+                if (Object.getOwnPropertySymbols) {
+                    var o = Object.getOwnPropertySymbols(e);
+                    r && (o = o.filter(function (r) { return Object.getOwnPropertyDescriptor(e, r).enumerable; })), t.push.apply(t, o);
+                }
+                return t;
+            }
+            function _objectSpread2(e) {
+                for (var r = 1; r < arguments.length; r++) {
+                    var t = null != arguments[r] ? arguments[r] : {};
+                    r % 2 ? ownKeys(Object(t), !0).forEach(function (r) {
+                        _defineProperty(e, r, t[r]);
+                    }) : Object.getOwnPropertyDescriptors ? Object.defineProperties(e, Object.getOwnPropertyDescriptors(t)) : ownKeys(Object(t)).forEach(function (r) {
+                        Object.defineProperty(e, r, Object.getOwnPropertyDescriptor(t, r));
+                    });
+                }
+                return e;
+            }",
+            &[Helper::DefineProperty],
+        ),
+        Helper::ObjectWithoutProperties => (
+            "function _objectWithoutProperties(e, t) {
+                if (null == e) return {};
+                var o, r, i = _objectWithoutPropertiesLoose(e, t);
+                if (Object.getOwnPropertySymbols) {
+                    var s = Object.getOwnPropertySymbols(e);
+                    for (r = 0; r < s.length; r++) o = s[r], t.indexOf(o) === -1 && {}.propertyIsEnumerable.call(e, o) && (i[o] = e[o]);
+                }
+                return i;
+            }",
+            &[Helper::ObjectWithoutPropertiesLoose],
+        ),
+        Helper::ObjectWithoutPropertiesLoose => (
+            "function _objectWithoutPropertiesLoose(r, e) {
+                if (null == r) return {};
+                var t = {};
+                for (var n in r) if ({}.hasOwnProperty.call(r, n)) {
+                    if (e.indexOf(n) !== -1) continue;
+                    t[n] = r[n];
+                }
+                return t;
+            }",
+            &[],
+        ),
+        Helper::OptionalChain => (
+            "function _optionalChain(r) {
+                for (var e = void 0, t = r[0], n = 1; n < r.length;) {
+                    var o = r[n], a = r[n + 1];
+                    if (n += 2, (\"optionalAccess\" === o || \"optionalCall\" === o) && null == t) return;
+                    \"access\" === o || \"optionalAccess\" === o ? (e = t, t = a(t)) : (t = a(function () {
+                        return t.apply(e, arguments);
+                    }), e = void 0);
+                }
+                return t;
+            }",
+            &[],
+        ),
+        Helper::OptionalChainDelete => (
+            "function _optionalChainDelete(r) { var e = _optionalChain(r); return null == e || e; }",
+            &[Helper::OptionalChain],
+        ),
+        Helper::OverloadYield => ("function _OverloadYield(e, d) { this.v = e, this.k = d; }", &[]),
+        Helper::PossibleConstructorReturn => (
+            "function _possibleConstructorReturn(t, e) {
+                if (e && (\"object\" == typeof e || \"function\" == typeof e)) return e;
+                if (void 0 !== e) throw new TypeError(\"Derived constructors may only return object or undefined\");
+                return _assertThisInitialized(t);
+            }",
+            &[Helper::AssertThisInitialized],
+        ),
+        Helper::SetPrototypeOf => (
+            "function _setPrototypeOf(t, e) {
+                return _setPrototypeOf = Object.setPrototypeOf ? Object.setPrototypeOf.bind() : function (t, e) {
+                    return t.__proto__ = e, t;
+                }, _setPrototypeOf(t, e);
+            }",
+            &[],
+        ),
+        Helper::SlicedToArray => (
+            "function _slicedToArray(r, e) {
+                return _arrayWithHoles(r) || _iterableToArrayLimit(r, e) || _unsupportedIterableToArray(r, e) || _nonIterableRest();
+            }",
+            &[
+                Helper::ArrayWithHoles,
+                Helper::IterableToArrayLimit,
+                Helper::UnsupportedIterableToArray,
+                Helper::NonIterableRest,
+            ],
+        ),
+        Helper::SuperPropBase => (
+            "function _superPropBase(t, o) {
+                for (; !{}.hasOwnProperty.call(t, o) && null !== (t = _getPrototypeOf(t)););
+                return t;
+            }",
+            &[Helper::GetPrototypeOf],
+        ),
+        Helper::TaggedTemplateLiteral => (
+            "function _taggedTemplateLiteral(e, t) {
+                return t || (t = e.slice(0)), Object.freeze(Object.defineProperties(e, { raw: { value: Object.freeze(t) } }));
+            }",
+            &[],
+        ),
+        Helper::TaggedTemplateLiteralLoose => (
+            "function _taggedTemplateLiteralLoose(e, t) { return t || (t = e.slice(0)), e.raw = t, e; }",
+            &[],
+        ),
+        Helper::ToArray => (
+            "function _toArray(r) {
+                return _arrayWithHoles(r) || _iterableToArray(r) || _unsupportedIterableToArray(r) || _nonIterableRest();
+            }",
+            &[
+                Helper::ArrayWithHoles,
+                Helper::IterableToArray,
+                Helper::UnsupportedIterableToArray,
+                Helper::NonIterableRest,
+            ],
+        ),
+        Helper::ToPrimitive => (
+            "function _toPrimitive(t, r) {
+                if (\"object\" != typeof t || !t) return t;
+                var e = t[Symbol.toPrimitive];
+                if (void 0 !== e) {
+                    var i = e.call(t, r || \"default\");
+                    if (\"object\" != typeof i) return i;
+                    throw new TypeError(\"@@toPrimitive must return a primitive value.\");
+                }
+                return (\"string\" === r ? String : Number)(t);
+            }",
+            &[],
+        ),
+        Helper::ToPropertyKey => (
+            "function _toPropertyKey(t) {
+                var i = _toPrimitive(t, \"string\");
+                return \"symbol\" == typeof i ? i : i + \"\";
+            }",
+            &[Helper::ToPrimitive],
+        ),
+        Helper::UnsupportedIterableToArray => (
+            "function _unsupportedIterableToArray(r, a) {
+                if (r) {
+                    if (\"string\" == typeof r) return _arrayLikeToArray(r, a);
+                    var t = {}.toString.call(r).slice(8, -1);
+                    return \"Object\" === t && r.constructor && (t = r.constructor.name), \"Map\" === t || \"Set\" === t ? Array.from(r) : \"Arguments\" === t || /^(?:Ui|I)nt(?:8|16|32)(?:Clamped)?Array$/.test(t) ? _arrayLikeToArray(r, a) : void 0;
+                }
+            }",
+            &[Helper::ArrayLikeToArray],
+        ),
+        Helper::UsingCtx => (
+            "function _usingCtx() {
+                var r = \"function\" == typeof SuppressedError ? SuppressedError : function (r, e) {
+                        var n = Error();
+                        return n.name = \"SuppressedError\", n.error = r, n.suppressed = e, n;
+                    },
+                    e = {},
+                    n = [];
+                function using(r, e) {
+                    if (null != e) {
+                        if (Object(e) !== e) throw new TypeError(\"using declarations can only be used with objects, functions, null, or undefined.\");
+                        if (r) var o = e[Symbol.asyncDispose || Symbol.for(\"Symbol.asyncDispose\")];
+                        if (void 0 === o && (o = e[Symbol.dispose || Symbol.for(\"Symbol.dispose\")], r)) var t = o;
+                        if (\"function\" != typeof o) throw new TypeError(\"Object is not disposable.\");
+                        t && (o = function () {
+                            try { t.call(e); } catch (r) { return Promise.reject(r); }
+                        }), n.push({ v: e, d: o, a: r });
+                    } else r && n.push({ d: e, a: r });
+                    return e;
+                }
+                return {
+                    e: e,
+                    u: using.bind(null, !1),
+                    a: using.bind(null, !0),
+                    d: function () {
+                        var o, t = this.e, s = 0;
+                        function next() {
+                            for (; o = n.pop();) try {
+                                if (!o.a && 1 === s) return s = 0, n.push(o), Promise.resolve().then(next);
+                                if (o.d) {
+                                    var r = o.d.call(o.v);
+                                    if (o.a) return s |= 2, Promise.resolve(r).then(next, err);
+                                } else s |= 1;
+                            } catch (r) {
+                                return err(r);
+                            }
+                            if (1 === s) return t !== e ? Promise.reject(t) : Promise.resolve();
+                            if (t !== e) throw t;
+                        }
+                        function err(n) {
+                            return t = t !== e ? new r(n, t) : n, next();
+                        }
+                        return next();
+                    }
+                };
+            }",
+            &[],
+        ),
+        Helper::WrapAsyncGenerator => (
+            "function _wrapAsyncGenerator(e) {
+                return function () { return new AsyncGenerator(e.apply(this, arguments)); };
+            }
+            function AsyncGenerator(e) {
+                var r, t;
+                function resume(r, t) {
+                    try {
+                        var n = e[r](t), o = n.value, u = o instanceof _OverloadYield;
+                        Promise.resolve(u ? o.v : o).then(function (t) {
+                            if (u) {
+                                var i = \"return\" === r ? \"return\" : \"next\";
+                                if (!o.k || t.done) return resume(i, t);
+                                t = e[i](t).value;
+                            }
+                            settle(n.done ? \"return\" : \"normal\", t);
+                        }, function (e) {
+                            resume(\"throw\", e);
+                        });
+                    } catch (e) {
+                        settle(\"throw\", e);
+                    }
+                }
+                function settle(e, n) {
+                    switch (e) {
+                        case \"return\":
+                            r.resolve({ value: n, done: !0 });
+                            break;
+                        case \"throw\":
+                            r.reject(n);
+                            break;
+                        default:
+                            r.resolve({ value: n, done: !1 });
+                    }
+                    (r = r.next) ? resume(r.key, r.arg) : t = null;
+                }
+                this._invoke = function (e, n) {
+                    return new Promise(function (o, u) {
+                        var i = { key: e, arg: n, resolve: o, reject: u, next: null };
+                        t ? t = t.next = i : (r = t = i, resume(e, n));
+                    });
+                }, \"function\" != typeof e.return && (this.return = void 0);
+            }
+            AsyncGenerator.prototype[\"function\" == typeof Symbol && Symbol.asyncIterator || \"@@asyncIterator\"] = function () {
+                return this;
+            }, AsyncGenerator.prototype.next = function (e) {
+                return this._invoke(\"next\", e);
+            }, AsyncGenerator.prototype.throw = function (e) {
+                return this._invoke(\"throw\", e);
+            }, AsyncGenerator.prototype.return = function (e) {
+                return this._invoke(\"return\", e);
+            };",
+            &[Helper::OverloadYield],
+        ),
+        Helper::WrapRegExp => (
+            "function _wrapRegExp() {
+                _wrapRegExp = function (e, r) { return new BabelRegExp(e, void 0, r); };
+                var e = RegExp.prototype, r = new WeakMap();
+                function BabelRegExp(e, t, p) {
+                    var o = RegExp(e, t);
+                    return r.set(o, p || r.get(e)), _setPrototypeOf(o, BabelRegExp.prototype);
+                }
+                function buildGroups(e, t) {
+                    var p = r.get(t);
+                    return Object.keys(p).reduce(function (r, t) {
+                        var o = p[t];
+                        if (\"number\" == typeof o) r[t] = e[o];
+                        else {
+                            for (var i = 0; void 0 === e[o[i]] && i + 1 < o.length;) i++;
+                            r[t] = e[o[i]];
+                        }
+                        return r;
+                    }, Object.create(null));
+                }
+                return _inherits(BabelRegExp, RegExp), BabelRegExp.prototype.exec = function (r) {
+                    var t = e.exec.call(this, r);
+                    if (t) {
+                        t.groups = buildGroups(t, this);
+                        var p = t.indices;
+                        p && (p.groups = buildGroups(p, this));
+                    }
+                    return t;
+                }, BabelRegExp.prototype[Symbol.replace] = function (t, p) {
+                    if (\"string\" == typeof p) {
+                        var o = r.get(this);
+                        return e[Symbol.replace].call(this, t, p.replace(/\\$<([^>]+)>/g, function (e, r) {
+                            var t = o[r];
+                            return \"$\" + (Array.isArray(t) ? t.join(\"$\") : t);
+                        }));
+                    }
+                    if (\"function\" == typeof p) {
+                        var i = this;
+                        return e[Symbol.replace].call(this, t, function () {
+                            var e = arguments;
+                            return \"object\" != typeof e[e.length - 1] && (e = [].slice.call(e)).push(buildGroups(e, i)), p.apply(this, e);
+                        });
+                    }
+                    return e[Symbol.replace].call(this, t, p);
+                }, _wrapRegExp.apply(this, arguments);
+            }",
+            &[Helper::Inherits, Helper::SetPrototypeOf],
+        ),
+        Helper::WriteOnlyError => (
+            "function _writeOnlyError(r) { throw new TypeError('\"' + r + '\" is write-only'); }",
+            &[],
+        ),
+    }
+}
+
+/// Visitor which binds a parsed helper definition in the program.
+///
+/// Scopes, symbols and references from semantic analysis of the definition are recreated in the
+/// program's `ScopeTree` and `SymbolTable`, and top-level names are renamed to their UIDs.
+/// Spans are reset, as they refer to the definition's source text, not the program's.
+pub struct HelperDefinitionBinder<'a, 'c> {
+    /// Top-level name in definition, or name of a helper it depends on -> (UID, symbol)
+    names: FxHashMap<String, (Atom<'a>, SymbolId)>,
+    /// Semantic data of the definition
+    symbols: SymbolTable,
+    scopes: ScopeTree,
+    /// Scope in definition -> scope in program
+    scope_ids: FxHashMap<ScopeId, ScopeId>,
+    /// Symbol in definition -> symbol in program
+    symbol_ids: FxHashMap<SymbolId, SymbolId>,
+    ctx: &'c mut TraverseCtx<'a>,
+}
+
+impl<'a, 'c> HelperDefinitionBinder<'a, 'c> {
+    /// `names` contains UIDs of the helper and the helpers it depends on.
+    /// Other top-level functions are private to this helper, and are given UIDs too.
+    pub fn new(
+        mut names: FxHashMap<String, (Atom<'a>, SymbolId)>,
+        program: &Program<'a>,
+        ctx: &'c mut TraverseCtx<'a>,
+    ) -> Self {
+        let (symbols, scopes) =
+            SemanticBuilder::new().build(program).semantic.into_symbol_table_and_scope_tree();
+
+        let mut symbol_ids = FxHashMap::default();
+        for (name, &symbol_id) in scopes.get_bindings(scopes.root_scope_id()) {
+            let (_, uid_symbol_id) = names.entry(name.to_string()).or_insert_with(|| {
+                let flags = symbols.get_flags(symbol_id);
+                let private = ctx.generate_uid_in_root_scope(name, flags);
+                (private.name, private.symbol_id)
+            });
+            symbol_ids.insert(symbol_id, *uid_symbol_id);
+        }
+
+        let mut scope_ids = FxHashMap::default();
+        scope_ids.insert(scopes.root_scope_id(), ctx.scopes().root_scope_id());
+
+        Self { names, symbols, scopes, scope_ids, symbol_ids, ctx }
+    }
+
+    fn bind_scope(&mut self, scope_id: ScopeId) -> ScopeId {
+        if let Some(&bound_scope_id) = self.scope_ids.get(&scope_id) {
+            return bound_scope_id;
+        }
+        let parent_id = self.scopes.get_parent_id(scope_id).unwrap();
+        let parent_id = self.bind_scope(parent_id);
+        // Strict mode is inherited from the program
+        let flags = self.scopes.get_flags(scope_id) - ScopeFlags::StrictMode;
+        let bound_scope_id = self.ctx.create_child_scope(parent_id, flags);
+        self.scope_ids.insert(scope_id, bound_scope_id);
+        bound_scope_id
+    }
+
+    fn bind_symbol(&mut self, symbol_id: SymbolId) -> SymbolId {
+        if let Some(&bound_symbol_id) = self.symbol_ids.get(&symbol_id) {
+            return bound_symbol_id;
+        }
+        let scope_id = self.bind_scope(self.symbols.get_scope_id(symbol_id));
+        let name = self.ctx.ast.atom(self.symbols.get_name(symbol_id));
+        let flags = self.symbols.get_flags(symbol_id);
+        let bound_symbol_id = self.ctx.generate_binding(name, scope_id, flags).symbol_id;
+        self.symbol_ids.insert(symbol_id, bound_symbol_id);
+        bound_symbol_id
+    }
+
+    /// Get UID of `name` if it's a top-level name, or a helper the definition depends on.
+    fn top_level_uid(&self, name: &str, symbol_id: Option<SymbolId>) -> Option<Atom<'a>> {
+        let is_top_level = symbol_id.map_or(true, |symbol_id| {
+            self.symbols.get_scope_id(symbol_id) == self.scopes.root_scope_id()
+        });
+        if is_top_level {
+            self.names.get(name).map(|(uid, _)| uid.clone())
+        } else {
+            None
+        }
+    }
+}
+
+/// Set `span` to `SPAN` in nodes which codegen adds source mappings for, and walk the node.
+macro_rules! reset_spans {
+    ($($visit:ident, $walk:ident, $ty:ty;)*) => {
+        $(
+            fn $visit(&mut self, it: &mut $ty) {
+                it.span = SPAN;
+                walk_mut::$walk(self, it);
+            }
+        )*
+    };
+}
+
+impl<'a, 'c> VisitMut<'a> for HelperDefinitionBinder<'a, 'c> {
+    fn enter_scope(&mut self, _flags: ScopeFlags, scope_id: &Cell<Option<ScopeId>>) {
+        scope_id.set(Some(self.bind_scope(scope_id.get().unwrap())));
+    }
+
+    fn visit_identifier_reference(&mut self, ident: &mut IdentifierReference<'a>) {
+        ident.span = SPAN;
+        let reference = self.symbols.get_reference(ident.reference_id());
+        let (symbol_id, flags) = (reference.symbol_id(), reference.flags());
+        if let Some(uid) = self.top_level_uid(&ident.name, symbol_id) {
+            ident.name = uid;
+        }
+        let reference_id = match symbol_id {
+            Some(symbol_id) => {
+                let symbol_id = self.bind_symbol(symbol_id);
+                self.ctx.create_bound_reference(symbol_id, flags)
+            }
+            // Reference to a helper this definition depends on
+            None => match self.names.get(ident.name.as_str()) {
+                Some(&(_, symbol_id)) => self.ctx.create_bound_reference(symbol_id, flags),
+                None => self.ctx.create_unbound_reference(ident.name.to_compact_str(), flags),
+            },
+        };
+        ident.reference_id.set(Some(reference_id));
+    }
+
+    fn visit_binding_identifier(&mut self, ident: &mut BindingIdentifier<'a>) {
+        ident.span = SPAN;
+        let symbol_id = ident.symbol_id();
+        if let Some(uid) = self.top_level_uid(&ident.name, Some(symbol_id)) {
+            ident.name = uid;
+        }
+        ident.symbol_id.set(Some(self.bind_symbol(symbol_id)));
+    }
+
+    fn visit_function(&mut self, func: &mut Function<'a>, flags: ScopeFlags) {
+        func.span = SPAN;
+        walk_mut::walk_function(self, func, flags);
+    }
+
+    reset_spans! {
+        visit_array_expression, walk_array_expression, ArrayExpression<'a>;
+        visit_block_statement, walk_block_statement, BlockStatement<'a>;
+        visit_break_statement, walk_break_statement, BreakStatement<'a>;
+        visit_call_expression, walk_call_expression, CallExpression<'a>;
+        visit_expression_statement, walk_expression_statement, ExpressionStatement<'a>;
+        visit_for_in_statement, walk_for_in_statement, ForInStatement<'a>;
+        visit_for_statement, walk_for_statement, ForStatement<'a>;
+        visit_identifier_name, walk_identifier_name, IdentifierName<'a>;
+        visit_if_statement, walk_if_statement, IfStatement<'a>;
+        visit_new_expression, walk_new_expression, NewExpression<'a>;
+        visit_numeric_literal, walk_numeric_literal, NumericLiteral<'a>;
+        visit_object_expression, walk_object_expression, ObjectExpression<'a>;
+        visit_object_property, walk_object_property, ObjectProperty<'a>;
+        visit_reg_exp_literal, walk_reg_exp_literal, RegExpLiteral<'a>;
+        visit_return_statement, walk_return_statement, ReturnStatement<'a>;
+        visit_string_literal, walk_string_literal, StringLiteral<'a>;
+        visit_switch_statement, walk_switch_statement, SwitchStatement<'a>;
+        visit_throw_statement, walk_throw_statement, ThrowStatement<'a>;
+        visit_try_statement, walk_try_statement, TryStatement<'a>;
+        visit_update_expression, walk_update_expression, UpdateExpression<'a>;
+        visit_variable_declaration, walk_variable_declaration, VariableDeclaration<'a>;
+        visit_continue_statement, walk_continue_statement, ContinueStatement<'a>;
+        visit_boolean_literal, walk_boolean_literal, BooleanLiteral;
+        visit_null_literal, walk_null_literal, NullLiteral;
+        visit_this_expression, walk_this_expression, ThisExpression;
+        visit_empty_statement, walk_empty_statement, EmptyStatement;
+    }
+}
@@ -8,7 +8,10 @@ use oxc_traverse::{Traverse, TraverseCtx};
 use crate::{EnvOptions, TransformCtx};
 
 pub mod arrow_function_converter;
+pub mod function_name;
+pub mod global_object;
 pub mod helper_loader;
+pub mod inline_helpers;
 pub mod module_imports;
 pub mod statement_injector;
 pub mod top_level_statements;
@@ -24,7 +27,7 @@ pub struct Common<'a, 'ctx> {
     var_declarations: VarDeclarations<'a, 'ctx>,
     statement_injector: StatementInjector<'a, 'ctx>,
     top_level_statements: TopLevelStatements<'a, 'ctx>,
-    arrow_function_converter: ArrowFunctionConverter<'a>,
+    arrow_function_converter: ArrowFunctionConverter<'a, 'ctx>,
 }
 
 impl<'a, 'ctx> Common<'a, 'ctx> {
@@ -34,7 +37,7 @@ impl<'a, 'ctx> Common<'a, 'ctx> {
             var_declarations: VarDeclarations::new(ctx),
             statement_injector: StatementInjector::new(ctx),
             top_level_statements: TopLevelStatements::new(ctx),
-            arrow_function_converter: ArrowFunctionConverter::new(options),
+            arrow_function_converter: ArrowFunctionConverter::new(options, ctx),
         }
     }
 }
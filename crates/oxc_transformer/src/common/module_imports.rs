@@ -242,7 +242,7 @@ impl<'a> ModuleImportsStore<'a> {
         let Some(Import::Default(local)) = names.into_iter().next() else { unreachable!() };
         let id = local.create_binding_pattern(ctx);
         let decl = {
-            let init = ctx.ast.expression_call(SPAN, callee, NONE, args, false);
+            let init = ctx.ast.expression_call(SPAN, callee, NONE, args, false, false);
             let decl = ctx.ast.variable_declarator(SPAN, var_kind, id, Some(init), false);
             ctx.ast.vec1(decl)
         };
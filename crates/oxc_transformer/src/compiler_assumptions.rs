@@ -25,27 +25,21 @@ pub struct CompilerAssumptions {
     pub enumerable_module_meta: bool,
 
     #[serde(default)]
-    #[deprecated = "Not Implemented"]
     pub ignore_function_length: bool,
 
     #[serde(default)]
-    #[deprecated = "Not Implemented"]
     pub ignore_to_primitive_hint: bool,
 
     #[serde(default)]
-    #[deprecated = "Not Implemented"]
     pub iterable_is_array: bool,
 
     #[serde(default)]
-    #[deprecated = "Not Implemented"]
     pub mutable_template_object: bool,
 
     #[serde(default)]
-    #[deprecated = "Not Implemented"]
     pub no_class_calls: bool,
 
     #[serde(default)]
-    #[deprecated = "Not Implemented"]
     pub no_document_all: bool,
 
     #[serde(default)]
@@ -61,7 +55,6 @@ pub struct CompilerAssumptions {
     pub no_uninitialized_private_field_access: bool,
 
     #[serde(default)]
-    #[deprecated = "Not Implemented"]
     pub object_rest_no_symbols: bool,
 
     #[serde(default)]
@@ -85,11 +78,9 @@ pub struct CompilerAssumptions {
     pub set_computed_properties: bool,
 
     #[serde(default)]
-    #[deprecated = "Not Implemented"]
     pub set_public_class_fields: bool,
 
     #[serde(default)]
-    #[deprecated = "Not Implemented"]
     pub set_spread_properties: bool,
 
     #[serde(default)]
@@ -97,6 +88,5 @@ pub struct CompilerAssumptions {
     pub skip_for_of_iterator_closing: bool,
 
     #[serde(default)]
-    #[deprecated = "Not Implemented"]
     pub super_is_callable_constructor: bool,
 }
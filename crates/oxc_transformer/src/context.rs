@@ -9,11 +9,11 @@ use oxc_span::SourceType;
 
 use crate::{
     common::{
-        helper_loader::HelperLoaderStore, module_imports::ModuleImportsStore,
-        statement_injector::StatementInjectorStore, top_level_statements::TopLevelStatementsStore,
-        var_declarations::VarDeclarationsStore,
+        global_object::GlobalObjectStore, helper_loader::HelperLoaderStore,
+        module_imports::ModuleImportsStore, statement_injector::StatementInjectorStore,
+        top_level_statements::TopLevelStatementsStore, var_declarations::VarDeclarationsStore,
     },
-    Module, TransformOptions,
+    CompilerAssumptions, Module, TransformOptions,
 };
 
 pub struct TransformCtx<'a> {
@@ -31,6 +31,14 @@ pub struct TransformCtx<'a> {
 
     pub module: Module,
 
+    pub assumptions: CompilerAssumptions,
+
+    /// Preserve `name` of functions and classes which transforms would change
+    pub keep_names: bool,
+
+    /// Annotate calls to helpers which are known to be side-effect free with `/* @__PURE__ */`
+    pub pure_annotations: bool,
+
     // Helpers
     /// Manage helper loading
     pub helper_loader: HelperLoaderStore<'a>,
@@ -42,6 +50,8 @@ pub struct TransformCtx<'a> {
     pub statement_injector: StatementInjectorStore<'a>,
     /// Manage inserting statements at top of program globally
     pub top_level_statements: TopLevelStatementsStore<'a>,
+    /// Manage the binding of the global object
+    pub global_object: GlobalObjectStore<'a>,
 }
 
 impl<'a> TransformCtx<'a> {
@@ -61,11 +71,15 @@ impl<'a> TransformCtx<'a> {
             source_type: SourceType::default(),
             source_text: "",
             module: options.env.module,
+            assumptions: options.assumptions,
+            keep_names: options.keep_names,
+            pure_annotations: options.pure_annotations,
             helper_loader: HelperLoaderStore::new(&options.helper_loader),
             module_imports: ModuleImportsStore::new(),
             var_declarations: VarDeclarationsStore::new(),
             statement_injector: StatementInjectorStore::new(),
             top_level_statements: TopLevelStatementsStore::new(),
+            global_object: GlobalObjectStore::new(),
         }
     }
 
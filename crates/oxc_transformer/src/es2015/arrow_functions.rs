@@ -8,8 +8,6 @@
 //!
 //! Implementation is incomplete at present. Still TODO:
 //!
-//! * `spec` option.
-//! * Handle `arguments` in arrow functions.
 //! * Handle `new.target` in arrow functions.
 //! * Handle arrow function in function params (`function f(g = () => this) {}`).
 //!   Babel gets this wrong: <https://babeljs.io/repl#?code_lz=GYVwdgxgLglg9mABMOcAUAPRBeRaCUOAfIlABYwDOhA3gL5A&presets=&externalPlugins=%40babel%2Fplugin-transform-arrow-functions%407.24.7>
@@ -74,7 +72,7 @@
 //! * Wrap the generated function in .bind(this) and keeps uses of this inside the function as-is,
 //!   instead of using a renamed this.
 //! * Add a runtime check to ensure the functions are not instantiated.
-//! * Add names to arrow functions.
+//! * Add names to arrow functions. (Not implemented yet)
 //!
 //! #### Example
 //!
@@ -0,0 +1,789 @@
+//! ES2015: Block Scoping
+//!
+//! This plugin transforms `let` and `const` declarations to `var`, renaming bindings
+//! where needed to preserve block scope semantics.
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! let a = 1;
+//! {
+//!   let a = 2;
+//!   console.log(a);
+//! }
+//! for (let i = 0; i < 3; i++) {
+//!   fns.push(() => i);
+//! }
+//! ```
+//!
+//! Output:
+//! ```js
+//! var a = 1;
+//! {
+//!   var _a = 2;
+//!   console.log(_a);
+//! }
+//! var _loop = function (i) {
+//!   fns.push(() => i);
+//! };
+//! for (var i = 0; i < 3; i++) {
+//!   _loop(i);
+//! }
+//! ```
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-transform-block-scoping](https://babel.dev/docs/babel-plugin-transform-block-scoping).
+//!
+//! Block-scoped bindings are moved into the enclosing function (or program) scope when the block
+//! which declares them is entered, before any of the block's contents are visited.
+//! A binding is renamed if moving it would shadow, or collide with, another binding of the same name.
+//!
+//! If a closure inside a loop body captures a binding which is declared in the loop, the loop body
+//! is moved into a `_loop` function which is called on every iteration, so each closure is bound to
+//! the binding's value in that iteration. Loop head bindings are passed to `_loop` as parameters.
+//!
+//! Differences from Babel:
+//!
+//! * TDZ checks (`tdz` option) and errors on reassignment of `const` bindings are not implemented.
+//! * A loop body which contains a capturing closure cannot be moved into a `_loop` function
+//!   if it also contains `break`, `return`, `continue` to an outer label, `yield`, `await`,
+//!   `arguments`, `super`, `var` declarations, or assignments to the loop head bindings.
+//!   A warning is reported for such loops, and they are transformed without the `_loop` function.
+//!
+//! ## References:
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/main/packages/babel-plugin-transform-block-scoping>
+//! * Let and Const declarations specification: <https://tc39.es/ecma262/#sec-let-and-const-declarations>
+
+use rustc_hash::FxHashMap;
+
+use oxc_allocator::GetAddress;
+use oxc_ast::{
+    ast::*,
+    visit::{walk, walk_mut},
+    AstBuilder, Visit, VisitMut, NONE,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_ecmascript::BoundNames;
+use oxc_semantic::{ScopeFlags, ScopeId, SymbolFlags, SymbolId};
+use oxc_span::{CompactStr, SPAN};
+use oxc_traverse::{Ancestor, BoundIdentifier, Traverse, TraverseCtx};
+
+use crate::TransformCtx;
+
+pub struct BlockScoping<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
+    /// Bindings which were renamed when moved into their enclosing var scope
+    renamed_symbols: FxHashMap<SymbolId, Atom<'a>>,
+    /// Loop head bindings which are passed into a `_loop` function.
+    /// Maps each binding to the `_loop` function's scope, and the parameter it is passed as.
+    loop_params: FxHashMap<SymbolId, (ScopeId, BoundIdentifier<'a>)>,
+    /// Loops whose bodies are being moved into a `_loop` function
+    loops: Vec<LoopFunction<'a>>,
+}
+
+/// A loop whose body is being moved into a `_loop` function.
+struct LoopFunction<'a> {
+    /// Scope of loop head for `for` loops, or scope of loop body for `while` / `do while` loops
+    loop_scope_id: ScopeId,
+    /// Scope of the `_loop` function.
+    /// `None` until the loop body is entered, if the body is not a `BlockStatement`.
+    function_scope_id: Option<ScopeId>,
+    /// Labels of the loop. `continue` to these labels is replaced with `return`.
+    labels: Vec<Atom<'a>>,
+    /// Loop head bindings which are referenced in the loop body
+    head_symbol_ids: Vec<SymbolId>,
+    /// Parameters of the `_loop` function, and the loop head bindings they're passed from
+    params: Vec<(BoundIdentifier<'a>, SymbolId)>,
+    /// Loop body contains `this`, so `_loop` must be called with `_loop.call(this)`
+    uses_this: bool,
+}
+
+impl<'a, 'ctx> BlockScoping<'a, 'ctx> {
+    pub fn new(ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self {
+            ctx,
+            renamed_symbols: FxHashMap::default(),
+            loop_params: FxHashMap::default(),
+            loops: vec![],
+        }
+    }
+}
+
+impl<'a, 'ctx> Traverse<'a> for BlockScoping<'a, 'ctx> {
+    fn enter_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.wrap_loop_body(stmt, ctx);
+        self.enter_loop(stmt, ctx);
+    }
+
+    fn exit_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.exit_loop(stmt, ctx);
+    }
+
+    fn enter_block_statement(&mut self, block: &mut BlockStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.move_bindings_to_var_scope(block.scope_id(), ctx);
+    }
+
+    fn enter_for_statement(&mut self, stmt: &mut ForStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.move_bindings_to_var_scope(stmt.scope_id(), ctx);
+    }
+
+    fn enter_for_in_statement(&mut self, stmt: &mut ForInStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.move_bindings_to_var_scope(stmt.scope_id(), ctx);
+    }
+
+    fn enter_for_of_statement(&mut self, stmt: &mut ForOfStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.move_bindings_to_var_scope(stmt.scope_id(), ctx);
+    }
+
+    fn enter_switch_statement(
+        &mut self,
+        stmt: &mut SwitchStatement<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        self.move_bindings_to_var_scope(stmt.scope_id(), ctx);
+    }
+
+    /// Convert `let` / `const` to `var`.
+    fn enter_variable_declaration(
+        &mut self,
+        decl: &mut VariableDeclaration<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if !decl.kind.is_lexical() {
+            return;
+        }
+
+        // `let x;` in a loop body must be reset on each iteration: `var x = void 0;`
+        let needs_init = decl.kind == VariableDeclarationKind::Let && Self::is_in_loop_body(ctx);
+
+        decl.kind = VariableDeclarationKind::Var;
+        for declarator in decl.declarations.iter_mut() {
+            declarator.kind = VariableDeclarationKind::Var;
+            declarator.id.bound_names(&mut |ident| {
+                let flags = ctx.symbols_mut().get_flags_mut(ident.symbol_id());
+                *flags -= SymbolFlags::BlockScopedVariable | SymbolFlags::ConstVariable;
+                *flags |= SymbolFlags::FunctionScopedVariable;
+            });
+            if needs_init && declarator.init.is_none() {
+                declarator.init = Some(ctx.ast.void_0(SPAN));
+            }
+        }
+    }
+
+    fn enter_binding_identifier(
+        &mut self,
+        ident: &mut BindingIdentifier<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+        if self.renamed_symbols.is_empty() {
+            return;
+        }
+        if let Some(name) = self.renamed_symbols.get(&ident.symbol_id()) {
+            ident.name = name.clone();
+        }
+    }
+
+    fn enter_identifier_reference(
+        &mut self,
+        ident: &mut IdentifierReference<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if self.renamed_symbols.is_empty() && self.loop_params.is_empty() {
+            return;
+        }
+
+        let reference = ctx.symbols().get_reference(ident.reference_id());
+        let Some(symbol_id) = reference.symbol_id() else { return };
+
+        // Reference to a loop head binding inside the `_loop` function refers to its parameter
+        if let Some((scope_id, param)) = self.loop_params.get(&symbol_id) {
+            if ctx.ancestor_scopes().any(|id| id == *scope_id) {
+                let flags = reference.flags();
+                ctx.delete_reference_for_identifier(ident);
+                ident.reference_id.set(Some(ctx.create_bound_reference(param.symbol_id, flags)));
+                ident.name = param.name.clone();
+                return;
+            }
+        }
+
+        if let Some(name) = self.renamed_symbols.get(&symbol_id) {
+            ident.name = name.clone();
+        }
+    }
+}
+
+impl<'a, 'ctx> BlockScoping<'a, 'ctx> {
+    /// Move `let` / `const` bindings of a block scope into the nearest var scope,
+    /// renaming them if they'd clash with another binding or an unresolved reference.
+    fn move_bindings_to_var_scope(&mut self, scope_id: ScopeId, ctx: &mut TraverseCtx<'a>) {
+        if ctx.scopes().get_flags(scope_id).is_var() {
+            return;
+        }
+
+        let symbol_ids = ctx
+            .scopes()
+            .get_bindings(scope_id)
+            .values()
+            .copied()
+            .filter(|&symbol_id| {
+                ctx.symbols().get_flags(symbol_id).contains(SymbolFlags::BlockScopedVariable)
+            })
+            .collect::<Vec<_>>();
+        if symbol_ids.is_empty() {
+            return;
+        }
+
+        let var_scope_id = ctx
+            .scopes()
+            .ancestors(scope_id)
+            .find(|&id| ctx.scopes().get_flags(id).is_var())
+            .unwrap();
+
+        for symbol_id in symbol_ids {
+            let name = CompactStr::from(ctx.symbols().get_name(symbol_id));
+            let needs_rename = ctx
+                .scopes()
+                .ancestors(scope_id)
+                .skip(1)
+                .any(|id| ctx.scopes().has_binding(id, &name))
+                || ctx.scopes().root_unresolved_references().contains_key(&name);
+
+            ctx.scopes_mut().remove_binding(scope_id, &name);
+            let name = if needs_rename {
+                let new_name = ctx.generate_uid_name(&name);
+                ctx.symbols_mut().set_name(symbol_id, new_name.clone());
+                self.renamed_symbols.insert(symbol_id, ctx.ast.atom(&new_name));
+                new_name
+            } else {
+                name
+            };
+            ctx.scopes_mut().add_binding(var_scope_id, name, symbol_id);
+            ctx.symbols_mut().set_scope_id(symbol_id, var_scope_id);
+        }
+    }
+
+    /// Check if a variable declaration is in a loop body, but not a loop head.
+    fn is_in_loop_body(ctx: &TraverseCtx<'a>) -> bool {
+        let mut ancestors = ctx.ancestors();
+        if matches!(
+            ancestors.next(),
+            Some(
+                Ancestor::ForStatementInit(_)
+                    | Ancestor::ForInStatementLeft(_)
+                    | Ancestor::ForOfStatementLeft(_)
+            )
+        ) {
+            return false;
+        }
+
+        for ancestor in ancestors {
+            match ancestor {
+                Ancestor::ForStatementBody(_)
+                | Ancestor::ForInStatementBody(_)
+                | Ancestor::ForOfStatementBody(_)
+                | Ancestor::WhileStatementBody(_)
+                | Ancestor::DoWhileStatementBody(_) => return true,
+                Ancestor::FunctionBody(_)
+                | Ancestor::ArrowFunctionExpressionBody(_)
+                | Ancestor::StaticBlockBody(_) => return false,
+                _ => {}
+            }
+        }
+        false
+    }
+
+    /// If a closure in the loop body captures a binding declared in the loop,
+    /// prepare to move the loop body into a `_loop` function.
+    fn enter_loop(&mut self, stmt: &Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        let (head_scope_id, body) = match stmt {
+            Statement::ForStatement(stmt) => (Some(stmt.scope_id()), &stmt.body),
+            Statement::ForInStatement(stmt) => (Some(stmt.scope_id()), &stmt.body),
+            Statement::ForOfStatement(stmt) => (Some(stmt.scope_id()), &stmt.body),
+            Statement::WhileStatement(stmt) => (None, &stmt.body),
+            Statement::DoWhileStatement(stmt) => (None, &stmt.body),
+            _ => return,
+        };
+        let loop_scope_id = match (head_scope_id, body) {
+            (Some(scope_id), _) => scope_id,
+            (None, Statement::BlockStatement(block)) => block.scope_id(),
+            // `while (x) foo;` - body cannot contain any block-scoped bindings
+            (None, _) => return,
+        };
+
+        // `outer: for (;;) {}`
+        let labels = ctx
+            .ancestors()
+            .map_while(|ancestor| match ancestor {
+                Ancestor::LabeledStatementBody(labeled) => Some(labeled.label().name.clone()),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        let mut analyzer = LoopBodyAnalyzer::new(head_scope_id, loop_scope_id, &labels, ctx);
+        analyzer.visit_statement(body);
+        if !analyzer.has_capturing_closure {
+            return;
+        }
+        if let Some(span) = analyzer.unsupported_span {
+            self.ctx.error(
+                OxcDiagnostic::warn(
+                    "Closures in this loop body will share block-scoped bindings between iterations, because the loop body contains a statement or expression which cannot be moved into a function.",
+                )
+                .with_label(span),
+            );
+            return;
+        }
+
+        let mut loop_function = LoopFunction {
+            loop_scope_id,
+            function_scope_id: None,
+            head_symbol_ids: analyzer.head_symbol_ids,
+            params: vec![],
+            uses_this: analyzer.uses_this,
+            labels,
+        };
+        if let Statement::BlockStatement(block) = body {
+            let scope_id = block.scope_id();
+            let parent_id = ctx.scopes().get_parent_id(scope_id).unwrap();
+            let flags = ctx.scopes().get_new_scope_flags(ScopeFlags::Function, parent_id);
+            *ctx.scopes_mut().get_flags_mut(scope_id) = flags;
+            self.init_loop_function_scope(&mut loop_function, scope_id, ctx);
+        }
+        self.loops.push(loop_function);
+    }
+
+    /// Wrap loop body in a block if it's not one already, so it can be moved into a `_loop` function.
+    ///
+    /// `for (let i of arr) fns.push(() => i);` -> `for (let i of arr) { fns.push(() => i); }`
+    fn wrap_loop_body(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Some(loop_function) = self.loops.last_mut() else { return };
+        if loop_function.function_scope_id.is_some()
+            || loop_function.loop_scope_id != ctx.current_scope_id()
+            || !matches!(
+                ctx.parent(),
+                Ancestor::ForStatementBody(_)
+                    | Ancestor::ForInStatementBody(_)
+                    | Ancestor::ForOfStatementBody(_)
+            )
+        {
+            return;
+        }
+
+        let scope_id = ctx.insert_scope_below_statement(stmt, ScopeFlags::Function);
+        let body = ctx.ast.move_statement(stmt);
+        *stmt = Statement::BlockStatement(ctx.ast.alloc_block_statement_with_scope_id(
+            SPAN,
+            ctx.ast.vec1(body),
+            scope_id,
+        ));
+
+        let mut loop_function = self.loops.pop().unwrap();
+        self.init_loop_function_scope(&mut loop_function, scope_id, ctx);
+        self.loops.push(loop_function);
+    }
+
+    /// Create parameters of `_loop` function for the loop head bindings referenced in loop body.
+    fn init_loop_function_scope(
+        &mut self,
+        loop_function: &mut LoopFunction<'a>,
+        scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        for &symbol_id in &loop_function.head_symbol_ids {
+            let name = ctx.ast.atom(ctx.symbols().get_name(symbol_id));
+            let param = ctx.generate_binding(name, scope_id, SymbolFlags::FunctionScopedVariable);
+            self.loop_params.insert(symbol_id, (scope_id, param.clone()));
+            loop_function.params.push((param, symbol_id));
+        }
+        loop_function.function_scope_id = Some(scope_id);
+    }
+
+    /// Move loop body into a `_loop` function, and call it from the loop body.
+    ///
+    /// ```js
+    /// for (var i = 0; i < 3; i++) {
+    ///   fns.push(() => i);
+    ///   continue;
+    /// }
+    /// ```
+    /// ->
+    /// ```js
+    /// var _loop = function (i) {
+    ///   fns.push(() => i);
+    ///   return;
+    /// };
+    /// for (var i = 0; i < 3; i++) {
+    ///   _loop(i);
+    /// }
+    /// ```
+    fn exit_loop(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Some(loop_function) = self.loops.last() else { return };
+        let (body, has_head_scope) = match stmt {
+            Statement::ForStatement(stmt) => (&mut stmt.body, true),
+            Statement::ForInStatement(stmt) => (&mut stmt.body, true),
+            Statement::ForOfStatement(stmt) => (&mut stmt.body, true),
+            Statement::WhileStatement(stmt) => (&mut stmt.body, false),
+            Statement::DoWhileStatement(stmt) => (&mut stmt.body, false),
+            _ => return,
+        };
+        let Statement::BlockStatement(block) = body else { return };
+        let scope_id = block.scope_id();
+        if loop_function.function_scope_id != Some(scope_id) {
+            return;
+        }
+        let loop_function = self.loops.pop().unwrap();
+        for (_, symbol_id) in &loop_function.params {
+            self.loop_params.remove(symbol_id);
+        }
+
+        // `function (i) { ... }`
+        let mut statements = ctx.ast.move_vec(&mut block.body);
+        ContinueToReturn::new(&loop_function.labels, ctx.ast).visit_statements(&mut statements);
+        let params = ctx.ast.formal_parameters(
+            SPAN,
+            FormalParameterKind::FormalParameter,
+            ctx.ast.vec_from_iter(loop_function.params.iter().map(|(param, _)| {
+                ctx.ast.plain_formal_parameter(SPAN, param.create_binding_pattern(ctx))
+            })),
+            NONE,
+        );
+        let function = Expression::FunctionExpression(ctx.ast.alloc_plain_function_with_scope_id(
+            FunctionType::FunctionExpression,
+            SPAN,
+            None,
+            params,
+            ctx.ast.function_body(SPAN, ctx.ast.vec(), statements),
+            scope_id,
+        ));
+        let current_scope_id = ctx.current_scope_id();
+        ctx.scopes_mut().change_parent_id(scope_id, Some(current_scope_id));
+
+        // `_loop(i)` or `_loop.call(this, i)`
+        let var_scope_id = ctx
+            .scopes()
+            .ancestors(current_scope_id)
+            .find(|&id| ctx.scopes().get_flags(id).is_var())
+            .unwrap();
+        let loop_binding =
+            ctx.generate_uid("loop", var_scope_id, SymbolFlags::FunctionScopedVariable);
+        let mut arguments = ctx.ast.vec_with_capacity(loop_function.params.len() + 1);
+        if loop_function.uses_this {
+            arguments.push(Argument::from(ctx.ast.expression_this(SPAN)));
+        }
+        for &(_, symbol_id) in &loop_function.params {
+            let name = ctx.ast.atom(ctx.symbols().get_name(symbol_id));
+            let ident = BoundIdentifier::new(name, symbol_id);
+            arguments.push(Argument::from(ident.create_read_expression(ctx)));
+        }
+        let mut callee = loop_binding.create_read_expression(ctx);
+        if loop_function.uses_this {
+            let property = ctx.ast.identifier_name(SPAN, "call");
+            callee =
+                Expression::from(ctx.ast.member_expression_static(SPAN, callee, property, false));
+        }
+        let call = ctx.ast.expression_call(SPAN, callee, NONE, arguments, false, false);
+
+        // `{ _loop(i); }`
+        let block_parent_id =
+            if has_head_scope { loop_function.loop_scope_id } else { current_scope_id };
+        let block_scope_id = ctx.create_child_scope(block_parent_id, ScopeFlags::empty());
+        block.body = ctx.ast.vec1(ctx.ast.statement_expression(SPAN, call));
+        block.scope_id.set(Some(block_scope_id));
+
+        self.insert_loop_function(stmt, &loop_binding, function, ctx);
+    }
+
+    /// Insert `var _loop = function () {}` before the loop.
+    ///
+    /// If the loop is not in a list of statements (e.g. `if (x) for (let i of y) {}`),
+    /// the declaration is hoisted to the top of the enclosing block instead.
+    fn insert_loop_function(
+        &self,
+        stmt: &Statement<'a>,
+        binding: &BoundIdentifier<'a>,
+        function: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let mut address = stmt.address();
+        for ancestor in ctx.ancestors() {
+            match ancestor {
+                Ancestor::LabeledStatementBody(labeled) => address = labeled.address(),
+                Ancestor::ProgramBody(_)
+                | Ancestor::BlockStatementBody(_)
+                | Ancestor::FunctionBodyStatements(_)
+                | Ancestor::StaticBlockBody(_)
+                | Ancestor::SwitchCaseConsequent(_)
+                | Ancestor::TSModuleBlockBody(_) => {
+                    let declarator = ctx.ast.variable_declarator(
+                        SPAN,
+                        VariableDeclarationKind::Var,
+                        binding.create_binding_pattern(ctx),
+                        Some(function),
+                        false,
+                    );
+                    let declaration =
+                        Statement::VariableDeclaration(ctx.ast.alloc_variable_declaration(
+                            SPAN,
+                            VariableDeclarationKind::Var,
+                            ctx.ast.vec1(declarator),
+                            false,
+                        ));
+                    self.ctx.statement_injector.insert_before(&address, declaration);
+                    return;
+                }
+                _ => break,
+            }
+        }
+        self.ctx.var_declarations.insert_var(binding, Some(function), ctx);
+    }
+}
+
+/// Collects information about a loop body, to determine whether it needs to be moved
+/// into a `_loop` function, and whether that's possible.
+struct LoopBodyAnalyzer<'a, 'b> {
+    ctx: &'b TraverseCtx<'a>,
+    /// Scope of loop head, for `for` loops
+    head_scope_id: Option<ScopeId>,
+    /// Outermost scope whose block-scoped bindings are created anew in each iteration
+    loop_scope_id: ScopeId,
+    /// Depth of functions, including arrow functions
+    function_depth: usize,
+    /// Depth of functions, excluding arrow functions
+    non_arrow_function_depth: usize,
+    /// Depth of loops and `switch` statements, which `break` can exit
+    breakable_depth: usize,
+    /// Labels of the loop
+    loop_labels: &'b [Atom<'a>],
+    /// Labels declared within loop body
+    labels: Vec<Atom<'a>>,
+    head_symbol_ids: Vec<SymbolId>,
+    has_capturing_closure: bool,
+    uses_this: bool,
+    /// Span of first statement or expression which can't be moved into a function
+    unsupported_span: Option<Span>,
+}
+
+impl<'a, 'b> LoopBodyAnalyzer<'a, 'b> {
+    fn new(
+        head_scope_id: Option<ScopeId>,
+        loop_scope_id: ScopeId,
+        loop_labels: &'b [Atom<'a>],
+        ctx: &'b TraverseCtx<'a>,
+    ) -> Self {
+        Self {
+            ctx,
+            head_scope_id,
+            loop_scope_id,
+            function_depth: 0,
+            non_arrow_function_depth: 0,
+            breakable_depth: 0,
+            loop_labels,
+            labels: vec![],
+            head_symbol_ids: vec![],
+            has_capturing_closure: false,
+            uses_this: false,
+            unsupported_span: None,
+        }
+    }
+
+    fn set_unsupported(&mut self, span: Span) {
+        self.unsupported_span.get_or_insert(span);
+    }
+
+    /// Check if binding is block-scoped and declared within the loop, outside of any functions.
+    fn is_per_iteration_binding(&self, symbol_id: SymbolId) -> bool {
+        let symbols = self.ctx.symbols();
+        if !symbols.get_flags(symbol_id).contains(SymbolFlags::BlockScopedVariable) {
+            return false;
+        }
+        let scopes = self.ctx.scopes();
+        for scope_id in scopes.ancestors(symbols.get_scope_id(symbol_id)) {
+            if scope_id == self.loop_scope_id {
+                return true;
+            }
+            if scopes.get_flags(scope_id).is_var() {
+                return false;
+            }
+        }
+        false
+    }
+}
+
+impl<'a, 'b> Visit<'a> for LoopBodyAnalyzer<'a, 'b> {
+    fn visit_statement(&mut self, stmt: &Statement<'a>) {
+        match stmt {
+            Statement::ForStatement(_)
+            | Statement::ForInStatement(_)
+            | Statement::ForOfStatement(_)
+            | Statement::WhileStatement(_)
+            | Statement::DoWhileStatement(_)
+            | Statement::SwitchStatement(_) => {
+                self.breakable_depth += 1;
+                walk::walk_statement(self, stmt);
+                self.breakable_depth -= 1;
+            }
+            _ => walk::walk_statement(self, stmt),
+        }
+    }
+
+    fn visit_labeled_statement(&mut self, stmt: &LabeledStatement<'a>) {
+        self.labels.push(stmt.label.name.clone());
+        walk::walk_labeled_statement(self, stmt);
+        self.labels.pop();
+    }
+
+    fn visit_break_statement(&mut self, stmt: &BreakStatement<'a>) {
+        if self.function_depth > 0 {
+            return;
+        }
+        let is_local = match &stmt.label {
+            Some(label) => self.labels.contains(&label.name),
+            None => self.breakable_depth > 0,
+        };
+        if !is_local {
+            self.set_unsupported(stmt.span);
+        }
+    }
+
+    /// `continue` which continues the loop is replaced with `return`
+    fn visit_continue_statement(&mut self, stmt: &ContinueStatement<'a>) {
+        if self.function_depth > 0 {
+            return;
+        }
+        if let Some(label) = &stmt.label {
+            if !self.labels.contains(&label.name) && !self.loop_labels.contains(&label.name) {
+                self.set_unsupported(stmt.span);
+            }
+        }
+    }
+
+    fn visit_return_statement(&mut self, stmt: &ReturnStatement<'a>) {
+        if self.function_depth == 0 {
+            self.set_unsupported(stmt.span);
+        }
+        walk::walk_return_statement(self, stmt);
+    }
+
+    fn visit_yield_expression(&mut self, expr: &YieldExpression<'a>) {
+        if self.function_depth == 0 {
+            self.set_unsupported(expr.span);
+        }
+        walk::walk_yield_expression(self, expr);
+    }
+
+    fn visit_await_expression(&mut self, expr: &AwaitExpression<'a>) {
+        if self.function_depth == 0 {
+            self.set_unsupported(expr.span);
+        }
+        walk::walk_await_expression(self, expr);
+    }
+
+    fn visit_variable_declaration(&mut self, decl: &VariableDeclaration<'a>) {
+        if self.function_depth == 0 && decl.kind == VariableDeclarationKind::Var {
+            self.set_unsupported(decl.span);
+        }
+        walk::walk_variable_declaration(self, decl);
+    }
+
+    fn visit_this_expression(&mut self, _expr: &ThisExpression) {
+        if self.non_arrow_function_depth == 0 {
+            self.uses_this = true;
+        }
+    }
+
+    fn visit_super(&mut self, expr: &Super) {
+        if self.non_arrow_function_depth == 0 {
+            self.set_unsupported(expr.span);
+        }
+    }
+
+    fn visit_function(&mut self, func: &Function<'a>, flags: ScopeFlags) {
+        self.function_depth += 1;
+        self.non_arrow_function_depth += 1;
+        walk::walk_function(self, func, flags);
+        self.function_depth -= 1;
+        self.non_arrow_function_depth -= 1;
+    }
+
+    fn visit_arrow_function_expression(&mut self, arrow: &ArrowFunctionExpression<'a>) {
+        self.function_depth += 1;
+        walk::walk_arrow_function_expression(self, arrow);
+        self.function_depth -= 1;
+    }
+
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+        let reference = self.ctx.symbols().get_reference(ident.reference_id());
+        let Some(symbol_id) = reference.symbol_id() else {
+            if self.non_arrow_function_depth == 0 && ident.name == "arguments" {
+                self.set_unsupported(ident.span);
+            }
+            return;
+        };
+
+        if Some(self.ctx.symbols().get_scope_id(symbol_id)) == self.head_scope_id {
+            if reference.is_write() {
+                self.set_unsupported(ident.span);
+            }
+            if !self.head_symbol_ids.contains(&symbol_id) {
+                self.head_symbol_ids.push(symbol_id);
+            }
+        }
+
+        if self.function_depth > 0
+            && !self.has_capturing_closure
+            && self.is_per_iteration_binding(symbol_id)
+        {
+            self.has_capturing_closure = true;
+        }
+    }
+}
+
+/// Replace `continue` statements which continue the loop with `return`.
+struct ContinueToReturn<'a, 'b> {
+    /// Labels of the loop
+    labels: &'b [Atom<'a>],
+    /// Depth of loops nested in the loop body
+    loop_depth: usize,
+    ast: AstBuilder<'a>,
+}
+
+impl<'a, 'b> ContinueToReturn<'a, 'b> {
+    fn new(labels: &'b [Atom<'a>], ast: AstBuilder<'a>) -> Self {
+        Self { labels, loop_depth: 0, ast }
+    }
+}
+
+impl<'a, 'b> VisitMut<'a> for ContinueToReturn<'a, 'b> {
+    fn visit_statement(&mut self, stmt: &mut Statement<'a>) {
+        match stmt {
+            Statement::ContinueStatement(continue_stmt) => {
+                let continues_loop = match &continue_stmt.label {
+                    Some(label) => self.labels.contains(&label.name),
+                    // Unlabeled `continue` in a nested loop continues that loop
+                    None => self.loop_depth == 0,
+                };
+                if continues_loop {
+                    *stmt = self.ast.statement_return(continue_stmt.span, None);
+                }
+            }
+            Statement::ForStatement(_)
+            | Statement::ForInStatement(_)
+            | Statement::ForOfStatement(_)
+            | Statement::WhileStatement(_)
+            | Statement::DoWhileStatement(_) => {
+                self.loop_depth += 1;
+                walk_mut::walk_statement(self, stmt);
+                self.loop_depth -= 1;
+            }
+            _ => walk_mut::walk_statement(self, stmt),
+        }
+    }
+
+    fn visit_function(&mut self, _func: &mut Function<'a>, _flags: ScopeFlags) {}
+
+    fn visit_arrow_function_expression(&mut self, _arrow: &mut ArrowFunctionExpression<'a>) {}
+}
@@ -0,0 +1,1141 @@
+//! ES2015: Classes
+//!
+//! This plugin transforms classes into constructor functions, with methods defined by
+//! `_createClass` helper.
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! class MyError extends Error {
+//!   constructor(message) {
+//!     super(message);
+//!     this.name = "MyError";
+//!   }
+//!   toString() {
+//!     return "MyError: " + super.toString();
+//!   }
+//! }
+//! ```
+//!
+//! Output:
+//! ```js
+//! let MyError = function (_Error) {
+//!   function MyError(message) {
+//!     var _this;
+//!     babelHelpers.classCallCheck(this, MyError);
+//!     _this = babelHelpers.callSuper(this, MyError, [message]);
+//!     _this.name = "MyError";
+//!     return _this;
+//!   }
+//!   babelHelpers.inherits(MyError, _Error);
+//!   return babelHelpers.createClass(MyError, [{
+//!     key: "toString",
+//!     value: function toString() {
+//!       return "MyError: " + babelHelpers.get(babelHelpers.getPrototypeOf(MyError.prototype), "toString", this).call(this);
+//!     }
+//!   }]);
+//! }(Error);
+//! ```
+//!
+//! ## Implementation
+//!
+//! WORK IN PROGRESS. INCOMPLETE.
+//!
+//! Implementation based on [@babel/plugin-transform-classes](https://babel.dev/docs/babel-plugin-transform-classes).
+//!
+//! Classes are transformed on exit, after the class body has been transformed by other plugins.
+//!
+//! In a derived class, `super(...)` is replaced with `_callSuper` helper, which constructs the
+//! instance with `Reflect.construct` where it's available, so the super class is called as
+//! a constructor. This is required to subclass built-ins such as `Error` and `Array`.
+//! Where `Reflect.construct` is not available, `_callSuper` falls back to calling the super class
+//! as a function. The instance is stored in `_this`, which replaces `this` in the constructor.
+//!
+//! Compiler assumptions:
+//!
+//! * `superIsCallableConstructor`: `super(...)` calls the super class as a function:
+//!   `_this = _Error.call(this, message) || this`. This is smaller and faster, but built-ins
+//!   cannot be subclassed.
+//! * `noClassCalls`: `_classCallCheck` is not inserted in constructors, so calling a class
+//!   without `new` does not throw.
+//!
+//! A class is left untransformed if:
+//! * It has properties, static blocks, private methods or decorators.
+//! * It assigns to a property of `super` (`super.x = 1`).
+//!
+//! Differences from Babel:
+//!
+//! * Super classes which are built-ins are not wrapped with `_wrapNativeSuper` helper.
+//! * Class declarations are replaced with `let` declarations, which are not transformed by block scoping.
+//! * Methods with keys which are not valid identifiers (`"a b"() {}`) are not given a function name.
+//!
+//! ## References:
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/main/packages/babel-plugin-transform-classes>
+//! * Class definitions specification: <https://tc39.es/ecma262/#sec-class-definitions>
+
+use std::{cell::Cell, mem};
+
+use oxc_allocator::{Box as ArenaBox, Vec as ArenaVec};
+use oxc_ast::{
+    ast::*,
+    visit::{walk, walk_mut},
+    Visit, VisitMut, NONE,
+};
+use oxc_semantic::{
+    Reference, ReferenceFlags, ReferenceId, ScopeFlags, ScopeId, ScopeTree, SymbolFlags, SymbolId,
+};
+use oxc_span::SPAN;
+use oxc_syntax::{
+    identifier::is_identifier_name,
+    keyword::is_reserved_keyword,
+    operator::{AssignmentOperator, LogicalOperator},
+};
+use oxc_traverse::{Ancestor, BoundIdentifier, Traverse, TraverseCtx};
+
+use crate::{
+    common::{function_name, helper_loader::Helper},
+    TransformCtx,
+};
+
+use super::ChildScopeCollector;
+
+pub struct Classes<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
+}
+
+impl<'a, 'ctx> Classes<'a, 'ctx> {
+    pub fn new(ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self { ctx }
+    }
+}
+
+impl<'a, 'ctx> Traverse<'a> for Classes<'a, 'ctx> {
+    fn exit_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        match stmt {
+            // `class A {}` -> `let A = ...`
+            Statement::ClassDeclaration(class) => {
+                if let Some(decl) = self.transform_class_declaration(class, ctx) {
+                    *stmt = Statement::VariableDeclaration(decl);
+                }
+            }
+            // `export class A {}` -> `export let A = ...`
+            Statement::ExportNamedDeclaration(export_decl) => {
+                if let Some(Declaration::ClassDeclaration(class)) = &mut export_decl.declaration {
+                    if let Some(decl) = self.transform_class_declaration(class, ctx) {
+                        export_decl.declaration = Some(Declaration::VariableDeclaration(decl));
+                    }
+                }
+            }
+            Statement::ExportDefaultDeclaration(export_decl) => {
+                let ExportDefaultDeclarationKind::ClassDeclaration(class) =
+                    &mut export_decl.declaration
+                else {
+                    return;
+                };
+                if class.id.is_some() {
+                    // `export default class A {}` -> `let A = ...; export { A as default };`
+                    let Some(decl) = self.transform_class_declaration(class, ctx) else { return };
+                    let BindingPatternKind::BindingIdentifier(ident) =
+                        &decl.declarations[0].id.kind
+                    else {
+                        unreachable!()
+                    };
+                    let binding = BoundIdentifier::from_binding_ident(ident);
+                    *stmt = Statement::VariableDeclaration(decl);
+                    ctx.symbols_mut().get_flags_mut(binding.symbol_id).insert(SymbolFlags::Export);
+
+                    let specifier = ctx.ast.export_specifier(
+                        SPAN,
+                        ModuleExportName::IdentifierReference(binding.create_read_reference(ctx)),
+                        ctx.ast.module_export_name_identifier_name(SPAN, "default"),
+                        ImportOrExportKind::Value,
+                    );
+                    let export_decl =
+                        ctx.ast.plain_export_named_declaration(SPAN, ctx.ast.vec1(specifier), None);
+                    self.ctx
+                        .statement_injector
+                        .insert_after(stmt, Statement::ExportNamedDeclaration(export_decl));
+                } else if let Some(expr) = self.transform_class(
+                    class,
+                    ClassName::Expression(None),
+                    self.ctx.keep_names.then(|| Atom::from("default")),
+                    ctx,
+                ) {
+                    // `export default class {}` -> `export default ...`
+                    export_decl.declaration = ExportDefaultDeclarationKind::from(expr);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Expression::ClassExpression(class) = expr else { return };
+
+        // Infer name of anonymous class from the variable it's assigned to: `let A = class {}`
+        let inferred_name = match ctx.parent() {
+            Ancestor::VariableDeclaratorInit(declarator) => match &declarator.id().kind {
+                BindingPatternKind::BindingIdentifier(ident) => Some(ident.name.clone()),
+                _ => None,
+            },
+            _ => None,
+        };
+        let keep_name = if self.ctx.keep_names && class.id.is_none() {
+            function_name::infer_function_name(ctx)
+        } else {
+            None
+        };
+        if let Some(new_expr) =
+            self.transform_class(class, ClassName::Expression(inferred_name), keep_name, ctx)
+        {
+            *expr = new_expr;
+        }
+    }
+}
+
+/// Name of the constructor function a class is transformed into.
+enum ClassName<'a> {
+    /// `class A {}`, with the symbol of the binding outside of the class
+    Declaration(SymbolId),
+    /// `(class A {})` or `(class {})`, with a name inferred from the context for an anonymous class
+    Expression(Option<Atom<'a>>),
+}
+
+impl<'a, 'ctx> Classes<'a, 'ctx> {
+    /// `class A {}` -> `let A = ...`
+    fn transform_class_declaration(
+        &self,
+        class: &mut Class<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<ArenaBox<'a, VariableDeclaration<'a>>> {
+        let binding = BoundIdentifier::from_binding_ident(class.id.as_ref()?);
+        let init =
+            self.transform_class(class, ClassName::Declaration(binding.symbol_id), None, ctx)?;
+
+        let flags = ctx.symbols_mut().get_flags_mut(binding.symbol_id);
+        *flags = SymbolFlags::BlockScopedVariable | (*flags & SymbolFlags::Export);
+        let ident = class.id.take().unwrap();
+        let id = ctx.ast.binding_pattern(
+            BindingPatternKind::BindingIdentifier(ctx.alloc(ident)),
+            NONE,
+            false,
+        );
+        let declarator =
+            ctx.ast.variable_declarator(SPAN, VariableDeclarationKind::Let, id, Some(init), false);
+        Some(ctx.ast.alloc_variable_declaration(
+            class.span,
+            VariableDeclarationKind::Let,
+            ctx.ast.vec1(declarator),
+            false,
+        ))
+    }
+
+    /// Transform class into an expression which evaluates to the constructor function.
+    ///
+    /// If `keep_name` is given (`keep_names` option), and the constructor function gets a different name,
+    /// the name is set to `keep_name` with `Object.defineProperty`.
+    ///
+    /// Returns `None` if the class cannot be transformed.
+    fn transform_class(
+        &self,
+        class: &mut Class<'a>,
+        name: ClassName<'a>,
+        keep_name: Option<Atom<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<Expression<'a>> {
+        if !Self::can_transform(class) {
+            return None;
+        }
+        // A static `name` method would be overwritten
+        let keep_name = keep_name.filter(|_| !Self::may_have_static_name(class));
+
+        let class_scope_id = class.scope_id();
+        let parent_scope_id = ctx.current_scope_id();
+        let super_class = class.super_class.take();
+
+        // Collect references to the class name before the class body is taken apart
+        let name_reference_ids = match (&name, &class.id) {
+            (_, Some(BindingIdentifier { name, .. }))
+            | (ClassName::Expression(Some(name)), None) => {
+                let mut collector = NameReferenceCollector::new(name);
+                collector.visit_class_body(&class.body);
+                collector.reference_ids
+            }
+            (_, None) => vec![],
+        };
+
+        // Class bodies are always strict mode code, but functions they are transformed into
+        // are only strict if the surrounding code is
+        let is_strict_mode = ctx.current_scope_flags().is_strict_mode();
+        let strict_mode_flag =
+            if is_strict_mode { ScopeFlags::StrictMode } else { ScopeFlags::empty() };
+        // Scopes inside methods also inherited flags of the method, e.g. `ScopeFlags::Constructor`
+        ScopeFlagsUpdater::new(ctx.scopes_mut(), !is_strict_mode).visit_class_body(&class.body);
+        *ctx.scopes_mut().get_flags_mut(class_scope_id) = ScopeFlags::Function | strict_mode_flag;
+
+        let mut constructor = None;
+        let mut methods = vec![];
+        for element in class.body.body.drain(..) {
+            let ClassElement::MethodDefinition(method) = element else { unreachable!() };
+            if method.kind.is_constructor() {
+                constructor = Some(method.unbox().value);
+            } else {
+                methods.push(method);
+            }
+        }
+
+        // Class with no super class and no methods is transformed to
+        // `_createClass(function A() {})`, without a wrapper function.
+        // Otherwise, class scope becomes scope of the wrapper function `function (_Super) { ... }`.
+        let has_wrapper = super_class.is_some() || !methods.is_empty();
+
+        let is_implicit_constructor = constructor.is_none();
+        let mut constructor = constructor.unwrap_or_else(|| {
+            let scope_id =
+                ctx.create_child_scope(class_scope_id, ScopeFlags::Function | strict_mode_flag);
+            let params = ctx.ast.formal_parameters(
+                SPAN,
+                FormalParameterKind::FormalParameter,
+                ctx.ast.vec(),
+                NONE,
+            );
+            let body = ctx.ast.function_body(SPAN, ctx.ast.vec(), ctx.ast.vec());
+            ctx.ast.alloc_plain_function_with_scope_id(
+                FunctionType::FunctionExpression,
+                SPAN,
+                None,
+                params,
+                body,
+                scope_id,
+            )
+        });
+        let constructor_scope_id = constructor.scope_id();
+        ctx.scopes_mut().get_flags_mut(constructor_scope_id).remove(ScopeFlags::Modifiers);
+        if !has_wrapper {
+            ctx.scopes_mut().change_parent_id(constructor_scope_id, Some(parent_scope_id));
+        }
+
+        // Function declaration binds its name in the wrapper function's scope.
+        // Function expression binds its name in its own scope.
+        let (binding_scope_id, binding_flags) = if !has_wrapper {
+            (constructor_scope_id, SymbolFlags::Function)
+        } else if is_strict_mode {
+            (class_scope_id, SymbolFlags::Function | SymbolFlags::BlockScopedVariable)
+        } else {
+            (class_scope_id, SymbolFlags::FunctionScopedVariable)
+        };
+        let binding = Self::create_class_binding(
+            class,
+            name,
+            name_reference_ids,
+            binding_scope_id,
+            binding_flags,
+            ctx,
+        );
+
+        let super_binding = super_class.as_ref().map(|super_class| {
+            ctx.generate_uid_based_on_node(
+                super_class,
+                class_scope_id,
+                SymbolFlags::FunctionScopedVariable,
+            )
+        });
+
+        self.transform_constructor(
+            &mut constructor,
+            is_implicit_constructor,
+            &binding,
+            super_binding.as_ref(),
+            ctx,
+        );
+        // Name of a named class expression is the same binding, so keep it as is
+        constructor.id = Some(match class.id.take() {
+            Some(ident) if ident.symbol_id() == binding.symbol_id => ident,
+            id => {
+                class.id = id;
+                binding.create_binding_identifier(ctx)
+            }
+        });
+
+        let mut instance_descriptors = ctx.ast.vec();
+        let mut static_descriptors = ctx.ast.vec();
+        for method in methods {
+            let descriptors =
+                if method.r#static { &mut static_descriptors } else { &mut instance_descriptors };
+            self.transform_method(method.unbox(), descriptors, &binding, ctx);
+        }
+
+        if !has_wrapper {
+            ctx.scopes_mut().delete_scope(class_scope_id);
+            constructor.r#type = FunctionType::FunctionExpression;
+            let arguments = ctx.ast.vec1(Argument::FunctionExpression(constructor));
+            let expr = self.ctx.helper_call_expr(Helper::CreateClass, arguments, ctx);
+            return Some(Self::keep_class_name(expr, keep_name, &binding, ctx));
+        }
+
+        // `_createClass(A, [...], [...])`
+        let mut arguments = ctx.ast.vec1(Argument::from(binding.create_read_expression(ctx)));
+        if !instance_descriptors.is_empty() || !static_descriptors.is_empty() {
+            arguments.push(Argument::from(if instance_descriptors.is_empty() {
+                ctx.ast.expression_null_literal(SPAN)
+            } else {
+                ctx.ast.expression_array(SPAN, instance_descriptors, None)
+            }));
+        }
+        if !static_descriptors.is_empty() {
+            arguments.push(Argument::from(ctx.ast.expression_array(
+                SPAN,
+                static_descriptors,
+                None,
+            )));
+        }
+
+        // `function (_Super) { function A() {} _inherits(A, _Super); return _createClass(A); }(Super)`
+        constructor.r#type = FunctionType::FunctionDeclaration;
+        let mut statements = ctx.ast.vec1(Statement::FunctionDeclaration(constructor));
+        if let Some(super_binding) = &super_binding {
+            let arguments = ctx.ast.vec_from_iter([
+                Argument::from(binding.create_read_expression(ctx)),
+                Argument::from(super_binding.create_read_expression(ctx)),
+            ]);
+            let inherits = self.ctx.helper_call_expr(Helper::Inherits, arguments, ctx);
+            statements.push(ctx.ast.statement_expression(SPAN, inherits));
+        }
+        let create_class = self.ctx.helper_call_expr(Helper::CreateClass, arguments, ctx);
+        statements.push(ctx.ast.statement_return(SPAN, Some(create_class)));
+
+        let items = match &super_binding {
+            Some(super_binding) => ctx.ast.vec1(
+                ctx.ast.plain_formal_parameter(SPAN, super_binding.create_binding_pattern(ctx)),
+            ),
+            None => ctx.ast.vec(),
+        };
+        let params =
+            ctx.ast.formal_parameters(SPAN, FormalParameterKind::FormalParameter, items, NONE);
+        let body = ctx.ast.function_body(SPAN, ctx.ast.vec(), statements);
+        let wrapper = ctx.ast.alloc_plain_function_with_scope_id(
+            FunctionType::FunctionExpression,
+            SPAN,
+            None,
+            params,
+            body,
+            class_scope_id,
+        );
+
+        // Super class is evaluated outside of the wrapper function
+        let arguments = match super_class {
+            Some(super_class) => {
+                let mut collector = ChildScopeCollector::default();
+                collector.visit_expression(&super_class);
+                collector.move_to(parent_scope_id, ctx);
+                ctx.ast.vec1(Argument::from(super_class))
+            }
+            None => ctx.ast.vec(),
+        };
+        let expr = ctx.ast.expression_call(
+            class.span,
+            Expression::FunctionExpression(wrapper),
+            NONE,
+            arguments,
+            false,
+            false,
+        );
+        Some(Self::keep_class_name(expr, keep_name, &binding, ctx))
+    }
+
+    /// `Object.defineProperty(_createClass(...), "name", { value: "A" })`,
+    /// if the constructor function's name is not `keep_name`.
+    fn keep_class_name(
+        expr: Expression<'a>,
+        keep_name: Option<Atom<'a>>,
+        binding: &BoundIdentifier<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        match keep_name {
+            Some(name) if name != binding.name => {
+                function_name::create_set_function_name(expr, name, ctx)
+            }
+            _ => expr,
+        }
+    }
+
+    /// Check if class may have a static method or accessor named `name`.
+    fn may_have_static_name(class: &Class<'a>) -> bool {
+        class.body.body.iter().any(|element| match element {
+            ClassElement::MethodDefinition(method) if method.r#static => {
+                method.key.static_name().map_or(true, |name| name == "name")
+            }
+            _ => false,
+        })
+    }
+
+    /// Classes with elements other than methods are not supported yet.
+    fn can_transform(class: &Class<'a>) -> bool {
+        if !class.decorators.is_empty() || class.declare {
+            return false;
+        }
+        let all_methods = class.body.body.iter().all(|element| match element {
+            ClassElement::MethodDefinition(method) => {
+                method.decorators.is_empty()
+                    && !method.key.is_private_identifier()
+                    && method.value.body.is_some()
+            }
+            _ => false,
+        });
+        if !all_methods {
+            return false;
+        }
+        let mut finder = SuperAssignmentFinder { found: false };
+        finder.visit_class_body(&class.body);
+        !finder.found
+    }
+
+    /// Create binding for the constructor function.
+    ///
+    /// For a class declaration, the binding is a new symbol, and references inside the class to the
+    /// binding outside of it are rebound to the new symbol.
+    fn create_class_binding(
+        class: &Class<'a>,
+        name: ClassName<'a>,
+        name_reference_ids: Vec<ReferenceId>,
+        scope_id: ScopeId,
+        flags: SymbolFlags,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> BoundIdentifier<'a> {
+        let class_scope_id = class.scope_id();
+        match (name, &class.id) {
+            (ClassName::Declaration(outer_symbol_id), Some(ident)) => {
+                let binding = ctx.generate_binding(ident.name.clone(), scope_id, flags);
+                for reference_id in name_reference_ids {
+                    let reference = ctx.symbols_mut().get_reference_mut(reference_id);
+                    if reference.symbol_id() != Some(outer_symbol_id) {
+                        continue;
+                    }
+                    *reference = Reference::new_with_symbol_id(
+                        reference.node_id(),
+                        binding.symbol_id,
+                        reference.flags(),
+                    );
+                    ctx.symbols_mut().delete_resolved_reference(outer_symbol_id, reference_id);
+                    ctx.symbols_mut().resolved_references[binding.symbol_id].push(reference_id);
+                }
+                binding
+            }
+            (ClassName::Expression(_), Some(ident)) => {
+                let binding = BoundIdentifier::from_binding_ident(ident);
+                *ctx.symbols_mut().get_flags_mut(binding.symbol_id) = flags;
+                if scope_id != class_scope_id {
+                    ctx.scopes_mut().move_binding(class_scope_id, scope_id, &binding.name);
+                    ctx.symbols_mut().set_scope_id(binding.symbol_id, scope_id);
+                }
+                binding
+            }
+            (ClassName::Expression(Some(name)), None) => {
+                // Use inferred name as is, unless the class refers to another binding of that name
+                if name_reference_ids.is_empty() {
+                    ctx.generate_binding(name, scope_id, flags)
+                } else {
+                    ctx.generate_uid(&name, scope_id, flags)
+                }
+            }
+            (_, None) => ctx.generate_uid("class", scope_id, flags),
+        }
+    }
+
+    /// Transform constructor body.
+    ///
+    /// ```js
+    /// function A() {
+    ///   babelHelpers.classCallCheck(this, A);
+    ///   ...
+    /// }
+    /// ```
+    fn transform_constructor(
+        &self,
+        constructor: &mut Function<'a>,
+        is_implicit: bool,
+        binding: &BoundIdentifier<'a>,
+        super_binding: Option<&BoundIdentifier<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let scope_id = constructor.scope_id();
+        let body = constructor.body.as_mut().unwrap();
+        let mut statements = ctx.ast.vec();
+
+        match super_binding {
+            // `return babelHelpers.callSuper(this, A, arguments);`
+            Some(super_binding) if is_implicit => {
+                let arguments = ctx.create_unbound_reference_id(
+                    SPAN,
+                    Atom::from("arguments"),
+                    ReferenceFlags::Read,
+                );
+                let arguments = Expression::Identifier(ctx.alloc(arguments));
+                let value = self.create_super_call(binding, super_binding, arguments, true, ctx);
+                body.statements.push(ctx.ast.statement_return(SPAN, Some(value)));
+            }
+            Some(super_binding) => {
+                let this_binding =
+                    ctx.generate_uid("this", scope_id, SymbolFlags::FunctionScopedVariable);
+                let mut transform = MethodBodyTransform {
+                    transform: self,
+                    ctx,
+                    home_object: HomeObject::Prototype,
+                    binding,
+                    constructor: Some(DerivedConstructor {
+                        super_binding,
+                        this_binding: &this_binding,
+                        is_this_initialized: false,
+                    }),
+                    function_depth: 0,
+                };
+                for stmt in body.statements.iter_mut() {
+                    let is_super_call = matches!(stmt, Statement::ExpressionStatement(stmt) if stmt.expression.is_super_call_expression());
+                    transform.visit_statement(stmt);
+                    if is_super_call {
+                        transform.constructor.as_mut().unwrap().is_this_initialized = true;
+                    }
+                }
+                // `return _this;`
+                if !matches!(body.statements.last(), Some(Statement::ReturnStatement(_))) {
+                    let value = transform.create_this();
+                    body.statements.push(transform.ctx.ast.statement_return(SPAN, Some(value)));
+                }
+
+                // `var _this;`
+                let declarator = ctx.ast.variable_declarator(
+                    SPAN,
+                    VariableDeclarationKind::Var,
+                    this_binding.create_binding_pattern(ctx),
+                    None,
+                    false,
+                );
+                statements.push(Statement::VariableDeclaration(
+                    ctx.ast.alloc_variable_declaration(
+                        SPAN,
+                        VariableDeclarationKind::Var,
+                        ctx.ast.vec1(declarator),
+                        false,
+                    ),
+                ));
+            }
+            None => {
+                let mut transform = MethodBodyTransform {
+                    transform: self,
+                    ctx,
+                    home_object: HomeObject::Prototype,
+                    binding,
+                    constructor: None,
+                    function_depth: 0,
+                };
+                transform.visit_function_body(body);
+            }
+        }
+
+        if !self.ctx.assumptions.no_class_calls {
+            let arguments = ctx.ast.vec_from_iter([
+                Argument::from(ctx.ast.expression_this(SPAN)),
+                Argument::from(binding.create_read_expression(ctx)),
+            ]);
+            let class_call_check =
+                self.ctx.helper_call_expr(Helper::ClassCallCheck, arguments, ctx);
+            statements.push(ctx.ast.statement_expression(SPAN, class_call_check));
+        }
+        body.statements.splice(0..0, statements);
+    }
+
+    /// Transform method into a property descriptor: `{ key: "foo", value: function foo() {} }`.
+    ///
+    /// Getter and setter of the same property are merged into one descriptor.
+    fn transform_method(
+        &self,
+        method: MethodDefinition<'a>,
+        descriptors: &mut ArenaVec<'a, ArrayExpressionElement<'a>>,
+        binding: &BoundIdentifier<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let MethodDefinition { key, value: mut function, kind, computed, r#static, .. } = method;
+        let scope_id = function.scope_id();
+        ctx.scopes_mut().get_flags_mut(scope_id).remove(ScopeFlags::Modifiers);
+
+        let home_object = if r#static { HomeObject::Constructor } else { HomeObject::Prototype };
+        let mut transform = MethodBodyTransform {
+            transform: self,
+            ctx,
+            home_object,
+            binding,
+            constructor: None,
+            function_depth: 0,
+        };
+        transform.visit_function_body(function.body.as_mut().unwrap());
+
+        let static_name = if computed { None } else { key.static_name() };
+        if kind == MethodDefinitionKind::Method {
+            // Methods with keys which are not valid identifiers (`"a b"() {}`, `123() {}`) are left anonymous
+            if let Some(name) = static_name.as_ref().filter(|name| is_identifier_name(name)) {
+                function.id = Some(Self::create_method_name(&function, name, scope_id, ctx));
+            }
+        }
+        function.r#type = FunctionType::FunctionExpression;
+        let value = Expression::FunctionExpression(function);
+        let descriptor_key = match kind {
+            MethodDefinitionKind::Get => "get",
+            MethodDefinitionKind::Set => "set",
+            MethodDefinitionKind::Method | MethodDefinitionKind::Constructor => "value",
+        };
+        let property = ctx.ast.object_property_kind_object_property(
+            SPAN,
+            PropertyKind::Init,
+            ctx.ast.property_key_identifier_name(SPAN, descriptor_key),
+            value,
+            false,
+            false,
+            false,
+        );
+
+        // `get x() {}` and `set x(v) {}` -> `{ key: "x", get: function () {}, set: function (v) {} }`
+        if kind.is_accessor() {
+            if let Some(name) = &static_name {
+                let existing = descriptors.iter_mut().rev().find_map(|descriptor| {
+                    let ArrayExpressionElement::ObjectExpression(object) = descriptor else {
+                        return None;
+                    };
+                    Self::is_accessor_descriptor_for(object, name).then_some(object)
+                });
+                if let Some(object) = existing {
+                    object.properties.push(property);
+                    return;
+                }
+            }
+        }
+
+        let key = match key {
+            PropertyKey::StaticIdentifier(ident) => {
+                ctx.ast.expression_string_literal(ident.span, ident.name.clone())
+            }
+            key => key.into_expression(),
+        };
+        let key_property = ctx.ast.object_property_kind_object_property(
+            SPAN,
+            PropertyKind::Init,
+            ctx.ast.property_key_identifier_name(SPAN, "key"),
+            key,
+            false,
+            false,
+            false,
+        );
+        let descriptor =
+            ctx.ast.expression_object(SPAN, ctx.ast.vec_from_iter([key_property, property]), None);
+        descriptors.push(ArrayExpressionElement::from(descriptor));
+    }
+
+    /// Name method function after the method, so `name` property of the function is preserved.
+    ///
+    /// A different name is used if the method's name is a reserved word,
+    /// or the method refers to another binding of that name.
+    fn create_method_name(
+        function: &Function<'a>,
+        name: &str,
+        scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> BindingIdentifier<'a> {
+        let mut collector = NameReferenceCollector::new(name);
+        collector.visit_function(function, ScopeFlags::Function);
+        let binding = if is_reserved_keyword(name) || !collector.reference_ids.is_empty() {
+            ctx.generate_uid(name, scope_id, SymbolFlags::Function)
+        } else {
+            ctx.generate_binding(ctx.ast.atom(name), scope_id, SymbolFlags::Function)
+        };
+        binding.create_binding_identifier(ctx)
+    }
+
+    /// Check if `object` is an accessor descriptor `{ key: "name", get: ... }`, with no setter yet.
+    fn is_accessor_descriptor_for(object: &ObjectExpression<'a>, name: &str) -> bool {
+        let mut properties = object.properties.iter().filter_map(|property| match property {
+            ObjectPropertyKind::ObjectProperty(property) => Some(property),
+            ObjectPropertyKind::SpreadProperty(_) => None,
+        });
+        let Some(key) = properties.next() else { return false };
+        if !matches!(&key.value, Expression::StringLiteral(lit) if lit.value == name) {
+            return false;
+        }
+        let kinds =
+            properties.filter_map(|property| property.key.static_name()).collect::<Vec<_>>();
+        kinds.len() == 1 && kinds[0] != "value"
+    }
+
+    /// `super(...args)` -> `babelHelpers.callSuper(this, A, [...args])`,
+    /// or `_Super.call(this, ...args) || this` with `superIsCallableConstructor` assumption.
+    ///
+    /// If `arguments_is_array_like` is `true`, `arguments` is passed as is, instead of
+    /// being wrapped in an array: `babelHelpers.callSuper(this, A, arguments)`.
+    fn create_super_call(
+        &self,
+        binding: &BoundIdentifier<'a>,
+        super_binding: &BoundIdentifier<'a>,
+        arguments: Expression<'a>,
+        arguments_is_array_like: bool,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        if self.ctx.assumptions.super_is_callable_constructor {
+            let (method, arguments) = if arguments_is_array_like {
+                // `_Super.apply(this, arguments)`
+                (
+                    "apply",
+                    ctx.ast.vec_from_iter([
+                        Argument::from(ctx.ast.expression_this(SPAN)),
+                        Argument::from(arguments),
+                    ]),
+                )
+            } else {
+                // `_Super.call(this, ...args)`
+                let Expression::ArrayExpression(array) = arguments else { unreachable!() };
+                let mut call_arguments =
+                    ctx.ast.vec1(Argument::from(ctx.ast.expression_this(SPAN)));
+                call_arguments.extend(array.unbox().elements.into_iter().map(
+                    |element| match element {
+                        ArrayExpressionElement::SpreadElement(spread) => {
+                            Argument::SpreadElement(spread)
+                        }
+                        element => Argument::from(element.into_expression()),
+                    },
+                ));
+                ("call", call_arguments)
+            };
+            let callee = ctx.ast.member_expression_static(
+                SPAN,
+                super_binding.create_read_expression(ctx),
+                ctx.ast.identifier_name(SPAN, method),
+                false,
+            );
+            let call = ctx.ast.expression_call(SPAN, callee.into(), NONE, arguments, false, false);
+            return ctx.ast.expression_logical(
+                SPAN,
+                call,
+                LogicalOperator::Or,
+                ctx.ast.expression_this(SPAN),
+            );
+        }
+
+        let mut call_arguments = ctx.ast.vec_from_iter([
+            Argument::from(ctx.ast.expression_this(SPAN)),
+            Argument::from(binding.create_read_expression(ctx)),
+        ]);
+        // `super()` -> `babelHelpers.callSuper(this, A)`
+        if !matches!(&arguments, Expression::ArrayExpression(array) if array.elements.is_empty()) {
+            call_arguments.push(Argument::from(arguments));
+        }
+        self.ctx.helper_call_expr(Helper::CallSuper, call_arguments, ctx)
+    }
+}
+
+/// Object which `super` property accesses are looked up on the prototype of.
+#[derive(Clone, Copy)]
+enum HomeObject {
+    /// `A.prototype`, in instance methods and constructor
+    Prototype,
+    /// `A`, in static methods
+    Constructor,
+}
+
+/// State of transform of a derived class's constructor.
+struct DerivedConstructor<'a, 'b> {
+    super_binding: &'b BoundIdentifier<'a>,
+    /// `_this`
+    this_binding: &'b BoundIdentifier<'a>,
+    /// `true` once `super()` has been called at top level of the constructor body
+    is_this_initialized: bool,
+}
+
+/// Transform `super` (and `this` in a derived class's constructor) in a method body.
+///
+/// Nested functions and classes are not entered, as they have their own `this` and `super`.
+struct MethodBodyTransform<'a, 'ctx, 'b> {
+    transform: &'b Classes<'a, 'ctx>,
+    ctx: &'b mut TraverseCtx<'a>,
+    home_object: HomeObject,
+    /// Binding of the constructor function
+    binding: &'b BoundIdentifier<'a>,
+    /// Present if the method is constructor of a derived class
+    constructor: Option<DerivedConstructor<'a, 'b>>,
+    /// Depth of arrow functions
+    function_depth: usize,
+}
+
+impl<'a, 'ctx, 'b> MethodBodyTransform<'a, 'ctx, 'b> {
+    /// `this`, or `_this` in constructor of a derived class.
+    ///
+    /// `_this` is wrapped in `babelHelpers.assertThisInitialized(_this)` if `super()` may not have been
+    /// called yet.
+    fn create_this(&mut self) -> Expression<'a> {
+        let Some(constructor) = &self.constructor else {
+            return self.ctx.ast.expression_this(SPAN);
+        };
+        let this = constructor.this_binding.create_read_expression(self.ctx);
+        if constructor.is_this_initialized {
+            this
+        } else {
+            let arguments = self.ctx.ast.vec1(Argument::from(this));
+            self.transform.ctx.helper_call_expr(Helper::AssertThisInitialized, arguments, self.ctx)
+        }
+    }
+
+    /// `super(...args)` -> `_this = babelHelpers.callSuper(this, A, [...args])`
+    fn transform_super_call(&mut self, call: &mut CallExpression<'a>) -> Expression<'a> {
+        let constructor = self.constructor.as_ref().unwrap();
+        let this_binding = constructor.this_binding;
+        let super_binding = constructor.super_binding;
+        let ctx = &mut *self.ctx;
+        let elements =
+            ctx.ast.vec_from_iter(call.arguments.drain(..).map(|argument| match argument {
+                Argument::SpreadElement(spread) => ArrayExpressionElement::SpreadElement(spread),
+                argument => ArrayExpressionElement::from(argument.into_expression()),
+            }));
+        let arguments = ctx.ast.expression_array(SPAN, elements, None);
+        let value =
+            self.transform.create_super_call(self.binding, super_binding, arguments, false, ctx);
+        ctx.ast.expression_assignment(
+            call.span,
+            AssignmentOperator::Assign,
+            this_binding.create_write_target(ctx),
+            value,
+        )
+    }
+
+    /// `super.foo` -> `babelHelpers.get(babelHelpers.getPrototypeOf(A.prototype), "foo", this)`
+    fn transform_super_member(&mut self, property: Expression<'a>) -> Expression<'a> {
+        let mut home_object = self.binding.create_read_expression(self.ctx);
+        if matches!(self.home_object, HomeObject::Prototype) {
+            home_object = self
+                .ctx
+                .ast
+                .member_expression_static(
+                    SPAN,
+                    home_object,
+                    self.ctx.ast.identifier_name(SPAN, "prototype"),
+                    false,
+                )
+                .into();
+        }
+        let arguments = self.ctx.ast.vec1(Argument::from(home_object));
+        let prototype =
+            self.transform.ctx.helper_call_expr(Helper::GetPrototypeOf, arguments, self.ctx);
+        let this = self.create_this();
+        let arguments = self.ctx.ast.vec_from_iter([
+            Argument::from(prototype),
+            Argument::from(property),
+            Argument::from(this),
+        ]);
+        self.transform.ctx.helper_call_expr(Helper::Get, arguments, self.ctx)
+    }
+
+    /// Get property of `super.foo` or `super[foo]`, or `None` if not a `super` member expression.
+    fn take_super_property(&mut self, expr: &mut Expression<'a>) -> Option<Expression<'a>> {
+        match expr {
+            Expression::StaticMemberExpression(member)
+                if matches!(member.object, Expression::Super(_)) =>
+            {
+                Some(
+                    self.ctx.ast.expression_string_literal(
+                        member.property.span,
+                        member.property.name.clone(),
+                    ),
+                )
+            }
+            Expression::ComputedMemberExpression(member)
+                if matches!(member.object, Expression::Super(_)) =>
+            {
+                self.visit_expression(&mut member.expression);
+                Some(self.ctx.ast.move_expression(&mut member.expression))
+            }
+            _ => None,
+        }
+    }
+}
+
+impl<'a, 'ctx, 'b> VisitMut<'a> for MethodBodyTransform<'a, 'ctx, 'b> {
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        match expr {
+            Expression::ThisExpression(_) if self.constructor.is_some() => {
+                *expr = self.create_this();
+            }
+            Expression::CallExpression(call)
+                if self.constructor.is_some() && matches!(call.callee, Expression::Super(_)) =>
+            {
+                for argument in call.arguments.iter_mut() {
+                    self.visit_argument(argument);
+                }
+                *expr = self.transform_super_call(call);
+            }
+            // `super.foo(...args)` -> `babelHelpers.get(...).call(this, ...args)`
+            Expression::CallExpression(call) if call.callee.is_member_expression() => {
+                let Some(property) = self.take_super_property(&mut call.callee) else {
+                    walk_mut::walk_expression(self, expr);
+                    return;
+                };
+                for argument in call.arguments.iter_mut() {
+                    self.visit_argument(argument);
+                }
+                let get = self.transform_super_member(property);
+                let callee = self.ctx.ast.member_expression_static(
+                    SPAN,
+                    get,
+                    self.ctx.ast.identifier_name(SPAN, "call"),
+                    false,
+                );
+                call.callee = callee.into();
+                let this = self.create_this();
+                call.arguments.insert(0, Argument::from(this));
+            }
+            Expression::StaticMemberExpression(_) | Expression::ComputedMemberExpression(_) => {
+                if let Some(property) = self.take_super_property(expr) {
+                    *expr = self.transform_super_member(property);
+                } else {
+                    walk_mut::walk_expression(self, expr);
+                }
+            }
+            _ => walk_mut::walk_expression(self, expr),
+        }
+    }
+
+    /// `return x;` -> `return babelHelpers.possibleConstructorReturn(_this, x);`
+    fn visit_return_statement(&mut self, stmt: &mut ReturnStatement<'a>) {
+        walk_mut::walk_return_statement(self, stmt);
+        if self.function_depth > 0 {
+            return;
+        }
+        let Some(this_binding) =
+            self.constructor.as_ref().map(|constructor| constructor.this_binding)
+        else {
+            return;
+        };
+        stmt.argument = Some(match stmt.argument.take() {
+            Some(argument) => {
+                let this = this_binding.create_read_expression(self.ctx);
+                let arguments =
+                    self.ctx.ast.vec_from_iter([Argument::from(this), Argument::from(argument)]);
+                self.transform.ctx.helper_call_expr(
+                    Helper::PossibleConstructorReturn,
+                    arguments,
+                    self.ctx,
+                )
+            }
+            None => self.create_this(),
+        });
+    }
+
+    fn visit_arrow_function_expression(&mut self, arrow: &mut ArrowFunctionExpression<'a>) {
+        self.function_depth += 1;
+        walk_mut::walk_arrow_function_expression(self, arrow);
+        self.function_depth -= 1;
+    }
+
+    fn visit_function(&mut self, _func: &mut Function<'a>, _flags: ScopeFlags) {}
+
+    fn visit_class(&mut self, _class: &mut Class<'a>) {}
+}
+
+/// Collect references to a name.
+struct NameReferenceCollector<'n> {
+    name: &'n str,
+    reference_ids: Vec<ReferenceId>,
+}
+
+impl<'n> NameReferenceCollector<'n> {
+    fn new(name: &'n str) -> Self {
+        Self { name, reference_ids: vec![] }
+    }
+}
+
+impl<'a, 'n> Visit<'a> for NameReferenceCollector<'n> {
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+        if ident.name == self.name {
+            self.reference_ids.push(ident.reference_id());
+        }
+    }
+}
+
+/// Update flags of scopes in a class body for the functions the class is transformed into.
+///
+/// * Remove `ScopeFlags::StrictMode` if the class is in sloppy mode code,
+///   except in nested classes and functions with a `"use strict"` directive.
+/// * Remove `ScopeFlags::Modifiers` inherited by block scopes from the method they're in.
+struct ScopeFlagsUpdater<'s> {
+    scopes: &'s mut ScopeTree,
+    remove_strict_mode: bool,
+    /// `true` when in a method, and not in a nested function
+    in_method: bool,
+}
+
+impl<'s> ScopeFlagsUpdater<'s> {
+    fn new(scopes: &'s mut ScopeTree, remove_strict_mode: bool) -> Self {
+        Self { scopes, remove_strict_mode, in_method: false }
+    }
+}
+
+impl<'a, 's> Visit<'a> for ScopeFlagsUpdater<'s> {
+    fn enter_scope(&mut self, _flags: ScopeFlags, scope_id: &Cell<Option<ScopeId>>) {
+        let mut flags_to_remove = ScopeFlags::empty();
+        if self.remove_strict_mode {
+            flags_to_remove |= ScopeFlags::StrictMode;
+        }
+        if self.in_method {
+            flags_to_remove |= ScopeFlags::Modifiers;
+        }
+        self.scopes.get_flags_mut(scope_id.get().unwrap()).remove(flags_to_remove);
+    }
+
+    fn visit_method_definition(&mut self, method: &MethodDefinition<'a>) {
+        self.visit_property_key(&method.key);
+        self.in_method = true;
+        walk::walk_function(self, &method.value, ScopeFlags::Function);
+        self.in_method = false;
+    }
+
+    fn visit_function(&mut self, func: &Function<'a>, flags: ScopeFlags) {
+        if func.is_strict() {
+            return;
+        }
+        let in_method = mem::replace(&mut self.in_method, false);
+        walk::walk_function(self, func, flags);
+        self.in_method = in_method;
+    }
+
+    fn visit_arrow_function_expression(&mut self, arrow: &ArrowFunctionExpression<'a>) {
+        let in_method = mem::replace(&mut self.in_method, false);
+        walk::walk_arrow_function_expression(self, arrow);
+        self.in_method = in_method;
+    }
+
+    fn visit_class(&mut self, class: &Class<'a>) {
+        if self.in_method {
+            self.scopes.get_flags_mut(class.scope_id()).remove(ScopeFlags::Modifiers);
+        }
+    }
+}
+
+/// Find assignments to properties of `super`, e.g. `super.x = 1`.
+struct SuperAssignmentFinder {
+    found: bool,
+}
+
+impl<'a> Visit<'a> for SuperAssignmentFinder {
+    fn visit_simple_assignment_target(&mut self, target: &SimpleAssignmentTarget<'a>) {
+        match target {
+            SimpleAssignmentTarget::StaticMemberExpression(member)
+                if matches!(member.object, Expression::Super(_)) =>
+            {
+                self.found = true;
+            }
+            SimpleAssignmentTarget::ComputedMemberExpression(member)
+                if matches!(member.object, Expression::Super(_)) =>
+            {
+                self.found = true;
+            }
+            _ => walk::walk_simple_assignment_target(self, target),
+        }
+    }
+}
@@ -0,0 +1,1130 @@
+//! ES2015: Destructuring
+//!
+//! This plugin transforms array and object destructuring into plain property accesses,
+//! with temporary variables where a value is read more than once.
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! const { a = 1, b: { c }, ...rest } = obj;
+//! const [x, , y] = arr;
+//! [a, b] = [b, a];
+//! function f({ a = 1, b: { c } }) {}
+//! ```
+//!
+//! Output:
+//! ```js
+//! const _obj$a = obj.a,
+//!   a = _obj$a === void 0 ? 1 : _obj$a,
+//!   c = obj.b.c,
+//!   rest = _objectWithoutProperties(obj, ["a", "b"]);
+//! const _arr = _slicedToArray(arr, 3),
+//!   x = _arr[0],
+//!   y = _arr[2];
+//! var _ref;
+//! _ref = [b, a], a = _ref[0], b = _ref[1];
+//! function f(_ref2) {
+//!   var _ref2$a = _ref2.a,
+//!     a = _ref2$a === void 0 ? 1 : _ref2$a,
+//!     c = _ref2.b.c;
+//! }
+//! ```
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-transform-destructuring](https://babel.dev/docs/babel-plugin-transform-destructuring).
+//!
+//! Patterns in variable declarations are expanded into a list of declarators in the same declaration,
+//! and patterns in assignment expressions into a sequence of assignments.
+//! Patterns in function parameters, `catch` clauses and `for in` / `for of` heads are replaced with
+//! a temporary variable, and the pattern is moved into a declaration (or assignment) at the top of
+//! the function / `catch` / loop body, which is then transformed when the body is visited.
+//!
+//! The value being destructured is stored in a temporary variable only if it's read more than once,
+//! and it's not a bound identifier which is never reassigned.
+//!
+//! Compiler assumptions:
+//!
+//! * `iterableIsArray`: Array patterns read elements directly by index, instead of converting
+//!   the value to an array with `_slicedToArray` / `_toArray` helpers.
+//!   Array literals are always destructured directly, without the helpers.
+//! * `objectRestNoSymbols`: Object rest uses `_objectWithoutPropertiesLoose` helper,
+//!   which does not copy symbol properties.
+//! * `ignoreFunctionLength`: Default values of parameters which follow a destructured parameter are
+//!   moved into the function body too, so they can refer to the bindings of the destructured parameter.
+//!   Without this assumption, those default values are left in the parameter list to preserve the
+//!   function's `length`, and cannot refer to bindings of earlier destructured parameters.
+//!
+//! ## References:
+//!
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/main/packages/babel-plugin-transform-destructuring>
+//! * Destructuring assignment specification: <https://tc39.es/ecma262/#sec-destructuring-assignment>
+//! * Destructuring binding patterns specification: <https://tc39.es/ecma262/#sec-destructuring-binding-patterns>
+
+use std::{cell::Cell, mem};
+
+use oxc_allocator::Vec as ArenaVec;
+use oxc_ast::{ast::*, Visit, NONE};
+use oxc_ecmascript::BoundNames;
+use oxc_semantic::{ReferenceFlags, ScopeFlags, ScopeId, SymbolFlags, SymbolId};
+use oxc_span::SPAN;
+use oxc_syntax::{
+    number::{NumberBase, ToJsString},
+    operator::{AssignmentOperator, BinaryOperator},
+};
+use oxc_traverse::{Ancestor, BoundIdentifier, Traverse, TraverseCtx};
+
+use crate::{common::helper_loader::Helper, TransformCtx};
+
+pub struct Destructuring<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
+    /// Only lower patterns which contain an object rest element.
+    /// Used by ES2018 object rest/spread transform.
+    object_rest_only: bool,
+}
+
+impl<'a, 'ctx> Destructuring<'a, 'ctx> {
+    pub fn new(ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self { ctx, object_rest_only: false }
+    }
+
+    /// Create a transform which only lowers patterns containing an object rest element,
+    /// e.g. `const { a, ...b } = c` or `[{ ...a }] = b`.
+    pub(crate) fn new_object_rest_only(ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self { ctx, object_rest_only: true }
+    }
+}
+
+impl<'a, 'ctx> Traverse<'a> for Destructuring<'a, 'ctx> {
+    /// Move patterns in `for in` / `for of` heads into loop body.
+    ///
+    /// This is done on entering the loop statement, rather than in `enter_for_of_statement`,
+    /// so that block scoping sees the bindings in the loop body.
+    fn enter_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        match stmt {
+            Statement::ForInStatement(stmt) => {
+                let stmt = &mut **stmt;
+                let scope_id = stmt.scope_id();
+                self.transform_for_head(&mut stmt.left, &mut stmt.body, scope_id, ctx);
+            }
+            Statement::ForOfStatement(stmt) => {
+                let stmt = &mut **stmt;
+                let scope_id = stmt.scope_id();
+                self.transform_for_head(&mut stmt.left, &mut stmt.body, scope_id, ctx);
+            }
+            _ => {}
+        }
+    }
+
+    /// `var { a, b } = obj` -> `var a = obj.a, b = obj.b`
+    fn enter_variable_declaration(
+        &mut self,
+        decl: &mut VariableDeclaration<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if !decl.declarations.iter().any(|declarator| self.should_lower_binding(&declarator.id)) {
+            return;
+        }
+
+        let kind = decl.kind;
+        let (scope_id, flags) = temp_binding_location(kind, ctx);
+        let mut lowering = PatternLowering::new(self.ctx, Some((kind, scope_id, flags)), ctx);
+        for declarator in decl.declarations.drain(..) {
+            if !self.should_lower_binding(&declarator.id) {
+                lowering.declarators.push(declarator);
+                continue;
+            }
+            // Only patterns in `for in` / `for of` heads have no initializer,
+            // and those have already been replaced in `enter_statement`
+            let Some(init) = declarator.init else { unreachable!() };
+            declarator.id.bound_names(&mut |ident| {
+                lowering.declared_symbol_ids.push(ident.symbol_id());
+            });
+            lowering.lower_binding_pattern(declarator.id, init, ctx);
+        }
+        decl.declarations = lowering.declarators;
+    }
+
+    /// `[a, b] = [b, a]` -> `_ref = [b, a], a = _ref[0], b = _ref[1]`
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Expression::AssignmentExpression(assign_expr) = expr else { return };
+        if assign_expr.operator != AssignmentOperator::Assign
+            || !self.should_lower_assignment_target(&assign_expr.left)
+        {
+            return;
+        }
+
+        let is_value_used = !Self::is_value_unused(ctx);
+        let Expression::AssignmentExpression(assign_expr) = ctx.ast.move_expression(expr) else {
+            unreachable!()
+        };
+        let assign_expr = assign_expr.unbox();
+
+        let mut lowering = PatternLowering::new(self.ctx, None, ctx);
+        if is_value_used {
+            // The value of the assignment expression is the right-hand side
+            let mut source = lowering.source(assign_expr.right, 2, ctx);
+            let value = source.read(ctx);
+            lowering.lower_assignment_target(assign_expr.left, value, ctx);
+            let value = source.read(ctx);
+            lowering.expressions.push(value);
+        } else {
+            lowering.lower_assignment_target(assign_expr.left, assign_expr.right, ctx);
+        }
+
+        let mut expressions = lowering.expressions;
+        *expr = if expressions.len() == 1 {
+            expressions.pop().unwrap()
+        } else {
+            // Value of an assignment within a sequence expression counts as read
+            for expr in &expressions {
+                if let Expression::AssignmentExpression(assign_expr) = expr {
+                    if let AssignmentTarget::AssignmentTargetIdentifier(ident) = &assign_expr.left {
+                        let reference = ctx.symbols_mut().get_reference_mut(ident.reference_id());
+                        *reference.flags_mut() |= ReferenceFlags::Read;
+                    }
+                }
+            }
+            ctx.ast.expression_sequence(assign_expr.span, expressions)
+        };
+    }
+
+    /// `function f({ a }) {}` -> `function f(_ref) { var { a } = _ref; }`
+    fn enter_function(&mut self, func: &mut Function<'a>, ctx: &mut TraverseCtx<'a>) {
+        let scope_id = func.scope_id();
+        let Some(body) = func.body.as_mut() else { return };
+        self.transform_params(&mut func.params, &mut body.statements, scope_id, ctx);
+    }
+
+    /// `({ a }) => a` -> `(_ref) => { var { a } = _ref; return a; }`
+    fn enter_arrow_function_expression(
+        &mut self,
+        arrow: &mut ArrowFunctionExpression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let scope_id = arrow.scope_id();
+        self.transform_params(&mut arrow.params, &mut arrow.body.statements, scope_id, ctx);
+    }
+
+    /// `catch ({ message }) {}` -> `catch (_ref) { let { message } = _ref; }`
+    fn enter_catch_clause(&mut self, clause: &mut CatchClause<'a>, ctx: &mut TraverseCtx<'a>) {
+        let body_scope_id = clause.body.scope_id();
+        let Some(param) = clause.param.as_mut() else { return };
+        if !self.should_lower_binding(&param.pattern) {
+            return;
+        }
+
+        // Bindings of `catch` parameter are in scope of the `catch` body
+        let binding = ctx.generate_uid(
+            "ref",
+            body_scope_id,
+            SymbolFlags::FunctionScopedVariable | SymbolFlags::CatchVariable,
+        );
+        let pattern = mem::replace(&mut param.pattern, binding.create_binding_pattern(ctx));
+
+        // Pattern is now declared in the `catch` body
+        let mut collector = ChildScopeCollector::default();
+        collector.visit_binding_pattern(&pattern);
+        collector.move_to(body_scope_id, ctx);
+        pattern.bound_names(&mut |ident| {
+            *ctx.symbols_mut().get_flags_mut(ident.symbol_id()) -= SymbolFlags::CatchVariable;
+        });
+
+        let decl = Self::create_declaration(
+            VariableDeclarationKind::Let,
+            pattern,
+            binding.create_read_expression(ctx),
+            ctx,
+        );
+        clause.body.body.insert(0, decl);
+    }
+}
+
+impl<'a, 'ctx> Destructuring<'a, 'ctx> {
+    /// Check if the value of the expression being visited is discarded.
+    fn is_value_unused(ctx: &TraverseCtx<'a>) -> bool {
+        let mut ancestors = ctx
+            .ancestors()
+            .skip_while(|a| matches!(a, Ancestor::ParenthesizedExpressionExpression(_)));
+        if !matches!(ancestors.next(), Some(Ancestor::ExpressionStatementExpression(_))) {
+            return false;
+        }
+        // Body of `() => expr` is an `ExpressionStatement`, but its value is returned
+        !matches!(
+            ancestors.nth(1),
+            Some(Ancestor::ArrowFunctionExpressionBody(arrow)) if *arrow.expression()
+        )
+    }
+
+    /// Replace destructured parameters with temporary variables,
+    /// and destructure them in a `var` declaration at top of the function body.
+    ///
+    /// With `ignoreFunctionLength` assumption, default values of any parameters after
+    /// the first destructured parameter are also moved into the function body.
+    ///
+    /// When only lowering object rest, any patterns after the first lowered parameter are moved too,
+    /// to preserve the order in which the parameters are destructured.
+    fn transform_params(
+        &self,
+        params: &mut FormalParameters<'a>,
+        statements: &mut ArenaVec<'a, Statement<'a>>,
+        scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let ignore_function_length = self.ctx.assumptions.ignore_function_length;
+
+        let mut new_statements = vec![];
+        let should_lower = |pattern: &BindingPattern<'a>, lowered_before: bool| {
+            self.should_lower_binding(pattern)
+                || (lowered_before && !pattern.kind.is_binding_identifier())
+        };
+        for param in params.items.iter_mut() {
+            let pattern = &mut param.pattern;
+            if let BindingPatternKind::AssignmentPattern(assign_pattern) = &mut pattern.kind {
+                if ignore_function_length && !new_statements.is_empty() {
+                    // `b = a` -> `b` + `if (b === void 0) b = a;`
+                    // `{ b } = a` -> `_ref2` + `var { b } = _ref2 === void 0 ? a : _ref2;`
+                    let BindingPatternKind::AssignmentPattern(assign_pattern) = mem::replace(
+                        &mut pattern.kind,
+                        ctx.ast.binding_pattern_kind_binding_identifier(SPAN, ""),
+                    ) else {
+                        unreachable!()
+                    };
+                    let AssignmentPattern { left, right, .. } = assign_pattern.unbox();
+                    new_statements
+                        .push(Self::move_param_default(pattern, left, right, scope_id, ctx));
+                } else if should_lower(&assign_pattern.left, !new_statements.is_empty()) {
+                    // `{ a } = {}` -> `_ref = {}`
+                    new_statements.push(Self::move_param_pattern(
+                        &mut assign_pattern.left,
+                        scope_id,
+                        ctx,
+                    ));
+                }
+                continue;
+            }
+            if should_lower(pattern, !new_statements.is_empty()) {
+                new_statements.push(Self::move_param_pattern(pattern, scope_id, ctx));
+            }
+        }
+        if let Some(rest) = params.rest.as_mut() {
+            if should_lower(&rest.argument, !new_statements.is_empty()) {
+                new_statements.push(Self::move_param_pattern(&mut rest.argument, scope_id, ctx));
+            }
+        }
+
+        if !new_statements.is_empty() {
+            statements.splice(0..0, new_statements);
+        }
+    }
+
+    /// Replace a parameter's pattern with a temporary variable.
+    ///
+    /// `{ a }` -> `_ref`, and return `var { a } = _ref;`
+    fn move_param_pattern(
+        pattern: &mut BindingPattern<'a>,
+        scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        let binding = ctx.generate_uid("ref", scope_id, SymbolFlags::FunctionScopedVariable);
+        let pattern = mem::replace(pattern, binding.create_binding_pattern(ctx));
+        let init = binding.create_read_expression(ctx);
+        Self::create_declaration(VariableDeclarationKind::Var, pattern, init, ctx)
+    }
+
+    /// Move default value of a parameter into function body.
+    ///
+    /// * `b = a` -> `b`, and return `if (b === void 0) b = a;`
+    /// * `{ b } = a` -> `_ref`, and return `var { b } = _ref === void 0 ? a : _ref;`
+    fn move_param_default(
+        pattern: &mut BindingPattern<'a>,
+        left: BindingPattern<'a>,
+        right: Expression<'a>,
+        scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        if let BindingPatternKind::BindingIdentifier(ident) = &left.kind {
+            let binding = BoundIdentifier::from_binding_ident(ident);
+            *pattern = left;
+            let test = ctx.ast.expression_binary(
+                SPAN,
+                binding.create_read_expression(ctx),
+                BinaryOperator::StrictEquality,
+                ctx.ast.void_0(SPAN),
+            );
+            let assignment = ctx.ast.expression_assignment(
+                SPAN,
+                AssignmentOperator::Assign,
+                binding.create_write_target(ctx),
+                right,
+            );
+            let consequent = ctx.ast.statement_expression(SPAN, assignment);
+            return ctx.ast.statement_if(SPAN, test, consequent, None);
+        }
+
+        let binding = ctx.generate_uid("ref", scope_id, SymbolFlags::FunctionScopedVariable);
+        *pattern = binding.create_binding_pattern(ctx);
+        let test = ctx.ast.expression_binary(
+            SPAN,
+            binding.create_read_expression(ctx),
+            BinaryOperator::StrictEquality,
+            ctx.ast.void_0(SPAN),
+        );
+        let init =
+            ctx.ast.expression_conditional(SPAN, test, right, binding.create_read_expression(ctx));
+        Self::create_declaration(VariableDeclarationKind::Var, left, init, ctx)
+    }
+
+    /// Move pattern in head of `for in` / `for of` loop into the loop body.
+    ///
+    /// * `for (const [a, b] of arr) {}` -> `for (const _ref of arr) { const [a, b] = _ref; }`
+    /// * `for ([a, b] of arr) {}` -> `for (var _ref of arr) { [a, b] = _ref; }`
+    fn transform_for_head(
+        &self,
+        left: &mut ForStatementLeft<'a>,
+        body: &mut Statement<'a>,
+        scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let mut collector = ChildScopeCollector::default();
+        let mut symbol_ids = vec![];
+        let stmt = match left {
+            ForStatementLeft::VariableDeclaration(decl) => {
+                let kind = decl.kind;
+                let declarator = decl.declarations.first_mut().unwrap();
+                if !self.should_lower_binding(&declarator.id) {
+                    return;
+                }
+                let (temp_scope_id, flags) = if kind.is_var() {
+                    temp_binding_location(kind, ctx)
+                } else {
+                    // Current scope is the scope outside the loop
+                    (scope_id, temp_binding_flags(kind))
+                };
+                let binding = ctx.generate_uid("ref", temp_scope_id, flags);
+                let pattern = mem::replace(&mut declarator.id, binding.create_binding_pattern(ctx));
+                collector.visit_binding_pattern(&pattern);
+                if kind.is_lexical() {
+                    pattern.bound_names(&mut |ident| symbol_ids.push(ident.symbol_id()));
+                }
+                Self::create_declaration(kind, pattern, binding.create_read_expression(ctx), ctx)
+            }
+            left @ match_assignment_target!(ForStatementLeft)
+                if self.should_lower_assignment_target(left.to_assignment_target()) =>
+            {
+                let (temp_scope_id, flags) =
+                    temp_binding_location(VariableDeclarationKind::Var, ctx);
+                let binding = ctx.generate_uid("ref", temp_scope_id, flags);
+                let declarator = ctx.ast.variable_declarator(
+                    SPAN,
+                    VariableDeclarationKind::Var,
+                    binding.create_binding_pattern(ctx),
+                    None,
+                    false,
+                );
+                let decl = ctx.ast.alloc_variable_declaration(
+                    SPAN,
+                    VariableDeclarationKind::Var,
+                    ctx.ast.vec1(declarator),
+                    false,
+                );
+                let target = mem::replace(left, ForStatementLeft::VariableDeclaration(decl))
+                    .into_assignment_target();
+                collector.visit_assignment_target(&target);
+                let assignment = ctx.ast.expression_assignment(
+                    SPAN,
+                    AssignmentOperator::Assign,
+                    target,
+                    binding.create_read_expression(ctx),
+                );
+                ctx.ast.statement_expression(SPAN, assignment)
+            }
+            _ => return,
+        };
+
+        let body_scope_id = if let Statement::BlockStatement(block) = body {
+            block.body.insert(0, stmt);
+            block.scope_id()
+        } else {
+            // `for (const [a] of arr) foo(a);` -> `for (const _ref of arr) { const [a] = _ref; foo(a); }`
+            collector.visit_statement(body);
+            let block_scope_id = ctx.create_child_scope(scope_id, ScopeFlags::empty());
+            let statements = ctx.ast.vec_from_iter([stmt, ctx.ast.move_statement(body)]);
+            *body = Statement::BlockStatement(ctx.ast.alloc_block_statement_with_scope_id(
+                SPAN,
+                statements,
+                block_scope_id,
+            ));
+            block_scope_id
+        };
+
+        collector.move_to(body_scope_id, ctx);
+        for symbol_id in symbol_ids {
+            move_binding(symbol_id, scope_id, body_scope_id, ctx);
+        }
+    }
+
+    fn should_lower_binding(&self, pattern: &BindingPattern<'a>) -> bool {
+        if self.object_rest_only {
+            binding_pattern_has_object_rest(pattern)
+        } else {
+            !pattern.kind.is_binding_identifier()
+        }
+    }
+
+    fn should_lower_assignment_target(&self, target: &AssignmentTarget<'a>) -> bool {
+        if self.object_rest_only {
+            assignment_target_has_object_rest(target)
+        } else {
+            target.is_assignment_target_pattern()
+        }
+    }
+
+    /// Create `<kind> <pattern> = <init>;`.
+    fn create_declaration(
+        kind: VariableDeclarationKind,
+        pattern: BindingPattern<'a>,
+        init: Expression<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        let declarator = ctx.ast.variable_declarator(SPAN, kind, pattern, Some(init), false);
+        Statement::VariableDeclaration(ctx.ast.alloc_variable_declaration(
+            SPAN,
+            kind,
+            ctx.ast.vec1(declarator),
+            false,
+        ))
+    }
+}
+
+/// Check if a binding pattern contains an object rest element, e.g. `[{ a, ...b }]`.
+fn binding_pattern_has_object_rest(pattern: &BindingPattern) -> bool {
+    match &pattern.kind {
+        BindingPatternKind::BindingIdentifier(_) => false,
+        BindingPatternKind::ObjectPattern(pattern) => {
+            pattern.rest.is_some()
+                || pattern
+                    .properties
+                    .iter()
+                    .any(|prop| binding_pattern_has_object_rest(&prop.value))
+        }
+        BindingPatternKind::ArrayPattern(pattern) => {
+            pattern.elements.iter().flatten().any(binding_pattern_has_object_rest)
+                || pattern
+                    .rest
+                    .as_ref()
+                    .is_some_and(|rest| binding_pattern_has_object_rest(&rest.argument))
+        }
+        BindingPatternKind::AssignmentPattern(pattern) => {
+            binding_pattern_has_object_rest(&pattern.left)
+        }
+    }
+}
+
+/// Check if an assignment target contains an object rest element, e.g. `[{ a, ...b }]`.
+fn assignment_target_has_object_rest(target: &AssignmentTarget) -> bool {
+    let maybe_default_has_object_rest = |target: &AssignmentTargetMaybeDefault| match target {
+        AssignmentTargetMaybeDefault::AssignmentTargetWithDefault(target) => {
+            assignment_target_has_object_rest(&target.binding)
+        }
+        target => target.as_assignment_target().is_some_and(assignment_target_has_object_rest),
+    };
+    match target {
+        AssignmentTarget::ObjectAssignmentTarget(target) => {
+            target.rest.is_some()
+                || target.properties.iter().any(|prop| match prop {
+                    AssignmentTargetProperty::AssignmentTargetPropertyIdentifier(_) => false,
+                    AssignmentTargetProperty::AssignmentTargetPropertyProperty(prop) => {
+                        maybe_default_has_object_rest(&prop.binding)
+                    }
+                })
+        }
+        AssignmentTarget::ArrayAssignmentTarget(target) => {
+            target.elements.iter().flatten().any(maybe_default_has_object_rest)
+                || target
+                    .rest
+                    .as_ref()
+                    .is_some_and(|rest| assignment_target_has_object_rest(&rest.target))
+        }
+        _ => false,
+    }
+}
+
+/// Get scope and symbol flags for a temporary variable declared in a declaration of `kind`.
+fn temp_binding_location(
+    kind: VariableDeclarationKind,
+    ctx: &TraverseCtx,
+) -> (ScopeId, SymbolFlags) {
+    let scope_id = if kind.is_var() {
+        ctx.ancestor_scopes().find(|&scope_id| ctx.scopes().get_flags(scope_id).is_var()).unwrap()
+    } else {
+        ctx.current_scope_id()
+    };
+    (scope_id, temp_binding_flags(kind))
+}
+
+fn temp_binding_flags(kind: VariableDeclarationKind) -> SymbolFlags {
+    match kind {
+        VariableDeclarationKind::Var => SymbolFlags::FunctionScopedVariable,
+        VariableDeclarationKind::Const => {
+            SymbolFlags::BlockScopedVariable | SymbolFlags::ConstVariable
+        }
+        _ => SymbolFlags::BlockScopedVariable,
+    }
+}
+
+/// Move a binding from one scope to another.
+fn move_binding(
+    symbol_id: SymbolId,
+    from_scope_id: ScopeId,
+    to_scope_id: ScopeId,
+    ctx: &mut TraverseCtx,
+) {
+    let name = ctx.symbols().get_name(symbol_id).into();
+    ctx.scopes_mut().remove_binding(from_scope_id, &name);
+    ctx.scopes_mut().add_binding(to_scope_id, name, symbol_id);
+    ctx.symbols_mut().set_scope_id(symbol_id, to_scope_id);
+}
+
+/// Collects scopes of a node which are not nested in another scope of the node.
+#[derive(Default)]
+pub(crate) struct ChildScopeCollector {
+    scope_ids: Vec<ScopeId>,
+    depth: usize,
+}
+
+impl ChildScopeCollector {
+    /// Make all collected scopes children of `parent_scope_id`.
+    pub(crate) fn move_to(&self, parent_scope_id: ScopeId, ctx: &mut TraverseCtx) {
+        for &scope_id in &self.scope_ids {
+            ctx.scopes_mut().change_parent_id(scope_id, Some(parent_scope_id));
+        }
+    }
+}
+
+impl<'a> Visit<'a> for ChildScopeCollector {
+    fn enter_scope(&mut self, _flags: ScopeFlags, scope_id: &Cell<Option<ScopeId>>) {
+        if self.depth == 0 {
+            self.scope_ids.push(scope_id.get().unwrap());
+        }
+        self.depth += 1;
+    }
+
+    fn leave_scope(&mut self) {
+        self.depth -= 1;
+    }
+}
+
+/// A value which is being destructured.
+enum Source<'a> {
+    /// Expression which is only read once
+    Expression(Option<Expression<'a>>),
+    /// Binding which can be read repeatedly
+    Binding(BoundIdentifier<'a>),
+    /// `this`
+    This,
+}
+
+impl<'a> Source<'a> {
+    fn read(&mut self, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        match self {
+            Self::Expression(expr) => expr.take().unwrap(),
+            Self::Binding(binding) => binding.create_read_expression(ctx),
+            Self::This => ctx.ast.expression_this(SPAN),
+        }
+    }
+}
+
+/// Flattens destructuring patterns into a list of declarators (for declarations),
+/// or a list of assignment expressions (for assignments).
+struct PatternLowering<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
+    /// Kind of declaration, and scope and symbol flags for temporary variables.
+    /// `None` for assignments.
+    declaration: Option<(VariableDeclarationKind, ScopeId, SymbolFlags)>,
+    /// Output declarators
+    declarators: ArenaVec<'a, VariableDeclarator<'a>>,
+    /// Output expressions
+    expressions: ArenaVec<'a, Expression<'a>>,
+    /// Symbols declared by the pattern. An identifier referring to one of these
+    /// can't be read repeatedly, as it may be re-declared part way through destructuring.
+    declared_symbol_ids: Vec<SymbolId>,
+    /// Temporary variables created by [`PatternLowering::memoize`].
+    /// These are only written once, so can be read repeatedly.
+    temp_symbol_ids: Vec<SymbolId>,
+}
+
+impl<'a, 'ctx> PatternLowering<'a, 'ctx> {
+    fn new(
+        ctx: &'ctx TransformCtx<'a>,
+        declaration: Option<(VariableDeclarationKind, ScopeId, SymbolFlags)>,
+        traverse_ctx: &TraverseCtx<'a>,
+    ) -> Self {
+        Self {
+            ctx,
+            declaration,
+            declarators: traverse_ctx.ast.vec(),
+            expressions: traverse_ctx.ast.vec(),
+            declared_symbol_ids: vec![],
+            temp_symbol_ids: vec![],
+        }
+    }
+
+    /// Lower a binding pattern, which is assigned `value`.
+    fn lower_binding_pattern(
+        &mut self,
+        pattern: BindingPattern<'a>,
+        value: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        match pattern.kind {
+            BindingPatternKind::BindingIdentifier(_) => {
+                let (kind, ..) = self.declaration.unwrap();
+                let declarator =
+                    ctx.ast.variable_declarator(SPAN, kind, pattern, Some(value), false);
+                self.declarators.push(declarator);
+            }
+            BindingPatternKind::AssignmentPattern(assign_pattern) => {
+                let AssignmentPattern { left, right, .. } = assign_pattern.unbox();
+                let value = self.with_default(value, right, ctx);
+                self.lower_binding_pattern(left, value, ctx);
+            }
+            BindingPatternKind::ObjectPattern(pattern) => {
+                let ObjectPattern { properties, rest, .. } = pattern.unbox();
+                let Some(mut object) =
+                    self.object_source(value, properties.len(), rest.is_some(), ctx)
+                else {
+                    return;
+                };
+                for property in properties {
+                    let value = object.read_property(property.key, self, ctx);
+                    self.lower_binding_pattern(property.value, value, ctx);
+                }
+                if let Some(rest) = rest {
+                    let value = object.read_rest(self, ctx);
+                    self.lower_binding_pattern(rest.unbox().argument, value, ctx);
+                }
+            }
+            BindingPatternKind::ArrayPattern(pattern) => {
+                let ArrayPattern { elements, rest, .. } = pattern.unbox();
+                let count = elements.len();
+                let reads = elements.iter().flatten().count();
+                let Some(mut array) = self.array_source(value, count, reads, rest.is_some(), ctx)
+                else {
+                    return;
+                };
+                for (index, element) in elements.into_iter().enumerate() {
+                    let Some(element) = element else { continue };
+                    let value = array.read_element(index, ctx);
+                    self.lower_binding_pattern(element, value, ctx);
+                }
+                if let Some(rest) = rest {
+                    let value = array.read_rest(count, ctx);
+                    self.lower_binding_pattern(rest.unbox().argument, value, ctx);
+                }
+            }
+        }
+    }
+
+    /// Lower an assignment target, which is assigned `value`.
+    fn lower_assignment_target(
+        &mut self,
+        target: AssignmentTarget<'a>,
+        value: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        match target {
+            AssignmentTarget::ObjectAssignmentTarget(target) => {
+                let ObjectAssignmentTarget { properties, rest, .. } = target.unbox();
+                let Some(mut object) =
+                    self.object_source(value, properties.len(), rest.is_some(), ctx)
+                else {
+                    return;
+                };
+                for property in properties {
+                    match property {
+                        // `{ a = 1 } = obj`
+                        AssignmentTargetProperty::AssignmentTargetPropertyIdentifier(property) => {
+                            let AssignmentTargetPropertyIdentifier { binding, init, .. } =
+                                property.unbox();
+                            let key =
+                                ctx.ast.property_key_identifier_name(SPAN, binding.name.clone());
+                            let mut value = object.read_property(key, self, ctx);
+                            if let Some(init) = init {
+                                value = self.with_default(value, init, ctx);
+                            }
+                            let target = AssignmentTarget::AssignmentTargetIdentifier(
+                                ctx.ast.alloc(binding),
+                            );
+                            self.lower_assignment_target(target, value, ctx);
+                        }
+                        // `{ a: b = 1 } = obj`
+                        AssignmentTargetProperty::AssignmentTargetPropertyProperty(property) => {
+                            let AssignmentTargetPropertyProperty { name, binding, .. } =
+                                property.unbox();
+                            let value = object.read_property(name, self, ctx);
+                            self.lower_assignment_target_maybe_default(binding, value, ctx);
+                        }
+                    }
+                }
+                if let Some(rest) = rest {
+                    let value = object.read_rest(self, ctx);
+                    self.lower_assignment_target(rest.target, value, ctx);
+                }
+            }
+            AssignmentTarget::ArrayAssignmentTarget(target) => {
+                let ArrayAssignmentTarget { elements, rest, .. } = target.unbox();
+                let count = elements.len();
+                let reads = elements.iter().flatten().count();
+                let Some(mut array) = self.array_source(value, count, reads, rest.is_some(), ctx)
+                else {
+                    return;
+                };
+                for (index, element) in elements.into_iter().enumerate() {
+                    let Some(element) = element else { continue };
+                    let value = array.read_element(index, ctx);
+                    self.lower_assignment_target_maybe_default(element, value, ctx);
+                }
+                if let Some(rest) = rest {
+                    let value = array.read_rest(count, ctx);
+                    self.lower_assignment_target(rest.target, value, ctx);
+                }
+            }
+            target => {
+                let assignment =
+                    ctx.ast.expression_assignment(SPAN, AssignmentOperator::Assign, target, value);
+                self.expressions.push(assignment);
+            }
+        }
+    }
+
+    fn lower_assignment_target_maybe_default(
+        &mut self,
+        target: AssignmentTargetMaybeDefault<'a>,
+        value: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        match target {
+            AssignmentTargetMaybeDefault::AssignmentTargetWithDefault(target) => {
+                let AssignmentTargetWithDefault { binding, init, .. } = target.unbox();
+                let value = self.with_default(value, init, ctx);
+                self.lower_assignment_target(binding, value, ctx);
+            }
+            target => self.lower_assignment_target(target.into_assignment_target(), value, ctx),
+        }
+    }
+
+    /// `value`, default `init` -> `_value === void 0 ? init : _value`
+    fn with_default(
+        &mut self,
+        value: Expression<'a>,
+        init: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let mut source = self.source(value, 2, ctx);
+        let test = ctx.ast.expression_binary(
+            SPAN,
+            source.read(ctx),
+            BinaryOperator::StrictEquality,
+            ctx.ast.void_0(SPAN),
+        );
+        ctx.ast.expression_conditional(SPAN, test, init, source.read(ctx))
+    }
+
+    /// Create a [`Source`] for `value`, which will be read `reads` times.
+    fn source(
+        &mut self,
+        value: Expression<'a>,
+        reads: usize,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Source<'a> {
+        if reads <= 1 {
+            return Source::Expression(Some(value));
+        }
+        if matches!(value, Expression::ThisExpression(_)) {
+            return Source::This;
+        }
+        if let Expression::Identifier(ident) = &value {
+            // Temporary variable, or bound identifier which is never reassigned,
+            // can be read repeatedly
+            let symbol_id = ctx.symbols().get_reference(ident.reference_id()).symbol_id();
+            if let Some(symbol_id) = symbol_id {
+                if self.temp_symbol_ids.contains(&symbol_id)
+                    || (ctx.is_static(&value) && !self.declared_symbol_ids.contains(&symbol_id))
+                {
+                    ctx.delete_reference_for_identifier(ident);
+                    return Source::Binding(BoundIdentifier::new(ident.name.clone(), symbol_id));
+                }
+            }
+        }
+        Source::Binding(self.memoize(value, ctx))
+    }
+
+    /// Store `value` in a temporary variable.
+    fn memoize(&mut self, value: Expression<'a>, ctx: &mut TraverseCtx<'a>) -> BoundIdentifier<'a> {
+        let binding = self.create_temp(&value, ctx);
+        self.init_temp(&binding, value, ctx);
+        binding
+    }
+
+    /// Create a temporary variable, named after `node`.
+    fn create_temp(
+        &mut self,
+        node: &Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> BoundIdentifier<'a> {
+        let (scope_id, flags) = match self.declaration {
+            Some((_, scope_id, flags)) => (scope_id, flags),
+            None => temp_binding_location(VariableDeclarationKind::Var, ctx),
+        };
+        let binding = ctx.generate_uid_based_on_node(node, scope_id, flags);
+        self.temp_symbol_ids.push(binding.symbol_id);
+        binding
+    }
+
+    /// Initialize temporary variable `binding` with `value`.
+    fn init_temp(
+        &mut self,
+        binding: &BoundIdentifier<'a>,
+        value: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if let Some((kind, _, _)) = self.declaration {
+            // `_value = value` declarator
+            let declarator = ctx.ast.variable_declarator(
+                SPAN,
+                kind,
+                binding.create_binding_pattern(ctx),
+                Some(value),
+                false,
+            );
+            self.declarators.push(declarator);
+        } else {
+            // `var _value;` and `_value = value` assignment
+            self.ctx.var_declarations.insert_var(binding, None, ctx);
+            let assignment = ctx.ast.expression_assignment(
+                SPAN,
+                AssignmentOperator::Assign,
+                binding.create_write_target(ctx),
+                value,
+            );
+            self.expressions.push(assignment);
+        }
+    }
+
+    /// Evaluate `value` for its side effects only.
+    fn discard(&mut self, value: Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.declaration.is_some() {
+            self.memoize(value, ctx);
+        } else {
+            self.expressions.push(value);
+        }
+    }
+
+    /// Prepare `value` for destructuring with an object pattern.
+    ///
+    /// Returns `None` if the pattern is empty, `{} = value` -> `_objectDestructuringEmpty(value)`.
+    fn object_source(
+        &mut self,
+        value: Expression<'a>,
+        properties_count: usize,
+        has_rest: bool,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<ObjectSource<'a>> {
+        if properties_count == 0 && !has_rest {
+            let binding = self.declaration.is_some().then(|| self.create_temp(&value, ctx));
+            let value = self.ctx.helper_call_expr(
+                Helper::ObjectDestructuringEmpty,
+                ctx.ast.vec1(Argument::from(value)),
+                ctx,
+            );
+            match binding {
+                Some(binding) => self.init_temp(&binding, value, ctx),
+                None => self.expressions.push(value),
+            }
+            return None;
+        }
+
+        let reads = properties_count + usize::from(has_rest);
+        let source = self.source(value, reads, ctx);
+        let excluded_keys = has_rest.then(|| ctx.ast.vec());
+        Some(ObjectSource { source, excluded_keys })
+    }
+
+    /// Prepare `value` for destructuring with an array pattern, converting it to an array.
+    ///
+    /// * `[a, b] = value` -> `_slicedToArray(value, 2)`
+    /// * `[a, ...b] = value` -> `_toArray(value)`
+    ///
+    /// Returns `None` if the pattern is empty.
+    fn array_source(
+        &mut self,
+        value: Expression<'a>,
+        count: usize,
+        reads: usize,
+        has_rest: bool,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<ArraySource<'a>> {
+        let reads = reads + usize::from(has_rest);
+        if self.ctx.assumptions.iterable_is_array || matches!(value, Expression::ArrayExpression(_))
+        {
+            if reads == 0 {
+                self.discard(value, ctx);
+                return None;
+            }
+            return Some(ArraySource { source: self.source(value, reads, ctx) });
+        }
+
+        // Name temp var after the original value, not the helper call
+        let binding = (reads > 1 || (reads == 0 && self.declaration.is_some()))
+            .then(|| self.create_temp(&value, ctx));
+        let value = if has_rest {
+            self.ctx.helper_call_expr(Helper::ToArray, ctx.ast.vec1(Argument::from(value)), ctx)
+        } else {
+            #[expect(clippy::cast_precision_loss)]
+            let count = count as f64;
+            let count = ctx.ast.expression_numeric_literal(
+                SPAN,
+                count,
+                count.to_string(),
+                NumberBase::Decimal,
+            );
+            let arguments = ctx.ast.vec_from_iter([Argument::from(value), Argument::from(count)]);
+            self.ctx.helper_call_expr(Helper::SlicedToArray, arguments, ctx)
+        };
+
+        let source = match binding {
+            Some(binding) => {
+                self.init_temp(&binding, value, ctx);
+                if reads == 0 {
+                    return None;
+                }
+                Source::Binding(binding)
+            }
+            None if reads == 0 => {
+                self.expressions.push(value);
+                return None;
+            }
+            None => Source::Expression(Some(value)),
+        };
+        Some(ArraySource { source })
+    }
+}
+
+/// A value being destructured with an object pattern.
+struct ObjectSource<'a> {
+    source: Source<'a>,
+    /// Keys of properties read so far, to exclude from the object rest.
+    /// `None` if the pattern has no rest element.
+    excluded_keys: Option<ArenaVec<'a, ArrayExpressionElement<'a>>>,
+}
+
+impl<'a> ObjectSource<'a> {
+    /// `{ a } = obj` -> `obj.a`, `{ [a]: b } = obj` -> `obj[a]`
+    fn read_property(
+        &mut self,
+        key: PropertyKey<'a>,
+        lowering: &mut PatternLowering<'a, '_>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let object = self.source.read(ctx);
+        let member_expr = match key {
+            PropertyKey::StaticIdentifier(ident) => {
+                if let Some(excluded_keys) = self.excluded_keys.as_mut() {
+                    let key = ctx.ast.expression_string_literal(SPAN, ident.name.clone());
+                    excluded_keys.push(ArrayExpressionElement::from(key));
+                }
+                ctx.ast.member_expression_static(SPAN, object, ident.unbox(), false)
+            }
+            PropertyKey::PrivateIdentifier(_) => unreachable!(),
+            key => {
+                let mut key = key.into_expression();
+                if let Some(excluded_keys) = self.excluded_keys.as_mut() {
+                    let name = match &key {
+                        Expression::StringLiteral(lit) => Some(lit.value.clone()),
+                        Expression::NumericLiteral(lit) => {
+                            Some(ctx.ast.atom(&lit.value.to_js_string()))
+                        }
+                        _ => None,
+                    };
+                    let excluded_key = if let Some(name) = name {
+                        ctx.ast.expression_string_literal(SPAN, name)
+                    } else {
+                        // Convert key to a property key once, and use it for both property access
+                        // and excluding from object rest: `_key = _toPropertyKey(key)`
+                        let binding = lowering.create_temp(&key, ctx);
+                        let key_expr = lowering.ctx.helper_call_expr(
+                            Helper::ToPropertyKey,
+                            ctx.ast.vec1(Argument::from(key)),
+                            ctx,
+                        );
+                        lowering.init_temp(&binding, key_expr, ctx);
+                        key = binding.create_read_expression(ctx);
+                        binding.create_read_expression(ctx)
+                    };
+                    excluded_keys.push(ArrayExpressionElement::from(excluded_key));
+                }
+                ctx.ast.member_expression_computed(SPAN, object, key, false)
+            }
+        };
+        Expression::from(member_expr)
+    }
+
+    /// `{ a, ...rest } = obj` -> `_objectWithoutProperties(obj, ["a"])`
+    fn read_rest(
+        &mut self,
+        lowering: &PatternLowering<'a, '_>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let object = self.source.read(ctx);
+        let excluded_keys = self.excluded_keys.take().unwrap();
+        let excluded_keys = ctx.ast.expression_array(SPAN, excluded_keys, None);
+        let helper = if lowering.ctx.assumptions.object_rest_no_symbols {
+            Helper::ObjectWithoutPropertiesLoose
+        } else {
+            Helper::ObjectWithoutProperties
+        };
+        let arguments =
+            ctx.ast.vec_from_iter([Argument::from(object), Argument::from(excluded_keys)]);
+        lowering.ctx.helper_call_expr(helper, arguments, ctx)
+    }
+}
+
+/// A value being destructured with an array pattern.
+struct ArraySource<'a> {
+    source: Source<'a>,
+}
+
+impl<'a> ArraySource<'a> {
+    /// `[, a] = arr` -> `arr[1]`
+    fn read_element(&mut self, index: usize, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        let array = self.source.read(ctx);
+        #[expect(clippy::cast_precision_loss)]
+        let index = index as f64;
+        let index =
+            ctx.ast.expression_numeric_literal(SPAN, index, index.to_string(), NumberBase::Decimal);
+        Expression::from(ctx.ast.member_expression_computed(SPAN, array, index, false))
+    }
+
+    /// `[a, ...rest] = arr` -> `arr.slice(1)`
+    fn read_rest(&mut self, index: usize, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        let array = self.source.read(ctx);
+        let slice = ctx.ast.identifier_name(SPAN, "slice");
+        let callee = Expression::from(ctx.ast.member_expression_static(SPAN, array, slice, false));
+        #[expect(clippy::cast_precision_loss)]
+        let index = index as f64;
+        let index =
+            ctx.ast.expression_numeric_literal(SPAN, index, index.to_string(), NumberBase::Decimal);
+        ctx.ast.expression_call(
+            SPAN,
+            callee,
+            NONE,
+            ctx.ast.vec1(Argument::from(index)),
+            false,
+            false,
+        )
+    }
+}
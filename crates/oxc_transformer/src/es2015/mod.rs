@@ -1,29 +1,183 @@
-use oxc_traverse::Traverse;
+use oxc_ast::ast::*;
+use oxc_traverse::{Traverse, TraverseCtx};
 
 mod arrow_functions;
+mod block_scoping;
+mod classes;
+mod destructuring;
+mod object_literal_proto;
 mod options;
+mod template_literals;
 
 pub use arrow_functions::{ArrowFunctions, ArrowFunctionsOptions};
+pub use block_scoping::BlockScoping;
+pub use classes::Classes;
+pub(crate) use destructuring::ChildScopeCollector;
+pub use destructuring::Destructuring;
+pub use object_literal_proto::ObjectLiteralProto;
 pub use options::ES2015Options;
+pub use template_literals::TemplateLiterals;
 
 use crate::context::TransformCtx;
 
 pub struct ES2015<'a, 'ctx> {
-    #[expect(unused)]
     options: ES2015Options,
 
     // Plugins
     #[expect(unused)]
     arrow_functions: ArrowFunctions<'a, 'ctx>,
+    block_scoping: BlockScoping<'a, 'ctx>,
+    classes: Classes<'a, 'ctx>,
+    destructuring: Destructuring<'a, 'ctx>,
+    object_literal_proto: ObjectLiteralProto<'a, 'ctx>,
+    template_literals: TemplateLiterals<'a, 'ctx>,
 }
 
 impl<'a, 'ctx> ES2015<'a, 'ctx> {
     pub fn new(options: ES2015Options, ctx: &'ctx TransformCtx<'a>) -> Self {
         Self {
             arrow_functions: ArrowFunctions::new(options.arrow_function.unwrap_or_default(), ctx),
+            block_scoping: BlockScoping::new(ctx),
+            classes: Classes::new(ctx),
+            destructuring: Destructuring::new(ctx),
+            object_literal_proto: ObjectLiteralProto::new(ctx),
+            template_literals: TemplateLiterals::new(ctx),
             options,
         }
     }
 }
 
-impl<'a, 'ctx> Traverse<'a> for ES2015<'a, 'ctx> {}
+impl<'a, 'ctx> Traverse<'a> for ES2015<'a, 'ctx> {
+    fn exit_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.template_literals {
+            self.template_literals.exit_program(program, ctx);
+        }
+    }
+
+    fn enter_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        // Destructuring must run first, to move patterns in loop heads into loop body
+        // before block scoping inspects the loop
+        if self.options.destructuring {
+            self.destructuring.enter_statement(stmt, ctx);
+        }
+        if self.options.block_scoping {
+            self.block_scoping.enter_statement(stmt, ctx);
+        }
+    }
+
+    fn exit_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.classes {
+            self.classes.exit_statement(stmt, ctx);
+        }
+        if self.options.block_scoping {
+            self.block_scoping.exit_statement(stmt, ctx);
+        }
+    }
+
+    fn enter_block_statement(&mut self, block: &mut BlockStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.block_scoping {
+            self.block_scoping.enter_block_statement(block, ctx);
+        }
+    }
+
+    fn enter_for_statement(&mut self, stmt: &mut ForStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.block_scoping {
+            self.block_scoping.enter_for_statement(stmt, ctx);
+        }
+    }
+
+    fn enter_for_in_statement(&mut self, stmt: &mut ForInStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.block_scoping {
+            self.block_scoping.enter_for_in_statement(stmt, ctx);
+        }
+    }
+
+    fn enter_for_of_statement(&mut self, stmt: &mut ForOfStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.block_scoping {
+            self.block_scoping.enter_for_of_statement(stmt, ctx);
+        }
+    }
+
+    fn enter_switch_statement(
+        &mut self,
+        stmt: &mut SwitchStatement<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if self.options.block_scoping {
+            self.block_scoping.enter_switch_statement(stmt, ctx);
+        }
+    }
+
+    fn enter_variable_declaration(
+        &mut self,
+        decl: &mut VariableDeclaration<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if self.options.block_scoping {
+            self.block_scoping.enter_variable_declaration(decl, ctx);
+        }
+        if self.options.destructuring {
+            self.destructuring.enter_variable_declaration(decl, ctx);
+        }
+    }
+
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.destructuring {
+            self.destructuring.enter_expression(expr, ctx);
+        }
+        if self.options.template_literals {
+            self.template_literals.enter_expression(expr, ctx);
+        }
+    }
+
+    fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.classes {
+            self.classes.exit_expression(expr, ctx);
+        }
+        if self.options.object_literal_proto {
+            self.object_literal_proto.exit_expression(expr, ctx);
+        }
+    }
+
+    fn enter_function(&mut self, func: &mut Function<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.destructuring {
+            self.destructuring.enter_function(func, ctx);
+        }
+    }
+
+    fn enter_arrow_function_expression(
+        &mut self,
+        arrow: &mut ArrowFunctionExpression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if self.options.destructuring {
+            self.destructuring.enter_arrow_function_expression(arrow, ctx);
+        }
+    }
+
+    fn enter_catch_clause(&mut self, clause: &mut CatchClause<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.destructuring {
+            self.destructuring.enter_catch_clause(clause, ctx);
+        }
+    }
+
+    fn enter_binding_identifier(
+        &mut self,
+        ident: &mut BindingIdentifier<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if self.options.block_scoping {
+            self.block_scoping.enter_binding_identifier(ident, ctx);
+        }
+    }
+
+    fn enter_identifier_reference(
+        &mut self,
+        ident: &mut IdentifierReference<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if self.options.block_scoping {
+            self.block_scoping.enter_identifier_reference(ident, ctx);
+        }
+    }
+}
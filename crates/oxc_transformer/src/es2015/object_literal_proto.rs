@@ -0,0 +1,173 @@
+//! ES2015: `__proto__` in Object Literals
+//!
+//! This plugin transforms a `__proto__: value` property in an object literal, which sets
+//! the prototype of the object, into an `Object.setPrototypeOf` call.
+//!
+//! > This plugin is not included in `preset-env`. `__proto__` in object literals is not
+//! > in compat data, so it is enabled for ES5 targets and IE before 11.
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! const a = { __proto__: proto, b: 1 };
+//! const c = { __proto__: getProto(), d: getD() };
+//! ```
+//!
+//! Output:
+//! ```js
+//! var _getProto;
+//! const a = Object.setPrototypeOf({ b: 1 }, proto);
+//! const c = (_getProto = getProto(), Object.setPrototypeOf({ d: getD() }, _getProto));
+//! ```
+//!
+//! ## Implementation
+//!
+//! Only a property defined with `__proto__:` or `"__proto__":` sets the prototype.
+//! A computed key (`["__proto__"]: value`), shorthand property (`{ __proto__ }`), and method
+//! (`__proto__() {}`) define an own property named `__proto__`, so they are left as is.
+//!
+//! Properties are evaluated in order, so if moving the value of `__proto__` after the properties
+//! which follow it may change the result, the value is stored in a temporary variable.
+//!
+//! `__proto__` with a primitive literal value (`{ __proto__: 1 }`) is ignored at runtime,
+//! so it is removed. `Object.setPrototypeOf` throws if given any other value which is not
+//! an object or `null`, unlike the object literal.
+//!
+//! `Object.setPrototypeOf` is not available in ES5 engines, so a polyfill is required.
+//!
+//! ## References:
+//! * `__proto__` Property Names in Object Initializers specification: <https://tc39.es/ecma262/#sec-__proto__-property-names-in-object-initializers>
+
+use oxc_ast::{ast::*, NONE};
+use oxc_ecmascript::{constant_evaluation::IsLiteralValue, side_effects::MayHaveSideEffects};
+use oxc_semantic::{ReferenceFlags, SymbolFlags};
+use oxc_span::SPAN;
+use oxc_syntax::operator::AssignmentOperator;
+use oxc_traverse::{Traverse, TraverseCtx};
+
+use crate::TransformCtx;
+
+pub struct ObjectLiteralProto<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
+}
+
+impl<'a, 'ctx> ObjectLiteralProto<'a, 'ctx> {
+    pub fn new(ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self { ctx }
+    }
+}
+
+impl<'a, 'ctx> Traverse<'a> for ObjectLiteralProto<'a, 'ctx> {
+    // `#[inline]` because this is a hot path, and most `Expression`s are not `ObjectExpression`s
+    #[inline]
+    fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if matches!(expr, Expression::ObjectExpression(_)) {
+            self.transform_object_expression(expr, ctx);
+        }
+    }
+}
+
+impl<'a, 'ctx> ObjectLiteralProto<'a, 'ctx> {
+    /// `{ __proto__: proto, a: 1 }` -> `Object.setPrototypeOf({ a: 1 }, proto)`
+    fn transform_object_expression(&self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Expression::ObjectExpression(object) = expr else { unreachable!() };
+
+        // There can only be one `__proto__` property, otherwise it's a syntax error
+        let Some(index) = object.properties.iter().position(Self::is_proto_setter) else {
+            return;
+        };
+        let ObjectPropertyKind::ObjectProperty(property) = object.properties.remove(index) else {
+            unreachable!()
+        };
+        let proto = property.unbox().value;
+        if Self::is_primitive_literal(&proto) {
+            return;
+        }
+
+        // `__proto__` value is now evaluated after the properties which followed it.
+        // If that may change the result, evaluate it first and store it in a temp var.
+        let later_properties = &object.properties.as_slice()[index..];
+        let needs_temp_var = !proto.is_literal_value(true)
+            && later_properties.iter().any(|property| !property.is_literal_value(true))
+            && (proto.may_have_side_effects()
+                || later_properties.iter().any(Self::property_may_have_side_effects));
+        let (proto, temp_var_init) = if needs_temp_var {
+            let binding = ctx.generate_uid_in_current_scope_based_on_node(
+                &proto,
+                SymbolFlags::FunctionScopedVariable,
+            );
+            self.ctx.var_declarations.insert_var(&binding, None, ctx);
+            let init = ctx.ast.expression_assignment(
+                SPAN,
+                AssignmentOperator::Assign,
+                binding.create_read_write_target(ctx),
+                proto,
+            );
+            (binding.create_read_expression(ctx), Some(init))
+        } else {
+            (proto, None)
+        };
+
+        let object = ctx.ast.move_expression(expr);
+        let arguments = ctx.ast.vec_from_iter([Argument::from(object), Argument::from(proto)]);
+        let set_prototype_of = ctx.ast.expression_call(
+            SPAN,
+            Self::create_object_set_prototype_of(ctx),
+            NONE,
+            arguments,
+            false,
+            false,
+        );
+
+        *expr = match temp_var_init {
+            // `(_proto = getProto(), Object.setPrototypeOf({ a: getA() }, _proto))`
+            Some(init) => {
+                ctx.ast.expression_sequence(SPAN, ctx.ast.vec_from_iter([init, set_prototype_of]))
+            }
+            None => set_prototype_of,
+        };
+    }
+
+    /// Check if property is `__proto__: value` or `"__proto__": value`.
+    fn is_proto_setter(property: &ObjectPropertyKind<'a>) -> bool {
+        let ObjectPropertyKind::ObjectProperty(property) = property else { return false };
+        property.kind == PropertyKind::Init
+            && !property.computed
+            && !property.shorthand
+            && !property.method
+            && (property.key.is_specific_id("__proto__")
+                || property.key.is_specific_string_literal("__proto__"))
+    }
+
+    /// Primitive values other than `null` are ignored as `__proto__` value.
+    fn is_primitive_literal(expr: &Expression<'a>) -> bool {
+        matches!(
+            expr,
+            Expression::BooleanLiteral(_)
+                | Expression::NumericLiteral(_)
+                | Expression::BigIntLiteral(_)
+                | Expression::StringLiteral(_)
+        )
+    }
+
+    fn property_may_have_side_effects(property: &ObjectPropertyKind<'a>) -> bool {
+        match property {
+            ObjectPropertyKind::ObjectProperty(property) => {
+                (property.computed && property.key.may_have_side_effects())
+                    || property.value.may_have_side_effects()
+            }
+            ObjectPropertyKind::SpreadProperty(_) => true,
+        }
+    }
+
+    /// `Object.setPrototypeOf`
+    fn create_object_set_prototype_of(ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        let symbol_id = ctx.scopes().find_binding(ctx.current_scope_id(), "Object");
+        let ident =
+            ctx.create_reference_id(SPAN, Atom::from("Object"), symbol_id, ReferenceFlags::Read);
+        let object = Expression::Identifier(ctx.alloc(ident));
+        let property = ctx.ast.identifier_name(SPAN, "setPrototypeOf");
+        Expression::from(ctx.ast.member_expression_static(SPAN, object, property, false))
+    }
+}
@@ -7,4 +7,19 @@ use super::ArrowFunctionsOptions;
 pub struct ES2015Options {
     #[serde(skip)]
     pub arrow_function: Option<ArrowFunctionsOptions>,
+
+    #[serde(skip)]
+    pub block_scoping: bool,
+
+    #[serde(skip)]
+    pub classes: bool,
+
+    #[serde(skip)]
+    pub destructuring: bool,
+
+    #[serde(skip)]
+    pub object_literal_proto: bool,
+
+    #[serde(skip)]
+    pub template_literals: bool,
 }
@@ -0,0 +1,215 @@
+//! ES2015: Template Literals
+//!
+//! This plugin transforms template literals into string concatenation, and tagged templates
+//! into calls to the tag with a cached template object.
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! const a = `foo${bar}baz${qux}`;
+//! const b = tag`foo${bar}`;
+//! ```
+//!
+//! Output:
+//! ```js
+//! var _templateObject;
+//! const a = "foo".concat(bar, "baz").concat(qux);
+//! const b = tag(_templateObject || (_templateObject = babelHelpers.taggedTemplateLiteral(["foo", ""])), bar);
+//! ```
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-transform-template-literals](https://babel.dev/docs/babel-plugin-transform-template-literals).
+//!
+//! Template literals and tagged templates are transformed on entry, so a template nested inside
+//! another template's substitutions is transformed after the outer one, as it is in Babel.
+//!
+//! Each `concat` call is passed at most one expression which is not a literal, so the expressions
+//! are converted to strings in the same order as in the template literal.
+//!
+//! The template object of each tagged template is created once, and cached in a variable declared
+//! at top of the program, because a tagged template evaluates to the same object every time.
+//! If the cooked string of a template element is `undefined` (due to an invalid escape sequence),
+//! or differs from the raw string, the raw strings are passed to the helper separately.
+//!
+//! Compiler assumptions:
+//!
+//! * `ignoreToPrimitiveHint`: Template literals are transformed into `+` operations
+//!   (`"foo" + bar + "baz" + qux`), which call `valueOf` before `toString`.
+//! * `mutableTemplateObject`: Template objects are not frozen, and are created with
+//!   `taggedTemplateLiteralLoose` helper.
+//!
+//! ## References:
+//! * Babel plugin implementation: <https://github.com/babel/babel/blob/main/packages/babel-plugin-transform-template-literals/src/index.ts>
+//! * Template Literals specification: <https://tc39.es/ecma262/#sec-template-literals>
+
+use oxc_allocator::Vec as ArenaVec;
+use oxc_ast::{ast::*, NONE};
+use oxc_semantic::SymbolFlags;
+use oxc_span::{GetSpanMut, SPAN};
+use oxc_syntax::operator::{AssignmentOperator, BinaryOperator, LogicalOperator};
+use oxc_traverse::{BoundIdentifier, Traverse, TraverseCtx};
+
+use crate::{common::helper_loader::Helper, TransformCtx};
+
+pub struct TemplateLiterals<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
+    /// Variables caching template objects of tagged templates
+    template_objects: Vec<BoundIdentifier<'a>>,
+}
+
+impl<'a, 'ctx> TemplateLiterals<'a, 'ctx> {
+    pub fn new(ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self { ctx, template_objects: vec![] }
+    }
+}
+
+impl<'a, 'ctx> Traverse<'a> for TemplateLiterals<'a, 'ctx> {
+    /// Declare `var _templateObject, _templateObject2;` at top of program.
+    fn exit_program(&mut self, _program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        for binding in self.template_objects.drain(..) {
+            self.ctx.var_declarations.insert_var(&binding, None, ctx);
+        }
+    }
+
+    // `#[inline]` because this is a hot path, and most `Expression`s are not templates
+    #[inline]
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        match expr {
+            Expression::TemplateLiteral(_) => self.transform_template_literal(expr, ctx),
+            Expression::TaggedTemplateExpression(_) => self.transform_tagged_template(expr, ctx),
+            _ => {}
+        }
+    }
+}
+
+impl<'a, 'ctx> TemplateLiterals<'a, 'ctx> {
+    /// `` `foo${bar}baz` `` -> `"foo".concat(bar, "baz")`
+    fn transform_template_literal(&self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Expression::TemplateLiteral(template) = ctx.ast.move_expression(expr) else {
+            unreachable!()
+        };
+        let TemplateLiteral { span, quasis, expressions } = template.unbox();
+
+        // Strings and expressions, omitting empty strings
+        let mut nodes = Vec::with_capacity(quasis.len() + expressions.len());
+        let mut expressions = expressions.into_iter();
+        for quasi in quasis {
+            if let Some(cooked) = quasi.value.cooked.filter(|cooked| !cooked.is_empty()) {
+                nodes.push(ctx.ast.expression_string_literal(SPAN, cooked));
+            }
+            if let Some(expr) = expressions.next() {
+                if !matches!(&expr, Expression::StringLiteral(lit) if lit.value.is_empty()) {
+                    nodes.push(expr);
+                }
+            }
+        }
+
+        // Result must be a string, so start with a string
+        let ignore_to_primitive_hint = self.ctx.assumptions.ignore_to_primitive_hint;
+        let starts_with_string = matches!(nodes.first(), Some(Expression::StringLiteral(_)))
+            || (ignore_to_primitive_hint
+                && matches!(nodes.get(1), Some(Expression::StringLiteral(_))));
+        if !starts_with_string {
+            nodes.insert(0, ctx.ast.expression_string_literal(SPAN, ""));
+        }
+
+        let mut nodes = nodes.into_iter();
+        let mut root = nodes.next().unwrap();
+        if ignore_to_primitive_hint {
+            for node in nodes {
+                root = ctx.ast.expression_binary(SPAN, root, BinaryOperator::Addition, node);
+            }
+        } else {
+            // Only one expression which is not a literal can be added to each `concat` call
+            let mut can_insert_non_literal = true;
+            for node in nodes {
+                let mut can_be_inserted =
+                    node.is_literal() || matches!(node, Expression::TemplateLiteral(_));
+                if !can_be_inserted && can_insert_non_literal {
+                    can_be_inserted = true;
+                    can_insert_non_literal = false;
+                }
+                if can_be_inserted {
+                    if let Expression::CallExpression(call) = &mut root {
+                        call.arguments.push(Argument::from(node));
+                        continue;
+                    }
+                }
+                let property = ctx.ast.identifier_name(SPAN, "concat");
+                let callee =
+                    Expression::from(ctx.ast.member_expression_static(SPAN, root, property, false));
+                let arguments = ctx.ast.vec1(Argument::from(node));
+                root = ctx.ast.expression_call(SPAN, callee, NONE, arguments, false, false);
+            }
+        }
+
+        *root.span_mut() = span;
+        *expr = root;
+    }
+
+    /// `` tag`foo${bar}` `` ->
+    /// `tag(_templateObject || (_templateObject = babelHelpers.taggedTemplateLiteral(["foo", ""])), bar)`
+    fn transform_tagged_template(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Expression::TaggedTemplateExpression(tagged) = ctx.ast.move_expression(expr) else {
+            unreachable!()
+        };
+        let TaggedTemplateExpression { span, tag, quasi, type_parameters: _ } = tagged.unbox();
+
+        let mut strings = ctx.ast.vec_with_capacity(quasi.quasis.len());
+        let mut raws = ctx.ast.vec_with_capacity(quasi.quasis.len());
+        let mut is_strings_raw_equal = true;
+        for quasi in &quasi.quasis {
+            let TemplateElementValue { raw, cooked } = &quasi.value;
+            let string = match cooked {
+                Some(cooked) => ctx.ast.expression_string_literal(SPAN, cooked.clone()),
+                None => ctx.ast.void_0(SPAN),
+            };
+            if cooked.as_ref() != Some(raw) {
+                is_strings_raw_equal = false;
+            }
+            strings.push(ArrayExpressionElement::from(string));
+            raws.push(ArrayExpressionElement::from(
+                ctx.ast.expression_string_literal(SPAN, raw.clone()),
+            ));
+        }
+
+        let mut helper_arguments =
+            ctx.ast.vec1(Argument::from(ctx.ast.expression_array(SPAN, strings, None)));
+        if !is_strings_raw_equal {
+            helper_arguments.push(Argument::from(ctx.ast.expression_array(SPAN, raws, None)));
+        }
+        let helper = if self.ctx.assumptions.mutable_template_object {
+            Helper::TaggedTemplateLiteralLoose
+        } else {
+            Helper::TaggedTemplateLiteral
+        };
+        let template_object = self.ctx.helper_call_expr(helper, helper_arguments, ctx);
+
+        // `_templateObject || (_templateObject = template_object)`
+        let binding =
+            ctx.generate_uid_in_root_scope("templateObject", SymbolFlags::FunctionScopedVariable);
+        let assignment = ctx.ast.expression_assignment(
+            SPAN,
+            AssignmentOperator::Assign,
+            binding.create_read_write_target(ctx),
+            template_object,
+        );
+        let template_object = ctx.ast.expression_logical(
+            SPAN,
+            binding.create_read_expression(ctx),
+            LogicalOperator::Or,
+            assignment,
+        );
+        self.template_objects.push(binding);
+
+        let mut arguments: ArenaVec<'a, Argument<'a>> =
+            ctx.ast.vec_with_capacity(1 + quasi.expressions.len());
+        arguments.push(Argument::from(template_object));
+        arguments.extend(quasi.expressions.into_iter().map(Argument::from));
+        *expr = ctx.ast.expression_call(span, tag, NONE, arguments, false, false);
+    }
+}
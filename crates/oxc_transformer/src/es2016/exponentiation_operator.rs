@@ -554,7 +554,7 @@ impl<'a, 'ctx> ExponentiationOperator<'a, 'ctx> {
         let callee =
             Expression::from(ctx.ast.member_expression_static(SPAN, object, property, false));
         let arguments = ctx.ast.vec_from_iter([Argument::from(left), Argument::from(right)]);
-        ctx.ast.expression_call(SPAN, callee, NONE, arguments, false)
+        ctx.ast.expression_call(SPAN, callee, NONE, arguments, false, false)
     }
 
     /// Create a temporary variable.
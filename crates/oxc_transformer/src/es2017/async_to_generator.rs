@@ -63,7 +63,10 @@ use oxc_syntax::{
 };
 use oxc_traverse::{Ancestor, BoundIdentifier, Traverse, TraverseCtx};
 
-use crate::{common::helper_loader::Helper, TransformCtx};
+use crate::{
+    common::{function_name, helper_loader::Helper},
+    TransformCtx,
+};
 
 pub struct AsyncToGenerator<'a, 'ctx> {
     ctx: &'ctx TransformCtx<'a>,
@@ -78,12 +81,16 @@ impl<'a, 'ctx> AsyncToGenerator<'a, 'ctx> {
 
 impl<'a, 'ctx> Traverse<'a> for AsyncToGenerator<'a, 'ctx> {
     fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let mut lost_name = None;
         let new_expr = match expr {
             Expression::AwaitExpression(await_expr) => {
                 Self::transform_await_expression(await_expr, ctx)
             }
             Expression::FunctionExpression(func) => {
                 if func.r#async && !func.generator && !func.is_typescript_syntax() {
+                    if func.id.is_none() {
+                        lost_name = self.get_lost_function_name(ctx);
+                    }
                     Some(self.executor.transform_function_expression(func, ctx))
                 } else {
                     None
@@ -91,6 +98,7 @@ impl<'a, 'ctx> Traverse<'a> for AsyncToGenerator<'a, 'ctx> {
             }
             Expression::ArrowFunctionExpression(arrow) => {
                 if arrow.r#async {
+                    lost_name = self.get_lost_function_name(ctx);
                     Some(self.executor.transform_arrow_function(arrow, ctx))
                 } else {
                     None
@@ -100,7 +108,10 @@ impl<'a, 'ctx> Traverse<'a> for AsyncToGenerator<'a, 'ctx> {
         };
 
         if let Some(new_expr) = new_expr {
-            *expr = new_expr;
+            *expr = match lost_name {
+                Some(name) => function_name::create_set_function_name(new_expr, name, ctx),
+                None => new_expr,
+            };
         }
     }
 
@@ -145,6 +156,18 @@ impl<'a, 'ctx> Traverse<'a> for AsyncToGenerator<'a, 'ctx> {
 }
 
 impl<'a, 'ctx> AsyncToGenerator<'a, 'ctx> {
+    /// With `keep_names`, get the name of an anonymous function which it would not have after
+    /// being transformed. The function which wraps the generator is only named with the name
+    /// inferred from a variable declarator or object property.
+    fn get_lost_function_name(&self, ctx: &mut TraverseCtx<'a>) -> Option<Atom<'a>> {
+        if !self.ctx.keep_names {
+            return None;
+        }
+        let name = function_name::infer_function_name(ctx)?;
+        let wrapper_name = AsyncGeneratorExecutor::infer_function_name_from_parent_node(ctx);
+        (wrapper_name.as_ref() != Some(&name)).then_some(name)
+    }
+
     /// Check whether the current node is inside an async function.
     fn is_inside_async_function(ctx: &mut TraverseCtx<'a>) -> bool {
         // Early return if current scope is top because we don't need to transform top-level await expression.
@@ -236,7 +259,8 @@ impl<'a, 'ctx> AsyncGeneratorExecutor<'a, 'ctx> {
         let params = Self::create_empty_params(ctx);
         let expression = self.create_async_to_generator_call(params, body, generator_scope_id, ctx);
         // Construct the IIFE
-        let expression = ctx.ast.expression_call(SPAN, expression, NONE, ctx.ast.vec(), false);
+        let expression =
+            ctx.ast.expression_call(SPAN, expression, NONE, ctx.ast.vec(), false, false);
         let statement = ctx.ast.statement_return(SPAN, Some(expression));
 
         // Modify the wrapper function
@@ -258,7 +282,11 @@ impl<'a, 'ctx> AsyncGeneratorExecutor<'a, 'ctx> {
         let id = wrapper_function.id.take();
         let has_function_id = id.is_some();
 
-        if !has_function_id && !Self::is_function_length_affected(&params) {
+        // With `keep_names`, create a wrapper function to give the function its inferred name
+        let keep_name = self.ctx.keep_names
+            && !has_function_id
+            && Self::infer_function_name_from_parent_node(ctx).is_some();
+        if !has_function_id && !keep_name && !Self::is_function_length_affected(&params) {
             return self.create_async_to_generator_call(
                 params,
                 body,
@@ -341,7 +369,7 @@ impl<'a, 'ctx> AsyncGeneratorExecutor<'a, 'ctx> {
         // Construct the IIFE
         let callee =
             Expression::FunctionExpression(ctx.alloc(ctx.ast.move_function(wrapper_function)));
-        ctx.ast.expression_call(SPAN, callee, NONE, ctx.ast.vec(), false)
+        ctx.ast.expression_call(SPAN, callee, NONE, ctx.ast.vec(), false, false)
     }
 
     /// Transforms async function declarations into generator functions wrapped in the asyncToGenerator helper.
@@ -477,7 +505,7 @@ impl<'a, 'ctx> AsyncGeneratorExecutor<'a, 'ctx> {
             let wrapper_function = Self::create_function(None, params, body, wrapper_scope_id, ctx);
             // Construct the IIFE
             let callee = Expression::FunctionExpression(wrapper_function);
-            ctx.ast.expression_call(SPAN, callee, NONE, ctx.ast.vec(), false)
+            ctx.ast.expression_call(SPAN, callee, NONE, ctx.ast.vec(), false, false)
         }
     }
 
@@ -612,7 +640,7 @@ impl<'a, 'ctx> AsyncGeneratorExecutor<'a, 'ctx> {
             ctx.ast.identifier_name(SPAN, "apply"),
             false,
         ));
-        let argument = ctx.ast.expression_call(SPAN, callee, NONE, arguments, false);
+        let argument = ctx.ast.expression_call(SPAN, callee, NONE, arguments, false, false);
         ctx.ast.statement_return(SPAN, Some(argument))
     }
 
@@ -143,6 +143,8 @@ impl<'a, 'ctx> AsyncGeneratorFunctions<'a, 'ctx> {
                 } else {
                     statements.push(ctx.ast.move_statement(stmt_body));
                 }
+            } else {
+                statements.push(ctx.ast.move_statement(stmt_body));
             }
             statements
         };
@@ -308,6 +310,7 @@ impl<'a, 'ctx> AsyncGeneratorFunctions<'a, 'ctx> {
                                             NONE,
                                             ctx.ast.vec(),
                                             false,
+                                            false,
                                         ),
                                     ),
                                 ),
@@ -442,6 +445,7 @@ impl<'a, 'ctx> AsyncGeneratorFunctions<'a, 'ctx> {
                                         NONE,
                                         ctx.ast.vec(),
                                         false,
+                                        false,
                                     ),
                                 ),
                             )),
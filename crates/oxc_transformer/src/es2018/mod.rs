@@ -2,7 +2,7 @@ pub(crate) mod async_generator_functions;
 mod object_rest_spread;
 mod options;
 
-use oxc_ast::ast::{Expression, ForOfStatement, Function, Statement};
+use oxc_ast::ast::*;
 use oxc_traverse::{Traverse, TraverseCtx};
 
 use crate::context::TransformCtx;
@@ -38,6 +38,38 @@ impl<'a, 'ctx> Traverse<'a> for ES2018<'a, 'ctx> {
         }
     }
 
+    fn enter_variable_declaration(
+        &mut self,
+        decl: &mut VariableDeclaration<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if self.options.object_rest_spread.is_some() {
+            self.object_rest_spread.enter_variable_declaration(decl, ctx);
+        }
+    }
+
+    fn enter_function(&mut self, func: &mut Function<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.object_rest_spread.is_some() {
+            self.object_rest_spread.enter_function(func, ctx);
+        }
+    }
+
+    fn enter_arrow_function_expression(
+        &mut self,
+        arrow: &mut ArrowFunctionExpression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if self.options.object_rest_spread.is_some() {
+            self.object_rest_spread.enter_arrow_function_expression(arrow, ctx);
+        }
+    }
+
+    fn enter_catch_clause(&mut self, clause: &mut CatchClause<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.object_rest_spread.is_some() {
+            self.object_rest_spread.enter_catch_clause(clause, ctx);
+        }
+    }
+
     fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
         if self.options.async_generator_functions {
             self.async_generator_functions.exit_expression(expr, ctx);
@@ -45,6 +77,9 @@ impl<'a, 'ctx> Traverse<'a> for ES2018<'a, 'ctx> {
     }
 
     fn enter_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.object_rest_spread.is_some() {
+            self.object_rest_spread.enter_statement(stmt, ctx);
+        }
         if self.options.async_generator_functions {
             self.async_generator_functions.enter_statement(stmt, ctx);
         }
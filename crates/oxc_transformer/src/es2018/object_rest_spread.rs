@@ -10,18 +10,30 @@
 //! ```js
 //! var x = { a: 1, b: 2 };
 //! var y = { ...x, c: 3 };
+//! var { a, ...z } = x;
 //! ```
 //!
 //! Output:
 //! ```js
 //! var x = { a: 1, b: 2 };
-//! var y = _objectSpread({}, x, { c: 3 });
+//! var y = _objectSpread(_objectSpread({}, x), { c: 3 });
+//! var a = x.a, z = _objectWithoutProperties(x, ["a"]);
 //! ```
 //!
 //! ## Implementation
 //!
 //! Implementation based on [@babel/plugin-transform-object-rest-spread](https://babeljs.io/docs/babel-plugin-transform-object-rest-spread).
 //!
+//! Object rest is lowered by the ES2015 [`Destructuring`] transform, restricted to patterns which
+//! contain an object rest element. Those patterns are lowered completely, including any array
+//! patterns they contain.
+//!
+//! Compiler assumptions:
+//!
+//! * `setSpreadProperties`: Object spread uses `Object.assign` instead of `_objectSpread` helper.
+//! * `objectRestNoSymbols`: Object rest uses `_objectWithoutPropertiesLoose` helper,
+//!   which does not copy symbol properties.
+//!
 //! ## References:
 //! * Babel plugin implementation: <https://github.com/babel/babel/tree/main/packages/babel-plugin-transform-object-rest-spread>
 //! * Object rest/spread TC39 proposal: <https://github.com/tc39/proposal-object-rest-spread>
@@ -33,7 +45,7 @@ use oxc_semantic::{ReferenceFlags, SymbolId};
 use oxc_span::SPAN;
 use oxc_traverse::{Traverse, TraverseCtx};
 
-use crate::{common::helper_loader::Helper, TransformCtx};
+use crate::{common::helper_loader::Helper, es2015::Destructuring, TransformCtx};
 
 #[derive(Debug, Default, Clone, Copy, Deserialize)]
 #[serde(default, rename_all = "camelCase")]
@@ -47,17 +59,47 @@ pub struct ObjectRestSpreadOptions {
 pub struct ObjectRestSpread<'a, 'ctx> {
     ctx: &'ctx TransformCtx<'a>,
     options: ObjectRestSpreadOptions,
+    object_rest: Destructuring<'a, 'ctx>,
 }
 
 impl<'a, 'ctx> ObjectRestSpread<'a, 'ctx> {
     pub fn new(options: ObjectRestSpreadOptions, ctx: &'ctx TransformCtx<'a>) -> Self {
-        Self { ctx, options }
+        Self { ctx, options, object_rest: Destructuring::new_object_rest_only(ctx) }
     }
 }
 
 impl<'a, 'ctx> Traverse<'a> for ObjectRestSpread<'a, 'ctx> {
+    fn enter_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.object_rest.enter_statement(stmt, ctx);
+    }
+
+    fn enter_variable_declaration(
+        &mut self,
+        decl: &mut VariableDeclaration<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        self.object_rest.enter_variable_declaration(decl, ctx);
+    }
+
     fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
         self.transform_object_expression(expr, ctx);
+        self.object_rest.enter_expression(expr, ctx);
+    }
+
+    fn enter_function(&mut self, func: &mut Function<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.object_rest.enter_function(func, ctx);
+    }
+
+    fn enter_arrow_function_expression(
+        &mut self,
+        arrow: &mut ArrowFunctionExpression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        self.object_rest.enter_arrow_function_expression(arrow, ctx);
+    }
+
+    fn enter_catch_clause(&mut self, clause: &mut CatchClause<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.object_rest.enter_catch_clause(clause, ctx);
     }
 }
 
@@ -103,7 +145,7 @@ impl<'a, 'ctx> ObjectRestSpread<'a, 'ctx> {
         let callee = self.get_extend_object_callee(object_id, ctx);
 
         // ({ ...x }) => _objectSpread({}, x)
-        *expr = ctx.ast.expression_call(SPAN, callee, NONE, arguments, false);
+        *expr = ctx.ast.expression_call(SPAN, callee, NONE, arguments, false, false);
 
         // ({ ...x, y, z }) => _objectSpread(_objectSpread({}, x), { y, z });
         if !obj_prop_list.is_empty() {
@@ -114,13 +156,13 @@ impl<'a, 'ctx> ObjectRestSpread<'a, 'ctx> {
 
             let callee = self.get_extend_object_callee(object_id, ctx);
 
-            *expr = ctx.ast.expression_call(SPAN, callee, NONE, arguments, false);
+            *expr = ctx.ast.expression_call(SPAN, callee, NONE, arguments, false, false);
         }
     }
 
     #[expect(clippy::option_option)]
     fn get_object_symbol_id(&self, ctx: &mut TraverseCtx<'a>) -> Option<Option<SymbolId>> {
-        if self.options.set_spread_properties {
+        if self.options.set_spread_properties || self.ctx.assumptions.set_spread_properties {
             Some(ctx.scopes().find_binding(ctx.current_scope_id(), "Object"))
         } else {
             None
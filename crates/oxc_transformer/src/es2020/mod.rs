@@ -5,9 +5,11 @@ use oxc_traverse::{Traverse, TraverseCtx};
 use crate::TransformCtx;
 
 mod nullish_coalescing_operator;
+mod optional_chaining;
 mod options;
 
 pub use nullish_coalescing_operator::NullishCoalescingOperator;
+pub use optional_chaining::OptionalChaining;
 pub use options::ES2020Options;
 
 pub struct ES2020<'a, 'ctx> {
@@ -17,11 +19,20 @@ pub struct ES2020<'a, 'ctx> {
 
     // Plugins
     nullish_coalescing_operator: NullishCoalescingOperator<'a, 'ctx>,
+    optional_chaining: OptionalChaining<'a, 'ctx>,
 }
 
 impl<'a, 'ctx> ES2020<'a, 'ctx> {
     pub fn new(options: ES2020Options, ctx: &'ctx TransformCtx<'a>) -> Self {
-        Self { ctx, nullish_coalescing_operator: NullishCoalescingOperator::new(ctx), options }
+        Self {
+            ctx,
+            nullish_coalescing_operator: NullishCoalescingOperator::new(ctx),
+            optional_chaining: OptionalChaining::new(
+                options.optional_chaining_helper_threshold,
+                ctx,
+            ),
+            options,
+        }
     }
 }
 
@@ -30,6 +41,9 @@ impl<'a, 'ctx> Traverse<'a> for ES2020<'a, 'ctx> {
         if self.options.nullish_coalescing_operator {
             self.nullish_coalescing_operator.enter_expression(expr, ctx);
         }
+        if self.options.optional_chaining {
+            self.optional_chaining.enter_expression(expr, ctx);
+        }
     }
 
     fn enter_big_int_literal(&mut self, node: &mut BigIntLiteral<'a>, _ctx: &mut TraverseCtx<'a>) {
@@ -63,7 +63,7 @@ impl<'a, 'ctx> Traverse<'a> for NullishCoalescingOperator<'a, 'ctx> {
 
         // skip creating extra reference when `left` is static
         if ctx.is_static(&logical_expr.left) {
-            *expr = Self::create_conditional_expression(
+            *expr = self.create_conditional_expression(
                 Self::clone_expression(&logical_expr.left, ctx),
                 logical_expr.left,
                 logical_expr.right,
@@ -81,7 +81,10 @@ impl<'a, 'ctx> Traverse<'a> for NullishCoalescingOperator<'a, 'ctx> {
         let current_scope_id = if is_parent_formal_parameter {
             ctx.create_child_scope_of_current(ScopeFlags::Arrow | ScopeFlags::Function)
         } else {
-            ctx.current_scope_id()
+            // Temporary variable is hoisted with `var`
+            ctx.ancestor_scopes()
+                .find(|&scope_id| ctx.scopes().get_flags(scope_id).is_var())
+                .unwrap()
         };
 
         // Add `var _name` to scope
@@ -99,7 +102,7 @@ impl<'a, 'ctx> Traverse<'a> for NullishCoalescingOperator<'a, 'ctx> {
             logical_expr.left,
         );
         let mut new_expr =
-            Self::create_conditional_expression(reference, assignment, logical_expr.right, ctx);
+            self.create_conditional_expression(reference, assignment, logical_expr.right, ctx);
 
         if is_parent_formal_parameter {
             // Replace `function (a, x = a.b ?? c) {}` to `function (a, x = (() => a.b ?? c)() ){}`
@@ -130,7 +133,8 @@ impl<'a, 'ctx> Traverse<'a> for NullishCoalescingOperator<'a, 'ctx> {
                 ),
             );
             // `(x) => x;` -> `((x) => x)();`
-            new_expr = ctx.ast.expression_call(SPAN, arrow_function, NONE, ctx.ast.vec(), false);
+            new_expr =
+                ctx.ast.expression_call(SPAN, arrow_function, NONE, ctx.ast.vec(), false, false);
         } else {
             self.ctx.var_declarations.insert_var(&binding, None, ctx);
         }
@@ -161,14 +165,23 @@ impl<'a, 'ctx> NullishCoalescingOperator<'a, 'ctx> {
     /// ```
     ///
     /// reference and assignment are the same in this case, but they can be different
+    ///
+    /// With `noDocumentAll` assumption, the test is `bar != null` instead.
     fn create_conditional_expression(
+        &self,
         reference: Expression<'a>,
         assignment: Expression<'a>,
         default: Expression<'a>,
         ctx: &mut TraverseCtx<'a>,
     ) -> Expression<'a> {
-        let op = BinaryOperator::StrictInequality;
         let null = ctx.ast.expression_null_literal(SPAN);
+        if self.ctx.assumptions.no_document_all {
+            let test =
+                ctx.ast.expression_binary(SPAN, assignment, BinaryOperator::Inequality, null);
+            return ctx.ast.expression_conditional(SPAN, test, reference, default);
+        }
+
+        let op = BinaryOperator::StrictInequality;
         let left = ctx.ast.expression_binary(SPAN, assignment, op, null);
         let right = ctx.ast.expression_binary(
             SPAN,
@@ -0,0 +1,644 @@
+//! ES2020: Optional Chaining
+//!
+//! This plugin transforms optional chaining (`?.`) to a series of conditional expressions.
+//!
+//! > This plugin is included in `preset-env`, in ES2020
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! const foo = { bar: { baz: () => 1 } };
+//! foo?.bar?.baz?.();
+//! qux?.quux;
+//! ```
+//!
+//! Output:
+//! ```js
+//! var _foo$bar, _foo$bar$baz, _qux;
+//! const foo = { bar: { baz: () => 1 } };
+//! foo === null || foo === void 0 || (_foo$bar = foo.bar) === null || _foo$bar === void 0 ||
+//!   (_foo$bar$baz = _foo$bar.baz) === null || _foo$bar$baz === void 0 ? void 0 : _foo$bar$baz.call(_foo$bar);
+//! (_qux = qux) === null || _qux === void 0 ? void 0 : _qux.quux;
+//! ```
+//!
+//! With `noDocumentAll` assumption, the checks are collapsed to `== null`:
+//! ```js
+//! (_qux = qux) == null ? void 0 : _qux.quux;
+//! ```
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-transform-optional-chaining](https://babeljs.io/docs/babel-plugin-transform-optional-chaining).
+//!
+//! Each optional link in the chain adds a check to a list, and the rest of the chain is rebuilt
+//! without `?.`. The checks are joined with `||` as the test of a conditional expression, which
+//! short-circuits to `void 0`. Objects which are not static are stored in temporary variables,
+//! so they are only evaluated once.
+//!
+//! Optional calls on a member expression (`a.b?.()`) are called with `.call(receiver)`,
+//! so `this` in the callee is still `a`.
+//!
+//! ### Helper
+//!
+//! With [`ES2020Options::optional_chaining_helper_threshold`], chains with at least that many
+//! `?.` are instead lowered to a call of the `optionalChain` helper, the same as Sucrase does.
+//! Each link of the chain is a pair of an operation and an arrow function which applies it,
+//! so no temporary variables are needed:
+//!
+//! ```js
+//! a?.b?.c?.d();
+//! delete a?.b?.c;
+//! // ->
+//! _optionalChain([a, "optionalAccess", _ => _.b, "optionalAccess", _2 => _2.c, "optionalAccess", _3 => _3.d, "call", _4 => _4()]);
+//! _optionalChainDelete([a, "optionalAccess", _ => _.b, "optionalAccess", _2 => delete _2.c]);
+//! ```
+//!
+//! The helper short-circuits with `== null`, so `document.all` is treated as nullish.
+//! Callees in the chain are bound to the object they are accessed on.
+//!
+//! [`ES2020Options::optional_chaining_helper_threshold`]: super::ES2020Options::optional_chaining_helper_threshold
+//!
+//! ## References:
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/main/packages/babel-plugin-transform-optional-chaining>
+//! * Optional chaining TC39 proposal: <https://github.com/tc39/proposal-optional-chaining>
+
+use oxc_allocator::CloneIn;
+use oxc_ast::{ast::*, Visit, NONE};
+use oxc_semantic::{ReferenceFlags, ScopeFlags, ScopeId, SymbolFlags};
+use oxc_span::SPAN;
+use oxc_syntax::operator::{AssignmentOperator, BinaryOperator, LogicalOperator, UnaryOperator};
+use oxc_traverse::{Ancestor, BoundIdentifier, Traverse, TraverseCtx};
+
+use crate::{common::helper_loader::Helper, es2015::ChildScopeCollector, TransformCtx};
+
+pub struct OptionalChaining<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
+    /// Minimum number of `?.` in a chain to lower it with the `optionalChain` helper
+    helper_threshold: Option<usize>,
+}
+
+impl<'a, 'ctx> OptionalChaining<'a, 'ctx> {
+    pub fn new(helper_threshold: Option<usize>, ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self { ctx, helper_threshold }
+    }
+}
+
+/// State collected while lowering a single chain.
+struct Chain<'a> {
+    /// Operands of the short-circuit test, joined with `||` in the output
+    checks: Vec<Expression<'a>>,
+    /// Temporary variables created for the chain
+    temps: Vec<BoundIdentifier<'a>>,
+    is_in_formal_parameters: bool,
+    /// Scope of the arrow function which wraps the chain in formal parameters
+    arrow_scope_id: Option<ScopeId>,
+}
+
+impl<'a> Chain<'a> {
+    /// Scope to create temporary variables in.
+    ///
+    /// In formal parameters, the temporary variables are parameters of an arrow function
+    /// wrapping the chain, which is created here on first use.
+    fn temp_scope_id(&mut self, ctx: &mut TraverseCtx<'a>) -> ScopeId {
+        if !self.is_in_formal_parameters {
+            // Temporary variables are hoisted with `var`
+            return ctx
+                .ancestor_scopes()
+                .find(|&scope_id| ctx.scopes().get_flags(scope_id).is_var())
+                .unwrap();
+        }
+        *self.arrow_scope_id.get_or_insert_with(|| {
+            ctx.create_child_scope_of_current(ScopeFlags::Arrow | ScopeFlags::Function)
+        })
+    }
+
+    /// Check if `expr` is a temporary variable of this chain.
+    fn is_temp(&self, expr: &Expression<'a>, ctx: &TraverseCtx<'a>) -> bool {
+        let Expression::Identifier(ident) = expr else { return false };
+        let symbol_id = ctx.symbols().get_reference(ident.reference_id()).symbol_id();
+        self.temps.iter().any(|temp| Some(temp.symbol_id) == symbol_id)
+    }
+}
+
+impl<'a, 'ctx> Traverse<'a> for OptionalChaining<'a, 'ctx> {
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        match expr {
+            // `a?.b`
+            Expression::ChainExpression(_) => {
+                let Expression::ChainExpression(chain_expr) = ctx.ast.move_expression(expr) else {
+                    unreachable!()
+                };
+                let element = Self::chain_element_into_expression(chain_expr.unbox().expression);
+                *expr = self.transform_chain(element, ChainKind::Value, ctx);
+            }
+            // `delete a?.b`
+            Expression::UnaryExpression(unary_expr)
+                if unary_expr.operator == UnaryOperator::Delete =>
+            {
+                if let Some(element) = Self::take_chain_element(&mut unary_expr.argument, ctx) {
+                    *expr = self.transform_chain(element, ChainKind::Delete, ctx);
+                }
+            }
+            // `(a?.b)()`
+            Expression::CallExpression(call_expr) => {
+                let is_member_chain = matches!(
+                    call_expr.callee.without_parentheses(),
+                    Expression::ChainExpression(chain_expr)
+                        if chain_expr.expression.is_member_expression()
+                );
+                if is_member_chain {
+                    let element = Self::take_chain_element(&mut call_expr.callee, ctx).unwrap();
+                    call_expr.callee = self.transform_chain(element, ChainKind::BoundCallee, ctx);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// How the result of the chain is used.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum ChainKind {
+    /// `a?.b` -> `a === null || a === void 0 ? void 0 : a.b`
+    Value,
+    /// `delete a?.b` -> `a === null || a === void 0 ? true : delete a.b`
+    Delete,
+    /// `(a?.b)()` -> `(a === null || a === void 0 ? void 0 : a.b.bind(a))()`
+    BoundCallee,
+}
+
+impl<'a, 'ctx> OptionalChaining<'a, 'ctx> {
+    fn chain_element_into_expression(element: ChainElement<'a>) -> Expression<'a> {
+        match element {
+            ChainElement::CallExpression(call_expr) => Expression::CallExpression(call_expr),
+            element @ match_member_expression!(ChainElement) => {
+                Expression::from(element.into_member_expression())
+            }
+        }
+    }
+
+    /// Take the chain out of `expr`, if `expr` is a (parenthesized) `ChainExpression`.
+    fn take_chain_element(
+        expr: &mut Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<Expression<'a>> {
+        let mut expr = expr;
+        while let Expression::ParenthesizedExpression(paren_expr) = expr {
+            expr = &mut paren_expr.expression;
+        }
+        if !matches!(expr, Expression::ChainExpression(_)) {
+            return None;
+        }
+        let Expression::ChainExpression(chain_expr) = ctx.ast.move_expression(expr) else {
+            unreachable!()
+        };
+        Some(Self::chain_element_into_expression(chain_expr.unbox().expression))
+    }
+
+    fn transform_chain(
+        &self,
+        expr: Expression<'a>,
+        kind: ChainKind,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        // `(a?.b)()` needs `a` as `this`, which the helper can't provide
+        if kind != ChainKind::BoundCallee
+            && self.helper_threshold.is_some_and(|threshold| {
+                Self::count_optional_links(&expr).is_some_and(|count| count >= threshold)
+            })
+        {
+            return self.transform_chain_with_helper(expr, kind, ctx);
+        }
+
+        let mut chain = Chain {
+            checks: vec![],
+            temps: vec![],
+            is_in_formal_parameters: Self::is_in_formal_parameters(ctx),
+            arrow_scope_id: None,
+        };
+
+        let (expr, alternate) = match kind {
+            ChainKind::Value => {
+                (self.transform_element(expr, &mut chain, ctx), ctx.ast.void_0(SPAN))
+            }
+            ChainKind::Delete => {
+                let expr = self.transform_element(expr, &mut chain, ctx);
+                (
+                    ctx.ast.expression_unary(SPAN, UnaryOperator::Delete, expr),
+                    ctx.ast.expression_boolean_literal(SPAN, true),
+                )
+            }
+            ChainKind::BoundCallee => {
+                (self.transform_bound_callee(expr, &mut chain, ctx), ctx.ast.void_0(SPAN))
+            }
+        };
+
+        let test = chain.checks.into_iter().reduce(|left, right| {
+            ctx.ast.expression_logical(SPAN, left, LogicalOperator::Or, right)
+        });
+        let new_expr = match test {
+            Some(test) => ctx.ast.expression_conditional(SPAN, test, alternate, expr),
+            None => expr,
+        };
+
+        if let Some(scope_id) = chain.arrow_scope_id {
+            // Replace `function (x = a?.b) {}` with `function (x = ((_a) => ...)()) {}`
+            // so the temporary variables are declared in correct scope
+            Self::wrap_with_arrow_function(new_expr, &chain.temps, scope_id, ctx)
+        } else {
+            for temp in &chain.temps {
+                self.ctx.var_declarations.insert_var(temp, None, ctx);
+            }
+            new_expr
+        }
+    }
+
+    /// Number of `?.` in the chain, or `None` if the chain can't be lowered with the helper.
+    fn count_optional_links(mut expr: &Expression<'a>) -> Option<usize> {
+        let mut count = 0;
+        let mut is_callee = false;
+        loop {
+            let (object, optional) = match expr {
+                expr if !Self::is_chain_link(expr) => {
+                    // `(a.b)?.()` needs `a` as `this`, which the helper can't provide
+                    if is_callee && expr.without_parentheses().is_member_expression() {
+                        return None;
+                    }
+                    return Some(count);
+                }
+                Expression::CallExpression(e) => (&e.callee, e.optional),
+                Expression::TSNonNullExpression(e) => (&e.expression, false),
+                expr => {
+                    let member_expr = expr.to_member_expression();
+                    (member_expr.object(), member_expr.optional())
+                }
+            };
+            count += usize::from(optional);
+            is_callee = matches!(expr, Expression::CallExpression(_));
+            expr = object;
+        }
+    }
+
+    /// Check if `expr` is a link of a chain, which is lowered to an operation of the helper.
+    ///
+    /// `super.a` is not a link, as `super` can't be passed to the helper.
+    fn is_chain_link(expr: &Expression<'a>) -> bool {
+        match expr {
+            Expression::CallExpression(_) | Expression::TSNonNullExpression(_) => true,
+            match_member_expression!(Expression) => {
+                !matches!(expr.to_member_expression().object(), Expression::Super(_))
+            }
+            _ => false,
+        }
+    }
+
+    /// `a?.b.c()` -> `_optionalChain([a, "optionalAccess", _ => _.b, "access", _2 => _2.c, "call", _3 => _3()])`
+    fn transform_chain_with_helper(
+        &self,
+        expr: Expression<'a>,
+        kind: ChainKind,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let mut links = vec![];
+        let object = Self::flatten_chain(expr, &mut links, ctx);
+
+        let mut elements = ctx.ast.vec_with_capacity(links.len() * 2 + 1);
+        elements.push(ArrayExpressionElement::from(object));
+        let last_index = links.len() - 1;
+        for (index, (optional, mut link)) in links.into_iter().enumerate() {
+            let operation = match (&link, optional) {
+                (Expression::CallExpression(_), false) => "call",
+                (Expression::CallExpression(_), true) => "optionalCall",
+                (_, false) => "access",
+                (_, true) => "optionalAccess",
+            };
+            elements.push(ArrayExpressionElement::from(
+                ctx.ast.expression_string_literal(SPAN, operation),
+            ));
+
+            // Scopes in computed properties and arguments are moved into the arrow function
+            let scope_id =
+                ctx.create_child_scope_of_current(ScopeFlags::Arrow | ScopeFlags::Function);
+            let mut collector = ChildScopeCollector::default();
+            collector.visit_expression(&link);
+            collector.move_to(scope_id, ctx);
+
+            let binding = ctx.generate_uid("", scope_id, SymbolFlags::FunctionScopedVariable);
+            match &mut link {
+                Expression::CallExpression(call_expr) => {
+                    call_expr.callee = binding.create_read_expression(ctx);
+                    call_expr.optional = false;
+                }
+                link => {
+                    let member_expr = link.to_member_expression_mut();
+                    *member_expr.object_mut() = binding.create_read_expression(ctx);
+                    Self::clear_optional(member_expr);
+                }
+            }
+            if kind == ChainKind::Delete && index == last_index {
+                link = ctx.ast.expression_unary(SPAN, UnaryOperator::Delete, link);
+            }
+            let arrow_function = Self::create_arrow_function(link, &[binding], scope_id, ctx);
+            elements.push(ArrayExpressionElement::from(arrow_function));
+        }
+
+        let helper = if kind == ChainKind::Delete {
+            Helper::OptionalChainDelete
+        } else {
+            Helper::OptionalChain
+        };
+        let array = ctx.ast.expression_array(SPAN, elements, None);
+        self.ctx.helper_call_expr(helper, ctx.ast.vec1(Argument::from(array)), ctx)
+    }
+
+    /// Split a chain into its innermost object and its links, which are member or call expressions
+    /// with their object or callee taken out, along with whether they are optional.
+    fn flatten_chain(
+        mut expr: Expression<'a>,
+        links: &mut Vec<(bool, Expression<'a>)>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        if !Self::is_chain_link(&expr) {
+            return expr;
+        }
+        let (object, optional) = match &mut expr {
+            Expression::CallExpression(call_expr) => {
+                (ctx.ast.move_expression(&mut call_expr.callee), call_expr.optional)
+            }
+            Expression::TSNonNullExpression(non_null_expr) => {
+                let expression = ctx.ast.move_expression(&mut non_null_expr.expression);
+                return Self::flatten_chain(expression, links, ctx);
+            }
+            expr => {
+                let member_expr = expr.to_member_expression_mut();
+                (ctx.ast.move_expression(member_expr.object_mut()), member_expr.optional())
+            }
+        };
+        let object = Self::flatten_chain(object, links, ctx);
+        links.push((optional, expr));
+        object
+    }
+
+    /// Remove `?.` from `expr` and its objects, adding a check to `chain` for each of them.
+    fn transform_element(
+        &self,
+        expr: Expression<'a>,
+        chain: &mut Chain<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        match expr {
+            Expression::StaticMemberExpression(mut member_expr) => {
+                let object = ctx.ast.move_expression(&mut member_expr.object);
+                member_expr.object =
+                    self.transform_object(object, member_expr.optional, chain, ctx);
+                member_expr.optional = false;
+                Expression::StaticMemberExpression(member_expr)
+            }
+            Expression::ComputedMemberExpression(mut member_expr) => {
+                let object = ctx.ast.move_expression(&mut member_expr.object);
+                member_expr.object =
+                    self.transform_object(object, member_expr.optional, chain, ctx);
+                member_expr.optional = false;
+                Expression::ComputedMemberExpression(member_expr)
+            }
+            Expression::PrivateFieldExpression(mut member_expr) => {
+                let object = ctx.ast.move_expression(&mut member_expr.object);
+                member_expr.object =
+                    self.transform_object(object, member_expr.optional, chain, ctx);
+                member_expr.optional = false;
+                Expression::PrivateFieldExpression(member_expr)
+            }
+            Expression::CallExpression(mut call_expr) => {
+                let callee = ctx.ast.move_expression(&mut call_expr.callee);
+                if call_expr.optional && callee.is_member_expression() {
+                    // `a.b?.()` -> `(_a$b = (_a = a).b) === null || _a$b === void 0 ? void 0 : _a$b.call(_a)`
+                    let mut member_expr = callee.into_member_expression();
+                    let object = ctx.ast.move_expression(member_expr.object_mut());
+                    let object = self.transform_object(object, member_expr.optional(), chain, ctx);
+                    let (object, receiver) = Self::memoize_receiver(object, chain, ctx);
+                    *member_expr.object_mut() = object;
+                    Self::clear_optional(&mut member_expr);
+                    let callee = self.add_check(Expression::from(member_expr), chain, ctx);
+                    let property = ctx.ast.identifier_name(SPAN, "call");
+                    call_expr.callee = Expression::from(
+                        ctx.ast.member_expression_static(SPAN, callee, property, false),
+                    );
+                    call_expr.arguments.insert(0, Argument::from(receiver));
+                } else {
+                    call_expr.callee =
+                        self.transform_object(callee, call_expr.optional, chain, ctx);
+                }
+                call_expr.optional = false;
+                Expression::CallExpression(call_expr)
+            }
+            Expression::TSNonNullExpression(non_null_expr) => {
+                self.transform_element(non_null_expr.unbox().expression, chain, ctx)
+            }
+            expr => expr,
+        }
+    }
+
+    /// Transform the object of a member expression or the callee of a call expression,
+    /// checking it for `null` / `undefined` if it is followed by `?.`.
+    fn transform_object(
+        &self,
+        object: Expression<'a>,
+        optional: bool,
+        chain: &mut Chain<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let object = self.transform_element(object, chain, ctx);
+        if optional {
+            self.add_check(object, chain, ctx)
+        } else {
+            object
+        }
+    }
+
+    /// `(a?.b)` as a callee -> `a.b.bind(a)`, so the callee is still called with `a` as `this`.
+    fn transform_bound_callee(
+        &self,
+        expr: Expression<'a>,
+        chain: &mut Chain<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let mut member_expr = expr.into_member_expression();
+        let object = ctx.ast.move_expression(member_expr.object_mut());
+        let object = self.transform_object(object, member_expr.optional(), chain, ctx);
+        let (object, receiver) = Self::memoize_receiver(object, chain, ctx);
+        *member_expr.object_mut() = object;
+        Self::clear_optional(&mut member_expr);
+        let property = ctx.ast.identifier_name(SPAN, "bind");
+        let callee =
+            ctx.ast.member_expression_static(SPAN, Expression::from(member_expr), property, false);
+        let arguments = ctx.ast.vec1(Argument::from(receiver));
+        ctx.ast.expression_call(SPAN, Expression::from(callee), NONE, arguments, false, false)
+    }
+
+    /// Add a check of `expr` for `null` / `undefined` to `chain`.
+    ///
+    /// Returns an expression to read the checked value again.
+    fn add_check(
+        &self,
+        expr: Expression<'a>,
+        chain: &mut Chain<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        if let Some(binding) = Self::memoize(&expr, chain, ctx) {
+            // `(_a = a) === null || _a === void 0`
+            let assignment = ctx.ast.expression_assignment(
+                SPAN,
+                AssignmentOperator::Assign,
+                binding.create_read_write_target(ctx),
+                expr,
+            );
+            let reference = binding.create_read_expression(ctx);
+            self.push_null_check(assignment, &reference, chain, ctx);
+            reference
+        } else {
+            // `a === null || a === void 0`
+            self.push_null_check(Self::clone_expression(&expr, ctx), &expr, chain, ctx);
+            expr
+        }
+    }
+
+    /// Store `object` in a temporary variable if it's not static, so it can be used as `this`.
+    ///
+    /// Returns the object to use in the member expression, and the receiver.
+    fn memoize_receiver(
+        object: Expression<'a>,
+        chain: &mut Chain<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> (Expression<'a>, Expression<'a>) {
+        // `super.a?.()` -> `super.a.call(this)`
+        if matches!(object, Expression::Super(_)) {
+            return (object, ctx.ast.expression_this(SPAN));
+        }
+        if let Some(binding) = Self::memoize(&object, chain, ctx) {
+            let assignment = ctx.ast.expression_assignment(
+                SPAN,
+                AssignmentOperator::Assign,
+                binding.create_read_write_target(ctx),
+                object,
+            );
+            (assignment, binding.create_read_expression(ctx))
+        } else {
+            let receiver = Self::clone_expression(&object, ctx);
+            (object, receiver)
+        }
+    }
+
+    /// Create a temporary variable for `expr`, unless it can be evaluated again without side effects.
+    fn memoize(
+        expr: &Expression<'a>,
+        chain: &mut Chain<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<BoundIdentifier<'a>> {
+        if ctx.is_static(expr) || chain.is_temp(expr, ctx) {
+            return None;
+        }
+        let scope_id = chain.temp_scope_id(ctx);
+        let binding =
+            ctx.generate_uid_based_on_node(expr, scope_id, SymbolFlags::FunctionScopedVariable);
+        chain.temps.push(binding.clone());
+        Some(binding)
+    }
+
+    /// Add `left === null || reference === void 0` to the checks of `chain`,
+    /// or `left == null` when `noDocumentAll` assumption is enabled.
+    fn push_null_check(
+        &self,
+        left: Expression<'a>,
+        reference: &Expression<'a>,
+        chain: &mut Chain<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let null = ctx.ast.expression_null_literal(SPAN);
+        if self.ctx.assumptions.no_document_all {
+            chain.checks.push(ctx.ast.expression_binary(
+                SPAN,
+                left,
+                BinaryOperator::Equality,
+                null,
+            ));
+            return;
+        }
+        let op = BinaryOperator::StrictEquality;
+        chain.checks.push(ctx.ast.expression_binary(SPAN, left, op, null));
+        let reference = Self::clone_expression(reference, ctx);
+        chain.checks.push(ctx.ast.expression_binary(SPAN, reference, op, ctx.ast.void_0(SPAN)));
+    }
+
+    fn clear_optional(member_expr: &mut MemberExpression<'a>) {
+        match member_expr {
+            MemberExpression::ComputedMemberExpression(e) => e.optional = false,
+            MemberExpression::StaticMemberExpression(e) => e.optional = false,
+            MemberExpression::PrivateFieldExpression(e) => e.optional = false,
+        }
+    }
+
+    fn clone_expression(expr: &Expression<'a>, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        match expr {
+            Expression::Identifier(ident) => Expression::Identifier(
+                ctx.ast.alloc(ctx.clone_identifier_reference(ident, ReferenceFlags::Read)),
+            ),
+            _ => expr.clone_in(ctx.ast.allocator),
+        }
+    }
+
+    /// Check if the chain is in formal parameters of the closest function.
+    fn is_in_formal_parameters(ctx: &TraverseCtx<'a>) -> bool {
+        for ancestor in ctx.ancestors() {
+            match ancestor {
+                Ancestor::FormalParameterPattern(_) | Ancestor::FormalParametersRest(_) => {
+                    return true;
+                }
+                Ancestor::FunctionBody(_)
+                | Ancestor::ArrowFunctionExpressionBody(_)
+                | Ancestor::StaticBlockBody(_)
+                | Ancestor::PropertyDefinitionValue(_) => return false,
+                _ => {}
+            }
+        }
+        false
+    }
+
+    /// `expr` -> `((_a, _b) => expr)()`
+    fn wrap_with_arrow_function(
+        expr: Expression<'a>,
+        temps: &[BoundIdentifier<'a>],
+        scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let arrow_function = Self::create_arrow_function(expr, temps, scope_id, ctx);
+        ctx.ast.expression_call(SPAN, arrow_function, NONE, ctx.ast.vec(), false, false)
+    }
+
+    /// `(_a, _b) => expr`
+    fn create_arrow_function(
+        expr: Expression<'a>,
+        params: &[BoundIdentifier<'a>],
+        scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let items = ctx.ast.vec_from_iter(params.iter().map(|param| {
+            let id = param.create_binding_pattern(ctx);
+            ctx.ast.formal_parameter(SPAN, ctx.ast.vec(), id, None, false, false)
+        }));
+        let params = ctx.ast.formal_parameters(
+            SPAN,
+            FormalParameterKind::ArrowFormalParameters,
+            items,
+            NONE,
+        );
+        let body = ctx.ast.function_body(
+            SPAN,
+            ctx.ast.vec(),
+            ctx.ast.vec1(ctx.ast.statement_expression(SPAN, expr)),
+        );
+        Expression::ArrowFunctionExpression(ctx.ast.alloc_arrow_function_expression_with_scope_id(
+            SPAN, true, false, NONE, params, NONE, body, scope_id,
+        ))
+    }
+}
@@ -6,6 +6,20 @@ pub struct ES2020Options {
     #[serde(skip)]
     pub nullish_coalescing_operator: bool,
 
+    #[serde(skip)]
+    pub optional_chaining: bool,
+
     #[serde(skip)]
     pub big_int: bool,
+
+    /// Lower optional chains with at least this many `?.` to a call of the `optionalChain` helper,
+    /// instead of conditional expressions with temporary variables, to reduce the size of the output
+    /// for deeply nested chains. `None` (the default) always lowers chains inline.
+    ///
+    /// The `optionalChain` and `optionalChainDelete` helpers are not part of `@babel/runtime`.
+    /// They must be provided with [`HelperLoaderOptions::resolve_source`] or as `babelHelpers`.
+    ///
+    /// [`HelperLoaderOptions::resolve_source`]: crate::HelperLoaderOptions::resolve_source
+    #[serde(skip)]
+    pub optional_chaining_helper_threshold: Option<usize>,
 }
@@ -89,7 +89,6 @@ impl<'a, 'ctx> Traverse<'a> for LogicalAssignmentOperators<'a, 'ctx> {
         //               ^     ^ assign_target
         //               ^ left_expr
 
-        // TODO: Add tests, cover private identifier
         let (left_expr, assign_target) = match &mut assignment_expr.left {
             // `a &&= c` -> `a && (a = c)`
             AssignmentTarget::AssignmentTargetIdentifier(ident) => {
@@ -104,9 +103,10 @@ impl<'a, 'ctx> Traverse<'a> for LogicalAssignmentOperators<'a, 'ctx> {
             AssignmentTarget::ComputedMemberExpression(computed_expr) => {
                 self.convert_computed_member_expression(computed_expr, ctx)
             }
-            // TODO
-            #[allow(clippy::match_same_arms)]
-            AssignmentTarget::PrivateFieldExpression(_) => return,
+            // `a.#b &&= c` -> `var _a; (_a = a).#b && (_a.#b = c)`
+            AssignmentTarget::PrivateFieldExpression(private_expr) => {
+                self.convert_private_field_expression(private_expr, ctx)
+            }
             // All other are TypeScript syntax.
 
             // It is a Syntax Error if AssignmentTargetType of LeftHandSideExpression is not simple.
@@ -199,6 +199,43 @@ impl<'a, 'ctx> LogicalAssignmentOperators<'a, 'ctx> {
         }
     }
 
+    fn convert_private_field_expression(
+        &mut self,
+        private_expr: &mut PrivateFieldExpression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> (Expression<'a>, AssignmentTarget<'a>) {
+        let object = ctx.ast.move_expression(&mut private_expr.object);
+        let field = &private_expr.field;
+
+        let (left_object, assign_object) =
+            if let Some(ident) = self.maybe_generate_memoised(&object, ctx) {
+                // (_o = o).#a || (_o.#a = 1)
+                let target = ident.create_read_write_target(ctx);
+                let left_object =
+                    ctx.ast.expression_assignment(SPAN, AssignmentOperator::Assign, target, object);
+                (left_object, ident.create_read_expression(ctx))
+            } else {
+                // this.#a || (this.#a = 1)
+                (Self::clone_expression(&object, ctx), object)
+            };
+
+        let left_expr = Expression::from(ctx.ast.member_expression_private_field_expression(
+            SPAN,
+            left_object,
+            field.clone(),
+            false,
+        ));
+        let assign_target =
+            AssignmentTarget::from(ctx.ast.member_expression_private_field_expression(
+                SPAN,
+                assign_object,
+                field.clone(),
+                false,
+            ));
+
+        (left_expr, assign_target)
+    }
+
     fn convert_computed_member_expression(
         &mut self,
         computed_expr: &mut ComputedMemberExpression<'a>,
@@ -315,8 +352,13 @@ impl<'a, 'ctx> LogicalAssignmentOperators<'a, 'ctx> {
         }
 
         // var _name;
-        let binding = ctx
-            .generate_uid_in_current_scope_based_on_node(expr, SymbolFlags::FunctionScopedVariable);
+        // Temporary variables are hoisted with `var`, so bind them in the nearest var scope
+        let scope_id = ctx
+            .ancestor_scopes()
+            .find(|&scope_id| ctx.scopes().get_flags(scope_id).is_var())
+            .unwrap();
+        let binding =
+            ctx.generate_uid_based_on_node(expr, scope_id, SymbolFlags::FunctionScopedVariable);
         self.ctx.var_declarations.insert_var(&binding, None, ctx);
 
         Some(binding)
@@ -0,0 +1,72 @@
+//! ES2021: Numeric Separator
+//!
+//! This plugin removes numeric separators (`_`) from numeric and bigint literals.
+//!
+//! > This plugin is included in `preset-env`, in ES2021
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! 1_000_000;
+//! 0x_FF_FF;
+//! 1_0e1_0;
+//! 1_000n;
+//! ```
+//!
+//! Output:
+//! ```js
+//! 1000000;
+//! 0xFFFF;
+//! 10e10;
+//! 1000n;
+//! ```
+//!
+//! Only `raw` is rewritten. `value` of `NumericLiteral` does not contain separators.
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-transform-numeric-separator](https://babel.dev/docs/babel-plugin-transform-numeric-separator).
+//!
+//! ## References:
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/main/packages/babel-plugin-transform-numeric-separator>
+//! * Numeric separators TC39 proposal: <https://github.com/tc39/proposal-numeric-separator>
+
+use std::borrow::Cow;
+
+use cow_utils::CowUtils;
+
+use oxc_ast::ast::*;
+use oxc_traverse::{Traverse, TraverseCtx};
+
+pub struct NumericSeparator;
+
+impl NumericSeparator {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl<'a> Traverse<'a> for NumericSeparator {
+    fn enter_numeric_literal(&mut self, lit: &mut NumericLiteral<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(raw) = Self::remove_separators(lit.raw, ctx) {
+            lit.raw = raw;
+        }
+    }
+
+    fn enter_big_int_literal(&mut self, lit: &mut BigIntLiteral<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(raw) = Self::remove_separators(lit.raw.as_str(), ctx) {
+            lit.raw = Atom::from(raw);
+        }
+    }
+}
+
+impl NumericSeparator {
+    /// Remove `_` from `raw`, or return `None` if it contains none.
+    fn remove_separators<'a>(raw: &str, ctx: &TraverseCtx<'a>) -> Option<&'a str> {
+        match raw.cow_replace('_', "") {
+            Cow::Borrowed(_) => None,
+            Cow::Owned(raw) => Some(ctx.ast.str(&raw)),
+        }
+    }
+}
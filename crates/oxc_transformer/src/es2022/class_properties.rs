@@ -53,6 +53,21 @@
 //!
 //! WORK IN PROGRESS. INCOMPLETE.
 //!
+//! Only public instance fields are transformed. Static fields and private fields are left as is.
+//!
+//! Fields are initialized in the constructor, after `super()` in a derived class.
+//! By default, a field is defined with `defineProperty` (`[[Define]]` semantics), so it is always
+//! an own property of the instance, even if a class in the prototype chain has an accessor
+//! with the same name. With `setPublicClassFields` assumption (or `loose` option),
+//! a field is assigned with `this.x = value` (`[[Set]]` semantics) instead, which calls
+//! an inherited setter. This matches TypeScript's `useDefineForClassFields: false`.
+//!
+//! A class is left untransformed if:
+//! * It has instance private fields, `accessor` properties, decorated fields, or fields with
+//!   computed keys, which would have to be initialized in order with the public fields.
+//! * A field initializer refers to a binding which the constructor would shadow.
+//! * It is a derived class whose constructor does not call `super()` at top level of its body.
+//!
 //! Implementation based on [@babel/plugin-transform-class-properties](https://babel.dev/docs/babel-plugin-transform-class-properties).
 //!
 //! ## References:
@@ -64,10 +79,16 @@
 
 use serde::Deserialize;
 
-use oxc_ast::ast::*;
+use oxc_ast::{ast::*, Visit};
+use oxc_span::SPAN;
+use oxc_syntax::operator::AssignmentOperator;
 use oxc_traverse::{Traverse, TraverseCtx};
 
-use crate::TransformCtx;
+use crate::{common::helper_loader::Helper, TransformCtx};
+
+use super::private_methods::{
+    find_super_call_statement, is_derived_class, AccessorScanner, PrivateMethods,
+};
 
 #[derive(Debug, Default, Clone, Copy, Deserialize)]
 #[serde(default, rename_all = "camelCase")]
@@ -77,18 +98,192 @@ pub struct ClassPropertiesOptions {
 }
 
 pub struct ClassProperties<'a, 'ctx> {
-    #[expect(dead_code)]
-    options: ClassPropertiesOptions,
-    #[expect(dead_code)]
     ctx: &'ctx TransformCtx<'a>,
+    /// Assign fields with `this.x = value`, instead of defining them
+    set_public_class_fields: bool,
 }
 
 impl<'a, 'ctx> ClassProperties<'a, 'ctx> {
     pub fn new(options: ClassPropertiesOptions, ctx: &'ctx TransformCtx<'a>) -> Self {
-        Self { options, ctx }
+        let set_public_class_fields =
+            options.set_public_class_fields || ctx.assumptions.set_public_class_fields;
+        Self { ctx, set_public_class_fields }
     }
 }
 
 impl<'a, 'ctx> Traverse<'a> for ClassProperties<'a, 'ctx> {
-    fn enter_class_body(&mut self, _body: &mut ClassBody<'a>, _ctx: &mut TraverseCtx<'a>) {}
+    fn exit_class_body(&mut self, body: &mut ClassBody<'a>, ctx: &mut TraverseCtx<'a>) {
+        if Self::can_transform(body, ctx) {
+            self.transform_class_body(body, ctx);
+        }
+    }
+}
+
+impl<'a, 'ctx> ClassProperties<'a, 'ctx> {
+    /// Check if instance fields of a class can be transformed.
+    fn can_transform(body: &ClassBody<'a>, ctx: &TraverseCtx<'a>) -> bool {
+        let mut has_fields = false;
+        let mut constructor = None;
+        for element in &body.body {
+            match element {
+                ClassElement::PropertyDefinition(prop) if !prop.r#static => {
+                    if prop.computed
+                        || prop.key.is_private_identifier()
+                        || !prop.decorators.is_empty()
+                    {
+                        return false;
+                    }
+                    has_fields = true;
+                }
+                ClassElement::AccessorProperty(prop) if !prop.r#static => return false,
+                ClassElement::MethodDefinition(method)
+                    if method.kind == MethodDefinitionKind::Constructor =>
+                {
+                    constructor = Some(&method.value);
+                }
+                _ => {}
+            }
+        }
+        if !has_fields {
+            return false;
+        }
+
+        let Some(constructor) = constructor else { return true };
+
+        // Fields are initialized after `super()` in constructor of a derived class.
+        // Bail if it's not possible to find where that is.
+        if is_derived_class(ctx)
+            && constructor
+                .body
+                .as_ref()
+                .and_then(|body| find_super_call_statement(&body.statements))
+                .is_none()
+        {
+            return false;
+        }
+
+        // Initializers are moved into the constructor, so must not refer to any bindings
+        // which have same name as bindings in the constructor
+        let constructor_scope_id = constructor.scope_id();
+        body.body.iter().all(|element| {
+            let ClassElement::PropertyDefinition(prop) = element else { return true };
+            let Some(value) = prop.value.as_ref().filter(|_| !prop.r#static) else { return true };
+            let mut scanner = AccessorScanner::new(ctx.symbols());
+            scanner.visit_expression(value);
+            !scanner
+                .outer_references
+                .iter()
+                .any(|name| ctx.scopes().get_binding(constructor_scope_id, name).is_some())
+        })
+    }
+
+    /// Remove instance fields from class body, and initialize them in the constructor.
+    fn transform_class_body(&self, body: &mut ClassBody<'a>, ctx: &mut TraverseCtx<'a>) {
+        // Remove fields from class body
+        let mut fields = vec![];
+        let elements = ctx.ast.move_vec(&mut body.body);
+        body.body.reserve(elements.len());
+        for element in elements {
+            match element {
+                ClassElement::PropertyDefinition(prop) if !prop.r#static => {
+                    let prop = prop.unbox();
+                    fields.push((prop.key, prop.value));
+                }
+                element => body.body.push(element),
+            }
+        }
+
+        // Find or create constructor
+        let constructor_index = body.body.iter().position(|element| {
+            matches!(element, ClassElement::MethodDefinition(method) if method.kind == MethodDefinitionKind::Constructor)
+        });
+        let constructor_index = constructor_index.unwrap_or_else(|| {
+            body.body.insert(0, PrivateMethods::create_constructor(is_derived_class(ctx), ctx));
+            0
+        });
+        let ClassElement::MethodDefinition(constructor) =
+            body.body.get_mut(constructor_index).unwrap()
+        else {
+            unreachable!()
+        };
+        let constructor = &mut constructor.value;
+        let constructor_scope_id = constructor.scope_id();
+
+        let mut statements = Vec::with_capacity(fields.len());
+        for (key, value) in fields {
+            let value = match value {
+                Some(value) => {
+                    // Scopes in the initializer are now inside the constructor
+                    let scope_ids = {
+                        let mut scanner = AccessorScanner::new(ctx.symbols());
+                        scanner.visit_expression(&value);
+                        scanner.scope_ids
+                    };
+                    for &scope_id in &scope_ids {
+                        let is_top_level = ctx
+                            .scopes()
+                            .get_parent_id(scope_id)
+                            .map_or(true, |parent_id| !scope_ids.contains(&parent_id));
+                        if is_top_level {
+                            ctx.scopes_mut().change_parent_id(scope_id, Some(constructor_scope_id));
+                        }
+                    }
+                    value
+                }
+                None => ctx.ast.void_0(SPAN),
+            };
+            let expr = if self.set_public_class_fields {
+                Self::create_assignment(key, value, ctx)
+            } else {
+                self.create_define_property(key, value, ctx)
+            };
+            statements.push(ctx.ast.statement_expression(SPAN, expr));
+        }
+
+        let body = constructor.body.as_mut().unwrap();
+        let index = find_super_call_statement(&body.statements).map_or(0, |index| index + 1);
+        body.statements.splice(index..index, statements);
+    }
+
+    /// `this.x = value` or `this["x"] = value`
+    fn create_assignment(
+        key: PropertyKey<'a>,
+        value: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let object = ctx.ast.expression_this(SPAN);
+        let target = match key {
+            PropertyKey::StaticIdentifier(ident) => {
+                ctx.ast.member_expression_static(SPAN, object, ident.unbox(), false)
+            }
+            key => ctx.ast.member_expression_computed(SPAN, object, key.into_expression(), false),
+        };
+        ctx.ast.expression_assignment(
+            SPAN,
+            AssignmentOperator::Assign,
+            AssignmentTarget::from(SimpleAssignmentTarget::from(target)),
+            value,
+        )
+    }
+
+    /// `_defineProperty(this, "x", value)`
+    fn create_define_property(
+        &self,
+        key: PropertyKey<'a>,
+        value: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let key = match key {
+            PropertyKey::StaticIdentifier(ident) => {
+                ctx.ast.expression_string_literal(ident.span, ident.name.clone())
+            }
+            key => key.into_expression(),
+        };
+        let arguments = ctx.ast.vec_from_iter([
+            Argument::from(ctx.ast.expression_this(SPAN)),
+            Argument::from(key),
+            Argument::from(value),
+        ]);
+        self.ctx.helper_call_expr(Helper::DefineProperty, arguments, ctx)
+    }
 }
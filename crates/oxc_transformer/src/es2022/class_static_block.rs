@@ -31,6 +31,16 @@
 //! }
 //! ```
 //!
+//! Static blocks are replaced in place, so they keep their position relative to other static members,
+//! and run in the same order as before. Being inside the class body, they can still access private
+//! static members, e.g. `static { foo(C.#x) }` -> `static #_ = foo(C.#x);`.
+//! `this` in a field initializer is the class, same as in a static block, and the IIFE is an arrow
+//! function, so `this` is unchanged.
+//!
+//! `await` is not allowed in static blocks, but parser does not reject it when the class is inside
+//! an async function. Such blocks would produce a field initializer containing `await`,
+//! so they are reported as an error and left unchanged.
+//!
 //! ## Implementation
 //!
 //! Implementation based on [@babel/plugin-transform-class-static-block](https://babel.dev/docs/babel-plugin-transform-class-static-block).
@@ -42,24 +52,29 @@
 use itoa::Buffer as ItoaBuffer;
 
 use oxc_allocator::String as ArenaString;
-use oxc_ast::{ast::*, Visit, NONE};
+use oxc_ast::{ast::*, visit::walk, Visit, NONE};
+use oxc_diagnostics::OxcDiagnostic;
 use oxc_semantic::SymbolTable;
-use oxc_span::SPAN;
+use oxc_span::{Span, SPAN};
 use oxc_syntax::{
     reference::ReferenceFlags,
     scope::{ScopeFlags, ScopeId},
 };
 use oxc_traverse::{Traverse, TraverseCtx};
 
-pub struct ClassStaticBlock;
+use crate::TransformCtx;
+
+pub struct ClassStaticBlock<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
+}
 
-impl ClassStaticBlock {
-    pub fn new() -> Self {
-        Self
+impl<'a, 'ctx> ClassStaticBlock<'a, 'ctx> {
+    pub fn new(ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self { ctx }
     }
 }
 
-impl<'a> Traverse<'a> for ClassStaticBlock {
+impl<'a, 'ctx> Traverse<'a> for ClassStaticBlock<'a, 'ctx> {
     fn enter_class_body(&mut self, body: &mut ClassBody<'a>, ctx: &mut TraverseCtx<'a>) {
         // Loop through class body elements and:
         // 1. Find if there are any `StaticBlock`s.
@@ -93,17 +108,21 @@ impl<'a> Traverse<'a> for ClassStaticBlock {
 
         for element in body.body.iter_mut() {
             if let ClassElement::StaticBlock(block) = element {
+                if let Some(span) = AwaitFinder::find(block) {
+                    self.ctx.error(static_block_await(span));
+                    continue;
+                }
                 *element = Self::convert_block_to_private_field(block, &mut keys, ctx);
             }
         }
     }
 }
 
-impl ClassStaticBlock {
+impl<'a, 'ctx> ClassStaticBlock<'a, 'ctx> {
     /// Convert static block to private field.
     /// `static { foo }` -> `static #_ = foo;`
     /// `static { foo; bar; }` -> `static #_ = (() => { foo; bar; })();`
-    fn convert_block_to_private_field<'a>(
+    fn convert_block_to_private_field(
         block: &mut StaticBlock<'a>,
         keys: &mut Keys<'a>,
         ctx: &mut TraverseCtx<'a>,
@@ -134,7 +153,7 @@ impl ClassStaticBlock {
     /// Convert static block to expression which will be value of private field.
     /// `static { foo }` -> `foo`
     /// `static { foo; bar; }` -> `(() => { foo; bar; })()`
-    fn convert_block_to_expression<'a>(
+    fn convert_block_to_expression(
         block: &mut StaticBlock<'a>,
         ctx: &mut TraverseCtx<'a>,
     ) -> Expression<'a> {
@@ -175,13 +194,13 @@ impl ClassStaticBlock {
                 SPAN, false, false, NONE, params, NONE, body, scope_id,
             ),
         );
-        ctx.ast.expression_call(SPAN, arrow, NONE, ctx.ast.vec(), false)
+        ctx.ast.expression_call(SPAN, arrow, NONE, ctx.ast.vec(), false, false)
     }
 
     /// Convert static block to expression which will be value of private field,
     /// where the static block contains only a single expression.
     /// `static { foo }` -> `foo`
-    fn convert_block_with_single_expression_to_expression<'a>(
+    fn convert_block_with_single_expression_to_expression(
         expr: &mut Expression<'a>,
         scope_id: ScopeId,
         ctx: &mut TraverseCtx<'a>,
@@ -205,6 +224,52 @@ impl ClassStaticBlock {
     }
 }
 
+fn static_block_await(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error("Cannot use await in class static initialization block").with_label(span)
+}
+
+/// Visitor which finds an `await` belonging to a static block.
+/// It skips functions and nested static blocks, as `await` in them does not belong to this block.
+#[derive(Default)]
+struct AwaitFinder {
+    span: Option<Span>,
+}
+
+impl AwaitFinder {
+    fn find(block: &StaticBlock) -> Option<Span> {
+        let mut finder = Self::default();
+        finder.visit_statements(&block.body);
+        finder.span
+    }
+}
+
+impl<'a> Visit<'a> for AwaitFinder {
+    fn visit_await_expression(&mut self, expr: &AwaitExpression<'a>) {
+        if self.span.is_none() {
+            self.span = Some(expr.span);
+        }
+    }
+
+    fn visit_for_of_statement(&mut self, stmt: &ForOfStatement<'a>) {
+        if stmt.r#await && self.span.is_none() {
+            self.span = Some(stmt.span);
+        }
+        walk::walk_for_of_statement(self, stmt);
+    }
+
+    fn visit_function(&mut self, _func: &Function<'a>, _flags: ScopeFlags) {
+        // Don't traverse further
+    }
+
+    fn visit_arrow_function_expression(&mut self, _arrow: &ArrowFunctionExpression<'a>) {
+        // Don't traverse further
+    }
+
+    fn visit_static_block(&mut self, _block: &StaticBlock<'a>) {
+        // Don't traverse further
+    }
+}
+
 /// Visitor which sets `ReferenceFlags::Read` flag on all `IdentifierReference`s.
 /// It skips `MemberExpression`s, because their flags are not affected by the change in position.
 struct ReferenceFlagsSetter<'s> {
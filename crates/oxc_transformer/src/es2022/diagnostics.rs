@@ -0,0 +1,20 @@
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_span::Span;
+
+pub fn private_field_undeclared(name: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error(format!("Private field '#{name}' must be declared in an enclosing class"))
+        .with_label(span)
+}
+
+pub fn super_private(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error("Private fields cannot be accessed on super").with_label(span)
+}
+
+pub fn delete_private_field(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error("Private fields can not be deleted").with_label(span)
+}
+
+pub fn private_accessors_not_transformed(reason: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Private accessors of this class are not transformed: {reason}"))
+        .with_label(span)
+}
@@ -5,29 +5,34 @@ use crate::TransformCtx;
 
 mod class_properties;
 mod class_static_block;
+mod diagnostics;
 mod options;
+mod private_methods;
 
 use class_properties::ClassProperties;
 pub use class_properties::ClassPropertiesOptions;
 use class_static_block::ClassStaticBlock;
+use private_methods::PrivateMethods;
 
 pub use options::ES2022Options;
 
 pub struct ES2022<'a, 'ctx> {
     options: ES2022Options,
     // Plugins
-    class_static_block: ClassStaticBlock,
+    class_static_block: ClassStaticBlock<'a, 'ctx>,
     class_properties: Option<ClassProperties<'a, 'ctx>>,
+    private_methods: PrivateMethods<'a, 'ctx>,
 }
 
 impl<'a, 'ctx> ES2022<'a, 'ctx> {
     pub fn new(options: ES2022Options, ctx: &'ctx TransformCtx<'a>) -> Self {
         Self {
             options,
-            class_static_block: ClassStaticBlock::new(),
+            class_static_block: ClassStaticBlock::new(ctx),
             class_properties: options
                 .class_properties
                 .map(|options| ClassProperties::new(options, ctx)),
+            private_methods: PrivateMethods::new(ctx),
         }
     }
 }
@@ -37,8 +42,43 @@ impl<'a, 'ctx> Traverse<'a> for ES2022<'a, 'ctx> {
         if self.options.class_static_block {
             self.class_static_block.enter_class_body(body, ctx);
         }
+        if self.options.private_methods {
+            self.private_methods.enter_class_body(body, ctx);
+        }
+    }
+
+    fn exit_class_body(&mut self, body: &mut ClassBody<'a>, ctx: &mut TraverseCtx<'a>) {
         if let Some(class_properties) = &mut self.class_properties {
-            class_properties.enter_class_body(body, ctx);
+            class_properties.exit_class_body(body, ctx);
+        }
+        if self.options.private_methods {
+            self.private_methods.exit_class_body(body, ctx);
+        }
+    }
+
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.private_methods {
+            self.private_methods.enter_expression(expr, ctx);
+        }
+    }
+
+    fn enter_private_field_expression(
+        &mut self,
+        expr: &mut PrivateFieldExpression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if self.options.private_methods {
+            self.private_methods.enter_private_field_expression(expr, ctx);
+        }
+    }
+
+    fn enter_simple_assignment_target(
+        &mut self,
+        node: &mut SimpleAssignmentTarget<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if self.options.private_methods {
+            self.private_methods.enter_simple_assignment_target(node, ctx);
         }
     }
 }
@@ -0,0 +1,987 @@
+//! ES2022: Private Methods
+//!
+//! This plugin transforms private accessors (`get #x() {}`, `set #x(v) {}`) of class instances.
+//!
+//! > This plugin is included in `preset-env`, in ES2022
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! class C {
+//!   get #x() { return 1; }
+//!   set #x(v) { log(v); }
+//!   method() {
+//!     this.#x = this.#x + 1;
+//!   }
+//! }
+//! ```
+//!
+//! Output:
+//! ```js
+//! var _x = new WeakMap();
+//! class C {
+//!   constructor() {
+//!     babelHelpers.classPrivateFieldInitSpec(this, _x, {
+//!       get: function() { return 1; },
+//!       set: function(v) { log(v); }
+//!     });
+//!   }
+//!   method() {
+//!     babelHelpers.classPrivateFieldSet(this, _x, babelHelpers.classPrivateFieldGet(this, _x) + 1);
+//!   }
+//! }
+//! ```
+//!
+//! ## Implementation
+//!
+//! Only private accessors of class instances are transformed.
+//! Private methods, static private accessors and private fields are left as is.
+//!
+//! Each accessor's getter and setter are stored as an accessor descriptor in a `WeakMap`,
+//! which is populated in the class constructor. `classPrivateFieldGet` calls the getter, and
+//! `classPrivateFieldSet` calls the setter, or throws if there is no setter.
+//! Reading an accessor which has no getter throws with `writeOnlyError`.
+//!
+//! A class is left untransformed, with a warning, if:
+//! * It has instance properties with initializers or instance `accessor` properties, which would
+//!   run before the accessors are attached to the instance.
+//! * An accessor uses `super`, or refers to a binding which the constructor would shadow.
+//! * It is a derived class whose constructor does not call `super()` at top level of its body.
+//! * An accessor is used in an optional chain (`obj?.#x`).
+//!
+//! Private names can only be accessed as `obj.#x` or `#x in obj`, and must be declared in
+//! an enclosing class. Invalid accesses (`super.#x`, `delete obj.#x`, or an undeclared name)
+//! are reported as errors, and left as is.
+//!
+//! Implementation based on [@babel/plugin-transform-private-methods](https://babel.dev/docs/babel-plugin-transform-private-methods).
+//!
+//! ## References:
+//! * Babel plugin implementation:
+//!   * <https://github.com/babel/babel/tree/main/packages/babel-plugin-transform-private-methods>
+//!   * <https://github.com/babel/babel/blob/main/packages/babel-helper-create-class-features-plugin/src/fields.ts>
+//! * Private methods TC39 proposal: <https://github.com/tc39/proposal-private-methods>
+
+use std::cell::Cell;
+
+use rustc_hash::FxHashMap;
+
+use oxc_allocator::Box as ArenaBox;
+use oxc_ast::{ast::*, visit::walk, Visit, NONE};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_semantic::SymbolTable;
+use oxc_span::{Span, SPAN};
+use oxc_syntax::{
+    operator::{AssignmentOperator, BinaryOperator, LogicalOperator, UnaryOperator},
+    reference::ReferenceFlags,
+    scope::{ScopeFlags, ScopeId},
+    symbol::SymbolFlags,
+};
+use oxc_traverse::{Ancestor, BoundIdentifier, Traverse, TraverseCtx};
+
+use crate::{common::helper_loader::Helper, TransformCtx};
+
+use super::diagnostics;
+
+pub struct PrivateMethods<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
+    /// Private names declared in each class body which we are currently inside, innermost last.
+    /// Private names which are not transformed map to `None`, so they shadow outer classes' names.
+    classes: Vec<FxHashMap<Atom<'a>, Option<PrivateAccessor<'a>>>>,
+}
+
+#[derive(Clone)]
+struct PrivateAccessor<'a> {
+    /// Name of private accessor, without `#`
+    name: Atom<'a>,
+    /// `WeakMap` which holds accessor descriptor for each instance
+    binding: BoundIdentifier<'a>,
+    has_getter: bool,
+}
+
+impl<'a, 'ctx> PrivateMethods<'a, 'ctx> {
+    pub fn new(ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self { ctx, classes: vec![] }
+    }
+}
+
+impl<'a, 'ctx> Traverse<'a> for PrivateMethods<'a, 'ctx> {
+    fn enter_class_body(&mut self, body: &mut ClassBody<'a>, ctx: &mut TraverseCtx<'a>) {
+        let names = self.collect_private_names(body, ctx);
+        self.classes.push(names);
+    }
+
+    fn exit_class_body(&mut self, body: &mut ClassBody<'a>, ctx: &mut TraverseCtx<'a>) {
+        let names = self.classes.pop().unwrap();
+        if names.values().any(Option::is_some) {
+            self.transform_class_body(body, &names, ctx);
+        }
+    }
+
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Expression::PrivateInExpression(private_in) = expr {
+            if !self.is_declared(&private_in.left.name) {
+                self.ctx.error(diagnostics::private_field_undeclared(
+                    &private_in.left.name,
+                    private_in.left.span,
+                ));
+            }
+        }
+
+        if self.classes.is_empty() {
+            return;
+        }
+
+        match expr {
+            Expression::PrivateFieldExpression(_) => {
+                self.transform_private_field_expression(expr, ctx);
+            }
+            Expression::CallExpression(call_expr)
+                if matches!(call_expr.callee, Expression::PrivateFieldExpression(_)) =>
+            {
+                self.transform_call_expression(call_expr, ctx);
+            }
+            Expression::TaggedTemplateExpression(tagged_expr)
+                if matches!(tagged_expr.tag, Expression::PrivateFieldExpression(_)) =>
+            {
+                self.transform_tagged_template_expression(tagged_expr, ctx);
+            }
+            Expression::AssignmentExpression(assign_expr)
+                if matches!(assign_expr.left, AssignmentTarget::PrivateFieldExpression(_)) =>
+            {
+                self.transform_assignment_expression(expr, ctx);
+            }
+            Expression::UpdateExpression(update_expr)
+                if matches!(
+                    update_expr.argument,
+                    SimpleAssignmentTarget::PrivateFieldExpression(_)
+                ) =>
+            {
+                self.transform_update_expression(expr, ctx);
+            }
+            Expression::PrivateInExpression(_) => {
+                self.transform_private_in_expression(expr, ctx);
+            }
+            _ => {}
+        }
+    }
+
+    /// Report invalid accesses of private names: `super.#x`, `delete obj.#x`, and undeclared names.
+    fn enter_private_field_expression(
+        &mut self,
+        field_expr: &mut PrivateFieldExpression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if matches!(field_expr.object, Expression::Super(_)) {
+            self.ctx.error(diagnostics::super_private(field_expr.span));
+        }
+        if is_delete_argument(ctx) {
+            self.ctx.error(diagnostics::delete_private_field(field_expr.span));
+        }
+        if !self.is_declared(&field_expr.field.name) {
+            self.ctx.error(diagnostics::private_field_undeclared(
+                &field_expr.field.name,
+                field_expr.field.span,
+            ));
+        }
+    }
+
+    /// Transform private accessor as a destructuring or `for in` / `for of` assignment target.
+    /// `[obj.#x] = arr` -> `[_classPrivateFieldDestructureSet(obj, _x).value] = arr`
+    fn enter_simple_assignment_target(
+        &mut self,
+        target: &mut SimpleAssignmentTarget<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let SimpleAssignmentTarget::PrivateFieldExpression(field_expr) = target else {
+            return;
+        };
+        let Some(accessor) = self.lookup_field(field_expr) else {
+            return;
+        };
+
+        let object = ctx.ast.move_expression(&mut field_expr.object);
+        let arguments = ctx.ast.vec_from_iter([
+            Argument::from(object),
+            Argument::from(accessor.binding.create_read_expression(ctx)),
+        ]);
+        let object =
+            self.ctx.helper_call_expr(Helper::ClassPrivateFieldDestructureSet, arguments, ctx);
+        let property = ctx.ast.identifier_name(SPAN, "value");
+        *target = SimpleAssignmentTarget::from(
+            ctx.ast.member_expression_static(SPAN, object, property, false),
+        );
+    }
+}
+
+// Class body
+impl<'a, 'ctx> PrivateMethods<'a, 'ctx> {
+    /// Collect private names declared in class body, and create a `WeakMap` for each
+    /// private accessor, if class can be transformed.
+    fn collect_private_names(
+        &self,
+        body: &ClassBody<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> FxHashMap<Atom<'a>, Option<PrivateAccessor<'a>>> {
+        let mut names = FxHashMap::default();
+        // `(name, has_getter)` for each instance private accessor, in order of declaration
+        let mut accessors: Vec<(Atom<'a>, bool)> = vec![];
+        for element in &body.body {
+            let key = match element {
+                ClassElement::MethodDefinition(method) => {
+                    if is_instance_accessor(method) {
+                        if let PropertyKey::PrivateIdentifier(ident) = &method.key {
+                            let is_getter = method.kind == MethodDefinitionKind::Get;
+                            if let Some(accessor) =
+                                accessors.iter_mut().find(|(name, _)| *name == ident.name)
+                            {
+                                accessor.1 |= is_getter;
+                            } else {
+                                accessors.push((ident.name.clone(), is_getter));
+                            }
+                        }
+                        continue;
+                    }
+                    &method.key
+                }
+                ClassElement::PropertyDefinition(prop) => &prop.key,
+                ClassElement::AccessorProperty(prop) => &prop.key,
+                ClassElement::StaticBlock(_) | ClassElement::TSIndexSignature(_) => continue,
+            };
+            if let PropertyKey::PrivateIdentifier(ident) = key {
+                names.insert(ident.name.clone(), None);
+            }
+        }
+
+        if accessors.is_empty() {
+            return names;
+        }
+
+        let transform = match Self::can_transform(body, &accessors, ctx) {
+            Ok(()) => true,
+            Err(warning) => {
+                self.ctx.error(warning);
+                false
+            }
+        };
+        // `WeakMap`s are declared in scope enclosing the class
+        let class_scope_id = ctx.current_scope_id();
+        let scope_id = ctx.scopes().get_parent_id(class_scope_id).unwrap();
+        for (name, has_getter) in accessors {
+            let accessor = transform.then(|| {
+                let binding =
+                    ctx.generate_uid(&name, scope_id, SymbolFlags::FunctionScopedVariable);
+                // `var _x = new WeakMap();`
+                let weak_map_symbol_id = ctx.scopes().find_binding(scope_id, "WeakMap");
+                let callee = ctx.create_reference_id(
+                    SPAN,
+                    Atom::from("WeakMap"),
+                    weak_map_symbol_id,
+                    ReferenceFlags::Read,
+                );
+                let callee = Expression::Identifier(ctx.alloc(callee));
+                let init = ctx.ast.expression_new(SPAN, callee, ctx.ast.vec(), NONE, false);
+                self.ctx.var_declarations.insert_var(&binding, Some(init), ctx);
+
+                PrivateAccessor { name: name.clone(), binding, has_getter }
+            });
+            names.insert(name, accessor);
+        }
+        names
+    }
+
+    /// Check if private accessors of a class can be transformed.
+    /// If not, return a warning explaining why.
+    fn can_transform(
+        body: &ClassBody<'a>,
+        accessors: &[(Atom<'a>, bool)],
+        ctx: &TraverseCtx<'a>,
+    ) -> Result<(), OxcDiagnostic> {
+        let mut constructor = None;
+        for element in &body.body {
+            match element {
+                // Initializers of instance properties run before constructor body
+                // (or immediately after `super()`), so would not be able to access accessors
+                ClassElement::PropertyDefinition(prop)
+                    if !prop.r#static && prop.value.is_some() =>
+                {
+                    return Err(diagnostics::private_accessors_not_transformed(
+                        "instance properties with initializers are not supported",
+                        prop.span,
+                    ));
+                }
+                ClassElement::AccessorProperty(prop) if !prop.r#static => {
+                    return Err(diagnostics::private_accessors_not_transformed(
+                        "instance `accessor` properties are not supported",
+                        prop.span,
+                    ));
+                }
+                ClassElement::MethodDefinition(method)
+                    if method.kind == MethodDefinitionKind::Constructor =>
+                {
+                    constructor = Some(&method.value);
+                }
+                _ => {}
+            }
+        }
+
+        // Accessors are attached after `super()` in constructor of a derived class.
+        // Bail if it's not possible to find where that is.
+        if let Some(constructor) = constructor {
+            if is_derived_class(ctx)
+                && constructor
+                    .body
+                    .as_ref()
+                    .and_then(|body| find_super_call_statement(&body.statements))
+                    .is_none()
+            {
+                return Err(diagnostics::private_accessors_not_transformed(
+                    "constructor of a derived class must call `super()` at top level of its body",
+                    constructor.span,
+                ));
+            }
+        }
+
+        // Accessor functions are moved into the constructor, so must not use `super`,
+        // or refer to any bindings which have same name as bindings in the constructor
+        for element in &body.body {
+            let ClassElement::MethodDefinition(method) = element else { continue };
+            if !is_instance_accessor(method) {
+                continue;
+            }
+            let mut scanner = AccessorScanner::new(ctx.symbols());
+            scanner.visit_function(&method.value, ScopeFlags::empty());
+            if scanner.has_super {
+                return Err(diagnostics::private_accessors_not_transformed(
+                    "accessors using `super` are not supported",
+                    method.span,
+                ));
+            }
+            if let Some(constructor) = constructor {
+                let constructor_scope_id = constructor.scope_id();
+                if scanner
+                    .outer_references
+                    .iter()
+                    .any(|name| ctx.scopes().get_binding(constructor_scope_id, name).is_some())
+                {
+                    return Err(diagnostics::private_accessors_not_transformed(
+                        "accessor refers to a binding which is shadowed in the constructor",
+                        method.span,
+                    ));
+                }
+            }
+        }
+
+        // Private accessors in optional chains are not supported
+        let mut finder = OptionalChainFinder { accessors, in_chain: false, found: None };
+        finder.visit_class_body(body);
+        match finder.found {
+            Some(span) => Err(diagnostics::private_accessors_not_transformed(
+                "accessors in optional chains are not supported",
+                span,
+            )),
+            None => Ok(()),
+        }
+    }
+
+    /// Remove private accessors from class body, and attach them to instance in the constructor.
+    fn transform_class_body(
+        &self,
+        body: &mut ClassBody<'a>,
+        names: &FxHashMap<Atom<'a>, Option<PrivateAccessor<'a>>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        // Remove accessors from class body
+        let mut descriptors: Vec<(BoundIdentifier<'a>, [Option<ArenaBox<'a, Function<'a>>>; 2])> =
+            vec![];
+        let elements = ctx.ast.move_vec(&mut body.body);
+        body.body.reserve(elements.len());
+        for element in elements {
+            let method = match element {
+                ClassElement::MethodDefinition(method) if is_instance_accessor(&method) => method,
+                element => {
+                    body.body.push(element);
+                    continue;
+                }
+            };
+            let accessor = match &method.key {
+                PropertyKey::PrivateIdentifier(ident) => {
+                    names.get(&ident.name).and_then(Option::as_ref)
+                }
+                _ => None,
+            };
+            let Some(accessor) = accessor else {
+                body.body.push(ClassElement::MethodDefinition(method));
+                continue;
+            };
+
+            let index = descriptors
+                .iter()
+                .position(|(binding, _)| binding.symbol_id == accessor.binding.symbol_id)
+                .unwrap_or_else(|| {
+                    descriptors.push((accessor.binding.clone(), [None, None]));
+                    descriptors.len() - 1
+                });
+            let method = method.unbox();
+            let function_index = usize::from(method.kind == MethodDefinitionKind::Set);
+            descriptors[index].1[function_index] = Some(method.value);
+        }
+
+        // Find or create constructor
+        let is_derived = is_derived_class(ctx);
+        let constructor_index = body.body.iter().position(|element| {
+            matches!(element, ClassElement::MethodDefinition(method) if method.kind == MethodDefinitionKind::Constructor)
+        });
+        let constructor_index = constructor_index.unwrap_or_else(|| {
+            body.body.insert(0, Self::create_constructor(is_derived, ctx));
+            0
+        });
+        let ClassElement::MethodDefinition(constructor) =
+            body.body.get_mut(constructor_index).unwrap()
+        else {
+            unreachable!()
+        };
+        let constructor = &mut constructor.value;
+        let constructor_scope_id = constructor.scope_id();
+
+        // `_classPrivateFieldInitSpec(this, _x, { get: function() {}, set: function(v) {} });`
+        let mut statements = Vec::with_capacity(descriptors.len());
+        for (binding, functions) in descriptors {
+            let mut properties = ctx.ast.vec_with_capacity(2);
+            for (function, kind) in functions.into_iter().zip(["get", "set"]) {
+                let Some(function) = function else { continue };
+                let scope_id = function.scope_id();
+                ctx.scopes_mut().get_flags_mut(scope_id).remove(ScopeFlags::Modifiers);
+                ctx.scopes_mut().change_parent_id(scope_id, Some(constructor_scope_id));
+                properties.push(ctx.ast.object_property_kind_object_property(
+                    SPAN,
+                    PropertyKind::Init,
+                    ctx.ast.property_key_identifier_name(SPAN, kind),
+                    Expression::FunctionExpression(function),
+                    false,
+                    false,
+                    false,
+                ));
+            }
+            let descriptor = ctx.ast.expression_object(SPAN, properties, None);
+            let arguments = ctx.ast.vec_from_iter([
+                Argument::from(ctx.ast.expression_this(SPAN)),
+                Argument::from(binding.create_read_expression(ctx)),
+                Argument::from(descriptor),
+            ]);
+            let call = self.ctx.helper_call_expr(Helper::ClassPrivateFieldInitSpec, arguments, ctx);
+            statements.push(ctx.ast.statement_expression(SPAN, call));
+        }
+
+        let body = constructor.body.as_mut().unwrap();
+        let index = find_super_call_statement(&body.statements).map_or(0, |index| index + 1);
+        body.statements.splice(index..index, statements);
+    }
+
+    /// Create empty constructor.
+    ///
+    /// Base class: `constructor() {}`
+    /// Derived class: `constructor(..._args) { super(..._args); }`
+    pub(super) fn create_constructor(
+        is_derived: bool,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> ClassElement<'a> {
+        let scope_id = ctx.create_child_scope_of_current(
+            ScopeFlags::Function | ScopeFlags::Constructor | ScopeFlags::StrictMode,
+        );
+
+        let (rest, statements) = if is_derived {
+            let binding = ctx.generate_uid("args", scope_id, SymbolFlags::FunctionScopedVariable);
+            let rest =
+                ctx.ast.alloc_binding_rest_element(SPAN, binding.create_binding_pattern(ctx));
+            let arguments = ctx
+                .ast
+                .vec1(ctx.ast.argument_spread_element(SPAN, binding.create_read_expression(ctx)));
+            let super_call = ctx.ast.expression_call(
+                SPAN,
+                ctx.ast.expression_super(SPAN),
+                NONE,
+                arguments,
+                false,
+                false,
+            );
+            (Some(rest), ctx.ast.vec1(ctx.ast.statement_expression(SPAN, super_call)))
+        } else {
+            (None, ctx.ast.vec())
+        };
+
+        let params = ctx.ast.alloc_formal_parameters(
+            SPAN,
+            FormalParameterKind::FormalParameter,
+            ctx.ast.vec(),
+            rest,
+        );
+        let body = ctx.ast.alloc_function_body(SPAN, ctx.ast.vec(), statements);
+        let function = ctx.ast.alloc_function_with_scope_id(
+            FunctionType::FunctionExpression,
+            SPAN,
+            None,
+            false,
+            false,
+            false,
+            NONE,
+            NONE,
+            params,
+            NONE,
+            Some(body),
+            scope_id,
+        );
+        ctx.ast.class_element_method_definition(
+            MethodDefinitionType::MethodDefinition,
+            SPAN,
+            ctx.ast.vec(),
+            ctx.ast.property_key_identifier_name(SPAN, "constructor"),
+            function,
+            MethodDefinitionKind::Constructor,
+            false,
+            false,
+            false,
+            false,
+            None,
+        )
+    }
+}
+
+// Expressions
+impl<'a, 'ctx> PrivateMethods<'a, 'ctx> {
+    /// `obj.#x` -> `_classPrivateFieldGet(obj, _x)`
+    fn transform_private_field_expression(
+        &self,
+        expr: &mut Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let Expression::PrivateFieldExpression(field_expr) = expr else { unreachable!() };
+        if is_delete_argument(ctx) {
+            return;
+        }
+        let Some(accessor) = self.lookup_field(field_expr) else {
+            return;
+        };
+
+        let object = ctx.ast.move_expression(&mut field_expr.object);
+        *expr = self.create_get(&accessor, object, ctx);
+    }
+
+    /// `obj.#x(...args)` -> `_classPrivateFieldGet(_obj = obj, _x).call(_obj, ...args)`
+    fn transform_call_expression(
+        &self,
+        call_expr: &mut CallExpression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let Expression::PrivateFieldExpression(field_expr) = &mut call_expr.callee else {
+            unreachable!()
+        };
+        let Some(accessor) = self.lookup_field(field_expr) else {
+            return;
+        };
+
+        let object = ctx.ast.move_expression(&mut field_expr.object);
+        let (object, this) = self.duplicate_object(object, ctx);
+        let get = self.create_get(&accessor, object, ctx);
+        let property = ctx.ast.identifier_name(SPAN, "call");
+        call_expr.callee =
+            Expression::from(ctx.ast.member_expression_static(SPAN, get, property, false));
+        call_expr.arguments.insert(0, Argument::from(this));
+    }
+
+    /// ``obj.#x`str` `` -> ``_classPrivateFieldGet(_obj = obj, _x).bind(_obj)`str` ``
+    fn transform_tagged_template_expression(
+        &self,
+        tagged_expr: &mut TaggedTemplateExpression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let Expression::PrivateFieldExpression(field_expr) = &mut tagged_expr.tag else {
+            unreachable!()
+        };
+        let Some(accessor) = self.lookup_field(field_expr) else {
+            return;
+        };
+
+        let object = ctx.ast.move_expression(&mut field_expr.object);
+        let (object, this) = self.duplicate_object(object, ctx);
+        let get = self.create_get(&accessor, object, ctx);
+        let property = ctx.ast.identifier_name(SPAN, "bind");
+        let callee = Expression::from(ctx.ast.member_expression_static(SPAN, get, property, false));
+        let arguments = ctx.ast.vec1(Argument::from(this));
+        tagged_expr.tag = ctx.ast.expression_call(SPAN, callee, NONE, arguments, false, false);
+    }
+
+    /// `obj.#x = value` -> `_classPrivateFieldSet(obj, _x, value)`
+    /// `obj.#x += value` -> `_classPrivateFieldSet(_obj = obj, _x, _classPrivateFieldGet(_obj, _x) + value)`
+    /// `obj.#x ||= value` -> `_classPrivateFieldGet(_obj = obj, _x) || _classPrivateFieldSet(_obj, _x, value)`
+    fn transform_assignment_expression(
+        &self,
+        expr: &mut Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let Expression::AssignmentExpression(assign_expr) = expr else { unreachable!() };
+        let AssignmentTarget::PrivateFieldExpression(field_expr) = &mut assign_expr.left else {
+            unreachable!()
+        };
+        let Some(accessor) = self.lookup_field(field_expr) else {
+            return;
+        };
+
+        let object = ctx.ast.move_expression(&mut field_expr.object);
+        let value = ctx.ast.move_expression(&mut assign_expr.right);
+        let operator = assign_expr.operator;
+        *expr = if operator == AssignmentOperator::Assign {
+            self.create_set(&accessor, object, value, ctx)
+        } else if let Some(operator) = to_logical_operator(operator) {
+            let (object, object2) = self.duplicate_object(object, ctx);
+            let get = self.create_get(&accessor, object, ctx);
+            let set = self.create_set(&accessor, object2, value, ctx);
+            ctx.ast.expression_logical(SPAN, get, operator, set)
+        } else {
+            let (object, object2) = self.duplicate_object(object, ctx);
+            let get = self.create_get(&accessor, object2, ctx);
+            let value = ctx.ast.expression_binary(SPAN, get, to_binary_operator(operator), value);
+            self.create_set(&accessor, object, value, ctx)
+        };
+    }
+
+    /// `++obj.#x` -> `_classPrivateFieldSet(_obj = obj, _x, (_obj$x = _classPrivateFieldGet(_obj, _x), ++_obj$x))`
+    ///
+    /// `obj.#x++` ->
+    /// `(_classPrivateFieldSet(_obj = obj, _x, (_obj$x = _classPrivateFieldGet(_obj, _x), _obj$x2 = _obj$x++, _obj$x)), _obj$x2)`
+    fn transform_update_expression(&self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let is_value_unused = is_value_unused(ctx);
+        let Expression::UpdateExpression(update_expr) = expr else { unreachable!() };
+        let SimpleAssignmentTarget::PrivateFieldExpression(field_expr) = &mut update_expr.argument
+        else {
+            unreachable!()
+        };
+        let Some(accessor) = self.lookup_field(field_expr) else {
+            return;
+        };
+
+        let temp = self.create_temp_var(&update_expr.argument, ctx);
+        let old_value = (!update_expr.prefix && !is_value_unused)
+            .then(|| self.create_temp_var(&update_expr.argument, ctx));
+        let SimpleAssignmentTarget::PrivateFieldExpression(field_expr) = &mut update_expr.argument
+        else {
+            unreachable!()
+        };
+        let object = ctx.ast.move_expression(&mut field_expr.object);
+        let (object, object2) = self.duplicate_object(object, ctx);
+
+        // `_obj$x = _classPrivateFieldGet(_obj, _x)`
+        let get = self.create_get(&accessor, object2, ctx);
+        let mut expressions = ctx.ast.vec_with_capacity(3);
+        expressions.push(ctx.ast.expression_assignment(
+            SPAN,
+            AssignmentOperator::Assign,
+            temp.create_read_write_target(ctx),
+            get,
+        ));
+
+        let operator = update_expr.operator;
+        if let Some(old_value) = &old_value {
+            // `_obj$x2 = _obj$x++, _obj$x`
+            let update = ctx.ast.expression_update(
+                SPAN,
+                operator,
+                false,
+                temp.create_read_write_target(ctx).into_simple_assignment_target(),
+            );
+            expressions.push(ctx.ast.expression_assignment(
+                SPAN,
+                AssignmentOperator::Assign,
+                old_value.create_read_write_target(ctx),
+                update,
+            ));
+            expressions.push(temp.create_read_expression(ctx));
+        } else {
+            // `++_obj$x`
+            expressions.push(ctx.ast.expression_update(
+                SPAN,
+                operator,
+                true,
+                temp.create_read_write_target(ctx).into_simple_assignment_target(),
+            ));
+        }
+
+        let value = ctx.ast.expression_sequence(SPAN, expressions);
+        let set = self.create_set(&accessor, object, value, ctx);
+        *expr = match old_value {
+            Some(old_value) => ctx.ast.expression_sequence(
+                SPAN,
+                ctx.ast.vec_from_iter([set, old_value.create_read_expression(ctx)]),
+            ),
+            None => set,
+        };
+    }
+
+    /// `#x in obj` -> `_x.has(_checkInRHS(obj))`
+    fn transform_private_in_expression(
+        &self,
+        expr: &mut Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let Expression::PrivateInExpression(private_in) = expr else { unreachable!() };
+        let Some(accessor) = self.lookup(&private_in.left.name) else {
+            return;
+        };
+
+        let object = ctx.ast.move_expression(&mut private_in.right);
+        let object = self.ctx.helper_call_expr(
+            Helper::CheckInRHS,
+            ctx.ast.vec1(Argument::from(object)),
+            ctx,
+        );
+        let property = ctx.ast.identifier_name(SPAN, "has");
+        let callee = Expression::from(ctx.ast.member_expression_static(
+            SPAN,
+            accessor.binding.create_read_expression(ctx),
+            property,
+            false,
+        ));
+        let arguments = ctx.ast.vec1(Argument::from(object));
+        *expr = ctx.ast.expression_call(SPAN, callee, NONE, arguments, false, false);
+    }
+}
+
+// Utils
+impl<'a, 'ctx> PrivateMethods<'a, 'ctx> {
+    /// Find private accessor by name, respecting shadowing by nested classes.
+    fn lookup(&self, name: &str) -> Option<PrivateAccessor<'a>> {
+        self.classes.iter().rev().find_map(|names| names.get(name)).and_then(Clone::clone)
+    }
+
+    /// Find private accessor accessed by `obj.#x`. `super.#x` is invalid, so is never transformed.
+    fn lookup_field(&self, field_expr: &PrivateFieldExpression<'a>) -> Option<PrivateAccessor<'a>> {
+        if matches!(field_expr.object, Expression::Super(_)) {
+            return None;
+        }
+        self.lookup(&field_expr.field.name)
+    }
+
+    /// Check if private name is declared in any class which we are currently inside.
+    fn is_declared(&self, name: &str) -> bool {
+        self.classes.iter().any(|names| names.contains_key(name))
+    }
+
+    /// `_classPrivateFieldGet(object, _x)`, or `(object, _writeOnlyError("#x"))` if no getter
+    fn create_get(
+        &self,
+        accessor: &PrivateAccessor<'a>,
+        object: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        if accessor.has_getter {
+            let arguments = ctx.ast.vec_from_iter([
+                Argument::from(object),
+                Argument::from(accessor.binding.create_read_expression(ctx)),
+            ]);
+            self.ctx.helper_call_expr(Helper::ClassPrivateFieldGet, arguments, ctx)
+        } else {
+            let name = ctx.ast.atom(&format!("#{}", accessor.name));
+            let arguments =
+                ctx.ast.vec1(Argument::from(ctx.ast.expression_string_literal(SPAN, name)));
+            let error = self.ctx.helper_call_expr(Helper::WriteOnlyError, arguments, ctx);
+            ctx.ast.expression_sequence(SPAN, ctx.ast.vec_from_iter([object, error]))
+        }
+    }
+
+    /// `_classPrivateFieldSet(object, _x, value)`.
+    /// Helper throws if there is no setter.
+    fn create_set(
+        &self,
+        accessor: &PrivateAccessor<'a>,
+        object: Expression<'a>,
+        value: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let arguments = ctx.ast.vec_from_iter([
+            Argument::from(object),
+            Argument::from(accessor.binding.create_read_expression(ctx)),
+            Argument::from(value),
+        ]);
+        self.ctx.helper_call_expr(Helper::ClassPrivateFieldSet, arguments, ctx)
+    }
+
+    /// Get 2 copies of `object`, to evaluate it only once.
+    ///
+    /// `this` or a bound identifier is returned as is: `(this, this)`.
+    /// Otherwise `object` is assigned to a temp var: `(_obj = obj, _obj)`.
+    fn duplicate_object(
+        &self,
+        object: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> (Expression<'a>, Expression<'a>) {
+        if ctx.is_static(&object) {
+            let object2 = match &object {
+                Expression::Identifier(ident) => Expression::Identifier(
+                    ctx.ast.alloc(ctx.clone_identifier_reference(ident, ReferenceFlags::Read)),
+                ),
+                _ => ctx.ast.expression_this(SPAN),
+            };
+            return (object, object2);
+        }
+
+        let binding = ctx.generate_uid_in_current_scope_based_on_node(
+            &object,
+            SymbolFlags::FunctionScopedVariable,
+        );
+        self.ctx.var_declarations.insert_var(&binding, None, ctx);
+        let assignment = ctx.ast.expression_assignment(
+            SPAN,
+            AssignmentOperator::Assign,
+            binding.create_read_write_target(ctx),
+            object,
+        );
+        (assignment, binding.create_read_expression(ctx))
+    }
+
+    /// Create a temp var named after `target`, and add `var _obj$x;` to enclosing scope.
+    fn create_temp_var(
+        &self,
+        target: &SimpleAssignmentTarget<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> BoundIdentifier<'a> {
+        let binding = ctx.generate_uid_in_current_scope_based_on_node(
+            target,
+            SymbolFlags::FunctionScopedVariable,
+        );
+        self.ctx.var_declarations.insert_var(&binding, None, ctx);
+        binding
+    }
+}
+
+/// Returns `true` if method is a non-static getter or setter.
+fn is_instance_accessor(method: &MethodDefinition) -> bool {
+    !method.r#static && matches!(method.kind, MethodDefinitionKind::Get | MethodDefinitionKind::Set)
+}
+
+/// Returns `true` if class body currently being visited belongs to a class with `extends`.
+pub(super) fn is_derived_class(ctx: &TraverseCtx) -> bool {
+    matches!(ctx.parent(), Ancestor::ClassBody(class) if class.super_class().is_some())
+}
+
+/// Find index of `super(...);` statement.
+pub(super) fn find_super_call_statement(statements: &[Statement]) -> Option<usize> {
+    statements.iter().position(|stmt| {
+        matches!(
+            stmt,
+            Statement::ExpressionStatement(stmt)
+                if matches!(&stmt.expression, Expression::CallExpression(call) if matches!(call.callee, Expression::Super(_)))
+        )
+    })
+}
+
+/// Returns `true` if expression currently being visited is the argument of `delete`.
+fn is_delete_argument(ctx: &TraverseCtx) -> bool {
+    matches!(ctx.parent(), Ancestor::UnaryExpressionArgument(unary) if *unary.operator() == UnaryOperator::Delete)
+}
+
+/// Returns `true` if value of expression currently being visited is not used.
+fn is_value_unused(ctx: &TraverseCtx) -> bool {
+    let mut ancestors = ctx.ancestors();
+    match ancestors.next() {
+        Some(Ancestor::ForStatementUpdate(_)) => true,
+        // Body of `() => expr` is an `ExpressionStatement`, but its value is returned
+        Some(Ancestor::ExpressionStatementExpression(_)) => !matches!(
+            ancestors.nth(1),
+            Some(Ancestor::ArrowFunctionExpressionBody(arrow)) if *arrow.expression()
+        ),
+        _ => false,
+    }
+}
+
+fn to_logical_operator(operator: AssignmentOperator) -> Option<LogicalOperator> {
+    match operator {
+        AssignmentOperator::LogicalAnd => Some(LogicalOperator::And),
+        AssignmentOperator::LogicalOr => Some(LogicalOperator::Or),
+        AssignmentOperator::LogicalNullish => Some(LogicalOperator::Coalesce),
+        _ => None,
+    }
+}
+
+fn to_binary_operator(operator: AssignmentOperator) -> BinaryOperator {
+    match operator {
+        AssignmentOperator::Addition => BinaryOperator::Addition,
+        AssignmentOperator::Subtraction => BinaryOperator::Subtraction,
+        AssignmentOperator::Multiplication => BinaryOperator::Multiplication,
+        AssignmentOperator::Division => BinaryOperator::Division,
+        AssignmentOperator::Remainder => BinaryOperator::Remainder,
+        AssignmentOperator::Exponential => BinaryOperator::Exponential,
+        AssignmentOperator::ShiftLeft => BinaryOperator::ShiftLeft,
+        AssignmentOperator::ShiftRight => BinaryOperator::ShiftRight,
+        AssignmentOperator::ShiftRightZeroFill => BinaryOperator::ShiftRightZeroFill,
+        AssignmentOperator::BitwiseOR => BinaryOperator::BitwiseOR,
+        AssignmentOperator::BitwiseXOR => BinaryOperator::BitwiseXOR,
+        AssignmentOperator::BitwiseAnd => BinaryOperator::BitwiseAnd,
+        AssignmentOperator::Assign
+        | AssignmentOperator::LogicalAnd
+        | AssignmentOperator::LogicalOr
+        | AssignmentOperator::LogicalNullish => unreachable!(),
+    }
+}
+
+/// Visitor which checks if a private accessor (or a field initializer) uses `super`,
+/// and collects names of references to bindings declared outside of it.
+pub(super) struct AccessorScanner<'a, 's> {
+    symbols: &'s SymbolTable,
+    /// Scopes inside the accessor
+    pub(super) scope_ids: Vec<ScopeId>,
+    pub(super) outer_references: Vec<Atom<'a>>,
+    has_super: bool,
+}
+
+impl<'a, 's> AccessorScanner<'a, 's> {
+    pub(super) fn new(symbols: &'s SymbolTable) -> Self {
+        Self { symbols, scope_ids: vec![], outer_references: vec![], has_super: false }
+    }
+}
+
+impl<'a, 's> Visit<'a> for AccessorScanner<'a, 's> {
+    fn enter_scope(&mut self, _flags: ScopeFlags, scope_id: &Cell<Option<ScopeId>>) {
+        self.scope_ids.push(scope_id.get().unwrap());
+    }
+
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+        let symbol_id = self.symbols.get_reference(ident.reference_id()).symbol_id();
+        let is_local = symbol_id.is_some_and(|symbol_id| {
+            self.scope_ids.contains(&self.symbols.get_scope_id(symbol_id))
+        });
+        if !is_local {
+            self.outer_references.push(ident.name.clone());
+        }
+    }
+
+    fn visit_super(&mut self, _super: &Super) {
+        self.has_super = true;
+    }
+}
+
+/// Visitor which checks if any private accessor is used in an optional chain.
+struct OptionalChainFinder<'a, 'n> {
+    accessors: &'n [(Atom<'a>, bool)],
+    in_chain: bool,
+    /// Span of first private accessor found in an optional chain
+    found: Option<Span>,
+}
+
+impl<'a, 'n> Visit<'a> for OptionalChainFinder<'a, 'n> {
+    fn visit_chain_expression(&mut self, expr: &ChainExpression<'a>) {
+        let in_chain = self.in_chain;
+        self.in_chain = true;
+        walk::walk_chain_expression(self, expr);
+        self.in_chain = in_chain;
+    }
+
+    fn visit_private_field_expression(&mut self, expr: &PrivateFieldExpression<'a>) {
+        if self.found.is_none()
+            && self.in_chain
+            && self.accessors.iter().any(|(name, _)| *name == expr.field.name)
+        {
+            self.found = Some(expr.span);
+        }
+        walk::walk_private_field_expression(self, expr);
+    }
+}
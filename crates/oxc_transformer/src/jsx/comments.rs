@@ -3,6 +3,7 @@ use std::borrow::Cow;
 use oxc_ast::{Comment, CommentKind};
 use oxc_syntax::identifier::is_irregular_whitespace;
 
+use super::diagnostics;
 use crate::{JsxOptions, JsxRuntime, TransformCtx, TypeScriptOptions};
 
 /// Scan through all comments and find the following pragmas:
@@ -15,29 +16,58 @@ use crate::{JsxOptions, JsxRuntime, TransformCtx, TypeScriptOptions};
 /// The comment does not need to be a JSDoc comment,
 /// otherwise `JSDoc` could be used instead.
 ///
-/// This behavior is aligned with Babel.
+/// This behavior is aligned with Babel, except that `@jsx` and `@jsxFrag` switch the file
+/// to the classic runtime, unless `@jsxRuntime` or `@jsxImportSource` is also present.
+/// Conflicting pragmas, e.g. `@jsxRuntime automatic` with `@jsx h`, are reported when
+/// the JSX transform is created.
+///
+/// The options passed in are the per-file copy owned by `Transformer`,
+/// so pragmas never affect other files.
 pub(crate) fn update_options_with_comments(
     comments: &[Comment],
     typescript: &mut TypeScriptOptions,
     jsx: &mut JsxOptions,
     ctx: &TransformCtx,
 ) {
+    let mut found = FoundPragmas::default();
     for comment in comments {
-        update_options_with_comment(typescript, jsx, comment, ctx.source_text);
+        update_options_with_comment(typescript, jsx, &mut found, comment, ctx);
+    }
+
+    // `@jsx` and `@jsxFrag` are only used by the classic runtime
+    if (found.pragma || found.pragma_frag)
+        && !found.runtime
+        && !found.import_source
+        && jsx.runtime.is_automatic()
+    {
+        jsx.runtime = JsxRuntime::Classic;
+        // `importSource` from options is only for the automatic runtime
+        jsx.import_source = None;
     }
 }
 
+/// Pragmas found in the file's comments
+#[derive(Default)]
+struct FoundPragmas {
+    runtime: bool,
+    import_source: bool,
+    pragma: bool,
+    pragma_frag: bool,
+}
+
 fn update_options_with_comment(
     typescript: &mut TypeScriptOptions,
     jsx: &mut JsxOptions,
+    found: &mut FoundPragmas,
     comment: &Comment,
-    source_text: &str,
+    ctx: &TransformCtx,
 ) {
-    let Some((keyword, remainder)) = find_jsx_pragma(comment, source_text) else { return };
+    let Some((keyword, remainder)) = find_jsx_pragma(comment, ctx.source_text) else { return };
 
     match keyword {
         // @jsx
         "" => {
+            found.pragma = true;
             // Don't set React option unless React transform is enabled
             // otherwise can cause error in `ReactJsx::new`
             if jsx.jsx_plugin || jsx.development {
@@ -47,18 +77,28 @@ fn update_options_with_comment(
         }
         // @jsxRuntime
         "Runtime" => {
-            jsx.runtime = match remainder {
+            let runtime = match remainder {
                 "classic" => JsxRuntime::Classic,
                 "automatic" => JsxRuntime::Automatic,
-                _ => return,
+                _ => {
+                    ctx.error(diagnostics::invalid_runtime_pragma(comment.span));
+                    return;
+                }
             };
+            found.runtime = true;
+            // `preserve` set in options takes precedence
+            if !jsx.runtime.is_preserve() {
+                jsx.runtime = runtime;
+            }
         }
         // @jsxImportSource
         "ImportSource" => {
+            found.import_source = true;
             jsx.import_source = Some(remainder.to_string());
         }
         // @jsxFrag
         "Frag" => {
+            found.pragma_frag = true;
             // Don't set React option unless React transform is enabled
             // otherwise can cause error in `ReactJsx::new`
             if jsx.jsx_plugin || jsx.development {
@@ -21,11 +21,23 @@ pub fn invalid_import_source() -> OxcDiagnostic {
         .with_help("Fix `importSource` option.")
 }
 
+pub fn invalid_runtime_pragma(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Runtime must be either \"classic\" or \"automatic\".")
+        .with_help("Fix `@jsxRuntime` pragma.")
+        .with_label(span)
+}
+
 pub fn namespace_does_not_support(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("Namespace tags are not supported by default. React's JSX doesn't support namespace tags. You can set `throwIfNamespace: false` to bypass this warning.")
         .with_label(span)
 }
 
+pub fn unresolved_component(name: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("`{name}` is not defined."))
+        .with_help(format!("`<{name}>` refers to a value. Did you forget to import `{name}`?"))
+        .with_label(span)
+}
+
 pub fn valueless_key(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("Please provide an explicit key value. Using \"key\" as a shorthand for \"key={true}\" is not allowed.")
         .with_label(span)
@@ -129,17 +129,22 @@ impl<'a, 'ctx> Traverse<'a> for ReactDisplayName<'a, 'ctx> {
 }
 
 impl<'a, 'ctx> ReactDisplayName<'a, 'ctx> {
+    /// Check if `call_expr` is `React.createClass(...)` or `createReactClass(...)`
+    pub(crate) fn is_create_class(call_expr: &CallExpression<'a>) -> bool {
+        match &call_expr.callee {
+            callee @ match_member_expression!(Expression) => {
+                callee.to_member_expression().is_specific_member_access("React", "createClass")
+            }
+            Expression::Identifier(ident) => ident.name == "createReactClass",
+            _ => false,
+        }
+    }
+
     /// Get the object from `React.createClass({})` or `createReactClass({})`
     fn get_object_from_create_class<'b>(
         call_expr: &'b mut CallExpression<'a>,
     ) -> Option<&'b mut ObjectExpression<'a>> {
-        if match &call_expr.callee {
-            callee @ match_member_expression!(Expression) => {
-                !callee.to_member_expression().is_specific_member_access("React", "createClass")
-            }
-            Expression::Identifier(ident) => ident.name != "createReactClass",
-            _ => true,
-        } {
+        if !Self::is_create_class(call_expr) {
             return None;
         }
         // Only 1 argument being the object expression.
@@ -379,6 +379,12 @@ impl<'a, 'ctx> JsxImpl<'a, 'ctx> {
                 let pragma_frag = Pragma::parse(options.pragma_frag.as_ref(), "Fragment", ast, ctx);
                 Bindings::Classic(ClassicBindings { pragma, pragma_frag })
             }
+            JsxRuntime::Preserve => {
+                // JSX is not transformed, so bindings are never used
+                let pragma = Pragma::default("createElement");
+                let pragma_frag = Pragma::default("Fragment");
+                Bindings::Classic(ClassicBindings { pragma, pragma_frag })
+            }
             JsxRuntime::Automatic => {
                 if options.pragma.is_some() || options.pragma_frag.is_some() {
                     ctx.error(diagnostics::pragma_and_pragma_frag_cannot_be_set());
@@ -708,7 +714,7 @@ impl<'a, 'ctx> JsxImpl<'a, 'ctx> {
         }
 
         let callee = self.get_create_element(has_key_after_props_spread, need_jsxs, ctx);
-        ctx.ast.expression_call(e.span(), callee, NONE, arguments, false)
+        ctx.ast.expression_call(e.span(), callee, NONE, arguments, false, false)
     }
 
     fn transform_element_name(
@@ -721,9 +727,13 @@ impl<'a, 'ctx> JsxImpl<'a, 'ctx> {
                 ctx.ast.expression_string_literal(ident.span, ident.name.clone())
             }
             JSXElementName::IdentifierReference(ident) => {
+                self.check_unresolved_component(ident, ctx);
                 Expression::Identifier(ctx.alloc(ident.as_ref().clone()))
             }
             JSXElementName::MemberExpression(member_expr) => {
+                if let Some(ident) = member_expr.get_identifier() {
+                    self.check_unresolved_component(ident, ctx);
+                }
                 Self::transform_jsx_member_expression(member_expr, ctx)
             }
             JSXElementName::NamespacedName(namespaced) => {
@@ -736,6 +746,15 @@ impl<'a, 'ctx> JsxImpl<'a, 'ctx> {
         }
     }
 
+    /// Report `<Foo />` where `Foo` is not bound in any enclosing scope.
+    fn check_unresolved_component(&self, ident: &IdentifierReference<'a>, ctx: &TraverseCtx<'a>) {
+        if self.options.check_unresolved_components
+            && ctx.scopes().find_binding(ctx.current_scope_id(), &ident.name).is_none()
+        {
+            self.ctx.error(diagnostics::unresolved_component(&ident.name, ident.span));
+        }
+    }
+
     fn get_fragment(&mut self, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
         match &mut self.bindings {
             Bindings::Classic(bindings) => bindings.pragma_frag.create_expression(ctx),
@@ -829,6 +848,7 @@ impl<'a, 'ctx> JsxImpl<'a, 'ctx> {
                     ctx.ast.expression_boolean_literal(e.span, true)
                 }
             },
+            // Shorthand attribute `<input disabled />` is `{ disabled: true }`
             None => ctx.ast.expression_boolean_literal(SPAN, true),
         }
     }
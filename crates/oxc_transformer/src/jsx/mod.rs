@@ -46,6 +46,12 @@ impl<'a, 'ctx> Jsx<'a, 'ctx> {
         if options.jsx_plugin || options.development {
             options.conform();
         }
+        if options.runtime.is_preserve() {
+            // Leave JSX elements and fragments untouched
+            options.jsx_plugin = false;
+            options.jsx_self_plugin = false;
+            options.jsx_source_plugin = false;
+        }
         let JsxOptions {
             jsx_plugin, display_name_plugin, jsx_self_plugin, jsx_source_plugin, ..
         } = options;
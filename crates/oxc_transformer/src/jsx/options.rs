@@ -9,6 +9,7 @@ fn default_as_true() -> bool {
 ///
 /// Auto imports the functions that JSX transpiles to.
 /// classic does not automatic import anything.
+/// preserve leaves JSX as is, while TypeScript syntax in it is still removed.
 #[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub enum JsxRuntime {
@@ -16,6 +17,7 @@ pub enum JsxRuntime {
     /// The default runtime is switched to automatic in Babel 8.
     #[default]
     Automatic,
+    Preserve,
 }
 
 impl JsxRuntime {
@@ -26,6 +28,10 @@ impl JsxRuntime {
     pub fn is_automatic(self) -> bool {
         self == Self::Automatic
     }
+
+    pub fn is_preserve(self) -> bool {
+        self == Self::Preserve
+    }
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -59,6 +65,14 @@ pub struct JsxOptions {
     #[serde(default = "default_as_true")]
     pub throw_if_namespace: bool,
 
+    /// Report a warning if a component element name (e.g. `<Foo />` or `<Foo.Bar />`) does not
+    /// resolve to any binding in scope, which usually means a missing import.
+    ///
+    /// Intrinsic elements (e.g. `<div />`) are never checked.
+    ///
+    /// Defaults to `false`.
+    pub check_unresolved_components: bool,
+
     /// Enables `@babel/plugin-transform-react-pure-annotations`.
     ///
     /// It will mark top-level React method calls as pure for tree shaking.
@@ -117,6 +131,7 @@ impl Default for JsxOptions {
             runtime: JsxRuntime::default(),
             development: false,
             throw_if_namespace: default_as_true(),
+            check_unresolved_components: false,
             pure: default_as_true(),
             import_source: None,
             pragma: None,
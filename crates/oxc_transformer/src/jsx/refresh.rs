@@ -1,10 +1,10 @@
 use base64::prelude::{Engine, BASE64_STANDARD};
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet};
 use sha1::{Digest, Sha1};
 
 use oxc_allocator::{CloneIn, GetAddress, Vec as ArenaVec};
-use oxc_ast::{ast::*, match_expression, AstBuilder, NONE};
-use oxc_semantic::{Reference, ReferenceFlags, ScopeFlags, ScopeId, SymbolFlags};
+use oxc_ast::{ast::*, match_expression, visit::walk, AstBuilder, Visit, NONE};
+use oxc_semantic::{ReferenceFlags, ScopeFlags, ScopeId, SymbolFlags, SymbolId, SymbolTable};
 use oxc_span::{Atom, GetSpan, SPAN};
 use oxc_syntax::operator::AssignmentOperator;
 use oxc_traverse::{Ancestor, BoundIdentifier, Traverse, TraverseCtx};
@@ -113,6 +113,8 @@ pub struct ReactRefresh<'a, 'ctx> {
     // (function_scope_id, (hook_name, hook_key, custom_hook_callee)
     hook_calls: FxHashMap<ScopeId, Vec<(Atom<'a>, Atom<'a>)>>,
     non_builtin_hooks_callee: FxHashMap<ScopeId, Vec<Option<Expression<'a>>>>,
+    /// Symbols which are used as the type of a JSX element, see [`JsxTypeCollector`].
+    used_as_jsx_type: FxHashSet<SymbolId>,
 }
 
 impl<'a, 'ctx> ReactRefresh<'a, 'ctx> {
@@ -130,12 +132,17 @@ impl<'a, 'ctx> ReactRefresh<'a, 'ctx> {
             last_signature: None,
             hook_calls: FxHashMap::default(),
             non_builtin_hooks_callee: FxHashMap::default(),
+            used_as_jsx_type: FxHashSet::default(),
         }
     }
 }
 
 impl<'a, 'ctx> Traverse<'a> for ReactRefresh<'a, 'ctx> {
     fn enter_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        let mut collector = JsxTypeCollector::new(ctx.symbols());
+        collector.visit_program(program);
+        self.used_as_jsx_type = collector.symbol_ids;
+
         let mut new_statements = ctx.ast.vec_with_capacity(program.body.len());
         for mut statement in program.body.drain(..) {
             let next_statement = self.process_statement(&mut statement, ctx);
@@ -171,7 +178,7 @@ impl<'a, 'ctx> Traverse<'a> for ReactRefresh<'a, 'ctx> {
             ));
             new_statements.push(ctx.ast.statement_expression(
                 SPAN,
-                ctx.ast.expression_call(SPAN, callee, NONE, arguments, false),
+                ctx.ast.expression_call(SPAN, callee, NONE, arguments, false, false),
             ));
         }
         program.body.push(Statement::from(ctx.ast.declaration_variable(
@@ -251,6 +258,7 @@ impl<'a, 'ctx> Traverse<'a> for ReactRefresh<'a, 'ctx> {
             NONE,
             arguments,
             false,
+            false,
         );
     }
 
@@ -276,7 +284,7 @@ impl<'a, 'ctx> Traverse<'a> for ReactRefresh<'a, 'ctx> {
 
         let binding = BoundIdentifier::from_binding_ident(&binding_identifier);
         let callee = binding.create_read_expression(ctx);
-        let expr = ctx.ast.expression_call(SPAN, callee, NONE, arguments, false);
+        let expr = ctx.ast.expression_call(SPAN, callee, NONE, arguments, false, false);
         let statement = ctx.ast.statement_expression(SPAN, expr);
 
         // Get the address of the statement containing this `FunctionDeclaration`
@@ -599,6 +607,7 @@ impl<'a, 'ctx> ReactRefresh<'a, 'ctx> {
                 NONE,
                 ctx.ast.vec(),
                 false,
+                false,
             ),
         );
 
@@ -613,6 +622,7 @@ impl<'a, 'ctx> ReactRefresh<'a, 'ctx> {
                 NONE,
                 ctx.ast.vec(),
                 false,
+                false,
             )),
             ctx,
         );
@@ -774,9 +784,8 @@ impl<'a, 'ctx> ReactRefresh<'a, 'ctx> {
 
         if !found_inside {
             // See if this identifier is used in JSX. Then it's a component.
-            // TODO: Here we should check if the variable is used in JSX. But now we only check if it has value references.
             // https://github.com/facebook/react/blob/ba6a9e94edf0db3ad96432804f9931ce9dc89fec/packages/react-refresh/src/ReactFreshBabelPlugin.js#L161-L199
-            if !ctx.symbols().get_resolved_references(symbol_id).any(Reference::is_value) {
+            if !self.used_as_jsx_type.contains(&symbol_id) {
                 return None;
             }
         }
@@ -808,6 +817,7 @@ impl<'a, 'ctx> ReactRefresh<'a, 'ctx> {
                 NONE,
                 arguments,
                 false,
+                false,
             ),
         );
 
@@ -856,6 +866,58 @@ impl<'a, 'ctx> ReactRefresh<'a, 'ctx> {
     }
 }
 
+/// Collects symbols which are likely used as a component, because they are the type of a JSX
+/// element (`<Foo />`), or passed to a JSX factory (`React.createElement(Foo)`, `jsx(Foo, {})`).
+///
+/// Same as the `isLikelyUsedAsType` check in `react-refresh/babel`.
+struct JsxTypeCollector<'s> {
+    symbols: &'s SymbolTable,
+    symbol_ids: FxHashSet<SymbolId>,
+}
+
+impl<'s> JsxTypeCollector<'s> {
+    fn new(symbols: &'s SymbolTable) -> Self {
+        Self { symbols, symbol_ids: FxHashSet::default() }
+    }
+
+    fn add(&mut self, ident: &IdentifierReference) {
+        let symbol_id = ident
+            .reference_id
+            .get()
+            .and_then(|reference_id| self.symbols.get_reference(reference_id).symbol_id());
+        if let Some(symbol_id) = symbol_id {
+            self.symbol_ids.insert(symbol_id);
+        }
+    }
+}
+
+impl<'a, 's> Visit<'a> for JsxTypeCollector<'s> {
+    fn visit_jsx_opening_element(&mut self, elem: &JSXOpeningElement<'a>) {
+        if let JSXElementName::IdentifierReference(ident) = &elem.name {
+            self.add(ident);
+        }
+        walk::walk_jsx_opening_element(self, elem);
+    }
+
+    fn visit_call_expression(&mut self, call_expr: &CallExpression<'a>) {
+        let fn_name = match &call_expr.callee {
+            Expression::Identifier(ident) => Some(&ident.name),
+            Expression::StaticMemberExpression(member) => Some(&member.property.name),
+            _ => None,
+        };
+        if fn_name.is_some_and(|name| {
+            matches!(name.as_str(), "createElement" | "jsx" | "jsxDEV" | "jsxs")
+        }) {
+            for argument in &call_expr.arguments {
+                if let Argument::Identifier(ident) = argument {
+                    self.add(ident);
+                }
+            }
+        }
+        walk::walk_call_expression(self, call_expr);
+    }
+}
+
 fn is_componentish_name(name: &str) -> bool {
     name.as_bytes().first().is_some_and(u8::is_ascii_uppercase)
 }
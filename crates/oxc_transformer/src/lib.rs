@@ -7,10 +7,12 @@
 
 use std::path::Path;
 
-use oxc_allocator::{Allocator, Vec as ArenaVec};
-use oxc_ast::{ast::*, AstBuilder};
+use rustc_hash::FxHashMap;
+
+use oxc_allocator::{Allocator, CloneIn, Vec as ArenaVec};
+use oxc_ast::{ast::*, visit::walk, AstBuilder, Visit};
 use oxc_diagnostics::OxcDiagnostic;
-use oxc_semantic::{ScopeTree, SymbolTable};
+use oxc_semantic::{ScopeTree, SemanticBuilder, SymbolTable};
 use oxc_span::SPAN;
 use oxc_traverse::{traverse_mut, Traverse, TraverseCtx};
 
@@ -30,6 +32,7 @@ mod es2020;
 mod es2021;
 mod es2022;
 mod jsx;
+mod proposals;
 mod regexp;
 mod typescript;
 
@@ -46,11 +49,13 @@ use es2020::ES2020;
 use es2021::ES2021;
 use es2022::ES2022;
 use jsx::Jsx;
+use plugins::{ImportAttributes, PureAnnotations, RemoveSideEffectImports, TaggedTemplates};
+use proposals::Proposals;
 use regexp::RegExp;
 use typescript::TypeScript;
 
 pub use crate::{
-    common::helper_loader::HelperLoaderMode,
+    common::helper_loader::{HelperLoaderMode, HelperLoaderOptions, HelperSourceResolver},
     compiler_assumptions::CompilerAssumptions,
     es2015::{ArrowFunctionsOptions, ES2015Options},
     jsx::{JsxOptions, JsxRuntime, ReactRefreshOptions},
@@ -59,6 +64,8 @@ pub use crate::{
         ESTarget, Engine, EngineTargets, EnvOptions, Module, TransformOptions,
     },
     plugins::*,
+    proposals::ProposalOptions,
+    regexp::LookBehindAssertionsMode,
     typescript::{RewriteExtensionsMode, TypeScriptOptions},
 };
 
@@ -68,6 +75,14 @@ pub struct TransformerReturn {
     pub scopes: ScopeTree,
 }
 
+/// Result of [`Transformer::dry_run`].
+pub struct DryRunReturn {
+    pub errors: std::vec::Vec<OxcDiagnostic>,
+    /// Names of helpers which the transform would use, in order of first use
+    /// (e.g. `asyncToGenerator`), regardless of [`HelperLoaderMode`].
+    pub helpers: std::vec::Vec<&'static str>,
+}
+
 pub struct Transformer<'a> {
     ctx: TransformCtx<'a>,
     // options: TransformOptions,
@@ -76,6 +91,13 @@ pub struct Transformer<'a> {
     typescript: TypeScriptOptions,
     jsx: JsxOptions,
     env: EnvOptions,
+    tagged_templates: TaggedTemplateOptions,
+    remove_side_effect_imports: RemoveSideEffectImportsOptions,
+    import_attributes: ImportAttributesOptions,
+    proposals: ProposalOptions,
+    define: FxHashMap<String, String>,
+    pure_annotations: bool,
+    disable_arrow_function_body_wrapping: bool,
 }
 
 impl<'a> Transformer<'a> {
@@ -87,18 +109,51 @@ impl<'a> Transformer<'a> {
             typescript: options.typescript.clone(),
             jsx: options.jsx.clone(),
             env: options.env,
+            tagged_templates: options.tagged_templates.clone(),
+            remove_side_effect_imports: options.remove_side_effect_imports.clone(),
+            import_attributes: options.import_attributes,
+            proposals: options.proposals,
+            define: options.define.clone(),
+            pure_annotations: options.pure_annotations,
+            disable_arrow_function_body_wrapping: options.disable_arrow_function_body_wrapping,
         }
     }
 
     pub fn build_with_symbols_and_scopes(
-        mut self,
+        self,
         symbols: SymbolTable,
         scopes: ScopeTree,
         program: &mut Program<'a>,
     ) -> TransformerReturn {
+        self.transform(symbols, scopes, program).0
+    }
+
+    /// Report which helpers transforming `program` would use, without changing `program`.
+    ///
+    /// e.g. so a bundler can resolve `@babel/runtime` dependencies before transforming.
+    /// `program` is cloned into the allocator and transformed, and the result is discarded.
+    pub fn dry_run(self, program: &Program<'a>) -> DryRunReturn {
+        let mut program = program.clone_in(self.allocator);
+        let (symbols, scopes) =
+            SemanticBuilder::new().build(&program).semantic.into_symbol_table_and_scope_tree();
+        let (ret, ctx) = self.transform(symbols, scopes, &mut program);
+        DryRunReturn { errors: ret.errors, helpers: ctx.helper_loader.used_helper_names() }
+    }
+
+    /// Transform `program`, and return the context, which holds the state of common utilities.
+    fn transform(
+        mut self,
+        symbols: SymbolTable,
+        scopes: ScopeTree,
+        program: &mut Program<'a>,
+    ) -> (TransformerReturn, TransformCtx<'a>) {
         let allocator = self.allocator;
         let ast_builder = AstBuilder::new(allocator);
 
+        if self.is_noop(program) {
+            return (TransformerReturn { errors: vec![], symbols, scopes }, self.ctx);
+        }
+
         self.ctx.source_type = program.source_type;
         self.ctx.source_text = program.source_text;
         jsx::update_options_with_comments(
@@ -108,13 +163,23 @@ impl<'a> Transformer<'a> {
             &self.ctx,
         );
 
+        let define = self.create_define(allocator);
+
         let mut transformer = TransformerImpl {
+            define,
             common: Common::new(&self.env, &self.ctx),
+            pure_annotations: self.pure_annotations.then(|| PureAnnotations::new(&self.ctx)),
             x0_typescript: program
                 .source_type
                 .is_typescript()
                 .then(|| TypeScript::new(&self.typescript, &self.ctx)),
+            tagged_templates: TaggedTemplates::new(self.tagged_templates),
+            remove_side_effect_imports: RemoveSideEffectImports::new(
+                self.remove_side_effect_imports,
+            ),
+            import_attributes: ImportAttributes::new(self.import_attributes),
             x1_jsx: Jsx::new(self.jsx, ast_builder, &self.ctx),
+            x2_proposals: Proposals::new(self.proposals, &self.ctx),
             x2_es2022: ES2022::new(self.env.es2022, &self.ctx),
             x2_es2021: ES2021::new(self.env.es2021, &self.ctx),
             x2_es2020: ES2020::new(self.env.es2020, &self.ctx),
@@ -124,17 +189,148 @@ impl<'a> Transformer<'a> {
             x2_es2017: ES2017::new(self.env.es2017, &self.ctx),
             x3_es2015: ES2015::new(self.env.es2015, &self.ctx),
             x4_regexp: RegExp::new(self.env.regexp, &self.ctx),
+            disable_arrow_function_body_wrapping: self.disable_arrow_function_body_wrapping,
         };
 
         let (symbols, scopes) = traverse_mut(&mut transformer, allocator, program, symbols, scopes);
-        TransformerReturn { errors: self.ctx.take_errors(), symbols, scopes }
+        (TransformerReturn { errors: self.ctx.take_errors(), symbols, scopes }, self.ctx)
+    }
+}
+
+impl<'a> Transformer<'a> {
+    /// Create [`ReplaceGlobalDefines`] plugin from [`TransformOptions::define`].
+    ///
+    /// Invalid keys and values are reported as errors, and no replacements are made.
+    fn create_define(&self, allocator: &'a Allocator) -> Option<ReplaceGlobalDefines<'a>> {
+        if self.define.is_empty() {
+            return None;
+        }
+        // Sort for deterministic order of replacements and errors
+        let mut defines = self.define.iter().collect::<Vec<_>>();
+        defines.sort_unstable();
+        match ReplaceGlobalDefinesConfig::new(&defines) {
+            Ok(config) => Some(ReplaceGlobalDefines::new(allocator, config)),
+            Err(errors) => {
+                for error in errors {
+                    self.ctx.error(error);
+                }
+                None
+            }
+        }
+    }
+}
+
+impl<'a> Transformer<'a> {
+    /// Check if no plugin would change `program` or report an error, so the traversal can be skipped.
+    ///
+    /// e.g. plain JS with a modern target.
+    ///
+    /// All options are destructured, so adding an option fails to compile until it's classified here.
+    fn is_noop(&self, program: &Program<'a>) -> bool {
+        let Self {
+            ctx: _,
+            allocator: _,
+            // TypeScript programs are always transformed
+            typescript: _,
+            jsx,
+            env,
+            tagged_templates,
+            remove_side_effect_imports,
+            import_attributes,
+            proposals,
+            define,
+            pure_annotations,
+            // Only affects output of the arrow functions transform
+            disable_arrow_function_body_wrapping: _,
+        } = self;
+        let JsxOptions {
+            // These only act on JSX, which is checked by `HasPluginSyntax`
+            jsx_plugin: _,
+            jsx_self_plugin: _,
+            jsx_source_plugin: _,
+            runtime: _,
+            development: _,
+            throw_if_namespace: _,
+            check_unresolved_components: _,
+            pure: _,
+            import_source: _,
+            pragma: _,
+            pragma_frag: _,
+            use_built_ins: _,
+            use_spread: _,
+            // Acts on `createReactClass` calls, which are checked by `HasPluginSyntax`
+            display_name_plugin,
+            refresh,
+        } = jsx;
+        let ProposalOptions { explicit_resource_management, decorators, auto_accessors } =
+            proposals;
+
+        !program.source_type.is_typescript()
+            && env.is_noop()
+            && refresh.is_none()
+            && tagged_templates.is_empty()
+            && define.is_empty()
+            && remove_side_effect_imports.extensions.is_empty()
+            && import_attributes.keyword.is_none()
+            && !explicit_resource_management
+            && !auto_accessors
+            && !pure_annotations
+            && !HasPluginSyntax::check(program, *decorators, *display_name_plugin)
+    }
+}
+
+/// Finds syntax which plugins act on even when no transforms are enabled:
+/// JSX, which `.js` files may contain, decorators, which are reported as errors unless enabled,
+/// and `React.createClass`/`createReactClass` calls, which get a `displayName`.
+///
+/// Visiting is much cheaper than traversing with all plugins.
+struct HasPluginSyntax {
+    decorators_enabled: bool,
+    display_name_enabled: bool,
+    found: bool,
+}
+
+impl HasPluginSyntax {
+    fn check(program: &Program<'_>, decorators_enabled: bool, display_name_enabled: bool) -> bool {
+        let mut visitor = Self { decorators_enabled, display_name_enabled, found: false };
+        visitor.visit_program(program);
+        visitor.found
+    }
+}
+
+impl<'a> Visit<'a> for HasPluginSyntax {
+    fn visit_decorator(&mut self, _decorator: &Decorator<'a>) {
+        if !self.decorators_enabled {
+            self.found = true;
+        }
+    }
+
+    fn visit_call_expression(&mut self, call_expr: &CallExpression<'a>) {
+        if self.display_name_enabled && jsx::ReactDisplayName::is_create_class(call_expr) {
+            self.found = true;
+            return;
+        }
+        walk::walk_call_expression(self, call_expr);
+    }
+
+    fn visit_jsx_element(&mut self, _element: &JSXElement<'a>) {
+        self.found = true;
+    }
+
+    fn visit_jsx_fragment(&mut self, _fragment: &JSXFragment<'a>) {
+        self.found = true;
     }
 }
 
 struct TransformerImpl<'a, 'ctx> {
     // NOTE: all callbacks must run in order.
     x0_typescript: Option<TypeScript<'a, 'ctx>>,
+    define: Option<ReplaceGlobalDefines<'a>>,
+    tagged_templates: TaggedTemplates,
+    remove_side_effect_imports: RemoveSideEffectImports,
+    import_attributes: ImportAttributes,
     x1_jsx: Jsx<'a, 'ctx>,
+    x2_proposals: Proposals<'a, 'ctx>,
     x2_es2022: ES2022<'a, 'ctx>,
     x2_es2021: ES2021<'a, 'ctx>,
     x2_es2020: ES2020<'a, 'ctx>,
@@ -142,14 +338,16 @@ struct TransformerImpl<'a, 'ctx> {
     x2_es2018: ES2018<'a, 'ctx>,
     x2_es2017: ES2017<'a, 'ctx>,
     x2_es2016: ES2016<'a, 'ctx>,
-    #[expect(unused)]
     x3_es2015: ES2015<'a, 'ctx>,
     x4_regexp: RegExp<'a, 'ctx>,
     common: Common<'a, 'ctx>,
+    pure_annotations: Option<PureAnnotations<'a, 'ctx>>,
+    disable_arrow_function_body_wrapping: bool,
 }
 
 impl<'a, 'ctx> Traverse<'a> for TransformerImpl<'a, 'ctx> {
     fn enter_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.remove_side_effect_imports.enter_program(program, ctx);
         if let Some(typescript) = self.x0_typescript.as_mut() {
             typescript.enter_program(program, ctx);
         }
@@ -161,7 +359,11 @@ impl<'a, 'ctx> Traverse<'a> for TransformerImpl<'a, 'ctx> {
         if let Some(typescript) = self.x0_typescript.as_mut() {
             typescript.exit_program(program, ctx);
         }
+        self.x3_es2015.exit_program(program, ctx);
         self.common.exit_program(program, ctx);
+        if let Some(pure_annotations) = self.pure_annotations.as_mut() {
+            pure_annotations.exit_program(program, ctx);
+        }
     }
 
     // ALPHASORT
@@ -174,6 +376,8 @@ impl<'a, 'ctx> Traverse<'a> for TransformerImpl<'a, 'ctx> {
         if let Some(typescript) = self.x0_typescript.as_mut() {
             typescript.enter_arrow_function_expression(arrow, ctx);
         }
+        self.x2_es2018.enter_arrow_function_expression(arrow, ctx);
+        self.x3_es2015.enter_arrow_function_expression(arrow, ctx);
     }
 
     fn enter_variable_declarator(
@@ -186,7 +390,49 @@ impl<'a, 'ctx> Traverse<'a> for TransformerImpl<'a, 'ctx> {
         }
     }
 
+    fn enter_variable_declaration(
+        &mut self,
+        decl: &mut VariableDeclaration<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        self.x2_es2018.enter_variable_declaration(decl, ctx);
+        self.x3_es2015.enter_variable_declaration(decl, ctx);
+    }
+
+    fn enter_binding_identifier(
+        &mut self,
+        ident: &mut BindingIdentifier<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        self.x3_es2015.enter_binding_identifier(ident, ctx);
+    }
+
+    fn enter_identifier_reference(
+        &mut self,
+        ident: &mut IdentifierReference<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        self.x3_es2015.enter_identifier_reference(ident, ctx);
+    }
+
+    fn enter_block_statement(&mut self, block: &mut BlockStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.x3_es2015.enter_block_statement(block, ctx);
+    }
+
+    fn enter_switch_statement(
+        &mut self,
+        stmt: &mut SwitchStatement<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        self.x3_es2015.enter_switch_statement(stmt, ctx);
+    }
+
+    fn enter_numeric_literal(&mut self, node: &mut NumericLiteral<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.x2_es2021.enter_numeric_literal(node, ctx);
+    }
+
     fn enter_big_int_literal(&mut self, node: &mut BigIntLiteral<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.x2_es2021.enter_big_int_literal(node, ctx);
         self.x2_es2020.enter_big_int_literal(node, ctx);
     }
 
@@ -209,13 +455,22 @@ impl<'a, 'ctx> Traverse<'a> for TransformerImpl<'a, 'ctx> {
         }
     }
 
+    fn enter_decorator(&mut self, decorator: &mut Decorator<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.x2_proposals.enter_decorator(decorator, ctx);
+    }
+
     fn enter_class_body(&mut self, body: &mut ClassBody<'a>, ctx: &mut TraverseCtx<'a>) {
         if let Some(typescript) = self.x0_typescript.as_mut() {
             typescript.enter_class_body(body, ctx);
         }
+        self.x2_proposals.enter_class_body(body, ctx);
         self.x2_es2022.enter_class_body(body, ctx);
     }
 
+    fn exit_class_body(&mut self, body: &mut ClassBody<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.x2_es2022.exit_class_body(body, ctx);
+    }
+
     fn enter_static_block(&mut self, block: &mut StaticBlock<'a>, ctx: &mut TraverseCtx<'a>) {
         self.common.enter_static_block(block, ctx);
     }
@@ -239,10 +494,16 @@ impl<'a, 'ctx> Traverse<'a> for TransformerImpl<'a, 'ctx> {
         if let Some(typescript) = self.x0_typescript.as_mut() {
             typescript.enter_expression(expr, ctx);
         }
+        if let Some(define) = self.define.as_mut() {
+            define.enter_expression(expr, ctx);
+        }
+        self.tagged_templates.enter_expression(expr, ctx);
+        self.x2_es2022.enter_expression(expr, ctx);
         self.x2_es2021.enter_expression(expr, ctx);
         self.x2_es2020.enter_expression(expr, ctx);
         self.x2_es2018.enter_expression(expr, ctx);
         self.x2_es2016.enter_expression(expr, ctx);
+        self.x3_es2015.enter_expression(expr, ctx);
         self.x4_regexp.enter_expression(expr, ctx);
         self.common.enter_expression(expr, ctx);
     }
@@ -251,9 +512,18 @@ impl<'a, 'ctx> Traverse<'a> for TransformerImpl<'a, 'ctx> {
         self.x1_jsx.exit_expression(expr, ctx);
         self.x2_es2018.exit_expression(expr, ctx);
         self.x2_es2017.exit_expression(expr, ctx);
+        self.x3_es2015.exit_expression(expr, ctx);
         self.common.exit_expression(expr, ctx);
     }
 
+    fn enter_private_field_expression(
+        &mut self,
+        expr: &mut PrivateFieldExpression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        self.x2_es2022.enter_private_field_expression(expr, ctx);
+    }
+
     fn enter_simple_assignment_target(
         &mut self,
         node: &mut SimpleAssignmentTarget<'a>,
@@ -262,6 +532,7 @@ impl<'a, 'ctx> Traverse<'a> for TransformerImpl<'a, 'ctx> {
         if let Some(typescript) = self.x0_typescript.as_mut() {
             typescript.enter_simple_assignment_target(node, ctx);
         }
+        self.x2_es2022.enter_simple_assignment_target(node, ctx);
     }
 
     fn enter_assignment_target(
@@ -285,6 +556,8 @@ impl<'a, 'ctx> Traverse<'a> for TransformerImpl<'a, 'ctx> {
     }
 
     fn enter_function(&mut self, func: &mut Function<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.x2_es2018.enter_function(func, ctx);
+        self.x3_es2015.enter_function(func, ctx);
         self.common.enter_function(func, ctx);
     }
 
@@ -388,6 +661,7 @@ impl<'a, 'ctx> Traverse<'a> for TransformerImpl<'a, 'ctx> {
         if let Some(typescript) = self.x0_typescript.as_mut() {
             typescript.enter_statements(stmts, ctx);
         }
+        self.x2_proposals.enter_statements(stmts, ctx);
     }
 
     fn exit_arrow_function_expression(
@@ -399,6 +673,10 @@ impl<'a, 'ctx> Traverse<'a> for TransformerImpl<'a, 'ctx> {
         // which can cause issues with the `() => x;` case, as it only allows a single statement.
         // To address this, we wrap the last statement in a return statement and set the expression to false.
         // This transforms the arrow function into the form `() => { return x; };`.
+        // `TransformOptions::disable_arrow_function_body_wrapping` turns this off.
+        if self.disable_arrow_function_body_wrapping {
+            return;
+        }
         if arrow.expression && arrow.body.statements.len() > 1 {
             let Statement::ExpressionStatement(statement) = arrow.body.statements.pop().unwrap()
             else {
@@ -431,6 +709,7 @@ impl<'a, 'ctx> Traverse<'a> for TransformerImpl<'a, 'ctx> {
         }
         self.x2_es2018.exit_statement(stmt, ctx);
         self.x2_es2017.exit_statement(stmt, ctx);
+        self.x3_es2015.exit_statement(stmt, ctx);
     }
 
     fn enter_tagged_template_expression(
@@ -447,7 +726,9 @@ impl<'a, 'ctx> Traverse<'a> for TransformerImpl<'a, 'ctx> {
         if let Some(typescript) = self.x0_typescript.as_mut() {
             typescript.enter_statement(stmt, ctx);
         }
+        self.x2_proposals.enter_statement(stmt, ctx);
         self.x2_es2018.enter_statement(stmt, ctx);
+        self.x3_es2015.enter_statement(stmt, ctx);
     }
 
     fn enter_declaration(&mut self, decl: &mut Declaration<'a>, ctx: &mut TraverseCtx<'a>) {
@@ -482,23 +763,29 @@ impl<'a, 'ctx> Traverse<'a> for TransformerImpl<'a, 'ctx> {
         if let Some(typescript) = self.x0_typescript.as_mut() {
             typescript.enter_for_statement(stmt, ctx);
         }
+        self.x3_es2015.enter_for_statement(stmt, ctx);
     }
 
     fn enter_for_of_statement(&mut self, stmt: &mut ForOfStatement<'a>, ctx: &mut TraverseCtx<'a>) {
         if let Some(typescript) = self.x0_typescript.as_mut() {
             typescript.enter_for_of_statement(stmt, ctx);
         }
+        self.x2_proposals.enter_for_of_statement(stmt, ctx);
         self.x2_es2018.enter_for_of_statement(stmt, ctx);
+        self.x3_es2015.enter_for_of_statement(stmt, ctx);
     }
 
     fn enter_for_in_statement(&mut self, stmt: &mut ForInStatement<'a>, ctx: &mut TraverseCtx<'a>) {
         if let Some(typescript) = self.x0_typescript.as_mut() {
             typescript.enter_for_in_statement(stmt, ctx);
         }
+        self.x3_es2015.enter_for_in_statement(stmt, ctx);
     }
 
     fn enter_catch_clause(&mut self, clause: &mut CatchClause<'a>, ctx: &mut TraverseCtx<'a>) {
         self.x2_es2019.enter_catch_clause(clause, ctx);
+        self.x2_es2018.enter_catch_clause(clause, ctx);
+        self.x3_es2015.enter_catch_clause(clause, ctx);
     }
 
     fn enter_import_declaration(
@@ -521,6 +808,16 @@ impl<'a, 'ctx> Traverse<'a> for TransformerImpl<'a, 'ctx> {
         }
     }
 
+    fn enter_export_default_declaration(
+        &mut self,
+        decl: &mut ExportDefaultDeclaration<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if let Some(typescript) = self.x0_typescript.as_mut() {
+            typescript.enter_export_default_declaration(decl, ctx);
+        }
+    }
+
     fn enter_export_named_declaration(
         &mut self,
         node: &mut ExportNamedDeclaration<'a>,
@@ -540,4 +837,19 @@ impl<'a, 'ctx> Traverse<'a> for TransformerImpl<'a, 'ctx> {
             typescript.enter_ts_export_assignment(export_assignment, ctx);
         }
     }
+
+    fn enter_with_clause(&mut self, clause: &mut WithClause<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.import_attributes.enter_with_clause(clause, ctx);
+    }
+
+    fn enter_import_expression(
+        &mut self,
+        expr: &mut ImportExpression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if let Some(typescript) = self.x0_typescript.as_mut() {
+            typescript.enter_import_expression(expr, ctx);
+        }
+        self.import_attributes.enter_import_expression(expr, ctx);
+    }
 }
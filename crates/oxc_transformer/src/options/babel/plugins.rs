@@ -52,6 +52,10 @@ pub struct BabelPlugins {
     pub set_notation: bool,
     // ES2015
     pub arrow_function: Option<ArrowFunctionsOptions>,
+    pub block_scoping: bool,
+    pub classes: bool,
+    pub destructuring: bool,
+    pub template_literals: bool,
     // ES2016
     pub exponentiation_operator: bool,
     // ES2017
@@ -63,11 +67,16 @@ pub struct BabelPlugins {
     pub optional_catch_binding: bool,
     // ES2020
     pub nullish_coalescing_operator: bool,
+    pub optional_chaining: bool,
     // ES2021
     pub logical_assignment_operators: bool,
+    pub numeric_separator: bool,
     // ES2022
     pub class_static_block: bool,
     pub class_properties: Option<ClassPropertiesOptions>,
+    pub private_methods: bool,
+    // Proposals
+    pub explicit_resource_management: bool,
 }
 
 impl TryFrom<PluginPresetEntries> for BabelPlugins {
@@ -117,6 +126,10 @@ impl TryFrom<PluginPresetEntries> for BabelPlugins {
                         .map_err(|err| p.errors.push(err))
                         .ok();
                 }
+                "transform-block-scoping" => p.block_scoping = true,
+                "transform-classes" => p.classes = true,
+                "transform-destructuring" => p.destructuring = true,
+                "transform-template-literals" => p.template_literals = true,
                 "transform-exponentiation-operator" => p.exponentiation_operator = true,
                 "transform-async-to-generator" => p.async_to_generator = true,
                 "transform-object-rest-spread" => {
@@ -128,7 +141,9 @@ impl TryFrom<PluginPresetEntries> for BabelPlugins {
                 "transform-async-generator-functions" => p.async_generator_functions = true,
                 "transform-optional-catch-binding" => p.optional_catch_binding = true,
                 "transform-nullish-coalescing-operator" => p.nullish_coalescing_operator = true,
+                "transform-optional-chaining" => p.optional_chaining = true,
                 "transform-logical-assignment-operators" => p.logical_assignment_operators = true,
+                "transform-numeric-separator" => p.numeric_separator = true,
                 "transform-class-static-block" => p.class_static_block = true,
                 "transform-class-properties" => {
                     p.class_properties = entry
@@ -136,6 +151,8 @@ impl TryFrom<PluginPresetEntries> for BabelPlugins {
                         .map_err(|err| p.errors.push(err))
                         .ok();
                 }
+                "transform-private-methods" => p.private_methods = true,
+                "proposal-explicit-resource-management" => p.explicit_resource_management = true,
                 s => p.unsupported.push(s.to_string()),
             }
         }
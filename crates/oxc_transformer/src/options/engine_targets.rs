@@ -39,10 +39,18 @@ impl EngineTargets {
     pub fn new(map: FxHashMap<Engine, Version>) -> Self {
         Self(map)
     }
+    /// Resolve a [browserslist] query, e.g. `"> 0.5%, last 2 versions"` or `"node 18"`,
+    /// into the minimum version of each engine.
+    ///
+    /// An empty query resolves to `defaults`.
+    /// Browsers which are not an [Engine] are ignored.
+    ///
     /// # Errors
     ///
     /// * Query is invalid.
-    pub fn try_from_query(query: &str) -> Result<Self, Error> {
+    ///
+    /// [browserslist]: <https://github.com/browserslist/browserslist>
+    pub fn from_browserslist(query: &str) -> Result<Self, Error> {
         BrowserslistQuery::Single(query.to_string()).exec()
     }
 
@@ -1,5 +1,7 @@
 use std::str::FromStr;
 
+use browserslist::Version;
+
 use oxc_diagnostics::Error;
 use serde::Deserialize;
 
@@ -12,7 +14,7 @@ use crate::{
     es2020::ES2020Options,
     es2021::ES2021Options,
     es2022::{ClassPropertiesOptions, ES2022Options},
-    regexp::RegExpOptions,
+    regexp::{LookBehindAssertionsMode, RegExpOptions},
     EngineTargets,
 };
 
@@ -58,6 +60,7 @@ impl EnvOptions {
                 dot_all_flag: true,
                 named_capture_groups: true,
                 look_behind_assertions: true,
+                look_behind_assertions_mode: LookBehindAssertionsMode::PassThrough,
                 match_indices: true,
                 set_notation: true,
             },
@@ -68,6 +71,17 @@ impl EnvOptions {
                 } else {
                     None
                 },
+                // Turned off because it is not ready.
+                block_scoping: include_unfinished_plugins,
+                // Turned off because it is not ready.
+                classes: include_unfinished_plugins,
+                // Turned off because it is not ready.
+                destructuring: include_unfinished_plugins,
+                // Turned off because `Object.setPrototypeOf` throws for some values which
+                // object literals ignore.
+                object_literal_proto: false,
+                // Turned off because it is not ready.
+                template_literals: include_unfinished_plugins,
             },
             es2016: ES2016Options { exponentiation_operator: true },
             es2017: ES2017Options { async_to_generator: true },
@@ -83,10 +97,12 @@ impl EnvOptions {
             es2019: ES2019Options { optional_catch_binding: true },
             es2020: ES2020Options {
                 nullish_coalescing_operator: true,
+                optional_chaining: true,
                 // Turn this on would throw error for all bigints.
                 big_int: false,
+                optional_chaining_helper_threshold: None,
             },
-            es2021: ES2021Options { logical_assignment_operators: true },
+            es2021: ES2021Options { logical_assignment_operators: true, numeric_separator: true },
             es2022: ES2022Options {
                 class_static_block: true,
                 class_properties: if include_unfinished_plugins {
@@ -94,10 +110,84 @@ impl EnvOptions {
                 } else {
                     None
                 },
+                // Turned off because it is not ready.
+                private_methods: include_unfinished_plugins,
             },
         }
     }
 
+    /// Check if no transforms are enabled, so the output is the same as the input.
+    pub(crate) fn is_noop(&self) -> bool {
+        let Self {
+            module: _,
+            regexp,
+            es2015,
+            es2016,
+            es2017,
+            es2018,
+            es2019,
+            es2020,
+            es2021,
+            es2022,
+        } = self;
+        let RegExpOptions {
+            sticky_flag,
+            unicode_flag,
+            unicode_property_escapes,
+            dot_all_flag,
+            named_capture_groups,
+            look_behind_assertions,
+            look_behind_assertions_mode: _,
+            match_indices,
+            set_notation,
+        } = regexp;
+        let ES2015Options {
+            arrow_function,
+            block_scoping,
+            classes,
+            destructuring,
+            object_literal_proto,
+            template_literals,
+        } = es2015;
+        let ES2018Options { object_rest_spread, async_generator_functions } = es2018;
+        let ES2020Options {
+            nullish_coalescing_operator,
+            optional_chaining,
+            big_int,
+            optional_chaining_helper_threshold: _,
+        } = es2020;
+        let ES2021Options { logical_assignment_operators, numeric_separator } = es2021;
+        let ES2022Options { class_static_block, class_properties, private_methods } = es2022;
+
+        !(*sticky_flag
+            || *unicode_flag
+            || *unicode_property_escapes
+            || *dot_all_flag
+            || *named_capture_groups
+            || *look_behind_assertions
+            || *match_indices
+            || *set_notation
+            || arrow_function.is_some()
+            || *block_scoping
+            || *classes
+            || *destructuring
+            || *object_literal_proto
+            || *template_literals
+            || es2016.exponentiation_operator
+            || es2017.async_to_generator
+            || object_rest_spread.is_some()
+            || *async_generator_functions
+            || es2019.optional_catch_binding
+            || *nullish_coalescing_operator
+            || *optional_chaining
+            || *big_int
+            || *logical_assignment_operators
+            || *numeric_separator
+            || *class_static_block
+            || class_properties.is_some()
+            || *private_methods)
+    }
+
     /// Initialize from a [browserslist] query.
     ///
     /// # Errors
@@ -106,7 +196,7 @@ impl EnvOptions {
     ///
     /// [browserslist]: <https://github.com/browserslist/browserslist>
     pub fn from_browserslist_query(query: &str) -> Result<Self, Error> {
-        EngineTargets::try_from_query(query).map(Self::from)
+        EngineTargets::from_browserslist(query).map(Self::from)
     }
 
     pub(crate) fn from_target(s: &str) -> Result<Self, Error> {
@@ -161,11 +251,22 @@ impl From<EngineTargets> for EnvOptions {
                 dot_all_flag: o.has_feature(ES2018DotallRegex),
                 named_capture_groups: o.has_feature(ES2018NamedCapturingGroupsRegex),
                 look_behind_assertions: o.has_feature(ES2018LookbehindRegex),
+                look_behind_assertions_mode: LookBehindAssertionsMode::default(),
                 match_indices: o.has_feature(ES2022MatchIndicesRegex),
                 set_notation: o.has_feature(ES2024UnicodeSetsRegex),
             },
             es2015: ES2015Options {
                 arrow_function: o.has_feature(ES2015ArrowFunctions).then(Default::default),
+                block_scoping: o.has_feature(ES2015BlockScoping),
+                classes: o.has_feature(ES2015Classes),
+                destructuring: o.has_feature(ES2015Destructuring),
+                // `__proto__` in object literals is not in compat data.
+                // It is standardized in ES2015, and supported since IE 11.
+                object_literal_proto: o
+                    .get(&Engine::Es)
+                    .is_some_and(|version| *version < ESTarget::ES2015.version())
+                    || o.get(&Engine::Ie).is_some_and(|version| *version < Version(11, 0, 0)),
+                template_literals: o.has_feature(ES2015TemplateLiterals),
             },
             es2016: ES2016Options {
                 exponentiation_operator: o.has_feature(ES2016ExponentiationOperator),
@@ -176,18 +277,24 @@ impl From<EngineTargets> for EnvOptions {
                 async_generator_functions: o.has_feature(ES2018AsyncGeneratorFunctions),
             },
             es2019: ES2019Options {
-                optional_catch_binding: o.has_feature(ES2018OptionalCatchBinding),
+                optional_catch_binding: o.has_feature(ES2019OptionalCatchBinding),
             },
             es2020: ES2020Options {
                 nullish_coalescing_operator: o.has_feature(ES2020NullishCoalescingOperator),
+                optional_chaining: o.has_feature(ES2019OptionalChaining),
                 big_int: o.has_feature(ES2020BigInt),
+                optional_chaining_helper_threshold: None,
             },
             es2021: ES2021Options {
                 logical_assignment_operators: o.has_feature(ES2020LogicalAssignmentOperators),
+                numeric_separator: o.has_feature(ES2021NumericSeparator),
             },
             es2022: ES2022Options {
                 class_static_block: o.has_feature(ES2022ClassStaticBlock),
                 class_properties: o.has_feature(ES2022ClassProperties).then(Default::default),
+                // Turned off because it is not ready.
+                // Only enabled by `EnvOptions::enable_all(true)` or the `transform-private-methods` plugin.
+                private_methods: false,
             },
         }
     }
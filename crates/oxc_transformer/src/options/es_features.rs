@@ -38,7 +38,7 @@ pub enum ESFeature {
     ES2018LookbehindRegex,
     ES2018ObjectRestSpread,
     ES2018AsyncGeneratorFunctions,
-    ES2018OptionalCatchBinding,
+    ES2019OptionalCatchBinding,
     ES2019JsonStrings,
     ES2019OptionalChaining,
     ES2020NullishCoalescingOperator,
@@ -623,7 +623,7 @@ pub fn features() -> &'static FxHashMap<ESFeature, EngineTargets> {
                 ])),
             ),
             (
-                ES2018OptionalCatchBinding,
+                ES2019OptionalCatchBinding,
                 EngineTargets::new(FxHashMap::from_iter([
                     (Chrome, Version(66u32, 0u32, 0u32)),
                     (Safari, Version(11u32, 1u32, 0u32)),
@@ -636,7 +636,7 @@ pub fn features() -> &'static FxHashMap<ESFeature, EngineTargets> {
                     (Opera, Version(53u32, 0u32, 0u32)),
                     (Ios, Version(11u32, 3u32, 0u32)),
                     (Edge, Version(79u32, 0u32, 0u32)),
-                    (Es, Version(2018u32, 0, 0)),
+                    (Es, Version(2019u32, 0, 0)),
                 ])),
             ),
             (
@@ -7,9 +7,12 @@ mod env;
 mod es_features;
 mod es_target;
 mod module;
+mod tsconfig;
 
 use std::path::PathBuf;
 
+use rustc_hash::FxHashMap;
+
 use oxc_diagnostics::Error;
 
 use crate::{
@@ -24,9 +27,11 @@ use crate::{
     es2021::ES2021Options,
     es2022::ES2022Options,
     jsx::JsxOptions,
-    regexp::RegExpOptions,
+    proposals::ProposalOptions,
+    regexp::{LookBehindAssertionsMode, RegExpOptions},
     typescript::TypeScriptOptions,
-    ReactRefreshOptions,
+    ImportAttributesOptions, ReactRefreshOptions, RemoveSideEffectImportsOptions,
+    TaggedTemplateOptions,
 };
 
 pub use self::{
@@ -63,6 +68,61 @@ pub struct TransformOptions {
     pub env: EnvOptions,
 
     pub helper_loader: HelperLoaderOptions,
+
+    /// Hooks which rewrite tagged templates, keyed by tag name.
+    pub tagged_templates: TaggedTemplateOptions,
+
+    /// Remove side-effect imports (`import "./x.css"`) of the given extensions.
+    pub remove_side_effect_imports: RemoveSideEffectImportsOptions,
+
+    /// Normalize the keyword of import attributes (`with` / `assert`).
+    pub import_attributes: ImportAttributesOptions,
+
+    /// Transforms for ECMAScript proposals, e.g. `using` declarations.
+    pub proposals: ProposalOptions,
+
+    /// Replace global identifiers and member expressions with expressions,
+    /// e.g. `process.env.NODE_ENV` with `"production"`.
+    ///
+    /// Keys are identifiers or dotted paths, and values are source text of expressions.
+    /// References are only replaced if they refer to a global, not a local binding of the same name.
+    /// See [`crate::ReplaceGlobalDefinesConfig`] for the keys which are accepted.
+    ///
+    /// Replacement happens before other transforms, so minifying the result can remove
+    /// dead code such as `if (process.env.NODE_ENV !== "production") { ... }`.
+    pub define: FxHashMap<String, String>,
+
+    /// Annotate initializers of top-level declarations which are known to be side-effect free
+    /// with `/* @__PURE__ */`, for better tree shaking by bundlers.
+    ///
+    /// Calls to injected helpers which are side-effect free (e.g. `_createClass(...)`) are
+    /// annotated too, wherever they are.
+    pub pure_annotations: bool,
+
+    /// Preserve `name` of functions and classes which are changed by transforms.
+    ///
+    /// e.g. `const f = () => this` transformed with arrow functions in spec mode is
+    /// `var f = function() {}.bind(this)`, whose name is `"bound "`.
+    /// With this option, the name is set with `Object.defineProperty(fn, "name", { value: "f" })`.
+    ///
+    /// This increases code size.
+    pub keep_names: bool,
+
+    /// Disable wrapping the body of a concise arrow function in a `return` statement
+    /// when plugins have inserted extra statements into it.
+    ///
+    /// By default, if a plugin adds statements to the body of `() => x`, the transformer turns
+    /// the arrow into `() => { ...; return x; }` on exit, because a concise body can only hold
+    /// a single expression statement.
+    ///
+    /// When set to `true`, this fix-up is skipped and the transformer trusts that any plugin which
+    /// touches an arrow function's body keeps `ArrowFunctionExpression::expression` and the body's
+    /// statements consistent.
+    ///
+    /// WARNING: If a concise arrow body ends up with more than one statement, codegen only prints
+    /// the first one, so any other statements are silently dropped from the output.
+    /// Only enable this if every plugin you run maintains this invariant itself.
+    pub disable_arrow_function_body_wrapping: bool,
 }
 
 impl TransformOptions {
@@ -85,6 +145,18 @@ impl TransformOptions {
                 mode: HelperLoaderMode::Runtime,
                 ..Default::default()
             },
+            tagged_templates: TaggedTemplateOptions::default(),
+            remove_side_effect_imports: RemoveSideEffectImportsOptions::default(),
+            import_attributes: ImportAttributesOptions::default(),
+            proposals: ProposalOptions {
+                decorators: true,
+                auto_accessors: true,
+                ..ProposalOptions::default()
+            },
+            define: FxHashMap::default(),
+            pure_annotations: false,
+            keep_names: false,
+            disable_arrow_function_body_wrapping: false,
         }
     }
 
@@ -174,6 +246,12 @@ impl TryFrom<&BabelOptions> for TransformOptions {
             dot_all_flag: env.regexp.dot_all_flag || options.plugins.dot_all_flag,
             look_behind_assertions: env.regexp.look_behind_assertions
                 || options.plugins.look_behind_assertions,
+            // The esbuild plugin passes lookbehind assertions through to `new RegExp()`
+            look_behind_assertions_mode: if options.plugins.look_behind_assertions {
+                LookBehindAssertionsMode::PassThrough
+            } else {
+                env.regexp.look_behind_assertions_mode
+            },
             named_capture_groups: env.regexp.named_capture_groups
                 || options.plugins.named_capture_groups,
             unicode_property_escapes: env.regexp.unicode_property_escapes
@@ -184,6 +262,11 @@ impl TryFrom<&BabelOptions> for TransformOptions {
 
         let es2015 = ES2015Options {
             arrow_function: options.plugins.arrow_function.or(env.es2015.arrow_function),
+            block_scoping: options.plugins.block_scoping || env.es2015.block_scoping,
+            classes: options.plugins.classes || env.es2015.classes,
+            destructuring: options.plugins.destructuring || env.es2015.destructuring,
+            object_literal_proto: env.es2015.object_literal_proto,
+            template_literals: options.plugins.template_literals || env.es2015.template_literals,
         };
 
         let es2016 = ES2016Options {
@@ -212,17 +295,21 @@ impl TryFrom<&BabelOptions> for TransformOptions {
         let es2020 = ES2020Options {
             nullish_coalescing_operator: options.plugins.nullish_coalescing_operator
                 || env.es2020.nullish_coalescing_operator,
+            optional_chaining: options.plugins.optional_chaining || env.es2020.optional_chaining,
             big_int: env.es2020.big_int,
+            optional_chaining_helper_threshold: env.es2020.optional_chaining_helper_threshold,
         };
 
         let es2021 = ES2021Options {
             logical_assignment_operators: options.plugins.logical_assignment_operators
                 || env.es2021.logical_assignment_operators,
+            numeric_separator: options.plugins.numeric_separator || env.es2021.numeric_separator,
         };
 
         let es2022 = ES2022Options {
             class_static_block: options.plugins.class_static_block || env.es2022.class_static_block,
             class_properties: options.plugins.class_properties.or(env.es2022.class_properties),
+            private_methods: options.plugins.private_methods || env.es2022.private_methods,
         };
 
         if !errors.is_empty() {
@@ -256,6 +343,19 @@ impl TryFrom<&BabelOptions> for TransformOptions {
                 es2022,
             },
             helper_loader,
+            tagged_templates: TaggedTemplateOptions::default(),
+            remove_side_effect_imports: RemoveSideEffectImportsOptions::default(),
+            import_attributes: ImportAttributesOptions::default(),
+            proposals: ProposalOptions {
+                explicit_resource_management: options.plugins.explicit_resource_management,
+                decorators: options.plugins.syntax_decorators.is_some()
+                    || options.plugins.proposal_decorators.is_some(),
+                auto_accessors: false,
+            },
+            define: FxHashMap::default(),
+            pure_annotations: false,
+            keep_names: false,
+            disable_arrow_function_body_wrapping: false,
         })
     }
 }
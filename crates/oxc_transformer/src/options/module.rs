@@ -17,6 +17,11 @@ pub enum Module {
     #[default]
     Preserve,
     ESM,
+    /// Only TypeScript `import x = require()` and `export =` are transformed,
+    /// and `"use strict"` is added to TypeScript files.
+    ///
+    /// ESM `import` and `export` statements are not transformed to CommonJS yet,
+    /// so exported bindings are not written to `exports`.
     CommonJS,
 }
 
@@ -0,0 +1,199 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use rustc_hash::FxHashMap;
+use serde::de::DeserializeOwned;
+use serde_json::{Map, Value};
+
+use oxc_diagnostics::Error;
+
+use super::{ESTarget, TransformOptions};
+use crate::JsxRuntime;
+
+type CompilerOptions = Map<String, Value>;
+
+impl TransformOptions {
+    /// Initialize from the `compilerOptions` of a `tsconfig.json`, including configs it `extends`.
+    ///
+    /// Supported options are `target`, `jsx`, `jsxImportSource`, `useDefineForClassFields`,
+    /// `experimentalDecorators` and `verbatimModuleSyntax`.
+    /// `paths` is validated, but not applied, as it only affects module resolution.
+    /// Other options are ignored. Without `target`, no syntax is lowered.
+    ///
+    /// # Errors
+    ///
+    /// * A config can't be read, parsed or found.
+    /// * `extends` is circular.
+    /// * A supported option has an invalid value.
+    pub fn from_tsconfig(path: &Path) -> Result<Self, Vec<Error>> {
+        let mut errors = vec![];
+        let compiler_options =
+            load_compiler_options(path, &mut vec![], &mut errors).unwrap_or_default();
+
+        let mut options = TransformOptions::default();
+
+        if let Some(target) = option::<String>(&compiler_options, "target", &mut errors) {
+            match ESTarget::from_str(&target) {
+                Ok(target) => options = TransformOptions::from(target),
+                Err(err) => errors.push(Error::msg(format!("compilerOptions.target: {err}"))),
+            }
+        }
+
+        if let Some(jsx) = option::<String>(&compiler_options, "jsx", &mut errors) {
+            match jsx.as_str() {
+                "react" => options.jsx.runtime = JsxRuntime::Classic,
+                "react-jsx" => options.jsx.runtime = JsxRuntime::Automatic,
+                "react-jsxdev" => {
+                    options.jsx.runtime = JsxRuntime::Automatic;
+                    options.jsx.development = true;
+                }
+                "preserve" | "react-native" => options.jsx.runtime = JsxRuntime::Preserve,
+                _ => errors.push(Error::msg(format!(
+                    "compilerOptions.jsx: Invalid value \"{jsx}\", expected \"preserve\", \"react\", \"react-jsx\", \"react-jsxdev\" or \"react-native\"."
+                ))),
+            }
+        }
+
+        if let Some(import_source) =
+            option::<String>(&compiler_options, "jsxImportSource", &mut errors)
+        {
+            options.jsx.import_source = Some(import_source);
+        }
+
+        // Class fields are assigned in the constructor, and type-only fields are removed.
+        if option::<bool>(&compiler_options, "useDefineForClassFields", &mut errors) == Some(false)
+        {
+            options.assumptions.set_public_class_fields = true;
+            options.typescript.allow_declare_fields = false;
+        }
+
+        // Legacy decorators are kept as written.
+        if let Some(decorators) =
+            option::<bool>(&compiler_options, "experimentalDecorators", &mut errors)
+        {
+            options.proposals.decorators = decorators;
+        }
+
+        if let Some(verbatim_module_syntax) =
+            option::<bool>(&compiler_options, "verbatimModuleSyntax", &mut errors)
+        {
+            options.typescript.verbatim_module_syntax = verbatim_module_syntax;
+        }
+
+        option::<FxHashMap<String, Vec<String>>>(&compiler_options, "paths", &mut errors);
+
+        if errors.is_empty() {
+            Ok(options)
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+/// Deserialize `compilerOptions[key]`, reporting an invalid value.
+fn option<T: DeserializeOwned>(
+    compiler_options: &CompilerOptions,
+    key: &str,
+    errors: &mut Vec<Error>,
+) -> Option<T> {
+    let value = compiler_options.get(key)?;
+    serde_json::from_value(value.clone())
+        .map_err(|err| errors.push(Error::msg(format!("compilerOptions.{key}: {err}"))))
+        .ok()
+}
+
+/// Read `compilerOptions` of the config at `path`, merged over the configs it `extends`.
+fn load_compiler_options(
+    path: &Path,
+    visited: &mut Vec<PathBuf>,
+    errors: &mut Vec<Error>,
+) -> Option<CompilerOptions> {
+    let canonical = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+    if visited.contains(&canonical) {
+        errors.push(Error::msg(format!("Circular `extends` in {}.", path.display())));
+        return None;
+    }
+    let mut config = read_config(path).map_err(|err| errors.push(err)).ok()?;
+    visited.push(canonical);
+
+    let mut compiler_options = CompilerOptions::new();
+    let extends = match config.remove("extends") {
+        None => vec![],
+        Some(Value::String(s)) => vec![s],
+        Some(Value::Array(values)) if values.iter().all(Value::is_string) => values
+            .into_iter()
+            .filter_map(|value| if let Value::String(s) = value { Some(s) } else { None })
+            .collect(),
+        Some(_) => {
+            errors.push(Error::msg(format!(
+                "extends: Expected a string or an array of strings in {}.",
+                path.display()
+            )));
+            vec![]
+        }
+    };
+    let dir = path.parent().unwrap_or_else(|| Path::new(""));
+    for specifier in extends {
+        let Some(base_path) = resolve_extends(dir, &specifier) else {
+            errors.push(Error::msg(format!(
+                "Cannot find config \"{specifier}\" extended by {}.",
+                path.display()
+            )));
+            continue;
+        };
+        // Configs later in the list override earlier ones.
+        if let Some(base) = load_compiler_options(&base_path, visited, errors) {
+            compiler_options.extend(base);
+        }
+    }
+    visited.pop();
+
+    match config.remove("compilerOptions") {
+        None => {}
+        Some(Value::Object(own)) => compiler_options.extend(own),
+        Some(_) => errors.push(Error::msg(format!(
+            "compilerOptions: Expected an object in {}.",
+            path.display()
+        ))),
+    }
+    Some(compiler_options)
+}
+
+fn read_config(path: &Path) -> Result<Map<String, Value>, Error> {
+    let mut content = fs::read_to_string(path)
+        .map_err(|err| Error::msg(format!("Failed to read {}: {err}", path.display())))?;
+    // tsconfig allows comments and trailing commas
+    json_strip_comments::strip(&mut content)
+        .map_err(|err| Error::msg(format!("Failed to parse {}: {err}", path.display())))?;
+    match serde_json::from_str::<Value>(&content) {
+        Ok(Value::Object(config)) => Ok(config),
+        Ok(_) => Err(Error::msg(format!("Expected an object in {}.", path.display()))),
+        Err(err) => Err(Error::msg(format!("Failed to parse {}: {err}", path.display()))),
+    }
+}
+
+/// Resolve a relative path (`./base`, `../tsconfig.base.json`) or a package (`@tsconfig/node20`)
+/// in `extends`, in the same way as TypeScript.
+fn resolve_extends(dir: &Path, specifier: &str) -> Option<PathBuf> {
+    fn file_or_json(path: PathBuf) -> Option<PathBuf> {
+        if path.is_file() {
+            return Some(path);
+        }
+        let mut with_json = path.into_os_string();
+        with_json.push(".json");
+        let with_json = PathBuf::from(with_json);
+        with_json.is_file().then_some(with_json)
+    }
+
+    let path = Path::new(specifier);
+    if specifier.starts_with('.') || path.is_absolute() {
+        return file_or_json(dir.join(path));
+    }
+    dir.ancestors().find_map(|ancestor| {
+        let package = ancestor.join("node_modules").join(path);
+        file_or_json(package.clone()).or_else(|| file_or_json(package.join("tsconfig.json")))
+    })
+}
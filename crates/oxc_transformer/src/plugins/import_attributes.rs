@@ -0,0 +1,123 @@
+//! Import Attributes Keyword
+//!
+//! Normalizes the keyword of import attributes to either `with` or `assert`,
+//! in both static and dynamic imports.
+//!
+//! `assert` is the deprecated syntax of import assertions, which was superseded by `with`.
+//! Normalize to `with` to migrate code, or to `assert` for engines which only support the old syntax
+//! (e.g. Chrome 91 - 122, Node.js 17 - 21).
+//!
+//! ## Example
+//!
+//! Options:
+//! ```rs
+//! ImportAttributesOptions { keyword: Some(ImportAttributesKeyword::With) }
+//! ```
+//!
+//! Input:
+//! ```js
+//! import data from "./data.json" assert { type: "json" };
+//! export * from "./data.json" assert { type: "json" };
+//! import("./data.json", { assert: { type: "json" } });
+//! ```
+//!
+//! Output:
+//! ```js
+//! import data from "./data.json" with { type: "json" };
+//! export * from "./data.json" with { type: "json" };
+//! import("./data.json", { with: { type: "json" } });
+//! ```
+
+use oxc_ast::ast::*;
+use oxc_traverse::{Traverse, TraverseCtx};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImportAttributesKeyword {
+    /// `import x from "y" with { type: "json" }`
+    With,
+    /// `import x from "y" assert { type: "json" }`
+    Assert,
+}
+
+impl ImportAttributesKeyword {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::With => "with",
+            Self::Assert => "assert",
+        }
+    }
+
+    fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "with" => Some(Self::With),
+            "assert" => Some(Self::Assert),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ImportAttributesOptions {
+    /// Keyword to convert import attributes to. `None` leaves them unchanged.
+    pub keyword: Option<ImportAttributesKeyword>,
+}
+
+pub struct ImportAttributes {
+    options: ImportAttributesOptions,
+}
+
+impl ImportAttributes {
+    pub fn new(options: ImportAttributesOptions) -> Self {
+        Self { options }
+    }
+
+    /// Check if `name` is a keyword of import attributes, other than `keyword`.
+    fn is_other_keyword(name: &str, keyword: ImportAttributesKeyword) -> bool {
+        ImportAttributesKeyword::from_str(name).is_some_and(|name| name != keyword)
+    }
+}
+
+impl<'a> Traverse<'a> for ImportAttributes {
+    /// `import x from "y" assert { type: "json" }` -> `import x from "y" with { type: "json" }`
+    ///
+    /// Also handles `export { x } from "y"` and `export * from "y"`.
+    fn enter_with_clause(&mut self, clause: &mut WithClause<'a>, _ctx: &mut TraverseCtx<'a>) {
+        let Some(keyword) = self.options.keyword else { return };
+        clause.attributes_keyword.name = Atom::from(keyword.as_str());
+    }
+
+    /// `import("y", { assert: { type: "json" } })` -> `import("y", { with: { type: "json" } })`
+    fn enter_import_expression(
+        &mut self,
+        expr: &mut ImportExpression<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+        let Some(keyword) = self.options.keyword else { return };
+        let Some(Expression::ObjectExpression(options)) = expr.arguments.first_mut() else {
+            return;
+        };
+        for property in options.properties.iter_mut() {
+            let ObjectPropertyKind::ObjectProperty(property) = property else { continue };
+            if property.computed {
+                continue;
+            }
+            match &mut property.key {
+                PropertyKey::StaticIdentifier(ident) => {
+                    if !Self::is_other_keyword(&ident.name, keyword) {
+                        continue;
+                    }
+                    ident.name = Atom::from(keyword.as_str());
+                }
+                PropertyKey::StringLiteral(lit) => {
+                    if !Self::is_other_keyword(&lit.value, keyword) {
+                        continue;
+                    }
+                    lit.value = Atom::from(keyword.as_str());
+                }
+                _ => continue,
+            }
+            // `{ assert }` -> `{ with: assert }`
+            property.shorthand = false;
+        }
+    }
+}
@@ -1,5 +1,16 @@
+mod import_attributes;
 mod inject_global_variables;
+mod pure_annotations;
+mod remove_side_effect_imports;
 mod replace_global_defines;
+mod tagged_template;
 
+pub(crate) use import_attributes::ImportAttributes;
+pub use import_attributes::{ImportAttributesKeyword, ImportAttributesOptions};
 pub use inject_global_variables::*;
+pub(crate) use pure_annotations::PureAnnotations;
+pub(crate) use remove_side_effect_imports::RemoveSideEffectImports;
+pub use remove_side_effect_imports::RemoveSideEffectImportsOptions;
 pub use replace_global_defines::*;
+pub(crate) use tagged_template::TaggedTemplates;
+pub use tagged_template::{TaggedTemplateHook, TaggedTemplateOptions};
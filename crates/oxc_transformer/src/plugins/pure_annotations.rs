@@ -0,0 +1,215 @@
+//! Pure Annotations
+//!
+//! Marks initializers of top-level declarations which are known to be side-effect free
+//! with `/* @__PURE__ */`, so bundlers can remove the declarations if they are unused.
+//!
+//! A call or `new` expression is known to be side-effect free if its arguments have no side effects, and:
+//!
+//! * It calls a helper which only returns a value, e.g. `_createClass(...)` or `_wrapRegExp(...)`.
+//! * It calls a top-level function annotated with `/* @__NO_SIDE_EFFECTS__ */`.
+//! * It creates an empty `Map`, `Set`, `WeakMap` or `WeakSet`.
+//! * It is an IIFE which only declares functions and returns a value which is known to be
+//!   side-effect free, e.g. a class transformed by the ES2015 classes plugin.
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! /* @__NO_SIDE_EFFECTS__ */ function createStore() { return {}; }
+//! const store = createStore();
+//! const cache = new WeakMap();
+//! const data = load();
+//! ```
+//!
+//! Output:
+//! ```js
+//! /* @__NO_SIDE_EFFECTS__ */ function createStore() { return {}; }
+//! const store = /* @__PURE__ */ createStore();
+//! const cache = /* @__PURE__ */ new WeakMap();
+//! const data = load();
+//! ```
+//!
+//! ## Implementation
+//!
+//! Runs after all other plugins, so declarations inserted by them (e.g. lowered classes) are annotated too.
+//! Only top-level declarations are annotated, as others are not removed by tree shaking.
+//!
+//! ## References:
+//! * `#__PURE__` notation: <https://github.com/javascript-compiler-hints/compiler-notations-spec/blob/main/pure-notation-spec.md>
+//! * `#__NO_SIDE_EFFECTS__` notation: <https://github.com/javascript-compiler-hints/compiler-notations-spec/blob/main/no-side-effects-notation-spec.md>
+
+use rustc_hash::FxHashSet;
+
+use oxc_ast::ast::*;
+use oxc_ecmascript::side_effects::MayHaveSideEffects;
+use oxc_semantic::{IsGlobalReference, Reference, SymbolId};
+use oxc_span::GetSpan;
+use oxc_traverse::{Traverse, TraverseCtx};
+
+use crate::TransformCtx;
+
+pub struct PureAnnotations<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
+    /// Top-level functions annotated with `/* @__NO_SIDE_EFFECTS__ */`
+    no_side_effects_functions: FxHashSet<SymbolId>,
+}
+
+impl<'a, 'ctx> PureAnnotations<'a, 'ctx> {
+    pub fn new(ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self { ctx, no_side_effects_functions: FxHashSet::default() }
+    }
+}
+
+impl<'a, 'ctx> Traverse<'a> for PureAnnotations<'a, 'ctx> {
+    fn exit_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.collect_no_side_effects_functions(program, ctx);
+
+        for stmt in program.body.iter_mut() {
+            let decl = match stmt {
+                Statement::VariableDeclaration(decl) => decl,
+                Statement::ExportNamedDeclaration(export_decl) => {
+                    match &mut export_decl.declaration {
+                        Some(Declaration::VariableDeclaration(decl)) => decl,
+                        _ => continue,
+                    }
+                }
+                _ => continue,
+            };
+            for declarator in decl.declarations.iter_mut() {
+                if let Some(init) = &mut declarator.init {
+                    self.annotate(init, ctx);
+                }
+            }
+        }
+    }
+}
+
+impl<'a, 'ctx> PureAnnotations<'a, 'ctx> {
+    /// Mark `expr` as pure if it is a call or `new` expression which is known to be side-effect free.
+    fn annotate(&self, expr: &mut Expression<'a>, ctx: &TraverseCtx<'a>) {
+        if !self.is_pure(expr, ctx) {
+            return;
+        }
+        match expr {
+            Expression::CallExpression(call) => call.pure = true,
+            Expression::NewExpression(new) => new.pure = true,
+            _ => {}
+        }
+    }
+
+    /// Check if `expr` is a call or `new` expression which is known to be side-effect free.
+    fn is_pure(&self, expr: &Expression<'a>, ctx: &TraverseCtx<'a>) -> bool {
+        match expr {
+            Expression::CallExpression(call) => {
+                if call.optional || !Self::are_arguments_pure(&call.arguments, ctx) {
+                    return false;
+                }
+                call.pure
+                    || self.ctx.is_pure_helper(&call.callee, ctx)
+                    || self.is_no_side_effects_function(&call.callee, ctx)
+                    || self.is_pure_iife(call, ctx)
+            }
+            Expression::NewExpression(new) => {
+                new.pure
+                    || (new.arguments.is_empty()
+                        && matches!(&new.callee, Expression::Identifier(ident)
+                            if matches!(ident.name.as_str(), "Map" | "Set" | "WeakMap" | "WeakSet")
+                                && ident.is_global_reference(ctx.symbols())))
+            }
+            _ => false,
+        }
+    }
+
+    fn are_arguments_pure(arguments: &[Argument<'a>], ctx: &TraverseCtx<'a>) -> bool {
+        arguments.iter().all(|argument| {
+            argument.as_expression().is_some_and(|expr| Self::is_pure_value(expr, ctx))
+        })
+    }
+
+    /// Check if evaluating `expr` has no side effects.
+    ///
+    /// Unlike `MayHaveSideEffects`, reading a declared binding and creating a function are side-effect free.
+    fn is_pure_value(expr: &Expression<'a>, ctx: &TraverseCtx<'a>) -> bool {
+        match expr {
+            Expression::Identifier(ident) => {
+                ctx.symbols().get_reference(ident.reference_id()).symbol_id().is_some()
+                    || !expr.may_have_side_effects()
+            }
+            Expression::FunctionExpression(_) | Expression::ArrowFunctionExpression(_) => true,
+            Expression::ArrayExpression(array) => array.elements.iter().all(|element| {
+                element.as_expression().is_some_and(|expr| Self::is_pure_value(expr, ctx))
+                    || element.is_elision()
+            }),
+            Expression::ObjectExpression(object) => object.properties.iter().all(|property| {
+                let ObjectPropertyKind::ObjectProperty(property) = property else { return false };
+                (!property.computed || !property.key.may_have_side_effects())
+                    && Self::is_pure_value(&property.value, ctx)
+            }),
+            _ => !expr.may_have_side_effects(),
+        }
+    }
+
+    /// `function () { function A() {} return _createClass(A); }()`
+    fn is_pure_iife(&self, call: &CallExpression<'a>, ctx: &TraverseCtx<'a>) -> bool {
+        let Expression::FunctionExpression(func) = call.callee.without_parentheses() else {
+            return false;
+        };
+        if func.r#async || func.generator || !call.arguments.is_empty() {
+            return false;
+        }
+        let Some(body) = &func.body else { return false };
+        let Some((last, rest)) = body.statements.split_last() else { return false };
+        let Statement::ReturnStatement(return_stmt) = last else { return false };
+        rest.iter().all(|stmt| matches!(stmt, Statement::FunctionDeclaration(_)))
+            && return_stmt.argument.as_ref().is_some_and(|argument| {
+                matches!(argument, Expression::Identifier(_)) || self.is_pure(argument, ctx)
+            })
+    }
+
+    /// Check if `callee` is a reference to a top-level function annotated with `/* @__NO_SIDE_EFFECTS__ */`.
+    fn is_no_side_effects_function(&self, callee: &Expression<'a>, ctx: &TraverseCtx<'a>) -> bool {
+        let Expression::Identifier(ident) = callee else { return false };
+        ctx.symbols()
+            .get_reference(ident.reference_id())
+            .symbol_id()
+            .is_some_and(|symbol_id| self.no_side_effects_functions.contains(&symbol_id))
+    }
+
+    /// Collect top-level function declarations annotated with `/* @__NO_SIDE_EFFECTS__ */`,
+    /// which are not reassigned.
+    fn collect_no_side_effects_functions(&mut self, program: &Program<'a>, ctx: &TraverseCtx<'a>) {
+        for stmt in &program.body {
+            let func = match stmt {
+                Statement::FunctionDeclaration(func) => func,
+                Statement::ExportNamedDeclaration(decl) => match &decl.declaration {
+                    Some(Declaration::FunctionDeclaration(func)) => func,
+                    _ => continue,
+                },
+                Statement::ExportDefaultDeclaration(decl) => match &decl.declaration {
+                    ExportDefaultDeclarationKind::FunctionDeclaration(func) => func,
+                    _ => continue,
+                },
+                _ => continue,
+            };
+            let Some(symbol_id) = func.symbol_id() else { continue };
+            if !Self::has_no_side_effects_comment(program, stmt.span().start)
+                && !Self::has_no_side_effects_comment(program, func.span.start)
+            {
+                continue;
+            }
+            let is_reassigned =
+                ctx.symbols().get_resolved_references(symbol_id).any(Reference::is_write);
+            if !is_reassigned {
+                self.no_side_effects_functions.insert(symbol_id);
+            }
+        }
+    }
+
+    fn has_no_side_effects_comment(program: &Program<'a>, start: u32) -> bool {
+        program.comments.iter().filter(|comment| comment.attached_to == start).any(|comment| {
+            let text = comment.content_span().source_text(program.source_text).trim_start();
+            text.strip_prefix(['@', '#'])
+                .is_some_and(|text| text.starts_with("__NO_SIDE_EFFECTS__"))
+        })
+    }
+}
@@ -0,0 +1,69 @@
+//! Remove Side Effect Imports
+//!
+//! Removes side-effect imports of assets such as stylesheets and images, which are handled by
+//! other stages of a bundler pipeline, by their extension.
+//!
+//! Only imports without any bindings are removed.
+//!
+//! ## Example
+//!
+//! Options:
+//! ```rs
+//! RemoveSideEffectImportsOptions { extensions: vec!["css".to_string()] }
+//! ```
+//!
+//! Input:
+//! ```js
+//! import "./reset.css";
+//! import styles from "./button.css";
+//! import "./polyfill.js";
+//! ```
+//!
+//! Output:
+//! ```js
+//! import styles from "./button.css";
+//! import "./polyfill.js";
+//! ```
+
+use oxc_ast::ast::*;
+use oxc_traverse::{Traverse, TraverseCtx};
+
+#[derive(Debug, Default, Clone)]
+pub struct RemoveSideEffectImportsOptions {
+    /// Extensions of side-effect imports to remove, without the leading dot, e.g. `["css", "svg"]`.
+    pub extensions: Vec<String>,
+}
+
+pub struct RemoveSideEffectImports {
+    options: RemoveSideEffectImportsOptions,
+}
+
+impl RemoveSideEffectImports {
+    pub fn new(options: RemoveSideEffectImportsOptions) -> Self {
+        Self { options }
+    }
+
+    /// Check if `import "source"` should be removed.
+    fn should_remove(&self, decl: &ImportDeclaration) -> bool {
+        // `import "./x.css"` and `import {} from "./x.css"`
+        if decl.specifiers.as_ref().is_some_and(|specifiers| !specifiers.is_empty()) {
+            return false;
+        }
+        // Ignore query and hash, e.g. `./x.css?inline`
+        let path = decl.source.value.split(['?', '#']).next().unwrap_or_default();
+        let Some((_, extension)) = path.rsplit_once('.') else { return false };
+        self.options.extensions.iter().any(|ext| ext == extension)
+    }
+}
+
+impl<'a> Traverse<'a> for RemoveSideEffectImports {
+    fn enter_program(&mut self, program: &mut Program<'a>, _ctx: &mut TraverseCtx<'a>) {
+        if self.options.extensions.is_empty() {
+            return;
+        }
+        program.body.retain(|stmt| match stmt {
+            Statement::ImportDeclaration(decl) => !self.should_remove(decl),
+            _ => true,
+        });
+    }
+}
@@ -1,10 +1,10 @@
 use std::{cmp::Ordering, sync::Arc};
 
 use oxc_allocator::Allocator;
-use oxc_ast::ast::*;
+use oxc_ast::{ast::*, VisitMut};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_parser::Parser;
-use oxc_semantic::{IsGlobalReference, ScopeTree, SymbolTable};
+use oxc_semantic::{IsGlobalReference, ReferenceFlags, ScopeTree, SymbolTable};
 use oxc_span::{CompactStr, SourceType};
 use oxc_syntax::identifier::is_identifier_name;
 use oxc_traverse::{traverse_mut, Traverse, TraverseCtx};
@@ -227,11 +227,27 @@ impl<'a> ReplaceGlobalDefines<'a> {
     }
 
     // Construct a new expression because we don't have ast clone right now.
-    fn parse_value(&self, source_text: &str) -> Expression<'a> {
+    fn parse_value(&self, source_text: &str, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
         // Allocate the string lazily because replacement happens rarely.
         let source_text = self.allocator.alloc_str(source_text);
         // Unwrapping here, it should already be checked by [ReplaceGlobalDefinesConfig::new].
-        Parser::new(self.allocator, source_text, SourceType::default()).parse_expression().unwrap()
+        let mut value = Parser::new(self.allocator, source_text, SourceType::default())
+            .parse_expression()
+            .unwrap();
+        ValueReferences { ctx }.visit_expression(&mut value);
+        value
+    }
+
+    /// Delete reference for the identifier at the root of `expr`, which is being replaced.
+    /// e.g. `process` in `process.env.NODE_ENV`.
+    fn delete_reference(expr: &Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let mut expr = expr;
+        while let Expression::StaticMemberExpression(member) = expr {
+            expr = &member.object;
+        }
+        if let Expression::Identifier(ident) = expr {
+            ctx.delete_reference_for_identifier(ident);
+        }
     }
 
     fn replace_identifier_defines(&self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
@@ -241,7 +257,8 @@ impl<'a> ReplaceGlobalDefines<'a> {
         }
         for (key, value) in &self.config.0.identifier {
             if ident.name.as_str() == key {
-                let value = self.parse_value(value);
+                Self::delete_reference(expr, ctx);
+                let value = self.parse_value(value, ctx);
                 *expr = value;
                 break;
             }
@@ -253,14 +270,15 @@ impl<'a> ReplaceGlobalDefines<'a> {
             Expression::StaticMemberExpression(member) => {
                 for dot_define in &self.config.0.dot {
                     if Self::is_dot_define(ctx.symbols(), dot_define, member) {
-                        let value = self.parse_value(&dot_define.value);
+                        Self::delete_reference(expr, ctx);
+                        let value = self.parse_value(&dot_define.value, ctx);
                         *expr = value;
                         return;
                     }
                 }
                 for meta_property_define in &self.config.0.meta_property {
                     if Self::is_meta_property_define(meta_property_define, member) {
-                        let value = self.parse_value(&meta_property_define.value);
+                        let value = self.parse_value(&meta_property_define.value, ctx);
                         *expr = value;
                         return;
                     }
@@ -270,7 +288,7 @@ impl<'a> ReplaceGlobalDefines<'a> {
                 if let Some(ref replacement) = self.config.0.import_meta {
                     if meta_property.meta.name == "import" && meta_property.property.name == "meta"
                     {
-                        let value = self.parse_value(replacement);
+                        let value = self.parse_value(replacement, ctx);
                         *expr = value;
                     }
                 }
@@ -363,38 +381,38 @@ impl<'a> ReplaceGlobalDefines<'a> {
     ) -> bool {
         debug_assert!(dot_define.parts.len() > 1);
 
-        let mut current_part_member_expression = Some(member);
-        let mut cur_part_name = &member.property.name;
-
-        for (i, part) in dot_define.parts.iter().enumerate().rev() {
-            if cur_part_name.as_str() != part {
+        // Match parts from last to first, e.g. `NODE_ENV`, `env`, then `process`
+        let Some((root, parts)) = dot_define.parts.split_first() else { return false };
+        let mut member = member;
+        for (i, part) in parts.iter().enumerate().rev() {
+            if member.property.name.as_str() != part {
                 return false;
             }
-
             if i == 0 {
                 break;
             }
-
-            current_part_member_expression = if let Some(member) = current_part_member_expression {
-                match &member.object {
-                    Expression::StaticMemberExpression(member) => {
-                        cur_part_name = &member.property.name;
-                        Some(member)
-                    }
-                    Expression::Identifier(ident) => {
-                        if !ident.is_global_reference(symbols) {
-                            return false;
-                        }
-                        cur_part_name = &ident.name;
-                        None
-                    }
-                    _ => None,
-                }
-            } else {
+            let Expression::StaticMemberExpression(object) = &member.object else {
                 return false;
             };
+            member = object;
         }
 
-        true
+        // Root of the path must be a global, e.g. not `foo.process.env.NODE_ENV`
+        matches!(&member.object, Expression::Identifier(ident)
+            if ident.name.as_str() == root && ident.is_global_reference(symbols))
+    }
+}
+
+/// Create references for identifiers in a replacement value, which is parsed without semantic data.
+struct ValueReferences<'a, 'c> {
+    ctx: &'c mut TraverseCtx<'a>,
+}
+
+impl<'a, 'c> VisitMut<'a> for ValueReferences<'a, 'c> {
+    fn visit_identifier_reference(&mut self, ident: &mut IdentifierReference<'a>) {
+        let reference_id = self
+            .ctx
+            .create_reference_in_current_scope(ident.name.to_compact_str(), ReferenceFlags::Read);
+        ident.reference_id.set(Some(reference_id));
     }
 }
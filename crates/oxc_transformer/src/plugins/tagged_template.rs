@@ -0,0 +1,131 @@
+//! Tagged Template Hooks
+//!
+//! Rewrites tagged templates with user-provided hooks, keyed by tag name.
+//! This lets CSS-in-JS and GraphQL tooling replace e.g. `` css`...` `` or `` gql`...` ``
+//! with a pre-compiled value at build time.
+//!
+//! Hooks run before all other transforms except TypeScript, so they always see the tagged template
+//! as written, before any lowering.
+//!
+//! ## Example
+//!
+//! ```rs
+//! let tagged_templates = TaggedTemplateOptions::default().with_hook("gql", |expr, ctx| {
+//!     let source = expr.quasi.quasis[0].value.raw.clone();
+//!     Some(ctx.ast.expression_string_literal(SPAN, source))
+//! });
+//! ```
+//!
+//! Input:
+//! ```js
+//! const query = gql`query { user }`;
+//! ```
+//!
+//! Output:
+//! ```js
+//! const query = "query { user }";
+//! ```
+
+use std::{fmt, sync::Arc};
+
+use oxc_ast::ast::*;
+use oxc_span::CompactStr;
+use oxc_traverse::{Traverse, TraverseCtx};
+
+/// Hook which rewrites a tagged template.
+///
+/// Returns the replacement expression, or `None` to leave the tagged template unchanged.
+pub type TaggedTemplateHook = Arc<
+    dyn for<'a> Fn(
+            &mut TaggedTemplateExpression<'a>,
+            &mut TraverseCtx<'a>,
+        ) -> Option<Expression<'a>>
+        + Send
+        + Sync,
+>;
+
+#[derive(Clone, Default)]
+pub struct TaggedTemplateOptions {
+    hooks: Vec<(CompactStr, TaggedTemplateHook)>,
+}
+
+impl TaggedTemplateOptions {
+    /// Add a hook for tagged templates with tag `tag`.
+    ///
+    /// `tag` is either an identifier (`gql`) or a dotted path (`styled.div`).
+    #[must_use]
+    pub fn with_hook<F>(mut self, tag: &str, hook: F) -> Self
+    where
+        F: for<'a> Fn(
+                &mut TaggedTemplateExpression<'a>,
+                &mut TraverseCtx<'a>,
+            ) -> Option<Expression<'a>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.hooks.push((CompactStr::from(tag), Arc::new(hook)));
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.hooks.is_empty()
+    }
+
+    fn find_hook(&self, tag: &Expression) -> Option<&TaggedTemplateHook> {
+        self.hooks.iter().find(|(name, _)| is_tag(tag, name)).map(|(_, hook)| hook)
+    }
+}
+
+impl fmt::Debug for TaggedTemplateOptions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TaggedTemplateOptions")
+            .field("hooks", &self.hooks.iter().map(|(name, _)| name).collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+pub struct TaggedTemplates {
+    options: TaggedTemplateOptions,
+}
+
+impl TaggedTemplates {
+    pub fn new(options: TaggedTemplateOptions) -> Self {
+        Self { options }
+    }
+}
+
+impl<'a> Traverse<'a> for TaggedTemplates {
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.is_empty() {
+            return;
+        }
+        let Expression::TaggedTemplateExpression(tagged_expr) = expr else { return };
+        let Some(hook) = self.options.find_hook(&tagged_expr.tag) else { return };
+        let Some(replacement) = hook(tagged_expr, ctx) else { return };
+
+        // Tag is removed, unless hook moved it into the replacement
+        let mut tag = &tagged_expr.tag;
+        while let Expression::StaticMemberExpression(member_expr) = tag {
+            tag = &member_expr.object;
+        }
+        if let Expression::Identifier(ident) = tag {
+            ctx.delete_reference_for_identifier(ident);
+        }
+
+        *expr = replacement;
+    }
+}
+
+/// Check if `expr` is `name`, e.g. `gql` or `styled.div`.
+fn is_tag(expr: &Expression, name: &str) -> bool {
+    match expr {
+        Expression::Identifier(ident) => ident.name == name,
+        Expression::StaticMemberExpression(member_expr) => {
+            name.rsplit_once('.').is_some_and(|(object, property)| {
+                member_expr.property.name == property && is_tag(&member_expr.object, object)
+            })
+        }
+        _ => false,
+    }
+}
@@ -0,0 +1,322 @@
+//! Auto-Accessors
+//!
+//! This plugin transforms `accessor` class fields into a private field with a getter and setter.
+//!
+//! > Auto-accessors are part of the decorators proposal.
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! class C {
+//!   accessor x = 1;
+//!   static accessor y;
+//!   static getY() {
+//!     return C.y;
+//!   }
+//! }
+//! ```
+//!
+//! Output:
+//! ```js
+//! class C {
+//!   #_x = 1;
+//!   get x() {
+//!     return this.#_x;
+//!   }
+//!   set x(_value) {
+//!     this.#_x = _value;
+//!   }
+//!   static #_y;
+//!   static get y() {
+//!     return this.#_y;
+//!   }
+//!   static set y(_value2) {
+//!     this.#_y = _value2;
+//!   }
+//!   static getY() {
+//!     return C.y;
+//!   }
+//! }
+//! ```
+//!
+//! ## Implementation
+//!
+//! The private backing field replaces the auto-accessor in the class body, so its initializer
+//! is evaluated in the same order relative to other fields and static blocks.
+//! The getter and setter follow it.
+//!
+//! For a `static` auto-accessor, the backing field is a static private field on the class
+//! constructor, and the getter and setter are static. Same as native auto-accessors,
+//! accessing it through a subclass (`Sub.y`) throws, as the subclass doesn't have the field.
+//!
+//! Names of backing fields don't clash with any private name used in the class body.
+//! A computed key is evaluated once, and stored in a temporary variable for the setter.
+//!
+//! Auto-accessors with decorators are not transformed.
+//!
+//! ## References:
+//! * Decorators proposal: <https://github.com/tc39/proposal-decorators#class-auto-accessors>
+
+use rustc_hash::FxHashSet;
+
+use oxc_allocator::CloneIn;
+use oxc_ast::{ast::*, Visit, NONE};
+use oxc_semantic::{ScopeFlags, ScopeId, SymbolFlags};
+use oxc_span::SPAN;
+use oxc_syntax::{identifier::is_identifier_name, operator::AssignmentOperator};
+use oxc_traverse::{Traverse, TraverseCtx};
+
+use crate::TransformCtx;
+
+pub struct AutoAccessors<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
+}
+
+impl<'a, 'ctx> AutoAccessors<'a, 'ctx> {
+    pub fn new(ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self { ctx }
+    }
+}
+
+impl<'a, 'ctx> Traverse<'a> for AutoAccessors<'a, 'ctx> {
+    fn enter_class_body(&mut self, body: &mut ClassBody<'a>, ctx: &mut TraverseCtx<'a>) {
+        if !body.body.iter().any(Self::is_auto_accessor) {
+            return;
+        }
+
+        let mut collector = PrivateNamesCollector::default();
+        collector.visit_class_body(body);
+        let mut private_names = collector.names;
+
+        let elements = ctx.ast.move_vec(&mut body.body);
+        let mut new_elements = ctx.ast.vec_with_capacity(elements.len());
+        for element in elements {
+            match element {
+                ClassElement::AccessorProperty(property)
+                    if Self::is_auto_accessor_property(&property) =>
+                {
+                    let property = property.unbox();
+                    let [field, getter, setter] =
+                        self.transform_auto_accessor(property, &mut private_names, ctx);
+                    new_elements.extend([field, getter, setter]);
+                }
+                element => new_elements.push(element),
+            }
+        }
+        body.body = new_elements;
+    }
+}
+
+impl<'a, 'ctx> AutoAccessors<'a, 'ctx> {
+    fn is_auto_accessor(element: &ClassElement<'a>) -> bool {
+        matches!(element, ClassElement::AccessorProperty(property) if Self::is_auto_accessor_property(property))
+    }
+
+    fn is_auto_accessor_property(property: &AccessorProperty<'a>) -> bool {
+        property.r#type == AccessorPropertyType::AccessorProperty && property.decorators.is_empty()
+    }
+
+    /// `accessor x = 1` -> `#_x = 1; get x() { return this.#_x; } set x(_value) { this.#_x = _value; }`
+    fn transform_auto_accessor(
+        &self,
+        property: AccessorProperty<'a>,
+        private_names: &mut FxHashSet<String>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> [ClassElement<'a>; 3] {
+        let AccessorProperty { span, key, value, computed, r#static, .. } = property;
+
+        let storage_name = Self::create_storage_name(&key, private_names, ctx);
+        let (getter_key, setter_key) = self.create_accessor_keys(key, computed, ctx);
+
+        // `#_x = 1`
+        let field = ctx.ast.class_element_property_definition(
+            PropertyDefinitionType::PropertyDefinition,
+            span,
+            ctx.ast.vec(),
+            ctx.ast.property_key_private_identifier(SPAN, storage_name.clone()),
+            value,
+            false,
+            r#static,
+            false,
+            false,
+            false,
+            false,
+            false,
+            NONE,
+            None,
+        );
+
+        // `get x() { return this.#_x; }`
+        let getter = {
+            let scope_id = ctx.create_child_scope_of_current(
+                ScopeFlags::Function | ScopeFlags::GetAccessor | ScopeFlags::StrictMode,
+            );
+            let storage = Self::create_storage_member(storage_name.clone(), ctx);
+            let statement = ctx.ast.statement_return(SPAN, Some(Expression::from(storage)));
+            let params = ctx.ast.formal_parameters(
+                SPAN,
+                FormalParameterKind::UniqueFormalParameters,
+                ctx.ast.vec(),
+                NONE,
+            );
+            Self::create_method(
+                getter_key,
+                MethodDefinitionKind::Get,
+                computed,
+                r#static,
+                params,
+                statement,
+                scope_id,
+                ctx,
+            )
+        };
+
+        // `set x(_value) { this.#_x = _value; }`
+        let setter = {
+            let scope_id = ctx.create_child_scope_of_current(
+                ScopeFlags::Function | ScopeFlags::SetAccessor | ScopeFlags::StrictMode,
+            );
+            let binding = ctx.generate_uid("value", scope_id, SymbolFlags::FunctionScopedVariable);
+            let storage = Self::create_storage_member(storage_name, ctx);
+            let assignment = ctx.ast.expression_assignment(
+                SPAN,
+                AssignmentOperator::Assign,
+                AssignmentTarget::from(storage),
+                binding.create_read_expression(ctx),
+            );
+            let statement = ctx.ast.statement_expression(SPAN, assignment);
+            let params = ctx.ast.formal_parameters(
+                SPAN,
+                FormalParameterKind::UniqueFormalParameters,
+                ctx.ast.vec1(
+                    ctx.ast.plain_formal_parameter(SPAN, binding.create_binding_pattern(ctx)),
+                ),
+                NONE,
+            );
+            Self::create_method(
+                setter_key,
+                MethodDefinitionKind::Set,
+                computed,
+                r#static,
+                params,
+                statement,
+                scope_id,
+                ctx,
+            )
+        };
+
+        [field, getter, setter]
+    }
+
+    /// Name of the private backing field, which is not used by any other private name in the class.
+    fn create_storage_name(
+        key: &PropertyKey<'a>,
+        private_names: &mut FxHashSet<String>,
+        ctx: &TraverseCtx<'a>,
+    ) -> Atom<'a> {
+        let base = key
+            .static_name()
+            .or_else(|| key.private_name().map(|name| name.as_str().into()))
+            .filter(|name| is_identifier_name(name))
+            .unwrap_or_else(|| "accessor".into());
+        let base = format!("_{base}");
+        let mut name = base.clone();
+        let mut i = 2;
+        while private_names.contains(&name) {
+            name = format!("{base}{i}");
+            i += 1;
+        }
+        private_names.insert(name.clone());
+        ctx.ast.atom(&name)
+    }
+
+    /// Keys of the getter and setter.
+    ///
+    /// A computed key which may not be the same when evaluated twice is stored in a temp var:
+    /// `get [_key = key()]() {}` and `set [_key](_value) {}`.
+    fn create_accessor_keys(
+        &self,
+        key: PropertyKey<'a>,
+        computed: bool,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> (PropertyKey<'a>, PropertyKey<'a>) {
+        if !computed || key.as_expression().is_some_and(Expression::is_literal) {
+            let setter_key = key.clone_in(ctx.ast.allocator);
+            return (key, setter_key);
+        }
+
+        let key = key.into_expression();
+        let parent_scope_id = ctx.scopes().get_parent_id(ctx.current_scope_id()).unwrap();
+        let binding = ctx.generate_uid_based_on_node(
+            &key,
+            parent_scope_id,
+            SymbolFlags::FunctionScopedVariable,
+        );
+        self.ctx.var_declarations.insert_var(&binding, None, ctx);
+        let getter_key = ctx.ast.expression_assignment(
+            SPAN,
+            AssignmentOperator::Assign,
+            binding.create_write_target(ctx),
+            key,
+        );
+        (PropertyKey::from(getter_key), PropertyKey::from(binding.create_read_expression(ctx)))
+    }
+
+    /// `this.#_x`
+    fn create_storage_member(name: Atom<'a>, ctx: &TraverseCtx<'a>) -> MemberExpression<'a> {
+        ctx.ast.member_expression_private_field_expression(
+            SPAN,
+            ctx.ast.expression_this(SPAN),
+            ctx.ast.private_identifier(SPAN, name),
+            false,
+        )
+    }
+
+    #[expect(clippy::too_many_arguments)]
+    fn create_method(
+        key: PropertyKey<'a>,
+        kind: MethodDefinitionKind,
+        computed: bool,
+        r#static: bool,
+        params: FormalParameters<'a>,
+        statement: Statement<'a>,
+        scope_id: ScopeId,
+        ctx: &TraverseCtx<'a>,
+    ) -> ClassElement<'a> {
+        let body = ctx.ast.function_body(SPAN, ctx.ast.vec(), ctx.ast.vec1(statement));
+        let function = ctx.ast.alloc_plain_function_with_scope_id(
+            FunctionType::FunctionExpression,
+            SPAN,
+            None,
+            params,
+            body,
+            scope_id,
+        );
+        ctx.ast.class_element_method_definition(
+            MethodDefinitionType::MethodDefinition,
+            SPAN,
+            ctx.ast.vec(),
+            key,
+            function,
+            kind,
+            computed,
+            r#static,
+            false,
+            false,
+            None,
+        )
+    }
+}
+
+/// Collects all private names declared or referenced in a class body, including nested classes.
+#[derive(Default)]
+struct PrivateNamesCollector {
+    names: FxHashSet<String>,
+}
+
+impl<'a> Visit<'a> for PrivateNamesCollector {
+    fn visit_private_identifier(&mut self, ident: &PrivateIdentifier<'a>) {
+        self.names.insert(ident.name.to_string());
+    }
+}
@@ -0,0 +1,38 @@
+//! Decorators
+//!
+//! Decorators are not transformed yet. They are not supported by any engine, so leaving them
+//! in the output produces code which can't be run. Unless decorators are enabled with
+//! [`ProposalOptions::decorators`], each decorator is reported as an error.
+//!
+//! When enabled, decorators are kept in the output as written, for a later tool to transform them.
+//!
+//! [`ProposalOptions::decorators`]: super::ProposalOptions::decorators
+
+use oxc_ast::ast::Decorator;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_span::Span;
+use oxc_traverse::{Traverse, TraverseCtx};
+
+use crate::TransformCtx;
+
+pub struct Decorators<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
+}
+
+impl<'a, 'ctx> Decorators<'a, 'ctx> {
+    pub fn new(ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self { ctx }
+    }
+}
+
+impl<'a, 'ctx> Traverse<'a> for Decorators<'a, 'ctx> {
+    fn enter_decorator(&mut self, decorator: &mut Decorator<'a>, _ctx: &mut TraverseCtx<'a>) {
+        self.ctx.error(decorators_not_enabled(decorator.span));
+    }
+}
+
+fn decorators_not_enabled(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error("Decorators are not enabled")
+        .with_help("Enable the `decorators` option to keep decorators in the output as written")
+        .with_label(span)
+}
@@ -0,0 +1,374 @@
+//! Explicit Resource Management
+//!
+//! This plugin transforms `using` and `await using` declarations, which dispose of resources
+//! when the scope they are declared in is exited.
+//!
+//! Statements of a block containing `using` declarations are wrapped in a `try` statement,
+//! and resources are added to a disposal context created by the `usingCtx` helper.
+//! The context disposes of resources in reverse order in the `finally` block, so they are disposed
+//! when the block is exited normally, or by `break`, `continue`, `return` or a thrown error.
+//!
+//! `using` declarations in the head of a `for-of` loop are moved into the loop body,
+//! so each iteration's resource is disposed at the end of that iteration.
+//!
+//! An error thrown in the block is stored in the context (`_usingCtx.e = _`), so disposing is
+//! aware of it. If disposing a resource throws too, the context wraps both errors in a
+//! `SuppressedError` (`error` is the disposal error, `suppressed` is the previous error),
+//! so with several resources the chain is built in reverse declaration order.
+//! `usingCtx` falls back to an `Error` with the same `name`, `error` and `suppressed` properties
+//! if `SuppressedError` is not available.
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! {
+//!   using x = getResource();
+//!   doSomethingWith(x);
+//! }
+//!
+//! for (await using y of resources) {
+//!   doSomethingWith(y);
+//! }
+//! ```
+//!
+//! Output:
+//! ```js
+//! {
+//!   try {
+//!     var _usingCtx = babelHelpers.usingCtx();
+//!     const x = _usingCtx.u(getResource());
+//!     doSomethingWith(x);
+//!   } catch (_) {
+//!     _usingCtx.e = _;
+//!   } finally {
+//!     _usingCtx.d();
+//!   }
+//! }
+//!
+//! for (const _y of resources) {
+//!   try {
+//!     var _usingCtx2 = babelHelpers.usingCtx();
+//!     const y = _usingCtx2.a(_y);
+//!     doSomethingWith(y);
+//!   } catch (_) {
+//!     _usingCtx2.e = _;
+//!   } finally {
+//!     await _usingCtx2.d();
+//!   }
+//! }
+//! ```
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-proposal-explicit-resource-management](https://babel.dev/docs/babel-plugin-proposal-explicit-resource-management).
+//!
+//! ## References:
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/main/packages/babel-plugin-proposal-explicit-resource-management>
+//! * Explicit Resource Management TC39 proposal: <https://github.com/tc39/proposal-explicit-resource-management>
+
+use std::mem;
+
+use oxc_allocator::{Box as ArenaBox, Vec as ArenaVec};
+use oxc_ast::{ast::*, NONE};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_ecmascript::BoundNames;
+use oxc_semantic::{ScopeFlags, ScopeId, SymbolFlags, SymbolId};
+use oxc_span::{GetSpan, Span, SPAN};
+use oxc_syntax::operator::AssignmentOperator;
+use oxc_traverse::{Ancestor, BoundIdentifier, Traverse, TraverseCtx};
+
+use crate::{common::helper_loader::Helper, TransformCtx};
+
+pub struct ExplicitResourceManagement<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
+
+    /// `using` bindings removed from `for-of` heads, to be declared in the loop body.
+    for_of_bindings: Vec<ForOfUsingBinding<'a>>,
+}
+
+struct ForOfUsingBinding<'a> {
+    /// Scope of the `for-of` statement
+    scope_id: ScopeId,
+    kind: VariableDeclarationKind,
+    /// `x` in `for (using x of y)`
+    binding: BindingPattern<'a>,
+    /// `_x` in `for (const _x of y)`
+    temp: BoundIdentifier<'a>,
+}
+
+impl<'a, 'ctx> ExplicitResourceManagement<'a, 'ctx> {
+    pub fn new(ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self { ctx, for_of_bindings: vec![] }
+    }
+}
+
+impl<'a, 'ctx> Traverse<'a> for ExplicitResourceManagement<'a, 'ctx> {
+    /// `for (using x of y) {}` -> `for (const _x of y) {}`
+    ///
+    /// `x` is declared in the loop body in `enter_statement`.
+    fn enter_for_of_statement(&mut self, stmt: &mut ForOfStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        let scope_id = stmt.scope_id();
+        let ForStatementLeft::VariableDeclaration(decl) = &mut stmt.left else { return };
+        if !is_using(decl.kind) {
+            return;
+        }
+        // `using` declarations in `for-of` heads have a single identifier binding
+        let Some(declarator) = decl.declarations.first_mut() else { return };
+        let BindingPatternKind::BindingIdentifier(ident) = &declarator.id.kind else { return };
+
+        let temp = ctx.generate_uid(
+            &ident.name,
+            scope_id,
+            SymbolFlags::BlockScopedVariable | SymbolFlags::ConstVariable,
+        );
+        let binding = mem::replace(&mut declarator.id, temp.create_binding_pattern(ctx));
+        declarator.kind = VariableDeclarationKind::Const;
+        let kind = mem::replace(&mut decl.kind, VariableDeclarationKind::Const);
+
+        self.for_of_bindings.push(ForOfUsingBinding { scope_id, kind, binding, temp });
+    }
+
+    /// `for (const _x of y) body` -> `for (const _x of y) { using x = _x; body }`
+    fn enter_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Ancestor::ForOfStatementBody(for_of) = ctx.parent() else { return };
+        let for_of_scope_id = for_of.scope_id().get().unwrap();
+        if self.for_of_bindings.last().map_or(true, |binding| binding.scope_id != for_of_scope_id) {
+            return;
+        }
+        let ForOfUsingBinding { kind, binding, temp, .. } = self.for_of_bindings.pop().unwrap();
+
+        if !matches!(stmt, Statement::BlockStatement(_)) {
+            let scope_id = ctx.insert_scope_below_statement(stmt, ScopeFlags::empty());
+            let body = ctx.ast.move_statement(stmt);
+            *stmt = Statement::BlockStatement(ctx.ast.alloc_block_statement_with_scope_id(
+                SPAN,
+                ctx.ast.vec1(body),
+                scope_id,
+            ));
+        }
+        let Statement::BlockStatement(block) = stmt else { unreachable!() };
+
+        // Move `x` into the loop body
+        let BindingPatternKind::BindingIdentifier(ident) = &binding.kind else { unreachable!() };
+        Self::move_binding(ident.symbol_id(), &ident.name, block.scope_id(), ctx);
+
+        let declarator = ctx.ast.variable_declarator(
+            SPAN,
+            kind,
+            binding,
+            Some(temp.create_read_expression(ctx)),
+            false,
+        );
+        let decl = ctx.ast.alloc_variable_declaration(SPAN, kind, ctx.ast.vec1(declarator), false);
+        block.body.insert(0, Statement::VariableDeclaration(decl));
+    }
+
+    /// Wrap statements containing `using` declarations in `try` / `catch` / `finally`.
+    fn enter_statements(
+        &mut self,
+        stmts: &mut ArenaVec<'a, Statement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let Some(using_span) = stmts.iter().find_map(|stmt| match stmt {
+            Statement::VariableDeclaration(decl) if is_using(decl.kind) => Some(decl.span),
+            _ => None,
+        }) else {
+            return;
+        };
+
+        // Imports are hoisted, so stay outside of the `try` block. Exports cannot be moved into it.
+        let mut module_declarations = ctx.ast.vec();
+        if matches!(ctx.parent(), Ancestor::ProgramBody(_)) {
+            if let Some(stmt) = stmts.iter().find(|stmt| {
+                stmt.is_module_declaration() && !matches!(stmt, Statement::ImportDeclaration(_))
+            }) {
+                self.ctx.error(using_with_exports(using_span, stmt.span()));
+                return;
+            }
+            let (imports, rest): (Vec<_>, Vec<_>) =
+                stmts.drain(..).partition(|stmt| matches!(stmt, Statement::ImportDeclaration(_)));
+            module_declarations.extend(imports);
+            stmts.extend(rest);
+        }
+
+        let has_await_using = stmts.iter().any(
+            |stmt| matches!(stmt, Statement::VariableDeclaration(decl) if decl.kind.is_await()),
+        );
+
+        let try_scope_id = ctx.insert_scope_below_statements(stmts, ScopeFlags::empty());
+        let var_scope_id = ctx
+            .ancestor_scopes()
+            .find(|&scope_id| ctx.scopes().get_flags(scope_id).is_var())
+            .unwrap();
+        let using_ctx =
+            ctx.generate_uid("usingCtx", var_scope_id, SymbolFlags::FunctionScopedVariable);
+
+        for stmt in stmts.iter_mut() {
+            Self::move_lexical_bindings(stmt, try_scope_id, ctx);
+            if let Statement::VariableDeclaration(decl) = stmt {
+                if is_using(decl.kind) {
+                    Self::transform_using_declaration(decl, &using_ctx, ctx);
+                }
+            }
+        }
+
+        // `var _usingCtx = babelHelpers.usingCtx();`
+        let init = self.ctx.helper_call_expr(Helper::UsingCtx, ctx.ast.vec(), ctx);
+        let declarator = ctx.ast.variable_declarator(
+            SPAN,
+            VariableDeclarationKind::Var,
+            using_ctx.create_binding_pattern(ctx),
+            Some(init),
+            false,
+        );
+        let using_ctx_decl = Statement::VariableDeclaration(ctx.ast.alloc_variable_declaration(
+            SPAN,
+            VariableDeclarationKind::Var,
+            ctx.ast.vec1(declarator),
+            false,
+        ));
+
+        let mut try_body = ctx.ast.vec_with_capacity(stmts.len() + 1);
+        try_body.push(using_ctx_decl);
+        try_body.extend(stmts.drain(..));
+        let block = ctx.ast.alloc_block_statement_with_scope_id(SPAN, try_body, try_scope_id);
+
+        let handler = Self::create_catch_clause(&using_ctx, ctx);
+        let finalizer = Self::create_finally_block(&using_ctx, has_await_using, ctx);
+
+        stmts.extend(module_declarations);
+        stmts.push(ctx.ast.statement_try(SPAN, block, Some(handler), Some(finalizer)));
+    }
+}
+
+impl<'a, 'ctx> ExplicitResourceManagement<'a, 'ctx> {
+    /// `using x = y` -> `const x = _usingCtx.u(y)`
+    ///
+    /// `await using x = y` -> `const x = _usingCtx.a(y)`
+    fn transform_using_declaration(
+        decl: &mut VariableDeclaration<'a>,
+        using_ctx: &BoundIdentifier<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let method = if decl.kind.is_await() { "a" } else { "u" };
+        decl.kind = VariableDeclarationKind::Const;
+        for declarator in decl.declarations.iter_mut() {
+            declarator.kind = VariableDeclarationKind::Const;
+            declarator.id.bound_names(&mut |ident| Self::set_const_flags(ident.symbol_id(), ctx));
+            let Some(init) = declarator.init.as_mut() else { continue };
+            let callee = Self::create_using_ctx_member(using_ctx, method, ctx);
+            let arguments = ctx.ast.vec1(Argument::from(ctx.ast.move_expression(init)));
+            *init = ctx.ast.expression_call(SPAN, callee, NONE, arguments, false, false);
+        }
+    }
+
+    /// `catch (_) { _usingCtx.e = _; }`
+    fn create_catch_clause(
+        using_ctx: &BoundIdentifier<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> ArenaBox<'a, CatchClause<'a>> {
+        let catch_scope_id = ctx.create_child_scope_of_current(ScopeFlags::CatchClause);
+        let body_scope_id = ctx.create_child_scope(catch_scope_id, ScopeFlags::empty());
+        let error = ctx.generate_uid(
+            "",
+            body_scope_id,
+            SymbolFlags::CatchVariable | SymbolFlags::FunctionScopedVariable,
+        );
+        let param = ctx.ast.catch_parameter(SPAN, error.create_binding_pattern(ctx));
+
+        let object = using_ctx.create_read_expression(ctx);
+        let target = AssignmentTarget::from(ctx.ast.member_expression_static(
+            SPAN,
+            object,
+            ctx.ast.identifier_name(SPAN, "e"),
+            false,
+        ));
+        let assignment = ctx.ast.expression_assignment(
+            SPAN,
+            AssignmentOperator::Assign,
+            target,
+            error.create_read_expression(ctx),
+        );
+        let body = ctx.ast.vec1(ctx.ast.statement_expression(SPAN, assignment));
+        let body = ctx.ast.alloc_block_statement_with_scope_id(SPAN, body, body_scope_id);
+        ctx.ast.alloc_catch_clause_with_scope_id(SPAN, Some(param), body, catch_scope_id)
+    }
+
+    /// `finally { _usingCtx.d(); }` or `finally { await _usingCtx.d(); }`
+    fn create_finally_block(
+        using_ctx: &BoundIdentifier<'a>,
+        has_await_using: bool,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> ArenaBox<'a, BlockStatement<'a>> {
+        let scope_id = ctx.create_child_scope_of_current(ScopeFlags::empty());
+        let callee = Self::create_using_ctx_member(using_ctx, "d", ctx);
+        let mut dispose = ctx.ast.expression_call(SPAN, callee, NONE, ctx.ast.vec(), false, false);
+        if has_await_using {
+            dispose = ctx.ast.expression_await(SPAN, dispose);
+        }
+        let body = ctx.ast.vec1(ctx.ast.statement_expression(SPAN, dispose));
+        ctx.ast.alloc_block_statement_with_scope_id(SPAN, body, scope_id)
+    }
+
+    /// `_usingCtx.<method>`
+    fn create_using_ctx_member(
+        using_ctx: &BoundIdentifier<'a>,
+        method: &'static str,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let object = using_ctx.create_read_expression(ctx);
+        let property = ctx.ast.identifier_name(SPAN, method);
+        Expression::from(ctx.ast.member_expression_static(SPAN, object, property, false))
+    }
+
+    /// Move bindings of lexical declarations in `stmt` into the `try` block.
+    fn move_lexical_bindings(stmt: &Statement<'a>, to: ScopeId, ctx: &mut TraverseCtx<'a>) {
+        let mut move_binding = |ident: &BindingIdentifier<'a>| {
+            Self::move_binding(ident.symbol_id(), &ident.name, to, ctx);
+        };
+        match stmt {
+            Statement::VariableDeclaration(decl) if decl.kind != VariableDeclarationKind::Var => {
+                for declarator in &decl.declarations {
+                    declarator.id.bound_names(&mut move_binding);
+                }
+            }
+            Statement::ClassDeclaration(class) => {
+                if let Some(ident) = &class.id {
+                    move_binding(ident);
+                }
+            }
+            Statement::FunctionDeclaration(func) => {
+                if let Some(ident) = &func.id {
+                    move_binding(ident);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Move binding of `symbol_id` to `to` scope.
+    fn move_binding(symbol_id: SymbolId, name: &str, to: ScopeId, ctx: &mut TraverseCtx<'a>) {
+        let from = ctx.symbols().get_scope_id(symbol_id);
+        ctx.scopes_mut().move_binding(from, to, name);
+        ctx.symbols_mut().set_scope_id(symbol_id, to);
+    }
+
+    /// `using` declarations are bound like `var` declarations. Convert to a `const` binding.
+    fn set_const_flags(symbol_id: SymbolId, ctx: &mut TraverseCtx<'a>) {
+        *ctx.symbols_mut().get_flags_mut(symbol_id) =
+            SymbolFlags::BlockScopedVariable | SymbolFlags::ConstVariable;
+    }
+}
+
+/// `using` or `await using`
+fn is_using(kind: VariableDeclarationKind) -> bool {
+    matches!(kind, VariableDeclarationKind::Using | VariableDeclarationKind::AwaitUsing)
+}
+
+fn using_with_exports(using_span: Span, export_span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error(
+        "Transforming `using` declarations at the top level of a module with exports is not supported",
+    )
+    .with_labels([using_span, export_span])
+}
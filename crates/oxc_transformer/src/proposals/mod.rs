@@ -0,0 +1,72 @@
+use oxc_allocator::Vec as ArenaVec;
+use oxc_ast::ast::*;
+use oxc_traverse::{Traverse, TraverseCtx};
+
+use crate::TransformCtx;
+
+mod auto_accessors;
+mod decorators;
+mod explicit_resource_management;
+mod options;
+
+pub use auto_accessors::AutoAccessors;
+pub use decorators::Decorators;
+pub use explicit_resource_management::ExplicitResourceManagement;
+pub use options::ProposalOptions;
+
+pub struct Proposals<'a, 'ctx> {
+    options: ProposalOptions,
+
+    // Plugins
+    auto_accessors: AutoAccessors<'a, 'ctx>,
+    decorators: Decorators<'a, 'ctx>,
+    explicit_resource_management: ExplicitResourceManagement<'a, 'ctx>,
+}
+
+impl<'a, 'ctx> Proposals<'a, 'ctx> {
+    pub fn new(options: ProposalOptions, ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self {
+            auto_accessors: AutoAccessors::new(ctx),
+            decorators: Decorators::new(ctx),
+            explicit_resource_management: ExplicitResourceManagement::new(ctx),
+            options,
+        }
+    }
+}
+
+impl<'a, 'ctx> Traverse<'a> for Proposals<'a, 'ctx> {
+    fn enter_class_body(&mut self, body: &mut ClassBody<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.auto_accessors {
+            self.auto_accessors.enter_class_body(body, ctx);
+        }
+    }
+
+    fn enter_decorator(&mut self, decorator: &mut Decorator<'a>, ctx: &mut TraverseCtx<'a>) {
+        // Decorators which are not enabled are reported as errors
+        if !self.options.decorators {
+            self.decorators.enter_decorator(decorator, ctx);
+        }
+    }
+
+    fn enter_for_of_statement(&mut self, stmt: &mut ForOfStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.explicit_resource_management {
+            self.explicit_resource_management.enter_for_of_statement(stmt, ctx);
+        }
+    }
+
+    fn enter_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.explicit_resource_management {
+            self.explicit_resource_management.enter_statement(stmt, ctx);
+        }
+    }
+
+    fn enter_statements(
+        &mut self,
+        stmts: &mut ArenaVec<'a, Statement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if self.options.explicit_resource_management {
+            self.explicit_resource_management.enter_statements(stmts, ctx);
+        }
+    }
+}
@@ -0,0 +1,20 @@
+use serde::Deserialize;
+
+/// Options for transforming ECMAScript proposals which are not yet part of a published edition.
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct ProposalOptions {
+    /// Transform `using` and `await using` declarations.
+    #[serde(skip)]
+    pub explicit_resource_management: bool,
+
+    /// Keep decorators in the output as written.
+    ///
+    /// Decorators are not transformed, so when this is `false` they are reported as errors.
+    #[serde(skip)]
+    pub decorators: bool,
+
+    /// Transform `accessor` class fields into private fields with a getter and setter.
+    #[serde(skip)]
+    pub auto_accessors: bool,
+}
@@ -0,0 +1,85 @@
+//! Lowering of `s` (dotAll) flag.
+//!
+//! With `s` flag, `.` matches any character including line terminators, which is the same as
+//! `[\s\S]`. So `.` is replaced with `[\s\S]`, and `s` flag is removed:
+//!
+//! ```js
+//! /a.b/s
+//! ```
+//!
+//! ```js
+//! /a[\s\S]b/
+//! ```
+//!
+//! `.` in a character class (`[.]`) and escaped `\.` are not `Dot` terms, so are left as is.
+//! Nor is `.` in a group which disables the flag with a modifier (`(?-s:.)`).
+
+use oxc_allocator::{Allocator, Box as ArenaBox, Vec as ArenaVec};
+use oxc_regular_expression::ast::{
+    CharacterClass, CharacterClassContents, CharacterClassContentsKind, CharacterClassEscape,
+    CharacterClassEscapeKind, Disjunction, Pattern, Term,
+};
+use oxc_span::{Span, SPAN};
+
+/// Replace `.` with `[\s\S]` in `pattern`.
+pub(super) fn lower_dot_all_flag<'a>(pattern: &mut Pattern<'a>, allocator: &'a Allocator) {
+    replace_dots_in_disjunction(&mut pattern.body, allocator);
+    // Pattern is no longer the same as the source text, so it must be printed from the AST
+    pattern.span = SPAN;
+}
+
+fn replace_dots_in_disjunction<'a>(disjunction: &mut Disjunction<'a>, allocator: &'a Allocator) {
+    for alternative in disjunction.body.iter_mut() {
+        for term in alternative.body.iter_mut() {
+            replace_dots_in_term(term, allocator);
+        }
+    }
+}
+
+fn replace_dots_in_term<'a>(term: &mut Term<'a>, allocator: &'a Allocator) {
+    match term {
+        Term::Dot(dot) => *term = create_any_character_class(dot.span, allocator),
+        Term::CapturingGroup(group) => replace_dots_in_disjunction(&mut group.body, allocator),
+        Term::IgnoreGroup(group) => {
+            // `(?-s:.)`
+            let disables_dot_all = group
+                .modifiers
+                .as_ref()
+                .and_then(|modifiers| modifiers.disabling.as_ref())
+                // `sticky` is the `s` modifier
+                .is_some_and(|modifier| modifier.sticky);
+            if !disables_dot_all {
+                replace_dots_in_disjunction(&mut group.body, allocator);
+            }
+        }
+        Term::LookAroundAssertion(assertion) => {
+            replace_dots_in_disjunction(&mut assertion.body, allocator);
+        }
+        Term::Quantifier(quantifier) => replace_dots_in_term(&mut quantifier.body, allocator),
+        _ => {}
+    }
+}
+
+/// `[\s\S]`
+fn create_any_character_class(span: Span, allocator: &Allocator) -> Term<'_> {
+    let escape = |kind| {
+        CharacterClassContents::CharacterClassEscape(ArenaBox::new_in(
+            CharacterClassEscape { span, kind },
+            allocator,
+        ))
+    };
+    let body = ArenaVec::from_iter_in(
+        [escape(CharacterClassEscapeKind::S), escape(CharacterClassEscapeKind::NegativeS)],
+        allocator,
+    );
+    Term::CharacterClass(ArenaBox::new_in(
+        CharacterClass {
+            span,
+            negative: false,
+            strings: false,
+            kind: CharacterClassContentsKind::Union,
+            body,
+        },
+        allocator,
+    ))
+}
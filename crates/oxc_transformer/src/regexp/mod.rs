@@ -20,12 +20,24 @@
 //! - @babel/plugin-transform-dotall-regex: <https://babeljs.io/docs/en/babel-plugin-transform-dotall-regex>
 //! - Spec: ECMAScript 2018: <https://262.ecma-international.org/9.0/#sec-get-regexp.prototype.dotAll>
 //!
+//! Lowered rather than passed to `new RegExp()`: `/a.b/s` -> `/a[\s\S]b/`. See [`dot_all_flag`].
+//!
 //! #### Lookbehind assertions (`/(?<=x)/` and `/(?<!x)/`)
 //! - Implementation: Same as esbuild's handling
 //!
+//! Lookbehind assertions can't be polyfilled by a `RegExp` polyfill, so by default they are
+//! reported as errors instead. See [`LookBehindAssertionsMode`].
+//!
 //! #### Named capture groups (`(?<name>x)`)
 //! - @babel/plugin-transform-named-capturing-groups-regex: <https://babeljs.io/docs/en/babel-plugin-transform-named-capturing-groups-regex>
 //!
+//! Unlike the other features, named groups are lowered rather than passed to `new RegExp()`:
+//! `/(?<a>b)\k<a>/` -> `_wrapRegExp(/(b)\1/, { a: 1 })`.
+//! `_wrapRegExp` helper provides `match.groups` and `$<a>` in `String.prototype.replace`.
+//! If the RegExp also has unsupported flags, the lowered pattern is passed to `new RegExp()` with
+//! the flags kept, and then wrapped: `/(?<a>b)/u` -> `_wrapRegExp(new RegExp("(b)", "u"), { a: 1 })`.
+//! See [`named_capture_groups`].
+//!
 //! #### Unicode property escapes (`\p{...}` and `\P{...}`)
 //! - @babel/plugin-transform-unicode-property-regex: <https://babeljs.io/docs/en/babel-plugin-proposal-unicode-property-regex>
 //!
@@ -40,6 +52,10 @@
 //! - @babel/plugin-transform-unicode-sets-regex: <https://babeljs.io/docs/en/babel-plugin-proposal-unicode-sets-regex>
 //! - TC39 Proposal: <https://github.com/tc39/proposal-regexp-set-notation>
 //!
+//! Lowered to `u` flag rather than passed to `new RegExp()`:
+//! `/[\p{L}&&\p{ASCII}]/v` -> `/(?:(?=\p{L})\p{ASCII})/u`. See [`set_notation`].
+//! Classes with strings (`[\q{abc}]`) can't be lowered, and are reported as errors.
+//!
 //! TODO(improve-on-babel): We could convert to plain `RegExp(...)` instead of `new RegExp(...)`.
 //! TODO(improve-on-babel): When flags is empty, we could output `RegExp("(?<=x)")` instead of `RegExp("(?<=x)", "")`.
 //! (actually these would be improvements on ESBuild, not Babel)
@@ -47,36 +63,44 @@
 use std::borrow::Cow;
 
 use oxc_ast::{ast::*, NONE};
-use oxc_diagnostics::Result;
+use oxc_diagnostics::{OxcDiagnostic, Result};
 use oxc_regular_expression::ast::{
-    CharacterClass, CharacterClassContents, LookAroundAssertionKind, Pattern, Term,
+    CharacterClass, CharacterClassContents, Disjunction, LookAroundAssertionKind, Pattern, Term,
 };
 use oxc_semantic::ReferenceFlags;
 use oxc_span::{Atom, SPAN};
+use oxc_syntax::{identifier::is_identifier_name, number::NumberBase};
 use oxc_traverse::{Traverse, TraverseCtx};
 
-use crate::TransformCtx;
+use crate::{common::helper_loader::Helper, TransformCtx};
 
+mod dot_all_flag;
+mod named_capture_groups;
 mod options;
+mod set_notation;
 
-pub use options::RegExpOptions;
+use dot_all_flag::lower_dot_all_flag;
+use named_capture_groups::{lower_named_capture_groups, NamedGroups};
+pub use options::{LookBehindAssertionsMode, RegExpOptions};
+use set_notation::lower_set_notation;
 
 pub struct RegExp<'a, 'ctx> {
     ctx: &'ctx TransformCtx<'a>,
     unsupported_flags: RegExpFlags,
+    dot_all_flag: bool,
     some_unsupported_patterns: bool,
     look_behind_assertions: bool,
+    look_behind_assertions_mode: LookBehindAssertionsMode,
     named_capture_groups: bool,
+    set_notation: bool,
     unicode_property_escapes: bool,
 }
 
 impl<'a, 'ctx> RegExp<'a, 'ctx> {
     pub fn new(options: RegExpOptions, ctx: &'ctx TransformCtx<'a>) -> Self {
         // Get unsupported flags
+        // `s` and `v` flags are not included, as they are lowered rather than passed to `new RegExp()`
         let mut unsupported_flags = RegExpFlags::empty();
-        if options.dot_all_flag {
-            unsupported_flags |= RegExpFlags::S;
-        }
         if options.sticky_flag {
             unsupported_flags |= RegExpFlags::Y;
         }
@@ -86,14 +110,14 @@ impl<'a, 'ctx> RegExp<'a, 'ctx> {
         if options.match_indices {
             unsupported_flags |= RegExpFlags::D;
         }
-        if options.set_notation {
-            unsupported_flags |= RegExpFlags::V;
-        }
 
         // Get if some unsupported patterns
         let RegExpOptions {
+            dot_all_flag,
             look_behind_assertions,
+            look_behind_assertions_mode,
             named_capture_groups,
+            set_notation,
             unicode_property_escapes,
             ..
         } = options;
@@ -104,9 +128,12 @@ impl<'a, 'ctx> RegExp<'a, 'ctx> {
         Self {
             ctx,
             unsupported_flags,
+            dot_all_flag,
             some_unsupported_patterns,
             look_behind_assertions,
+            look_behind_assertions_mode,
             named_capture_groups,
+            set_notation,
             unicode_property_escapes,
         }
     }
@@ -123,80 +150,169 @@ impl<'a, 'ctx> Traverse<'a> for RegExp<'a, 'ctx> {
         };
         let regexp = regexp.as_mut();
 
-        let flags = regexp.regex.flags;
+        let mut flags = regexp.regex.flags;
         let has_unsupported_flags = flags.intersects(self.unsupported_flags);
-        if !has_unsupported_flags {
-            if !self.some_unsupported_patterns {
-                // This RegExp has no unsupported flags, and there are no patterns which may need transforming,
-                // so there's nothing to do
+        let has_dot_all_flag = self.dot_all_flag && flags.contains(RegExpFlags::S);
+        let has_set_notation = self.set_notation && flags.contains(RegExpFlags::V);
+        if !has_unsupported_flags
+            && !has_dot_all_flag
+            && !has_set_notation
+            && !self.some_unsupported_patterns
+        {
+            // This RegExp has no unsupported flags, and there are no patterns which may need transforming,
+            // so there's nothing to do
+            return;
+        }
+
+        let mut named_groups = vec![];
+        let mut has_unsupported_pattern = false;
+        if has_dot_all_flag || has_set_notation || self.some_unsupported_patterns {
+            let Some(pattern) = self.parse_pattern(regexp, ctx) else { return };
+            if self.look_behind_assertions
+                && self.look_behind_assertions_mode == LookBehindAssertionsMode::Error
+                && disjunction_has_look_behind_assertion(&pattern.body)
+            {
+                self.ctx.error(look_behind_assertions_not_available(regexp.span));
                 return;
             }
-
-            let literal_span = regexp.span;
-            let pattern = match &mut regexp.regex.pattern {
-                RegExpPattern::Raw(raw) => {
-                    #[expect(clippy::cast_possible_truncation)]
-                    let pattern_len = raw.len() as u32;
-                    let pattern_span_start = literal_span.start + 1; // +1 to skip the opening `/`
-                    let flags_span_start = pattern_span_start + pattern_len + 1; // +1 to skip the closing `/`
-                    let flags_text = Span::new(flags_span_start, literal_span.end)
-                        .source_text(self.ctx.source_text);
-                    // Try to parse pattern
-                    match try_parse_pattern(
-                        raw,
-                        pattern_span_start,
-                        flags_text,
-                        flags_span_start,
-                        ctx,
-                    ) {
-                        Ok(pattern) => {
-                            regexp.regex.pattern = RegExpPattern::Pattern(ctx.alloc(pattern));
-                            let RegExpPattern::Pattern(pattern) = &regexp.regex.pattern else {
-                                unreachable!()
-                            };
-                            pattern
-                        }
-                        Err(error) => {
-                            regexp.regex.pattern = RegExpPattern::Invalid(raw);
-                            self.ctx.error(error);
-                            return;
-                        }
-                    }
+            if has_set_notation {
+                // `/[\p{L}&&\p{ASCII}]/v` -> `/(?:(?=\p{L})\p{ASCII})/u`
+                if let Err(error) = lower_set_notation(pattern, ctx.ast.allocator) {
+                    self.ctx.error(error);
+                    return;
                 }
+                flags.remove(RegExpFlags::V);
+                flags.insert(RegExpFlags::U);
+            }
+            if has_dot_all_flag {
+                // `/a.b/s` -> `/a[\s\S]b/`
+                lower_dot_all_flag(pattern, ctx.ast.allocator);
+                flags.remove(RegExpFlags::S);
+            }
+            if self.named_capture_groups {
+                named_groups = lower_named_capture_groups(pattern, ctx.ast.allocator);
+            }
+            has_unsupported_pattern = self.has_unsupported_regular_expression_pattern(pattern);
+        }
+        regexp.regex.flags = flags;
+
+        // `v` flag may have been lowered to `u` flag, which may be unsupported too
+        let has_unsupported_flags = flags.intersects(self.unsupported_flags);
+        if has_unsupported_flags || has_unsupported_pattern {
+            let pattern_source: Cow<'_, str> = match &regexp.regex.pattern {
+                RegExpPattern::Raw(raw) => Cow::Borrowed(raw),
+                RegExpPattern::Pattern(p) => Cow::Owned(p.to_string()),
                 RegExpPattern::Invalid(_) => return,
-                RegExpPattern::Pattern(pattern) => &**pattern,
             };
 
-            if !self.has_unsupported_regular_expression_pattern(pattern) {
-                return;
-            }
-        }
+            let callee = {
+                let symbol_id = ctx.scopes().find_binding(ctx.current_scope_id(), "RegExp");
+                let ident = ctx.create_reference_id(
+                    SPAN,
+                    Atom::from("RegExp"),
+                    symbol_id,
+                    ReferenceFlags::read(),
+                );
+                Expression::Identifier(ctx.alloc(ident))
+            };
 
-        let pattern_source: Cow<'_, str> = match &regexp.regex.pattern {
-            RegExpPattern::Raw(raw) => Cow::Borrowed(raw),
-            RegExpPattern::Pattern(p) => Cow::Owned(p.to_string()),
-            RegExpPattern::Invalid(_) => return,
-        };
+            let mut arguments = ctx.ast.vec_with_capacity(2);
+            arguments.push(Argument::from(ctx.ast.expression_string_literal(SPAN, pattern_source)));
 
-        let callee = {
-            let symbol_id = ctx.scopes().find_binding(ctx.current_scope_id(), "RegExp");
-            let ident = ctx.create_reference_id(
-                SPAN,
-                Atom::from("RegExp"),
-                symbol_id,
-                ReferenceFlags::read(),
-            );
-            Expression::Identifier(ctx.alloc(ident))
-        };
+            let flags_str = flags.to_string();
+            let flags_str = Argument::from(ctx.ast.expression_string_literal(SPAN, flags_str));
+            arguments.push(flags_str);
 
-        let mut arguments = ctx.ast.vec_with_capacity(2);
-        arguments.push(Argument::from(ctx.ast.expression_string_literal(SPAN, pattern_source)));
+            *expr = ctx.ast.expression_new(regexp.span, callee, arguments, NONE, false);
+        }
 
-        let flags_str = flags.to_string();
-        let flags_str = Argument::from(ctx.ast.expression_string_literal(SPAN, flags_str));
-        arguments.push(flags_str);
+        if !named_groups.is_empty() {
+            // `/(?<a>b)/` -> `_wrapRegExp(/(b)/, { a: 1 })`
+            let regexp = ctx.ast.move_expression(expr);
+            *expr = self.create_wrap_reg_exp_call(regexp, named_groups, ctx);
+        }
+    }
+}
 
-        *expr = ctx.ast.expression_new(regexp.span, callee, arguments, NONE);
+impl<'a, 'ctx> RegExp<'a, 'ctx> {
+    /// Parse pattern of the RegExp literal, if it's not parsed already.
+    ///
+    /// Returns `None` if the pattern is invalid.
+    fn parse_pattern<'r>(
+        &self,
+        regexp: &'r mut RegExpLiteral<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<&'r mut Pattern<'a>> {
+        if let RegExpPattern::Raw(raw) = regexp.regex.pattern {
+            let literal_span = regexp.span;
+            #[expect(clippy::cast_possible_truncation)]
+            let pattern_len = raw.len() as u32;
+            let pattern_span_start = literal_span.start + 1; // +1 to skip the opening `/`
+            let flags_span_start = pattern_span_start + pattern_len + 1; // +1 to skip the closing `/`
+            let flags_text =
+                Span::new(flags_span_start, literal_span.end).source_text(self.ctx.source_text);
+            // Try to parse pattern
+            match try_parse_pattern(raw, pattern_span_start, flags_text, flags_span_start, ctx) {
+                Ok(pattern) => {
+                    regexp.regex.pattern = RegExpPattern::Pattern(ctx.alloc(pattern));
+                }
+                Err(error) => {
+                    regexp.regex.pattern = RegExpPattern::Invalid(raw);
+                    self.ctx.error(error);
+                }
+            }
+        }
+
+        match &mut regexp.regex.pattern {
+            RegExpPattern::Pattern(pattern) => Some(pattern),
+            RegExpPattern::Raw(_) | RegExpPattern::Invalid(_) => None,
+        }
+    }
+
+    /// `_wrapRegExp(regexp, { a: 1, b: [2, 3] })`
+    fn create_wrap_reg_exp_call(
+        &self,
+        regexp: Expression<'a>,
+        named_groups: NamedGroups<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let properties = ctx.ast.vec_from_iter(named_groups.into_iter().map(|(name, indices)| {
+            let key = if is_identifier_name(&name) {
+                ctx.ast.property_key_identifier_name(SPAN, name)
+            } else {
+                PropertyKey::from(ctx.ast.expression_string_literal(SPAN, name))
+            };
+            let value = if let [index] = indices[..] {
+                ctx.ast.expression_numeric_literal(
+                    SPAN,
+                    f64::from(index),
+                    index.to_string(),
+                    NumberBase::Decimal,
+                )
+            } else {
+                let elements = ctx.ast.vec_from_iter(indices.into_iter().map(|index| {
+                    ArrayExpressionElement::from(ctx.ast.expression_numeric_literal(
+                        SPAN,
+                        f64::from(index),
+                        index.to_string(),
+                        NumberBase::Decimal,
+                    ))
+                }));
+                ctx.ast.expression_array(SPAN, elements, None)
+            };
+            ctx.ast.object_property_kind_object_property(
+                SPAN,
+                PropertyKind::Init,
+                key,
+                value,
+                false,
+                false,
+                false,
+            )
+        }));
+        let groups = ctx.ast.expression_object(SPAN, properties, None);
+        let arguments = ctx.ast.vec_from_iter([Argument::from(regexp), Argument::from(groups)]);
+        self.ctx.helper_call_expr(Helper::WrapRegExp, arguments, ctx)
     }
 }
 
@@ -205,7 +321,11 @@ impl<'a, 'ctx> RegExp<'a, 'ctx> {
     ///
     /// Based on parsed regular expression pattern.
     fn has_unsupported_regular_expression_pattern(&self, pattern: &Pattern<'a>) -> bool {
-        pattern.body.body.iter().any(|alternative| {
+        self.disjunction_contains_unsupported(&pattern.body)
+    }
+
+    fn disjunction_contains_unsupported(&self, disjunction: &Disjunction) -> bool {
+        disjunction.body.iter().any(|alternative| {
             alternative.body.iter().any(|term| self.term_contains_unsupported(term))
         })
     }
@@ -214,19 +334,25 @@ impl<'a, 'ctx> RegExp<'a, 'ctx> {
         // Loop because `Term::Quantifier` contains a nested `Term`
         loop {
             match term {
-                Term::CapturingGroup(_) => return self.named_capture_groups,
+                Term::CapturingGroup(group) => {
+                    return self.disjunction_contains_unsupported(&group.body)
+                }
+                Term::IgnoreGroup(group) => {
+                    return self.disjunction_contains_unsupported(&group.body)
+                }
                 Term::UnicodePropertyEscape(_) => return self.unicode_property_escapes,
                 Term::CharacterClass(character_class) => {
                     return self.unicode_property_escapes
                         && character_class_has_unicode_property_escape(character_class)
                 }
                 Term::LookAroundAssertion(assertion) => {
-                    return self.look_behind_assertions
+                    return (self.look_behind_assertions
                         && matches!(
                             assertion.kind,
                             LookAroundAssertionKind::Lookbehind
                                 | LookAroundAssertionKind::NegativeLookbehind
-                        )
+                        ))
+                        || self.disjunction_contains_unsupported(&assertion.body)
                 }
                 Term::Quantifier(quantifier) => term = &quantifier.body,
                 _ => return false,
@@ -235,6 +361,36 @@ impl<'a, 'ctx> RegExp<'a, 'ctx> {
     }
 }
 
+fn disjunction_has_look_behind_assertion(disjunction: &Disjunction) -> bool {
+    disjunction
+        .body
+        .iter()
+        .any(|alternative| alternative.body.iter().any(term_has_look_behind_assertion))
+}
+
+fn term_has_look_behind_assertion(term: &Term) -> bool {
+    match term {
+        Term::LookAroundAssertion(assertion) => {
+            matches!(
+                assertion.kind,
+                LookAroundAssertionKind::Lookbehind | LookAroundAssertionKind::NegativeLookbehind
+            ) || disjunction_has_look_behind_assertion(&assertion.body)
+        }
+        Term::CapturingGroup(group) => disjunction_has_look_behind_assertion(&group.body),
+        Term::IgnoreGroup(group) => disjunction_has_look_behind_assertion(&group.body),
+        Term::Quantifier(quantifier) => term_has_look_behind_assertion(&quantifier.body),
+        _ => false,
+    }
+}
+
+fn look_behind_assertions_not_available(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error(
+        "Lookbehind assertions are not available in the configured target environment.",
+    )
+    .with_help("Lookbehind assertions can't be transformed. Remove them, or target an environment which supports them.")
+    .with_label(span)
+}
+
 fn character_class_has_unicode_property_escape(character_class: &CharacterClass) -> bool {
     character_class.body.iter().any(|element| match element {
         CharacterClassContents::UnicodePropertyEscape(_) => true,
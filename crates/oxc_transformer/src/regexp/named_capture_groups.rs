@@ -0,0 +1,139 @@
+//! Lowering of named capture groups.
+//!
+//! Named groups are replaced with plain capturing groups, and named backreferences with
+//! indexed backreferences:
+//!
+//! ```js
+//! /(?<year>\d{4})-\k<year>/
+//! ```
+//!
+//! ```js
+//! /(\d{4})-\1/
+//! ```
+//!
+//! Names of the groups are returned as a map from name to indices of the groups,
+//! which is passed to `_wrapRegExp` helper to provide `match.groups` and `$<name>`
+//! in `String.prototype.replace` at runtime.
+//!
+//! With duplicate named groups in different alternatives (`/(?<a>x)|(?<a>y)/`), a name maps to
+//! multiple groups, and a backreference `\k<a>` is replaced with `(?:\1\2)`.
+//! This is equivalent, as a backreference to a group which did not participate in the match
+//! matches an empty string.
+
+use oxc_allocator::{Allocator, Box as ArenaBox, Vec as ArenaVec};
+use oxc_regular_expression::ast::{
+    Alternative, Disjunction, IgnoreGroup, IndexedReference, Pattern, Term,
+};
+use oxc_span::{Atom, Span, SPAN};
+
+/// Names of capture groups, with indices of groups of each name, in order of first appearance.
+pub(super) type NamedGroups<'a> = Vec<(Atom<'a>, Vec<u32>)>;
+
+/// Replace named groups and named backreferences in `pattern`.
+///
+/// Returns names of the groups. If there are no named groups, `pattern` is not changed.
+pub(super) fn lower_named_capture_groups<'a>(
+    pattern: &mut Pattern<'a>,
+    allocator: &'a Allocator,
+) -> NamedGroups<'a> {
+    let mut groups = vec![];
+    let mut index = 0;
+    collect_groups_in_disjunction(&mut pattern.body, &mut index, &mut groups);
+    if groups.is_empty() {
+        return groups;
+    }
+
+    replace_references_in_disjunction(&mut pattern.body, &groups, allocator);
+    // Pattern is no longer the same as the source text, so it must be printed from the AST
+    pattern.span = SPAN;
+    groups
+}
+
+/// Number capturing groups in order of their opening parentheses, and remove their names.
+fn collect_groups_in_disjunction<'a>(
+    disjunction: &mut Disjunction<'a>,
+    index: &mut u32,
+    groups: &mut NamedGroups<'a>,
+) {
+    for alternative in disjunction.body.iter_mut() {
+        for term in alternative.body.iter_mut() {
+            collect_groups_in_term(term, index, groups);
+        }
+    }
+}
+
+fn collect_groups_in_term<'a>(term: &mut Term<'a>, index: &mut u32, groups: &mut NamedGroups<'a>) {
+    match term {
+        Term::CapturingGroup(group) => {
+            *index += 1;
+            if let Some(name) = group.name.take() {
+                match groups.iter_mut().find(|(group_name, _)| *group_name == name) {
+                    Some((_, indices)) => indices.push(*index),
+                    None => groups.push((name, vec![*index])),
+                }
+            }
+            collect_groups_in_disjunction(&mut group.body, index, groups);
+        }
+        Term::IgnoreGroup(group) => collect_groups_in_disjunction(&mut group.body, index, groups),
+        Term::LookAroundAssertion(assertion) => {
+            collect_groups_in_disjunction(&mut assertion.body, index, groups);
+        }
+        Term::Quantifier(quantifier) => collect_groups_in_term(&mut quantifier.body, index, groups),
+        _ => {}
+    }
+}
+
+fn replace_references_in_disjunction<'a>(
+    disjunction: &mut Disjunction<'a>,
+    groups: &NamedGroups<'a>,
+    allocator: &'a Allocator,
+) {
+    for alternative in disjunction.body.iter_mut() {
+        for term in alternative.body.iter_mut() {
+            replace_references_in_term(term, groups, allocator);
+        }
+    }
+}
+
+fn replace_references_in_term<'a>(
+    term: &mut Term<'a>,
+    groups: &NamedGroups<'a>,
+    allocator: &'a Allocator,
+) {
+    match term {
+        Term::NamedReference(reference) => {
+            let Some((_, indices)) = groups.iter().find(|(name, _)| *name == reference.name) else {
+                return;
+            };
+            *term = create_indexed_reference(reference.span, indices, allocator);
+        }
+        Term::CapturingGroup(group) => {
+            replace_references_in_disjunction(&mut group.body, groups, allocator);
+        }
+        Term::IgnoreGroup(group) => {
+            replace_references_in_disjunction(&mut group.body, groups, allocator);
+        }
+        Term::LookAroundAssertion(assertion) => {
+            replace_references_in_disjunction(&mut assertion.body, groups, allocator);
+        }
+        Term::Quantifier(quantifier) => {
+            replace_references_in_term(&mut quantifier.body, groups, allocator);
+        }
+        _ => {}
+    }
+}
+
+/// `\1`, or `(?:\1\2)` for a name shared by multiple groups.
+fn create_indexed_reference<'a>(span: Span, indices: &[u32], allocator: &'a Allocator) -> Term<'a> {
+    let reference = |index| {
+        Term::IndexedReference(ArenaBox::new_in(IndexedReference { span, index }, allocator))
+    };
+    if let [index] = indices {
+        return reference(*index);
+    }
+
+    let terms = ArenaVec::from_iter_in(indices.iter().copied().map(reference), allocator);
+    let alternative = Alternative { span, body: terms };
+    let body = Disjunction { span, body: ArenaVec::from_iter_in([alternative], allocator) };
+    Term::IgnoreGroup(ArenaBox::new_in(IgnoreGroup { span, modifiers: None, body }, allocator))
+}
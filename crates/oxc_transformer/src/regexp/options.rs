@@ -24,6 +24,10 @@ pub struct RegExpOptions {
     /// ES2018 <https://github.com/tc39/proposal-regexp-lookbehind>
     pub look_behind_assertions: bool,
 
+    /// How to handle the RegExp literal that has lookbehind assertions, when
+    /// [`RegExpOptions::look_behind_assertions`] is enabled.
+    pub look_behind_assertions_mode: LookBehindAssertionsMode,
+
     /// Enables plugin to transform the `d` flag
     /// ES2022 <https://github.com/tc39/proposal-regexp-match-indices>
     pub match_indices: bool,
@@ -32,3 +36,15 @@ pub struct RegExpOptions {
     /// ES2024 <https://babel.dev/docs/babel-plugin-transform-unicode-sets-regex>
     pub set_notation: bool,
 }
+
+/// Lookbehind assertions can't be transformed into equivalent syntax which older engines support.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LookBehindAssertionsMode {
+    /// Report an error, and leave the RegExp literal as is.
+    #[default]
+    Error,
+    /// Pass the pattern through to a `new RegExp()` constructor call, same as esbuild.
+    /// Engines which don't support lookbehind assertions throw when the expression is evaluated,
+    /// rather than failing to parse the whole file.
+    PassThrough,
+}
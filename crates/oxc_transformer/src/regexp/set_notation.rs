@@ -0,0 +1,309 @@
+//! Lowering of `v` (unicodeSets) flag to `u` flag.
+//!
+//! Outside of character classes, `v` flag has the same syntax as `u` flag.
+//! Character classes which use `v` flag only syntax are replaced with equivalent `u` flag syntax:
+//!
+//! * Nested classes are merged into the outer class: `[a[b-c]]` -> `[ab-c]`.
+//!   A nested class which can't be merged becomes an alternative: `[a[^b]]` -> `(?:[a]|[^b])`.
+//! * Intersection is replaced with lookaheads: `[\p{ASCII}&&\p{L}]` -> `(?:(?=\p{ASCII})\p{L})`.
+//! * Subtraction is replaced with negative lookaheads: `[\w--\d]` -> `(?:(?!\d)\w)`.
+//! * Negated class which can't be a `u` flag class is replaced with a negative lookahead:
+//!   `[^\w--\d]` -> `(?:(?!(?!\d)\w)[\s\S])`.
+//! * String disjunction of single characters is merged into the class: `[\q{a|b}]` -> `[ab]`.
+//!
+//! Each operand of a class matches exactly one code point, so a lookahead for an operand
+//! followed by another operand matches a code point which is in both.
+//!
+//! Strings can't be represented in a `u` flag class, so a string disjunction with strings
+//! (`[\q{abc}]`) or a property of strings (`[\p{RGI_Emoji}]`) is an error.
+//!
+//! Babel computes the resulting set of code points from Unicode data instead, which produces
+//! a plain class, but requires the data for every property.
+
+use std::mem;
+
+use oxc_allocator::{Allocator, Box as ArenaBox, Vec as ArenaVec};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_regular_expression::ast::{
+    Alternative, Character, CharacterClass, CharacterClassContents, CharacterClassContentsKind,
+    CharacterClassEscape, CharacterClassEscapeKind, CharacterKind, Disjunction, IgnoreGroup,
+    LookAroundAssertion, LookAroundAssertionKind, Pattern, Term,
+};
+use oxc_span::{GetSpan, Span, SPAN};
+
+/// Replace `v` flag character classes in `pattern` with `u` flag syntax.
+///
+/// Returns an error if a class contains strings.
+pub(super) fn lower_set_notation<'a>(
+    pattern: &mut Pattern<'a>,
+    allocator: &'a Allocator,
+) -> Result<(), OxcDiagnostic> {
+    lower_disjunction(&mut pattern.body, allocator)?;
+    // Pattern is no longer the same as the source text, so it must be printed from the AST
+    pattern.span = SPAN;
+    Ok(())
+}
+
+fn lower_disjunction<'a>(
+    disjunction: &mut Disjunction<'a>,
+    allocator: &'a Allocator,
+) -> Result<(), OxcDiagnostic> {
+    for alternative in disjunction.body.iter_mut() {
+        for term in alternative.body.iter_mut() {
+            lower_term(term, allocator)?;
+        }
+    }
+    Ok(())
+}
+
+fn lower_term<'a>(term: &mut Term<'a>, allocator: &'a Allocator) -> Result<(), OxcDiagnostic> {
+    match term {
+        Term::CharacterClass(class) => *term = lower_class(class, allocator)?,
+        Term::CapturingGroup(group) => lower_disjunction(&mut group.body, allocator)?,
+        Term::IgnoreGroup(group) => lower_disjunction(&mut group.body, allocator)?,
+        Term::LookAroundAssertion(assertion) => lower_disjunction(&mut assertion.body, allocator)?,
+        Term::Quantifier(quantifier) => lower_term(&mut quantifier.body, allocator)?,
+        _ => {}
+    }
+    Ok(())
+}
+
+/// Lower a class into a term which matches the same code points with `u` flag.
+fn lower_class<'a>(
+    class: &mut CharacterClass<'a>,
+    allocator: &'a Allocator,
+) -> Result<Term<'a>, OxcDiagnostic> {
+    let span = class.span;
+    let body = mem::replace(&mut class.body, ArenaVec::new_in(allocator));
+    let term = match class.kind {
+        CharacterClassContentsKind::Union => lower_union(span, body, allocator)?,
+        // `[a&&b&&c]` -> `(?:(?=a)(?=b)c)`
+        CharacterClassContentsKind::Intersection => {
+            let mut operands = lower_operands(body, allocator)?;
+            let last = operands.pop().unwrap();
+            let terms = operands
+                .into_iter()
+                .map(|operand| lookahead(LookAroundAssertionKind::Lookahead, operand, allocator))
+                .chain([last]);
+            group(span, ArenaVec::from_iter_in(terms, allocator), allocator)
+        }
+        // `[a--b--c]` -> `(?:(?!b)(?!c)a)`
+        CharacterClassContentsKind::Subtraction => {
+            let mut operands = lower_operands(body, allocator)?.into_iter();
+            let first = operands.next().unwrap();
+            let terms = operands
+                .map(|operand| {
+                    lookahead(LookAroundAssertionKind::NegativeLookahead, operand, allocator)
+                })
+                .chain([first]);
+            group(span, ArenaVec::from_iter_in(terms, allocator), allocator)
+        }
+    };
+
+    if !class.negative {
+        return Ok(term);
+    }
+    Ok(match term {
+        // `[^ab]`
+        Term::CharacterClass(mut class) if !class.negative => {
+            class.negative = true;
+            Term::CharacterClass(class)
+        }
+        // `[^a&&b]` -> `(?:(?!(?=a)b)[\s\S])`
+        term => {
+            let not = lookahead(LookAroundAssertionKind::NegativeLookahead, term, allocator);
+            let any = Term::CharacterClass(ArenaBox::new_in(
+                create_class(span, any_character(span, allocator)),
+                allocator,
+            ));
+            group(span, ArenaVec::from_iter_in([not, any], allocator), allocator)
+        }
+    })
+}
+
+/// `[ab[cd][^e]]` -> `(?:[abcd]|[^e])`
+fn lower_union<'a>(
+    span: Span,
+    body: ArenaVec<'a, CharacterClassContents<'a>>,
+    allocator: &'a Allocator,
+) -> Result<Term<'a>, OxcDiagnostic> {
+    let mut contents = ArenaVec::new_in(allocator);
+    let mut alternatives = vec![];
+    for content in body {
+        match content {
+            CharacterClassContents::Character(mut character) => {
+                *character = to_unicode_mode_character(*character);
+                contents.push(CharacterClassContents::Character(character));
+            }
+            CharacterClassContents::CharacterClassRange(mut range) => {
+                range.min = to_unicode_mode_character(range.min);
+                range.max = to_unicode_mode_character(range.max);
+                contents.push(CharacterClassContents::CharacterClassRange(range));
+            }
+            CharacterClassContents::CharacterClassEscape(_) => contents.push(content),
+            CharacterClassContents::UnicodePropertyEscape(escape) => {
+                if escape.strings {
+                    return Err(strings_not_supported(escape.span));
+                }
+                contents.push(CharacterClassContents::UnicodePropertyEscape(escape));
+            }
+            CharacterClassContents::ClassStringDisjunction(disjunction) => {
+                if disjunction.strings {
+                    return Err(strings_not_supported(disjunction.span));
+                }
+                // Each string is a single character
+                for string in disjunction.unbox().body {
+                    let character = to_unicode_mode_character(string.body[0]);
+                    contents.push(CharacterClassContents::Character(ArenaBox::new_in(
+                        character, allocator,
+                    )));
+                }
+            }
+            CharacterClassContents::NestedCharacterClass(mut nested) => {
+                match lower_class(&mut nested, allocator)? {
+                    Term::CharacterClass(nested) if !nested.negative => {
+                        contents.extend(nested.unbox().body);
+                    }
+                    term => alternatives.push(term),
+                }
+            }
+        }
+    }
+
+    let class = Term::CharacterClass(ArenaBox::new_in(create_class(span, contents), allocator));
+    if alternatives.is_empty() {
+        return Ok(class);
+    }
+    if matches!(&class, Term::CharacterClass(class) if !class.body.is_empty()) {
+        alternatives.insert(0, class);
+    }
+    if alternatives.len() == 1 {
+        return Ok(alternatives.pop().unwrap());
+    }
+
+    let alternatives = alternatives
+        .into_iter()
+        .map(|term| Alternative { span, body: ArenaVec::from_iter_in([term], allocator) });
+    let body = Disjunction { span, body: ArenaVec::from_iter_in(alternatives, allocator) };
+    Ok(Term::IgnoreGroup(ArenaBox::new_in(IgnoreGroup { span, modifiers: None, body }, allocator)))
+}
+
+/// Lower each operand of an intersection or subtraction into a term.
+fn lower_operands<'a>(
+    body: ArenaVec<'a, CharacterClassContents<'a>>,
+    allocator: &'a Allocator,
+) -> Result<Vec<Term<'a>>, OxcDiagnostic> {
+    body.into_iter()
+        .map(|content| match content {
+            CharacterClassContents::CharacterClassEscape(escape) => {
+                Ok(Term::CharacterClassEscape(escape))
+            }
+            CharacterClassContents::UnicodePropertyEscape(escape) if !escape.strings => {
+                Ok(Term::UnicodePropertyEscape(escape))
+            }
+            CharacterClassContents::NestedCharacterClass(mut nested) => {
+                lower_class(&mut nested, allocator)
+            }
+            content => {
+                let span = content.span();
+                lower_union(span, ArenaVec::from_iter_in([content], allocator), allocator)
+            }
+        })
+        .collect()
+}
+
+/// Convert a character to syntax which is valid in a class with `u` flag.
+fn to_unicode_mode_character(mut character: Character) -> Character {
+    let is_syntax_character = char::from_u32(character.value).is_some_and(|ch| {
+        matches!(
+            ch,
+            '^' | '$'
+                | '\\'
+                | '.'
+                | '*'
+                | '+'
+                | '?'
+                | '('
+                | ')'
+                | '['
+                | ']'
+                | '{'
+                | '}'
+                | '|'
+                | '/'
+        )
+    });
+    match character.kind {
+        // `\&` is only valid with `v` flag
+        CharacterKind::Identifier if !is_syntax_character && character.value != u32::from('-') => {
+            character.kind = CharacterKind::Symbol;
+        }
+        // `-` or `^` from a nested class or string disjunction may have a special meaning here
+        CharacterKind::Symbol if is_syntax_character || character.value == u32::from('-') => {
+            character.kind = CharacterKind::Identifier;
+        }
+        _ => {}
+    }
+    character
+}
+
+fn create_class<'a>(
+    span: Span,
+    body: ArenaVec<'a, CharacterClassContents<'a>>,
+) -> CharacterClass<'a> {
+    CharacterClass {
+        span,
+        negative: false,
+        strings: false,
+        kind: CharacterClassContentsKind::Union,
+        body,
+    }
+}
+
+/// `\s\S`
+fn any_character(span: Span, allocator: &Allocator) -> ArenaVec<'_, CharacterClassContents<'_>> {
+    let escape = |kind| {
+        CharacterClassContents::CharacterClassEscape(ArenaBox::new_in(
+            CharacterClassEscape { span, kind },
+            allocator,
+        ))
+    };
+    ArenaVec::from_iter_in(
+        [escape(CharacterClassEscapeKind::S), escape(CharacterClassEscapeKind::NegativeS)],
+        allocator,
+    )
+}
+
+/// `(?=term)` or `(?!term)`
+fn lookahead<'a>(
+    kind: LookAroundAssertionKind,
+    term: Term<'a>,
+    allocator: &'a Allocator,
+) -> Term<'a> {
+    let span = term.span();
+    let body = match term {
+        // `(?=(?:a|b))` -> `(?=a|b)`
+        Term::IgnoreGroup(group) if group.modifiers.is_none() => group.unbox().body,
+        term => Disjunction {
+            span,
+            body: ArenaVec::from_iter_in(
+                [Alternative { span, body: ArenaVec::from_iter_in([term], allocator) }],
+                allocator,
+            ),
+        },
+    };
+    Term::LookAroundAssertion(ArenaBox::new_in(LookAroundAssertion { span, kind, body }, allocator))
+}
+
+/// `(?:terms)`
+fn group<'a>(span: Span, terms: ArenaVec<'a, Term<'a>>, allocator: &'a Allocator) -> Term<'a> {
+    let alternative = Alternative { span, body: terms };
+    let body = Disjunction { span, body: ArenaVec::from_iter_in([alternative], allocator) };
+    Term::IgnoreGroup(ArenaBox::new_in(IgnoreGroup { span, modifiers: None, body }, allocator))
+}
+
+fn strings_not_supported(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error("Strings in a character class with `v` flag can't be transformed.")
+        .with_help("Only classes of single characters can be transformed to `u` flag.")
+        .with_label(span)
+}
@@ -1,10 +1,10 @@
 use rustc_hash::FxHashSet;
 
-use oxc_allocator::Vec as ArenaVec;
-use oxc_ast::ast::*;
+use oxc_allocator::{Box as ArenaBox, Vec as ArenaVec};
+use oxc_ast::{ast::*, NONE};
 use oxc_diagnostics::OxcDiagnostic;
-use oxc_semantic::SymbolFlags;
-use oxc_span::{Atom, GetSpan, Span, SPAN};
+use oxc_semantic::{Reference, SymbolFlags};
+use oxc_span::{Atom, CompactStr, GetSpan, Span, SPAN};
 use oxc_syntax::{
     operator::AssignmentOperator,
     reference::ReferenceFlags,
@@ -13,6 +13,7 @@ use oxc_syntax::{
 };
 use oxc_traverse::{Traverse, TraverseCtx};
 
+use super::diagnostics;
 use crate::{TransformCtx, TypeScriptOptions};
 
 pub struct TypeScriptAnnotations<'a, 'ctx> {
@@ -20,6 +21,9 @@ pub struct TypeScriptAnnotations<'a, 'ctx> {
 
     // Options
     only_remove_type_imports: bool,
+    verbatim_module_syntax: bool,
+    isolated_modules: bool,
+    allow_declare_fields: bool,
 
     /// Assignments to be added to the constructor body
     assignments: Vec<Assignment<'a>>,
@@ -49,6 +53,9 @@ impl<'a, 'ctx> TypeScriptAnnotations<'a, 'ctx> {
         Self {
             ctx,
             only_remove_type_imports: options.only_remove_type_imports,
+            verbatim_module_syntax: options.verbatim_module_syntax,
+            isolated_modules: options.isolated_modules,
+            allow_declare_fields: options.allow_declare_fields,
             has_super_call: false,
             assignments: vec![],
             has_jsx_element: false,
@@ -65,10 +72,27 @@ impl<'a, 'ctx> Traverse<'a> for TypeScriptAnnotations<'a, 'ctx> {
         let mut no_modules_remaining = true;
         let mut some_modules_deleted = false;
 
+        // Imported names that can be either a value or a type, i.e. excluding `import type`
+        // and namespace imports.
+        let ambiguous_imports = if self.isolated_modules {
+            Self::collect_ambiguous_imports(program)
+        } else {
+            FxHashSet::default()
+        };
+
+        // Symbols of removed imports, which are removed after all imports are checked
+        let mut removed_imports: Vec<(SymbolId, Span)> = vec![];
+
         program.body.retain_mut(|stmt| {
             let need_retain = match stmt {
                 Statement::ExportNamedDeclaration(decl) if decl.declaration.is_some() => {
-                    decl.declaration.as_ref().is_some_and(|decl| !decl.is_typescript_syntax())
+                    let decl = decl.declaration.as_ref().unwrap();
+                    if decl.is_typescript_syntax() {
+                        Self::remove_type_declaration_symbol(decl, ctx);
+                        false
+                    } else {
+                        true
+                    }
                 }
                 Statement::ExportNamedDeclaration(decl) => {
                     if decl.export_kind.is_type() {
@@ -86,15 +110,56 @@ impl<'a, 'ctx> Traverse<'a> for TypeScriptAnnotations<'a, 'ctx> {
                                     if ctx.symbols().get_reference(ident.reference_id()).is_type()
                                 ))
                         });
+                        if decl.source.is_none() && !ambiguous_imports.is_empty() {
+                            self.report_ambiguous_exports(decl, &ambiguous_imports, ctx);
+                        }
                         // Keep the export declaration if there are still specifiers after removing type exports
                         !decl.specifiers.is_empty()
                     }
                 }
                 Statement::ExportAllDeclaration(decl) => !decl.export_kind.is_type(),
-                Statement::ExportDefaultDeclaration(decl) => !decl.is_typescript_syntax(),
+                Statement::ExportDefaultDeclaration(decl) => {
+                    // `type A = string; export default A;` has no runtime export
+                    !decl.is_typescript_syntax()
+                        && !matches!(
+                            &decl.declaration, ExportDefaultDeclarationKind::Identifier(ident)
+                            if ctx.symbols().get_reference(ident.reference_id()).flags().is_type_only()
+                        )
+                }
                 Statement::ImportDeclaration(decl) => {
                     if decl.import_kind.is_type() {
+                        // `import type A, { B } from 'mod'`
+                        if decl.specifiers.as_ref().is_some_and(|specifiers| {
+                            specifiers.len() > 1
+                                && matches!(
+                                    specifiers[0],
+                                    ImportDeclarationSpecifier::ImportDefaultSpecifier(_)
+                                )
+                        }) {
+                            self.ctx
+                                .error(diagnostics::type_only_import_default_and_named(decl.span));
+                        }
+                        if let Some(specifiers) = &decl.specifiers {
+                            removed_imports.extend(specifiers.iter().map(|s| (s.local().symbol_id(), s.local().span)));
+                        }
                         false
+                    } else if self.verbatim_module_syntax {
+                        // Only remove inline type specifiers, and keep the rest as written.
+                        // `import { type A } from 'mod'` -> `import {} from 'mod'`
+                        if let Some(specifiers) = &mut decl.specifiers {
+                            specifiers.retain(|specifier| {
+                                let is_type = matches!(
+                                    specifier,
+                                    ImportDeclarationSpecifier::ImportSpecifier(s)
+                                        if s.import_kind.is_type()
+                                );
+                                if is_type {
+                                    removed_imports.push((specifier.local().symbol_id(), specifier.local().span));
+                                }
+                                !is_type
+                            });
+                        }
+                        true
                     } else if self.only_remove_type_imports {
                         true
                     } else if let Some(specifiers) = &mut decl.specifiers {
@@ -107,6 +172,7 @@ impl<'a, 'ctx> Traverse<'a> for TypeScriptAnnotations<'a, 'ctx> {
                                 let id = match specifier {
                                     ImportDeclarationSpecifier::ImportSpecifier(s) => {
                                         if s.import_kind.is_type() {
+                                            removed_imports.push((s.local.symbol_id(), s.local.span));
                                             return false;
                                         }
                                         &s.local
@@ -118,7 +184,11 @@ impl<'a, 'ctx> Traverse<'a> for TypeScriptAnnotations<'a, 'ctx> {
                                         &s.local
                                     }
                                 };
-                                self.has_value_reference(&id.name, ctx)
+                                let retain = self.has_value_reference(&id.name, ctx);
+                                if !retain {
+                                    removed_imports.push((id.symbol_id(), id.span));
+                                }
+                                retain
                             });
                             !specifiers.is_empty()
                         }
@@ -141,6 +211,15 @@ impl<'a, 'ctx> Traverse<'a> for TypeScriptAnnotations<'a, 'ctx> {
             need_retain
         });
 
+        for (symbol_id, span) in removed_imports {
+            Self::remove_symbol_declaration(
+                symbol_id,
+                span,
+                SymbolFlags::Import | SymbolFlags::TypeImport,
+                ctx,
+            );
+        }
+
         // Determine if we still have import/export statements, otherwise we
         // need to inject an empty statement (`export {}`) so that the file is
         // still considered a module
@@ -150,14 +229,16 @@ impl<'a, 'ctx> Traverse<'a> for TypeScriptAnnotations<'a, 'ctx> {
             );
             program.body.push(export_decl);
         }
+
+        Self::delete_type_references(ctx);
     }
 
     fn enter_arrow_function_expression(
         &mut self,
         expr: &mut ArrowFunctionExpression<'a>,
-        _ctx: &mut TraverseCtx<'a>,
+        ctx: &mut TraverseCtx<'a>,
     ) {
-        expr.type_parameters = None;
+        Self::remove_type_parameters(expr.type_parameters.take(), ctx);
         expr.return_type = None;
     }
 
@@ -181,14 +262,14 @@ impl<'a, 'ctx> Traverse<'a> for TypeScriptAnnotations<'a, 'ctx> {
         expr.type_parameters = None;
     }
 
-    fn enter_class(&mut self, class: &mut Class<'a>, _ctx: &mut TraverseCtx<'a>) {
-        class.type_parameters = None;
+    fn enter_class(&mut self, class: &mut Class<'a>, ctx: &mut TraverseCtx<'a>) {
+        Self::remove_type_parameters(class.type_parameters.take(), ctx);
         class.super_type_parameters = None;
         class.implements = None;
         class.r#abstract = false;
     }
 
-    fn enter_class_body(&mut self, body: &mut ClassBody<'a>, _ctx: &mut TraverseCtx<'a>) {
+    fn enter_class_body(&mut self, body: &mut ClassBody<'a>, ctx: &mut TraverseCtx<'a>) {
         // Remove type only members
         body.body.retain(|elem| match elem {
             ClassElement::MethodDefinition(method) => {
@@ -198,6 +279,13 @@ impl<'a, 'ctx> Traverse<'a> for TypeScriptAnnotations<'a, 'ctx> {
             ClassElement::PropertyDefinition(prop) => {
                 if prop.declare {
                     false
+                } else if !self.allow_declare_fields
+                    && prop.value.is_none()
+                    && prop.decorators.is_empty()
+                    && !prop.key.is_private_identifier()
+                {
+                    // Without `declare`, fields with no initializer are treated as type-only
+                    false
                 } else {
                     matches!(prop.r#type, PropertyDefinitionType::PropertyDefinition)
                 }
@@ -208,6 +296,10 @@ impl<'a, 'ctx> Traverse<'a> for TypeScriptAnnotations<'a, 'ctx> {
             ClassElement::TSIndexSignature(_) => false,
             ClassElement::StaticBlock(_) => true,
         });
+
+        if self.allow_declare_fields {
+            Self::declare_parameter_properties(body, ctx);
+        }
     }
 
     fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
@@ -217,6 +309,27 @@ impl<'a, 'ctx> Traverse<'a> for TypeScriptAnnotations<'a, 'ctx> {
         }
     }
 
+    /// `export default foo as Bar` -> `export default foo`
+    ///
+    /// After stripping the type assertion, `foo` is exported directly, so mark its binding as exported,
+    /// the same as semantic does for `export default foo`.
+    fn enter_export_default_declaration(
+        &mut self,
+        decl: &mut ExportDefaultDeclaration<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let Some(expr) = decl.declaration.as_expression() else { return };
+        if !expr.is_typescript_syntax() {
+            return;
+        }
+        if let Expression::Identifier(ident) = expr.get_inner_expression() {
+            let reference = ctx.symbols().get_reference(ident.reference_id());
+            if let Some(symbol_id) = reference.symbol_id() {
+                ctx.symbols_mut().union_flag(symbol_id, SymbolFlags::Export);
+            }
+        }
+    }
+
     fn enter_simple_assignment_target(
         &mut self,
         target: &mut SimpleAssignmentTarget<'a>,
@@ -269,9 +382,9 @@ impl<'a, 'ctx> Traverse<'a> for TypeScriptAnnotations<'a, 'ctx> {
         param.accessibility = None;
     }
 
-    fn exit_function(&mut self, func: &mut Function<'a>, _ctx: &mut TraverseCtx<'a>) {
+    fn exit_function(&mut self, func: &mut Function<'a>, ctx: &mut TraverseCtx<'a>) {
         func.this_param = None;
-        func.type_parameters = None;
+        Self::remove_type_parameters(func.type_parameters.take(), ctx);
         func.return_type = None;
     }
 
@@ -292,13 +405,17 @@ impl<'a, 'ctx> Traverse<'a> for TypeScriptAnnotations<'a, 'ctx> {
         // for each of them in the constructor body.
         if def.kind == MethodDefinitionKind::Constructor {
             for param in def.value.params.items.as_mut_slice() {
-                if param.accessibility.is_some() || param.readonly || param.r#override {
+                if param.has_modifier() {
                     if let Some(id) = param.pattern.get_binding_identifier() {
                         self.assignments.push(Assignment {
                             span: id.span,
                             name: id.name.clone(),
                             symbol_id: id.symbol_id(),
                         });
+                    } else {
+                        self.ctx.error(diagnostics::parameter_property_binding_pattern(
+                            param.pattern.kind.span(),
+                        ));
                     }
                 }
 
@@ -381,7 +498,7 @@ impl<'a, 'ctx> Traverse<'a> for TypeScriptAnnotations<'a, 'ctx> {
     fn enter_statements(
         &mut self,
         stmts: &mut ArenaVec<'a, Statement<'a>>,
-        _ctx: &mut TraverseCtx<'a>,
+        ctx: &mut TraverseCtx<'a>,
     ) {
         // Remove declare declaration
         stmts.retain(
@@ -393,6 +510,24 @@ impl<'a, 'ctx> Traverse<'a> for TypeScriptAnnotations<'a, 'ctx> {
                 }
             },
         );
+
+        // Remove `import x = require('mod')` and `import x = A.B` if `x` is never used as a value
+        if !self.only_remove_type_imports && !self.verbatim_module_syntax {
+            stmts.retain(|stmt| match stmt {
+                Statement::TSImportEqualsDeclaration(decl) => {
+                    let symbol_id = decl.id.symbol_id();
+                    let is_used = ctx
+                        .symbols()
+                        .get_resolved_references(symbol_id)
+                        .any(|reference| !reference.is_type());
+                    if !is_used {
+                        Self::remove_type_declaration_symbol(stmt.to_declaration(), ctx);
+                    }
+                    is_used
+                }
+                _ => true,
+            });
+        }
     }
 
     fn exit_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
@@ -419,14 +554,22 @@ impl<'a, 'ctx> Traverse<'a> for TypeScriptAnnotations<'a, 'ctx> {
     fn exit_statements(
         &mut self,
         stmts: &mut ArenaVec<'a, Statement<'a>>,
-        _ctx: &mut TraverseCtx<'a>,
+        ctx: &mut TraverseCtx<'a>,
     ) {
         // Remove TS specific statements
         stmts.retain(|stmt| match stmt {
             Statement::ExpressionStatement(s) => !s.expression.is_typescript_syntax(),
             // Any namespaces left after namespace transform are type only, so remove them
             Statement::TSModuleDeclaration(_) => false,
-            match_declaration!(Statement) => !stmt.to_declaration().is_typescript_syntax(),
+            match_declaration!(Statement) => {
+                let decl = stmt.to_declaration();
+                if decl.is_typescript_syntax() {
+                    Self::remove_type_declaration_symbol(decl, ctx);
+                    false
+                } else {
+                    true
+                }
+            }
             // Ignore ModuleDeclaration as it's handled in the program
             _ => true,
         });
@@ -530,6 +673,116 @@ impl<'a, 'ctx> Traverse<'a> for TypeScriptAnnotations<'a, 'ctx> {
 }
 
 impl<'a, 'ctx> TypeScriptAnnotations<'a, 'ctx> {
+    /// Remove symbol of a removed type alias, interface or import equals declaration,
+    /// and delete the declaration's scope.
+    pub(super) fn remove_type_declaration_symbol(
+        decl: &Declaration<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let (id, scope_id, flags) = match decl {
+            Declaration::TSTypeAliasDeclaration(decl) => {
+                (&decl.id, Some(decl.scope_id()), SymbolFlags::TypeAlias)
+            }
+            Declaration::TSInterfaceDeclaration(decl) => {
+                (&decl.id, Some(decl.scope_id()), SymbolFlags::Interface)
+            }
+            Declaration::TSImportEqualsDeclaration(decl) => {
+                if let Some(type_name) = decl.module_reference.as_ts_type_name() {
+                    let ident = TSTypeName::get_first_name(type_name);
+                    ctx.delete_reference_for_identifier(&ident);
+                }
+                (&decl.id, None, SymbolFlags::Import | SymbolFlags::TypeImport)
+            }
+            _ => return,
+        };
+        if let Some(scope_id) = scope_id {
+            ctx.scopes_mut().delete_scope(scope_id);
+        }
+        Self::remove_symbol_declaration(id.symbol_id(), id.span, flags, ctx);
+    }
+
+    /// Remove bindings of type parameters of a function or class.
+    fn remove_type_parameters(
+        type_parameters: Option<ArenaBox<'a, TSTypeParameterDeclaration<'a>>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let Some(type_parameters) = type_parameters else { return };
+        for param in &type_parameters.params {
+            let symbol_id = param.name.symbol_id();
+            let scope_id = ctx.symbols().get_scope_id(symbol_id);
+            ctx.scopes_mut().remove_binding(scope_id, &CompactStr::from(param.name.name.as_str()));
+        }
+    }
+
+    /// Remove a declaration of a symbol which has been removed, along with the symbol's binding.
+    ///
+    /// If the symbol has another declaration which is a value, e.g. `const A = 1; type A = number;`,
+    /// the binding is kept, and only the declaration's `flags` and span are removed from the symbol.
+    fn remove_symbol_declaration(
+        symbol_id: SymbolId,
+        span: Span,
+        flags: SymbolFlags,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let symbols = ctx.symbols_mut();
+        if (symbols.get_flags(symbol_id) - flags).is_value() {
+            *symbols.get_flags_mut(symbol_id) -= flags;
+            // If the removed declaration came first, the next declaration becomes the declaration
+            let span = match symbols.get_redeclarations(symbol_id).first() {
+                Some(&next_span) if symbols.get_span(symbol_id) == span => {
+                    symbols.spans[symbol_id] = next_span;
+                    next_span
+                }
+                _ => span,
+            };
+            symbols.remove_redeclaration(symbol_id, span);
+        } else {
+            let scope_id = symbols.get_scope_id(symbol_id);
+            let name = CompactStr::from(symbols.get_name(symbol_id));
+            // Values still referring to the removed symbol, e.g. `d` in `import type d = A; d;`,
+            // become references to a global
+            let value_reference_ids = symbols
+                .get_resolved_reference_ids(symbol_id)
+                .iter()
+                .copied()
+                .filter(|&reference_id| symbols.references[reference_id].is_value())
+                .collect::<Vec<_>>();
+            for &reference_id in &value_reference_ids {
+                symbols.delete_resolved_reference(symbol_id, reference_id);
+                let reference = &mut symbols.references[reference_id];
+                *reference = Reference::new(reference.node_id(), reference.flags());
+            }
+            for reference_id in value_reference_ids {
+                ctx.scopes_mut().add_root_unresolved_reference(name.clone(), reference_id);
+            }
+            ctx.scopes_mut().remove_binding(scope_id, &name);
+        }
+    }
+
+    /// Delete references in types, e.g. `Bar` in `let foo: Bar` or `foo as Bar`.
+    ///
+    /// All types have been removed from the AST, so every reference which is not used as a value
+    /// is gone too. Only run at the end of the transform, as import elision inspects them.
+    fn delete_type_references(ctx: &mut TraverseCtx<'a>) {
+        let symbols = ctx.symbols_mut();
+        for reference_ids in &mut symbols.resolved_references {
+            reference_ids.retain(|&reference_id| symbols.references[reference_id].is_value());
+        }
+
+        let unresolved_type_references = ctx
+            .scopes()
+            .root_unresolved_references()
+            .iter()
+            .flat_map(|(name, reference_ids)| {
+                reference_ids.iter().map(move |&reference_id| (name.clone(), reference_id))
+            })
+            .filter(|&(_, reference_id)| !ctx.symbols().get_reference(reference_id).is_value())
+            .collect::<Vec<_>>();
+        for (name, reference_id) in unresolved_type_references {
+            ctx.scopes_mut().delete_root_unresolved_reference(&name, reference_id);
+        }
+    }
+
     /// Check if the given name is a JSX pragma or fragment pragma import
     /// and if the file contains JSX elements or fragments
     fn is_jsx_imports(&self, name: &str) -> bool {
@@ -537,6 +790,58 @@ impl<'a, 'ctx> TypeScriptAnnotations<'a, 'ctx> {
             || self.has_jsx_fragment && name == self.jsx_fragment_import_name
     }
 
+    /// Declare fields for parameter properties of the constructor, before other fields.
+    ///
+    /// ```ts
+    /// class C { y = 1; constructor(public x) {} }
+    /// // to
+    /// class C { x; y = 1; constructor(x) { this.x = x; } }
+    /// ```
+    ///
+    /// Parameter properties already declared as fields in the class body are skipped.
+    fn declare_parameter_properties(body: &mut ClassBody<'a>, ctx: &TraverseCtx<'a>) {
+        let Some(constructor) = body.body.iter().find_map(|element| match element {
+            ClassElement::MethodDefinition(method) if method.kind.is_constructor() => Some(method),
+            _ => None,
+        }) else {
+            return;
+        };
+
+        let fields = constructor
+            .value
+            .params
+            .items
+            .iter()
+            .filter(|param| param.has_modifier())
+            .filter_map(|param| param.pattern.get_binding_identifier())
+            .filter(|ident| {
+                !body.body.iter().any(|element| {
+                    matches!(element, ClassElement::PropertyDefinition(prop)
+                        if !prop.r#static && prop.key.is_specific_id(&ident.name))
+                })
+            })
+            .map(|ident| {
+                ctx.ast.class_element_property_definition(
+                    PropertyDefinitionType::PropertyDefinition,
+                    SPAN,
+                    ctx.ast.vec(),
+                    ctx.ast.property_key_identifier_name(ident.span, ident.name.clone()),
+                    None,
+                    false,
+                    false,
+                    false,
+                    false,
+                    false,
+                    false,
+                    false,
+                    NONE,
+                    None,
+                )
+            })
+            .collect::<Vec<_>>();
+        body.body.splice(0..0, fields);
+    }
+
     fn create_block_with_statement(
         stmt: Statement<'a>,
         span: Span,
@@ -568,6 +873,62 @@ impl<'a, 'ctx> TypeScriptAnnotations<'a, 'ctx> {
         }
     }
 
+    fn collect_ambiguous_imports(program: &Program<'a>) -> FxHashSet<SymbolId> {
+        let mut symbol_ids = FxHashSet::default();
+        for stmt in &program.body {
+            let Statement::ImportDeclaration(decl) = stmt else { continue };
+            if decl.import_kind.is_type() {
+                continue;
+            }
+            let Some(specifiers) = &decl.specifiers else { continue };
+            for specifier in specifiers {
+                match specifier {
+                    ImportDeclarationSpecifier::ImportSpecifier(s) if !s.import_kind.is_type() => {
+                        symbol_ids.insert(s.local.symbol_id());
+                    }
+                    ImportDeclarationSpecifier::ImportDefaultSpecifier(s) => {
+                        symbol_ids.insert(s.local.symbol_id());
+                    }
+                    _ => {}
+                }
+            }
+        }
+        symbol_ids
+    }
+
+    /// `import { A } from 'mod'; export { A }`
+    ///
+    /// Report the export if `A` is not used as a value anywhere else in this file, because it may
+    /// be a type that the exporting module can't elide without knowing about `mod`.
+    fn report_ambiguous_exports(
+        &self,
+        decl: &ExportNamedDeclaration<'a>,
+        ambiguous_imports: &FxHashSet<SymbolId>,
+        ctx: &TraverseCtx<'a>,
+    ) {
+        for specifier in &decl.specifiers {
+            let ModuleExportName::IdentifierReference(ident) = &specifier.local else { continue };
+            let reference_id = ident.reference_id();
+            let Some(symbol_id) = ctx.symbols().get_reference(reference_id).symbol_id() else {
+                continue;
+            };
+            if !ambiguous_imports.contains(&symbol_id) {
+                continue;
+            }
+            let has_other_value_reference = ctx
+                .symbols()
+                .get_resolved_reference_ids(symbol_id)
+                .iter()
+                .any(|&id| id != reference_id && !ctx.symbols().get_reference(id).is_type());
+            if !has_other_value_reference {
+                self.ctx.error(diagnostics::re_exporting_type_requires_export_type(
+                    &ident.name,
+                    specifier.span,
+                ));
+            }
+        }
+    }
+
     pub fn has_value_reference(&self, name: &str, ctx: &TraverseCtx<'a>) -> bool {
         if let Some(symbol_id) = ctx.scopes().get_root_binding(name) {
             // `import T from 'mod'; const T = 1;` The T has a value redeclaration
@@ -0,0 +1,492 @@
+//! Emit Decorator Metadata
+//!
+//! Implements TypeScript's [`emitDecoratorMetadata`](https://www.typescriptlang.org/tsconfig#emitDecoratorMetadata) option.
+//!
+//! Appends `Reflect.metadata` decorators which record the design-time types of decorated classes
+//! and members, for use by libraries such as `reflect-metadata`:
+//!
+//! * Class: `design:paramtypes` of the constructor parameters.
+//! * Method: `design:type`, `design:paramtypes` and `design:returntype`.
+//! * Getter / setter: `design:type`, and `design:paramtypes` for setters.
+//! * Property: `design:type`.
+//!
+//! Metadata is only emitted for declarations which are decorated, or which have decorated parameters.
+//!
+//! Types are serialized the same way as TypeScript does, without type information:
+//!
+//! * `number`, `string`, `boolean`, `bigint`, `symbol` (and their literal types) -> `Number`, `String`, `Boolean`, `BigInt`, `Symbol`
+//! * Arrays and tuples -> `Array`, function and constructor types -> `Function`
+//! * `void`, `undefined`, `null`, `never` -> `void 0`
+//! * Class references -> the class itself.
+//!   Imported or global references are guarded with `typeof Foo === "undefined" ? Object : Foo`,
+//!   and classes declared after the decorated class (circular references) fall back to `Object`.
+//! * Enums, including `const enum`s -> `Number` or `String` if all of their members are numbers
+//!   or strings, otherwise `Object`
+//! * Unions of the same type (ignoring `null` and `undefined`) -> that type
+//! * Everything else, including interfaces, type aliases and type parameters -> `Object`
+//!
+//! Imports which are only referenced as types in serialized metadata are retained.
+//!
+//! ## Example
+//!
+//! Input:
+//! ```ts
+//! @Injectable()
+//! class Service {
+//!   constructor(http: HttpClient, retries: number) {}
+//! }
+//! ```
+//!
+//! Output:
+//! ```js
+//! @Injectable()
+//! @Reflect.metadata("design:paramtypes", [typeof HttpClient === "undefined" ? Object : HttpClient, Number])
+//! class Service {
+//!   constructor(http, retries) {}
+//! }
+//! ```
+
+use rustc_hash::FxHashMap;
+
+use oxc_allocator::Vec as ArenaVec;
+use oxc_ast::{ast::*, visit::walk, Visit, NONE};
+use oxc_span::{Span, SPAN};
+use oxc_syntax::{
+    operator::{BinaryOperator, UnaryOperator},
+    reference::ReferenceFlags,
+    symbol::{SymbolFlags, SymbolId},
+};
+use oxc_traverse::{Traverse, TraverseCtx};
+
+use super::TypeScriptOptions;
+
+pub struct TypeScriptDecoratorMetadata {
+    /// Serialized types of enums, keyed by their symbol
+    enum_types: FxHashMap<SymbolId, &'static str>,
+}
+
+impl TypeScriptDecoratorMetadata {
+    pub fn new(options: &TypeScriptOptions) -> Option<Self> {
+        options.emit_decorator_metadata.then(|| Self { enum_types: FxHashMap::default() })
+    }
+}
+
+impl<'a> Traverse<'a> for TypeScriptDecoratorMetadata {
+    /// Collect enums before the transform, as enums declared before a class have already been
+    /// transformed when the class is visited.
+    fn enter_program(&mut self, program: &mut Program<'a>, _ctx: &mut TraverseCtx<'a>) {
+        EnumTypeCollector { enum_types: &mut self.enum_types }.visit_program(program);
+    }
+
+    fn enter_class(&mut self, class: &mut Class<'a>, ctx: &mut TraverseCtx<'a>) {
+        let class_span = class.span;
+
+        for element in class.body.body.iter_mut() {
+            match element {
+                ClassElement::MethodDefinition(method) => {
+                    if method.value.body.is_none()
+                        || (method.decorators.is_empty()
+                            && !has_parameter_decorators(&method.value.params))
+                    {
+                        continue;
+                    }
+                    let metadata = match method.kind {
+                        // Handled along with the class below
+                        MethodDefinitionKind::Constructor => continue,
+                        MethodDefinitionKind::Method => {
+                            let func = &method.value;
+                            let return_type = match &func.return_type {
+                                Some(return_type) => self.serialize_type(
+                                    &return_type.type_annotation,
+                                    class_span,
+                                    ctx,
+                                ),
+                                None if func.r#async => global_reference("Promise", ctx),
+                                None => ctx.ast.void_0(SPAN),
+                            };
+                            [
+                                Some(("design:type", global_reference("Function", ctx))),
+                                Some((
+                                    "design:paramtypes",
+                                    self.serialize_parameter_types(&func.params, class_span, ctx),
+                                )),
+                                Some(("design:returntype", return_type)),
+                            ]
+                        }
+                        MethodDefinitionKind::Get => {
+                            let ty =
+                                method.value.return_type.as_ref().map(|ty| &ty.type_annotation);
+                            [
+                                Some((
+                                    "design:type",
+                                    self.serialize_optional_type(ty, class_span, ctx),
+                                )),
+                                None,
+                                None,
+                            ]
+                        }
+                        MethodDefinitionKind::Set => {
+                            let params = &method.value.params;
+                            let ty = params.items.first().and_then(|param| {
+                                param.pattern.type_annotation.as_ref().map(|ty| &ty.type_annotation)
+                            });
+                            [
+                                Some((
+                                    "design:type",
+                                    self.serialize_optional_type(ty, class_span, ctx),
+                                )),
+                                Some((
+                                    "design:paramtypes",
+                                    self.serialize_parameter_types(params, class_span, ctx),
+                                )),
+                                None,
+                            ]
+                        }
+                    };
+                    push_metadata(&mut method.decorators, metadata.into_iter().flatten(), ctx);
+                }
+                ClassElement::PropertyDefinition(prop) => {
+                    if prop.declare || prop.decorators.is_empty() {
+                        continue;
+                    }
+                    let ty = prop.type_annotation.as_ref().map(|ty| &ty.type_annotation);
+                    let ty = self.serialize_optional_type(ty, class_span, ctx);
+                    push_metadata(&mut prop.decorators, [("design:type", ty)], ctx);
+                }
+                ClassElement::AccessorProperty(prop) => {
+                    if prop.decorators.is_empty() {
+                        continue;
+                    }
+                    let ty = prop.type_annotation.as_ref().map(|ty| &ty.type_annotation);
+                    let ty = self.serialize_optional_type(ty, class_span, ctx);
+                    push_metadata(&mut prop.decorators, [("design:type", ty)], ctx);
+                }
+                ClassElement::StaticBlock(_) | ClassElement::TSIndexSignature(_) => {}
+            }
+        }
+
+        // Class metadata includes the constructor parameters, and parameter decorators of the
+        // constructor apply to the class
+        let constructor = class.body.body.iter().find_map(|element| match element {
+            ClassElement::MethodDefinition(method)
+                if method.kind == MethodDefinitionKind::Constructor
+                    && method.value.body.is_some() =>
+            {
+                Some(&method.value.params)
+            }
+            _ => None,
+        });
+        let Some(params) = constructor else { return };
+        if class.decorators.is_empty() && !has_parameter_decorators(params) {
+            return;
+        }
+        let param_types = self.serialize_parameter_types(params, class_span, ctx);
+        push_metadata(&mut class.decorators, [("design:paramtypes", param_types)], ctx);
+    }
+}
+
+fn has_parameter_decorators(params: &FormalParameters<'_>) -> bool {
+    params.items.iter().any(|param| !param.decorators.is_empty())
+}
+
+/// Append `@Reflect.metadata(key, value)` for each entry of `metadata`.
+fn push_metadata<'a>(
+    decorators: &mut ArenaVec<'a, Decorator<'a>>,
+    metadata: impl IntoIterator<Item = (&'static str, Expression<'a>)>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    for (key, value) in metadata {
+        let reflect = global_reference("Reflect", ctx);
+        let callee = Expression::from(ctx.ast.member_expression_static(
+            SPAN,
+            reflect,
+            ctx.ast.identifier_name(SPAN, "metadata"),
+            false,
+        ));
+        let mut arguments = ctx.ast.vec_with_capacity(2);
+        arguments.push(Argument::from(ctx.ast.expression_string_literal(SPAN, key)));
+        arguments.push(Argument::from(value));
+        let expression = ctx.ast.expression_call(SPAN, callee, NONE, arguments, false, false);
+        decorators.push(ctx.ast.decorator(SPAN, expression));
+    }
+}
+
+impl TypeScriptDecoratorMetadata {
+    /// `[Number, String]`
+    ///
+    /// Rest parameters are serialized as their element type, e.g. `...args: string[]` -> `String`.
+    fn serialize_parameter_types<'a>(
+        &self,
+        params: &FormalParameters<'a>,
+        class_span: Span,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let mut elements = ctx.ast.vec_with_capacity(params.items.len() + 1);
+        for param in &params.items {
+            let ty = param.pattern.type_annotation.as_ref().map(|ty| &ty.type_annotation);
+            let ty = self.serialize_optional_type(ty, class_span, ctx);
+            elements.push(ArrayExpressionElement::from(ty));
+        }
+        if let Some(rest) = &params.rest {
+            let ty = rest.argument.type_annotation.as_ref().and_then(|ty| {
+                match ty.type_annotation.without_parenthesized() {
+                    TSType::TSArrayType(array) => Some(&array.element_type),
+                    TSType::TSTypeReference(reference) => {
+                        reference.type_parameters.as_ref().and_then(|params| params.params.first())
+                    }
+                    _ => None,
+                }
+            });
+            let ty = self.serialize_optional_type(ty, class_span, ctx);
+            elements.push(ArrayExpressionElement::from(ty));
+        }
+        ctx.ast.expression_array(SPAN, elements, None)
+    }
+
+    /// Serialize `ty`, or `Object` if there is no type annotation.
+    fn serialize_optional_type<'a>(
+        &self,
+        ty: Option<&TSType<'a>>,
+        class_span: Span,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        match ty {
+            Some(ty) => self.serialize_type(ty, class_span, ctx),
+            None => global_reference("Object", ctx),
+        }
+    }
+
+    fn serialize_type<'a>(
+        &self,
+        ty: &TSType<'a>,
+        class_span: Span,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        match SerializedType::from_type(ty) {
+            SerializedType::Void => ctx.ast.void_0(SPAN),
+            SerializedType::Global(name) => global_reference(name, ctx),
+            SerializedType::Reference(type_name) => {
+                self.serialize_type_reference(type_name, class_span, ctx)
+            }
+        }
+    }
+
+    /// `Foo` or `typeof Foo === "undefined" ? Object : Foo`, or `Object` if `Foo` is not a value.
+    fn serialize_type_reference<'a>(
+        &self,
+        type_name: &TSTypeName<'a>,
+        class_span: Span,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let ident = TSTypeName::get_first_name(type_name);
+        let symbol_id = ctx.symbols().get_reference(ident.reference_id()).symbol_id();
+
+        let guarded = match symbol_id {
+            Some(symbol_id) => {
+                // Enums serialize to the type of their members.
+                // Look them up by symbol, as enums declared earlier are already transformed to variables.
+                if let Some(&ty) = self.enum_types.get(&symbol_id) {
+                    return global_reference(ty, ctx);
+                }
+                let flags = ctx.symbols().get_flags(symbol_id);
+                // Interfaces, type aliases and type parameters have no runtime value
+                if !flags.is_value() || flags.is_type_import() {
+                    return global_reference("Object", ctx);
+                }
+                if flags.is_import() {
+                    true
+                } else if ctx.symbols().get_span(symbol_id).start > class_span.end {
+                    // Declared after the decorated class, e.g. `class A { b: B } class B { a: A }`.
+                    // `B` is in its TDZ when the decorators of `A` are evaluated.
+                    if flags.intersects(SymbolFlags::BlockScoped) {
+                        return global_reference("Object", ctx);
+                    }
+                    true
+                } else {
+                    false
+                }
+            }
+            None => true,
+        };
+
+        let expr = type_name_to_expression(type_name, symbol_id, ctx);
+        if !guarded {
+            return expr;
+        }
+        let typeof_expr = ctx.ast.expression_unary(SPAN, UnaryOperator::Typeof, expr);
+        let test = ctx.ast.expression_binary(
+            SPAN,
+            typeof_expr,
+            BinaryOperator::StrictEquality,
+            ctx.ast.expression_string_literal(SPAN, "undefined"),
+        );
+        let consequent = global_reference("Object", ctx);
+        let alternate = type_name_to_expression(type_name, symbol_id, ctx);
+        ctx.ast.expression_conditional(SPAN, test, consequent, alternate)
+    }
+}
+
+/// `Foo` or `ns.Foo`. `symbol_id` is the binding of the leftmost identifier.
+fn type_name_to_expression<'a>(
+    type_name: &TSTypeName<'a>,
+    symbol_id: Option<SymbolId>,
+    ctx: &mut TraverseCtx<'a>,
+) -> Expression<'a> {
+    match type_name {
+        TSTypeName::IdentifierReference(ident) => {
+            let ident =
+                ctx.create_reference_id(SPAN, ident.name.clone(), symbol_id, ReferenceFlags::Read);
+            Expression::Identifier(ctx.alloc(ident))
+        }
+        TSTypeName::QualifiedName(name) => {
+            let object = type_name_to_expression(&name.left, symbol_id, ctx);
+            let property = ctx.ast.identifier_name(SPAN, name.right.name.clone());
+            Expression::from(ctx.ast.member_expression_static(SPAN, object, property, false))
+        }
+    }
+}
+
+/// Reference to global `name`, e.g. `Number`.
+fn global_reference<'a>(name: &'static str, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+    let symbol_id = ctx.scopes().find_binding(ctx.current_scope_id(), name);
+    let ident = ctx.create_reference_id(SPAN, Atom::from(name), symbol_id, ReferenceFlags::Read);
+    Expression::Identifier(ctx.alloc(ident))
+}
+
+#[derive(Clone, Copy)]
+enum SerializedType<'t, 'a> {
+    /// `void 0`
+    Void,
+    /// `Number`, `Object` etc
+    Global(&'static str),
+    /// `Foo`
+    Reference(&'t TSTypeName<'a>),
+}
+
+impl<'t, 'a> SerializedType<'t, 'a> {
+    const OBJECT: Self = Self::Global("Object");
+
+    fn from_type(ty: &'t TSType<'a>) -> Self {
+        match ty {
+            TSType::TSNumberKeyword(_) => Self::Global("Number"),
+            TSType::TSStringKeyword(_) | TSType::TSTemplateLiteralType(_) => Self::Global("String"),
+            TSType::TSBooleanKeyword(_) => Self::Global("Boolean"),
+            TSType::TSBigIntKeyword(_) => Self::Global("BigInt"),
+            TSType::TSSymbolKeyword(_) => Self::Global("Symbol"),
+            TSType::TSVoidKeyword(_)
+            | TSType::TSUndefinedKeyword(_)
+            | TSType::TSNullKeyword(_)
+            | TSType::TSNeverKeyword(_) => Self::Void,
+            TSType::TSArrayType(_) | TSType::TSTupleType(_) => Self::Global("Array"),
+            TSType::TSFunctionType(_) | TSType::TSConstructorType(_) => Self::Global("Function"),
+            TSType::TSTypePredicate(predicate) => {
+                if predicate.asserts {
+                    Self::Void
+                } else {
+                    Self::Global("Boolean")
+                }
+            }
+            TSType::TSLiteralType(ty) => match &ty.literal {
+                TSLiteral::BooleanLiteral(_) => Self::Global("Boolean"),
+                TSLiteral::NumericLiteral(_) => Self::Global("Number"),
+                TSLiteral::BigIntLiteral(_) => Self::Global("BigInt"),
+                TSLiteral::StringLiteral(_) | TSLiteral::TemplateLiteral(_) => {
+                    Self::Global("String")
+                }
+                TSLiteral::NullLiteral(_) => Self::Void,
+                // `-1` or `-1n`
+                TSLiteral::UnaryExpression(expr) => match &expr.argument {
+                    Expression::BigIntLiteral(_) => Self::Global("BigInt"),
+                    _ => Self::Global("Number"),
+                },
+                TSLiteral::RegExpLiteral(_) => Self::OBJECT,
+            },
+            TSType::TSTypeOperatorType(ty) => match ty.operator {
+                TSTypeOperatorOperator::Readonly => Self::from_type(&ty.type_annotation),
+                TSTypeOperatorOperator::Unique => Self::Global("Symbol"),
+                TSTypeOperatorOperator::Keyof => Self::OBJECT,
+            },
+            TSType::TSParenthesizedType(ty) => Self::from_type(&ty.type_annotation),
+            TSType::JSDocNullableType(ty) => Self::from_type(&ty.type_annotation),
+            TSType::JSDocNonNullableType(ty) => Self::from_type(&ty.type_annotation),
+            TSType::TSTypeReference(ty) => Self::Reference(&ty.type_name),
+            TSType::TSUnionType(ty) => Self::from_constituents(&ty.types),
+            TSType::TSIntersectionType(ty) => Self::from_constituents(&ty.types),
+            _ => Self::OBJECT,
+        }
+    }
+
+    /// `string | null` -> `String`, `string | number` -> `Object`
+    fn from_constituents(types: &'t ArenaVec<'a, TSType<'a>>) -> Self {
+        let mut serialized = None;
+        for ty in types {
+            match (Self::from_type(ty), serialized) {
+                (Self::Void, _) => {}
+                (ty, None) => serialized = Some(ty),
+                (Self::Global(name), Some(Self::Global(prev))) if name == prev => {}
+                _ => return Self::OBJECT,
+            }
+        }
+        serialized.unwrap_or(Self::Void)
+    }
+}
+
+/// Collect the serialized types of enums, which are the types of their members.
+///
+/// Members without an initializer, or with an initializer which is not a string, are numbers,
+/// as TypeScript requires computed members to be numbers.
+struct EnumTypeCollector<'b> {
+    enum_types: &'b mut FxHashMap<SymbolId, &'static str>,
+}
+
+impl<'b> EnumTypeCollector<'b> {
+    /// `"a"`, `` `a` ``, `"a" + 1`, or a reference to a string member of the same enum
+    fn is_string_initializer<'a>(expr: &Expression<'a>, string_members: &[Atom<'a>]) -> bool {
+        match expr.without_parentheses() {
+            Expression::StringLiteral(_) | Expression::TemplateLiteral(_) => true,
+            Expression::BinaryExpression(expr) if expr.operator == BinaryOperator::Addition => {
+                Self::is_string_initializer(&expr.left, string_members)
+                    || Self::is_string_initializer(&expr.right, string_members)
+            }
+            Expression::Identifier(ident) => string_members.contains(&ident.name),
+            _ => false,
+        }
+    }
+}
+
+impl<'a, 'b> Visit<'a> for EnumTypeCollector<'b> {
+    fn visit_ts_enum_declaration(&mut self, decl: &TSEnumDeclaration<'a>) {
+        let mut string_members = vec![];
+        let mut has_number_member = false;
+        for member in &decl.members {
+            let is_string = member.initializer.as_ref().is_some_and(|initializer| {
+                Self::is_string_initializer(initializer, &string_members)
+            });
+            if is_string {
+                string_members.push(member.id.static_name());
+            } else {
+                has_number_member = true;
+            }
+        }
+
+        // Empty enums are numeric
+        let ty = if string_members.is_empty() {
+            "Number"
+        } else if has_number_member {
+            "Object"
+        } else {
+            "String"
+        };
+        // Merge with other declarations of the same enum
+        self.enum_types
+            .entry(decl.id.symbol_id())
+            .and_modify(|prev| {
+                if *prev != ty {
+                    *prev = "Object";
+                }
+            })
+            .or_insert(ty);
+
+        walk::walk_ts_enum_declaration(self, decl);
+    }
+}
@@ -13,7 +13,7 @@ pub fn import_equals_cannot_be_used_in_esm(span: Span) -> OxcDiagnostic {
 
 pub fn export_assignment_cannot_bed_used_in_esm(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("Export assignment cannot be used when targeting ECMAScript modules.")
-        .with_help("Consider using 'export default' or another module format instead.")
+        .with_help("Consider using 'export default' instead, or targeting CommonJS and importing with 'esModuleInterop'.")
         .with_label(span)
         .with_error_code("TS", "1203")
 }
@@ -32,3 +32,40 @@ pub fn namespace_not_supported(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("Namespace not marked type-only declare. Non-declarative namespaces are only supported experimentally in Babel. To enable and review caveats see: https://babeljs.io/docs/en/babel-plugin-transform-typescript")
         .with_label(span)
 }
+
+pub fn parameter_property_binding_pattern(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error("A parameter property may not be declared using a binding pattern.")
+        .with_label(span)
+        .with_error_code("TS", "1187")
+}
+
+pub fn const_enum_member_not_constant(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("const enum member initializers must be constant expressions.")
+        .with_help("The enum is emitted as a regular enum.")
+        .with_label(span)
+        .with_error_code("TS", "2474")
+}
+
+pub fn const_enum_invalid_reference(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("'const' enums can only be used in property or index access expressions.")
+        .with_help("The enum is emitted as a regular enum.")
+        .with_label(span)
+        .with_error_code("TS", "2475")
+}
+
+pub fn type_only_import_default_and_named(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error(
+        "A type-only import can specify a default import or named bindings, but not both.",
+    )
+    .with_label(span)
+    .with_error_code("TS", "1363")
+}
+
+pub fn re_exporting_type_requires_export_type(name: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(
+        "Re-exporting a type when 'isolatedModules' is enabled requires using 'export type'.",
+    )
+    .with_help(format!("If `{name}` is a type, use `export type {{ {name} }}`."))
+    .with_label(span)
+    .with_error_code("TS", "1205")
+}
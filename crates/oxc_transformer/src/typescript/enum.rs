@@ -1,28 +1,116 @@
 use rustc_hash::FxHashMap;
 
 use oxc_allocator::Vec as ArenaVec;
-use oxc_ast::{ast::*, visit::walk_mut, VisitMut, NONE};
+use oxc_ast::{
+    ast::*,
+    visit::{walk, walk_mut},
+    Visit, VisitMut, NONE,
+};
 use oxc_ecmascript::ToInt32;
-use oxc_span::{Atom, Span, SPAN};
+use oxc_span::{Atom, CompactStr, Span, SPAN};
 use oxc_syntax::{
     number::{NumberBase, ToJsString},
     operator::{AssignmentOperator, BinaryOperator, LogicalOperator, UnaryOperator},
     reference::ReferenceFlags,
-    symbol::SymbolFlags,
+    scope::ScopeFlags,
+    symbol::{SymbolFlags, SymbolId},
 };
 use oxc_traverse::{BoundIdentifier, Traverse, TraverseCtx};
 
-pub struct TypeScriptEnum<'a> {
+use super::diagnostics;
+use crate::{TransformCtx, TypeScriptOptions};
+
+pub struct TypeScriptEnum<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
+
+    // Options
+    optimize_const_enums: bool,
+
     enums: FxHashMap<Atom<'a>, FxHashMap<Atom<'a>, ConstantValue>>,
+    /// `const enum`s whose members are inlined, keyed by the symbol of the enum.
+    /// Only populated when `optimize_const_enums` is enabled.
+    const_enums: FxHashMap<SymbolId, ConstEnum<'a>>,
+    /// Constant members of regular enums which are declared at top level of a module,
+    /// and are only used by reading their members, keyed by the symbol of the enum.
+    /// Only populated when `optimize_const_enums` is enabled.
+    local_enums: FxHashMap<SymbolId, FxHashMap<Atom<'a>, ConstantValue>>,
+}
+
+#[derive(Default)]
+struct ConstEnum<'a> {
+    members: FxHashMap<Atom<'a>, ConstantValue>,
+    /// `true` if the enum object is needed at runtime, so its declaration is transformed
+    /// like a regular enum instead of being removed.
+    retain: bool,
 }
 
-impl<'a> TypeScriptEnum<'a> {
-    pub fn new() -> Self {
-        Self { enums: FxHashMap::default() }
+impl<'a, 'ctx> TypeScriptEnum<'a, 'ctx> {
+    pub fn new(options: &TypeScriptOptions, ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self {
+            ctx,
+            optimize_const_enums: options.optimize_const_enums,
+            enums: FxHashMap::default(),
+            const_enums: FxHashMap::default(),
+            local_enums: FxHashMap::default(),
+        }
     }
 }
 
-impl<'a> Traverse<'a> for TypeScriptEnum<'a> {
+impl<'a, 'ctx> Traverse<'a> for TypeScriptEnum<'a, 'ctx> {
+    /// Collect `const enum`s before the transform, so that member accesses which come before
+    /// the declaration can be inlined too.
+    fn enter_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        if !self.optimize_const_enums {
+            return;
+        }
+        ConstEnumCollector::new(self, ctx).collect(program);
+        // Enums are collected again in source order while transforming
+        self.enums.clear();
+    }
+
+    /// Remove `const enum` declarations which are inlined.
+    fn enter_statements(
+        &mut self,
+        stmts: &mut ArenaVec<'a, Statement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if self.const_enums.is_empty() {
+            return;
+        }
+        stmts.retain(|stmt| match stmt {
+            Statement::TSEnumDeclaration(decl) => !self.remove_const_enum(decl, ctx),
+            _ => true,
+        });
+    }
+
+    /// `Color.Red` -> `0`
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.const_enums.is_empty() && self.local_enums.is_empty() {
+            return;
+        }
+        let Some(member_expr) = expr.as_member_expression() else { return };
+        let Expression::Identifier(ident) = member_expr.object() else { return };
+        // References created by the transform of enum members have no `ReferenceId`
+        let Some(reference_id) = ident.reference_id.get() else { return };
+        let Some(symbol_id) = ctx.symbols().get_reference(reference_id).symbol_id() else {
+            return;
+        };
+        let members = match self.const_enums.get(&symbol_id) {
+            Some(const_enum) => Some(&const_enum.members),
+            None => self.local_enums.get(&symbol_id),
+        };
+        let Some(value) = members.and_then(|members| {
+            member_expr.static_property_name().and_then(|name| members.get(name))
+        }) else {
+            return;
+        };
+        let value = match value {
+            ConstantValue::Number(value) => Self::get_initializer_expr(*value, ctx),
+            ConstantValue::String(value) => ctx.ast.expression_string_literal(SPAN, value.as_str()),
+        };
+        ctx.delete_reference_for_identifier(ident);
+        *expr = value;
+    }
     fn enter_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
         let new_stmt = match stmt {
             Statement::TSEnumDeclaration(ts_enum_decl) => {
@@ -45,7 +133,7 @@ impl<'a> Traverse<'a> for TypeScriptEnum<'a> {
     }
 }
 
-impl<'a> TypeScriptEnum<'a> {
+impl<'a, 'ctx> TypeScriptEnum<'a, 'ctx> {
     /// ```TypeScript
     /// enum Foo {
     ///   X = 1,
@@ -76,6 +164,13 @@ impl<'a> TypeScriptEnum<'a> {
 
         let enum_name = decl.id.name.clone();
         let func_scope_id = decl.scope_id();
+        // The enum's scope becomes the scope of the function, where members are not bindings
+        let member_names =
+            ctx.scopes().get_bindings(func_scope_id).keys().cloned().collect::<Vec<_>>();
+        for name in &member_names {
+            ctx.scopes_mut().remove_binding(func_scope_id, name);
+        }
+        *ctx.scopes_mut().get_flags_mut(func_scope_id) |= ScopeFlags::Function;
         let param_binding = ctx.generate_binding(
             enum_name.clone(),
             func_scope_id,
@@ -134,9 +229,11 @@ impl<'a> TypeScriptEnum<'a> {
             ast.vec1(Argument::from(expression))
         };
 
-        let call_expression = ast.expression_call(SPAN, callee, NONE, arguments, false);
+        let call_expression = ast.expression_call(SPAN, callee, NONE, arguments, false, false);
 
         if is_already_declared {
+            // Merged declarations are assignments to the variable declared by the first one
+            ctx.symbols_mut().remove_redeclaration(var_symbol_id, decl.id.span);
             let op = AssignmentOperator::Assign;
             let left = ctx.create_bound_reference_id(
                 decl.id.span,
@@ -149,11 +246,13 @@ impl<'a> TypeScriptEnum<'a> {
             return Some(ast.statement_expression(decl.span, expr));
         }
 
-        let kind = if is_export || is_not_top_scope {
-            VariableDeclarationKind::Let
+        let (kind, symbol_flags) = if is_export || is_not_top_scope {
+            (VariableDeclarationKind::Let, SymbolFlags::BlockScopedVariable)
         } else {
-            VariableDeclarationKind::Var
+            (VariableDeclarationKind::Var, SymbolFlags::FunctionScopedVariable)
         };
+        let flags = ctx.symbols_mut().get_flags_mut(var_symbol_id);
+        *flags = (*flags & SymbolFlags::Export) | symbol_flags;
         let decls = {
             let binding_identifier = decl.id.clone();
             let binding_pattern_kind =
@@ -335,7 +434,73 @@ enum ConstantValue {
     String(String),
 }
 
-impl<'a> TypeScriptEnum<'a> {
+impl<'a, 'ctx> TypeScriptEnum<'a, 'ctx> {
+    /// Compute the values of members of an enum which can be inlined.
+    ///
+    /// Members of a `const enum` which are not constant are reported, and the enum is retained
+    /// at runtime. Members of a regular enum which are not constant are not inlined.
+    fn collect_enum(&mut self, decl: &TSEnumDeclaration<'a>) {
+        let mut members = self.enums.get(&decl.id.name).cloned().unwrap_or_default();
+        let mut retain = false;
+        let mut prev_constant_value = Some(ConstantValue::Number(-1.0));
+
+        for member in &decl.members {
+            let member_name = match &member.id {
+                TSEnumMemberName::Identifier(id) => &id.name,
+                TSEnumMemberName::String(str) => &str.value,
+            };
+            let constant_value = if let Some(initializer) = &member.initializer {
+                self.computed_constant_value(initializer, &members)
+            } else if let Some(ConstantValue::Number(value)) = prev_constant_value {
+                Some(ConstantValue::Number(value + 1.0))
+            } else {
+                None
+            };
+
+            if let Some(constant_value) = &constant_value {
+                members.insert(member_name.clone(), constant_value.clone());
+            } else if decl.r#const {
+                retain = true;
+                self.ctx.error(diagnostics::const_enum_member_not_constant(member.span));
+            }
+            prev_constant_value = constant_value;
+        }
+
+        self.enums.insert(decl.id.name.clone(), members.clone());
+        if !decl.r#const {
+            self.local_enums.insert(decl.id.symbol_id(), members);
+            return;
+        }
+        let const_enum = self.const_enums.entry(decl.id.symbol_id()).or_default();
+        const_enum.members.extend(members);
+        const_enum.retain |= retain;
+    }
+
+    /// Remove the declaration of a `const enum` if all of its references are inlined.
+    fn remove_const_enum(
+        &mut self,
+        decl: &TSEnumDeclaration<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> bool {
+        if !decl.r#const || decl.declare {
+            return false;
+        }
+        let Some(const_enum) = self.const_enums.get(&decl.id.symbol_id()) else { return false };
+        if const_enum.retain {
+            return false;
+        }
+
+        // Make members available to enums declared later, e.g. `enum Foo { A = Color.Red }`
+        let members = self.enums.entry(decl.id.name.clone()).or_default();
+        members
+            .extend(const_enum.members.iter().map(|(name, value)| (name.clone(), value.clone())));
+
+        let scope_id = ctx.current_scope_id();
+        ctx.scopes_mut().remove_binding(scope_id, &CompactStr::from(decl.id.name.as_str()));
+        ctx.scopes_mut().delete_scope(decl.scope_id());
+        true
+    }
+
     /// Evaluate the expression to a constant value.
     /// Refer to [babel](https://github.com/babel/babel/blob/610897a9a96c5e344e77ca9665df7613d2f88358/packages/babel-plugin-transform-typescript/src/enum.ts#L241C1-L394C2)
     fn computed_constant_value(
@@ -576,3 +741,126 @@ impl<'a, 'b> VisitMut<'a> for IdentifierReferenceRename<'a, 'b> {
         }
     }
 }
+
+/// Collect `const enum`s, and check that all of their references can be inlined.
+///
+/// A `const enum` is retained at runtime if it's exported, as it may be used by other modules,
+/// or if it's used other than by accessing a member, e.g. `Object.keys(Color)` or `Color[key]`.
+///
+/// Regular enums declared at top level of a module are collected too. As the enum object can be
+/// modified at runtime, their members are only inlined if the enum is not exported or merged
+/// with another declaration, and is only used by reading its members.
+struct ConstEnumCollector<'a, 'ctx, 'b> {
+    r#enum: &'b mut TypeScriptEnum<'a, 'ctx>,
+    ctx: &'b TraverseCtx<'a>,
+    /// References to `const enum`s, with the name of the accessed member if it's statically known
+    references: Vec<(SymbolId, Option<&'a str>, Span)>,
+}
+
+impl<'a, 'ctx, 'b> ConstEnumCollector<'a, 'ctx, 'b> {
+    fn new(r#enum: &'b mut TypeScriptEnum<'a, 'ctx>, ctx: &'b TraverseCtx<'a>) -> Self {
+        Self { r#enum, ctx, references: vec![] }
+    }
+
+    fn collect(mut self, program: &Program<'a>) {
+        self.visit_program(program);
+
+        for (symbol_id, member_name, span) in self.references {
+            let Some(const_enum) = self.r#enum.const_enums.get_mut(&symbol_id) else {
+                // Reading a member which is not constant is fine, but the enum object may be
+                // modified through any other reference
+                if member_name.is_none() {
+                    self.r#enum.local_enums.remove(&symbol_id);
+                }
+                continue;
+            };
+            if let Some(member_name) = member_name {
+                // Members which are not constant have already been reported
+                if !const_enum.members.contains_key(member_name) {
+                    const_enum.retain = true;
+                }
+            } else {
+                const_enum.retain = true;
+                self.r#enum.ctx.error(diagnostics::const_enum_invalid_reference(span));
+            }
+        }
+    }
+
+    /// Get symbol of the enum which `ident` refers to, ignoring references in types.
+    fn get_enum_symbol(&self, ident: &IdentifierReference<'a>) -> Option<SymbolId> {
+        let reference = self.ctx.symbols().get_reference(ident.reference_id());
+        if reference.is_type() {
+            return None;
+        }
+        let symbol_id = reference.symbol_id()?;
+        self.ctx.symbols().get_flags(symbol_id).intersects(SymbolFlags::Enum).then_some(symbol_id)
+    }
+
+    /// Whether the members of a regular enum can be inlined, if all references to it can.
+    fn is_local_enum(&self, decl: &TSEnumDeclaration<'a>) -> bool {
+        let symbol_id = decl.id.symbol_id();
+        let symbols = self.ctx.symbols();
+        !decl.r#const
+            && !decl.declare
+            && self.r#enum.ctx.source_type.is_module()
+            && symbols.get_scope_id(symbol_id) == self.ctx.scopes().root_scope_id()
+            && symbols.get_redeclarations(symbol_id).is_empty()
+    }
+}
+
+impl<'a, 'ctx, 'b> Visit<'a> for ConstEnumCollector<'a, 'ctx, 'b> {
+    fn visit_ts_enum_declaration(&mut self, decl: &TSEnumDeclaration<'a>) {
+        if (decl.r#const && !decl.declare) || self.is_local_enum(decl) {
+            self.r#enum.collect_enum(decl);
+        }
+        walk::walk_ts_enum_declaration(self, decl);
+    }
+
+    fn visit_export_named_declaration(&mut self, decl: &ExportNamedDeclaration<'a>) {
+        walk::walk_export_named_declaration(self, decl);
+        if let Some(Declaration::TSEnumDeclaration(decl)) = &decl.declaration {
+            if let Some(const_enum) = self.r#enum.const_enums.get_mut(&decl.id.symbol_id()) {
+                const_enum.retain = true;
+            }
+            self.r#enum.local_enums.remove(&decl.id.symbol_id());
+        }
+    }
+
+    fn visit_expression(&mut self, expr: &Expression<'a>) {
+        if let Some(member_expr) = expr.as_member_expression() {
+            if let Expression::Identifier(ident) = member_expr.object() {
+                if let Some(symbol_id) = self.get_enum_symbol(ident) {
+                    let member_name = member_expr.static_property_name();
+                    self.references.push((symbol_id, member_name, ident.span));
+                    if let MemberExpression::ComputedMemberExpression(member_expr) = member_expr {
+                        self.visit_expression(&member_expr.expression);
+                    }
+                    return;
+                }
+            }
+        }
+        walk::walk_expression(self, expr);
+    }
+
+    /// `delete Color.Red` modifies a regular enum
+    fn visit_unary_expression(&mut self, expr: &UnaryExpression<'a>) {
+        if expr.operator == UnaryOperator::Delete {
+            if let Some(Expression::Identifier(ident)) =
+                expr.argument.as_member_expression().map(MemberExpression::object)
+            {
+                if let Some(symbol_id) = self.get_enum_symbol(ident) {
+                    if self.ctx.symbols().get_flags(symbol_id).contains(SymbolFlags::RegularEnum) {
+                        self.references.push((symbol_id, None, ident.span));
+                    }
+                }
+            }
+        }
+        walk::walk_unary_expression(self, expr);
+    }
+
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+        if let Some(symbol_id) = self.get_enum_symbol(ident) {
+            self.references.push((symbol_id, None, ident.span));
+        }
+    }
+}
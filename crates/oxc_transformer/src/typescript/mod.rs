@@ -5,6 +5,7 @@ use oxc_traverse::{Traverse, TraverseCtx};
 use crate::TransformCtx;
 
 mod annotations;
+mod decorator_metadata;
 mod diagnostics;
 mod r#enum;
 mod module;
@@ -13,6 +14,7 @@ mod options;
 mod rewrite_extensions;
 
 use annotations::TypeScriptAnnotations;
+use decorator_metadata::TypeScriptDecoratorMetadata;
 use module::TypeScriptModule;
 use namespace::TypeScriptNamespace;
 use r#enum::TypeScriptEnum;
@@ -45,10 +47,11 @@ pub struct TypeScript<'a, 'ctx> {
     ctx: &'ctx TransformCtx<'a>,
 
     annotations: TypeScriptAnnotations<'a, 'ctx>,
-    r#enum: TypeScriptEnum<'a>,
+    r#enum: TypeScriptEnum<'a, 'ctx>,
     namespace: TypeScriptNamespace<'a, 'ctx>,
     module: TypeScriptModule<'a, 'ctx>,
     rewrite_extensions: Option<TypeScriptRewriteExtensions>,
+    decorator_metadata: Option<TypeScriptDecoratorMetadata>,
 }
 
 impl<'a, 'ctx> TypeScript<'a, 'ctx> {
@@ -56,10 +59,11 @@ impl<'a, 'ctx> TypeScript<'a, 'ctx> {
         Self {
             ctx,
             annotations: TypeScriptAnnotations::new(options, ctx),
-            r#enum: TypeScriptEnum::new(),
+            r#enum: TypeScriptEnum::new(options, ctx),
             namespace: TypeScriptNamespace::new(options, ctx),
             module: TypeScriptModule::new(ctx),
             rewrite_extensions: TypeScriptRewriteExtensions::new(options),
+            decorator_metadata: TypeScriptDecoratorMetadata::new(options),
         }
     }
 }
@@ -73,6 +77,10 @@ impl<'a, 'ctx> Traverse<'a> for TypeScript<'a, 'ctx> {
             program.body.clear();
         } else {
             program.source_type = program.source_type.with_javascript(true);
+            if let Some(decorator_metadata) = self.decorator_metadata.as_mut() {
+                decorator_metadata.enter_program(program, ctx);
+            }
+            self.r#enum.enter_program(program, ctx);
             self.namespace.enter_program(program, ctx);
         }
     }
@@ -107,6 +115,9 @@ impl<'a, 'ctx> Traverse<'a> for TypeScript<'a, 'ctx> {
     }
 
     fn enter_class(&mut self, class: &mut Class<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(decorator_metadata) = self.decorator_metadata.as_mut() {
+            decorator_metadata.enter_class(class, ctx);
+        }
         self.annotations.enter_class(class, ctx);
     }
 
@@ -124,6 +135,15 @@ impl<'a, 'ctx> Traverse<'a> for TypeScript<'a, 'ctx> {
 
     fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
         self.annotations.enter_expression(expr, ctx);
+        self.r#enum.enter_expression(expr, ctx);
+    }
+
+    fn enter_export_default_declaration(
+        &mut self,
+        decl: &mut ExportDefaultDeclaration<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        self.annotations.enter_export_default_declaration(decl, ctx);
     }
 
     fn enter_simple_assignment_target(
@@ -204,6 +224,7 @@ impl<'a, 'ctx> Traverse<'a> for TypeScript<'a, 'ctx> {
         ctx: &mut TraverseCtx<'a>,
     ) {
         self.annotations.enter_statements(stmts, ctx);
+        self.r#enum.enter_statements(stmts, ctx);
     }
 
     fn exit_statements(
@@ -223,6 +244,14 @@ impl<'a, 'ctx> Traverse<'a> for TypeScript<'a, 'ctx> {
         self.annotations.exit_statement(stmt, ctx);
     }
 
+    fn enter_ts_export_assignment(
+        &mut self,
+        export_assignment: &mut TSExportAssignment<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        self.module.enter_ts_export_assignment(export_assignment, ctx);
+    }
+
     fn enter_if_statement(&mut self, stmt: &mut IfStatement<'a>, ctx: &mut TraverseCtx<'a>) {
         self.annotations.enter_if_statement(stmt, ctx);
     }
@@ -291,6 +320,16 @@ impl<'a, 'ctx> Traverse<'a> for TypeScript<'a, 'ctx> {
         }
     }
 
+    fn enter_import_expression(
+        &mut self,
+        node: &mut ImportExpression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if let Some(rewrite_extensions) = &mut self.rewrite_extensions {
+            rewrite_extensions.enter_import_expression(node, ctx);
+        }
+    }
+
     fn enter_export_named_declaration(
         &mut self,
         node: &mut ExportNamedDeclaration<'a>,
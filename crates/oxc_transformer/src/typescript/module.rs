@@ -1,6 +1,6 @@
 use oxc_ast::{ast::*, NONE};
-use oxc_span::{CompactStr, SPAN};
-use oxc_syntax::reference::ReferenceFlags;
+use oxc_span::{Atom, CompactStr, SPAN};
+use oxc_syntax::{reference::ReferenceFlags, symbol::SymbolFlags};
 use oxc_traverse::{Traverse, TraverseCtx};
 
 use super::diagnostics;
@@ -31,7 +31,23 @@ impl<'a, 'ctx> Traverse<'a> for TypeScriptModule<'a, 'ctx> {
 
     fn enter_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
         if let Statement::TSExportAssignment(export_assignment) = stmt {
-            *stmt = self.transform_ts_export_assignment(export_assignment, ctx);
+            // `module.exports` is not valid in ESM, the statement is removed by `TypeScriptAnnotations`
+            // and reported in `enter_ts_export_assignment`.
+            if !self.ctx.module.is_esm() {
+                *stmt = Self::transform_ts_export_assignment(export_assignment, ctx);
+            }
+        }
+    }
+
+    fn enter_ts_export_assignment(
+        &mut self,
+        export_assignment: &mut TSExportAssignment<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+        if self.ctx.module.is_esm() {
+            self.ctx.error(diagnostics::export_assignment_cannot_bed_used_in_esm(
+                export_assignment.span,
+            ));
         }
     }
 
@@ -47,16 +63,9 @@ impl<'a, 'ctx> Traverse<'a> for TypeScriptModule<'a, 'ctx> {
 impl<'a, 'ctx> TypeScriptModule<'a, 'ctx> {
     /// Transform `export = expression` to `module.exports = expression`.
     fn transform_ts_export_assignment(
-        &mut self,
         export_assignment: &mut TSExportAssignment<'a>,
         ctx: &mut TraverseCtx<'a>,
     ) -> Statement<'a> {
-        if self.ctx.module.is_esm() {
-            self.ctx.error(diagnostics::export_assignment_cannot_bed_used_in_esm(
-                export_assignment.span,
-            ));
-        }
-
         // module.exports
         let module_exports = {
             let reference_id = ctx
@@ -70,6 +79,11 @@ impl<'a, 'ctx> TypeScriptModule<'a, 'ctx> {
 
         let left = AssignmentTarget::from(SimpleAssignmentTarget::from(module_exports));
         let right = ctx.ast.move_expression(&mut export_assignment.expression);
+        // `export = foo` may refer to a type, but `module.exports = foo` only reads a value
+        if let Expression::Identifier(ident) = &right {
+            let reference = ctx.symbols_mut().get_reference_mut(ident.reference_id());
+            *reference.flags_mut() = ReferenceFlags::Read;
+        }
         let assignment_expr =
             ctx.ast.expression_assignment(SPAN, AssignmentOperator::Assign, left, right);
         ctx.ast.statement_expression(SPAN, assignment_expr)
@@ -91,38 +105,48 @@ impl<'a, 'ctx> TypeScriptModule<'a, 'ctx> {
         ctx: &mut TraverseCtx<'a>,
     ) -> Declaration<'a> {
         let binding_pattern_kind =
-            ctx.ast.binding_pattern_kind_binding_identifier(SPAN, &decl.id.name);
+            BindingPatternKind::BindingIdentifier(ctx.alloc(decl.id.clone()));
         let binding = ctx.ast.binding_pattern(binding_pattern_kind, NONE, false);
         let decl_span = decl.span;
 
         let (kind, init) = match &mut decl.module_reference {
             type_name @ match_ts_type_name!(TSModuleReference) => (
                 VariableDeclarationKind::Var,
-                self.transform_ts_type_name(&mut *type_name.to_ts_type_name_mut(), ctx),
+                Self::transform_ts_type_name(&mut *type_name.to_ts_type_name_mut(), ctx),
             ),
             TSModuleReference::ExternalModuleReference(reference) => {
                 if self.ctx.module.is_esm() {
                     self.ctx.error(diagnostics::import_equals_cannot_be_used_in_esm(decl_span));
                 }
 
-                let callee = ctx.ast.expression_identifier_reference(SPAN, "require");
+                let callee = ctx.create_unbound_reference_id(
+                    SPAN,
+                    Atom::from("require"),
+                    ReferenceFlags::Read,
+                );
+                let callee = Expression::Identifier(ctx.alloc(callee));
                 let arguments =
                     ctx.ast.vec1(Argument::StringLiteral(ctx.alloc(reference.expression.clone())));
                 (
                     VariableDeclarationKind::Const,
-                    ctx.ast.expression_call(SPAN, callee, NONE, arguments, false),
+                    ctx.ast.expression_call(SPAN, callee, NONE, arguments, false, false),
                 )
             }
         };
+        let symbol_flags = match kind {
+            VariableDeclarationKind::Var => SymbolFlags::FunctionScopedVariable,
+            _ => SymbolFlags::BlockScopedVariable | SymbolFlags::ConstVariable,
+        };
+        let flags = ctx.symbols_mut().get_flags_mut(decl.id.symbol_id());
+        *flags = (*flags & SymbolFlags::Export) | symbol_flags;
         let decls =
             ctx.ast.vec1(ctx.ast.variable_declarator(SPAN, kind, binding, Some(init), false));
 
         ctx.ast.declaration_variable(SPAN, kind, decls, false)
     }
 
-    #[allow(clippy::only_used_in_recursion)]
-    fn transform_ts_type_name(
-        &self,
+    /// `A.B.C` -> `A.B.C` as an expression
+    pub(super) fn transform_ts_type_name(
         type_name: &mut TSTypeName<'a>,
         ctx: &mut TraverseCtx<'a>,
     ) -> Expression<'a> {
@@ -137,7 +161,7 @@ impl<'a, 'ctx> TypeScriptModule<'a, 'ctx> {
                 .ast
                 .member_expression_static(
                     SPAN,
-                    self.transform_ts_type_name(&mut qualified_name.left, ctx),
+                    Self::transform_ts_type_name(&mut qualified_name.left, ctx),
                     qualified_name.right.clone(),
                     false,
                 )
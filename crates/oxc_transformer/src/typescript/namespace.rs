@@ -1,20 +1,23 @@
-use rustc_hash::FxHashSet;
+use rustc_hash::{FxHashMap, FxHashSet};
 
 use oxc_allocator::{Box as ArenaBox, Vec as ArenaVec};
-use oxc_ast::{ast::*, NONE};
+use oxc_ast::{ast::*, Visit, NONE};
 use oxc_ecmascript::BoundNames;
+use oxc_semantic::Reference;
 use oxc_span::{Atom, CompactStr, SPAN};
 use oxc_syntax::{
     operator::{AssignmentOperator, LogicalOperator},
     scope::{ScopeFlags, ScopeId},
     symbol::SymbolFlags,
 };
-use oxc_traverse::{Traverse, TraverseCtx};
+use oxc_traverse::{BoundIdentifier, Traverse, TraverseCtx};
 
-use crate::TransformCtx;
+use crate::{es2015::ChildScopeCollector, TransformCtx};
 
 use super::{
+    annotations::TypeScriptAnnotations,
     diagnostics::{ambient_module_nested, namespace_exporting_non_const, namespace_not_supported},
+    module::TypeScriptModule,
     TypeScriptOptions,
 };
 
@@ -23,11 +26,26 @@ pub struct TypeScriptNamespace<'a, 'ctx> {
 
     // Options
     allow_namespaces: bool,
+    flatten_namespaces: bool,
+
+    /// Names exported from each namespace across all of its declarations,
+    /// keyed by namespace path e.g. `A.B`
+    exported_names: FxHashMap<String, FxHashSet<Atom<'a>>>,
+
+    /// Namespaces removed because they only contain types. Their symbols are removed after
+    /// all namespaces are transformed, as later declarations may still look them up.
+    removed_namespaces: Vec<BindingIdentifier<'a>>,
 }
 
 impl<'a, 'ctx> TypeScriptNamespace<'a, 'ctx> {
     pub fn new(options: &TypeScriptOptions, ctx: &'ctx TransformCtx<'a>) -> Self {
-        Self { ctx, allow_namespaces: options.allow_namespaces }
+        Self {
+            ctx,
+            allow_namespaces: options.allow_namespaces,
+            flatten_namespaces: options.flatten_namespaces,
+            exported_names: FxHashMap::default(),
+            removed_namespaces: vec![],
+        }
     }
 }
 
@@ -40,6 +58,8 @@ impl<'a, 'ctx> Traverse<'a> for TypeScriptNamespace<'a, 'ctx> {
             return;
         }
 
+        collect_exported_names(&program.body, "", &mut self.exported_names);
+
         // Collect function/class/enum/namespace binding names
         let mut names: FxHashSet<Atom<'a>> = FxHashSet::default();
 
@@ -56,21 +76,24 @@ impl<'a, 'ctx> Traverse<'a> for TypeScriptNamespace<'a, 'ctx> {
                             self.ctx.error(namespace_not_supported(decl.span));
                         }
 
-                        if let Some(transformed_stmt) = self.handle_nested(
+                        let transformed_stmt = self.handle_nested(
                             {
                                 // SAFETY: `ast.copy` is unsound! We need to fix.
                                 unsafe { ctx.ast.copy(&decl) }.unbox()
                             },
                             None,
+                            "",
                             ctx,
-                        ) {
-                            let name = decl.id.name();
-                            if names.insert(name.clone()) {
-                                new_stmts.push(Statement::from(Self::create_variable_declaration(
-                                    name, ctx,
-                                )));
+                        );
+                        if let TSModuleDeclarationName::Identifier(id) = &decl.id {
+                            if let Some(transformed_stmt) = transformed_stmt {
+                                if let Some(declaration) =
+                                    Self::create_variable_declaration(id, &mut names, ctx)
+                                {
+                                    new_stmts.push(Statement::from(declaration));
+                                }
+                                new_stmts.push(transformed_stmt);
                             }
-                            new_stmts.push(transformed_stmt);
                             continue;
                         }
                     }
@@ -85,28 +108,49 @@ impl<'a, 'ctx> Traverse<'a> for TypeScriptNamespace<'a, 'ctx> {
                                     self.ctx.error(namespace_not_supported(decl.span));
                                 }
 
-                                if let Some(transformed_stmt) = self.handle_nested(
+                                if self.flatten_namespaces
+                                    && self.ctx.source_type.is_module()
+                                    && Self::can_flatten(decl, ctx)
+                                {
+                                    let Statement::ExportNamedDeclaration(export_decl) = stmt
+                                    else {
+                                        unreachable!()
+                                    };
+                                    let Some(Declaration::TSModuleDeclaration(decl)) =
+                                        export_decl.unbox().declaration
+                                    else {
+                                        unreachable!()
+                                    };
+                                    new_stmts.extend(Self::flatten(decl.unbox(), ctx));
+                                    continue;
+                                }
+
+                                let transformed_stmt = self.handle_nested(
                                     {
                                         // SAFETY: `ast.copy` is unsound! We need to fix.
                                         unsafe { ctx.ast.copy(decl) }
                                     },
                                     None,
+                                    "",
                                     ctx,
-                                ) {
-                                    let name = decl.id.name();
-                                    if names.insert(name.clone()) {
-                                        let declaration =
-                                            Self::create_variable_declaration(name, ctx);
-                                        let export_named_decl =
-                                            ctx.ast.plain_export_named_declaration_declaration(
-                                                SPAN,
-                                                declaration,
+                                );
+                                if let TSModuleDeclarationName::Identifier(id) = &decl.id {
+                                    if let Some(transformed_stmt) = transformed_stmt {
+                                        if let Some(declaration) =
+                                            Self::create_variable_declaration(id, &mut names, ctx)
+                                        {
+                                            let export_named_decl =
+                                                ctx.ast.plain_export_named_declaration_declaration(
+                                                    SPAN,
+                                                    declaration,
+                                                );
+                                            let stmt = Statement::ExportNamedDeclaration(
+                                                export_named_decl,
                                             );
-                                        let stmt =
-                                            Statement::ExportNamedDeclaration(export_named_decl);
-                                        new_stmts.push(stmt);
+                                            new_stmts.push(stmt);
+                                        }
+                                        new_stmts.push(transformed_stmt);
                                     }
-                                    new_stmts.push(transformed_stmt);
                                     continue;
                                 }
                             }
@@ -139,31 +183,120 @@ impl<'a, 'ctx> Traverse<'a> for TypeScriptNamespace<'a, 'ctx> {
         }
 
         program.body = new_stmts;
+
+        for id in std::mem::take(&mut self.removed_namespaces) {
+            Self::remove_namespace_symbol(&id, ctx);
+        }
     }
 }
 
 impl<'a, 'ctx> TypeScriptNamespace<'a, 'ctx> {
-    fn handle_nested(
-        &self,
+    /// Whether an exported namespace can be emitted as ES module exports.
+    ///
+    /// The namespace must not contain nested namespaces or export `let`/`var` variables,
+    /// must not be merged with other declarations or referenced as a value, and none of its
+    /// bindings can clash with top level bindings or global references.
+    fn can_flatten(decl: &TSModuleDeclaration<'a>, ctx: &TraverseCtx<'a>) -> bool {
+        let TSModuleDeclarationName::Identifier(id) = &decl.id else { return false };
+        let Some(TSModuleDeclarationBody::TSModuleBlock(block)) = &decl.body else { return false };
+
+        let is_flat = block.body.iter().all(|stmt| match stmt {
+            Statement::TSModuleDeclaration(_) => false,
+            Statement::ExportNamedDeclaration(export_decl) => match &export_decl.declaration {
+                Some(
+                    Declaration::TSModuleDeclaration(_) | Declaration::TSImportEqualsDeclaration(_),
+                ) => false,
+                Some(Declaration::VariableDeclaration(var_decl)) => var_decl.kind.is_const(),
+                _ => true,
+            },
+            _ => true,
+        });
+        if !is_flat {
+            return false;
+        }
+
+        let (Some(symbol_id), Some(scope_id)) = (id.symbol_id.get(), decl.scope_id.get()) else {
+            return false;
+        };
+        let symbols = ctx.symbols();
+        if !symbols.get_redeclarations(symbol_id).is_empty()
+            || symbols.get_resolved_references(symbol_id).any(Reference::is_value)
+        {
+            return false;
+        }
+
+        let scopes = ctx.scopes();
+        let root_scope_id = scopes.root_scope_id();
+        let unresolved_references = scopes.root_unresolved_references();
+        scopes.get_bindings(scope_id).keys().all(|name| {
+            !scopes.has_binding(root_scope_id, name) && !unresolved_references.contains_key(name)
+        })
+    }
+
+    /// `export namespace N { export const a = 1; }` -> `export const a = 1;`
+    ///
+    /// Bindings and child scopes of the namespace are moved to the program scope.
+    fn flatten(
         decl: TSModuleDeclaration<'a>,
-        parent_export: Option<Expression<'a>>,
         ctx: &mut TraverseCtx<'a>,
-    ) -> Option<Statement<'a>> {
-        // Skip empty declaration e.g. `namespace x;`
-        let body = decl.body?;
+    ) -> ArenaVec<'a, Statement<'a>> {
+        let Some(TSModuleDeclarationBody::TSModuleBlock(block)) = decl.body else { unreachable!() };
+        let stmts = block.unbox().body;
+        let scope_id = decl.scope_id.get().unwrap();
+        let root_scope_id = ctx.scopes().root_scope_id();
 
-        let mut names: FxHashSet<Atom<'a>> = FxHashSet::default();
+        let bindings = ctx
+            .scopes()
+            .get_bindings(scope_id)
+            .iter()
+            .map(|(name, &symbol_id)| (name.clone(), symbol_id))
+            .collect::<Vec<_>>();
+        for (name, symbol_id) in bindings {
+            ctx.scopes_mut().move_binding(scope_id, root_scope_id, &name);
+            ctx.symbols_mut().set_scope_id(symbol_id, root_scope_id);
+        }
+
+        let mut collector = ChildScopeCollector::default();
+        collector.visit_statements(&stmts);
+        collector.move_to(root_scope_id, ctx);
+        ctx.scopes_mut().delete_scope(scope_id);
 
-        let TSModuleDeclarationName::Identifier(BindingIdentifier { name: real_name, .. }) =
-            decl.id
-        else {
+        let name = CompactStr::from(decl.id.name().as_str());
+        ctx.scopes_mut().remove_binding(root_scope_id, &name);
+
+        stmts
+    }
+
+    /// Transform a namespace declaration to a function call.
+    ///
+    /// Returns `None` if the namespace is removed, because it only contains types.
+    fn handle_nested(
+        &mut self,
+        decl: TSModuleDeclaration<'a>,
+        parent_binding: Option<&BoundIdentifier<'a>>,
+        parent_path: &str,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<Statement<'a>> {
+        let TSModuleDeclarationName::Identifier(id) = decl.id else {
             return None;
         };
 
         // Reuse `TSModuleDeclaration`'s scope in transformed function
         let scope_id = decl.scope_id.get().unwrap();
+
+        // Skip empty declaration e.g. `namespace x;`
+        let Some(body) = decl.body else {
+            ctx.scopes_mut().delete_scope(scope_id);
+            self.removed_namespaces.push(id);
+            return None;
+        };
+
+        let mut names: FxHashSet<Atom<'a>> = FxHashSet::default();
+
+        let real_name = id.name.clone();
+        let path = namespace_path(parent_path, &real_name);
+
         let binding = ctx.generate_uid(&real_name, scope_id, SymbolFlags::FunctionScopedVariable);
-        let name = binding.name;
 
         let directives;
         let namespace_top_level;
@@ -198,13 +331,15 @@ impl<'a, 'ctx> TypeScriptNamespace<'a, 'ctx> {
                         continue;
                     }
 
-                    let module_name = decl.id.name().clone();
-                    if let Some(transformed) = self.handle_nested(decl.unbox(), None, ctx) {
-                        if names.insert(module_name.clone()) {
-                            new_stmts.push(Statement::from(Self::create_variable_declaration(
-                                module_name.clone(),
-                                ctx,
-                            )));
+                    let TSModuleDeclarationName::Identifier(module_id) = &decl.id else {
+                        unreachable!()
+                    };
+                    let module_id = module_id.clone();
+                    if let Some(transformed) = self.handle_nested(decl.unbox(), None, &path, ctx) {
+                        if let Some(declaration) =
+                            Self::create_variable_declaration(&module_id, &mut names, ctx)
+                        {
+                            new_stmts.push(Statement::from(declaration));
                         }
                         new_stmts.push(transformed);
                     }
@@ -224,7 +359,7 @@ impl<'a, 'ctx> TypeScriptNamespace<'a, 'ctx> {
                             | Declaration::ClassDeclaration(_) => {
                                 Self::add_declaration(
                                     decl,
-                                    name.clone(),
+                                    &binding,
                                     &mut names,
                                     &mut new_stmts,
                                     ctx,
@@ -237,7 +372,7 @@ impl<'a, 'ctx> TypeScriptNamespace<'a, 'ctx> {
                                     }
                                 });
                                 let stmts =
-                                    Self::handle_variable_declaration(var_decl, name.clone(), ctx);
+                                    Self::handle_variable_declaration(var_decl, &binding, ctx);
                                 new_stmts.extend(stmts);
                             }
                             Declaration::TSModuleDeclaration(module_decl) => {
@@ -246,24 +381,46 @@ impl<'a, 'ctx> TypeScriptNamespace<'a, 'ctx> {
                                     continue;
                                 }
 
-                                let module_name = module_decl.id.name().clone();
+                                let TSModuleDeclarationName::Identifier(module_id) =
+                                    &module_decl.id
+                                else {
+                                    unreachable!()
+                                };
+                                let module_id = module_id.clone();
+                                // The namespace is no longer exported, only assigned to the parent
+                                *ctx.symbols_mut().get_flags_mut(module_id.symbol_id()) -=
+                                    SymbolFlags::Export;
                                 if let Some(transformed) = self.handle_nested(
                                     module_decl.unbox(),
-                                    Some(ctx.ast.expression_identifier_reference(SPAN, &name)),
+                                    Some(&binding),
+                                    &path,
                                     ctx,
                                 ) {
-                                    if names.insert(module_name.clone()) {
-                                        new_stmts.push(Statement::from(
-                                            Self::create_variable_declaration(
-                                                module_name.clone(),
-                                                ctx,
-                                            ),
-                                        ));
+                                    if let Some(declaration) = Self::create_variable_declaration(
+                                        &module_id, &mut names, ctx,
+                                    ) {
+                                        new_stmts.push(Statement::from(declaration));
                                     }
                                     new_stmts.push(transformed);
                                 }
                             }
-                            _ => {}
+                            Declaration::TSImportEqualsDeclaration(import_decl) => {
+                                if let Some(var_decl) = self.handle_import_equals_declaration(
+                                    import_decl,
+                                    &binding,
+                                    &path,
+                                    scope_id,
+                                    ctx,
+                                ) {
+                                    let stmts =
+                                        Self::handle_variable_declaration(var_decl, &binding, ctx);
+                                    new_stmts.extend(stmts);
+                                }
+                            }
+                            Declaration::TSTypeAliasDeclaration(_)
+                            | Declaration::TSInterfaceDeclaration(_) => {
+                                TypeScriptAnnotations::remove_type_declaration_symbol(&decl, ctx);
+                            }
                         }
                     }
                     continue;
@@ -274,9 +431,21 @@ impl<'a, 'ctx> TypeScriptNamespace<'a, 'ctx> {
                 | Statement::TSEnumDeclaration(_) => {
                     names.insert(stmt.to_declaration().id().as_ref().unwrap().name.clone());
                 }
-                Statement::TSTypeAliasDeclaration(_)
-                | Statement::TSInterfaceDeclaration(_)
-                | Statement::TSImportEqualsDeclaration(_) => continue,
+                Statement::TSTypeAliasDeclaration(_) | Statement::TSInterfaceDeclaration(_) => {
+                    TypeScriptAnnotations::remove_type_declaration_symbol(
+                        stmt.to_declaration(),
+                        ctx,
+                    );
+                    continue;
+                }
+                // `import x = A.B` is transformed to `var x = A.B` later
+                Statement::TSImportEqualsDeclaration(ref decl) if decl.import_kind.is_type() => {
+                    TypeScriptAnnotations::remove_type_declaration_symbol(
+                        stmt.to_declaration(),
+                        ctx,
+                    );
+                    continue;
+                }
                 _ => {}
             }
             new_stmts.push(stmt);
@@ -285,17 +454,19 @@ impl<'a, 'ctx> TypeScriptNamespace<'a, 'ctx> {
         if new_stmts.is_empty() {
             // Delete the scope binding that `ctx.generate_uid` created above,
             // as no binding is actually being created
-            ctx.scopes_mut().remove_binding(scope_id, &CompactStr::from(name.as_str()));
+            ctx.scopes_mut().remove_binding(scope_id, &CompactStr::from(binding.name.as_str()));
+            ctx.scopes_mut().delete_scope(scope_id);
+            self.removed_namespaces.push(id);
 
             return None;
         }
 
         Some(Self::transform_namespace(
-            name,
-            real_name,
+            &binding,
+            &id,
             new_stmts,
             directives,
-            parent_export,
+            parent_binding,
             scope_id,
             ctx,
         ))
@@ -303,26 +474,70 @@ impl<'a, 'ctx> TypeScriptNamespace<'a, 'ctx> {
 
     // `namespace Foo { }` -> `let Foo; (function (_Foo) { })(Foo || (Foo = {}));`
     //                         ^^^^^^^
-    fn create_variable_declaration(name: Atom<'a>, ctx: &TraverseCtx<'a>) -> Declaration<'a> {
+    //
+    // Returns `None` if `Foo` is already declared, by a previous declaration of the namespace,
+    // or by a class, function or enum of the same name.
+    fn create_variable_declaration(
+        id: &BindingIdentifier<'a>,
+        names: &mut FxHashSet<Atom<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<Declaration<'a>> {
+        let symbol_id = id.symbol_id();
+        let symbols = ctx.symbols_mut();
+        // Merged declarations are assignments to the variable declared by the first one
+        symbols.remove_redeclaration(symbol_id, id.span);
+        let flags = symbols.get_flags_mut(symbol_id);
+        *flags -= SymbolFlags::NameSpaceModule | SymbolFlags::ValueModule;
+        if !names.insert(id.name.clone()) {
+            return None;
+        }
+        *flags |= SymbolFlags::BlockScopedVariable;
+
         let kind = VariableDeclarationKind::Let;
         let declarations = {
-            let pattern_kind = ctx.ast.binding_pattern_kind_binding_identifier(SPAN, name);
+            let pattern_kind = BindingPatternKind::BindingIdentifier(ctx.alloc(id.clone()));
             let binding = ctx.ast.binding_pattern(pattern_kind, NONE, false);
             let decl = ctx.ast.variable_declarator(SPAN, kind, binding, None, false);
             ctx.ast.vec1(decl)
         };
-        ctx.ast.declaration_variable(SPAN, kind, declarations, false)
+        Some(ctx.ast.declaration_variable(SPAN, kind, declarations, false))
+    }
+
+    /// Remove symbol of a namespace which has been removed, because it only contains types.
+    ///
+    /// If the namespace is merged with another declaration, only the namespace's declaration
+    /// is removed from the symbol.
+    fn remove_namespace_symbol(id: &BindingIdentifier<'a>, ctx: &mut TraverseCtx<'a>) {
+        let symbol_id = id.symbol_id();
+        let symbols = ctx.symbols_mut();
+        *symbols.get_flags_mut(symbol_id) -=
+            SymbolFlags::NameSpaceModule | SymbolFlags::ValueModule;
+        if symbols.get_redeclarations(symbol_id).is_empty() {
+            if !symbols.get_flags(symbol_id).is_value() {
+                let scope_id = symbols.get_scope_id(symbol_id);
+                ctx.scopes_mut().remove_binding(scope_id, &CompactStr::from(id.name.as_str()));
+            }
+            return;
+        }
+        // If the removed declaration came first, the next declaration becomes the declaration
+        let span = if symbols.get_span(symbol_id) == id.span {
+            let next_span = symbols.get_redeclarations(symbol_id)[0];
+            symbols.spans[symbol_id] = next_span;
+            next_span
+        } else {
+            id.span
+        };
+        symbols.remove_redeclaration(symbol_id, span);
     }
 
     // `namespace Foo { }` -> `let Foo; (function (_Foo) { })(Foo || (Foo = {}));`
     //                                  ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
-    #[allow(clippy::needless_pass_by_value, clippy::too_many_arguments)]
     fn transform_namespace(
-        arg_name: Atom<'a>,
-        real_name: Atom<'a>,
+        binding: &BoundIdentifier<'a>,
+        id: &BindingIdentifier<'a>,
         stmts: ArenaVec<'a, Statement<'a>>,
         directives: ArenaVec<'a, Directive<'a>>,
-        parent_export: Option<Expression<'a>>,
+        parent_binding: Option<&BoundIdentifier<'a>>,
         scope_id: ScopeId,
         ctx: &mut TraverseCtx<'a>,
     ) -> Statement<'a> {
@@ -331,8 +546,7 @@ impl<'a, 'ctx> TypeScriptNamespace<'a, 'ctx> {
         let callee = {
             let body = ctx.ast.function_body(SPAN, directives, stmts);
             let params = {
-                let ident = ctx.ast.binding_pattern_kind_binding_identifier(SPAN, arg_name);
-                let pattern = ctx.ast.binding_pattern(ident, NONE, false);
+                let pattern = binding.create_binding_pattern(ctx);
                 let items = ctx.ast.vec1(ctx.ast.plain_formal_parameter(SPAN, pattern));
                 ctx.ast.formal_parameters(SPAN, FormalParameterKind::FormalParameter, items, NONE)
             };
@@ -345,36 +559,32 @@ impl<'a, 'ctx> TypeScriptNamespace<'a, 'ctx> {
                     body,
                     scope_id,
                 ));
-            *ctx.scopes_mut().get_flags_mut(scope_id) =
-                ScopeFlags::Function | ScopeFlags::StrictMode;
+            let flags = ctx.scopes_mut().get_flags_mut(scope_id);
+            *flags = ScopeFlags::Function | (*flags & ScopeFlags::StrictMode);
             ctx.ast.expression_parenthesized(SPAN, function_expr)
         };
 
+        let namespace = BoundIdentifier::from_binding_ident(id);
+
         // (function (_N) { var M; (function (_M) { var x; })(M || (M = _N.M || (_N.M = {})));})(N || (N = {}));
         //                                                   ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^    ^^^^^^^^^^^^^^
         //                                                   Nested namespace arguments         Normal namespace arguments
         let arguments = {
             // M
-            let logical_left = ctx.ast.expression_identifier_reference(SPAN, &real_name);
+            let logical_left = namespace.create_read_expression(ctx);
 
             // (_N.M = {}) or (N = {})
             let mut logical_right = {
-                // _N.M
-                // SAFETY: `ast.copy` is unsound! We need to fix.
-                let parent_export = unsafe { ctx.ast.copy(&parent_export) };
-                let assign_left = if let Some(parent_export) = parent_export {
-                    AssignmentTarget::from(ctx.ast.member_expression_static(
-                        SPAN,
-                        parent_export,
-                        ctx.ast.identifier_name(SPAN, real_name.clone()),
-                        false,
-                    ))
-                } else {
-                    // _N
+                let assign_left = if let Some(parent_binding) = parent_binding {
+                    // _N.M
+                    let object = parent_binding.create_read_expression(ctx);
+                    let property = ctx.ast.identifier_name(SPAN, namespace.name.clone());
                     AssignmentTarget::from(
-                        ctx.ast
-                            .simple_assignment_target_identifier_reference(SPAN, real_name.clone()),
+                        ctx.ast.member_expression_static(SPAN, object, property, false),
                     )
+                } else {
+                    // N
+                    namespace.create_read_write_target(ctx)
                 };
 
                 let assign_right = ctx.ast.expression_object(SPAN, ctx.ast.vec(), None);
@@ -385,19 +595,18 @@ impl<'a, 'ctx> TypeScriptNamespace<'a, 'ctx> {
             };
 
             // (M = _N.M || (_N.M = {}))
-            if let Some(parent_export) = parent_export {
-                let assign_left =
-                    ctx.ast.simple_assignment_target_identifier_reference(SPAN, &real_name);
+            if let Some(parent_binding) = parent_binding {
+                let assign_left = namespace.create_read_write_target(ctx);
                 let assign_right = {
-                    let property = ctx.ast.identifier_name(SPAN, real_name.clone());
+                    let object = parent_binding.create_read_expression(ctx);
+                    let property = ctx.ast.identifier_name(SPAN, namespace.name.clone());
                     let logical_left =
-                        ctx.ast.member_expression_static(SPAN, parent_export, property, false);
+                        ctx.ast.member_expression_static(SPAN, object, property, false);
                     let op = LogicalOperator::Or;
                     ctx.ast.expression_logical(SPAN, logical_left.into(), op, logical_right)
                 };
                 let op = AssignmentOperator::Assign;
-                logical_right =
-                    ctx.ast.expression_assignment(SPAN, op, assign_left.into(), assign_right);
+                logical_right = ctx.ast.expression_assignment(SPAN, op, assign_left, assign_right);
                 logical_right = ctx.ast.expression_parenthesized(SPAN, logical_right);
             }
 
@@ -406,7 +615,7 @@ impl<'a, 'ctx> TypeScriptNamespace<'a, 'ctx> {
             ctx.ast.vec1(Argument::from(expr))
         };
 
-        let expr = ctx.ast.expression_call(SPAN, callee, NONE, arguments, false);
+        let expr = ctx.ast.expression_call(SPAN, callee, NONE, arguments, false, false);
         ctx.ast.statement_expression(SPAN, expr)
     }
 
@@ -414,44 +623,106 @@ impl<'a, 'ctx> TypeScriptNamespace<'a, 'ctx> {
     /// function id() {} -> function id() {}; Name.id = id;
     fn add_declaration(
         decl: Declaration<'a>,
-        name: Atom<'a>,
+        binding: &BoundIdentifier<'a>,
         names: &mut FxHashSet<Atom<'a>>,
         new_stmts: &mut ArenaVec<'a, Statement<'a>>,
-        ctx: &TraverseCtx<'a>,
+        ctx: &mut TraverseCtx<'a>,
     ) {
         // This function is only called with a function, class, or enum declaration,
         // all of which are guaranteed to have an `id`
-        let ident = decl.id().unwrap();
-        let item_name = ident.name.clone();
+        let item = BoundIdentifier::from_binding_ident(decl.id().unwrap());
+        // The declaration is no longer exported, only assigned to the namespace
+        *ctx.symbols_mut().get_flags_mut(item.symbol_id) -= SymbolFlags::Export;
         new_stmts.push(Statement::from(decl));
-        let assignment_statement = Self::create_assignment_statement(name, item_name.clone(), ctx);
+        let assignment_statement = Self::create_assignment_statement(binding, &item, ctx);
         let assignment_statement = ctx.ast.statement_expression(SPAN, assignment_statement);
         new_stmts.push(assignment_statement);
-        names.insert(item_name);
+        names.insert(item.name);
     }
 
     // name.item_name = item_name
     fn create_assignment_statement(
-        name: Atom<'a>,
-        item_name: Atom<'a>,
-        ctx: &TraverseCtx<'a>,
+        binding: &BoundIdentifier<'a>,
+        item: &BoundIdentifier<'a>,
+        ctx: &mut TraverseCtx<'a>,
     ) -> Expression<'a> {
-        let object = ctx.ast.expression_identifier_reference(SPAN, name);
-        let property = ctx.ast.identifier_name(SPAN, &item_name);
+        let object = binding.create_read_expression(ctx);
+        let property = ctx.ast.identifier_name(SPAN, item.name.clone());
         let left = ctx.ast.member_expression_static(SPAN, object, property, false);
         let left = AssignmentTarget::from(left);
-        let right = ctx.ast.expression_identifier_reference(SPAN, item_name);
+        let right = item.create_read_expression(ctx);
         let op = AssignmentOperator::Assign;
         ctx.ast.expression_assignment(SPAN, op, left, right)
     }
 
+    /// Convert `export import foo = A.B` to `var foo = A.B`, which is then exported
+    /// as `var foo = Namespace.foo = A.B`.
+    ///
+    /// If `A` is not declared in scope but is exported from another declaration of the same
+    /// namespace, it's qualified with the namespace, i.e. `var foo = Namespace.A.B`.
+    ///
+    /// Returns `None` if nothing needs to be emitted, i.e. for `export import type foo = A.B`,
+    /// or if `A` refers to a type, such as a namespace which only contains types.
+    fn handle_import_equals_declaration(
+        &self,
+        mut decl: ArenaBox<'a, TSImportEqualsDeclaration<'a>>,
+        binding: &BoundIdentifier<'a>,
+        path: &str,
+        scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<ArenaBox<'a, VariableDeclaration<'a>>> {
+        // `export import foo = require('mod')` is not allowed in namespaces
+        let type_name = decl.module_reference.as_ts_type_name()?;
+        let first_name = TSTypeName::get_first_name(type_name);
+        // Look up binding by name, as references to types are not resolved
+        let first_symbol_id = ctx.scopes().find_binding(scope_id, &first_name.name);
+        if decl.import_kind.is_type()
+            || first_symbol_id
+                .is_some_and(|symbol_id| !ctx.symbols().get_flags(symbol_id).is_value())
+        {
+            let decl = Declaration::TSImportEqualsDeclaration(decl);
+            TypeScriptAnnotations::remove_type_declaration_symbol(&decl, ctx);
+            return None;
+        }
+        let is_merged_export = first_symbol_id.is_none()
+            && self.exported_names.get(path).is_some_and(|names| names.contains(&first_name.name));
+
+        let type_name = decl.module_reference.as_ts_type_name_mut().unwrap();
+        let mut init = TypeScriptModule::transform_ts_type_name(type_name, ctx);
+        if is_merged_export {
+            // `A.B` -> `Namespace.A.B`
+            let mut object = &mut init;
+            while let Expression::StaticMemberExpression(member) = object {
+                object = &mut member.object;
+            }
+            if let Expression::Identifier(ident) = object {
+                ctx.delete_reference_for_identifier(ident);
+                let property = ctx.ast.identifier_name(ident.span, ident.name.clone());
+                let namespace = binding.create_read_expression(ctx);
+                *object = ctx.ast.member_expression_static(SPAN, namespace, property, false).into();
+            }
+        }
+
+        let kind = VariableDeclarationKind::Var;
+        *ctx.symbols_mut().get_flags_mut(decl.id.symbol_id()) = SymbolFlags::FunctionScopedVariable;
+        let id = BindingPatternKind::BindingIdentifier(ctx.alloc(decl.id.clone()));
+        let id = ctx.ast.binding_pattern(id, NONE, false);
+        let declarator = ctx.ast.variable_declarator(SPAN, kind, id, Some(init), false);
+        Some(ctx.ast.alloc_variable_declaration(decl.span, kind, ctx.ast.vec1(declarator), false))
+    }
+
     /// Convert `export const foo = 1` to `Namespace.foo = 1`;
     #[allow(clippy::needless_pass_by_value)]
     fn handle_variable_declaration(
         mut var_decl: ArenaBox<'a, VariableDeclaration<'a>>,
-        name: Atom<'a>,
-        ctx: &TraverseCtx<'a>,
+        binding: &BoundIdentifier<'a>,
+        ctx: &mut TraverseCtx<'a>,
     ) -> ArenaVec<'a, Statement<'a>> {
+        // The variables are no longer exported, only assigned to the namespace
+        var_decl.bound_names(&mut |id| {
+            *ctx.symbols_mut().get_flags_mut(id.symbol_id()) -= SymbolFlags::Export;
+        });
+
         let is_all_binding_identifier = var_decl
             .declarations
             .iter()
@@ -460,27 +731,22 @@ impl<'a, 'ctx> TypeScriptNamespace<'a, 'ctx> {
         // `export const a = 1` transforms to `const a = N.a = 1`, the output
         // is smaller than `const a = 1; N.a = a`;
         if is_all_binding_identifier {
-            var_decl.declarations.iter_mut().for_each(|declarator| {
+            for declarator in var_decl.declarations.iter_mut() {
                 let Some(property_name) = declarator.id.get_identifier() else {
-                    return;
+                    continue;
                 };
                 if let Some(init) = &mut declarator.init {
-                    declarator.init = Some(
-                        ctx.ast.expression_assignment(
-                            SPAN,
-                            AssignmentOperator::Assign,
-                            SimpleAssignmentTarget::from(ctx.ast.member_expression_static(
-                                SPAN,
-                                ctx.ast.expression_identifier_reference(SPAN, &name),
-                                ctx.ast.identifier_name(SPAN, property_name),
-                                false,
-                            ))
-                            .into(),
-                            ctx.ast.move_expression(init),
-                        ),
-                    );
+                    let object = binding.create_read_expression(ctx);
+                    let property = ctx.ast.identifier_name(SPAN, property_name);
+                    let left = ctx.ast.member_expression_static(SPAN, object, property, false);
+                    declarator.init = Some(ctx.ast.expression_assignment(
+                        SPAN,
+                        AssignmentOperator::Assign,
+                        SimpleAssignmentTarget::from(left).into(),
+                        ctx.ast.move_expression(init),
+                    ));
                 }
-            });
+            }
             return ctx.ast.vec1(Statement::VariableDeclaration(var_decl));
         }
 
@@ -488,7 +754,8 @@ impl<'a, 'ctx> TypeScriptNamespace<'a, 'ctx> {
         // `export const [a] = 1` transforms to `const [a] = 1; N.a = a`
         let mut assignments = ctx.ast.vec();
         var_decl.bound_names(&mut |id| {
-            assignments.push(Self::create_assignment_statement(name.clone(), id.name.clone(), ctx));
+            let item = BoundIdentifier::from_binding_ident(id);
+            assignments.push(Self::create_assignment_statement(binding, &item, ctx));
         });
 
         let mut stmts = ctx.ast.vec_with_capacity(2);
@@ -510,3 +777,85 @@ fn has_namespace(stmts: &[Statement]) -> bool {
         _ => false,
     })
 }
+
+/// `A` + `B` -> `A.B`
+fn namespace_path(parent_path: &str, name: &str) -> String {
+    if parent_path.is_empty() {
+        name.to_string()
+    } else {
+        format!("{parent_path}.{name}")
+    }
+}
+
+/// Collect names exported from each namespace, keyed by namespace path e.g. `A.B`.
+///
+/// A namespace can be declared multiple times, in which case the exports of all declarations
+/// are merged.
+fn collect_exported_names<'a>(
+    stmts: &[Statement<'a>],
+    parent_path: &str,
+    exported_names: &mut FxHashMap<String, FxHashSet<Atom<'a>>>,
+) {
+    for stmt in stmts {
+        let decl = match stmt {
+            Statement::TSModuleDeclaration(decl) => decl,
+            Statement::ExportNamedDeclaration(export_decl) => match &export_decl.declaration {
+                Some(Declaration::TSModuleDeclaration(decl)) => decl,
+                _ => continue,
+            },
+            _ => continue,
+        };
+        collect_namespace_exported_names(decl, parent_path, exported_names);
+    }
+}
+
+fn collect_namespace_exported_names<'a>(
+    decl: &TSModuleDeclaration<'a>,
+    parent_path: &str,
+    exported_names: &mut FxHashMap<String, FxHashSet<Atom<'a>>>,
+) {
+    let TSModuleDeclarationName::Identifier(id) = &decl.id else { return };
+    if decl.declare {
+        return;
+    }
+    let path = namespace_path(parent_path, &id.name);
+    match &decl.body {
+        Some(TSModuleDeclarationBody::TSModuleBlock(block)) => {
+            let mut names = FxHashSet::default();
+            for stmt in &block.body {
+                let Statement::ExportNamedDeclaration(export_decl) = stmt else { continue };
+                match &export_decl.declaration {
+                    Some(Declaration::VariableDeclaration(var_decl)) => {
+                        var_decl.bound_names(&mut |id| {
+                            names.insert(id.name.clone());
+                        });
+                    }
+                    Some(Declaration::TSImportEqualsDeclaration(import_decl))
+                        if !import_decl.import_kind.is_type() =>
+                    {
+                        names.insert(import_decl.id.name.clone());
+                    }
+                    Some(
+                        decl @ (Declaration::FunctionDeclaration(_)
+                        | Declaration::ClassDeclaration(_)
+                        | Declaration::TSEnumDeclaration(_)),
+                    ) => {
+                        names.insert(decl.id().unwrap().name.clone());
+                    }
+                    Some(Declaration::TSModuleDeclaration(module_decl)) => {
+                        names.insert(module_decl.id.name().clone());
+                    }
+                    _ => {}
+                }
+            }
+            exported_names.entry(path.clone()).or_default().extend(names);
+            collect_exported_names(&block.body, &path, exported_names);
+        }
+        // `namespace A.B {}` exports `B` from `A`
+        Some(TSModuleDeclarationBody::TSModuleDeclaration(nested)) => {
+            exported_names.entry(path.clone()).or_default().insert(nested.id.name().clone());
+            collect_namespace_exported_names(nested, &path, exported_names);
+        }
+        None => {}
+    }
+}
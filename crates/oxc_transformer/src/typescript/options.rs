@@ -36,17 +36,59 @@ pub struct TypeScriptOptions {
     /// This should only be used if you are using TypeScript >= 3.8.
     pub only_remove_type_imports: bool,
 
+    /// Elide imports and exports the same as TypeScript's
+    /// [`verbatimModuleSyntax`](https://www.typescriptlang.org/tsconfig#verbatimModuleSyntax).
+    ///
+    /// Only `import type`/`export type` declarations and inline `type` specifiers are removed.
+    /// Other imports are kept as written, even if they are unused or only used as types,
+    /// e.g. `import { type A, b } from "m"` -> `import { b } from "m"`. Defaults to `false`.
+    pub verbatim_module_syntax: bool,
+
+    /// Check exports the same as TypeScript's
+    /// [`isolatedModules`](https://www.typescriptlang.org/tsconfig#isolatedModules).
+    ///
+    /// `import { A } from "m"; export { A }` can't be known to export a value or a type from this
+    /// file alone, so the export is kept and a warning suggests `export type { A }` instead.
+    /// Defaults to `false`.
+    pub isolated_modules: bool,
+
     // Enables compilation of TypeScript namespaces.
     #[serde(default = "default_as_true")]
     pub allow_namespaces: bool,
 
+    /// Emit the members of an exported namespace as ES module exports, instead of properties of
+    /// an object created by an IIFE, e.g. `export namespace N { export const a = 1 }` ->
+    /// `export const a = 1`.
+    ///
+    /// Only namespaces in ES modules which don't contain nested namespaces, are not merged with
+    /// other declarations, and are not referenced as a value are flattened. Other namespaces are
+    /// still transformed to an IIFE. The namespace name itself is no longer exported, so it should
+    /// be imported with `import * as N`. Defaults to `false`.
+    pub flatten_namespaces: bool,
+
     // When enabled, type-only class fields are only removed if they are prefixed with the declare modifier:
     #[serde(default = "default_as_true")]
     pub allow_declare_fields: bool,
 
-    /// Unused.
+    /// Inline members of `const enum`s at use sites and remove their declarations,
+    /// the same as TypeScript does without `isolatedModules`.
+    ///
+    /// Only `const enum`s declared in the same file are inlined.
+    /// Exported `const enum`s, and `const enum`s which are used other than by accessing a member,
+    /// are also emitted as regular enums.
+    ///
+    /// Constant members of regular enums declared at top level of a module are inlined too,
+    /// if the enum is not exported and is only used by reading its members. Their declarations
+    /// are kept. Defaults to `false`.
     pub optimize_const_enums: bool,
 
+    /// Emit design-time type metadata for decorated declarations, the same as TypeScript's
+    /// [`emitDecoratorMetadata`](https://www.typescriptlang.org/tsconfig#emitDecoratorMetadata).
+    ///
+    /// Metadata is emitted as `@Reflect.metadata(...)` decorators, which requires a
+    /// `reflect-metadata` polyfill at runtime. Defaults to `false`.
+    pub emit_decorator_metadata: bool,
+
     // Preset options
     /// Modifies extensions in import and export declarations.
     ///
@@ -64,9 +106,13 @@ impl Default for TypeScriptOptions {
             jsx_pragma: default_for_jsx_pragma(),
             jsx_pragma_frag: default_for_jsx_pragma_frag(),
             only_remove_type_imports: false,
+            verbatim_module_syntax: false,
+            isolated_modules: false,
             allow_namespaces: default_as_true(),
+            flatten_namespaces: false,
             allow_declare_fields: default_as_true(),
             optimize_const_enums: false,
+            emit_decorator_metadata: false,
             rewrite_import_extensions: None,
         }
     }
@@ -3,10 +3,15 @@
 //! This plugin is used to rewrite/remove extensions from import/export source.
 //! It is only handled source that contains `/` or `\` in the source.
 //!
+//! `.ts` and `.tsx` are rewritten to `.js`, and `.mts` and `.cts` keep their module kind,
+//! i.e. are rewritten to `.mjs` and `.cjs`. Dynamic imports are only rewritten if the source is
+//! a string literal.
+//!
 //! Based on Babel's [plugin-rewrite-ts-imports](https://github.com/babel/babel/blob/3bcfee232506a4cebe410f02042fb0f0adeeb0b1/packages/babel-preset-typescript/src/plugin-rewrite-ts-imports.ts)
 
 use oxc_ast::ast::{
-    ExportAllDeclaration, ExportNamedDeclaration, ImportDeclaration, StringLiteral,
+    ExportAllDeclaration, ExportNamedDeclaration, Expression, ImportDeclaration, ImportExpression,
+    StringLiteral,
 };
 use oxc_traverse::{Traverse, TraverseCtx};
 
@@ -88,4 +93,13 @@ impl<'a> Traverse<'a> for TypeScriptRewriteExtensions {
         }
         self.rewrite_extensions(&mut node.source, ctx);
     }
+    fn enter_import_expression(
+        &mut self,
+        node: &mut ImportExpression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if let Expression::StringLiteral(source) = &mut node.source {
+            self.rewrite_extensions(source, ctx);
+        }
+    }
 }
@@ -0,0 +1,23 @@
+use oxc_span::SourceType;
+use oxc_transformer::TransformOptions;
+
+use crate::{codegen, test};
+
+#[test]
+fn arrow_function_body_wrapping() {
+    // Logical assignment transform inserts `var _a;` into the arrow function's body.
+    let source_text = "const f = () => a().c ||= 1;";
+
+    let options = TransformOptions::from_target("es2015").unwrap();
+    let expected = "const f = () => { var _a; return (_a = a()).c || (_a.c = 1); };";
+    assert_eq!(test(source_text, &options).unwrap(), codegen(expected, SourceType::mjs()));
+
+    // When disabled, a multi-statement concise body is left as-is,
+    // fixing it up is the responsibility of the caller.
+    // Codegen only prints the first statement of a concise body, which is `var _a;` here.
+    let options = TransformOptions {
+        disable_arrow_function_body_wrapping: true,
+        ..TransformOptions::from_target("es2015").unwrap()
+    };
+    assert_eq!(test(source_text, &options).unwrap(), "const f = () => ;\n");
+}
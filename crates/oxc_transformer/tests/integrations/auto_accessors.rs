@@ -0,0 +1,127 @@
+use oxc_span::SourceType;
+use oxc_transformer::{ProposalOptions, TransformOptions};
+
+use crate::{codegen, test};
+
+fn options() -> TransformOptions {
+    TransformOptions {
+        proposals: ProposalOptions { auto_accessors: true, ..ProposalOptions::default() },
+        ..TransformOptions::default()
+    }
+}
+
+fn assert_transform(source_text: &str, expected: &str) {
+    let result = test(source_text, &options()).unwrap();
+    assert_eq!(result, codegen(expected, SourceType::default()), "{source_text}");
+}
+
+#[test]
+fn instance() {
+    assert_transform(
+        "class A { accessor x = 1; accessor y; }",
+        "class A {
+            #_x = 1;
+            get x() { return this.#_x; }
+            set x(_value) { this.#_x = _value; }
+            #_y;
+            get y() { return this.#_y; }
+            set y(_value2) { this.#_y = _value2; }
+        }",
+    );
+}
+
+#[test]
+fn static_with_initializer() {
+    assert_transform(
+        "class A { static accessor x = 1; }",
+        "class A {
+            static #_x = 1;
+            static get x() { return this.#_x; }
+            static set x(_value) { this.#_x = _value; }
+        }",
+    );
+}
+
+#[test]
+fn static_without_initializer() {
+    assert_transform(
+        "class A { static accessor x; }",
+        "class A {
+            static #_x;
+            static get x() { return this.#_x; }
+            static set x(_value) { this.#_x = _value; }
+        }",
+    );
+}
+
+#[test]
+fn static_access_from_static_method() {
+    assert_transform(
+        "class A { static accessor x = 1; static inc() { return A.x++ + this.x; } }",
+        "class A {
+            static #_x = 1;
+            static get x() { return this.#_x; }
+            static set x(_value) { this.#_x = _value; }
+            static inc() { return A.x++ + this.x; }
+        }",
+    );
+}
+
+#[test]
+fn static_field_order() {
+    // Backing field stays in place of the accessor, so initializers run in the same order
+    assert_transform(
+        "class A { static a = 1; static accessor b = A.a + 1; static { A.b++; } static c = A.b; }",
+        "class A {
+            static a = 1;
+            static #_b = A.a + 1;
+            static get b() { return this.#_b; }
+            static set b(_value) { this.#_b = _value; }
+            static { A.b++; }
+            static c = A.b;
+        }",
+    );
+}
+
+#[test]
+fn keys() {
+    assert_transform(
+        "class A { accessor #x; static accessor 'a-b'; accessor [key()] = 1; accessor [1]; }",
+        "var _key;
+        class A {
+            #_x;
+            get #x() { return this.#_x; }
+            set #x(_value) { this.#_x = _value; }
+            static #_accessor;
+            static get 'a-b'() { return this.#_accessor; }
+            static set 'a-b'(_value2) { this.#_accessor = _value2; }
+            #_accessor2 = 1;
+            get [_key = key()]() { return this.#_accessor2; }
+            set [_key](_value3) { this.#_accessor2 = _value3; }
+            #_accessor3;
+            get [1]() { return this.#_accessor3; }
+            set [1](_value4) { this.#_accessor3 = _value4; }
+        }",
+    );
+}
+
+#[test]
+fn private_name_clash() {
+    assert_transform(
+        "class A { #_x = 1; static accessor x; method() { return class { #_x2; }; } }",
+        "class A {
+            #_x = 1;
+            static #_x3;
+            static get x() { return this.#_x3; }
+            static set x(_value) { this.#_x3 = _value; }
+            method() { return class { #_x2; }; }
+        }",
+    );
+}
+
+#[test]
+fn not_enabled() {
+    let source_text = "class A { static accessor x = 1; }";
+    let result = test(source_text, &TransformOptions::default()).unwrap();
+    assert_eq!(result, codegen(source_text, SourceType::default()));
+}
@@ -0,0 +1,14 @@
+use oxc_transformer::TransformOptions;
+
+use crate::test;
+
+#[test]
+fn await_in_static_block() {
+    let options = TransformOptions::from_target("es2021").unwrap();
+
+    // Parser accepts `await` in a static block inside an async function
+    let source_text = "async function f() { class C { static { await foo(); } } }";
+    let errors = test(source_text, &options).unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].message, "Cannot use await in class static initialization block");
+}
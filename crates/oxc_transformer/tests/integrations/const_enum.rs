@@ -0,0 +1,38 @@
+use oxc_span::SourceType;
+use oxc_transformer::{TransformOptions, TypeScriptOptions};
+
+use crate::test_with_source_type;
+
+fn options() -> TransformOptions {
+    TransformOptions {
+        typescript: TypeScriptOptions {
+            optimize_const_enums: true,
+            ..TypeScriptOptions::default()
+        },
+        ..TransformOptions::default()
+    }
+}
+
+fn test_const_enum_warning(source_text: &str, expected: &str) {
+    let errors = test_with_source_type(source_text, SourceType::ts(), &options()).unwrap_err();
+    assert!(
+        errors.iter().any(|error| error.to_string() == expected),
+        "for source {source_text}, errors: {errors:?}"
+    );
+}
+
+#[test]
+fn unsupported_usage() {
+    test_const_enum_warning(
+        "const enum A { X } Object.keys(A);",
+        "'const' enums can only be used in property or index access expressions.",
+    );
+    test_const_enum_warning(
+        "const enum A { X } declare const key: string; A[key];",
+        "'const' enums can only be used in property or index access expressions.",
+    );
+    test_const_enum_warning(
+        "const enum A { X = Math.random() } A.X;",
+        "const enum member initializers must be constant expressions.",
+    );
+}
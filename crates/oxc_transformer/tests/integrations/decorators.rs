@@ -0,0 +1,39 @@
+use oxc_span::SourceType;
+use oxc_transformer::{ProposalOptions, TransformOptions};
+
+use crate::{codegen, test, test_with_source_type};
+
+#[test]
+fn decorators_not_enabled() {
+    let options = TransformOptions::default();
+
+    let source_text = "@dec class A { @dec method() {} @dec.a(1) prop = 1; }";
+    let errors = test(source_text, &options).unwrap_err();
+    assert_eq!(errors.len(), 3);
+    for error in &errors {
+        assert_eq!(error.message, "Decorators are not enabled");
+    }
+    let labels = errors[0].labels.as_ref().unwrap();
+    assert_eq!(labels[0].offset(), 0);
+    assert_eq!(labels[0].len(), "@dec".len());
+
+    // Parameter decorators in TypeScript
+    let source_text = "class A { constructor(@inject() a: string) {} }";
+    let errors = test_with_source_type(source_text, SourceType::ts(), &options).unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].message, "Decorators are not enabled");
+
+    // No error without decorators
+    let source_text = "class A { method() {} }";
+    assert_eq!(test(source_text, &options).unwrap(), codegen(source_text, SourceType::default()));
+}
+
+#[test]
+fn decorators_enabled() {
+    let options = TransformOptions {
+        proposals: ProposalOptions { decorators: true, ..ProposalOptions::default() },
+        ..TransformOptions::default()
+    };
+    let source_text = "@dec class A { @dec method() {} @dec.a(1) prop = 1; }";
+    assert_eq!(test(source_text, &options).unwrap(), codegen(source_text, SourceType::default()));
+}
@@ -0,0 +1,70 @@
+use oxc_span::SourceType;
+use oxc_transformer::TransformOptions;
+
+use crate::{codegen, test};
+
+fn options(defines: &[(&str, &str)]) -> TransformOptions {
+    TransformOptions {
+        define: defines
+            .iter()
+            .map(|(key, value)| ((*key).to_string(), (*value).to_string()))
+            .collect(),
+        ..TransformOptions::default()
+    }
+}
+
+#[test]
+fn dotted() {
+    let options = options(&[("process.env.NODE_ENV", "'production'"), ("DEBUG", "false")]);
+    let source_text = "
+if (process.env.NODE_ENV !== 'production') { log(process.env.NODE_ENV) }
+if (DEBUG) { debug() }
+x = process.env.OTHER;
+x = process.env;
+x = foo.process.env.NODE_ENV;
+";
+    let expected = "
+if ('production' !== 'production') { log('production') }
+if (false) { debug() }
+x = process.env.OTHER;
+x = process.env;
+x = foo.process.env.NODE_ENV;
+";
+    let result = test(source_text, &options).unwrap();
+    assert_eq!(result, codegen(expected, SourceType::default()));
+}
+
+#[test]
+fn shadowed() {
+    let options = options(&[("process.env.NODE_ENV", "'production'"), ("DEBUG", "false")]);
+    let source_text = "
+function f(process, DEBUG) { return [process.env.NODE_ENV, DEBUG] }
+{ let DEBUG = 1; x = DEBUG; }
+x = [process.env.NODE_ENV, DEBUG];
+";
+    let expected = "
+function f(process, DEBUG) { return [process.env.NODE_ENV, DEBUG] }
+{ let DEBUG = 1; x = DEBUG; }
+x = ['production', false];
+";
+    let result = test(source_text, &options).unwrap();
+    assert_eq!(result, codegen(expected, SourceType::default()));
+}
+
+#[test]
+fn before_other_transforms() {
+    // Replaced value is transformed
+    let mut options = options(&[("CONFIG", "a ?? b")]);
+    options.env = TransformOptions::from_target("es2019").unwrap().env;
+    let result = test("x = CONFIG;", &options).unwrap();
+    let expected = "var _a; x = (_a = a) !== null && _a !== void 0 ? _a : b;";
+    assert_eq!(result, codegen(expected, SourceType::default()));
+}
+
+#[test]
+fn invalid() {
+    let options = options(&[("process.env.", "1")]);
+    let errors = test("x = 1;", &options).unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].to_string(), "`process.env.` is not an identifier.");
+}
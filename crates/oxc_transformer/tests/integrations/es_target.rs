@@ -14,9 +14,11 @@ fn es_target() {
         ("es2016", "async function foo() {}"),
         ("es2017", "({ ...x })"),
         ("es2017", "try {} catch {}"),
+        ("es2018", "a?.b"),
         ("es2019", "a ?? b"),
         ("es2019", "a ||= b"),
         ("es2019", "1n ** 2n"), // test target error
+        ("es2020", "1_000n; 0xF_Fn"),
         ("es2021", "class foo { static {} }"),
     ];
 
@@ -74,3 +76,58 @@ fn target_list_fail() {
         assert_eq!(result.unwrap_err().to_string(), expected);
     }
 }
+
+#[test]
+fn optional_catch_binding_target() {
+    let source_text = "try {} catch {}";
+    let lowered = codegen("try {} catch (_unused) {}", SourceType::mjs());
+    let unchanged = codegen(source_text, SourceType::mjs());
+
+    for (target, expected) in [
+        ("es2017", &lowered),
+        ("es2018", &lowered),
+        ("es2019", &unchanged),
+        ("chrome65", &lowered),
+        ("chrome66", &unchanged),
+    ] {
+        let options = TransformOptions::from_target(target).unwrap();
+        assert_eq!(test(source_text, &options).as_ref(), Ok(expected), "for target {target}");
+    }
+
+    // Generated binding does not collide with existing names
+    let options = TransformOptions::from_target("es2018").unwrap();
+    let source_text = "let _unused; try {} catch { _unused = 1; }";
+    let expected = "let _unused; try {} catch (_unused2) { _unused = 1; }";
+    assert_eq!(test(source_text, &options), Ok(codegen(expected, SourceType::mjs())));
+}
+
+#[test]
+fn symbol_async_iterator_method() {
+    let source_text = "class A { async *[Symbol.asyncIterator]() { yield await this.x; } }";
+
+    // Async generator methods are supported in ES2018
+    let options = TransformOptions::from_target("es2018").unwrap();
+    assert_eq!(test(source_text, &options), Ok(codegen(source_text, SourceType::mjs())));
+
+    // Computed key stays on the method, and the body is moved into a generator with `this` bound
+    let options = TransformOptions::from_target("es2017").unwrap();
+    let expected = "
+        import _awaitAsyncGenerator from '@babel/runtime/helpers/awaitAsyncGenerator';
+        import _wrapAsyncGenerator from '@babel/runtime/helpers/wrapAsyncGenerator';
+        class A {
+            [Symbol.asyncIterator]() {
+                var _this = this;
+                return _wrapAsyncGenerator(function* () {
+                    yield yield _awaitAsyncGenerator(_this.x);
+                })();
+            }
+        }
+    ";
+    assert_eq!(test(source_text, &options), Ok(codegen(expected, SourceType::mjs())));
+
+    // Also with classes and arrow functions lowered
+    let options = TransformOptions::from_target("es5").unwrap();
+    let result = test(source_text, &options).unwrap();
+    assert!(result.contains("key: Symbol.asyncIterator"));
+    assert!(result.contains("_awaitAsyncGenerator(_this.x)"));
+}
@@ -0,0 +1,31 @@
+use oxc_span::SourceType;
+use oxc_transformer::{EnvOptions, Module, TransformOptions};
+
+use crate::test_with_source_type;
+
+fn options(module: Module) -> TransformOptions {
+    TransformOptions {
+        env: EnvOptions { module, ..EnvOptions::default() },
+        ..TransformOptions::default()
+    }
+}
+
+#[test]
+fn esm() {
+    let source_text = "const foo = 1;\nexport = foo;";
+    let errors =
+        test_with_source_type(source_text, SourceType::ts(), &options(Module::ESM)).unwrap_err();
+    assert_eq!(errors.len(), 1);
+    let error = &errors[0];
+    assert_eq!(
+        error.message,
+        "Export assignment cannot be used when targeting ECMAScript modules."
+    );
+    assert!(error
+        .help
+        .as_ref()
+        .is_some_and(|help| help.contains("export default") && help.contains("esModuleInterop")));
+    // Points at `export = foo;`
+    let label = &error.labels.as_ref().unwrap()[0];
+    assert_eq!(&source_text[label.offset()..label.offset() + label.len()], "export = foo;");
+}
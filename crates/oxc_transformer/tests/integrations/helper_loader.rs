@@ -0,0 +1,178 @@
+use std::path::Path;
+
+use oxc_allocator::Allocator;
+use oxc_codegen::CodeGenerator;
+use oxc_parser::Parser;
+use oxc_semantic::SemanticBuilder;
+use oxc_span::SourceType;
+use oxc_tasks_transform_checker::check_semantic_after_transform;
+use oxc_transformer::{HelperLoaderMode, HelperSourceResolver, TransformOptions, Transformer};
+
+use crate::{codegen, test_with_source_type};
+
+#[test]
+fn resolve_source() {
+    let mut options = TransformOptions::from_target("es2016").unwrap();
+    options.helper_loader.resolve_source =
+        Some(HelperSourceResolver::new(|helper_name| match helper_name {
+            "asyncToGenerator" => Some("./chunks/async.js".to_string()),
+            "objectSpread2" => Some("./chunks/object.js".to_string()),
+            _ => None,
+        }));
+
+    let source_text = "
+async function foo() { await bar; }
+const { a, ...rest } = { ...b };
+";
+    let result = test_with_source_type(source_text, SourceType::mjs(), &options).unwrap();
+    let expected = "
+import _asyncToGenerator from './chunks/async.js';
+import _objectWithoutProperties from '@babel/runtime/helpers/objectWithoutProperties';
+import _objectSpread from './chunks/object.js';
+function foo() {
+  return _foo.apply(this, arguments);
+}
+function _foo() {
+  _foo = _asyncToGenerator(function* () {
+    yield bar;
+  });
+  return _foo.apply(this, arguments);
+}
+const _b = _objectSpread({}, b), a = _b.a, rest = _objectWithoutProperties(_b, ['a']);
+";
+    assert_eq!(result, codegen(expected, SourceType::mjs()));
+}
+
+#[test]
+fn dry_run() {
+    let source_text = "
+async function foo() { await bar; }
+const x = { ...y };
+";
+    let allocator = Allocator::default();
+    let program = Parser::new(&allocator, source_text, SourceType::mjs()).parse().program;
+    let original = CodeGenerator::new().build(&program).code;
+
+    for mode in [HelperLoaderMode::Runtime, HelperLoaderMode::External] {
+        let mut options = TransformOptions::from_target("es2016").unwrap();
+        options.helper_loader.mode = mode;
+        let ret = Transformer::new(&allocator, Path::new(""), &options).dry_run(&program);
+        assert!(ret.errors.is_empty());
+        assert_eq!(ret.helpers, ["asyncToGenerator", "objectSpread2"]);
+    }
+
+    // Program is not transformed
+    assert_eq!(CodeGenerator::new().build(&program).code, original);
+
+    // No helpers are needed for a target which supports all syntax used
+    let options = TransformOptions::from_target("es2018").unwrap();
+    let ret = Transformer::new(&allocator, Path::new(""), &options).dry_run(&program);
+    assert!(ret.helpers.is_empty());
+}
+
+#[test]
+fn inline() {
+    let mut options = TransformOptions::from_target("es2016").unwrap();
+    options.helper_loader.mode = HelperLoaderMode::Inline;
+
+    let source_text = "
+const _asyncToGenerator = 1;
+async function foo() { await bar; }
+async function qux() { await bar; }
+const x = { ...y }, z = { ...w };
+class A { a = 1; b = 2; }
+";
+    let result = test_with_source_type(source_text, SourceType::mjs(), &options).unwrap();
+
+    // Each helper, and each helper they depend on, is defined once
+    for helper in [
+        "_asyncGeneratorStep",
+        "_asyncToGenerator2",
+        "_defineProperty",
+        "_objectSpread",
+        "_ownKeys",
+        "_toPrimitive",
+        "_toPropertyKey",
+    ] {
+        assert_eq!(result.matches(&format!("function {helper}(")).count(), 1, "{helper}");
+    }
+    assert!(!result.contains("import"));
+
+    // Helpers don't collide with bindings in the program
+    assert!(result.contains("const _asyncToGenerator = 1;"));
+    assert_eq!(result.matches("_asyncToGenerator2(function* ()").count(), 2);
+    assert!(result.contains("const x = _objectSpread({}, y), z = _objectSpread({}, w);"));
+    assert!(result.contains("_defineProperty(this, 'a', 1);\n\t\t_defineProperty(this, 'b', 2);"));
+}
+
+#[test]
+fn inline_all_helpers() {
+    // Every helper has an inline definition, so nothing is imported from the runtime
+    let mut options = TransformOptions::from_target("es2015").unwrap();
+    options.helper_loader.mode = HelperLoaderMode::Inline;
+    options.env.es2020.optional_chaining_helper_threshold = Some(1);
+    options.proposals.explicit_resource_management = true;
+
+    let source_text = "
+async function* f() { await x; yield* y; for await (const z of w) {} }
+const a = b?.c?.();
+const d = delete e?.f;
+const g = /(?<h>i)/;
+{ using j = k; }
+";
+    let result = test_with_source_type(source_text, SourceType::mjs(), &options).unwrap();
+    for helper in [
+        "_asyncGeneratorDelegate",
+        "_asyncIterator",
+        "_awaitAsyncGenerator",
+        "_optionalChain",
+        "_optionalChainDelete",
+        "_OverloadYield",
+        // `_usingCtx` is the name of the transform's variable
+        "_usingCtx2",
+        "_wrapAsyncGenerator",
+        "_wrapRegExp",
+    ] {
+        assert_eq!(result.matches(&format!("function {helper}(")).count(), 1, "{helper}");
+    }
+    assert!(!result.contains("import"), "{result}");
+}
+
+#[test]
+fn inline_semantic() {
+    // Scopes, symbols and references in definitions are added to the program's semantic data
+    let mut options = TransformOptions::from_target("es2015").unwrap();
+    options.helper_loader.mode = HelperLoaderMode::Inline;
+
+    let source_text = "
+const x = { ...y }, { a, ...rest } = z;
+const r = /(?<h>i)/;
+class A { a = 1; }
+const f = async function* () { await x; yield* y; for await (const z of w) {} };
+const d = delete e?.f;
+{ using j = k; }
+";
+    options.env.es2020.optional_chaining_helper_threshold = Some(1);
+    options.proposals.explicit_resource_management = true;
+    for source_type in [SourceType::mjs(), SourceType::cjs()] {
+        let allocator = Allocator::default();
+        let mut program = Parser::new(&allocator, source_text, source_type).parse().program;
+        let (symbols, scopes) =
+            SemanticBuilder::new().build(&program).semantic.into_symbol_table_and_scope_tree();
+        let ret = Transformer::new(&allocator, Path::new(""), &options)
+            .build_with_symbols_and_scopes(symbols, scopes, &mut program);
+        assert!(ret.errors.is_empty());
+        let errors = check_semantic_after_transform(&ret.symbols, &ret.scopes, &program);
+        assert!(errors.is_none(), "{errors:#?}");
+    }
+}
+
+#[test]
+fn inline_unused() {
+    let mut options = TransformOptions::from_target("es2016").unwrap();
+    options.helper_loader.mode = HelperLoaderMode::Inline;
+
+    let source_text = "const x = { y };";
+    let result = test_with_source_type(source_text, SourceType::mjs(), &options).unwrap();
+    assert_eq!(result, codegen(source_text, SourceType::mjs()));
+}
@@ -0,0 +1,32 @@
+use oxc_span::SourceType;
+use oxc_transformer::{EnvOptions, Module, TransformOptions};
+
+use crate::{codegen, test_with_source_type};
+
+fn options(module: Module) -> TransformOptions {
+    TransformOptions {
+        env: EnvOptions { module, ..EnvOptions::default() },
+        ..TransformOptions::default()
+    }
+}
+
+fn test_import_equals(source_text: &str, expected: &str, options: &TransformOptions) {
+    let result = test_with_source_type(source_text, SourceType::ts(), options).unwrap();
+    assert_eq!(result, codegen(expected, SourceType::mjs()), "for source {source_text}");
+}
+
+#[test]
+fn esm() {
+    let options = options(Module::ESM);
+    for source_text in ["import x = require('m'); x();", "export import x = require('m');"] {
+        let errors = test_with_source_type(source_text, SourceType::ts(), &options).unwrap_err();
+        assert!(
+            errors.iter().any(|error| error.to_string()
+                == "Import assignment cannot be used when targeting ECMAScript modules."),
+            "for source {source_text}, errors: {errors:?}"
+        );
+    }
+
+    // Entity names don't involve `require`
+    test_import_equals("import x = A.B.C; x();", "var x = A.B.C; x();", &options);
+}
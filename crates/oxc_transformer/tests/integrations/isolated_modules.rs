@@ -0,0 +1,53 @@
+use oxc_span::SourceType;
+use oxc_transformer::{TransformOptions, TypeScriptOptions};
+
+use crate::{codegen, test_with_source_type};
+
+fn options(isolated_modules: bool) -> TransformOptions {
+    TransformOptions {
+        typescript: TypeScriptOptions { isolated_modules, ..TypeScriptOptions::default() },
+        ..TransformOptions::default()
+    }
+}
+
+#[test]
+fn ambiguous_export() {
+    let source_text = "import { A } from 'm'; export { A };";
+    let errors = test_with_source_type(source_text, SourceType::ts(), &options(true)).unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(
+        errors[0].message,
+        "Re-exporting a type when 'isolatedModules' is enabled requires using 'export type'."
+    );
+
+    // Only used as a type elsewhere
+    let source_text = "import A from 'm'; export { A as B }; let a: A;";
+    let errors = test_with_source_type(source_text, SourceType::ts(), &options(true)).unwrap_err();
+    assert_eq!(errors.len(), 1);
+
+    // The export is kept either way
+    let source_text = "import { A } from 'm'; export { A };";
+    let result = test_with_source_type(source_text, SourceType::ts(), &options(false)).unwrap();
+    assert_eq!(result, codegen(source_text, SourceType::mjs()));
+}
+
+#[test]
+fn unambiguous_export() {
+    for source_text in [
+        // Used as a value
+        "import { A } from 'm'; export { A }; A();",
+        // Declared in this file
+        "const a = 1; export { a };",
+        "type T = 1; export { T };",
+        "import * as N from 'm'; export { N };",
+        // Marked as a type
+        "import { type A } from 'm'; export { A };",
+        "import { A } from 'm'; export type { A };",
+        "import { A } from 'm'; export { type A };",
+        // Re-exports from another module are kept as written
+        "export { A } from 'm';",
+    ] {
+        let result = test_with_source_type(source_text, SourceType::ts(), &options(true));
+        assert!(result.is_ok(), "for source {source_text}");
+    }
+}
@@ -0,0 +1,23 @@
+use oxc_span::SourceType;
+use oxc_transformer::{JsxOptions, JsxRuntime, TransformOptions};
+
+use crate::test_with_source_type;
+
+fn options(runtime: JsxRuntime, development: bool) -> TransformOptions {
+    TransformOptions {
+        jsx: JsxOptions { runtime, development, ..JsxOptions::default() },
+        ..TransformOptions::default()
+    }
+}
+
+#[test]
+fn existing_source_attribute() {
+    let source_type = SourceType::jsx();
+    let source_text = "<a __source={source} />";
+    for runtime in [JsxRuntime::Classic, JsxRuntime::Automatic] {
+        let errors =
+            test_with_source_type(source_text, source_type, &options(runtime, true)).unwrap_err();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].message, "Duplicate __source prop found.");
+    }
+}
@@ -0,0 +1,44 @@
+use oxc_allocator::Allocator;
+use oxc_parser::Parser;
+use oxc_span::SourceType;
+use oxc_transformer::{JsxOptions, JsxRuntime, TransformOptions};
+
+use crate::test_with_source_type;
+
+fn options(runtime: JsxRuntime, throw_if_namespace: bool) -> TransformOptions {
+    TransformOptions {
+        jsx: JsxOptions { runtime, throw_if_namespace, ..JsxOptions::default() },
+        ..TransformOptions::default()
+    }
+}
+
+#[test]
+fn throw_if_namespace() {
+    for runtime in [JsxRuntime::Classic, JsxRuntime::Automatic] {
+        // Namespaced element names are reported
+        let errors =
+            test_with_source_type("<svg:rect />", SourceType::jsx(), &options(runtime, true))
+                .unwrap_err();
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].message.starts_with("Namespace tags are not supported by default."));
+
+        // Namespaced attribute names are not
+        let result = test_with_source_type(
+            "<rect xlink:href='#a' />",
+            SourceType::jsx(),
+            &options(runtime, true),
+        );
+        assert!(result.is_ok());
+    }
+}
+
+#[test]
+fn namespaced_member_expression() {
+    // A namespaced name can't be part of a member expression, so it never reaches the transformer
+    let allocator = Allocator::default();
+    for source_text in ["<svg:rect.a />", "<Svg.a:rect />"] {
+        let ret = Parser::new(&allocator, source_text, SourceType::jsx()).parse();
+        assert_eq!(ret.errors.len(), 1, "for source {source_text}");
+        assert_eq!(ret.errors[0].message, "Unexpected token");
+    }
+}
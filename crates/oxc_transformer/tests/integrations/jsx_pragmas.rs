@@ -0,0 +1,62 @@
+use oxc_span::SourceType;
+use oxc_transformer::TransformOptions;
+
+use crate::{codegen, test_with_source_type};
+
+fn test(source_text: &str, expected: &str, options: &TransformOptions) {
+    let result = test_with_source_type(source_text, SourceType::jsx(), options).unwrap();
+    assert_eq!(result, codegen(expected, SourceType::mjs()), "for source {source_text}");
+}
+
+fn test_errors(source_text: &str, options: &TransformOptions) -> Vec<String> {
+    let errors = test_with_source_type(source_text, SourceType::jsx(), options).unwrap_err();
+    errors.into_iter().map(|error| error.message.to_string()).collect()
+}
+
+#[test]
+fn pragmas_do_not_leak_between_files() {
+    // Every file is transformed with the same options
+    let options = TransformOptions::default();
+    let default_output = "import { jsx as _jsx } from 'react/jsx-runtime'; _jsx('div', {});";
+
+    test(
+        "/** @jsxImportSource preact */ <div />;",
+        "import { jsx as _jsx } from 'preact/jsx-runtime';\n/** @jsxImportSource preact */ _jsx('div', {});",
+        &options,
+    );
+    test("<div />;", default_output, &options);
+
+    test(
+        "/** @jsxRuntime classic */ <div />;",
+        "/** @jsxRuntime classic */ React.createElement('div', null);",
+        &options,
+    );
+    test("<div />;", default_output, &options);
+
+    test("/** @jsx h */ <div />;", "/** @jsx h */ h('div', null);", &options);
+    test("<div />;", default_output, &options);
+}
+
+#[test]
+fn conflicting_pragmas() {
+    let options = TransformOptions::default();
+    let pragma_error = "pragma and pragmaFrag cannot be set when runtime is automatic.";
+    let import_source_error = "importSource cannot be set when runtime is classic.";
+
+    assert_eq!(
+        test_errors("/** @jsxRuntime automatic */ /** @jsx h */ <div />;", &options),
+        [pragma_error]
+    );
+    assert_eq!(
+        test_errors("/** @jsxImportSource preact */ /** @jsxFrag F */ <div />;", &options),
+        [pragma_error]
+    );
+    assert_eq!(
+        test_errors("/** @jsxRuntime classic */ /** @jsxImportSource preact */ <div />;", &options),
+        [import_source_error]
+    );
+    assert_eq!(
+        test_errors("/** @jsxRuntime foo */ <div />;", &options),
+        ["Runtime must be either \"classic\" or \"automatic\"."]
+    );
+}
@@ -0,0 +1,44 @@
+use oxc_span::SourceType;
+use oxc_transformer::{JsxOptions, JsxRuntime, TransformOptions};
+
+use crate::test_with_source_type;
+
+fn errors(source_text: &str, check_unresolved_components: bool) -> Vec<String> {
+    let options = TransformOptions {
+        jsx: JsxOptions {
+            runtime: JsxRuntime::Classic,
+            check_unresolved_components,
+            ..JsxOptions::default()
+        },
+        ..TransformOptions::default()
+    };
+    match test_with_source_type(source_text, SourceType::mjs().with_jsx(true), &options) {
+        Ok(_) => vec![],
+        Err(errors) => errors.into_iter().map(|error| error.message.to_string()).collect(),
+    }
+}
+
+#[test]
+fn unresolved() {
+    assert_eq!(errors("<Foo />", true), ["`Foo` is not defined."]);
+    assert_eq!(errors("<Foo.Bar.Baz />", true), ["`Foo` is not defined."]);
+    assert_eq!(
+        errors("function f() { return <div><Foo /></div>; }", true),
+        ["`Foo` is not defined."]
+    );
+}
+
+#[test]
+fn resolved() {
+    assert!(errors("<div />", true).is_empty());
+    assert!(errors("import Foo from 'foo'; <Foo />", true).is_empty());
+    assert!(errors("import * as UI from 'ui'; <UI.Button />", true).is_empty());
+    assert!(errors("function Foo() {} <Foo />", true).is_empty());
+    assert!(errors("function f(Foo) { return <Foo />; }", true).is_empty());
+    assert!(errors("class C { render() { return <this.Foo />; } }", true).is_empty());
+}
+
+#[test]
+fn disabled_by_default() {
+    assert!(errors("<Foo />", false).is_empty());
+}
@@ -0,0 +1,96 @@
+use oxc_span::SourceType;
+use oxc_transformer::{ArrowFunctionsOptions, TransformOptions};
+
+use crate::{codegen, test};
+
+fn options(target: &str) -> TransformOptions {
+    TransformOptions { keep_names: true, ..TransformOptions::from_target(target).unwrap() }
+}
+
+#[test]
+fn arrow_function() {
+    // Lowered arrow function is still anonymous, so gets its name from the variable
+    let source_text = "const f = () => {};";
+    let expected = "var f = function() {};";
+    let result = test(source_text, &options("es5")).unwrap();
+    assert_eq!(result, codegen(expected, SourceType::default()));
+}
+
+#[test]
+fn spec_arrow_function() {
+    let mut options = options("es5");
+    options.env.es2015.arrow_function = Some(ArrowFunctionsOptions { spec: true });
+    let source_text = "const f = () => this; g = () => {};";
+    let expected = "
+        var _this = this;
+        import _newArrowCheck from '@babel/runtime/helpers/newArrowCheck';
+        var f = Object.defineProperty(function() {
+            _newArrowCheck(this, _this);
+            return this;
+        }.bind(this), 'name', { value: 'f' });
+        g = Object.defineProperty(function() {
+            _newArrowCheck(this, _this);
+        }.bind(this), 'name', { value: 'g' });
+    ";
+    let result = test(source_text, &options).unwrap();
+    assert_eq!(result, codegen(expected, SourceType::default()));
+}
+
+#[test]
+fn async_function() {
+    let source_text = "
+        const f = async function() { await x; };
+        g = async () => {};
+        export default async () => {};
+    ";
+    let expected = "
+        import _asyncToGenerator from '@babel/runtime/helpers/asyncToGenerator';
+        const f = function() {
+            var _ref = _asyncToGenerator(function* () { yield x; });
+            return function f() { return _ref.apply(this, arguments); };
+        }();
+        g = Object.defineProperty(_asyncToGenerator(function* () {}), 'name', { value: 'g' });
+        export default Object.defineProperty(_asyncToGenerator(function* () {}), 'name', { value: 'default' });
+    ";
+    let result = test(source_text, &options("es2016")).unwrap();
+    assert_eq!(result, codegen(expected, SourceType::mjs()));
+}
+
+#[test]
+fn class() {
+    let source_text = "
+        A = class {};
+        export default class { m() {} }
+    ";
+    let expected = "
+        import _classCallCheck from '@babel/runtime/helpers/classCallCheck';
+        import _createClass from '@babel/runtime/helpers/createClass';
+        A = Object.defineProperty(_createClass(function _class() {
+            _classCallCheck(this, _class);
+        }), 'name', { value: 'A' });
+        export default Object.defineProperty(function() {
+            function _class2() {
+                _classCallCheck(this, _class2);
+            }
+            return _createClass(_class2, [{ key: 'm', value: function m() {} }]);
+        }(), 'name', { value: 'default' });
+    ";
+    let result = test(source_text, &options("es5")).unwrap();
+    assert_eq!(result, codegen(expected, SourceType::mjs()));
+}
+
+#[test]
+fn class_with_static_name() {
+    // `Object.defineProperty` would overwrite the static method
+    let source_text = "A = class { static name() {} };";
+    let result = test(source_text, &options("es5")).unwrap();
+    assert!(!result.contains("defineProperty"));
+}
+
+#[test]
+fn disabled() {
+    let source_text = "A = class {}; g = async () => {};";
+    let options = TransformOptions::from_target("es5").unwrap();
+    let result = test(source_text, &options).unwrap();
+    assert!(!result.contains("defineProperty"));
+}
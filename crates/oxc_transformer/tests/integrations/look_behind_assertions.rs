@@ -0,0 +1,21 @@
+use oxc_transformer::TransformOptions;
+
+use crate::test;
+
+#[test]
+fn look_behind_assertions_error() {
+    let options = TransformOptions::from_target("chrome40").unwrap();
+
+    let source_text = "x = 1; a = /(?<=x)y/; b = /(?:(?<!x)){2}/g;";
+    let errors = test(source_text, &options).unwrap_err();
+    assert_eq!(errors.len(), 2);
+    for (error, literal) in errors.iter().zip(["/(?<=x)y/", "/(?:(?<!x)){2}/g"]) {
+        assert_eq!(
+            error.message,
+            "Lookbehind assertions are not available in the configured target environment."
+        );
+        let labels = error.labels.as_ref().unwrap();
+        assert_eq!(labels[0].offset(), source_text.find(literal).unwrap());
+        assert_eq!(labels[0].len(), literal.len());
+    }
+}
@@ -1,6 +1,31 @@
+mod arrow_function_body;
+mod auto_accessors;
+mod class_static_block;
+mod const_enum;
+mod decorators;
+mod define;
 mod es_target;
+mod export_assignment;
+mod helper_loader;
+mod import_equals;
+mod isolated_modules;
+mod jsx_development;
+mod jsx_namespace;
+mod jsx_pragmas;
+mod jsx_unresolved_components;
+mod keep_names;
+mod look_behind_assertions;
+mod noop;
+mod numeric_separator;
+mod optional_chaining_helper;
 mod plugins;
+mod private_methods;
+mod pure_annotations;
+mod set_notation;
+mod sourcemap;
 mod targets;
+mod tsconfig;
+mod verbatim_module_syntax;
 
 use std::path::Path;
 
@@ -25,7 +50,14 @@ pub(crate) fn test(
     source_text: &str,
     options: &TransformOptions,
 ) -> Result<String, Vec<OxcDiagnostic>> {
-    let source_type = SourceType::default();
+    test_with_source_type(source_text, SourceType::default(), options)
+}
+
+pub(crate) fn test_with_source_type(
+    source_text: &str,
+    source_type: SourceType,
+    options: &TransformOptions,
+) -> Result<String, Vec<OxcDiagnostic>> {
     let allocator = Allocator::default();
     let ret = Parser::new(&allocator, source_text, source_type).parse();
     let mut program = ret.program;
@@ -0,0 +1,47 @@
+use oxc_span::SourceType;
+use oxc_transformer::TransformOptions;
+
+use crate::{codegen, test, test_with_source_type};
+
+#[test]
+fn modern_target() {
+    // No transforms are enabled, so the traversal is skipped and the output is the same
+    let options = TransformOptions::from_target("esnext").unwrap();
+    let source_text = "
+        import { a } from 'a';
+        const { b, ...rest } = a ?? {};
+        class C { static #x = 1; static { this.y = C.#x ** 2; } }
+        async function* f() { for await (const x of g?.()) yield x; }
+        let c = () => this;
+        c ||= /(?<name>x)(?<=y)/dsuv;
+        try {} catch {}
+        export default 1_000n;
+    ";
+    assert_eq!(test(source_text, &options).unwrap(), codegen(source_text, SourceType::default()));
+}
+
+#[test]
+fn jsx() {
+    // `.js` files may contain JSX, which is still transformed
+    let options = TransformOptions::from_target("esnext").unwrap();
+    let source_text = "const a = <div />;";
+    let result = test_with_source_type(source_text, SourceType::jsx(), &options).unwrap();
+    assert!(result.contains("_jsx('div'"), "{result}");
+}
+
+#[test]
+fn decorators() {
+    // Decorators are still reported
+    let options = TransformOptions::from_target("esnext").unwrap();
+    let errors = test("@dec class A {}", &options).unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].message, "Decorators are not enabled");
+}
+
+#[test]
+fn create_react_class() {
+    // `displayName` is still added to `createReactClass` calls in plain JS
+    let options = TransformOptions::from_target("esnext").unwrap();
+    let result = test("var Foo = createReactClass({});", &options).unwrap();
+    assert!(result.contains("displayName: 'Foo'"), "{result}");
+}
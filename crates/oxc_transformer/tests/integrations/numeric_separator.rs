@@ -0,0 +1,17 @@
+use oxc_span::SourceType;
+use oxc_transformer::TransformOptions;
+
+use crate::{codegen, test};
+
+#[test]
+fn numeric_separator() {
+    let source_text = "1_000_000; 0xFF_FF; 0b1_0; 0o7_7; 1_0e1_0; 1_0.0_1; 1_000n; 0xF_Fn; 123;";
+
+    let options = TransformOptions::from_target("es2020").unwrap();
+    let expected = "1000000; 0xFFFF; 0b10; 0o77; 10e10; 10.01; 1000n; 0xFFn; 123;";
+    assert_eq!(test(source_text, &options).unwrap(), codegen(expected, SourceType::mjs()));
+
+    // Not transformed when target supports numeric separators
+    let options = TransformOptions::from_target("es2021").unwrap();
+    assert_eq!(test(source_text, &options).unwrap(), codegen(source_text, SourceType::mjs()));
+}
@@ -0,0 +1,79 @@
+use oxc_span::SourceType;
+use oxc_transformer::{HelperLoaderMode, HelperLoaderOptions, TransformOptions};
+
+use crate::{codegen, test_with_source_type};
+
+fn options(threshold: Option<usize>) -> TransformOptions {
+    let mut options = TransformOptions {
+        helper_loader: HelperLoaderOptions {
+            mode: HelperLoaderMode::External,
+            ..HelperLoaderOptions::default()
+        },
+        ..TransformOptions::default()
+    };
+    options.env.es2020.optional_chaining = true;
+    options.env.es2020.optional_chaining_helper_threshold = threshold;
+    options
+}
+
+fn test_chain(source_text: &str, expected: &str, threshold: Option<usize>) {
+    let result =
+        test_with_source_type(source_text, SourceType::mjs(), &options(threshold)).unwrap();
+    assert_eq!(result, codegen(expected, SourceType::mjs()), "for source {source_text}");
+}
+
+#[test]
+fn deep_chain() {
+    let source_text = "a?.b?.c?.d ?? fallback;";
+
+    // Inline with temporary variables
+    let inline = "
+var _a, _a$b, _a$b$c;
+((_a = a) === null || _a === void 0 || (_a$b = _a.b) === null || _a$b === void 0 || (_a$b$c = _a$b.c) === null || _a$b$c === void 0 ? void 0 : _a$b$c.d) ?? fallback;
+";
+    test_chain(source_text, inline, None);
+    test_chain(source_text, inline, Some(4));
+
+    test_chain(
+        source_text,
+        "babelHelpers.optionalChain([a, 'optionalAccess', (_) => _.b, 'optionalAccess', (_2) => _2.c, 'optionalAccess', (_3) => _3.d]) ?? fallback;",
+        Some(3),
+    );
+}
+
+#[test]
+fn links() {
+    test_chain(
+        "a?.b.c?.[key]?.(x).d;",
+        "babelHelpers.optionalChain([a, 'optionalAccess', (_) => _.b, 'access', (_2) => _2.c, 'optionalAccess', (_3) => _3[key], 'optionalCall', (_4) => _4(x), 'access', (_5) => _5.d]);",
+        Some(2),
+    );
+    test_chain(
+        "delete a?.b?.c;",
+        "babelHelpers.optionalChainDelete([a, 'optionalAccess', (_) => _.b, 'optionalAccess', (_2) => delete _2.c]);",
+        Some(2),
+    );
+    test_chain(
+        "class A extends B { m() { super.x?.y?.z; } }",
+        "class A extends B { m() { babelHelpers.optionalChain([super.x, 'optionalAccess', (_) => _.y, 'optionalAccess', (_2) => _2.z]); } }",
+        Some(2),
+    );
+
+    // Shorter chains are lowered inline
+    test_chain("a?.b;", "var _a; (_a = a) === null || _a === void 0 ? void 0 : _a.b;", Some(2));
+}
+
+#[test]
+fn unbound_callee() {
+    // The helper can't call the callee with the object as `this`
+    test_chain(
+        "(a?.b?.c)();",
+        "var _a, _a$b; ((_a = a) === null || _a === void 0 || (_a$b = _a.b) === null || _a$b === void 0 ? void 0 : _a$b.c.bind(_a$b))();",
+        Some(2),
+    );
+    test_chain(
+        "class A extends B { m() { super.x?.()?.y; } }",
+        "class A extends B { m() { var _super$x, _super$x$call; (_super$x = super.x) === null || _super$x === void 0 || (_super$x$call = _super$x.call(this)) === null || _super$x$call === void 0 ? void 0 : _super$x$call.y; } }",
+        Some(2),
+    );
+}
@@ -0,0 +1,70 @@
+use oxc_span::SourceType;
+use oxc_transformer::{ImportAttributesKeyword, ImportAttributesOptions, TransformOptions};
+
+use crate::{codegen, test};
+
+fn test_keyword(source_text: &str, expected: &str, keyword: Option<ImportAttributesKeyword>) {
+    let options = TransformOptions {
+        import_attributes: ImportAttributesOptions { keyword },
+        ..TransformOptions::default()
+    };
+    let result = test(source_text, &options).unwrap();
+    assert_eq!(result, codegen(expected, SourceType::default()), "for source {source_text}");
+}
+
+#[test]
+fn assert_to_with() {
+    let keyword = Some(ImportAttributesKeyword::With);
+    test_keyword(
+        "import x from 'y' assert { type: 'json' }; x;",
+        "import x from 'y' with { type: 'json' }; x;",
+        keyword,
+    );
+    test_keyword(
+        "export { x } from 'y' assert { type: 'json' }; export * from 'z' assert { type: 'json' };",
+        "export { x } from 'y' with { type: 'json' }; export * from 'z' with { type: 'json' };",
+        keyword,
+    );
+    test_keyword(
+        "import('y', { assert: { type: 'json' } }); import('z', { 'assert': { type: 'json' } });",
+        "import('y', { with: { type: 'json' } }); import('z', { 'with': { type: 'json' } });",
+        keyword,
+    );
+    test_keyword("import('y', { assert });", "import('y', { with: assert });", keyword);
+    // Already `with`
+    test_keyword(
+        "import x from 'y' with { type: 'json' }; x;",
+        "import x from 'y' with { type: 'json' }; x;",
+        keyword,
+    );
+}
+
+#[test]
+fn with_to_assert() {
+    let keyword = Some(ImportAttributesKeyword::Assert);
+    test_keyword(
+        "import x from 'y' with { type: 'json' }; x;",
+        "import x from 'y' assert { type: 'json' }; x;",
+        keyword,
+    );
+    test_keyword(
+        "import('y', { with: { type: 'json' } });",
+        "import('y', { assert: { type: 'json' } });",
+        keyword,
+    );
+}
+
+#[test]
+fn unchanged() {
+    test_keyword(
+        "import x from 'y' assert { type: 'json' }; import('z', { with: { type: 'json' } }); x;",
+        "import x from 'y' assert { type: 'json' }; import('z', { with: { type: 'json' } }); x;",
+        None,
+    );
+    // Other options and computed keys are left alone
+    test_keyword(
+        "import('y', { other: 1, [assert]: 2 }); import('z', options);",
+        "import('y', { other: 1, [assert]: 2 }); import('z', options);",
+        Some(ImportAttributesKeyword::With),
+    );
+}
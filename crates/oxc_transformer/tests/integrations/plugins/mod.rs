@@ -1,2 +1,5 @@
+mod import_attributes;
 mod inject_global_variables;
+mod remove_side_effect_imports;
 mod replace_global_defines;
+mod tagged_template;
@@ -0,0 +1,40 @@
+use oxc_span::SourceType;
+use oxc_transformer::{RemoveSideEffectImportsOptions, TransformOptions};
+
+use crate::{codegen, test};
+
+fn test_remove(source_text: &str, expected: &str, extensions: &[&str]) {
+    let options = TransformOptions {
+        remove_side_effect_imports: RemoveSideEffectImportsOptions {
+            extensions: extensions.iter().map(ToString::to_string).collect(),
+        },
+        ..TransformOptions::default()
+    };
+    let result = test(source_text, &options).unwrap();
+    assert_eq!(result, codegen(expected, SourceType::default()), "for source {source_text}");
+}
+
+#[test]
+fn remove_side_effect_imports() {
+    test_remove("import './x.css'; import {} from './y.css';", "", &["css"]);
+    test_remove("import './x.css?inline'; import './icon.svg#id';", "", &["css", "svg"]);
+}
+
+#[test]
+fn keep_imports_with_bindings() {
+    test_remove(
+        "import './x.css'; import styles from './x.css'; import * as ns from './y.css'; styles, ns;",
+        "import styles from './x.css'; import * as ns from './y.css'; styles, ns;",
+        &["css"],
+    );
+}
+
+#[test]
+fn keep_other_extensions() {
+    test_remove(
+        "import './x.css'; import './polyfill.js'; import './styles'; import 'css';",
+        "import './polyfill.js'; import './styles'; import 'css';",
+        &["css"],
+    );
+    test_remove("import './x.css';", "import './x.css';", &[]);
+}
@@ -0,0 +1,74 @@
+use oxc_ast::ast::PropertyKind;
+use oxc_span::{SourceType, SPAN};
+use oxc_transformer::{TaggedTemplateOptions, TransformOptions};
+
+use crate::{codegen, test};
+
+fn gql_options() -> TransformOptions {
+    let tagged_templates = TaggedTemplateOptions::default().with_hook("gql", |expr, ctx| {
+        // Only templates without substitutions can be precompiled
+        if !expr.quasi.expressions.is_empty() {
+            return None;
+        }
+        let source = expr.quasi.quasis[0].value.raw.clone();
+        let properties = ctx.ast.vec_from_iter([
+            ctx.ast.object_property_kind_object_property(
+                SPAN,
+                PropertyKind::Init,
+                ctx.ast.property_key_identifier_name(SPAN, "kind"),
+                ctx.ast.expression_string_literal(SPAN, "Document"),
+                false,
+                false,
+                false,
+            ),
+            ctx.ast.object_property_kind_object_property(
+                SPAN,
+                PropertyKind::Init,
+                ctx.ast.property_key_identifier_name(SPAN, "source"),
+                ctx.ast.expression_string_literal(SPAN, source),
+                false,
+                false,
+                false,
+            ),
+        ]);
+        Some(ctx.ast.expression_object(SPAN, properties, None))
+    });
+    TransformOptions { tagged_templates, ..TransformOptions::default() }
+}
+
+fn test_gql(source_text: &str, expected: &str) {
+    let result = test(source_text, &gql_options()).unwrap();
+    assert_eq!(result, codegen(expected, SourceType::default()), "for source {source_text}");
+}
+
+#[test]
+fn precompile_gql() {
+    test_gql(
+        "const query = gql`query { user { id } }`;",
+        "const query = { kind: 'Document', source: 'query { user { id } }' };",
+    );
+    test_gql(
+        "fetch(gql`{ a }`, gql`{ b }`);",
+        "fetch({ kind: 'Document', source: '{ a }' }, { kind: 'Document', source: '{ b }' });",
+    );
+}
+
+#[test]
+fn declined_by_hook() {
+    test_gql("gql`query { ${fragment} }`;", "gql`query { ${fragment} }`;");
+}
+
+#[test]
+fn other_tags() {
+    test_gql("css`color: red;`; a.gql`{ a }`;", "css`color: red;`; a.gql`{ a }`;");
+}
+
+#[test]
+fn member_tag() {
+    let tagged_templates = TaggedTemplateOptions::default()
+        .with_hook("styled.div", |_, ctx| Some(ctx.ast.expression_string_literal(SPAN, "div")));
+    let options = TransformOptions { tagged_templates, ..TransformOptions::default() };
+    let result = test("styled.div`color: red;`; styled.span`color: red;`;", &options).unwrap();
+    let expected = codegen("'div'; styled.span`color: red;`;", SourceType::default());
+    assert_eq!(result, expected);
+}
@@ -0,0 +1,84 @@
+use oxc_transformer::TransformOptions;
+
+use crate::test;
+
+fn options() -> TransformOptions {
+    // Not enabled by targets, because it is not ready
+    let mut options = TransformOptions::from_target("es2021").unwrap();
+    options.env.es2022.private_methods = true;
+    options
+}
+
+#[test]
+fn not_enabled_by_targets() {
+    let options = TransformOptions::from_target("es2021").unwrap();
+    assert!(!options.env.es2022.private_methods);
+}
+
+#[test]
+fn unsupported_classes_are_reported() {
+    let cases = [
+        (
+            "class A { prop = this.#x; get #x() { return 1; } }",
+            "instance properties with initializers are not supported",
+        ),
+        (
+            "class A { accessor prop; get #x() { return 1; } }",
+            "instance `accessor` properties are not supported",
+        ),
+        ("class B extends Base { get #x() { return super.x; } }", "accessors using `super` are not supported"),
+        (
+            "let value = 1; class C { get #x() { return value; } constructor(value) {} }",
+            "accessor refers to a binding which is shadowed in the constructor",
+        ),
+        (
+            "class D { get #x() { return 1; } test(obj) { return obj?.#x; } }",
+            "accessors in optional chains are not supported",
+        ),
+        (
+            "class E extends Base { get #x() { return 1; } constructor() { if (a) super(); else super(1); } }",
+            "constructor of a derived class must call `super()` at top level of its body",
+        ),
+    ];
+    for (source_text, reason) in cases {
+        let errors = test(source_text, &options()).unwrap_err();
+        assert_eq!(errors.len(), 1, "{source_text}");
+        assert_eq!(
+            errors[0].message,
+            format!("Private accessors of this class are not transformed: {reason}"),
+            "{source_text}"
+        );
+    }
+}
+
+#[test]
+fn super_private() {
+    // Parser accepts `super.#x`
+    let source_text = "class A extends B { get #x() { return 1; } m() { super.#x; } }";
+    let errors = test(source_text, &options()).unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].message, "Private fields cannot be accessed on super");
+}
+
+#[test]
+fn delete_private_field() {
+    let source_text = "class C { get #x() { return 1; } m() { delete this.#x; } }";
+    let errors = test(source_text, &options()).unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].message, "Private fields can not be deleted");
+}
+
+#[test]
+fn undeclared_private_name() {
+    let source_text = "class C { m(obj) { return obj.#y + (#z in obj); } } obj.#x;";
+    let errors = test(source_text, &options()).unwrap_err();
+    let messages = errors.iter().map(|error| error.message.to_string()).collect::<Vec<_>>();
+    assert_eq!(
+        messages,
+        [
+            "Private field '#y' must be declared in an enclosing class",
+            "Private field '#z' must be declared in an enclosing class",
+            "Private field '#x' must be declared in an enclosing class",
+        ]
+    );
+}
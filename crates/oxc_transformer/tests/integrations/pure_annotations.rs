@@ -0,0 +1,117 @@
+use oxc_span::SourceType;
+use oxc_transformer::TransformOptions;
+
+use crate::{codegen, test};
+
+fn options() -> TransformOptions {
+    TransformOptions { pure_annotations: true, ..TransformOptions::default() }
+}
+
+#[test]
+fn no_side_effects_function() {
+    let source_text = "
+/* @__NO_SIDE_EFFECTS__ */ function createPure(a) { return { a }; }
+const x = createPure(1);
+export const y = createPure({ b: 2 }, () => {});
+const z = createPure(sideEffect());
+function f() { const w = createPure(); }
+";
+    let expected = "
+/* @__NO_SIDE_EFFECTS__ */ function createPure(a) { return { a }; }
+const x = /* @__PURE__ */ createPure(1);
+export const y = /* @__PURE__ */ createPure({ b: 2 }, () => {});
+const z = createPure(sideEffect());
+function f() { const w = createPure(); }
+";
+    assert_eq!(test(source_text, &options()).unwrap(), codegen(expected, SourceType::default()));
+}
+
+#[test]
+fn not_known_pure() {
+    // Not annotated, or reassigned
+    let source_text = "
+function notAnnotated() {}
+const a = notAnnotated();
+/* @__NO_SIDE_EFFECTS__ */ function reassigned() {}
+reassigned = sideEffect;
+const b = reassigned();
+const c = unknown();
+";
+    assert_eq!(test(source_text, &options()).unwrap(), codegen(source_text, SourceType::default()));
+}
+
+#[test]
+fn constructors() {
+    let source_text = "
+const a = new WeakMap();
+const b = new Set();
+const c = new Set([1]);
+const d = new Foo();
+";
+    let expected = "
+const a = /* @__PURE__ */ new WeakMap();
+const b = /* @__PURE__ */ new Set();
+const c = new Set([1]);
+const d = new Foo();
+";
+    assert_eq!(test(source_text, &options()).unwrap(), codegen(expected, SourceType::default()));
+
+    // Shadowed
+    let source_text = "class Map {} const a = new Map();";
+    assert_eq!(test(source_text, &options()).unwrap(), codegen(source_text, SourceType::default()));
+}
+
+#[test]
+fn helpers() {
+    let options = TransformOptions {
+        pure_annotations: true,
+        ..TransformOptions::from_target("es5").unwrap()
+    };
+    let source_text = "class A { foo() {} }\nconst r = /(?<y>\\d)/;";
+    let expected = r#"
+import _classCallCheck from "@babel/runtime/helpers/classCallCheck";
+import _createClass from "@babel/runtime/helpers/createClass";
+import _wrapRegExp from "@babel/runtime/helpers/wrapRegExp";
+let A = /* @__PURE__ */ function() {
+    function A() {
+        _classCallCheck(this, A);
+    }
+    return /* @__PURE__ */ _createClass(A, [{
+        key: "foo",
+        value: function foo() {}
+    }]);
+}();
+var r = /* @__PURE__ */ _wrapRegExp(/(\d)/, { y: 1 });
+"#;
+    assert_eq!(test(source_text, &options).unwrap(), codegen(expected, SourceType::mjs()));
+}
+
+#[test]
+fn nested_helpers() {
+    // Helper calls are annotated wherever they are, not only in top-level declarations
+    let options = TransformOptions {
+        pure_annotations: true,
+        ..TransformOptions::from_target("es5").unwrap()
+    };
+    let source_text = "function f() { return class {}; }";
+    let expected = r#"
+import _classCallCheck from "@babel/runtime/helpers/classCallCheck";
+import _createClass from "@babel/runtime/helpers/createClass";
+function f() {
+    return /* @__PURE__ */ _createClass(function _class() {
+        _classCallCheck(this, _class);
+    });
+}
+"#;
+    assert_eq!(test(source_text, &options).unwrap(), codegen(expected, SourceType::mjs()));
+
+    let result = test(source_text, &TransformOptions::from_target("es5").unwrap()).unwrap();
+    assert!(!result.contains("__PURE__"));
+}
+
+#[test]
+fn disabled() {
+    let source_text = "/* @__NO_SIDE_EFFECTS__ */ function f() {}\nconst x = f();";
+    let result = test(source_text, &TransformOptions::default()).unwrap();
+    assert_eq!(result, codegen(source_text, SourceType::default()));
+}
@@ -0,0 +1,21 @@
+use oxc_transformer::TransformOptions;
+
+use crate::test;
+
+#[test]
+fn set_notation_strings() {
+    let options = TransformOptions::from_target("chrome100").unwrap();
+
+    let source_text = r"a = /[\q{abc}]/v; b = /[\q{a|b}]/v; c = /[[\w--\d]\p{RGI_Emoji}]/v;";
+    let errors = test(source_text, &options).unwrap_err();
+    assert_eq!(errors.len(), 2);
+    for (error, strings) in errors.iter().zip([r"\q{abc}", r"\p{RGI_Emoji}"]) {
+        assert_eq!(
+            error.message,
+            "Strings in a character class with `v` flag can't be transformed."
+        );
+        let labels = error.labels.as_ref().unwrap();
+        assert_eq!(labels[0].offset(), source_text.find(strings).unwrap());
+        assert_eq!(labels[0].len(), strings.len());
+    }
+}
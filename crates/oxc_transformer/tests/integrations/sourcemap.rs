@@ -0,0 +1,67 @@
+use std::path::{Path, PathBuf};
+
+use oxc_allocator::Allocator;
+use oxc_codegen::{CodeGenerator, CodegenOptions};
+use oxc_parser::Parser;
+use oxc_semantic::SemanticBuilder;
+use oxc_sourcemap::SourceMap;
+use oxc_span::SourceType;
+use oxc_transformer::{ArrowFunctionsOptions, TransformOptions, Transformer};
+
+/// Transform `source_text`, and return the code printed by codegen with its source map,
+/// which is built from the spans of the transformed AST.
+fn transform(source_text: &str, options: &TransformOptions) -> (String, SourceMap) {
+    let allocator = Allocator::default();
+    let mut program = Parser::new(&allocator, source_text, SourceType::mjs()).parse().program;
+    let (symbols, scopes) =
+        SemanticBuilder::new().build(&program).semantic.into_symbol_table_and_scope_tree();
+    let ret = Transformer::new(&allocator, Path::new("test.js"), options)
+        .build_with_symbols_and_scopes(symbols, scopes, &mut program);
+    assert!(ret.errors.is_empty());
+    let codegen_options =
+        CodegenOptions { source_map_path: Some(PathBuf::from("test.js")), ..Default::default() };
+    let ret = CodeGenerator::new().with_options(codegen_options).build(&program);
+    (ret.code, ret.map.unwrap())
+}
+
+/// Original `(line, column)` of the first occurrence of `needle` in `code`,
+/// looked up in the same way as a source map consumer.
+#[expect(clippy::cast_possible_truncation)]
+fn original_position(code: &str, map: &SourceMap, needle: &str) -> (u32, u32) {
+    let offset = code.find(needle).unwrap();
+    let line = code[..offset].matches('\n').count() as u32;
+    let column = (offset - code[..offset].rfind('\n').map_or(0, |index| index + 1)) as u32;
+    let token = map.lookup_token(&map.generate_lookup_table(), line, column).unwrap();
+    (token.get_src_line(), token.get_src_col())
+}
+
+fn options() -> TransformOptions {
+    TransformOptions::from_target("es5").unwrap()
+}
+
+const SOURCE: &str = "function f() {
+  return (a) => this.x + a;
+}
+";
+
+#[test]
+fn arrow_function() {
+    let (code, map) = transform(SOURCE, &options());
+    assert_eq!(map.get_sources().collect::<Vec<_>>(), ["test.js"]);
+    // Converted function keeps span of the arrow function
+    assert_eq!(original_position(&code, &map, "function(a)"), (1, 9));
+    // Concise body is wrapped in `return`, and `this` is replaced with `_this`
+    assert_eq!(original_position(&code, &map, "return _this.x"), (1, 16));
+    assert_eq!(original_position(&code, &map, "a;"), (1, 25));
+}
+
+#[test]
+fn arrow_function_spec() {
+    let mut options = options();
+    options.env.es2015.arrow_function = Some(ArrowFunctionsOptions { spec: true });
+    let (code, map) = transform(SOURCE, &options);
+    assert_eq!(original_position(&code, &map, "function(a)"), (1, 9));
+    // Injected statement is mapped to the arrow function, from the start of its line
+    assert_eq!(original_position(&code, &map, "\t\t_newArrowCheck"), (1, 9));
+    assert_eq!(original_position(&code, &map, "return this.x"), (1, 16));
+}
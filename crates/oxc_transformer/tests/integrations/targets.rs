@@ -1,6 +1,6 @@
 use crate::{codegen, test};
 use oxc_span::SourceType;
-use oxc_transformer::{ESTarget, EnvOptions, TransformOptions};
+use oxc_transformer::{ESTarget, Engine, EngineTargets, EnvOptions, TransformOptions};
 
 #[test]
 fn targets() {
@@ -34,3 +34,36 @@ fn targets() {
         assert_eq!(test(case, &options), test(case, &options_node));
     }
 }
+
+#[test]
+fn browserslist_queries() {
+    // `defaults` and the empty query are the same, and do not include node
+    let defaults = EngineTargets::from_browserslist("defaults").unwrap();
+    let empty = EngineTargets::from_browserslist("").unwrap();
+    assert_eq!(defaults.get(&Engine::Chrome), empty.get(&Engine::Chrome));
+    assert!(defaults.get(&Engine::Node).is_none());
+    let env = EnvOptions::from(defaults);
+    assert!(!env.es2016.exponentiation_operator);
+    assert!(!env.es2020.nullish_coalescing_operator);
+
+    // Node 14 supports `??` but not `||=`
+    let node = EngineTargets::from_browserslist("node 14").unwrap();
+    assert_eq!(node.len(), 1);
+    assert!(node.contains_key(&Engine::Node));
+    let env = EnvOptions::from(node);
+    assert!(!env.es2020.nullish_coalescing_operator);
+    assert!(env.es2021.logical_assignment_operators);
+
+    // The minimum version of each engine is used
+    let env = EnvOptions::from(EngineTargets::from_browserslist("chrome 50, chrome 80").unwrap());
+    assert!(env.es2016.exponentiation_operator);
+
+    // Queries which include old browsers enable more transforms
+    let env = EnvOptions::from(
+        EngineTargets::from_browserslist("> 0.5%, last 2 versions, safari 12").unwrap(),
+    );
+    assert!(!env.es2016.exponentiation_operator);
+    assert!(env.es2020.nullish_coalescing_operator);
+
+    assert!(EngineTargets::from_browserslist("not a query").is_err());
+}
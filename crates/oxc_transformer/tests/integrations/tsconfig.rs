@@ -0,0 +1,65 @@
+use std::path::{Path, PathBuf};
+
+use oxc_span::SourceType;
+use oxc_transformer::{JsxRuntime, TransformOptions};
+
+use crate::{codegen, test_with_source_type};
+
+fn fixture(name: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/integrations/fixtures/tsconfig")
+        .join(name)
+        .join("tsconfig.json")
+}
+
+#[test]
+#[allow(deprecated)]
+fn extends_chain() {
+    let options = TransformOptions::from_tsconfig(&fixture("extends")).unwrap();
+    // `target: "ES2015"` from the base config
+    assert!(options.env.es2016.exponentiation_operator);
+    assert!(options.env.es2015.arrow_function.is_none());
+    assert!(options.proposals.decorators);
+    // `jsx` is overridden by the middle config, `jsxImportSource` by the last one
+    assert_eq!(options.jsx.runtime, JsxRuntime::Automatic);
+    assert!(!options.jsx.development);
+    assert_eq!(options.jsx.import_source.as_deref(), Some("preact"));
+    // `useDefineForClassFields: false`
+    assert!(options.assumptions.set_public_class_fields);
+    assert!(!options.typescript.allow_declare_fields);
+    assert!(options.typescript.verbatim_module_syntax);
+}
+
+#[test]
+fn react_jsx() {
+    let options = TransformOptions::from_tsconfig(&fixture("react-jsx")).unwrap();
+    assert_eq!(options.jsx.runtime, JsxRuntime::Automatic);
+    let result = test_with_source_type("<div />", SourceType::tsx(), &options).unwrap();
+    let expected = "
+        import { jsx as _jsx } from 'react/jsx-runtime';
+        _jsx('div', {});
+    ";
+    assert_eq!(result, codegen(expected, SourceType::mjs()));
+}
+
+#[test]
+fn errors() {
+    let errors = TransformOptions::from_tsconfig(&fixture("errors")).unwrap_err();
+    let messages = errors.iter().map(ToString::to_string).collect::<Vec<_>>();
+    let expected = [
+        "Cannot find config \"./missing\"",
+        "Circular `extends`",
+        "compilerOptions.target: Invalid target \"es3\".",
+        "compilerOptions.jsx: Invalid value \"react-foo\"",
+        "compilerOptions.verbatimModuleSyntax: invalid type",
+        "compilerOptions.paths: invalid type",
+    ];
+    assert_eq!(messages.len(), expected.len(), "{messages:#?}");
+    for (message, expected) in messages.iter().zip(expected) {
+        assert!(message.starts_with(expected), "{message} does not start with {expected}");
+    }
+
+    let errors = TransformOptions::from_tsconfig(&fixture("missing")).unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0].to_string().starts_with("Failed to read"));
+}
@@ -0,0 +1,27 @@
+use oxc_span::SourceType;
+use oxc_transformer::{TransformOptions, TypeScriptOptions};
+
+use crate::test_with_source_type;
+
+fn options(verbatim_module_syntax: bool) -> TransformOptions {
+    TransformOptions {
+        typescript: TypeScriptOptions { verbatim_module_syntax, ..TypeScriptOptions::default() },
+        ..TransformOptions::default()
+    }
+}
+
+#[test]
+fn type_only_import_default_and_named() {
+    for verbatim_module_syntax in [false, true] {
+        let errors = test_with_source_type(
+            "import type A, { B } from 'm';",
+            SourceType::ts(),
+            &options(verbatim_module_syntax),
+        )
+        .unwrap_err();
+        assert_eq!(
+            errors[0].to_string(),
+            "A type-only import can specify a default import or named bindings, but not both."
+        );
+    }
+}
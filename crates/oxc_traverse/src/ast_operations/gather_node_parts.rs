@@ -173,6 +173,7 @@ impl<'a> GatherNodeParts<'a> for Expression<'a> {
             match_member_expression!(Self) => self.to_member_expression().gather(f),
             Self::Identifier(ident) => ident.gather(f),
             Self::CallExpression(expr) => expr.gather(f),
+            Self::ChainExpression(expr) => expr.gather(f),
             Self::NewExpression(expr) => expr.gather(f),
             Self::ObjectExpression(expr) => expr.gather(f),
             Self::ThisExpression(expr) => expr.gather(f),
@@ -223,6 +224,17 @@ impl<'a> GatherNodeParts<'a> for CallExpression<'a> {
     }
 }
 
+impl<'a> GatherNodeParts<'a> for ChainExpression<'a> {
+    fn gather<F: FnMut(&str)>(&self, f: &mut F) {
+        match &self.expression {
+            ChainElement::CallExpression(expr) => expr.gather(f),
+            match_member_expression!(ChainElement) => {
+                self.expression.to_member_expression().gather(f);
+            }
+        }
+    }
+}
+
 impl<'a> GatherNodeParts<'a> for NewExpression<'a> {
     fn gather<F: FnMut(&str)>(&self, f: &mut F) {
         self.callee.gather(f);
@@ -103,6 +103,25 @@ impl TraverseScoping {
         self.insert_scope_below(&collector.scope_ids, flags)
     }
 
+    /// Insert a scope into scope tree below a list of statements.
+    ///
+    /// Statements must be in current scope.
+    /// New scope is created as child of current scope.
+    /// All child scopes of the statements are reassigned to be children of the new scope.
+    ///
+    /// `flags` provided are amended to inherit from parent scope's flags.
+    pub fn insert_scope_below_statements(
+        &mut self,
+        stmts: &[Statement],
+        flags: ScopeFlags,
+    ) -> ScopeId {
+        let mut collector = ChildScopeCollector::new();
+        for stmt in stmts {
+            collector.visit_statement(stmt);
+        }
+        self.insert_scope_below(&collector.scope_ids, flags)
+    }
+
     /// Insert a scope into scope tree below an expression.
     ///
     /// Expression must be in current scope.
@@ -20,6 +20,8 @@ export interface ModuleLexer {
   hasModuleSyntax: boolean
   /** Facade modules that only use import / export syntax */
   facade: boolean
+  /** `require("...")` calls, if `detectRequires` option is enabled */
+  requires: Array<ModuleLexerRequireSpecifier>
 }
 
 /**
@@ -29,6 +31,21 @@ export interface ModuleLexer {
  */
 export declare function moduleLexerAsync(sourceText: string, options?: ParserOptions | undefined | null): Promise<ModuleLexer>
 
+/**
+ * Run the module lexer over multiple sources in parallel.
+ *
+ * Results are returned in the order of `sources`.
+ * A source which fails does not affect the others, its `errors` are reported instead.
+ */
+export declare function moduleLexerBatchAsync(sources: Array<ModuleLexerSourceInput>): Promise<Array<ModuleLexerBatchResult>>
+
+export interface ModuleLexerBatchResult {
+  /** Module lexer output, or undefined if lexing this source failed unexpectedly. */
+  moduleLexer?: ModuleLexer
+  /** Parse errors, or the reason this source failed. */
+  errors: Array<string>
+}
+
 export interface ModuleLexerExportSpecifier {
   /** Exported name */
   n: string
@@ -42,6 +59,21 @@ export interface ModuleLexerExportSpecifier {
   ls?: number
   /** End of local name */
   le?: number
+  /** Module specifier of a re-export, or undefined for a local export. */
+  from?: string
+  /** Position of `s`, if `includePositions` option is enabled */
+  sPos?: ModuleLexerPosition
+  /** Position of `e`, if `includePositions` option is enabled */
+  ePos?: ModuleLexerPosition
+  /** Position of `ls`, if `includePositions` option is enabled */
+  lsPos?: ModuleLexerPosition
+  /** Position of `le`, if `includePositions` option is enabled */
+  lePos?: ModuleLexerPosition
+}
+
+export interface ModuleLexerImportAttribute {
+  key: string
+  value: string
 }
 
 export interface ModuleLexerImportSpecifier {
@@ -74,6 +106,54 @@ export interface ModuleLexerImportSpecifier {
    * Otherwise this is `-1`.
    */
   a: number
+  /**
+   * Import attributes (`with { type: "json" }` or `assert { type: "json" }`),
+   * or undefined if there are none, or they are not statically known.
+   */
+  attributes?: Array<ModuleLexerImportAttribute>
+  /** Position of `s`, if `includePositions` option is enabled */
+  sPos?: ModuleLexerPosition
+  /** Position of `e`, if `includePositions` option is enabled */
+  ePos?: ModuleLexerPosition
+  /** Position of `ss`, if `includePositions` option is enabled */
+  ssPos?: ModuleLexerPosition
+  /** Position of `se`, if `includePositions` option is enabled */
+  sePos?: ModuleLexerPosition
+}
+
+export interface ModuleLexerPosition {
+  /** 1-based line number */
+  line: number
+  /** 0-based column, counted in UTF-16 code units */
+  column: number
+}
+
+export interface ModuleLexerRequireSpecifier {
+  /**
+   * Module name
+   *
+   * This field will be empty if the argument is not a valid JS string.
+   */
+  n?: string
+  /** Start of module specifier */
+  s: number
+  /** End of module specifier */
+  e: number
+  /** Start of `require` call */
+  ss: number
+  /** End of `require` call */
+  se: number
+}
+
+export interface ModuleLexerSourceInput {
+  /**
+   * File name, used for inferring the source type, e.g. `.ts` or `.jsx`.
+   *
+   * This takes precedence over `options.sourceFilename`.
+   */
+  filename: string
+  sourceText: string
+  options?: ParserOptions
 }
 
 /**
@@ -117,6 +197,21 @@ export interface ParserOptions {
    * Default: true
    */
   preserveParens?: boolean
+  /**
+   * Report `require("...")` calls in the `requires` array of module lexer output.
+   *
+   * Calls of a `require` which is declared in the file are not reported.
+   *
+   * Default: false
+   */
+  detectRequires?: boolean
+  /**
+   * Report 1-based line and 0-based UTF-16 column positions alongside byte offsets
+   * of module lexer import and export specifiers.
+   *
+   * Default: false
+   */
+  includePositions?: boolean
 }
 
 /**
@@ -1,3 +1,4 @@
+mod line_offset_table;
 mod module_lexer;
 
 use std::sync::Arc;
@@ -8,13 +9,13 @@ use napi_derive::napi;
 use oxc::{
     allocator::Allocator,
     ast::CommentKind,
-    diagnostics::{Error, NamedSource},
+    diagnostics::{Error, NamedSource, OxcDiagnostic},
     napi::parse::{Comment, ParseResult, ParserOptions},
     parser::{ParseOptions, Parser, ParserReturn},
     span::SourceType,
 };
 
-pub use crate::module_lexer::*;
+pub use crate::{line_offset_table::ModuleLexerPosition, module_lexer::*};
 
 fn parse<'a>(
     allocator: &'a Allocator,
@@ -54,23 +55,30 @@ pub fn parse_without_return(source_text: String, options: Option<ParserOptions>)
     parse(&allocator, &source_text, &options);
 }
 
+fn format_errors(
+    source_text: &str,
+    options: &ParserOptions,
+    errors: Vec<OxcDiagnostic>,
+) -> Vec<String> {
+    if errors.is_empty() {
+        return vec![];
+    }
+    let file_name = options.source_filename.clone().unwrap_or_default();
+    let source = Arc::new(NamedSource::new(file_name, source_text.to_string()));
+    errors
+        .into_iter()
+        .map(|diagnostic| Error::from(diagnostic).with_source_code(Arc::clone(&source)))
+        .map(|error| format!("{error:?}"))
+        .collect()
+}
+
 #[allow(clippy::needless_lifetimes)]
 fn parse_with_return<'a>(source_text: &'a str, options: &ParserOptions) -> ParseResult {
     let allocator = Allocator::default();
     let ret = parse(&allocator, source_text, options);
     let program = serde_json::to_string(&ret.program).unwrap();
 
-    let errors = if ret.errors.is_empty() {
-        vec![]
-    } else {
-        let file_name = options.source_filename.clone().unwrap_or_default();
-        let source = Arc::new(NamedSource::new(file_name, source_text.to_string()));
-        ret.errors
-            .into_iter()
-            .map(|diagnostic| Error::from(diagnostic).with_source_code(Arc::clone(&source)))
-            .map(|error| format!("{error:?}"))
-            .collect()
-    };
+    let errors = format_errors(source_text, options, ret.errors);
 
     let comments = ret
         .program
@@ -0,0 +1,73 @@
+use napi_derive::napi;
+
+#[napi(object)]
+pub struct ModuleLexerPosition {
+    /// 1-based line number
+    pub line: u32,
+
+    /// 0-based column, counted in UTF-16 code units
+    pub column: u32,
+}
+
+struct Line {
+    /// Byte offset of the start of the line
+    start: u32,
+    /// Whether the line contains only ASCII characters,
+    /// in which case UTF-8 and UTF-16 columns are the same.
+    is_ascii: bool,
+}
+
+/// Byte offset to line / column lookup table.
+///
+/// Built once per source text, so each lookup is a binary search over the lines,
+/// rather than a scan from the start of the file.
+pub struct LineOffsetTable<'a> {
+    source_text: &'a str,
+    lines: Vec<Line>,
+}
+
+impl<'a> LineOffsetTable<'a> {
+    /// Line terminators are `\n`, `\r\n`, `\r`, `<LS>` and `<PS>`, as in ECMAScript.
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn new(source_text: &'a str) -> Self {
+        let mut lines = vec![];
+        let mut start = 0;
+        let mut is_ascii = true;
+        let mut chars = source_text.char_indices().peekable();
+        while let Some((offset, ch)) = chars.next() {
+            let end = match ch {
+                '\r' => {
+                    // Handle Windows-specific "\r\n" newlines
+                    if chars.next_if(|&(_, ch)| ch == '\n').is_some() {
+                        offset + 2
+                    } else {
+                        offset + 1
+                    }
+                }
+                '\n' | '\u{2028}' | '\u{2029}' => offset + ch.len_utf8(),
+                _ => {
+                    is_ascii &= ch.is_ascii();
+                    continue;
+                }
+            };
+            lines.push(Line { start, is_ascii });
+            start = end as u32;
+            is_ascii = true;
+        }
+        lines.push(Line { start, is_ascii });
+        Self { source_text, lines }
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn position(&self, offset: u32) -> ModuleLexerPosition {
+        // `lines[0].start` is always 0, so `index` is at least 1
+        let index = self.lines.partition_point(|line| line.start <= offset) - 1;
+        let line = &self.lines[index];
+        let column = if line.is_ascii {
+            offset - line.start
+        } else {
+            self.source_text[line.start as usize..offset as usize].encode_utf16().count() as u32
+        };
+        ModuleLexerPosition { line: index as u32 + 1, column }
+    }
+}
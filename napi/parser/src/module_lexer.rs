@@ -1,10 +1,25 @@
+use std::panic;
+
 use napi::{bindgen_prelude::AsyncTask, Task};
 use napi_derive::napi;
 
-use oxc::allocator::Allocator;
+use oxc::{
+    allocator::Allocator,
+    ast::{
+        ast::{Expression, Program},
+        AstKind,
+    },
+    semantic::{Semantic, SemanticBuilder},
+    span::GetSpan,
+};
 use oxc_module_lexer::ImportType;
+use rayon::prelude::*;
 
-use crate::{parse, ParserOptions};
+use crate::{
+    format_errors,
+    line_offset_table::{LineOffsetTable, ModuleLexerPosition},
+    parse, ParserOptions,
+};
 
 #[napi(object)]
 pub struct ModuleLexerImportSpecifier {
@@ -37,6 +52,29 @@ pub struct ModuleLexerImportSpecifier {
     /// If this import has an import assertion, this is the start value
     /// Otherwise this is `-1`.
     pub a: i64,
+
+    /// Import attributes (`with { type: "json" }` or `assert { type: "json" }`),
+    /// or undefined if there are none, or they are not statically known.
+    pub attributes: Option<Vec<ModuleLexerImportAttribute>>,
+
+    /// Position of `s`, if `includePositions` option is enabled
+    pub s_pos: Option<ModuleLexerPosition>,
+
+    /// Position of `e`, if `includePositions` option is enabled
+    pub e_pos: Option<ModuleLexerPosition>,
+
+    /// Position of `ss`, if `includePositions` option is enabled
+    pub ss_pos: Option<ModuleLexerPosition>,
+
+    /// Position of `se`, if `includePositions` option is enabled
+    pub se_pos: Option<ModuleLexerPosition>,
+}
+
+#[napi(object)]
+pub struct ModuleLexerImportAttribute {
+    pub key: String,
+
+    pub value: String,
 }
 
 #[napi(object)]
@@ -58,6 +96,21 @@ pub struct ModuleLexerExportSpecifier {
 
     /// End of local name
     pub le: Option<u32>,
+
+    /// Module specifier of a re-export, or undefined for a local export.
+    pub from: Option<String>,
+
+    /// Position of `s`, if `includePositions` option is enabled
+    pub s_pos: Option<ModuleLexerPosition>,
+
+    /// Position of `e`, if `includePositions` option is enabled
+    pub e_pos: Option<ModuleLexerPosition>,
+
+    /// Position of `ls`, if `includePositions` option is enabled
+    pub ls_pos: Option<ModuleLexerPosition>,
+
+    /// Position of `le`, if `includePositions` option is enabled
+    pub le_pos: Option<ModuleLexerPosition>,
 }
 
 impl<'a> From<oxc_module_lexer::ImportSpecifier<'a>> for ModuleLexerImportSpecifier {
@@ -76,6 +129,19 @@ impl<'a> From<oxc_module_lexer::ImportSpecifier<'a>> for ModuleLexerImportSpecif
                 ImportType::ExportStar => -3,
             },
             a: i.a.map_or(-1, |a| a as i64),
+            attributes: i.attributes.map(|attributes| {
+                attributes
+                    .into_iter()
+                    .map(|attribute| ModuleLexerImportAttribute {
+                        key: attribute.key.to_string(),
+                        value: attribute.value.to_string(),
+                    })
+                    .collect()
+            }),
+            s_pos: None,
+            e_pos: None,
+            ss_pos: None,
+            se_pos: None,
         }
     }
 }
@@ -89,10 +155,35 @@ impl<'a> From<oxc_module_lexer::ExportSpecifier<'a>> for ModuleLexerExportSpecif
             e: e.e,
             ls: e.ls,
             le: e.le,
+            from: e.from.map(|from| from.to_string()),
+            s_pos: None,
+            e_pos: None,
+            ls_pos: None,
+            le_pos: None,
         }
     }
 }
 
+#[napi(object)]
+pub struct ModuleLexerRequireSpecifier {
+    /// Module name
+    ///
+    /// This field will be empty if the argument is not a valid JS string.
+    pub n: Option<String>,
+
+    /// Start of module specifier
+    pub s: u32,
+
+    /// End of module specifier
+    pub e: u32,
+
+    /// Start of `require` call
+    pub ss: u32,
+
+    /// End of `require` call
+    pub se: u32,
+}
+
 #[napi(object)]
 pub struct ModuleLexer {
     pub imports: Vec<ModuleLexerImportSpecifier>,
@@ -106,23 +197,89 @@ pub struct ModuleLexer {
 
     /// Facade modules that only use import / export syntax
     pub facade: bool,
+
+    /// `require("...")` calls, if `detectRequires` option is enabled
+    pub requires: Vec<ModuleLexerRequireSpecifier>,
 }
 
-#[allow(clippy::needless_pass_by_value)]
 fn module_lexer(source_text: &str, options: &ParserOptions) -> ModuleLexer {
     let allocator = Allocator::default();
     let ret = parse(&allocator, source_text, options);
-    let module_lexer = oxc_module_lexer::ModuleLexer::new().build(&ret.program);
-    let imports = module_lexer.imports.into_iter().map(ModuleLexerImportSpecifier::from).collect();
-    let exports = module_lexer.exports.into_iter().map(ModuleLexerExportSpecifier::from).collect();
+    build_module_lexer(source_text, options, &ret.program)
+}
+
+fn build_module_lexer(
+    source_text: &str,
+    options: &ParserOptions,
+    program: &Program,
+) -> ModuleLexer {
+    let module_lexer = oxc_module_lexer::ModuleLexer::new().build(program);
+    let mut imports: Vec<_> =
+        module_lexer.imports.into_iter().map(ModuleLexerImportSpecifier::from).collect();
+    let mut exports: Vec<_> =
+        module_lexer.exports.into_iter().map(ModuleLexerExportSpecifier::from).collect();
+    if options.include_positions == Some(true) {
+        let table = LineOffsetTable::new(source_text);
+        for import in &mut imports {
+            import.s_pos = Some(table.position(import.s));
+            import.e_pos = Some(table.position(import.e));
+            import.ss_pos = Some(table.position(import.ss));
+            import.se_pos = Some(table.position(import.se));
+        }
+        for export in &mut exports {
+            export.s_pos = Some(table.position(export.s));
+            export.e_pos = Some(table.position(export.e));
+            export.ls_pos = export.ls.map(|ls| table.position(ls));
+            export.le_pos = export.le.map(|le| table.position(le));
+        }
+    }
+    let requires = if options.detect_requires == Some(true) {
+        let semantic = SemanticBuilder::new().build(program).semantic;
+        collect_requires(&semantic)
+    } else {
+        vec![]
+    };
     ModuleLexer {
         imports,
         exports,
         has_module_syntax: module_lexer.has_module_syntax,
         facade: module_lexer.facade,
+        requires,
     }
 }
 
+/// Collect `require(...)` calls where `require` is not declared in the file.
+fn collect_requires(semantic: &Semantic) -> Vec<ModuleLexerRequireSpecifier> {
+    let Some(reference_ids) = semantic.scopes().root_unresolved_references().get("require") else {
+        return vec![];
+    };
+    let nodes = semantic.nodes();
+    let mut requires = reference_ids
+        .iter()
+        .filter_map(|&reference_id| {
+            let node_id = semantic.symbols().get_reference(reference_id).node_id();
+            let AstKind::CallExpression(call) = nodes.parent_kind(node_id)? else { return None };
+            // `require` is the callee, not an argument
+            if !matches!(&call.callee, Expression::Identifier(ident) if ident.reference_id() == reference_id)
+            {
+                return None;
+            }
+            let [argument] = call.arguments.as_slice() else { return None };
+            let argument = argument.as_expression()?;
+            // +1 -1 to remove the string quotes
+            let (n, s, e) = if let Expression::StringLiteral(s) = argument {
+                (Some(s.value.to_string()), s.span.start + 1, s.span.end - 1)
+            } else {
+                let span = argument.span();
+                (None, span.start, span.end)
+            };
+            Some(ModuleLexerRequireSpecifier { n, s, e, ss: call.span.start, se: call.span.end })
+        })
+        .collect::<Vec<_>>();
+    requires.sort_unstable_by_key(|require| require.ss);
+    requires
+}
+
 /// Outputs the list of exports and locations of import specifiers,
 /// including dynamic import and import meta handling.
 ///
@@ -167,3 +324,79 @@ pub fn module_lexer_async(
     let options = options.unwrap_or_default();
     AsyncTask::new(ResolveTask { source_text, options })
 }
+
+#[napi(object)]
+pub struct ModuleLexerSourceInput {
+    /// File name, used for inferring the source type, e.g. `.ts` or `.jsx`.
+    ///
+    /// This takes precedence over `options.sourceFilename`.
+    pub filename: String,
+
+    pub source_text: String,
+
+    pub options: Option<ParserOptions>,
+}
+
+#[napi(object)]
+pub struct ModuleLexerBatchResult {
+    /// Module lexer output, or undefined if lexing this source failed unexpectedly.
+    pub module_lexer: Option<ModuleLexer>,
+
+    /// Parse errors, or the reason this source failed.
+    pub errors: Vec<String>,
+}
+
+fn module_lexer_batch_item(input: ModuleLexerSourceInput) -> ModuleLexerBatchResult {
+    let ModuleLexerSourceInput { filename, source_text, options } = input;
+    let mut options = options.unwrap_or_default();
+    options.source_filename = Some(filename);
+    // Contain a panic to this source, so the rest of the batch is still returned.
+    let result = panic::catch_unwind(|| {
+        let allocator = Allocator::default();
+        let ret = parse(&allocator, &source_text, &options);
+        let module_lexer = build_module_lexer(&source_text, &options, &ret.program);
+        (module_lexer, ret.errors)
+    });
+    match result {
+        Ok((module_lexer, errors)) => ModuleLexerBatchResult {
+            module_lexer: Some(module_lexer),
+            errors: format_errors(&source_text, &options, errors),
+        },
+        Err(payload) => {
+            let message = payload
+                .downcast_ref::<&str>()
+                .map(ToString::to_string)
+                .or_else(|| payload.downcast_ref::<String>().cloned())
+                .unwrap_or_else(|| "unknown panic".to_string());
+            ModuleLexerBatchResult { module_lexer: None, errors: vec![message] }
+        }
+    }
+}
+
+pub struct BatchTask {
+    sources: Vec<ModuleLexerSourceInput>,
+}
+
+#[napi]
+impl Task for BatchTask {
+    type JsValue = Vec<ModuleLexerBatchResult>;
+    type Output = Vec<ModuleLexerBatchResult>;
+
+    fn compute(&mut self) -> napi::Result<Self::Output> {
+        let sources = std::mem::take(&mut self.sources);
+        Ok(sources.into_par_iter().map(module_lexer_batch_item).collect())
+    }
+
+    fn resolve(&mut self, _: napi::Env, result: Self::Output) -> napi::Result<Self::JsValue> {
+        Ok(result)
+    }
+}
+
+/// Run the module lexer over multiple sources in parallel.
+///
+/// Results are returned in the order of `sources`.
+/// A source which fails does not affect the others, its `errors` are reported instead.
+#[napi]
+pub fn module_lexer_batch_async(sources: Vec<ModuleLexerSourceInput>) -> AsyncTask<BatchTask> {
+    AsyncTask::new(BatchTask { sources })
+}
@@ -23,7 +23,160 @@ describe('module lexer', () => {
         exports: [],
         hasModuleSyntax: true,
         facade: true,
+        requires: [],
       },
     );
   });
+
+  it('returns re-export specifiers', async () => {
+    const code = [
+      "export { a, default as b } from './m';",
+      "export * as ns from './n';",
+      "export * from './o';",
+      'const c = 1;',
+      'export { c };',
+    ].join('\n');
+    const ret = await oxc.moduleLexerAsync(code);
+    expect(ret.exports.map(({ n, ln, from }) => ({ n, ln, from }))).toEqual([
+      { n: 'a', ln: undefined, from: './m' },
+      { n: 'b', ln: undefined, from: './m' },
+      { n: 'ns', ln: undefined, from: './n' },
+      { n: 'c', ln: 'c', from: undefined },
+    ]);
+    // `export *` is still only reported as an import
+    expect(ret.imports.filter((i) => i.d === -3).map((i) => i.n)).toEqual(['./o']);
+  });
+
+  it('returns static import attributes', async () => {
+    const code = [
+      "import json from './foo.json' with { type: 'json' };",
+      "import sheet from './foo.css' assert { type: 'css' };",
+      "import js from './foo.js';",
+    ].join('\n');
+    const ret = await oxc.moduleLexerAsync(code);
+    expect(ret.imports.map((i) => i.attributes)).toEqual([
+      [{ key: 'type', value: 'json' }],
+      [{ key: 'type', value: 'css' }],
+      undefined,
+    ]);
+  });
+
+  it('returns dynamic import attributes', async () => {
+    const code = [
+      "import('./foo.json', { with: { type: 'json' } });",
+      "import('./foo.css', { assert: { type: 'css' } });",
+      "import('./foo.json', options);",
+    ].join('\n');
+    const ret = await oxc.moduleLexerAsync(code);
+    expect(ret.imports.map((i) => i.attributes)).toEqual([
+      [{ key: 'type', value: 'json' }],
+      [{ key: 'type', value: 'css' }],
+      undefined,
+    ]);
+  });
+
+  it('returns top-level and nested require calls', async () => {
+    const code = [
+      "const a = require('a');",
+      'function f() {',
+      "  return require('b').c;",
+      '}',
+      'require(name);',
+    ].join('\n');
+    const ret = await oxc.moduleLexerAsync(code, { detectRequires: true });
+    expect(ret.requires.map(({ n }) => n)).toEqual(['a', 'b', undefined]);
+    const [first] = ret.requires;
+    expect(code.slice(first.s, first.e)).toBe('a');
+    expect(code.slice(first.ss, first.se)).toBe("require('a')");
+  });
+
+  it('skips shadowed require', async () => {
+    const code = [
+      "require('a');",
+      'function require() {}',
+    ].join('\n');
+    const ret = await oxc.moduleLexerAsync(code, { detectRequires: true });
+    expect(ret.requires).toEqual([]);
+  });
+
+  it('does not return require calls by default', async () => {
+    const ret = await oxc.moduleLexerAsync("require('a');");
+    expect(ret.requires).toEqual([]);
+  });
+
+  it('returns line and column positions', async () => {
+    const code = [
+      "import a from 'a';",
+      "const s = '🍄';",
+      "export { s as b };",
+    ].join('\n');
+    const ret = await oxc.moduleLexerAsync(code, { includePositions: true });
+    expect(ret.imports[0].sPos).toEqual({ line: 1, column: 15 });
+    expect(ret.imports[0].ssPos).toEqual({ line: 1, column: 0 });
+    expect(ret.exports[0].sPos).toEqual({ line: 3, column: 14 });
+    expect(ret.exports[0].lsPos).toEqual({ line: 3, column: 9 });
+  });
+
+  it('returns utf16 columns after multi-byte characters', async () => {
+    const code = "/* 🍄é */ import a from 'a';";
+    const ret = await oxc.moduleLexerAsync(code, { includePositions: true });
+    // '🍄' is 2 UTF-16 code units and 'é' is 1, while they are 4 and 2 bytes
+    expect(ret.imports[0].s).toBe(28);
+    expect(ret.imports[0].sPos).toEqual({ line: 1, column: 25 });
+  });
+
+  it('returns positions with CRLF line endings', async () => {
+    const code = "import a from 'a';\r\nimport b from 'b';";
+    const ret = await oxc.moduleLexerAsync(code, { includePositions: true });
+    expect(ret.imports.map((i) => i.ssPos)).toEqual([
+      { line: 1, column: 0 },
+      { line: 2, column: 0 },
+    ]);
+  });
+
+  it('does not return positions by default', async () => {
+    const ret = await oxc.moduleLexerAsync("import a from 'a';");
+    expect(ret.imports[0].sPos).toBeUndefined();
+  });
+
+  describe('batch', () => {
+    it('returns results in input order', async () => {
+      const sources = Array.from({ length: 20 }, (_, i) => ({
+        filename: `${i}.js`,
+        sourceText: `import a from './${i}';`,
+      }));
+      const ret = await oxc.moduleLexerBatchAsync(sources);
+      expect(ret.map((r) => r.moduleLexer?.imports[0].n)).toEqual(
+        sources.map((_, i) => `./${i}`),
+      );
+      expect(ret.every((r) => r.errors.length === 0)).toBe(true);
+    });
+
+    it('infers source type from filename', async () => {
+      const ret = await oxc.moduleLexerBatchAsync([
+        { filename: 'a.ts', sourceText: "import type { A } from './a'; export const b: A = 1;" },
+        { filename: 'b.jsx', sourceText: "import c from './c'; export default <div />;" },
+        { filename: 'c.js', sourceText: "import type { A } from './a';" },
+      ]);
+      expect(ret[0].errors).toEqual([]);
+      expect(ret[0].moduleLexer?.exports.map((e) => e.n)).toEqual(['b']);
+      expect(ret[1].errors).toEqual([]);
+      expect(ret[1].moduleLexer?.exports.map((e) => e.n)).toEqual(['default']);
+      // TypeScript syntax is an error in a `.js` file
+      expect(ret[2].errors.length).toBeGreaterThan(0);
+    });
+
+    it('isolates errors to their source', async () => {
+      const ret = await oxc.moduleLexerBatchAsync([
+        { filename: 'a.js', sourceText: "import a from './a';" },
+        { filename: 'b.js', sourceText: 'import {' },
+        { filename: 'c.js', sourceText: "export * from './c';", options: { includePositions: true } },
+      ]);
+      expect(ret[0].errors).toEqual([]);
+      expect(ret[0].moduleLexer?.imports.map((i) => i.n)).toEqual(['./a']);
+      expect(ret[1].errors.length).toBeGreaterThan(0);
+      expect(ret[2].errors).toEqual([]);
+      expect(ret[2].moduleLexer?.imports[0].ssPos).toEqual({ line: 1, column: 0 });
+    });
+  });
 });
@@ -15,6 +15,36 @@ export interface ArrowFunctionsOptions {
 export interface Es2015Options {
   /** Transform arrow functions into function expressions. */
   arrowFunction?: ArrowFunctionsOptions
+  /**
+   * Transform `let` and `const` declarations into `var` declarations.
+   *
+   * @default false
+   */
+  blockScoping?: boolean
+  /**
+   * Transform classes into constructor functions.
+   *
+   * @default false
+   */
+  classes?: boolean
+  /**
+   * Transform array and object destructuring into property accesses.
+   *
+   * @default false
+   */
+  destructuring?: boolean
+  /**
+   * Transform `__proto__` in object literals into `Object.setPrototypeOf` calls.
+   *
+   * @default false
+   */
+  objectLiteralProto?: boolean
+  /**
+   * Transform template literals into string concatenation, and tagged templates into calls.
+   *
+   * @default false
+   */
+  templateLiterals?: boolean
 }
 
 /** TypeScript Isolated Declarations for Standalone DTS Emit */
@@ -50,10 +80,11 @@ export interface JsxOptions {
    *
    * - 'automatic' - auto-import the correct JSX factories
    * - 'classic' - no auto-import
+   * - 'preserve' - keep JSX as is, only removing TypeScript syntax
    *
    * @default 'automatic'
    */
-  runtime?: 'classic' | 'automatic'
+  runtime?: 'classic' | 'automatic' | 'preserve'
   /**
    * Emit development-specific information, such as `__source` and `__self`.
    *
@@ -72,6 +103,13 @@ export interface JsxOptions {
    * @default true
    */
   throwIfNamespace?: boolean
+  /**
+   * Report a warning if a component element name (e.g. `<Foo />`) does not
+   * resolve to any binding in scope, which usually means a missing import.
+   *
+   * @default false
+   */
+  checkUnresolvedComponents?: boolean
   /**
    * Enables `@babel/plugin-transform-react-pure-annotations`.
    *
@@ -147,6 +185,11 @@ export interface ReactRefreshOptions {
    * @default `$RefreshSig$`.
    */
   refreshSig?: string
+  /**
+   * Emit readable signatures of hook calls, instead of a hash of them.
+   *
+   * @default false
+   */
   emitFullSignatures?: boolean
 }
 
@@ -203,6 +246,14 @@ export interface TransformOptions {
   typescript?: TypeScriptOptions
   /** Configure how TSX and JSX are transformed. */
   jsx?: JsxOptions
+  /**
+   * Keep decorators in the output as written.
+   *
+   * Decorators are not transformed, so when this is not enabled they are reported as errors.
+   *
+   * @default false
+   */
+  decorators?: boolean
   /** Define Plugin */
   define?: Record<string, string>
   /** Inject Plugin */
@@ -253,8 +304,41 @@ export interface TypeScriptOptions {
   jsxPragma?: string
   jsxPragmaFrag?: string
   onlyRemoveTypeImports?: boolean
+  /**
+   * Only remove type-only imports and exports and inline `type` specifiers,
+   * and keep other imports as written, the same as TypeScript's `verbatimModuleSyntax`.
+   *
+   * @default false
+   */
+  verbatimModuleSyntax?: boolean
+  /**
+   * Warn about re-exports of imported names that may be types,
+   * the same as TypeScript's `isolatedModules`.
+   *
+   * @default false
+   */
+  isolatedModules?: boolean
   allowNamespaces?: boolean
+  /**
+   * Emit the members of flat exported namespaces as ES module exports, instead of an IIFE.
+   *
+   * @default false
+   */
+  flattenNamespaces?: boolean
   allowDeclareFields?: boolean
+  /**
+   * Inline members of `const enum`s at use sites and remove their declarations.
+   * Constant members of regular enums which are only used by reading members are inlined too.
+   *
+   * @default false
+   */
+  optimizeConstEnums?: boolean
+  /**
+   * Emit design-time type metadata for decorated declarations, as `Reflect.metadata` decorators.
+   *
+   * @default false
+   */
+  emitDecoratorMetadata?: boolean
   /**
    * Also generate a `.d.ts` declaration file for TypeScript files.
    *
@@ -216,14 +216,14 @@ const es2018 = [
     babel: 'transform-async-generator-functions',
     features: ['Asynchronous Iterators'],
   },
+].map(f('ES2018'));
+
+const es2019 = [
   {
     name: 'OptionalCatchBinding',
     babel: 'transform-optional-catch-binding',
     features: ['optional catch binding'],
   },
-].map(f('ES2018'));
-
-const es2019 = [
   {
     name: 'JsonStrings',
     babel: 'transform-json-strings',
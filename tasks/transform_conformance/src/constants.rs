@@ -5,7 +5,7 @@ pub(crate) const PLUGINS: &[&str] = &[
     // // ES2022
     // "babel-plugin-transform-class-properties",
     "babel-plugin-transform-class-static-block",
-    // "babel-plugin-transform-private-methods",
+    "babel-plugin-transform-private-methods",
     // "babel-plugin-transform-private-property-in-object",
     // // [Syntax] "babel-plugin-transform-syntax-top-level-await",
     // ES2021
@@ -15,7 +15,7 @@ pub(crate) const PLUGINS: &[&str] = &[
     // "babel-plugin-transform-export-namespace-from",
     // "babel-plugin-transform-dynamic-import",
     "babel-plugin-transform-nullish-coalescing-operator",
-    // "babel-plugin-transform-optional-chaining",
+    "babel-plugin-transform-optional-chaining",
     // // [Syntax] "babel-plugin-transform-syntax-bigint",
     // // [Syntax] "babel-plugin-transform-syntax-dynamic-import",
     // // [Syntax] "babel-plugin-transform-syntax-import-meta",
@@ -34,11 +34,14 @@ pub(crate) const PLUGINS: &[&str] = &[
     "babel-plugin-transform-exponentiation-operator",
     // ES2015
     "babel-plugin-transform-arrow-functions",
+    "babel-plugin-transform-block-scoping",
+    "babel-plugin-transform-classes",
+    "babel-plugin-transform-destructuring",
     // "babel-plugin-transform-function-name",
     // "babel-plugin-transform-shorthand-properties",
     // "babel-plugin-transform-sticky-regex",
     // "babel-plugin-transform-unicode-regex",
-    // "babel-plugin-transform-template-literals",
+    "babel-plugin-transform-template-literals",
     // "babel-plugin-transform-duplicate-keys",
     // "babel-plugin-transform-instanceof",
     // "babel-plugin-transform-new-target",
@@ -56,7 +59,7 @@ pub(crate) const PLUGINS: &[&str] = &[
     "babel-plugin-transform-react-jsx-development",
     // // Proposal
     // "babel-plugin-proposal-decorators",
-
+    "babel-plugin-proposal-explicit-resource-management",
     // RegExp tests ported from esbuild + a few additions
     "regexp",
 ];
@@ -4,6 +4,7 @@ use oxc::{
     ast::ast::Program,
     codegen::{CodegenOptions, CodegenReturn},
     diagnostics::OxcDiagnostic,
+    parser::ParseOptions,
     span::SourceType,
     transformer::{TransformOptions, TransformerReturn},
     CompilerInterface,
@@ -18,6 +19,11 @@ pub struct Driver {
 }
 
 impl CompilerInterface for Driver {
+    fn parse_options(&self) -> ParseOptions {
+        // Babel's exec tests return a promise from the top level to run async assertions
+        ParseOptions { allow_return_outside_function: true, ..ParseOptions::default() }
+    }
+
     fn transform_options(&self) -> Option<&TransformOptions> {
         Some(&self.options)
     }
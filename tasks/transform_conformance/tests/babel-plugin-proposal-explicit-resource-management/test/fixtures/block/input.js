@@ -0,0 +1,5 @@
+{
+  using x = getResource(), y = getOther();
+  let z = () => x;
+  doSomethingWith(x, y, z);
+}
@@ -0,0 +1,12 @@
+{
+  try {
+    var _usingCtx = babelHelpers.usingCtx();
+    const x = _usingCtx.u(getResource()), y = _usingCtx.u(getOther());
+    let z = () => x;
+    doSomethingWith(x, y, z);
+  } catch (_) {
+    _usingCtx.e = _;
+  } finally {
+    _usingCtx.d();
+  }
+}
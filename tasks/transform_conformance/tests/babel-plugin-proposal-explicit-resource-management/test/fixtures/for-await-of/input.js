@@ -0,0 +1,8 @@
+async function f() {
+  for (await using x of resources) {
+    await doSomethingWith(x);
+  }
+  for await (using y of asyncIterable) {
+    doSomethingWith(y);
+  }
+}
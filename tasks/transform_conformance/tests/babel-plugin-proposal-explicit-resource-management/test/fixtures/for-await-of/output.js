@@ -0,0 +1,24 @@
+async function f() {
+  for (const _x of resources) {
+    try {
+      var _usingCtx = babelHelpers.usingCtx();
+      const x = _usingCtx.a(_x);
+      await doSomethingWith(x);
+    } catch (_) {
+      _usingCtx.e = _;
+    } finally {
+      await _usingCtx.d();
+    }
+  }
+  for await (const _y of asyncIterable) {
+    try {
+      var _usingCtx2 = babelHelpers.usingCtx();
+      const y = _usingCtx2.u(_y);
+      doSomethingWith(y);
+    } catch (_2) {
+      _usingCtx2.e = _2;
+    } finally {
+      _usingCtx2.d();
+    }
+  }
+}
@@ -0,0 +1,24 @@
+const log = [];
+
+function resource(name) {
+  return {
+    [Symbol.dispose]() {
+      log.push(`dispose ${name}`);
+    },
+  };
+}
+
+for (using x of [resource("a"), resource("b")]) {
+  log.push("body");
+}
+
+expect(log).toEqual(["body", "dispose a", "body", "dispose b"]);
+
+log.length = 0;
+
+for (using x of [resource("a"), resource("b")]) {
+  log.push("body");
+  break;
+}
+
+expect(log).toEqual(["body", "dispose a"]);
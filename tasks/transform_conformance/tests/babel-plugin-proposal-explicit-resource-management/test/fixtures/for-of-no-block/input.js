@@ -0,0 +1 @@
+for (using x of resources) doSomethingWith(x);
@@ -0,0 +1,11 @@
+for (const _x of resources) {
+  try {
+    var _usingCtx = babelHelpers.usingCtx();
+    const x = _usingCtx.u(_x);
+    doSomethingWith(x);
+  } catch (_) {
+    _usingCtx.e = _;
+  } finally {
+    _usingCtx.d();
+  }
+}
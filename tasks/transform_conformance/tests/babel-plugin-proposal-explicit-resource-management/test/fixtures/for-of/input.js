@@ -0,0 +1,5 @@
+for (using x of resources) {
+  if (x.skip) continue;
+  if (x.last) break;
+  doSomethingWith(x);
+}
@@ -0,0 +1,13 @@
+for (const _x of resources) {
+  try {
+    var _usingCtx = babelHelpers.usingCtx();
+    const x = _usingCtx.u(_x);
+    if (x.skip) continue;
+    if (x.last) break;
+    doSomethingWith(x);
+  } catch (_) {
+    _usingCtx.e = _;
+  } finally {
+    _usingCtx.d();
+  }
+}
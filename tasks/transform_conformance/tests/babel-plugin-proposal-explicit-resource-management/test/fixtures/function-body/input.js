@@ -0,0 +1,13 @@
+function f() {
+  const a = 1;
+  using x = getResource();
+  return g(a, x);
+  function g() {}
+}
+
+async function h() {
+  await using x = getResource();
+  using y = getResource();
+  class C {}
+  return new C(x, y);
+}
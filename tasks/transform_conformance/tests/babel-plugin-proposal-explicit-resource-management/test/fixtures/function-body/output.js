@@ -0,0 +1,26 @@
+function f() {
+  try {
+    var _usingCtx = babelHelpers.usingCtx();
+    const a = 1;
+    const x = _usingCtx.u(getResource());
+    return g(a, x);
+    function g() {}
+  } catch (_) {
+    _usingCtx.e = _;
+  } finally {
+    _usingCtx.d();
+  }
+}
+async function h() {
+  try {
+    var _usingCtx2 = babelHelpers.usingCtx();
+    const x = _usingCtx2.a(getResource());
+    const y = _usingCtx2.u(getResource());
+    class C {}
+    return new C(x, y);
+  } catch (_2) {
+    _usingCtx2.e = _2;
+  } finally {
+    await _usingCtx2.d();
+  }
+}
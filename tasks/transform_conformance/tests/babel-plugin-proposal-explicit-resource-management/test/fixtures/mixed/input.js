@@ -0,0 +1,11 @@
+function f() {
+  try {
+    using x = getResource();
+    for (using y of x.children) {
+      using z = y.open();
+      z.run();
+    }
+  } catch (err) {
+    report(err);
+  }
+}
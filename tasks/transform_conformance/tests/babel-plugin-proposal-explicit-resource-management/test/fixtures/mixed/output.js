@@ -0,0 +1,26 @@
+function f() {
+  try {
+    try {
+      var _usingCtx = babelHelpers.usingCtx();
+      const x = _usingCtx.u(getResource());
+      for (const _y of x.children) {
+        try {
+          var _usingCtx2 = babelHelpers.usingCtx();
+          const y = _usingCtx2.u(_y);
+          const z = _usingCtx2.u(y.open());
+          z.run();
+        } catch (_2) {
+          _usingCtx2.e = _2;
+        } finally {
+          _usingCtx2.d();
+        }
+      }
+    } catch (_) {
+      _usingCtx.e = _;
+    } finally {
+      _usingCtx.d();
+    }
+  } catch (err) {
+    report(err);
+  }
+}
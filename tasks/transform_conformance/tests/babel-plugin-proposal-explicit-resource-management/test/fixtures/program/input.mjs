@@ -0,0 +1,3 @@
+import { getResource } from "resource";
+using x = getResource();
+doSomethingWith(x);
@@ -0,0 +1,10 @@
+import { getResource } from "resource";
+try {
+  var _usingCtx = babelHelpers.usingCtx();
+  const x = _usingCtx.u(getResource());
+  doSomethingWith(x);
+} catch (_) {
+  _usingCtx.e = _;
+} finally {
+  _usingCtx.d();
+}
@@ -0,0 +1,63 @@
+const log = [];
+
+function resource(name, rejects) {
+  return {
+    async [Symbol.asyncDispose]() {
+      log.push(`dispose ${name}`);
+      if (rejects) throw new Error(name);
+    },
+  };
+}
+
+async function run(fn) {
+  log.length = 0;
+  try {
+    await fn();
+  } catch (error) {
+    return error;
+  }
+}
+
+return (async () => {
+  // Body throws and every disposal rejects.
+  // Each rejection suppresses the error thrown before it, so the chain is built in reverse disposal order.
+  let caught = await run(async () => {
+    await using a = resource("a", true);
+    await using b = resource("b", true);
+    await using c = resource("c", true);
+    log.push("body");
+    throw new Error("body");
+  });
+
+  expect(log).toEqual(["body", "dispose c", "dispose b", "dispose a"]);
+  expect(caught.name).toBe("SuppressedError");
+  expect(caught.error.message).toBe("a");
+  expect(caught.suppressed.name).toBe("SuppressedError");
+  expect(caught.suppressed.error.message).toBe("b");
+  expect(caught.suppressed.suppressed.name).toBe("SuppressedError");
+  expect(caught.suppressed.suppressed.error.message).toBe("c");
+  expect(caught.suppressed.suppressed.suppressed.message).toBe("body");
+
+  // Only disposals reject
+  caught = await run(async () => {
+    await using a = resource("a", true);
+    await using b = resource("b", false);
+    await using c = resource("c", true);
+    log.push("body");
+  });
+
+  expect(log).toEqual(["body", "dispose c", "dispose b", "dispose a"]);
+  expect(caught.name).toBe("SuppressedError");
+  expect(caught.error.message).toBe("a");
+  expect(caught.suppressed.message).toBe("c");
+
+  // A single rejection is thrown as is
+  caught = await run(async () => {
+    await using a = resource("a", false);
+    await using b = resource("b", true);
+  });
+
+  expect(log).toEqual(["dispose b", "dispose a"]);
+  expect(caught.name).toBe("Error");
+  expect(caught.message).toBe("b");
+})();
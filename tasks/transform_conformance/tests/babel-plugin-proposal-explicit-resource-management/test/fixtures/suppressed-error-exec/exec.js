@@ -0,0 +1,61 @@
+const log = [];
+
+function resource(name, throws) {
+  return {
+    [Symbol.dispose]() {
+      log.push(`dispose ${name}`);
+      if (throws) throw new Error(name);
+    },
+  };
+}
+
+// Body and disposals throw.
+// Each disposal error suppresses the error thrown before it, so the chain is built in reverse disposal order.
+let caught;
+try {
+  using a = resource("a", true);
+  using b = resource("b", false);
+  using c = resource("c", true);
+  log.push("body");
+  throw new Error("body");
+} catch (error) {
+  caught = error;
+}
+
+expect(log).toEqual(["body", "dispose c", "dispose b", "dispose a"]);
+expect(caught.name).toBe("SuppressedError");
+expect(caught.error.message).toBe("a");
+expect(caught.suppressed.name).toBe("SuppressedError");
+expect(caught.suppressed.error.message).toBe("c");
+expect(caught.suppressed.suppressed.message).toBe("body");
+
+// Only disposals throw
+log.length = 0;
+caught = undefined;
+try {
+  using a = resource("a", true);
+  using b = resource("b", true);
+  log.push("body");
+} catch (error) {
+  caught = error;
+}
+
+expect(log).toEqual(["body", "dispose b", "dispose a"]);
+expect(caught.name).toBe("SuppressedError");
+expect(caught.error.message).toBe("a");
+expect(caught.suppressed.message).toBe("b");
+
+// A single error is thrown as is
+log.length = 0;
+caught = undefined;
+try {
+  using a = resource("a", false);
+  using b = resource("b", true);
+} catch (error) {
+  caught = error;
+}
+
+expect(log).toEqual(["dispose b", "dispose a"]);
+expect(caught).toBeInstanceOf(Error);
+expect(caught.name).toBe("Error");
+expect(caught.message).toBe("b");
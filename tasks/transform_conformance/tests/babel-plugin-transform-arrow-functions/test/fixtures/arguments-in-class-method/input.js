@@ -0,0 +1,8 @@
+class C {
+  method() {
+    return () => arguments.length;
+  }
+  static method() {
+    return () => this.name + arguments[0];
+  }
+}
@@ -0,0 +1,14 @@
+class C {
+  method() {
+    var _arguments = arguments;
+    return function() {
+      return _arguments.length;
+    };
+  }
+  static method() {
+    var _this = this, _arguments2 = arguments;
+    return function() {
+      return _this.name + _arguments2[0];
+    };
+  }
+}
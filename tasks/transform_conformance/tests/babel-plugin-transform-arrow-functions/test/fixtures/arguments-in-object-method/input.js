@@ -0,0 +1,8 @@
+var obj = {
+  method() {
+    return () => [this, arguments[0]];
+  },
+  nested() {
+    return () => () => arguments;
+  },
+};
@@ -0,0 +1,16 @@
+var obj = {
+  method() {
+    var _this = this, _arguments = arguments;
+    return function() {
+      return [_this, _arguments[0]];
+    };
+  },
+  nested() {
+    var _arguments2 = arguments;
+    return function() {
+      return function() {
+        return _arguments2;
+      };
+    };
+  },
+};
@@ -0,0 +1,8 @@
+function foo(arguments) {
+  return () => arguments;
+}
+
+function bar() {
+  var arguments = [];
+  return () => arguments;
+}
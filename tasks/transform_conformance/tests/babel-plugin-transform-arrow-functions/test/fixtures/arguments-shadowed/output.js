@@ -0,0 +1,12 @@
+function foo(arguments) {
+  return function() {
+    return arguments;
+  };
+}
+
+function bar() {
+  var arguments = [];
+  return function() {
+    return arguments;
+  };
+}
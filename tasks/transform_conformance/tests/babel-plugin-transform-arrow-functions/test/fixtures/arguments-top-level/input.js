@@ -0,0 +1 @@
+var f = () => [this, arguments];
@@ -0,0 +1,4 @@
+var _this = this, _arguments = typeof arguments === "undefined" ? void 0 : arguments;
+var f = function() {
+  return [_this, _arguments];
+};
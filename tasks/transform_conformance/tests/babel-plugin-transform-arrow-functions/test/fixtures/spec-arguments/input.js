@@ -0,0 +1,13 @@
+var f = () => [this, arguments];
+
+var obj = {
+  method() {
+    return () => arguments[0];
+  },
+};
+
+class C {
+  method() {
+    return () => () => [this, arguments];
+  }
+}
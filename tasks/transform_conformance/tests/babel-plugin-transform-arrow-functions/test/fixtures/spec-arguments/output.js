@@ -0,0 +1,28 @@
+var _this = this, _arguments = typeof arguments === "undefined" ? void 0 : arguments;
+var f = function() {
+  babelHelpers.newArrowCheck(this, _this);
+  return [this, _arguments];
+}.bind(this);
+
+var obj = {
+  method() {
+    var _this2 = this, _arguments2 = arguments;
+    return function() {
+      babelHelpers.newArrowCheck(this, _this2);
+      return _arguments2[0];
+    }.bind(this);
+  },
+};
+
+class C {
+  method() {
+    var _this3 = this, _arguments3 = arguments;
+    return function() {
+      babelHelpers.newArrowCheck(this, _this3);
+      return function() {
+        babelHelpers.newArrowCheck(this, _this3);
+        return [this, _arguments3];
+      }.bind(this);
+    }.bind(this);
+  }
+}
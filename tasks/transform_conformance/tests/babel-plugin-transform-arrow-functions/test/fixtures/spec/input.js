@@ -0,0 +1,11 @@
+var a = () => this;
+
+var bob = {
+  printFriends() {
+    this.friends.forEach((f) => console.log(this.name + " knows " + f));
+  },
+};
+
+function outer() {
+  return () => () => arguments;
+}
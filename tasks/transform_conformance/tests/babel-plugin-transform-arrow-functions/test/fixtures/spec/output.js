@@ -0,0 +1,26 @@
+var _this = this;
+var a = function() {
+  babelHelpers.newArrowCheck(this, _this);
+  return this;
+}.bind(this);
+
+var bob = {
+  printFriends() {
+    var _this2 = this;
+    this.friends.forEach(function(f) {
+      babelHelpers.newArrowCheck(this, _this2);
+      return console.log(this.name + " knows " + f);
+    }.bind(this));
+  },
+};
+
+function outer() {
+  var _this3 = this, _arguments = arguments;
+  return function() {
+    babelHelpers.newArrowCheck(this, _this3);
+    return function() {
+      babelHelpers.newArrowCheck(this, _this3);
+      return _arguments;
+    }.bind(this);
+  }.bind(this);
+}
@@ -0,0 +1,8 @@
+class Foo {
+  a = () => this;
+  static b = () => () => this.a;
+  accessor c = () => this;
+  d = () => {
+    const f = () => this;
+  };
+}
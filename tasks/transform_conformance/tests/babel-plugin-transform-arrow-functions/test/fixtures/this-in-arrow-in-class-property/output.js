@@ -0,0 +1,18 @@
+class Foo {
+  a = function() {
+    return this;
+  }.bind(this);
+  static b = function() {
+    return function() {
+      return this.a;
+    }.bind(this);
+  }.bind(this);
+  accessor c = function() {
+    return this;
+  }.bind(this);
+  d = function() {
+    const f = function() {
+      return this;
+    }.bind(this);
+  }.bind(this);
+}
@@ -0,0 +1,9 @@
+async function* f(iterable) {
+  for await (const x of iterable) {
+    if (x === 0) continue;
+    if (x === 1) break;
+    if (x === 2) return x;
+    if (x === 3) throw new Error("three");
+    yield x;
+  }
+}
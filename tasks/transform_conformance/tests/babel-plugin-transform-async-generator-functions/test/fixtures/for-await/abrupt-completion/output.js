@@ -0,0 +1,36 @@
+function f(_x) {
+  return _f.apply(this, arguments);
+}
+function _f() {
+  _f = babelHelpers.wrapAsyncGenerator(function* (iterable) {
+    var _iteratorAbruptCompletion = false;
+    var _didIteratorError = false;
+    var _iteratorError;
+    try {
+      for (var _iterator = babelHelpers.asyncIterator(iterable), _step; _iteratorAbruptCompletion = !(_step = yield babelHelpers.awaitAsyncGenerator(_iterator.next())).done; _iteratorAbruptCompletion = false) {
+        const x = _step.value;
+        {
+          if (x === 0) continue;
+          if (x === 1) break;
+          if (x === 2) return x;
+          if (x === 3) throw new Error("three");
+          yield x;
+        }
+      }
+    } catch (err) {
+      _didIteratorError = true;
+      _iteratorError = err;
+    } finally {
+      try {
+        if (_iteratorAbruptCompletion && _iterator.return != null) {
+          yield babelHelpers.awaitAsyncGenerator(_iterator.return());
+        }
+      } finally {
+        if (_didIteratorError) {
+          throw _iteratorError;
+        }
+      }
+    }
+  });
+  return _f.apply(this, arguments);
+}
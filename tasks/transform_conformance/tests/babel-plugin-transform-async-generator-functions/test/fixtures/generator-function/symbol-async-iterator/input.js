@@ -0,0 +1,14 @@
+class Stream {
+  async *[Symbol.asyncIterator]() {
+    yield await this.next();
+  }
+  *[Symbol.iterator]() {
+    yield this.value;
+  }
+}
+
+const obj = {
+  async *[Symbol.asyncIterator]() {
+    yield await this.next();
+  }
+};
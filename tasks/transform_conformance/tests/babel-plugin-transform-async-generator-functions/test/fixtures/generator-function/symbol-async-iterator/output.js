@@ -0,0 +1,20 @@
+class Stream {
+	[Symbol.asyncIterator]() {
+		var _this = this;
+		return babelHelpers.wrapAsyncGenerator(function* () {
+			yield yield babelHelpers.awaitAsyncGenerator(_this.next());
+		})();
+	}
+	*[Symbol.iterator]() {
+		yield this.value;
+	}
+}
+
+const obj = {
+	[Symbol.asyncIterator]() {
+		var _this2 = this;
+		return babelHelpers.wrapAsyncGenerator(function* () {
+			yield yield babelHelpers.awaitAsyncGenerator(_this2.next());
+		})();
+	}
+};
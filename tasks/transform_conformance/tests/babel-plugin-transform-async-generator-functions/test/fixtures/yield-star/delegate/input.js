@@ -0,0 +1,15 @@
+async function* inner() {
+  yield 1;
+  return 2;
+}
+
+async function* outer() {
+  yield* inner();
+  const result = yield* inner();
+  yield* [3, 4];
+  return result;
+}
+
+const expr = async function* () {
+  yield* outer();
+};
@@ -0,0 +1,25 @@
+function inner() {
+  return _inner.apply(this, arguments);
+}
+function _inner() {
+  _inner = babelHelpers.wrapAsyncGenerator(function* () {
+    yield 1;
+    return 2;
+  });
+  return _inner.apply(this, arguments);
+}
+function outer() {
+  return _outer.apply(this, arguments);
+}
+function _outer() {
+  _outer = babelHelpers.wrapAsyncGenerator(function* () {
+    yield* babelHelpers.asyncGeneratorDelegate(babelHelpers.asyncIterator(inner()));
+    const result = yield* babelHelpers.asyncGeneratorDelegate(babelHelpers.asyncIterator(inner()));
+    yield* babelHelpers.asyncGeneratorDelegate(babelHelpers.asyncIterator([3, 4]));
+    return result;
+  });
+  return _outer.apply(this, arguments);
+}
+const expr = babelHelpers.wrapAsyncGenerator(function* () {
+  yield* babelHelpers.asyncGeneratorDelegate(babelHelpers.asyncIterator(outer()));
+});
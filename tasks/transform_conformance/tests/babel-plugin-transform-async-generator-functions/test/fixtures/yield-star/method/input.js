@@ -0,0 +1,11 @@
+const obj = {
+  async *values(iterable) {
+    yield* iterable;
+  },
+};
+
+class Foo {
+  async *values(iterable) {
+    yield* iterable;
+  }
+}
@@ -0,0 +1,14 @@
+const obj = {
+  values(iterable) {
+    return babelHelpers.wrapAsyncGenerator(function* () {
+      yield* babelHelpers.asyncGeneratorDelegate(babelHelpers.asyncIterator(iterable));
+    })();
+  },
+};
+class Foo {
+  values(iterable) {
+    return babelHelpers.wrapAsyncGenerator(function* () {
+      yield* babelHelpers.asyncGeneratorDelegate(babelHelpers.asyncIterator(iterable));
+    })();
+  }
+}
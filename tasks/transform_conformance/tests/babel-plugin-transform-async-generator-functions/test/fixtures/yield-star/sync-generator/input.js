@@ -0,0 +1,6 @@
+async function* outer() {
+  function* inner() {
+    yield* [1, 2];
+  }
+  yield* inner();
+}
@@ -0,0 +1,12 @@
+function outer() {
+  return _outer.apply(this, arguments);
+}
+function _outer() {
+  _outer = babelHelpers.wrapAsyncGenerator(function* () {
+    function* inner() {
+      yield* [1, 2];
+    }
+    yield* babelHelpers.asyncGeneratorDelegate(babelHelpers.asyncIterator(inner()));
+  });
+  return _outer.apply(this, arguments);
+}
@@ -0,0 +1,5 @@
+function outer() {
+  const f = async () => {
+    await g(this, arguments);
+  };
+}
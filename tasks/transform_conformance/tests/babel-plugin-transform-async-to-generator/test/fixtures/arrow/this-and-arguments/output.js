@@ -0,0 +1,11 @@
+function outer() {
+	var _this = this, _arguments = arguments;
+	const f = function() {
+		var _ref = babelHelpers.asyncToGenerator(function* () {
+			yield g(_this, _arguments);
+		});
+		return function f() {
+			return _ref.apply(this, arguments);
+		};
+	}();
+}
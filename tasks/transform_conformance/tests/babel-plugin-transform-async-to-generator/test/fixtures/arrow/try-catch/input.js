@@ -0,0 +1,7 @@
+const f = async () => {
+  try {
+    await a();
+  } catch (e) {
+    throw new Wrapped(e);
+  }
+};
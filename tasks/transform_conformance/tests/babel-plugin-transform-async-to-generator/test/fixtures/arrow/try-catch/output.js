@@ -0,0 +1,12 @@
+const f = function() {
+  var _ref = babelHelpers.asyncToGenerator(function* () {
+    try {
+      yield a();
+    } catch (e) {
+      throw new Wrapped(e);
+    }
+  });
+  return function f() {
+    return _ref.apply(this, arguments);
+  };
+}();
@@ -0,0 +1,8 @@
+async function f() {
+  try {
+    await Promise.reject(new Error("rejected"));
+    return "unreachable";
+  } catch (e) {
+    return e.message;
+  }
+}
@@ -0,0 +1,14 @@
+function f() {
+  return _f.apply(this, arguments);
+}
+function _f() {
+  _f = babelHelpers.asyncToGenerator(function* () {
+    try {
+      yield Promise.reject(new Error("rejected"));
+      return "unreachable";
+    } catch (e) {
+      return e.message;
+    }
+  });
+  return _f.apply(this, arguments);
+}
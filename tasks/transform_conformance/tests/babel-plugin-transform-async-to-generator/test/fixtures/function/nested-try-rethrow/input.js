@@ -0,0 +1,17 @@
+async function f(items) {
+  for (const item of items) {
+    try {
+      try {
+        await process(item);
+      } catch (e) {
+        if (e.retry) {
+          await process(item);
+        } else {
+          throw e;
+        }
+      }
+    } catch (e) {
+      await report(e);
+    }
+  }
+}
@@ -0,0 +1,23 @@
+function f(_x) {
+  return _f.apply(this, arguments);
+}
+function _f() {
+  _f = babelHelpers.asyncToGenerator(function* (items) {
+    for (const item of items) {
+      try {
+        try {
+          yield process(item);
+        } catch (e) {
+          if (e.retry) {
+            yield process(item);
+          } else {
+            throw e;
+          }
+        }
+      } catch (e) {
+        yield report(e);
+      }
+    }
+  });
+  return _f.apply(this, arguments);
+}
@@ -0,0 +1,9 @@
+async function f() {
+  try {
+    return await a();
+  } catch {
+    return fallback();
+  } finally {
+    await cleanup();
+  }
+}
@@ -0,0 +1,15 @@
+function f() {
+  return _f.apply(this, arguments);
+}
+function _f() {
+  _f = babelHelpers.asyncToGenerator(function* () {
+    try {
+      return yield a();
+    } catch {
+      return fallback();
+    } finally {
+      yield cleanup();
+    }
+  });
+  return _f.apply(this, arguments);
+}
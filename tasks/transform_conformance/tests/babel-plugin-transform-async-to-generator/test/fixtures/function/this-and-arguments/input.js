@@ -0,0 +1,3 @@
+async function f() {
+  await g(this, arguments);
+}
@@ -0,0 +1,9 @@
+function f() {
+	return _f.apply(this, arguments);
+}
+function _f() {
+	_f = babelHelpers.asyncToGenerator(function* () {
+		yield g(this, arguments);
+	});
+	return _f.apply(this, arguments);
+}
@@ -0,0 +1,9 @@
+async function f() {
+  try {
+    await a();
+  } catch (e) {
+    await b(e);
+  } finally {
+    await c();
+  }
+}
@@ -0,0 +1,15 @@
+function f() {
+	return _f.apply(this, arguments);
+}
+function _f() {
+	_f = babelHelpers.asyncToGenerator(function* () {
+		try {
+			yield a();
+		} catch (e) {
+			yield b(e);
+		} finally {
+			yield c();
+		}
+	});
+	return _f.apply(this, arguments);
+}
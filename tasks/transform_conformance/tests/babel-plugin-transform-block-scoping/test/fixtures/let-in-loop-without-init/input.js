@@ -0,0 +1,10 @@
+for (let i = 0; i < 3; i++) {
+  let a;
+  if (i) a = i;
+  console.log(a);
+}
+while (x) {
+  let b, c = 1;
+  x = b || c;
+}
+for (let d in obj);
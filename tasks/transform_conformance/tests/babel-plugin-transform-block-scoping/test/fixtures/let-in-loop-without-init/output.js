@@ -0,0 +1,10 @@
+for (var i = 0; i < 3; i++) {
+  var a = void 0;
+  if (i) a = i;
+  console.log(a);
+}
+while (x) {
+  var b = void 0, c = 1;
+  x = b || c;
+}
+for (var d in obj);
@@ -0,0 +1,7 @@
+let a = 1;
+const b = 2;
+function foo() {
+  let c = a + b;
+  const d = () => c;
+  return d;
+}
@@ -0,0 +1,7 @@
+var a = 1;
+var b = 2;
+function foo() {
+  var c = a + b;
+  var d = () => c;
+  return d;
+}
@@ -0,0 +1,7 @@
+var fns = [];
+for (var i = 0; i < 3; i++) {
+  let j = i * 2;
+  fns.push(function () {
+    return j;
+  });
+}
@@ -0,0 +1,10 @@
+var fns = [];
+var _loop = function() {
+  var j = i * 2;
+  fns.push(function() {
+    return j;
+  });
+};
+for (var i = 0; i < 3; i++) {
+  _loop();
+}
@@ -0,0 +1,7 @@
+for (let i = 0; i < 3; i++) {
+  if (i === 1) continue;
+  for (let j = 0; j < 3; j++) {
+    if (j === 1) continue;
+  }
+  fns.push(() => i);
+}
@@ -0,0 +1,10 @@
+var _loop = function(i) {
+  if (i === 1) return;
+  for (var j = 0; j < 3; j++) {
+    if (j === 1) continue;
+  }
+  fns.push(() => i);
+};
+for (var i = 0; i < 3; i++) {
+  _loop(i);
+}
@@ -0,0 +1,6 @@
+outer: for (let i in obj) {
+  inner: for (let j of arr) {
+    if (j) continue inner;
+    fns.push(() => i + j);
+  }
+}
@@ -0,0 +1,12 @@
+var _loop2 = function(i) {
+  var _loop = function(j) {
+    if (j) return;
+    fns.push(() => i + j);
+  };
+  inner: for (var j of arr) {
+    _loop(j);
+  }
+};
+outer: for (var i in obj) {
+  _loop2(i);
+}
@@ -0,0 +1,3 @@
+for (const x of xs) fns.push(function () {
+  return x;
+});
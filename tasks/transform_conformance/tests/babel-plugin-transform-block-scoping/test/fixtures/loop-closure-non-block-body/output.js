@@ -0,0 +1,8 @@
+var _loop = function(x) {
+  fns.push(function() {
+    return x;
+  });
+};
+for (var x of xs) {
+  _loop(x);
+}
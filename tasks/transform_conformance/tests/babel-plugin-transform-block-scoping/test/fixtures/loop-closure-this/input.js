@@ -0,0 +1,6 @@
+function foo() {
+  while (x) {
+    let y = x--;
+    this.fns.push(() => y);
+  }
+}
@@ -0,0 +1,9 @@
+function foo() {
+  var _loop = function() {
+    var y = x--;
+    this.fns.push(() => y);
+  };
+  while (x) {
+    _loop.call(this);
+  }
+}
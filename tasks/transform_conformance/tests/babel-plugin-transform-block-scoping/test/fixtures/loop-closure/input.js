@@ -0,0 +1,6 @@
+var fns = [];
+for (let i = 0; i < 3; i++) {
+  fns.push(function () {
+    return i;
+  });
+}
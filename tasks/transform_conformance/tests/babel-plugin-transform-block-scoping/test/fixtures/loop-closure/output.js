@@ -0,0 +1,9 @@
+var fns = [];
+var _loop = function(i) {
+  fns.push(function() {
+    return i;
+  });
+};
+for (var i = 0; i < 3; i++) {
+  _loop(i);
+}
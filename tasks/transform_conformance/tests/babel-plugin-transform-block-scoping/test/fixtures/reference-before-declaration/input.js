@@ -0,0 +1,8 @@
+let a = 1;
+{
+  function getA() {
+    return a;
+  }
+  let a = 2;
+  console.log(getA());
+}
@@ -0,0 +1,8 @@
+var a = 1;
+{
+  function getA() {
+    return _a;
+  }
+  var _a = 2;
+  console.log(getA());
+}
@@ -0,0 +1,7 @@
+function foo() {
+  {
+    let window = 1;
+    bar(window);
+  }
+  return window;
+}
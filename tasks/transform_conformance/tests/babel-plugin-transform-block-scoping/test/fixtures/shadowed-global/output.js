@@ -0,0 +1,7 @@
+function foo() {
+  {
+    var _window = 1;
+    bar(_window);
+  }
+  return window;
+}
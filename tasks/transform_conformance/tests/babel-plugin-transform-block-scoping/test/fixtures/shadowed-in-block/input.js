@@ -0,0 +1,13 @@
+let a = 1;
+{
+  let a = 2;
+  const b = a;
+  console.log(a, b);
+}
+function foo(x) {
+  if (x) {
+    let x = 1;
+    bar(x);
+  }
+  return x;
+}
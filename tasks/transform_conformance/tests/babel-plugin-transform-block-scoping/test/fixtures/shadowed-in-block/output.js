@@ -0,0 +1,13 @@
+var a = 1;
+{
+  var _a = 2;
+  var b = _a;
+  console.log(_a, b);
+}
+function foo(x) {
+  if (x) {
+    var _x = 1;
+    bar(_x);
+  }
+  return x;
+}
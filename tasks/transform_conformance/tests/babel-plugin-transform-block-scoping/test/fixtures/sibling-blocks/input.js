@@ -0,0 +1,8 @@
+{
+  let a = 1;
+  console.log(a);
+}
+{
+  let a = 2;
+  console.log(a);
+}
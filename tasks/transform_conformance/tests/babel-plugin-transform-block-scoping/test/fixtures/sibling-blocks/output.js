@@ -0,0 +1,8 @@
+{
+  var a = 1;
+  console.log(a);
+}
+{
+  var _a = 2;
+  console.log(_a);
+}
@@ -0,0 +1,9 @@
+let a = 1;
+switch (x) {
+  case 1:
+    let a = 2;
+    console.log(a);
+    break;
+  default:
+    const b = 3;
+}
@@ -0,0 +1,8 @@
+var a = 1;
+switch (x) {
+  case 1:
+    var _a = 2;
+    console.log(_a);
+    break;
+  default: var b = 3;
+}
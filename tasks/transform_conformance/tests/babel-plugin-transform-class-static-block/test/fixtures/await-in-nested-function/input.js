@@ -0,0 +1 @@
+class C { static { foo(async () => await bar()); } }
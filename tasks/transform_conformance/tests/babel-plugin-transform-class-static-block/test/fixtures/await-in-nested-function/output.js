@@ -0,0 +1,3 @@
+class C {
+  static #_ = foo(async () => await bar());
+}
@@ -0,0 +1,13 @@
+class Foo {
+  static #bar = 21;
+  static #double() {
+    return Foo.#bar * 2;
+  }
+  static {
+    Foo.value = Foo.#double();
+  }
+  static {
+    Foo.hasBar = #bar in Foo;
+    log(Foo.#bar);
+  }
+}
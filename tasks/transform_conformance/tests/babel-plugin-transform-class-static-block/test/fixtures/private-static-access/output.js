@@ -0,0 +1,11 @@
+class Foo {
+  static #bar = 21;
+  static #double() {
+    return Foo.#bar * 2;
+  }
+  static #_ = Foo.value = Foo.#double();
+  static #_2 = (() => {
+    Foo.hasBar = #bar in Foo;
+    log(Foo.#bar);
+  })();
+}
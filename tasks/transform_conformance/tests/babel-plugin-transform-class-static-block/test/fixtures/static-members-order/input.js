@@ -0,0 +1,12 @@
+class Foo {
+  static a = log("a");
+  static {
+    log("block 1");
+  }
+  static b = log("b");
+  static {
+    log("block 2");
+    log(Foo.a, Foo.b);
+  }
+  static c = log("c");
+}
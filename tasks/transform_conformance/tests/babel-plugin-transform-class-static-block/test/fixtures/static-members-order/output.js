@@ -0,0 +1,10 @@
+class Foo {
+  static a = log("a");
+  static #_ = log("block 1");
+  static b = log("b");
+  static #_2 = (() => {
+    log("block 2");
+    log(Foo.a, Foo.b);
+  })();
+  static c = log("c");
+}
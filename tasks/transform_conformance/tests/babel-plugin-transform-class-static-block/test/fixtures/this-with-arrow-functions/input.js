@@ -0,0 +1,11 @@
+class Foo {
+  static a = 1;
+  static {
+    this.b = this.a + 1;
+  }
+  static c = 3;
+  static {
+    const getC = () => this.c;
+    this.d = getC();
+  }
+}
@@ -0,0 +1,11 @@
+class Foo {
+  static a = 1;
+  static #_ = this.b = this.a + 1;
+  static c = 3;
+  static #_2 = function() {
+    const getC = function() {
+      return this.c;
+    }.bind(this);
+    this.d = getC();
+  }.bind(this)();
+}
@@ -0,0 +1,8 @@
+class Stream {
+  async *[Symbol.asyncIterator]() {
+    yield await this.next();
+  }
+  async [Symbol.iterator]() {
+    await this.next();
+  }
+}
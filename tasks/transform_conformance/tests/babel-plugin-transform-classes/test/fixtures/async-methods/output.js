@@ -0,0 +1,22 @@
+let Stream = function() {
+	function Stream() {
+		babelHelpers.classCallCheck(this, Stream);
+	}
+	return babelHelpers.createClass(Stream, [{
+		key: Symbol.asyncIterator,
+		value: function() {
+			var _this = this;
+			return babelHelpers.wrapAsyncGenerator(function* () {
+				yield yield babelHelpers.awaitAsyncGenerator(_this.next());
+			})();
+		}
+	}, {
+		key: Symbol.iterator,
+		value: function() {
+			var _this2 = this;
+			return babelHelpers.asyncToGenerator(function* () {
+				yield _this2.next();
+			})();
+		}
+	}]);
+}();
@@ -0,0 +1,18 @@
+class Point {
+  constructor(x, y) {
+    this.x = x;
+    this.y = y;
+  }
+  toString() {
+    return `(${this.x}, ${this.y})`;
+  }
+  get length() {
+    return Math.hypot(this.x, this.y);
+  }
+  set length(value) {
+    throw new Error("Cannot set length to " + value);
+  }
+  static origin() {
+    return new Point(0, 0);
+  }
+}
@@ -0,0 +1,26 @@
+let Point = function() {
+	function Point(x, y) {
+		babelHelpers.classCallCheck(this, Point);
+		this.x = x;
+		this.y = y;
+	}
+	return babelHelpers.createClass(Point, [{
+		key: 'toString',
+		value: function toString() {
+			return `(${this.x}, ${this.y})`;
+		}
+	}, {
+		key: 'length',
+		get: function() {
+			return Math.hypot(this.x, this.y);
+		},
+		set: function(value) {
+			throw new Error('Cannot set length to ' + value);
+		}
+	}], [{
+		key: 'origin',
+		value: function origin() {
+			return new Point(0, 0);
+		}
+	}]);
+}();
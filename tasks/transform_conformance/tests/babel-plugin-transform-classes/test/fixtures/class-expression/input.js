@@ -0,0 +1,13 @@
+const Anonymous = class {
+  method() {}
+};
+const Named = class Inner {
+  method() {
+    return Inner;
+  }
+};
+const Shadowed = class {
+  method() {
+    return Shadowed;
+  }
+};
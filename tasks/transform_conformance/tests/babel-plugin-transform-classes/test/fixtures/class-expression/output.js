@@ -0,0 +1,31 @@
+const Anonymous = function() {
+	function Anonymous() {
+		babelHelpers.classCallCheck(this, Anonymous);
+	}
+	return babelHelpers.createClass(Anonymous, [{
+		key: 'method',
+		value: function method() {}
+	}]);
+}();
+const Named = function() {
+	function Inner() {
+		babelHelpers.classCallCheck(this, Inner);
+	}
+	return babelHelpers.createClass(Inner, [{
+		key: 'method',
+		value: function method() {
+			return Inner;
+		}
+	}]);
+}();
+const Shadowed = function() {
+	function _Shadowed() {
+		babelHelpers.classCallCheck(this, _Shadowed);
+	}
+	return babelHelpers.createClass(_Shadowed, [{
+		key: 'method',
+		value: function method() {
+			return Shadowed;
+		}
+	}]);
+}();
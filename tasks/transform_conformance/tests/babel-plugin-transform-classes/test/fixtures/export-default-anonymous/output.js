@@ -0,0 +1,9 @@
+export default (function() {
+	function _class() {
+		babelHelpers.classCallCheck(this, _class);
+	}
+	return babelHelpers.createClass(_class, [{
+		key: 'method',
+		value: function method() {}
+	}]);
+})();
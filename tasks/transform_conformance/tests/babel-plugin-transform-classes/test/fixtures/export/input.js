@@ -0,0 +1,4 @@
+export class Named {
+  method() {}
+}
+export default class Default extends Named {}
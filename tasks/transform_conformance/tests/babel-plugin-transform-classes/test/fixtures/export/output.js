@@ -0,0 +1,18 @@
+export let Named = function() {
+	function Named() {
+		babelHelpers.classCallCheck(this, Named);
+	}
+	return babelHelpers.createClass(Named, [{
+		key: 'method',
+		value: function method() {}
+	}]);
+}();
+let Default = function(_Named) {
+	function Default() {
+		babelHelpers.classCallCheck(this, Default);
+		return babelHelpers.callSuper(this, Default, arguments);
+	}
+	babelHelpers.inherits(Default, _Named);
+	return babelHelpers.createClass(Default);
+}(Named);
+export { Default as default };
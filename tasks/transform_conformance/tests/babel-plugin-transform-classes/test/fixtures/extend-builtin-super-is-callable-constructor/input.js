@@ -0,0 +1,6 @@
+class MyError extends Error {
+  constructor(message) {
+    super(message);
+    this.name = "MyError";
+  }
+}
@@ -0,0 +1,11 @@
+let MyError = function(_Error) {
+	function MyError(message) {
+		var _this;
+		babelHelpers.classCallCheck(this, MyError);
+		_this = _Error.call(this, message) || this;
+		_this.name = 'MyError';
+		return _this;
+	}
+	babelHelpers.inherits(MyError, _Error);
+	return babelHelpers.createClass(MyError);
+}(Error);
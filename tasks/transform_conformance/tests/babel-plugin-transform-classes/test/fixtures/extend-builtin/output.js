@@ -0,0 +1,11 @@
+let MyError = function(_Error) {
+	function MyError(message) {
+		var _this;
+		babelHelpers.classCallCheck(this, MyError);
+		_this = babelHelpers.callSuper(this, MyError, [message]);
+		_this.name = 'MyError';
+		return _this;
+	}
+	babelHelpers.inherits(MyError, _Error);
+	return babelHelpers.createClass(MyError);
+}(Error);
@@ -0,0 +1 @@
+class List extends Array {}
@@ -0,0 +1,8 @@
+let List = function(_Array) {
+	function List() {
+		babelHelpers.classCallCheck(this, List);
+		return _Array.apply(this, arguments) || this;
+	}
+	babelHelpers.inherits(List, _Array);
+	return babelHelpers.createClass(List);
+}(Array);
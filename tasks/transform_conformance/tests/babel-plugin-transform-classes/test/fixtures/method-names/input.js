@@ -0,0 +1,9 @@
+class Methods {
+  delete() {}
+  method() {
+    return method;
+  }
+  "string key"() {}
+  [computed]() {}
+  123() {}
+}
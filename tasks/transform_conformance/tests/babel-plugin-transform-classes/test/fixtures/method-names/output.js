@@ -0,0 +1,29 @@
+let Methods = function() {
+	function Methods() {
+		babelHelpers.classCallCheck(this, Methods);
+	}
+	return babelHelpers.createClass(Methods, [
+		{
+			key: 'delete',
+			value: function _delete() {}
+		},
+		{
+			key: 'method',
+			value: function _method() {
+				return method;
+			}
+		},
+		{
+			key: 'string key',
+			value: function() {}
+		},
+		{
+			key: computed,
+			value: function() {}
+		},
+		{
+			key: 123,
+			value: function() {}
+		}
+	]);
+}();
@@ -0,0 +1,2 @@
+class Base {}
+class Derived extends Base {}
@@ -0,0 +1,8 @@
+let Base = babelHelpers.createClass(function Base() {});
+let Derived = function(_Base) {
+	function Derived() {
+		return babelHelpers.callSuper(this, Derived, arguments);
+	}
+	babelHelpers.inherits(Derived, _Base);
+	return babelHelpers.createClass(Derived);
+}(Base);
@@ -0,0 +1,6 @@
+class Empty {}
+class WithConstructor {
+  constructor(x) {
+    this.x = x;
+  }
+}
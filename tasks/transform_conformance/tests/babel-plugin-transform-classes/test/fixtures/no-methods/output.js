@@ -0,0 +1,7 @@
+let Empty = babelHelpers.createClass(function Empty() {
+	babelHelpers.classCallCheck(this, Empty);
+});
+let WithConstructor = babelHelpers.createClass(function WithConstructor(x) {
+	babelHelpers.classCallCheck(this, WithConstructor);
+	this.x = x;
+});
@@ -0,0 +1,9 @@
+class Counter {
+  static create() {
+    return new Counter();
+  }
+  increment() {
+    Counter.count++;
+  }
+}
+Counter.count = 0;
@@ -0,0 +1,17 @@
+let Counter = function() {
+	function Counter() {
+		babelHelpers.classCallCheck(this, Counter);
+	}
+	return babelHelpers.createClass(Counter, [{
+		key: 'increment',
+		value: function increment() {
+			Counter.count++;
+		}
+	}], [{
+		key: 'create',
+		value: function create() {
+			return new Counter();
+		}
+	}]);
+}();
+Counter.count = 0;
@@ -0,0 +1,12 @@
+class Child extends Parent {
+  constructor(...args) {
+    super(...args);
+    super.init(this);
+  }
+  describe() {
+    return super.describe() + super["name"];
+  }
+  static create() {
+    return super.create();
+  }
+}
@@ -0,0 +1,21 @@
+let Child = function(_Parent) {
+	function Child(...args) {
+		var _this;
+		babelHelpers.classCallCheck(this, Child);
+		_this = babelHelpers.callSuper(this, Child, [...args]);
+		babelHelpers.get(babelHelpers.getPrototypeOf(Child.prototype), 'init', _this).call(_this, _this);
+		return _this;
+	}
+	babelHelpers.inherits(Child, _Parent);
+	return babelHelpers.createClass(Child, [{
+		key: 'describe',
+		value: function describe() {
+			return babelHelpers.get(babelHelpers.getPrototypeOf(Child.prototype), 'describe', this).call(this) + babelHelpers.get(babelHelpers.getPrototypeOf(Child.prototype), 'name', this);
+		}
+	}], [{
+		key: 'create',
+		value: function create() {
+			return babelHelpers.get(babelHelpers.getPrototypeOf(Child), 'create', this).call(this);
+		}
+	}]);
+}(Parent);
@@ -0,0 +1,19 @@
+class Child extends Parent {
+  constructor(flag) {
+    if (flag) {
+      super(1);
+    } else {
+      super(2);
+    }
+    this.flag = flag;
+  }
+}
+class Returning extends Parent {
+  constructor(obj) {
+    super();
+    if (obj) {
+      return obj;
+    }
+    const fn = () => this;
+  }
+}
@@ -0,0 +1,29 @@
+let Child = function(_Parent) {
+	function Child(flag) {
+		var _this;
+		babelHelpers.classCallCheck(this, Child);
+		if (flag) {
+			_this = babelHelpers.callSuper(this, Child, [1]);
+		} else {
+			_this = babelHelpers.callSuper(this, Child, [2]);
+		}
+		babelHelpers.assertThisInitialized(_this).flag = flag;
+		return babelHelpers.assertThisInitialized(_this);
+	}
+	babelHelpers.inherits(Child, _Parent);
+	return babelHelpers.createClass(Child);
+}(Parent);
+let Returning = function(_Parent2) {
+	function Returning(obj) {
+		var _this2;
+		babelHelpers.classCallCheck(this, Returning);
+		_this2 = babelHelpers.callSuper(this, Returning);
+		if (obj) {
+			return babelHelpers.possibleConstructorReturn(_this2, obj);
+		}
+		const fn = () => _this2;
+		return _this2;
+	}
+	babelHelpers.inherits(Returning, _Parent2);
+	return babelHelpers.createClass(Returning);
+}(Parent);
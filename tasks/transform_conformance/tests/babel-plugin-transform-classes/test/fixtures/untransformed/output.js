@@ -0,0 +1,8 @@
+class WithProperty {
+	x = 1;
+}
+class WithSuperAssignment extends Base {
+	method() {
+		super.x = 1;
+	}
+}
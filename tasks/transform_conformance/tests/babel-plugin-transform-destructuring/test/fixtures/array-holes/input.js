@@ -0,0 +1 @@
+const [x, , y] = arr;
@@ -0,0 +1 @@
+const _arr = babelHelpers.slicedToArray(arr, 3), x = _arr[0], y = _arr[2];
@@ -0,0 +1 @@
+var [head, ...tail] = list;
@@ -0,0 +1 @@
+var _list = babelHelpers.toArray(list), head = _list[0], tail = _list.slice(1);
@@ -0,0 +1 @@
+const g = ({ a }, [b]) => a + b;
@@ -0,0 +1,5 @@
+const g = (_ref, _ref2) => {
+	var a = _ref.a;
+	var b = babelHelpers.slicedToArray(_ref2, 1)[0];
+	return a + b;
+};
@@ -0,0 +1 @@
+let r = ({ p, q } = foo());
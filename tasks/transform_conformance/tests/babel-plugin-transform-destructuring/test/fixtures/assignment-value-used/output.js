@@ -0,0 +1,2 @@
+var _foo;
+let r = (_foo = foo(), p = _foo.p, q = _foo.q, _foo);
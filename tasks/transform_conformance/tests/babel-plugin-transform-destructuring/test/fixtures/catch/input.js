@@ -0,0 +1,3 @@
+try {} catch ({ message, fn = () => message }) {
+  console.log(message, fn);
+}
@@ -0,0 +1,4 @@
+try {} catch (_ref) {
+	let message = _ref.message, _ref$fn = _ref.fn, fn = _ref$fn === void 0 ? () => message : _ref$fn;
+	console.log(message, fn);
+}
@@ -0,0 +1 @@
+const { [key()]: val, ...others } = obj;
@@ -0,0 +1 @@
+const _obj = obj, _key = babelHelpers.toPropertyKey(key()), val = _obj[_key], others = babelHelpers.objectWithoutProperties(_obj, [_key]);
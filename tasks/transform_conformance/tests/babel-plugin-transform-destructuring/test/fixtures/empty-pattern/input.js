@@ -0,0 +1,3 @@
+const {} = obj;
+({} = obj);
+const [] = arr;
@@ -0,0 +1,3 @@
+const _obj = babelHelpers.objectDestructuringEmpty(obj);
+babelHelpers.objectDestructuringEmpty(obj);
+const _arr = babelHelpers.slicedToArray(arr, 0);
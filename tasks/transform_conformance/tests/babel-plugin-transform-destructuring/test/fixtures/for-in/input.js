@@ -0,0 +1,6 @@
+for (const { a, b: [c] } in obj) {
+  console.log(a, c);
+}
+for (obj.key in source) console.log(obj.key);
+for ({ length: obj.len } in source) console.log(obj.len);
+for ([m, n] in source) console.log(m, n);
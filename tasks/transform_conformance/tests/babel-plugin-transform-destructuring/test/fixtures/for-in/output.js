@@ -0,0 +1,14 @@
+for (const _ref in obj) {
+	const a = _ref.a, c = babelHelpers.slicedToArray(_ref.b, 1)[0];
+	console.log(a, c);
+}
+for (obj.key in source) console.log(obj.key);
+for (var _ref2 in source) {
+	obj.len = _ref2.length;
+	console.log(obj.len);
+}
+for (var _ref3 in source) {
+	var _ref4;
+	_ref4 = babelHelpers.slicedToArray(_ref3, 2), m = _ref4[0], n = _ref4[1];
+	console.log(m, n);
+}
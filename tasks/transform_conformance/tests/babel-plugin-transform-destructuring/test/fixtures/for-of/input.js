@@ -0,0 +1,4 @@
+for (const [k, v] of entries) {
+  console.log(k, v);
+}
+for ([m, n] of pairs) console.log(m, n);
@@ -0,0 +1,9 @@
+for (const _ref of entries) {
+	const _ref2 = babelHelpers.slicedToArray(_ref, 2), k = _ref2[0], v = _ref2[1];
+	console.log(k, v);
+}
+for (var _ref3 of pairs) {
+	var _ref4;
+	_ref4 = babelHelpers.slicedToArray(_ref3, 2), m = _ref4[0], n = _ref4[1];
+	console.log(m, n);
+}
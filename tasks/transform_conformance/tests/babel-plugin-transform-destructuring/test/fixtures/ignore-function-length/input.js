@@ -0,0 +1 @@
+function f({ a }, b = a, c) {}
@@ -0,0 +1,4 @@
+function f(_ref, b, c) {
+	var a = _ref.a;
+	if (b === void 0) b = a;
+}
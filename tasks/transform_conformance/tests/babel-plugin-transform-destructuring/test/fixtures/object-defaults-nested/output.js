@@ -0,0 +1 @@
+const _obj = obj, _obj$a = _obj.a, a = _obj$a === void 0 ? 1 : _obj$a, c = _obj.b.c;
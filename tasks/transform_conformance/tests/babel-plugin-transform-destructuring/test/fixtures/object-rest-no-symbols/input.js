@@ -0,0 +1 @@
+const { a, ...rest } = obj;
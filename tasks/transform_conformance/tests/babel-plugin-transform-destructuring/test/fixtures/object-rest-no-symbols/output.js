@@ -0,0 +1 @@
+const _obj = obj, a = _obj.a, rest = babelHelpers.objectWithoutPropertiesLoose(_obj, ["a"]);
@@ -0,0 +1 @@
+const _obj = obj, a = _obj.a, b = _obj["b"], rest = babelHelpers.objectWithoutProperties(_obj, ["a", "b"]);
@@ -0,0 +1 @@
+function f({ a = 1, b: { c } }) {}
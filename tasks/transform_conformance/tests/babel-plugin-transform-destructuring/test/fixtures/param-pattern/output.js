@@ -0,0 +1,3 @@
+function f(_ref) {
+	var _ref$a = _ref.a, a = _ref$a === void 0 ? 1 : _ref$a, c = _ref.b.c;
+}
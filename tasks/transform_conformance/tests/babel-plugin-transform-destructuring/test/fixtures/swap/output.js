@@ -0,0 +1,2 @@
+var _ref;
+_ref = [b, a], a = _ref[0], b = _ref[1];
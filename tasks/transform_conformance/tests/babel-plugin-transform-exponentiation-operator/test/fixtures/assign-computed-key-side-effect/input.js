@@ -0,0 +1,4 @@
+let obj;
+obj[key()] **= b;
+getObj()[key()] **= b;
+x = obj[key()] **= b;
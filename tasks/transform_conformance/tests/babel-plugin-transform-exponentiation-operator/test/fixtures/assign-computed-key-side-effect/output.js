@@ -0,0 +1,5 @@
+var _key, _getObj, _key2, _key3;
+let obj;
+_key = key(), obj[_key] = Math.pow(obj[_key], b);
+_getObj = getObj(), _key2 = key(), _getObj[_key2] = Math.pow(_getObj[_key2], b);
+x = (_key3 = key(), obj[_key3] = Math.pow(obj[_key3], b));
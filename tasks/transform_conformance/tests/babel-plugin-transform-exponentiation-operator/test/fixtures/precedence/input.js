@@ -0,0 +1,7 @@
+a ** b ** c;
+(a ** b) ** c;
+(-a) ** b;
+a * b ** c;
+(a + b) ** (c - d);
+(a ** b).toFixed();
+x = a ** b + c;
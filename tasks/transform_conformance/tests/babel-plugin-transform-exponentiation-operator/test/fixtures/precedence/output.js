@@ -0,0 +1,7 @@
+Math.pow(a, Math.pow(b, c));
+Math.pow(Math.pow(a, b), c);
+Math.pow(-a, b);
+a * Math.pow(b, c);
+Math.pow(a + b, c - d);
+Math.pow(a, b).toFixed();
+x = Math.pow(a, b) + c;
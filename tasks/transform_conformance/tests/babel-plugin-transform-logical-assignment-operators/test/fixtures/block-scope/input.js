@@ -0,0 +1,7 @@
+for (const o of list) {
+  o.a.b ||= 1;
+}
+{
+  let o = {};
+  o.a[key] ??= 2;
+}
@@ -0,0 +1,9 @@
+for (const o of list) {
+  var _o$a;
+  (_o$a = o.a).b || (_o$a.b = 1);
+}
+{
+  var _o$a2, _key, _o$a2$_key;
+  let o = {};
+  (_o$a2$_key = (_o$a2 = o.a)[_key = key]) !== null && _o$a2$_key !== void 0 ? _o$a2$_key : _o$a2[_key] = 2;
+}
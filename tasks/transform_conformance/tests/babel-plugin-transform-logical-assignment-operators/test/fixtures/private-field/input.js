@@ -0,0 +1,8 @@
+class Foo {
+  #x;
+  method() {
+    this.#x ||= 1;
+    this.#x &&= 2;
+    getFoo().#x ??= 3;
+  }
+}
@@ -0,0 +1,9 @@
+class Foo {
+  #x;
+  method() {
+    var _getFoo, _getFoo$x;
+    this.#x || (this.#x = 1);
+    this.#x && (this.#x = 2);
+    (_getFoo$x = (_getFoo = getFoo()).#x) !== null && _getFoo$x !== void 0 ? _getFoo$x : _getFoo.#x = 3;
+  }
+}
@@ -0,0 +1,3 @@
+obj[side()].x ??= y;
+obj[side()][key()] ||= y;
+obj.a[key()] &&= y;
@@ -0,0 +1,4 @@
+var _obj$side, _obj$side$x, _obj$side2, _key, _obj$a, _key2;
+(_obj$side$x = (_obj$side = obj[side()]).x) !== null && _obj$side$x !== void 0 ? _obj$side$x : _obj$side.x = y;
+(_obj$side2 = obj[side()])[_key = key()] || (_obj$side2[_key] = y);
+(_obj$a = obj.a)[_key2 = key()] && (_obj$a[_key2] = y);
@@ -0,0 +1,2 @@
+x = foo ?? "default";
+x = foo.bar ?? (() => {});
@@ -0,0 +1,3 @@
+var _foo, _foo$bar;
+x = (_foo = foo) != null ? _foo : "default";
+x = (_foo$bar = foo.bar) != null ? _foo$bar : () => {};
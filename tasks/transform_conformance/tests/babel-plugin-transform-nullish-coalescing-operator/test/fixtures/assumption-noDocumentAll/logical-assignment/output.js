@@ -0,0 +1,2 @@
+var _a, _a$b;
+(_a$b = (_a = a).b) != null ? _a$b : _a.b = c;
@@ -0,0 +1,3 @@
+var _a, _a$b, _a2, _k, _a2$_k;
+(_a$b = (_a = a).b) !== null && _a$b !== void 0 ? _a$b : _a.b = c;
+(_a2$_k = (_a2 = a)[_k = k()]) !== null && _a2$_k !== void 0 ? _a2$_k : _a2[_k] = c;
@@ -0,0 +1,2 @@
+var _a$b, _a;
+x = (_a$b = (_a = a) === null || _a === void 0 ? void 0 : _a.b) !== null && _a$b !== void 0 ? _a$b : c;
@@ -0,0 +1,4 @@
+var _ref, _a, _a2;
+x = (_ref = a || b) !== null && _ref !== void 0 ? _ref : c;
+x = (_a = a) !== null && _a !== void 0 ? _a : b && c;
+x = ((_a2 = a) !== null && _a2 !== void 0 ? _a2 : b) || c;
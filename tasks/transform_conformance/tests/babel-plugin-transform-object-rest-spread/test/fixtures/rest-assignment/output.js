@@ -0,0 +1,3 @@
+var _c, _z;
+_c = c, a = _c.a, b = babelHelpers.objectWithoutProperties(_c, ["a"]);
+result = (_z = z, x = _z.x, y = babelHelpers.objectWithoutProperties(_z, ["x"]), _z);
@@ -0,0 +1,3 @@
+try {} catch ({ message, ...info }) {
+  console.log(message, info);
+}
@@ -0,0 +1,2 @@
+const { a, ...rest } = obj;
+const { b } = plain;
@@ -0,0 +1,2 @@
+const _obj = obj, a = _obj.a, rest = babelHelpers.objectWithoutProperties(_obj, ["a"]);
+const { b } = plain;
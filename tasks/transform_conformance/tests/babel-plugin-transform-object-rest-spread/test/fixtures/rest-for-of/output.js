@@ -0,0 +1,8 @@
+for (const _ref of list) {
+	const k = _ref.k, v = babelHelpers.objectWithoutProperties(_ref, ["k"]);
+	console.log(k, v);
+}
+for (var _ref2 of pairs) {
+	m = _ref2.m, n = babelHelpers.objectWithoutProperties(_ref2, ["m"]);
+	console.log(m, n);
+}
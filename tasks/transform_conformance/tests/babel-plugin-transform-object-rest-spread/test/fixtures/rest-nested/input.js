@@ -0,0 +1,2 @@
+const [x, { y, ...others }] = arr;
+const { c: { d, ...e } } = obj;
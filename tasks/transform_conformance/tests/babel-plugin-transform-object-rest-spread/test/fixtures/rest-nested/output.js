@@ -0,0 +1,2 @@
+const _arr = babelHelpers.slicedToArray(arr, 2), x = _arr[0], _arr$ = _arr[1], y = _arr$.y, others = babelHelpers.objectWithoutProperties(_arr$, ["y"]);
+const _obj$c = obj.c, d = _obj$c.d, e = babelHelpers.objectWithoutProperties(_obj$c, ["d"]);
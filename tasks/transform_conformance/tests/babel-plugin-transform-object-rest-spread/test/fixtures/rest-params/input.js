@@ -0,0 +1,2 @@
+function f({ a, ...b }, c, [d]) {}
+const g = ({ ...all }) => all;
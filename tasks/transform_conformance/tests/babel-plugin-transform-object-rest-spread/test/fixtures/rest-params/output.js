@@ -0,0 +1,8 @@
+function f(_ref, c, _ref2) {
+	var a = _ref.a, b = babelHelpers.objectWithoutProperties(_ref, ["a"]);
+	var [d] = _ref2;
+}
+const g = (_ref3) => {
+	var all = babelHelpers.objectWithoutProperties(_ref3, []);
+	return all;
+};
@@ -0,0 +1 @@
+const obj = { ...a, x: 1 };
@@ -0,0 +1 @@
+const obj = Object.assign(Object.assign({}, a), { x: 1 });
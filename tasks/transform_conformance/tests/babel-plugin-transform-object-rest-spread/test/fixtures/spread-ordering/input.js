@@ -0,0 +1,2 @@
+const obj = { ...a, x: 1, ...b, y: 2 };
+const order = { ...{ get x() { log("x"); return 1; } }, y: log("y"), ...{ get z() { log("z"); return 3; } } };
@@ -0,0 +1,8 @@
+const obj = babelHelpers.objectSpread2(babelHelpers.objectSpread2(babelHelpers.objectSpread2(babelHelpers.objectSpread2({}, a), { x: 1 }), b), { y: 2 });
+const order = babelHelpers.objectSpread2(babelHelpers.objectSpread2(babelHelpers.objectSpread2({}, { get x() {
+	log("x");
+	return 1;
+} }), { y: log("y") }), { get z() {
+	log("z");
+	return 3;
+} });
@@ -0,0 +1,8 @@
+const obj = { a: { b: [{ c: () => ({ d: 1 }) }] } };
+
+obj?.a.b[0]?.c().d;
+obj.a?.b?.[0].c?.().d;
+foo?.bar.baz?.[qux()]?.(1, 2)?.quux;
+foo?.[bar?.baz]?.(qux?.quux);
+a.b?.();
+delete a?.b;
@@ -0,0 +1,8 @@
+var _obj$a$b$, _obj$a, _obj$a$b, _obj$a$b$2, _obj$a$b$2$c, _foo, _foo$bar$baz, _foo$bar$baz$qux, _foo$bar$baz$qux$call, _foo2, _foo2$bar$baz, _bar, _qux, _a, _a$b, _a2;
+const obj = { a: { b: [{ c: () => ({ d: 1 }) }] } };
+obj == null || (_obj$a$b$ = obj.a.b[0]) == null ? void 0 : _obj$a$b$.c().d;
+(_obj$a = obj.a) == null || (_obj$a$b = _obj$a.b) == null || (_obj$a$b$2$c = (_obj$a$b$2 = _obj$a$b[0]).c) == null ? void 0 : _obj$a$b$2$c.call(_obj$a$b$2).d;
+(_foo = foo) == null || (_foo$bar$baz = _foo.bar.baz) == null || (_foo$bar$baz$qux = _foo$bar$baz[qux()]) == null || (_foo$bar$baz$qux$call = _foo$bar$baz$qux.call(_foo$bar$baz, 1, 2)) == null ? void 0 : _foo$bar$baz$qux$call.quux;
+(_foo2 = foo) == null || (_foo2$bar$baz = _foo2[(_bar = bar) == null ? void 0 : _bar.baz]) == null ? void 0 : _foo2$bar$baz.call(_foo2, (_qux = qux) == null ? void 0 : _qux.quux);
+(_a$b = (_a = a).b) == null ? void 0 : _a$b.call(_a);
+(_a2 = a) == null ? true : delete _a2.b;
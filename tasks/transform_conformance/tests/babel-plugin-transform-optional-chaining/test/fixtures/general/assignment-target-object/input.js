@@ -0,0 +1,6 @@
+(a?.b).c = 1;
+(a?.b)[key()] += 1;
+(a?.b.c).d++;
+({ x: (a?.b).c } = obj);
+[(a?.b).c] = arr;
+for ((a?.b).c of arr);
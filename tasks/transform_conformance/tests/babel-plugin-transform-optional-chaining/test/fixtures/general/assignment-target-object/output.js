@@ -0,0 +1,7 @@
+var _a, _a2, _a3, _a4, _a5, _a6;
+((_a = a) === null || _a === void 0 ? void 0 : _a.b).c = 1;
+((_a2 = a) === null || _a2 === void 0 ? void 0 : _a2.b)[key()] += 1;
+((_a3 = a) === null || _a3 === void 0 ? void 0 : _a3.b.c).d++;
+({x: ((_a4 = a) === null || _a4 === void 0 ? void 0 : _a4.b).c} = obj);
+[((_a5 = a) === null || _a5 === void 0 ? void 0 : _a5.b).c] = arr;
+for (((_a6 = a) === null || _a6 === void 0 ? void 0 : _a6.b).c of arr);
@@ -0,0 +1,13 @@
+a.b?.();
+a?.b();
+a?.b?.();
+a.b.c?.(1);
+this.a?.();
+(a?.b)();
+(a?.b.c)(1);
+
+class Foo extends Bar {
+  method() {
+    return super.method?.();
+  }
+}
@@ -0,0 +1,14 @@
+var _a, _a$b, _a2, _a3, _a3$b, _a$b2, _a$b2$c, _this$a, _a4, _a5, _a5$b;
+(_a$b = (_a = a).b) === null || _a$b === void 0 ? void 0 : _a$b.call(_a);
+(_a2 = a) === null || _a2 === void 0 ? void 0 : _a2.b();
+(_a3 = a) === null || _a3 === void 0 || (_a3$b = _a3.b) === null || _a3$b === void 0 ? void 0 : _a3$b.call(_a3);
+(_a$b2$c = (_a$b2 = a.b).c) === null || _a$b2$c === void 0 ? void 0 : _a$b2$c.call(_a$b2, 1);
+(_this$a = this.a) === null || _this$a === void 0 ? void 0 : _this$a.call(this);
+((_a4 = a) === null || _a4 === void 0 ? void 0 : _a4.b.bind(_a4))();
+((_a5 = a) === null || _a5 === void 0 ? void 0 : (_a5$b = _a5.b).c.bind(_a5$b))(1);
+class Foo extends Bar {
+  method() {
+    var _super$method;
+    return (_super$method = super.method) === null || _super$method === void 0 ? void 0 : _super$method.call(this);
+  }
+}
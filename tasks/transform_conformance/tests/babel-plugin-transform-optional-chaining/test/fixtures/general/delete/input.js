@@ -0,0 +1,4 @@
+delete a?.b;
+delete a?.b.c;
+delete (a?.b);
+delete a.b?.[c()];
@@ -0,0 +1,5 @@
+var _a, _a2, _a3, _a$b;
+(_a = a) === null || _a === void 0 ? true : delete _a.b;
+(_a2 = a) === null || _a2 === void 0 ? true : delete _a2.b.c;
+(_a3 = a) === null || _a3 === void 0 ? true : delete _a3.b;
+(_a$b = a.b) === null || _a$b === void 0 ? true : delete _a$b[c()];
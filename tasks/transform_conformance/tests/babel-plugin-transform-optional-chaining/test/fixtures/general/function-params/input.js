@@ -0,0 +1,2 @@
+function f(a, b = a?.b, c = d?.e, ...[f = a?.b?.c]) {}
+const g = (a = b?.c) => a?.d;
@@ -0,0 +1,2 @@
+function f(a, b = a === null || a === void 0 ? void 0 : a.b, c = ((_d) => (_d = d) === null || _d === void 0 ? void 0 : _d.e)(), ...[f = ((_a$b) => a === null || a === void 0 || (_a$b = a.b) === null || _a$b === void 0 ? void 0 : _a$b.c)()]) {}
+const g = (a = ((_b) => (_b = b) === null || _b === void 0 ? void 0 : _b.c)()) => a === null || a === void 0 ? void 0 : a.d;
@@ -0,0 +1,6 @@
+var _obj$a$b$, _obj$a, _obj$a$b, _obj$a$b$2, _obj$a$b$2$c, _foo, _foo$bar$baz, _foo$bar$baz$qux, _foo$bar$baz$qux$call, _foo2, _foo2$bar$baz, _bar, _qux;
+const obj = { a: { b: [{ c: () => ({ d: 1 }) }] } };
+obj === null || obj === void 0 || (_obj$a$b$ = obj.a.b[0]) === null || _obj$a$b$ === void 0 ? void 0 : _obj$a$b$.c().d;
+(_obj$a = obj.a) === null || _obj$a === void 0 || (_obj$a$b = _obj$a.b) === null || _obj$a$b === void 0 || (_obj$a$b$2$c = (_obj$a$b$2 = _obj$a$b[0]).c) === null || _obj$a$b$2$c === void 0 ? void 0 : _obj$a$b$2$c.call(_obj$a$b$2).d;
+(_foo = foo) === null || _foo === void 0 || (_foo$bar$baz = _foo.bar.baz) === null || _foo$bar$baz === void 0 || (_foo$bar$baz$qux = _foo$bar$baz[qux()]) === null || _foo$bar$baz$qux === void 0 || (_foo$bar$baz$qux$call = _foo$bar$baz$qux.call(_foo$bar$baz, 1, 2)) === null || _foo$bar$baz$qux$call === void 0 ? void 0 : _foo$bar$baz$qux$call.quux;
+(_foo2 = foo) === null || _foo2 === void 0 || (_foo2$bar$baz = _foo2[(_bar = bar) === null || _bar === void 0 ? void 0 : _bar.baz]) === null || _foo2$bar$baz === void 0 ? void 0 : _foo2$bar$baz.call(_foo2, (_qux = qux) === null || _qux === void 0 ? void 0 : _qux.quux);
@@ -0,0 +1,16 @@
+class Cl {
+  get #fn() {
+    return function() {};
+  }
+
+  set #fn(value) {}
+
+  test(obj) {
+    this.#fn(1, 2);
+    getObj().#fn();
+    this.#fn`tagged`;
+    [this.#fn, { a: obj.#fn }] = [1, { a: 2 }];
+    for (this.#fn of []);
+    return #fn in obj;
+  }
+}
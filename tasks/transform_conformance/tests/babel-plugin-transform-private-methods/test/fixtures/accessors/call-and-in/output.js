@@ -0,0 +1,20 @@
+var _fn = new WeakMap();
+class Cl {
+	constructor() {
+		babelHelpers.classPrivateFieldInitSpec(this, _fn, {
+			get: function() {
+				return function() {};
+			},
+			set: function(value) {}
+		});
+	}
+	test(obj) {
+		var _getObj;
+		babelHelpers.classPrivateFieldGet(this, _fn).call(this, 1, 2);
+		babelHelpers.classPrivateFieldGet(_getObj = getObj(), _fn).call(_getObj);
+		babelHelpers.classPrivateFieldGet(this, _fn).bind(this)`tagged`;
+		[babelHelpers.classPrivateFieldDestructureSet(this, _fn).value, {a: babelHelpers.classPrivateFieldDestructureSet(obj, _fn).value}] = [1, { a: 2 }];
+		for (babelHelpers.classPrivateFieldDestructureSet(this, _fn).value of []);
+		return _fn.has(babelHelpers.checkInRHS(obj));
+	}
+}
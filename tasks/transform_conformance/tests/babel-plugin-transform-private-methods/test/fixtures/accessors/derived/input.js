@@ -0,0 +1,20 @@
+class A extends Base {
+  get #x() {
+    return 1;
+  }
+
+  constructor(a, b) {
+    super(a);
+    this.y = this.#x + b;
+  }
+}
+
+class B extends Base {
+  get #x() {
+    return 2;
+  }
+
+  getX() {
+    return this.#x;
+  }
+}
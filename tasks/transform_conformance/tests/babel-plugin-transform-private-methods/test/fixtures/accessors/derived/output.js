@@ -0,0 +1,21 @@
+var _x = new WeakMap(), _x2 = new WeakMap();
+class A extends Base {
+	constructor(a, b) {
+		super(a);
+		babelHelpers.classPrivateFieldInitSpec(this, _x, { get: function() {
+			return 1;
+		} });
+		this.y = babelHelpers.classPrivateFieldGet(this, _x) + b;
+	}
+}
+class B extends Base {
+	constructor(..._args) {
+		super(..._args);
+		babelHelpers.classPrivateFieldInitSpec(this, _x2, { get: function() {
+			return 2;
+		} });
+	}
+	getX() {
+		return babelHelpers.classPrivateFieldGet(this, _x2);
+	}
+}
@@ -0,0 +1,15 @@
+class Cl {
+  get #value() {
+    return 42;
+  }
+
+  read() {
+    return this.#value;
+  }
+
+  write() {
+    // Throws, as `#value` has no setter
+    this.#value = 1;
+    this.#value += 1;
+  }
+}
@@ -0,0 +1,15 @@
+var _value = new WeakMap();
+class Cl {
+	constructor() {
+		babelHelpers.classPrivateFieldInitSpec(this, _value, { get: function() {
+			return 42;
+		} });
+	}
+	read() {
+		return babelHelpers.classPrivateFieldGet(this, _value);
+	}
+	write() {
+		babelHelpers.classPrivateFieldSet(this, _value, 1);
+		babelHelpers.classPrivateFieldSet(this, _value, babelHelpers.classPrivateFieldGet(this, _value) + 1);
+	}
+}
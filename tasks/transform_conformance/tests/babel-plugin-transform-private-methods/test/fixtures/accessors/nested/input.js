@@ -0,0 +1,21 @@
+class Outer {
+  get #x() {
+    return 1;
+  }
+
+  test() {
+    class Inner {
+      #x = 2;
+
+      test(obj) {
+        return obj.#x;
+      }
+    }
+
+    return class {
+      test(obj) {
+        return obj.#x;
+      }
+    };
+  }
+}
@@ -0,0 +1,21 @@
+var _x = new WeakMap();
+class Outer {
+	constructor() {
+		babelHelpers.classPrivateFieldInitSpec(this, _x, { get: function() {
+			return 1;
+		} });
+	}
+	test() {
+		class Inner {
+			#x = 2;
+			test(obj) {
+				return obj.#x;
+			}
+		}
+		return class {
+			test(obj) {
+				return babelHelpers.classPrivateFieldGet(obj, _x);
+			}
+		};
+	}
+}
@@ -0,0 +1,13 @@
+class Cl {
+  get #x() {
+    return 1;
+  }
+
+  set #x(value) {}
+
+  static copy(a, b) {
+    b.#x = a.#x;
+    a.b.#x += 1;
+    return a === b || a.#x === b.#x;
+  }
+}
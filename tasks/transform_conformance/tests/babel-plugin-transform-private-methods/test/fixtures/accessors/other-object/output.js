@@ -0,0 +1,17 @@
+var _x = new WeakMap();
+class Cl {
+	constructor() {
+		babelHelpers.classPrivateFieldInitSpec(this, _x, {
+			get: function() {
+				return 1;
+			},
+			set: function(value) {}
+		});
+	}
+	static copy(a, b) {
+		var _a$b;
+		babelHelpers.classPrivateFieldSet(b, _x, babelHelpers.classPrivateFieldGet(a, _x));
+		babelHelpers.classPrivateFieldSet(_a$b = a.b, _x, babelHelpers.classPrivateFieldGet(_a$b, _x) + 1);
+		return a === b || babelHelpers.classPrivateFieldGet(a, _x) === babelHelpers.classPrivateFieldGet(b, _x);
+	}
+}
@@ -0,0 +1,26 @@
+class Counter {
+  _count;
+
+  get #count() {
+    return this._count;
+  }
+
+  set #count(value) {
+    this._count = value;
+  }
+
+  get count() {
+    return this.#count;
+  }
+
+  test(other) {
+    this.#count = 1;
+    this.#count += 2;
+    other.#count **= 2;
+    this.#count ??= 3;
+    this.#count++;
+    --this.#count;
+    const old = this.#count--;
+    return this.#count;
+  }
+}
@@ -0,0 +1,28 @@
+var _count = new WeakMap();
+class Counter {
+	constructor() {
+		babelHelpers.classPrivateFieldInitSpec(this, _count, {
+			get: function() {
+				return this._count;
+			},
+			set: function(value) {
+				this._count = value;
+			}
+		});
+	}
+	_count;
+	get count() {
+		return babelHelpers.classPrivateFieldGet(this, _count);
+	}
+	test(other) {
+		var _this$count, _this$count2, _this$count3, _this$count4;
+		babelHelpers.classPrivateFieldSet(this, _count, 1);
+		babelHelpers.classPrivateFieldSet(this, _count, babelHelpers.classPrivateFieldGet(this, _count) + 2);
+		babelHelpers.classPrivateFieldSet(other, _count, babelHelpers.classPrivateFieldGet(other, _count) ** 2);
+		babelHelpers.classPrivateFieldGet(this, _count) ?? babelHelpers.classPrivateFieldSet(this, _count, 3);
+		babelHelpers.classPrivateFieldSet(this, _count, (_this$count = babelHelpers.classPrivateFieldGet(this, _count), ++_this$count));
+		babelHelpers.classPrivateFieldSet(this, _count, (_this$count2 = babelHelpers.classPrivateFieldGet(this, _count), --_this$count2));
+		const old = (babelHelpers.classPrivateFieldSet(this, _count, (_this$count3 = babelHelpers.classPrivateFieldGet(this, _count), _this$count4 = _this$count3--, _this$count3)), _this$count4);
+		return babelHelpers.classPrivateFieldGet(this, _count);
+	}
+}
@@ -0,0 +1,14 @@
+class Cl {
+  set #value(v) {
+    this._value = v;
+  }
+
+  write(v) {
+    this.#value = v;
+  }
+
+  read() {
+    // Throws, as `#value` has no getter
+    return this.#value;
+  }
+}
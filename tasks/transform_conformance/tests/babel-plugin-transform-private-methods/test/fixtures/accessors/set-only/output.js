@@ -0,0 +1,14 @@
+var _value = new WeakMap();
+class Cl {
+	constructor() {
+		babelHelpers.classPrivateFieldInitSpec(this, _value, { set: function(v) {
+			this._value = v;
+		} });
+	}
+	write(v) {
+		babelHelpers.classPrivateFieldSet(this, _value, v);
+	}
+	read() {
+		return this, babelHelpers.writeOnlyError("#value");
+	}
+}
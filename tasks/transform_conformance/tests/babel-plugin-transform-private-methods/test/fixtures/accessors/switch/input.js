@@ -0,0 +1,21 @@
+class Cl {
+  get #x() {
+    return 1;
+  }
+
+  test() {
+    switch (this.#x) {
+      case this.#x:
+        return this.#x;
+    }
+  }
+
+  static is(obj) {
+    switch (true) {
+      case #x in obj:
+        return true;
+      default:
+        return false;
+    }
+  }
+}
@@ -0,0 +1,24 @@
+var _x = new WeakMap();
+class Cl {
+  constructor() {
+    babelHelpers.classPrivateFieldInitSpec(this, _x, {
+      get: function () {
+        return 1;
+      }
+    });
+  }
+  test() {
+    switch (babelHelpers.classPrivateFieldGet(this, _x)) {
+      case babelHelpers.classPrivateFieldGet(this, _x):
+        return babelHelpers.classPrivateFieldGet(this, _x);
+    }
+  }
+  static is(obj) {
+    switch (true) {
+      case _x.has(babelHelpers.checkInRHS(obj)):
+        return true;
+      default:
+        return false;
+    }
+  }
+}
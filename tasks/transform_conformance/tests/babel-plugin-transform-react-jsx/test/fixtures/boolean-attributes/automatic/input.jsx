@@ -0,0 +1,3 @@
+<input disabled />;
+<svg xlink:flag />;
+<input disabled value="a" data-id aria-hidden checked={false} aria-label="b" />;
@@ -0,0 +1,11 @@
+import { jsx as _jsx } from "react/jsx-runtime";
+_jsx("input", { disabled: true });
+_jsx("svg", { "xlink:flag": true });
+_jsx("input", {
+  disabled: true,
+  value: "a",
+  "data-id": true,
+  "aria-hidden": true,
+  checked: false,
+  "aria-label": "b"
+});
@@ -0,0 +1,10 @@
+React.createElement("input", { disabled: true });
+React.createElement("svg", { "xlink:flag": true });
+React.createElement("input", {
+  disabled: true,
+  value: "a",
+  "data-id": true,
+  "aria-hidden": true,
+  checked: false,
+  "aria-label": "b"
+});
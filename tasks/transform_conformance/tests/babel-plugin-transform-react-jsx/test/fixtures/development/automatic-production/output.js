@@ -0,0 +1,4 @@
+import { jsx as _jsx, Fragment as _Fragment } from "react/jsx-runtime";
+function f() {
+  return _jsx(_Fragment, { children: _jsx("a", {}) });
+}
@@ -0,0 +1,9 @@
+var _jsxFileName = "<CWD>/tests/babel-plugin-transform-react-jsx/test/fixtures/development/automatic/input.jsx";
+import { jsxDEV as _jsxDEV, Fragment as _Fragment } from "react/jsx-dev-runtime";
+function f() {
+  return _jsxDEV(_Fragment, { children: _jsxDEV("a", {}, void 0, false, {
+    fileName: _jsxFileName,
+    lineNumber: 2,
+    columnNumber: 12
+  }, this) }, void 0, false);
+}
@@ -0,0 +1,4 @@
+function f() {
+  return React.createElement("div", null, "😀", React.createElement("a", null),
+    React.createElement(React.Fragment, null, React.createElement("b", null)));
+}
@@ -0,0 +1,13 @@
+var _jsxFileName = "<CWD>/tests/babel-plugin-transform-react-jsx/test/fixtures/development/classic/input.jsx";
+function f() {
+  return React.createElement("div", {
+    __self: this,
+    __source: { fileName: _jsxFileName, lineNumber: 1, columnNumber: 23 }
+  }, "😀", React.createElement("a", {
+    __self: this,
+    __source: { fileName: _jsxFileName, lineNumber: 1, columnNumber: 34 }
+  }), React.createElement(React.Fragment, null, React.createElement("b", {
+    __self: this,
+    __source: { fileName: _jsxFileName, lineNumber: 1, columnNumber: 41 }
+  })));
+}
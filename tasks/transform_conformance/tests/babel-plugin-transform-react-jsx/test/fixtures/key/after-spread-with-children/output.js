@@ -0,0 +1,3 @@
+import { jsx as _jsx } from "react/jsx-runtime";
+import { createElement as _createElement } from "react";
+_createElement(A, { ...p, key: k }, _jsx("b", {}), _jsx("c", {}));
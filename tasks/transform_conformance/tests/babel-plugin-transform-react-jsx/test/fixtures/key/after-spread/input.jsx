@@ -0,0 +1 @@
+<A {...p} key={k} />;
@@ -0,0 +1,2 @@
+import { createElement as _createElement } from "react";
+_createElement(A, { ...p, key: k });
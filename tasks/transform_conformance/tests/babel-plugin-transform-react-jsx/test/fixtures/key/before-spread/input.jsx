@@ -0,0 +1 @@
+<A key={k} {...p} />;
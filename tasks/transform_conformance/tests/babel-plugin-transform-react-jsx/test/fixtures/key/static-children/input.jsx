@@ -0,0 +1 @@
+<A key={k} a="1"><b /><c /></A>;
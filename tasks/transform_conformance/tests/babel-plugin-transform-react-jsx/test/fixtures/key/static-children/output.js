@@ -0,0 +1,2 @@
+import { jsx as _jsx, jsxs as _jsxs } from "react/jsx-runtime";
+_jsxs(A, { a: "1", children: [_jsx("b", {}), _jsx("c", {})] }, k);
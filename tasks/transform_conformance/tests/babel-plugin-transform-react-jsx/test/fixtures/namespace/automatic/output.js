@@ -0,0 +1,2 @@
+import { jsx as _jsx } from "react/jsx-runtime";
+_jsx("svg:rect", { "xlink:href": "#a", width: 1 });
@@ -0,0 +1 @@
+<svg:rect xlink:href="#a" width={1} />;
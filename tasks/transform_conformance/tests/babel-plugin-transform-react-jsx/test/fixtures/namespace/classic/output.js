@@ -0,0 +1 @@
+React.createElement("svg:rect", { "xlink:href": "#a", width: 1 });
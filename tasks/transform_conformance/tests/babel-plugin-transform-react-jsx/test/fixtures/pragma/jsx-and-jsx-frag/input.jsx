@@ -0,0 +1 @@
+/** @jsx h */ /** @jsxFrag Fragment */ <><div /></>;
@@ -0,0 +1 @@
+/** @jsx h */ /** @jsxFrag Fragment */ h(Fragment, null, h("div", null));
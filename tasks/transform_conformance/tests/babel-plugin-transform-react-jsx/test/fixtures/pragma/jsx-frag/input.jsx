@@ -0,0 +1 @@
+/** @jsxFrag Fragment */ <></>;
@@ -0,0 +1 @@
+/** @jsxFrag Fragment */ React.createElement(Fragment, null);
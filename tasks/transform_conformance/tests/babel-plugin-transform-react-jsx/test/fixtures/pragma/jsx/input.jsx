@@ -0,0 +1 @@
+/** @jsx h */ <div />;
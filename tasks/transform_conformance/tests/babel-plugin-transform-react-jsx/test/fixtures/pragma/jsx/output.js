@@ -0,0 +1 @@
+/** @jsx h */ h("div", null);
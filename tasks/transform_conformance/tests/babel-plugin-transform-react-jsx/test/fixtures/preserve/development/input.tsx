@@ -0,0 +1 @@
+<div className={x as string} />;
@@ -0,0 +1,2 @@
+/** @jsxRuntime classic */
+<div className={x} />;
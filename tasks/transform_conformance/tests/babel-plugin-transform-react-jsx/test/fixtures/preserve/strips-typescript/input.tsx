@@ -0,0 +1,2 @@
+<div className={x as string} />;
+const a = <Foo<T> value={b!}><>{c satisfies C}</></Foo>;
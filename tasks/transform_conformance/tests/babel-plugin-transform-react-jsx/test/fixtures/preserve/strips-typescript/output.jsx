@@ -0,0 +1,2 @@
+<div className={x} />;
+const a = <Foo value={b}><>{c}</></Foo>;
@@ -0,0 +1,4 @@
+const Config = createConfig();
+const Button = styled(Base);
+console.log(Config);
+export const App = () => <Button />;
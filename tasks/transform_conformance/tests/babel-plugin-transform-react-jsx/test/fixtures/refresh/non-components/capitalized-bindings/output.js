@@ -0,0 +1,9 @@
+const Config = createConfig();
+const Button = styled(Base);
+_c = Button;
+console.log(Config);
+export const App = () => React.createElement(Button, null);
+_c2 = App;
+var _c, _c2;
+$RefreshReg$(_c, "Button");
+$RefreshReg$(_c2, "App");
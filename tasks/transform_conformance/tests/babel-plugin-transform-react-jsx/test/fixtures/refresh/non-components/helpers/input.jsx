@@ -0,0 +1,3 @@
+function formatDate(date) { return date.toString(); }
+const helper = () => {};
+export function App() { return <div>{formatDate(new Date())}</div>; }
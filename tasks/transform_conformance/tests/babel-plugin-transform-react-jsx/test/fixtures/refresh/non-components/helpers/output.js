@@ -0,0 +1,6 @@
+function formatDate(date) { return date.toString(); }
+const helper = () => {};
+export function App() { return React.createElement("div", null, formatDate(new Date())); }
+_c = App;
+var _c;
+$RefreshReg$(_c, "App");
@@ -0,0 +1,4 @@
+x = `a${b}${c}d`;
+y = `${a}${b}`;
+z = `${a}b`;
+w = tag`a`;
@@ -0,0 +1,5 @@
+var _templateObject;
+x = "a" + b + c + "d";
+y = "" + a + b;
+z = a + "b";
+w = tag(_templateObject || (_templateObject = babelHelpers.taggedTemplateLiteralLoose(["a"])));
@@ -0,0 +1,7 @@
+x = ``;
+x = `a`;
+x = `${a}`;
+x = `a${b}c`;
+// Only one non-literal expression in each `concat` call
+x = `a${b}${c}d`;
+x = `${1}${a}${''}`;
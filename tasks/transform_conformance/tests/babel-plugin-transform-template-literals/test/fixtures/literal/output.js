@@ -0,0 +1,7 @@
+x = "";
+x = "a";
+x = "".concat(a);
+x = "a".concat(b, "c");
+// Only one non-literal expression in each `concat` call
+x = "a".concat(b).concat(c, "d");
+x = "".concat(1, a);
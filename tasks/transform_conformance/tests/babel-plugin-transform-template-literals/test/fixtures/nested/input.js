@@ -0,0 +1,4 @@
+x = `a${inner`b${c}`}d`;
+x = outer`a${`b${c}`}d`;
+// Each tagged template has its own template object
+x = outer`a${inner`b${c}`}d`;
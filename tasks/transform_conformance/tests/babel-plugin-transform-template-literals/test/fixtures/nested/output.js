@@ -0,0 +1,5 @@
+var _templateObject, _templateObject2, _templateObject3, _templateObject4;
+x = "a".concat(inner(_templateObject || (_templateObject = babelHelpers.taggedTemplateLiteral(["b", ""])), c), "d");
+x = outer(_templateObject2 || (_templateObject2 = babelHelpers.taggedTemplateLiteral(["a", "d"])), "b".concat(c));
+// Each tagged template has its own template object
+x = outer(_templateObject3 || (_templateObject3 = babelHelpers.taggedTemplateLiteral(["a", "d"])), inner(_templateObject4 || (_templateObject4 = babelHelpers.taggedTemplateLiteral(["b", ""])), c));
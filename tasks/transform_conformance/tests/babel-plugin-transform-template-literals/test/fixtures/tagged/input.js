@@ -0,0 +1,5 @@
+x = tag`a${b}c`;
+// `this` of member expression tag is preserved
+x = a.tag`b`;
+// Raw strings are passed separately if they differ from cooked strings
+x = tag`\n${a}\unicode`;
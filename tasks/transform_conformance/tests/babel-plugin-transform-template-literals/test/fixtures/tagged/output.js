@@ -0,0 +1,6 @@
+var _templateObject, _templateObject2, _templateObject3;
+x = tag(_templateObject || (_templateObject = babelHelpers.taggedTemplateLiteral(["a", "c"])), b);
+// `this` of member expression tag is preserved
+x = a.tag(_templateObject2 || (_templateObject2 = babelHelpers.taggedTemplateLiteral(["b"])));
+// Raw strings are passed separately if they differ from cooked strings
+x = tag(_templateObject3 || (_templateObject3 = babelHelpers.taggedTemplateLiteral(["\n", void 0], ["\\n", "\\unicode"])), a);
@@ -1,4 +1,8 @@
 class Foo {
+  foo;
+  bar;
+  zoo;
+  bang;
   boom;
   constructor(foo, bar, zoo, bang, too) {
     this.foo = foo;
@@ -9,6 +13,11 @@ class Foo {
   }
 }
 class Bar extends Foo {
+  foo;
+  bar;
+  zoo;
+  bang;
+  boom;
   constructor(foo, bar, zoo, bang, boom, too) {
     super(foo, bar, zoo, bang, too);
     this.foo = foo;
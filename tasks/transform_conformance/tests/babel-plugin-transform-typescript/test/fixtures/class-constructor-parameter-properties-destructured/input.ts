@@ -0,0 +1,3 @@
+class Foo {
+  constructor(public { x }: { x: number }) {}
+}
@@ -0,0 +1,21 @@
+class Point {
+  y = 0;
+  constructor(x: number, private readonly z: number, public w = 1, ...rest: number[]) {
+    console.log(x, rest);
+  }
+}
+
+class Point3D extends Point {
+  constructor(private readonly label: string, z: number) {
+    const scaled = z * 2;
+    super(0, scaled);
+    console.log(this.label);
+  }
+}
+
+class Branches extends Point {
+  constructor(public flag: boolean) {
+    if (flag) super(1, 2);
+    else super(3, 4);
+  }
+}
@@ -0,0 +1,31 @@
+class Point {
+  z;
+  w;
+  y = 0;
+  constructor(x, z, w = 1, ...rest) {
+    this.z = z;
+    this.w = w;
+    console.log(x, rest);
+  }
+}
+class Point3D extends Point {
+  label;
+  constructor(label, z) {
+    const scaled = z * 2;
+    super(0, scaled);
+    this.label = label;
+    console.log(this.label);
+  }
+}
+class Branches extends Point {
+  flag;
+  constructor(flag) {
+    if (flag) {
+      super(1, 2);
+      this.flag = flag;
+    } else {
+      super(3, 4);
+      this.flag = flag;
+    }
+  }
+}
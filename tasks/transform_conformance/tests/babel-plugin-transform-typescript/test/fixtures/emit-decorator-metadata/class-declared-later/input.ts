@@ -0,0 +1,8 @@
+// Classes declared later are in their TDZ, so fall back to `Object`
+class A {
+  @dec self: A;
+  @dec b: B;
+}
+class B {
+  @dec a: A;
+}
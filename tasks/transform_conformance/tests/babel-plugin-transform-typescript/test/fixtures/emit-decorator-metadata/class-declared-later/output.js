@@ -0,0 +1,8 @@
+// Classes declared later are in their TDZ, so fall back to `Object`
+class A {
+  @dec @Reflect.metadata("design:type", A) self;
+  @dec @Reflect.metadata("design:type", Object) b;
+}
+class B {
+  @dec @Reflect.metadata("design:type", A) a;
+}
@@ -0,0 +1,19 @@
+// tsc: `__metadata("design:paramtypes", [HttpClient, Number, String, Object])`
+class HttpClient {}
+interface Options {}
+@Injectable()
+class Service {
+  constructor(private http: HttpClient, retries: number, name: string, options: Options) {}
+}
+
+// Parameter decorators on the constructor apply to the class
+class Injected {
+  constructor(@Inject("token") token: string, ...rest: number[]) {}
+}
+
+// No constructor, or no decorators
+@dec
+class A {}
+class B {
+  constructor(x: number) {}
+}
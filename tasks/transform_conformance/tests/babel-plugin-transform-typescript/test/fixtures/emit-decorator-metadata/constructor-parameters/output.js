@@ -0,0 +1,23 @@
+// tsc: `__metadata("design:paramtypes", [HttpClient, Number, String, Object])`
+class HttpClient {}
+@Injectable()
+@Reflect.metadata("design:paramtypes", [HttpClient, Number, String, Object])
+class Service {
+  http;
+  constructor(http, retries, name, options) {
+    this.http = http;
+  }
+}
+
+// Parameter decorators on the constructor apply to the class
+@Reflect.metadata("design:paramtypes", [String, Number])
+class Injected {
+  constructor(@Inject("token") token, ...rest) {}
+}
+
+// No constructor, or no decorators
+@dec
+class A {}
+class B {
+  constructor(x) {}
+}
@@ -0,0 +1,8 @@
+// Enum member types, and inlined `const enum`s
+const enum Label {
+  A = "a",
+  B = "b",
+}
+class A {
+  @dec method(label: Label, a: Label.A) {}
+}
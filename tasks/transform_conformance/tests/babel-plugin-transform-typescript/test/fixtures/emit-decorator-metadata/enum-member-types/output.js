@@ -0,0 +1,8 @@
+// Enum member types, and inlined `const enum`s
+class A {
+  @dec
+  @Reflect.metadata("design:type", Function)
+  @Reflect.metadata("design:paramtypes", [String, String])
+  @Reflect.metadata("design:returntype", void 0)
+  method(label, a) {}
+}
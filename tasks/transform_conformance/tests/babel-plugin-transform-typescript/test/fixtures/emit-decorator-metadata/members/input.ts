@@ -0,0 +1,11 @@
+class A {
+  @dec x: number;
+  @dec y;
+  @dec method(a: string, b?: boolean): void {}
+  @dec async run() {}
+  @dec get value(): string {
+    return "";
+  }
+  @dec set value(v: string) {}
+  other(@dec a: bigint): symbol {}
+}
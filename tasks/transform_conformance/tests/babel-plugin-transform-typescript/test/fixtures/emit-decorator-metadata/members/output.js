@@ -0,0 +1,31 @@
+class A {
+  @dec
+  @Reflect.metadata("design:type", Number)
+  x;
+  @dec
+  @Reflect.metadata("design:type", Object)
+  y;
+  @dec
+  @Reflect.metadata("design:type", Function)
+  @Reflect.metadata("design:paramtypes", [String, Boolean])
+  @Reflect.metadata("design:returntype", void 0)
+  method(a, b) {}
+  @dec
+  @Reflect.metadata("design:type", Function)
+  @Reflect.metadata("design:paramtypes", [])
+  @Reflect.metadata("design:returntype", Promise)
+  async run() {}
+  @dec
+  @Reflect.metadata("design:type", String)
+  get value() {
+    return "";
+  }
+  @dec
+  @Reflect.metadata("design:type", String)
+  @Reflect.metadata("design:paramtypes", [String])
+  set value(v) {}
+  @Reflect.metadata("design:type", Function)
+  @Reflect.metadata("design:paramtypes", [BigInt])
+  @Reflect.metadata("design:returntype", Symbol)
+  other(@dec a) {}
+}
@@ -0,0 +1,33 @@
+// tsc: `__metadata("design:paramtypes", [Service, Number, String, Boolean, Number, String, Object, Number])`
+class Service {}
+enum Direction {
+  Up,
+  Down,
+}
+enum Label {
+  A = "a",
+  B = `b`,
+  C = A + B,
+}
+const enum Flags {
+  None = 0,
+  A = 1 << 0,
+  B = 1 << 1,
+}
+const enum Mixed {
+  A = 1,
+  B = "b",
+}
+class A {
+  @dec
+  method(
+    service: Service,
+    count: number,
+    name: string,
+    enabled: boolean,
+    direction: Direction,
+    label: Label,
+    mixed: Mixed,
+    flags: Flags,
+  ) {}
+}
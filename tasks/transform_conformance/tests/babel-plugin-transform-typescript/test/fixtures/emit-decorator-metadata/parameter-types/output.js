@@ -0,0 +1,31 @@
+// tsc: `__metadata("design:paramtypes", [Service, Number, String, Boolean, Number, String, Object, Number])`
+class Service {}
+var Direction = function(Direction) {
+  Direction[Direction["Up"] = 0] = "Up";
+  Direction[Direction["Down"] = 1] = "Down";
+  return Direction;
+}(Direction || {});
+var Label = function(Label) {
+  Label["A"] = "a";
+  Label["B"] = "b";
+  Label["C"] = "ab";
+  return Label;
+}(Label || {});
+var Flags = function(Flags) {
+  Flags[Flags["None"] = 0] = "None";
+  Flags[Flags["A"] = 1] = "A";
+  Flags[Flags["B"] = 2] = "B";
+  return Flags;
+}(Flags || {});
+var Mixed = function(Mixed) {
+  Mixed[Mixed["A"] = 1] = "A";
+  Mixed["B"] = "b";
+  return Mixed;
+}(Mixed || {});
+class A {
+  @dec
+  @Reflect.metadata("design:type", Function)
+  @Reflect.metadata("design:paramtypes", [Service, Number, String, Boolean, Number, String, Object, Number])
+  @Reflect.metadata("design:returntype", void 0)
+  method(service, count, name, enabled, direction, label, mixed, flags) {}
+}
@@ -0,0 +1,9 @@
+// Imports referenced by metadata are retained
+import { Foo } from "./foo";
+import * as ns from "./ns";
+import type { Bar } from "./bar";
+class A {
+  @dec a: Foo;
+  @dec b: ns.Baz;
+  @dec c: Bar;
+}
@@ -0,0 +1,8 @@
+// Imports referenced by metadata are retained
+import { Foo } from "./foo";
+import * as ns from "./ns";
+class A {
+  @dec @Reflect.metadata("design:type", typeof Foo === "undefined" ? Object : Foo) a;
+  @dec @Reflect.metadata("design:type", typeof ns.Baz === "undefined" ? Object : ns.Baz) b;
+  @dec @Reflect.metadata("design:type", Object) c;
+}
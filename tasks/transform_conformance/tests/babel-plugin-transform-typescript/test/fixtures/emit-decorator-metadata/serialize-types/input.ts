@@ -0,0 +1,21 @@
+type Alias = string;
+enum E {
+  A,
+}
+class A<T> {
+  @dec a: "x" | "y";
+  @dec b: string | null | undefined;
+  @dec c: string | number;
+  @dec d: number[];
+  @dec e: [number, string];
+  @dec f: () => void;
+  @dec g: Alias;
+  @dec h: E;
+  @dec i: T;
+  @dec j: { x: number };
+  @dec k: any;
+  @dec l: Date;
+  @dec m: readonly string[];
+  @dec n: (number);
+  @dec o: -1;
+}
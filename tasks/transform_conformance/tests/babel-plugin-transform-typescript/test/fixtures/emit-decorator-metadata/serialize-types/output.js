@@ -0,0 +1,21 @@
+var E = function(E) {
+  E[E["A"] = 0] = "A";
+  return E;
+}(E || {});
+class A {
+  @dec @Reflect.metadata("design:type", String) a;
+  @dec @Reflect.metadata("design:type", String) b;
+  @dec @Reflect.metadata("design:type", Object) c;
+  @dec @Reflect.metadata("design:type", Array) d;
+  @dec @Reflect.metadata("design:type", Array) e;
+  @dec @Reflect.metadata("design:type", Function) f;
+  @dec @Reflect.metadata("design:type", Object) g;
+  @dec @Reflect.metadata("design:type", Number) h;
+  @dec @Reflect.metadata("design:type", Object) i;
+  @dec @Reflect.metadata("design:type", Object) j;
+  @dec @Reflect.metadata("design:type", Object) k;
+  @dec @Reflect.metadata("design:type", typeof Date === "undefined" ? Object : Date) l;
+  @dec @Reflect.metadata("design:type", Array) m;
+  @dec @Reflect.metadata("design:type", Number) n;
+  @dec @Reflect.metadata("design:type", Number) o;
+}
@@ -0,0 +1,4 @@
+import { Foo } from "./foo";
+class A {
+  @dec a: Foo;
+}
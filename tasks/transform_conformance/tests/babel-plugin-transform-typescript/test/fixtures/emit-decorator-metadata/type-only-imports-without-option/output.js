@@ -0,0 +1,4 @@
+class A {
+  @dec a;
+}
+export {};
@@ -0,0 +1,2 @@
+const obj = { a: 1 };
+export default obj;
@@ -0,0 +1,2 @@
+interface I {}
+export default I;
@@ -0,0 +1,2 @@
+const foo = 1;
+export default foo satisfies Bar;
@@ -0,0 +1,2 @@
+import type { T } from './t';
+export default T;
@@ -0,0 +1,5 @@
+import { U } from './u';
+const v = 1;
+type v = number;
+export default v;
+export { U };
@@ -0,0 +1,4 @@
+import { U } from './u';
+const v = 1;
+export default v;
+export { U };
@@ -0,0 +1,5 @@
+// Clashes with a top level binding
+const a = 0;
+export namespace N {
+  export const a = 1;
+}
@@ -0,0 +1,6 @@
+// Clashes with a top level binding
+const a = 0;
+export let N;
+(function(_N) {
+  const a = _N.a = 1;
+})(N || (N = {}));
@@ -0,0 +1,12 @@
+export namespace N {
+  export const a = 1, b = a + 1;
+  export function f() {
+    return c;
+  }
+  export class C {}
+  export enum E {
+    X,
+  }
+  export type T = string;
+  const c = 2;
+}
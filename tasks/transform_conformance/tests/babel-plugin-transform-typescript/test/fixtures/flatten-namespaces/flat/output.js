@@ -0,0 +1,10 @@
+export const a = 1, b = a + 1;
+export function f() {
+  return c;
+}
+export class C {}
+export let E = function(E) {
+  E[E["X"] = 0] = "X";
+  return E;
+}({});
+const c = 2;
@@ -0,0 +1,4 @@
+export function N() {}
+export namespace N {
+  export const a = 1;
+}
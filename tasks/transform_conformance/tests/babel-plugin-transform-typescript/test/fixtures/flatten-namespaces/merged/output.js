@@ -0,0 +1,4 @@
+export function N() {}
+(function(_N) {
+  const a = _N.a = 1;
+})(N || (N = {}));
@@ -0,0 +1,5 @@
+export namespace N {
+  export namespace M {
+    export const a = 1;
+  }
+}
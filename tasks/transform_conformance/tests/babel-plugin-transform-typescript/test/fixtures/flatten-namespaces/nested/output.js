@@ -0,0 +1,7 @@
+export let N;
+(function(_N) {
+  let M;
+  (function(_M) {
+    const a = _M.a = 1;
+  })(M || (M = _N.M || (_N.M = {})));
+})(N || (N = {}));
@@ -0,0 +1,4 @@
+let N;
+(function(_N) {
+  const a = _N.a = 1;
+})(N || (N = {}));
@@ -0,0 +1,4 @@
+export namespace N {
+  export const a = 1;
+}
+console.log(N.a);
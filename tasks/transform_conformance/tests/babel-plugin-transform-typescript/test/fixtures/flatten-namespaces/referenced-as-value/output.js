@@ -0,0 +1,5 @@
+export let N;
+(function(_N) {
+  const a = _N.a = 1;
+})(N || (N = {}));
+console.log(N.a);
@@ -0,0 +1,6 @@
+import a = require("m");
+a();
+export import b = require("m");
+import c = A.B.C;
+c();
+export import d = A.B;
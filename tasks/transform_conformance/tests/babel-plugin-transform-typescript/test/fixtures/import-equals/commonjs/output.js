@@ -0,0 +1,7 @@
+"use strict";
+const a = require("m");
+a();
+export const b = require("m");
+var c = A.B.C;
+c();
+export var d = A.B;
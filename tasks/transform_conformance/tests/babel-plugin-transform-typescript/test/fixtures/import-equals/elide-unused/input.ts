@@ -0,0 +1,6 @@
+import a = require("m");
+import b = require("m");
+let y: b.T;
+import c = A.B;
+import type d = require("m");
+d;
@@ -0,0 +1,6 @@
+namespace A.X {
+  export const B = { C: 1 };
+}
+namespace A.X {
+  export import D = B.C;
+}
@@ -0,0 +1,13 @@
+let A;
+(function(_A) {
+  let X;
+  (function(_X) {
+    const B = _X.B = { C: 1 };
+  })(X || (X = _A.X || (_A.X = {})));
+})(A || (A = {}));
+(function(_A2) {
+  let X;
+  (function(_X2) {
+    var D = _X2.D = _X2.B.C;
+  })(X || (X = _A2.X || (_A2.X = {})));
+})(A || (A = {}));
@@ -0,0 +1,9 @@
+// `B` is exported from an earlier declaration of `A`
+namespace A {
+  export namespace B {
+    export const x = 1;
+  }
+}
+namespace A {
+  export import D = B;
+}
@@ -0,0 +1,11 @@
+// `B` is exported from an earlier declaration of `A`
+let A;
+(function(_A) {
+  let B;
+  (function(_B) {
+    const x = _B.x = 1;
+  })(B || (B = _A.B || (_A.B = {})));
+})(A || (A = {}));
+(function(_A2) {
+  var D = _A2.D = _A2.B;
+})(A || (A = {}));
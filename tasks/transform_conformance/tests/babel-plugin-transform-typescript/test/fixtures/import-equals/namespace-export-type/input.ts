@@ -0,0 +1,11 @@
+namespace A {
+  export import type D = B.C;
+}
+
+// `T` only contains types
+namespace T {
+  export type U = 1;
+}
+namespace E {
+  export import D = T;
+}
@@ -0,0 +1,4 @@
+namespace A {
+  export import D = B.C;
+  D.x();
+}
@@ -0,0 +1,5 @@
+let A;
+(function(_A) {
+  var D = _A.D = B.C;
+  D.x();
+})(A || (A = {}));
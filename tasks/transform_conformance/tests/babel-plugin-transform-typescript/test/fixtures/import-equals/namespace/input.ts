@@ -0,0 +1,4 @@
+namespace N {
+  import x = A.B;
+  export const y = x;
+}
@@ -0,0 +1,5 @@
+let N;
+(function(_N) {
+  var x = A.B;
+  const y = _N.y = x;
+})(N || (N = {}));
@@ -0,0 +1 @@
+import x = require("m");
@@ -0,0 +1,2 @@
+"use strict";
+const x = require("m");
@@ -0,0 +1,21 @@
+function beforeDeclaration() {
+  return Flags.ReadWrite;
+}
+
+const enum Flags {
+  None,
+  Read = 1 << 0,
+  Write = 1 << 1,
+  ReadWrite = Read | Write,
+  Next,
+}
+
+const enum Size {
+  Small = Flags.Write * 10,
+  Large = Small * 2,
+  Label = "size-" + Large,
+  Negative = -Large,
+}
+
+let flags: Flags = Flags.Read | Flags["Write"];
+console.log(Flags.None, Flags.Next, Size.Small, Size.Large, Size.Label, Size.Negative);
@@ -0,0 +1,5 @@
+function beforeDeclaration() {
+  return 3;
+}
+let flags = 1 | 2;
+console.log(0, 4, 20, 40, "size-40", -40);
@@ -0,0 +1,4 @@
+const enum A {
+  X,
+}
+A.X;
@@ -0,0 +1,5 @@
+var A = function(A) {
+  A[A["X"] = 0] = "X";
+  return A;
+}(A || {});
+A.X;
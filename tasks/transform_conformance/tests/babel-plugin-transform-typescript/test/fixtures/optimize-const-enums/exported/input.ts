@@ -0,0 +1,6 @@
+// Exported `const enum`s may be used by other modules
+export const enum A {
+  X,
+  Y,
+}
+A.Y;
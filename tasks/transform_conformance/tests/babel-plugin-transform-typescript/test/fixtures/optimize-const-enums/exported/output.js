@@ -0,0 +1,7 @@
+// Exported `const enum`s may be used by other modules
+export let A = function(A) {
+  A[A["X"] = 0] = "X";
+  A[A["Y"] = 1] = "Y";
+  return A;
+}({});
+1;
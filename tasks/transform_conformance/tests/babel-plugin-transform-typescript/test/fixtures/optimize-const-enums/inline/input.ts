@@ -0,0 +1,22 @@
+const enum Color {
+  Red,
+  Green = "green",
+}
+console.log(Color.Red, Color["Green"]);
+
+// Members of `const enum`s are available to regular enums
+const enum A {
+  X = 1 << 3,
+}
+enum B {
+  Y = A.X + 1,
+}
+
+// Shadowed
+const enum C {
+  X,
+}
+function f(C) {
+  return C.X;
+}
+C.X;
@@ -0,0 +1,13 @@
+console.log(0, "green");
+
+// Members of `const enum`s are available to regular enums
+var B = function(B) {
+  B[B["Y"] = 9] = "Y";
+  return B;
+}(B || {});
+
+// Shadowed
+function f(C) {
+  return C.X;
+}
+0;
@@ -0,0 +1,6 @@
+const enum Color {
+  Red,
+  Green = "green",
+}
+const a = [Color.Red, Color["Green"], ...[Color.Red]];
+const o = { red: Color.Red, [Color.Green]: 1, nested: { green: Color.Green } };
@@ -0,0 +1,2 @@
+const a = [0, "green", ...[0]];
+const o = { red: 0, ["green"]: 1, nested: { green: "green" } };
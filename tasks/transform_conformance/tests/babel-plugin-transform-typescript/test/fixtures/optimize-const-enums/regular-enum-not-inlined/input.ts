@@ -0,0 +1,33 @@
+// Used other than by reading a member
+enum A {
+  Red,
+}
+const a = [A.Red, Object.keys(A)];
+
+enum B {
+  Red,
+}
+B.Red = 1;
+const b = [B.Red];
+
+function f() {
+  delete C.Red;
+}
+enum C {
+  Red,
+}
+const c = [C.Red];
+
+// Exported
+export enum D {
+  Red,
+}
+const d = [D.Red];
+
+// Members which are not constant
+enum E {
+  Red = 1,
+  Green = "a".length,
+  Blue = E.Red,
+}
+const e = [E.Red, E.Green];
@@ -0,0 +1,38 @@
+// Used other than by reading a member
+var A = function(A) {
+  A[A["Red"] = 0] = "Red";
+  return A;
+}(A || {});
+const a = [A.Red, Object.keys(A)];
+
+var B = function(B) {
+  B[B["Red"] = 0] = "Red";
+  return B;
+}(B || {});
+B.Red = 1;
+const b = [B.Red];
+
+function f() {
+  delete C.Red;
+}
+var C = function(C) {
+  C[C["Red"] = 0] = "Red";
+  return C;
+}(C || {});
+const c = [C.Red];
+
+// Exported
+export let D = function(D) {
+  D[D["Red"] = 0] = "Red";
+  return D;
+}({});
+const d = [D.Red];
+
+// Members which are not constant
+var E = function(E) {
+  E[E["Red"] = 1] = "Red";
+  E[E["Green"] = "a".length] = "Green";
+  E[E["Blue"] = 1] = "Blue";
+  return E;
+}(E || {});
+const e = [1, E.Green];
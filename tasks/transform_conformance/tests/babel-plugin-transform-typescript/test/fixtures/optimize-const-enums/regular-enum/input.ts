@@ -0,0 +1,7 @@
+// Regular enums which are only used by reading constant members
+enum Color {
+  Red,
+  Green,
+}
+const a = [Color.Red, Color.Green];
+const o = { red: Color.Red };
@@ -0,0 +1,8 @@
+// Regular enums which are only used by reading constant members
+var Color = function(Color) {
+  Color[Color["Red"] = 0] = "Red";
+  Color[Color["Green"] = 1] = "Green";
+  return Color;
+}(Color || {});
+const a = [0, 1];
+const o = { red: 0 };
@@ -0,0 +1,8 @@
+import { type A, b } from "m";
+import { type C } from "n";
+import D, { E } from "o";
+import * as ns from "p";
+import type { F } from "q";
+export { type G, h } from "r";
+let x: A;
+let y: E;
@@ -0,0 +1,3 @@
+export { h } from "r";
+let x;
+let y;
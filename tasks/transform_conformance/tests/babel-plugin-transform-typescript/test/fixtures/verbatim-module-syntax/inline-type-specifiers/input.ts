@@ -0,0 +1,3 @@
+import { type A, b } from "m";
+import { type C } from "n";
+let x: A;
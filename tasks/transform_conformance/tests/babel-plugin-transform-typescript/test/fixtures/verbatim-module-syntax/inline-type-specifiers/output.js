@@ -0,0 +1,3 @@
+import { b } from "m";
+import {} from "n";
+let x;
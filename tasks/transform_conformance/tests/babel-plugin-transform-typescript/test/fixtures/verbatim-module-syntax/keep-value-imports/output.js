@@ -0,0 +1,3 @@
+import A, { B } from "m";
+import * as ns from "n";
+let x;
@@ -0,0 +1,4 @@
+import type { A } from "m";
+import type B from "n";
+export type { C } from "o";
+export { type D, e } from "p";
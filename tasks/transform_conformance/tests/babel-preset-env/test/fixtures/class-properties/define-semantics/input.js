@@ -0,0 +1,9 @@
+class Base {
+  get x() {
+    return 1;
+  }
+}
+class C extends Base {
+  x = 2;
+  y;
+}
@@ -0,0 +1,13 @@
+class Base {
+  get x() {
+    return 1;
+  }
+}
+class C extends Base {
+  constructor(..._args) {
+    super(..._args);
+    // `x` is defined on the instance, shadowing the getter
+    babelHelpers.defineProperty(this, "x", 2);
+    babelHelpers.defineProperty(this, "y", void 0);
+  }
+}
@@ -0,0 +1,10 @@
+class C extends B {
+  'a-b' = 1;
+  f = () => this;
+  static s = 2;
+  constructor(v) {
+    log();
+    super(v);
+    this.v = v;
+  }
+}
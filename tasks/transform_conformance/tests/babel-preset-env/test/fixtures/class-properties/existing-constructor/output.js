@@ -0,0 +1,10 @@
+class C extends B {
+  static s = 2;
+  constructor(v) {
+    log();
+    super(v);
+    this["a-b"] = 1;
+    this.f = () => this;
+    this.v = v;
+  }
+}
@@ -0,0 +1,13 @@
+class Base {
+  get x() {
+    return 1;
+  }
+}
+class C extends Base {
+  constructor(..._args) {
+    super(..._args);
+    // `this.x = 2` calls the inherited accessor, which has no setter
+    this.x = 2;
+    this.y = void 0;
+  }
+}
@@ -0,0 +1,10 @@
+class Base {
+  get x() {
+    return 1;
+  }
+}
+class C extends Base {
+  declare x: number;
+  y: string;
+  z = 3;
+}
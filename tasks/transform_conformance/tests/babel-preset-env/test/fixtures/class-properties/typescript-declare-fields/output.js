@@ -0,0 +1,11 @@
+class Base {
+  get x() {
+    return 1;
+  }
+}
+class C extends Base {
+  constructor(..._args) {
+    super(..._args);
+    this.z = 3;
+  }
+}
@@ -0,0 +1,12 @@
+class Base {
+  get x() {
+    return 1;
+  }
+}
+class C extends Base {
+  constructor(..._args) {
+    super(..._args);
+    babelHelpers.defineProperty(this, "y", void 0);
+    babelHelpers.defineProperty(this, "z", 3);
+  }
+}
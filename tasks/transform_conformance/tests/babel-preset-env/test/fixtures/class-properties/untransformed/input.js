@@ -0,0 +1,26 @@
+// Private field
+class A {
+  x = 1;
+  #y = 2;
+}
+
+// Computed key
+class B {
+  [k] = 1;
+}
+
+// Initializer refers to a binding shadowed by constructor parameter
+let v = 1;
+class C {
+  x = v;
+  constructor(v) {}
+}
+
+// `super()` is not at top level of constructor
+class D extends Base {
+  x = 1;
+  constructor() {
+    if (a) super();
+    else super();
+  }
+}
@@ -0,0 +1,24 @@
+// Identifier and string keys set the prototype
+x = { __proto__: p, a: 1 };
+x = { a: 1, '__proto__': p };
+x = { __proto__: null };
+
+// Computed keys, shorthand properties and methods define an own property
+x = { ['__proto__']: p };
+x = { __proto__ };
+x = { __proto__() {} };
+
+// Primitive values are ignored
+x = { __proto__: 1, a: 1 };
+x = { __proto__: 'p', a: 1 };
+
+// Nested object literals
+x = { __proto__: { __proto__: p } };
+
+// No temp var when order of evaluation does not matter
+x = { __proto__: p(), a: 1 };
+x = { a: a(), __proto__: p() };
+
+// `__proto__` value is evaluated before properties which follow it
+x = { __proto__: p(), a: a() };
+x = { __proto__: p, [k()]: 1 };
@@ -0,0 +1,25 @@
+var _p, _p2;
+// Identifier and string keys set the prototype
+x = Object.setPrototypeOf({ a: 1 }, p);
+x = Object.setPrototypeOf({ a: 1 }, p);
+x = Object.setPrototypeOf({}, null);
+
+// Computed keys, shorthand properties and methods define an own property
+x = { ['__proto__']: p };
+x = { __proto__ };
+x = { __proto__() {} };
+
+// Primitive values are ignored
+x = { a: 1 };
+x = { a: 1 };
+
+// Nested object literals
+x = Object.setPrototypeOf({}, Object.setPrototypeOf({}, p));
+
+// No temp var when order of evaluation does not matter
+x = Object.setPrototypeOf({ a: 1 }, p());
+x = Object.setPrototypeOf({ a: a() }, p());
+
+// `__proto__` value is evaluated before properties which follow it
+x = (_p = p(), Object.setPrototypeOf({ a: a() }, _p));
+x = (_p2 = p, Object.setPrototypeOf({ [k()]: 1 }, _p2));
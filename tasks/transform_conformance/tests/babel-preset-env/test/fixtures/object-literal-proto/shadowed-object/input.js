@@ -0,0 +1,4 @@
+function f(Object) {
+  return { __proto__: p };
+}
+x = { __proto__: p };
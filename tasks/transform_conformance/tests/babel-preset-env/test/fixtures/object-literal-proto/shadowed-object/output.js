@@ -0,0 +1,4 @@
+function f(Object) {
+  return Object.setPrototypeOf({}, p);
+}
+x = Object.setPrototypeOf({}, p);
@@ -0,0 +1 @@
+x = { __proto__: p, a: a() };
@@ -0,0 +1,18 @@
+import "./a.ts";
+import "./a.mts";
+import "./a.cts";
+import "./react.tsx";
+// .mtsx and .ctsx are not valid and should not be transformed.
+import "./react.mtsx";
+import "./react.ctsx";
+import "a-package/file.ts";
+// Bare import, it's either a node package or remapped by an import map
+import "soundcloud.ts";
+import "ipaddr.js";
+import "./data.json";
+import "./extensionless";
+export * from "./x.mts";
+export { y } from "./y.cts";
+import("./y.cts");
+import(`./z.ts`);
+import(source);
@@ -0,0 +1,16 @@
+import "./a.js";
+import "./a.mjs";
+import "./a.cjs";
+import "./react.js";
+import "./react.mtsx";
+import "./react.ctsx";
+import "a-package/file.js";
+import "soundcloud.ts";
+import "ipaddr.js";
+import "./data.json";
+import "./extensionless";
+export * from "./x.mjs";
+export { y } from "./y.cjs";
+import("./y.cjs");
+import(`./z.ts`);
+import(source);
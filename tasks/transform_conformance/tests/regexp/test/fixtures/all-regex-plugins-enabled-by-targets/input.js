@@ -6,14 +6,11 @@ x2 = /./u
 // ES2018
 // RegExpDotAllFlag
 a1 = /a.b/s
-// RegExpLookbehindAssertions
-b1 = /(?<!x)/
-b2 = /(?<=x)/
-b3 = /((?<!x)){2}/
-b4 = /((?<=x)){3}/
+a2 = /a.b/sy
 // RegExpNamedCaptureGroups
 c1 = /(?<a>b)/
 c2 = /((?<c>d)){4}/;
+c3 = /(?<e>.)\k<e>/s;
 // RegExpUnicodePropertyEscapes
 d1 = /\p{Emoji}/u
 // ES2022
@@ -1,12 +1,10 @@
 x1 = new RegExp(".", "y");
 x2 = new RegExp(".", "u");
-a1 = new RegExp("a.b", "s");
-b1 = new RegExp("(?<!x)", "");
-b2 = new RegExp("(?<=x)", "");
-b3 = new RegExp("((?<!x)){2}", "");
-b4 = new RegExp("((?<=x)){3}", "");
-c1 = new RegExp("(?<a>b)", "");
-c2 = new RegExp("((?<c>d)){4}", "");
+a1 = /a[\s\S]b/;
+a2 = new RegExp("a[\\s\\S]b", "y");
+c1 = babelHelpers.wrapRegExp(/(b)/, { a: 1 });
+c2 = babelHelpers.wrapRegExp(/((d)){4}/, { c: 2 });
+c3 = babelHelpers.wrapRegExp(/([\s\S])\1/, { e: 1 });
 d1 = new RegExp("\\p{Emoji}", "u");
 f1 = new RegExp("y", "d");
-g1 = new RegExp("[\\p{White_Space}&&\\p{ASCII}]", "v");
+g1 = new RegExp("(?:(?=\\p{White_Space})\\p{ASCII})", "u");
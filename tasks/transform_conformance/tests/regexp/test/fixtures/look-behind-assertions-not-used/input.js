@@ -0,0 +1,3 @@
+a = /(?=x)(?!y)/
+b = /[(?<=x)]/
+c = /\(?<=x\)/
@@ -0,0 +1,7 @@
+b1 = /(?<!x)/
+b2 = /(?<=x)/
+b3 = /((?<!x)){2}/
+b4 = /((?<=x)){3}/
+b5 = /(?=(?<=x))/
+b6 = /(?=x)(?!y)/
+b7 = /[(?<=x)]/
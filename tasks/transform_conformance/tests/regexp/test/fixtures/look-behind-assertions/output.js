@@ -0,0 +1,7 @@
+b1 = new RegExp("(?<!x)", "");
+b2 = new RegExp("(?<=x)", "");
+b3 = new RegExp("((?<!x)){2}", "");
+b4 = new RegExp("((?<=x)){3}", "");
+b5 = new RegExp("(?=(?<=x))", "");
+b6 = /(?=x)(?!y)/;
+b7 = /[(?<=x)]/;
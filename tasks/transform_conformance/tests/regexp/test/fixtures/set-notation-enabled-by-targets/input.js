@@ -0,0 +1,3 @@
+a = /[\p{ASCII}&&\p{L}]/v
+b = /[\w--[a-f]]/v
+c = /[a[bc]]/v
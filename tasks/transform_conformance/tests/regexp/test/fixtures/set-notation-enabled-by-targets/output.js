@@ -0,0 +1,3 @@
+a = /(?:(?=\p{ASCII})\p{L})/u;
+b = /(?:(?![a-f])\w)/u;
+c = /[abc]/u;
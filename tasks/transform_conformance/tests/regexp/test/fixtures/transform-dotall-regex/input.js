@@ -1 +1,6 @@
 a1 = /a.b/s
+a2 = /a.b/gims
+a3 = /[.]\..+/s
+a4 = /(.)(?:.|x)*(?=.)(?-s:.)/s
+a5 = /./su
+a6 = /a.b/
@@ -1 +1,6 @@
-a1 = new RegExp("a.b", "s");
+a1 = /a[\s\S]b/;
+a2 = /a[\s\S]b/gim;
+a3 = /[.]\.[\s\S]+/;
+a4 = /([\s\S])(?:[\s\S]|x)*(?=[\s\S])(?-s:.)/;
+a5 = /[\s\S]/u;
+a6 = /a.b/;
@@ -0,0 +1,3 @@
+a1 = /(?<year>\d{4})-\d{2}|\d{2}-(?<year>\d{4})/;
+a2 = /(?:(?<a>x)|(?<a>y))\k<a>/;
+a3 = /(?<a>x)|(?<b>y)(?<a>z)\k<a>+/;
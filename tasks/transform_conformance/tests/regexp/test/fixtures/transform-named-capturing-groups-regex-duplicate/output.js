@@ -0,0 +1,6 @@
+a1 = babelHelpers.wrapRegExp(/(\d{4})-\d{2}|\d{2}-(\d{4})/, { year: [1, 2] });
+a2 = babelHelpers.wrapRegExp(/(?:(x)|(y))(?:\1\2)/, { a: [1, 2] });
+a3 = babelHelpers.wrapRegExp(/(x)|(y)(z)(?:\1\3)+/, {
+	a: [1, 3],
+	b: 2
+});
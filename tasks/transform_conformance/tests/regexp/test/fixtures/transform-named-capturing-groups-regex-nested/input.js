@@ -0,0 +1,7 @@
+a1 = /(?<date>(?<year>\d{4})-(?<month>\d{2}))/;
+a2 = /(\d)(?<word>\w+)\k<word>\1/;
+a3 = /\k<later>(?<later>x)/;
+a4 = /(?:(?<a>x)|y)+(?=(?<b>z))\k<a>*/;
+a5 = /(?<a>x)/g;
+a6 = /[(?<a>x)]/;
+a7 = /(?<a>x)(?<=y)/s;
@@ -0,0 +1,14 @@
+a1 = babelHelpers.wrapRegExp(/((\d{4})-(\d{2}))/, {
+	date: 1,
+	year: 2,
+	month: 3
+});
+a2 = babelHelpers.wrapRegExp(/(\d)(\w+)\2\1/, { word: 2 });
+a3 = babelHelpers.wrapRegExp(/\1(x)/, { later: 1 });
+a4 = babelHelpers.wrapRegExp(/(?:(x)|y)+(?=(z))\1*/, {
+	a: 1,
+	b: 2
+});
+a5 = babelHelpers.wrapRegExp(/(x)/g, { a: 1 });
+a6 = /[(?<a>x)]/;
+a7 = babelHelpers.wrapRegExp(/(x)(?<=y)/s, { a: 1 });
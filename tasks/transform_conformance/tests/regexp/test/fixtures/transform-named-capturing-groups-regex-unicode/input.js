@@ -0,0 +1,3 @@
+u1 = /(?<year>\d{4})/u;
+u2 = /(?<emoji>\u{1F600})\k<emoji>/gu;
+u3 = /(?<year>\d{4})-(?<month>\d{2})/;
@@ -0,0 +1,6 @@
+u1 = babelHelpers.wrapRegExp(new RegExp("(\\d{4})", "u"), { year: 1 });
+u2 = babelHelpers.wrapRegExp(new RegExp("(\\u{1F600})\\1", "gu"), { emoji: 1 });
+u3 = babelHelpers.wrapRegExp(/(\d{4})-(\d{2})/, {
+	year: 1,
+	month: 2
+});
@@ -1 +1,11 @@
 g1 = /[\p{White_Space}&&\p{ASCII}]/v
+g2 = /[\p{ASCII}&&\p{L}&&[a-f]]/v
+g3 = /[\w--\d]/v
+g4 = /[\w--[a-f]--_]+/gv
+g5 = /[a[b-c]\q{d|e}]/v
+g6 = /[a[^b]]/v
+g7 = /[^\w--\d]/v
+g8 = /[^a[bc]]/v
+g9 = /[\&\-\!][\(\)]/v
+g10 = /(?<x>[\w&&[^\d]])\k<x>/v
+g11 = /\p{L}.[^]/v
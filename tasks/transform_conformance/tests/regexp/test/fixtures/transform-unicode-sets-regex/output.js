@@ -1 +1,11 @@
-g1 = new RegExp("[\\p{White_Space}&&\\p{ASCII}]", "v");
+g1 = /(?:(?=\p{White_Space})\p{ASCII})/u;
+g2 = /(?:(?=\p{ASCII})(?=\p{L})[a-f])/u;
+g3 = /(?:(?!\d)\w)/u;
+g4 = /(?:(?![a-f])(?![_])\w)+/gu;
+g5 = /[ab-cde]/u;
+g6 = /(?:[a]|[^b])/u;
+g7 = /(?:(?!(?!\d)\w)[\s\S])/u;
+g8 = /[^abc]/u;
+g9 = /[&\-!][\(\)]/u;
+g10 = /(?<x>(?:(?=\w)[^\d]))\k<x>/u;
+g11 = /\p{L}.[^]/u;